@@ -0,0 +1,80 @@
+use clap::{App, AppSettings, Arg, SubCommand};
+use std::process::{self, Command};
+
+mod new_lint;
+
+const CATEGORIES: [&str; 9] = [
+    "style",
+    "correctness",
+    "suspicious",
+    "complexity",
+    "perf",
+    "pedantic",
+    "restriction",
+    "cargo",
+    "nursery",
+];
+
+fn main() {
+    let matches = App::new("Clippy developer tooling")
+        .setting(AppSettings::SubcommandRequiredElseHelp)
+        .subcommand(
+            SubCommand::with_name("new_lint")
+                .about("Create a new lint and run `util/update_lints.py`")
+                .arg(
+                    Arg::with_name("pass")
+                        .short("p")
+                        .long("pass")
+                        .help("Specify whether the lint runs during the early or late pass")
+                        .takes_value(true)
+                        .possible_values(&["early", "late"])
+                        .required(true),
+                )
+                .arg(
+                    Arg::with_name("name")
+                        .short("n")
+                        .long("name")
+                        .help("Name of the new lint in snake case, ex: fn_too_long")
+                        .takes_value(true)
+                        .required(true),
+                )
+                .arg(
+                    Arg::with_name("category")
+                        .short("c")
+                        .long("category")
+                        .help("What category the lint belongs to")
+                        .default_value("nursery")
+                        .possible_values(&CATEGORIES)
+                        .takes_value(true),
+                ),
+        )
+        .get_matches();
+
+    if let Some(matches) = matches.subcommand_matches("new_lint") {
+        let lint = new_lint::NewLint {
+            pass: matches.value_of("pass").expect("`pass` is required"),
+            name: matches.value_of("name").expect("`name` is required"),
+            category: matches.value_of("category").expect("`category` has a default value"),
+        };
+
+        if let Err(err) = lint.create() {
+            eprintln!("Unable to create lint: {}", err);
+            process::exit(1);
+        }
+
+        update_lints();
+    }
+}
+
+/// Runs `util/update_lints.py` to declare the new module and add the lint to its lint groups.
+fn update_lints() {
+    let ran = Command::new("python")
+        .arg("util/update_lints.py")
+        .status()
+        .map(|status| status.success())
+        .unwrap_or(false);
+
+    if !ran {
+        eprintln!("Could not run `util/update_lints.py`, please run it manually to finish registering the lint");
+    }
+}
@@ -0,0 +1,209 @@
+//! Scaffolding of new lints: creates the lint module and its ui test, and registers the lint
+//! pass in `clippy_lints/src/lib.rs`.
+
+use std::fs::{self, OpenOptions};
+use std::io::{self, ErrorKind, Write};
+use std::path::Path;
+
+const LIB_RS: &str = "clippy_lints/src/lib.rs";
+const CARGO_TOML: &str = "clippy_lints/Cargo.toml";
+
+pub struct NewLint<'a> {
+    /// Either `early` or `late`.
+    pub pass: &'a str,
+    /// The name of the lint in snake case.
+    pub name: &'a str,
+    pub category: &'a str,
+}
+
+impl<'a> NewLint<'a> {
+    pub fn create(&self) -> io::Result<()> {
+        if !Path::new(LIB_RS).is_file() {
+            return Err(io::Error::new(
+                ErrorKind::NotFound,
+                "call this command from the Clippy checkout directory",
+            ));
+        }
+
+        let lint_path = format!("clippy_lints/src/{}.rs", self.name);
+        let test_path = format!("tests/ui/{}.rs", self.name);
+
+        let version = next_version(&fs::read_to_string(CARGO_TOML)?)
+            .ok_or_else(|| io::Error::new(ErrorKind::InvalidData, "no version found in the manifest of clippy_lints"))?;
+        create_file(&lint_path, &self.lint_module(&version))?;
+        create_file(&test_path, &self.test())?;
+        self.register_pass()?;
+
+        println!("Generated lint file: `{}`", lint_path);
+        println!("Generated test file: `{}`", test_path);
+
+        Ok(())
+    }
+
+    /// The name of the lint pass struct, in camel case.
+    fn pass_type(&self) -> String {
+        to_camel_case(self.name)
+    }
+
+    /// The lint module, the lint being declared as added in Clippy `version`.
+    fn lint_module(&self, version: &str) -> String {
+        let (imports, pass_impl) = if self.pass == "early" {
+            (
+                "use rustc::lint::{EarlyLintPass, LintArray, LintPass};",
+                format!("impl EarlyLintPass for {} {{}}", self.pass_type()),
+            )
+        } else {
+            (
+                "use rustc::lint::{LateLintPass, LintArray, LintPass};",
+                format!("impl<'a, 'tcx> LateLintPass<'a, 'tcx> for {} {{}}", self.pass_type()),
+            )
+        };
+
+        format!(
+            r#"{imports}
+use rustc::{{declare_lint, lint_array}};
+
+declare_clippy_lint! {{
+    /// **What it does:**
+    ///
+    /// **Why is this bad?**
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// // example code
+    /// ```
+    #[clippy::version = "{version}"]
+    pub {upper},
+    {category},
+    "default lint description"
+}}
+
+pub struct {pass_type};
+
+impl LintPass for {pass_type} {{
+    fn get_lints(&self) -> LintArray {{
+        lint_array!({upper})
+    }}
+}}
+
+{pass_impl}
+"#,
+            imports = imports,
+            version = version,
+            upper = self.name.to_uppercase(),
+            category = self.category,
+            pass_type = self.pass_type(),
+            pass_impl = pass_impl,
+        )
+    }
+
+    fn test(&self) -> String {
+        format!(
+            r#"#![warn({})]
+
+fn main() {{
+    // test code goes here
+}}
+"#,
+            self.name
+        )
+    }
+
+    /// Adds the registration of the lint pass after the last registered pass. The module and the
+    /// lint groups are taken care of by `util/update_lints.py`.
+    fn register_pass(&self) -> io::Result<()> {
+        let registration = format!(
+            "    reg.register_{}_lint_pass(box {}::{});",
+            self.pass,
+            self.name,
+            self.pass_type()
+        );
+
+        let lib_rs = fs::read_to_string(LIB_RS)?;
+        let mut lines: Vec<&str> = lib_rs.lines().collect();
+
+        let last_pass = lines
+            .iter()
+            .rposition(|line| line.contains("reg.register_early_lint_pass") || line.contains("reg.register_late_lint_pass"))
+            .ok_or_else(|| io::Error::new(ErrorKind::InvalidData, "no lint pass registration found in lib.rs"))?;
+        // registrations can span several lines
+        let end = lines[last_pass..]
+            .iter()
+            .position(|line| line.trim_right().ends_with(';'))
+            .map_or(last_pass, |offset| last_pass + offset);
+        lines.insert(end + 1, &registration);
+
+        let mut content = lines.join("\n");
+        content.push('\n');
+        fs::write(LIB_RS, content)
+    }
+}
+
+/// Returns the version of the next release, the one a new lint will be part of, from the manifest
+/// of `clippy_lints`: `0.0.213` for the `0.0.212` version.
+fn next_version(manifest: &str) -> Option<String> {
+    let version = manifest
+        .lines()
+        .find(|line| line.starts_with("version = "))?
+        .trim_left_matches("version = ")
+        .trim_matches('"');
+    let (major_minor, patch) = version.split_at(version.rfind('.')? + 1);
+    Some(format!("{}{}", major_minor, patch.parse::<u32>().ok()? + 1))
+}
+
+/// Creates a new file, failing if it already exists rather than overwriting an existing lint.
+fn create_file(path: &str, content: &str) -> io::Result<()> {
+    OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(content.as_bytes()))
+        .map_err(|err| io::Error::new(err.kind(), format!("could not create `{}`: {}", path, err)))
+}
+
+fn to_camel_case(name: &str) -> String {
+    name.split('_')
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_camel_case() {
+        assert_eq!(to_camel_case("fn_too_long"), "FnTooLong");
+        assert_eq!(to_camel_case("exit"), "Exit");
+        assert_eq!(to_camel_case("a__b"), "AB");
+    }
+
+    #[test]
+    fn test_lint_module() {
+        let lint = NewLint {
+            pass: "early",
+            name: "fn_too_long",
+            category: "style",
+        };
+        let module = lint.lint_module("0.0.213");
+        assert!(module.contains("#[clippy::version = \"0.0.213\"]\n    pub FN_TOO_LONG,\n    style,"));
+        assert!(module.contains("impl EarlyLintPass for FnTooLong {}"));
+    }
+
+    #[test]
+    fn test_next_version() {
+        let manifest = "[package]\nname = \"clippy_lints\"\n# begin automatic update\nversion = \"0.0.212\"\n";
+        assert_eq!(next_version(manifest), Some("0.0.213".to_owned()));
+        assert_eq!(next_version("version = \"0.1.9\""), Some("0.1.10".to_owned()));
+        assert_eq!(next_version("[package]\nname = \"clippy_lints\"\n"), None);
+    }
+}
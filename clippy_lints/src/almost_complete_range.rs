@@ -0,0 +1,119 @@
+use crate::consts::{constant, Constant};
+use crate::utils::{higher, in_macro, snippet_opt, span_help_and_lint, span_lint_and_then};
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::{declare_lint, lint_array};
+use rustc_errors::Applicability;
+use syntax::ast::{LitKind, RangeLimits};
+use syntax::source_map::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for ranges of ASCII letters or digits which are
+    /// almost complete, e.g. `'a'..'z'` which misses the `'z'`, and for ranges
+    /// of letters mixing both cases, e.g. `'A'..='z'`, which also contain the
+    /// punctuation between `'Z'` and `'a'`.
+    ///
+    /// **Why is this bad?** This is most likely a typo for an inclusive range, or
+    /// for the letters of both cases.
+    ///
+    /// **Known problems:** Only `char` and byte (`b'a'`) endpoints are checked.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let _ = 'a'..'z';
+    /// let _ = 'A'..='z';
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let _ = 'a'..='z';
+    /// let _ = ('A'..='Z').chain('a'..='z');
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub ALMOST_COMPLETE_RANGE,
+    correctness,
+    "almost complete ASCII letter or digit range"
+}
+
+#[derive(Copy, Clone)]
+pub struct AlmostCompleteRange;
+
+impl LintPass for AlmostCompleteRange {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(ALMOST_COMPLETE_RANGE)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for AlmostCompleteRange {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if let Some(higher::Range {
+            start: Some(start),
+            end: Some(end),
+            limits,
+        }) = higher::range(cx, expr)
+        {
+            check_range(cx, expr.span, start, end, limits == RangeLimits::HalfOpen);
+        }
+    }
+
+    fn check_pat(&mut self, cx: &LateContext<'a, 'tcx>, pat: &'tcx Pat) {
+        if let PatKind::Range(ref start, ref end, ref range_end) = pat.node {
+            check_range(cx, pat.span, start, end, *range_end == RangeEnd::Excluded);
+        }
+    }
+}
+
+const PAIRS: [(char, char); 3] = [('a', 'z'), ('A', 'Z'), ('0', '9')];
+
+fn check_range(cx: &LateContext<'_, '_>, span: Span, start: &Expr, end: &Expr, exclusive: bool) {
+    if in_macro(span) {
+        return;
+    }
+    let (start_char, is_byte) = match endpoint(cx, start) {
+        Some(endpoint) => endpoint,
+        None => return,
+    };
+    let end_char = match endpoint(cx, end) {
+        Some((end_char, end_is_byte)) if end_is_byte == is_byte => end_char,
+        _ => return,
+    };
+
+    if exclusive && PAIRS.contains(&(start_char, end_char)) {
+        span_lint_and_then(cx, ALMOST_COMPLETE_RANGE, span, "almost complete ascii range", |db| {
+            if let (Some(start), Some(end)) = (snippet_opt(cx, start.span), snippet_opt(cx, end.span)) {
+                db.span_suggestion_with_applicability(
+                    span,
+                    "use an inclusive range",
+                    format!("{}..={}", start, end),
+                    Applicability::MachineApplicable,
+                );
+            }
+        });
+    } else if start_char == 'A' && end_char == 'z' {
+        let prefix = if is_byte { "b" } else { "" };
+        span_help_and_lint(
+            cx,
+            ALMOST_COMPLETE_RANGE,
+            span,
+            "this range also contains the characters between `Z` and `a`",
+            &format!(
+                "if you meant to use the letters of both cases, use two ranges: `{0}'A'..={0}'Z'` and \
+                 `{0}'a'..={0}'z'`",
+                prefix
+            ),
+        );
+    }
+}
+
+/// Returns the ASCII character of a `char` or byte range endpoint, and whether it's a byte
+fn endpoint(cx: &LateContext<'_, '_>, expr: &Expr) -> Option<(char, bool)> {
+    // integers are only checked when written as byte literals to avoid linting ranges like `48..57`
+    if let ExprKind::Lit(ref lit) = expr.node {
+        if let LitKind::Byte(b) = lit.node {
+            return Some((char::from(b), true));
+        }
+    }
+    match constant(cx, cx.tables, expr) {
+        Some((Constant::Char(c), _)) if c.is_ascii() => Some((c, false)),
+        _ => None,
+    }
+}
@@ -0,0 +1,217 @@
+use crate::consts::{constant_simple, Constant};
+use crate::utils::{
+    clip, in_constant, int_bits, match_def_path, paths, sext, span_lint, uint_bits, unsext, walk_ptrs_ty,
+};
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::ty::{self, Ty};
+use rustc::{declare_lint, lint_array};
+use syntax::ast;
+use syntax::source_map::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for arithmetic operations which can overflow or panic at
+    /// runtime: the integer operations whose operands aren't known to keep the result
+    /// in range, and the overloaded operators of other types.
+    ///
+    /// Operations on constants which don't overflow, like `1 + 2`, additions of `0`,
+    /// multiplications by `0` or `1`, divisions by a constant other than `0` and `-1`
+    /// and shifts by a constant smaller than the bit width are allowed, as well as
+    /// floating-point operations and the ones of `Wrapping` and `String`.
+    ///
+    /// **Why is this bad?** Overflows panic in debug builds and wrap in release
+    /// builds, and a division by zero always panics. In some applications one wants
+    /// explicitly checked, wrapping or saturating arithmetic.
+    ///
+    /// **Known problems:** The operators of other types are assumed to possibly panic.
+    /// The types whose operators never do can be listed in the
+    /// `arithmetic-side-effects-allowed` configuration value.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let n = 1u32;
+    /// let x = n + 1;
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let n = 1u32;
+    /// let x = n.checked_add(1);
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub ARITHMETIC_SIDE_EFFECTS,
+    restriction,
+    "any arithmetic operation which can overflow or panic"
+}
+
+/// The types whose operators are known to never panic
+const KNOWN_TYPES: [&[&str]; 2] = [&paths::STRING, &paths::WRAPPING];
+
+pub struct ArithmeticSideEffects {
+    allowed: Vec<String>,
+    span: Option<Span>,
+}
+
+impl ArithmeticSideEffects {
+    pub fn new(allowed: Vec<String>) -> Self {
+        Self { allowed, span: None }
+    }
+
+    fn is_allowed(&self, cx: &LateContext<'_, '_>, ty: Ty<'_>) -> bool {
+        match ty.sty {
+            ty::Float(_) => true,
+            ty::Adt(def, _) => {
+                KNOWN_TYPES.iter().any(|path| match_def_path(cx.tcx, def.did, path))
+                    || self
+                        .allowed
+                        .iter()
+                        .any(|path| match_def_path(cx.tcx, def.did, &path.split("::").collect::<Vec<_>>()))
+            },
+            _ => false,
+        }
+    }
+
+    fn check_binary(&self, cx: &LateContext<'_, '_>, op: BinOpKind, l: &Expr, r: &Expr) -> bool {
+        match op {
+            BinOpKind::Add
+            | BinOpKind::Sub
+            | BinOpKind::Mul
+            | BinOpKind::Div
+            | BinOpKind::Rem
+            | BinOpKind::Shl
+            | BinOpKind::Shr => (),
+            _ => return false,
+        }
+        let ty = walk_ptrs_ty(cx.tables.expr_ty(l));
+        match ty.sty {
+            ty::Int(_) | ty::Uint(_) => !is_in_range(cx, ty, op, l, r),
+            _ => !self.is_allowed(cx, ty),
+        }
+    }
+
+    fn check_neg(&self, cx: &LateContext<'_, '_>, arg: &Expr) -> bool {
+        let ty = walk_ptrs_ty(cx.tables.expr_ty(arg));
+        match ty.sty {
+            ty::Int(ity) => int_value(cx, arg).map_or(true, |n| {
+                let n = sext(cx.tcx, n, ity);
+                n.checked_neg().map_or(true, |neg| !fits_int(cx, ity, neg))
+            }),
+            ty::Uint(_) => true,
+            _ => !self.is_allowed(cx, ty),
+        }
+    }
+}
+
+impl LintPass for ArithmeticSideEffects {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(ARITHMETIC_SIDE_EFFECTS)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for ArithmeticSideEffects {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if self.span.is_some() || in_constant(cx, expr.id) {
+            return;
+        }
+        let side_effects = match expr.node {
+            ExprKind::Binary(op, ref l, ref r) | ExprKind::AssignOp(op, ref l, ref r) => {
+                self.check_binary(cx, op.node, l, r)
+            },
+            ExprKind::Unary(UnNeg, ref arg) => self.check_neg(cx, arg),
+            _ => false,
+        };
+        if side_effects {
+            span_lint(
+                cx,
+                ARITHMETIC_SIDE_EFFECTS,
+                expr.span,
+                "arithmetic operation that can potentially result in unexpected side-effects",
+            );
+            self.span = Some(expr.span);
+        }
+    }
+
+    fn check_expr_post(&mut self, _: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if Some(expr.span) == self.span {
+            self.span = None;
+        }
+    }
+}
+
+fn int_value(cx: &LateContext<'_, '_>, expr: &Expr) -> Option<u128> {
+    match constant_simple(cx, cx.tables, expr) {
+        Some(Constant::Int(n)) => Some(n),
+        _ => None,
+    }
+}
+
+fn fits_int(cx: &LateContext<'_, '_>, ity: ast::IntTy, n: i128) -> bool {
+    sext(cx.tcx, unsext(cx.tcx, n, ity), ity) == n
+}
+
+/// Checks whether the integer operation `l op r` of type `ty` can't overflow nor panic
+fn is_in_range(cx: &LateContext<'_, '_>, ty: Ty<'_>, op: BinOpKind, l: &Expr, r: &Expr) -> bool {
+    let (l, r) = (int_value(cx, l), int_value(cx, r));
+    let bits = match ty.sty {
+        ty::Int(ity) => u128::from(int_bits(cx.tcx, ity)),
+        ty::Uint(uty) => u128::from(uint_bits(cx.tcx, uty)),
+        _ => return false,
+    };
+    // the right operand of a shift has its own type
+    if op == BinOpKind::Shl || op == BinOpKind::Shr {
+        return r.map_or(false, |r| r < bits);
+    }
+
+    match ty.sty {
+        ty::Int(ity) => {
+            let (l, r) = (l.map(|l| sext(cx.tcx, l, ity)), r.map(|r| sext(cx.tcx, r, ity)));
+            if let (Some(l), Some(r)) = (l, r) {
+                let result = match op {
+                    BinOpKind::Add => l.checked_add(r),
+                    BinOpKind::Sub => l.checked_sub(r),
+                    BinOpKind::Mul => l.checked_mul(r),
+                    BinOpKind::Div => l.checked_div(r),
+                    BinOpKind::Rem => l.checked_rem(r),
+                    _ => None,
+                };
+                return result.map_or(false, |result| fits_int(cx, ity, result));
+            }
+            match (op, l, r) {
+                (BinOpKind::Add, Some(0), _)
+                | (BinOpKind::Add, _, Some(0))
+                | (BinOpKind::Sub, _, Some(0))
+                | (BinOpKind::Mul, Some(0), _)
+                | (BinOpKind::Mul, _, Some(0))
+                | (BinOpKind::Mul, Some(1), _)
+                | (BinOpKind::Mul, _, Some(1)) => true,
+                // `MIN / -1` overflows
+                (BinOpKind::Div, _, Some(r)) | (BinOpKind::Rem, _, Some(r)) => r != 0 && r != -1,
+                _ => false,
+            }
+        },
+        ty::Uint(uty) => {
+            if let (Some(l), Some(r)) = (l, r) {
+                let result = match op {
+                    BinOpKind::Add => l.checked_add(r),
+                    BinOpKind::Sub => l.checked_sub(r),
+                    BinOpKind::Mul => l.checked_mul(r),
+                    BinOpKind::Div => l.checked_div(r),
+                    BinOpKind::Rem => l.checked_rem(r),
+                    _ => None,
+                };
+                return result.map_or(false, |result| clip(cx.tcx, result, uty) == result);
+            }
+            match (op, l, r) {
+                (BinOpKind::Add, Some(0), _)
+                | (BinOpKind::Add, _, Some(0))
+                | (BinOpKind::Sub, _, Some(0))
+                | (BinOpKind::Mul, Some(0), _)
+                | (BinOpKind::Mul, _, Some(0))
+                | (BinOpKind::Mul, Some(1), _)
+                | (BinOpKind::Mul, _, Some(1)) => true,
+                (BinOpKind::Div, _, Some(r)) | (BinOpKind::Rem, _, Some(r)) => r != 0,
+                _ => false,
+            }
+        },
+        _ => false,
+    }
+}
@@ -0,0 +1,113 @@
+use crate::consts::{constant, Constant};
+use crate::utils::sugg::Sugg;
+use crate::utils::{get_parent_expr, in_constant, in_macro, remove_blocks, span_lint_and_then};
+use if_chain::if_chain;
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::ty;
+use rustc::{declare_lint, lint_array};
+use rustc_errors::Applicability;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `if` expressions returning `1` when the condition
+    /// holds and `0` otherwise, or the reverse.
+    ///
+    /// **Why is this bad?** `From<bool>` is implemented for the integer types and does
+    /// the same conversion, without a branch.
+    ///
+    /// **Known problems:** Some consider the `if` more readable, as it spells out the
+    /// values.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let condition = false;
+    /// let n: i32 = if condition { 1 } else { 0 };
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let condition = false;
+    /// let n = i32::from(condition);
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub BOOL_TO_INT_WITH_IF,
+    style,
+    "using `if` to convert a `bool` to an integer"
+}
+
+#[derive(Copy, Clone)]
+pub struct BoolToIntWithIf;
+
+impl LintPass for BoolToIntWithIf {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(BOOL_TO_INT_WITH_IF)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for BoolToIntWithIf {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if_chain! {
+            if let ExprKind::If(ref cond, ref then, Some(ref els)) = expr.node;
+            if !in_macro(expr.span) && !in_constant(cx, expr.id);
+            // the `else if` branches of a chain can't be replaced on their own
+            if !is_else_if(cx, expr);
+            if let ExprKind::Block(..) = els.node;
+            if match cx.tables.expr_ty(expr).sty {
+                ty::Int(_) | ty::Uint(_) => true,
+                _ => false,
+            };
+            if let Some(negated) = match (int_value(cx, then), int_value(cx, els)) {
+                (Some(1), Some(0)) => Some(false),
+                (Some(0), Some(1)) => Some(true),
+                _ => None,
+            };
+            then {
+                let ty = cx.tables.expr_ty(expr);
+                let cond = match cond.node {
+                    ExprKind::Unary(UnNot, ref inner) if negated => Sugg::hir(cx, inner, ".."),
+                    _ if negated => !Sugg::hir(cx, cond, ".."),
+                    _ => Sugg::hir(cx, cond, ".."),
+                };
+                span_lint_and_then(
+                    cx,
+                    BOOL_TO_INT_WITH_IF,
+                    expr.span,
+                    "boolean to int conversion using if",
+                    |db| {
+                        db.span_suggestion_with_applicability(
+                            expr.span,
+                            "replace with from",
+                            format!("{}::from({})", ty, cond),
+                            Applicability::MachineApplicable,
+                        );
+                        db.note(&format!(
+                            "`{}::from(bool)` gives the same values, but the `if` may read more clearly",
+                            ty
+                        ));
+                    },
+                );
+            }
+        }
+    }
+}
+
+fn is_else_if(cx: &LateContext<'_, '_>, expr: &Expr) -> bool {
+    if let Some(parent) = get_parent_expr(cx, expr) {
+        if let ExprKind::If(_, _, Some(ref els)) = parent.node {
+            return els.id == expr.id;
+        }
+    }
+    false
+}
+
+/// Returns the value of a branch made only of an integer literal
+fn int_value(cx: &LateContext<'_, '_>, branch: &Expr) -> Option<u128> {
+    let value = remove_blocks(branch);
+    match value.node {
+        ExprKind::Lit(_) if !in_macro(value.span) => (),
+        _ => return None,
+    }
+    match constant(cx, cx.tables, value) {
+        Some((Constant::Int(n), _)) => Some(n),
+        _ => None,
+    }
+}
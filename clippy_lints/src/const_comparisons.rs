@@ -0,0 +1,240 @@
+use crate::consts::{constant, Constant};
+use crate::utils::comparisons::{normalize_comparison, Rel};
+use crate::utils::{get_parent_expr, in_macro, int_bits, sext, snippet, span_lint_and_then, span_note_and_lint, uint_bits,
+                   SpanlessEq};
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::ty::{self, Ty};
+use rustc::{declare_lint, lint_array};
+use syntax::ast::UintTy;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `&&` chains comparing the same expression to
+    /// constants, which can never be true, like `x < 2 && x > 5`.
+    ///
+    /// **Why is this bad?** The condition is always false, it's probably a mistake,
+    /// e.g. an `&&` instead of an `||`.
+    ///
+    /// **Known problems:** Only integers are checked. The comparisons which are
+    /// always true or false on their own are linted by `absurd_extreme_comparisons`.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let status_code = 200;
+    /// if status_code <= 400 && status_code > 500 {}
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub IMPOSSIBLE_COMPARISONS,
+    correctness,
+    "double comparisons of the same value to constants that will never evaluate to `true`"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `&&` chains comparing the same expression to
+    /// constants, where one comparison is implied by another, like `x > 5 && x > 3`.
+    ///
+    /// **Why is this bad?** The implied comparison has no effect, the bound is
+    /// probably not the intended one.
+    ///
+    /// **Known problems:** Only integers are checked.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let status_code = 200;
+    /// if status_code <= 400 && status_code < 500 {}
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub REDUNDANT_COMPARISONS,
+    correctness,
+    "double comparisons of the same value to constants where one of them has no effect"
+}
+
+#[derive(Copy, Clone)]
+pub struct ConstComparisons;
+
+impl LintPass for ConstComparisons {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(IMPOSSIBLE_COMPARISONS, REDUNDANT_COMPARISONS)
+    }
+}
+
+/// The values a comparison of an expression to a constant holds for
+#[derive(Copy, Clone)]
+enum Cond {
+    /// The inclusive range `lo..=hi`
+    Range(i128, i128),
+    /// Any value but this one
+    Ne(i128),
+}
+
+struct Comparison<'a> {
+    expr: &'a Expr,
+    /// The expression compared to the constant
+    value: &'a Expr,
+    cond: Cond,
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for ConstComparisons {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if !is_and(expr) || in_macro(expr.span) || get_parent_expr(cx, expr).map_or(false, is_and) {
+            return;
+        }
+        let mut operands = Vec::new();
+        and_operands(expr, &mut operands);
+        let comparisons: Vec<_> = operands.into_iter().filter_map(|op| comparison(cx, op)).collect();
+
+        // an impossible chain is linted rather than its redundant comparisons
+        for &impossible in &[true, false] {
+            for (i, first) in comparisons.iter().enumerate() {
+                for second in &comparisons[i + 1..] {
+                    if SpanlessEq::new(cx).ignore_fn().eq_expr(first.value, second.value)
+                        && check_pair(cx, expr, first, second, impossible)
+                    {
+                        return;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Lints the comparisons `first && second` of the same value, if the chain is impossible or, if
+/// `impossible` is false, if one of them is redundant. Returns `true` if linted.
+fn check_pair(
+    cx: &LateContext<'_, '_>,
+    expr: &Expr,
+    first: &Comparison<'_>,
+    second: &Comparison<'_>,
+    impossible: bool,
+) -> bool {
+    let (range, other) = match (first.cond, second.cond) {
+        (Cond::Range(..), _) => (first, second),
+        (_, Cond::Range(..)) => (second, first),
+        (Cond::Ne(_), Cond::Ne(_)) => return false,
+    };
+    let (lo1, hi1) = match range.cond {
+        Cond::Range(lo, hi) => (lo, hi),
+        Cond::Ne(_) => unreachable!(),
+    };
+    match other.cond {
+        Cond::Range(lo2, hi2) => {
+            if lo1.max(lo2) > hi1.min(hi2) {
+                lint_impossible(cx, expr, first, second);
+            } else if impossible {
+                return false;
+            } else if lo2 <= lo1 && hi1 <= hi2 {
+                lint_redundant(cx, other, range);
+            } else if lo1 <= lo2 && hi2 <= hi1 {
+                lint_redundant(cx, range, other);
+            } else {
+                return false;
+            }
+        },
+        Cond::Ne(n) => {
+            if lo1 == n && hi1 == n {
+                lint_impossible(cx, expr, first, second);
+            } else if !impossible && (n < lo1 || hi1 < n) {
+                lint_redundant(cx, other, range);
+            } else {
+                return false;
+            }
+        },
+    }
+    true
+}
+
+fn is_and(expr: &Expr) -> bool {
+    match expr.node {
+        ExprKind::Binary(op, _, _) => op.node == BinOpKind::And,
+        _ => false,
+    }
+}
+
+fn and_operands<'a>(expr: &'a Expr, operands: &mut Vec<&'a Expr>) {
+    match expr.node {
+        ExprKind::Binary(op, ref left, ref right) if op.node == BinOpKind::And => {
+            and_operands(left, operands);
+            and_operands(right, operands);
+        },
+        _ => operands.push(expr),
+    }
+}
+
+/// Returns the comparison of an integer expression to a constant, if it isn't always true or
+/// false on its own
+fn comparison<'a>(cx: &LateContext<'_, '_>, expr: &'a Expr) -> Option<Comparison<'a>> {
+    let (op, left, right) = match expr.node {
+        ExprKind::Binary(op, ref left, ref right) if !in_macro(expr.span) => (op.node, &**left, &**right),
+        _ => return None,
+    };
+    let (rel, lhs, rhs) = normalize_comparison(op, left, right)?;
+    let ty = cx.tables.expr_ty(lhs);
+    let (min, max) = bounds(cx, ty)?;
+    let (value, cst, value_is_lhs) = match (int_value(cx, ty, lhs), int_value(cx, ty, rhs)) {
+        (None, Some(cst)) => (lhs, cst, true),
+        (Some(cst), None) => (rhs, cst, false),
+        _ => return None,
+    };
+    let cond = match (rel, value_is_lhs) {
+        (Rel::Lt, true) => Cond::Range(min, cst.checked_sub(1)?),
+        (Rel::Le, true) => Cond::Range(min, cst),
+        (Rel::Lt, false) => Cond::Range(cst.checked_add(1)?, max),
+        (Rel::Le, false) => Cond::Range(cst, max),
+        (Rel::Eq, _) => Cond::Range(cst, cst),
+        (Rel::Ne, _) => Cond::Ne(cst),
+    };
+    match cond {
+        Cond::Range(lo, hi) if lo > hi || (lo <= min && max <= hi) => None,
+        Cond::Ne(n) if n < min || max < n => None,
+        _ => Some(Comparison { expr, value, cond }),
+    }
+}
+
+/// Returns the range of values of an integer type, if it fits in an `i128`
+fn bounds(cx: &LateContext<'_, '_>, ty: Ty<'_>) -> Option<(i128, i128)> {
+    match ty.sty {
+        ty::Int(ity) => {
+            let bits = int_bits(cx.tcx, ity);
+            let max = (1i128 << (bits - 1)).wrapping_sub(1);
+            Some((-max - 1, max))
+        },
+        ty::Uint(UintTy::U128) => None,
+        ty::Uint(uty) => Some((0, (1i128 << uint_bits(cx.tcx, uty)) - 1)),
+        _ => None,
+    }
+}
+
+fn int_value(cx: &LateContext<'_, '_>, ty: Ty<'_>, expr: &Expr) -> Option<i128> {
+    match (constant(cx, cx.tables, expr)?.0, &ty.sty) {
+        (Constant::Int(n), ty::Int(ity)) => Some(sext(cx.tcx, n, *ity)),
+        (Constant::Int(n), ty::Uint(_)) => Some(n as i128),
+        _ => None,
+    }
+}
+
+fn lint_impossible(cx: &LateContext<'_, '_>, expr: &Expr, first: &Comparison<'_>, second: &Comparison<'_>) {
+    span_lint_and_then(
+        cx,
+        IMPOSSIBLE_COMPARISONS,
+        expr.span,
+        "boolean expression will never evaluate to `true`",
+        |db| {
+            db.note(&format!(
+                "`{}` and `{}` can't both be true",
+                snippet(cx, first.expr.span, ".."),
+                snippet(cx, second.expr.span, "..")
+            ));
+        },
+    );
+}
+
+fn lint_redundant(cx: &LateContext<'_, '_>, redundant: &Comparison<'_>, implying: &Comparison<'_>) {
+    span_note_and_lint(
+        cx,
+        REDUNDANT_COMPARISONS,
+        redundant.expr.span,
+        "this comparison is redundant",
+        implying.expr.span,
+        "it's implied by this comparison",
+    );
+}
@@ -1,31 +1,44 @@
 use syntax::ast::*;
-use rustc::lint::{EarlyContext, EarlyLintPass, LintArray, LintPass};
+use rustc::lint::{EarlyContext, EarlyLintPass, LintArray, LintContext, LintPass};
 use rustc::{declare_lint, lint_array};
 use crate::utils::{in_macro, snippet, span_lint_and_then};
+use crate::utils::msrvs::{self, MsrvStack, RustcVersion};
 
-/// **What it does:** Checks for constants with an explicit `'static` lifetime.
-///
-/// **Why is this bad?** Adding `'static` to every reference can create very
-/// complicated types.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// const FOO: &'static [(&'static str, &'static str, fn(&Bar) -> bool)] =
-/// &[...]
-/// ```
-/// This code can be rewritten as
-/// ```rust
-///  const FOO: &[(&str, &str, fn(&Bar) -> bool)] = &[...]
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for constants with an explicit `'static` lifetime.
+    ///
+    /// **Why is this bad?** Adding `'static` to every reference can create very
+    /// complicated types.
+    ///
+    /// **Known problems:** The elision needs Rust 1.17, the lint is disabled if the `msrv` is
+    /// older.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// const FOO: &'static [(&'static str, &'static str, fn(&Bar) -> bool)] =
+    /// &[...]
+    /// ```
+    /// This code can be rewritten as
+    /// ```rust
+    ///  const FOO: &[(&str, &str, fn(&Bar) -> bool)] = &[...]
+    /// ```
+    #[clippy::version = "0.0.167"]
     pub CONST_STATIC_LIFETIME,
     style,
     "Using explicit `'static` lifetime for constants when elision rules would allow omitting them."
 }
 
-pub struct StaticConst;
+pub struct StaticConst {
+    msrv: MsrvStack,
+}
+
+impl StaticConst {
+    pub fn new(msrv: Option<RustcVersion>) -> Self {
+        Self {
+            msrv: MsrvStack::new(msrv),
+        }
+    }
+}
 
 impl LintPass for StaticConst {
     fn get_lints(&self) -> LintArray {
@@ -79,8 +92,16 @@ impl StaticConst {
 }
 
 impl EarlyLintPass for StaticConst {
+    fn enter_lint_attrs(&mut self, cx: &EarlyContext<'_>, attrs: &[Attribute]) {
+        self.msrv.enter_lint_attrs(cx.sess(), attrs);
+    }
+
+    fn exit_lint_attrs(&mut self, _: &EarlyContext<'_>, attrs: &[Attribute]) {
+        self.msrv.exit_lint_attrs(attrs);
+    }
+
     fn check_item(&mut self, cx: &EarlyContext<'_>, item: &Item) {
-        if !in_macro(item.span) {
+        if !in_macro(item.span) && self.msrv.meets(msrvs::STATIC_IN_CONST) {
             // Match only constants...
             if let ItemKind::Const(ref var_type, _) = item.node {
                 self.visit_type(var_type, cx);
@@ -8,10 +8,13 @@ use rustc::hir::*;
 use rustc::ty::{self, Ty, TyCtxt, Instance};
 use rustc::ty::subst::{Subst, Substs};
 use std::cmp::Ordering::{self, Equal};
+use std::cell::RefCell;
 use std::cmp::PartialOrd;
+use std::env;
 use std::hash::{Hash, Hasher};
 use std::mem;
 use std::rc::Rc;
+use rustc_data_structures::fx::FxHashMap;
 use syntax::ast::{FloatTy, LitKind};
 use syntax::ptr::P;
 use crate::utils::{sext, unsext, clip};
@@ -200,6 +203,55 @@ pub fn constant_context<'c, 'cc>(lcx: &LateContext<'c, 'cc>, tables: &'c ty::Typ
     }
 }
 
+/// Several lints evaluate the same expressions (e.g. `identity_op`, `erasing_op` and
+/// `integer_arithmetic` all look at the operands of every binary operation), and the evaluation of
+/// an expression evaluates all its sub-expressions, so the results are cached.
+///
+/// The cache only holds the results for one body at a time: lints look at a body at a time, and
+/// `HirId`s are only unique together with their tables.
+#[derive(Default)]
+struct ConstCache {
+    /// The address of the `TypeckTables` the cached results belong to.
+    tables: usize,
+    /// The result of the evaluation of each expression, and whether it needed to resolve a
+    /// `const` item.
+    results: FxHashMap<HirId, (Option<Constant>, bool)>,
+    hits: u64,
+    misses: u64,
+}
+
+impl ConstCache {
+    fn get(&mut self, tables: usize, id: HirId) -> Option<(Option<Constant>, bool)> {
+        if self.tables != tables {
+            self.tables = tables;
+            self.results.clear();
+        }
+        let result = self.results.get(&id).cloned();
+        if result.is_some() {
+            self.hits += 1;
+        } else {
+            self.misses += 1;
+        }
+        result
+    }
+}
+
+impl Drop for ConstCache {
+    /// Set `CLIPPY_CONST_CACHE_STATS` to know how effective the cache is.
+    fn drop(&mut self) {
+        if env::var_os("CLIPPY_CONST_CACHE_STATS").is_some() {
+            eprintln!(
+                "clippy constant evaluation cache: {} hits, {} misses",
+                self.hits, self.misses
+            );
+        }
+    }
+}
+
+thread_local! {
+    static CONST_CACHE: RefCell<ConstCache> = RefCell::new(ConstCache::default());
+}
+
 pub struct ConstEvalLateContext<'a, 'tcx: 'a> {
     tcx: TyCtxt<'a, 'tcx, 'tcx>,
     tables: &'a ty::TypeckTables<'tcx>,
@@ -211,6 +263,30 @@ pub struct ConstEvalLateContext<'a, 'tcx: 'a> {
 impl<'c, 'cc> ConstEvalLateContext<'c, 'cc> {
     /// simple constant folding: Insert an expression, get a constant or none.
     pub fn expr(&mut self, e: &Expr) -> Option<Constant> {
+        // the results with substitutions depend on the substitutions
+        if !self.substs.is_empty() {
+            return self.expr_uncached(e);
+        }
+
+        let tables = self.tables as *const ty::TypeckTables<'_> as usize;
+        if let Some((result, needed_resolution)) = CONST_CACHE.with(|cache| cache.borrow_mut().get(tables, e.hir_id)) {
+            self.needed_resolution |= needed_resolution;
+            return result;
+        }
+
+        let outer_needed_resolution = mem::replace(&mut self.needed_resolution, false);
+        let result = self.expr_uncached(e);
+        CONST_CACHE.with(|cache| {
+            cache
+                .borrow_mut()
+                .results
+                .insert(e.hir_id, (result.clone(), self.needed_resolution));
+        });
+        self.needed_resolution |= outer_needed_resolution;
+        result
+    }
+
+    fn expr_uncached(&mut self, e: &Expr) -> Option<Constant> {
         match e.node {
             ExprKind::Path(ref qpath) => self.fetch_path(qpath, e.hir_id),
             ExprKind::Block(ref block, _) => self.block(block),
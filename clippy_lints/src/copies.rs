@@ -1,102 +1,177 @@
 use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
 use rustc::{declare_lint, lint_array};
-use rustc::ty::Ty;
+use rustc::ty::{self, Ty};
 use rustc::hir::*;
+use rustc::hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
 use std::collections::HashMap;
 use std::collections::hash_map::Entry;
+use syntax::source_map::Span;
 use syntax::symbol::LocalInternedString;
 use rustc_data_structures::small_vec::OneVector;
 use crate::utils::{SpanlessEq, SpanlessHash};
-use crate::utils::{get_parent_expr, in_macro, snippet, span_lint_and_then, span_note_and_lint};
-
-/// **What it does:** Checks for consecutive `if`s with the same condition.
-///
-/// **Why is this bad?** This is probably a copy & paste error.
-///
-/// **Known problems:** Hopefully none.
-///
-/// **Example:**
-/// ```rust
-/// if a == b {
-///     …
-/// } else if a == b {
-///     …
-/// }
-/// ```
-///
-/// Note that this lint ignores all conditions with a function call as it could
-/// have side effects:
-///
-/// ```rust
-/// if foo() {
-///     …
-/// } else if foo() { // not linted
-///     …
-/// }
-/// ```
+use crate::utils::{get_enclosing_block, get_parent_expr, in_macro, snippet, span_lint_and_then, span_note_and_lint};
+
 declare_clippy_lint! {
+    /// **What it does:** Checks for consecutive `if`s with the same condition.
+    ///
+    /// **Why is this bad?** This is probably a copy & paste error.
+    ///
+    /// **Known problems:** Hopefully none.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// if a == b {
+    ///     …
+    /// } else if a == b {
+    ///     …
+    /// }
+    /// ```
+    ///
+    /// Note that this lint ignores all conditions with a function call as it could
+    /// have side effects:
+    ///
+    /// ```rust
+    /// if foo() {
+    ///     …
+    /// } else if foo() { // not linted
+    ///     …
+    /// }
+    /// ```
+    #[clippy::version = "pre 0.0.213"]
     pub IFS_SAME_COND,
     correctness,
     "consecutive `ifs` with the same condition"
 }
 
-/// **What it does:** Checks for `if/else` with the same body as the *then* part
-/// and the *else* part.
-///
-/// **Why is this bad?** This is probably a copy & paste error.
-///
-/// **Known problems:** Hopefully none.
-///
-/// **Example:**
-/// ```rust
-/// let foo = if … {
-///     42
-/// } else {
-///     42
-/// };
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for consecutive `if`s with the same function call.
+    ///
+    /// **Why is this bad?** This is probably a copy & paste error. The conditions calling
+    /// a function with a mutable reference, e.g. `v.pop()`, are not linted: the calls may
+    /// return different values.
+    ///
+    /// **Known problems:** The functions with side effects through a shared reference or
+    /// a global state, e.g. `Cell::replace` or `rand::random`, are linted too.
+    ///
+    /// **Example:**
+    /// ```ignore
+    /// if foo() == bar {
+    ///     …
+    /// } else if foo() == bar {
+    ///     …
+    /// }
+    /// ```
+    ///
+    /// This probably should be:
+    /// ```ignore
+    /// if foo() == bar {
+    ///     …
+    /// } else if foo() == baz {
+    ///     …
+    /// }
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub SAME_FUNCTIONS_IN_IF_CONDITION,
+    correctness,
+    "consecutive `ifs` with the same function call"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `if/else` with the same body as the *then* part
+    /// and the *else* part.
+    ///
+    /// **Why is this bad?** This is probably a copy & paste error.
+    ///
+    /// **Known problems:** Hopefully none.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let foo = if … {
+    ///     42
+    /// } else {
+    ///     42
+    /// };
+    /// ```
+    #[clippy::version = "pre 0.0.213"]
     pub IF_SAME_THEN_ELSE,
     correctness,
     "if with the same *then* and *else* blocks"
 }
 
-/// **What it does:** Checks for `match` with identical arm bodies.
-///
-/// **Why is this bad?** This is probably a copy & paste error. If arm bodies
-/// are the same on purpose, you can factor them
-/// [using `|`](https://doc.rust-lang.org/book/patterns.html#multiple-patterns).
-///
-/// **Known problems:** False positive possible with order dependent `match`
-/// (see issue
-/// [#860](https://github.com/rust-lang-nursery/rust-clippy/issues/860)).
-///
-/// **Example:**
-/// ```rust,ignore
-/// match foo {
-///     Bar => bar(),
-///     Quz => quz(),
-///     Baz => bar(), // <= oops
-/// }
-/// ```
-///
-/// This should probably be
-/// ```rust,ignore
-/// match foo {
-///     Bar => bar(),
-///     Quz => quz(),
-///     Baz => baz(), // <= fixed
-/// }
-/// ```
-///
-/// or if the original code was not a typo:
-/// ```rust,ignore
-/// match foo {
-///     Bar | Baz => bar(), // <= shows the intent better
-///     Quz => quz(),
-/// }
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks if the `if` and `else` blocks contain shared code that can be
+    /// moved out of the blocks.
+    ///
+    /// **Why is this bad?** Duplicate code is less maintainable.
+    ///
+    /// **Known problems:** The statements moved before the `if` are executed before the
+    /// conditions of the `else if` blocks instead of after them. The code at the start of
+    /// the blocks is not linted if a condition calls a function with a mutable reference,
+    /// e.g. `v.pop()`, but other side effects of the conditions are not detected.
+    ///
+    /// **Example:**
+    /// ```ignore
+    /// let foo = if … {
+    ///     println!("Hello World");
+    ///     13
+    /// } else {
+    ///     println!("Hello World");
+    ///     42
+    /// };
+    /// ```
+    ///
+    /// Could be written as:
+    /// ```ignore
+    /// println!("Hello World");
+    /// let foo = if … {
+    ///     13
+    /// } else {
+    ///     42
+    /// };
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub BRANCHES_SHARING_CODE,
+    complexity,
+    "`if` statement with shared code in all blocks"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `match` with identical arm bodies.
+    ///
+    /// **Why is this bad?** This is probably a copy & paste error. If arm bodies
+    /// are the same on purpose, you can factor them
+    /// [using `|`](https://doc.rust-lang.org/book/patterns.html#multiple-patterns).
+    ///
+    /// **Known problems:** False positive possible with order dependent `match`
+    /// (see issue
+    /// [#860](https://github.com/rust-lang-nursery/rust-clippy/issues/860)).
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// match foo {
+    ///     Bar => bar(),
+    ///     Quz => quz(),
+    ///     Baz => bar(), // <= oops
+    /// }
+    /// ```
+    ///
+    /// This should probably be
+    /// ```rust,ignore
+    /// match foo {
+    ///     Bar => bar(),
+    ///     Quz => quz(),
+    ///     Baz => baz(), // <= fixed
+    /// }
+    /// ```
+    ///
+    /// or if the original code was not a typo:
+    /// ```rust,ignore
+    /// match foo {
+    ///     Bar | Baz => bar(), // <= shows the intent better
+    ///     Quz => quz(),
+    /// }
+    /// ```
+    #[clippy::version = "pre 0.0.213"]
     pub MATCH_SAME_ARMS,
     pedantic,
     "`match` with identical arm bodies"
@@ -107,7 +182,13 @@ pub struct CopyAndPaste;
 
 impl LintPass for CopyAndPaste {
     fn get_lints(&self) -> LintArray {
-        lint_array![IFS_SAME_COND, IF_SAME_THEN_ELSE, MATCH_SAME_ARMS]
+        lint_array![
+            IFS_SAME_COND,
+            SAME_FUNCTIONS_IN_IF_CONDITION,
+            IF_SAME_THEN_ELSE,
+            BRANCHES_SHARING_CODE,
+            MATCH_SAME_ARMS
+        ]
     }
 }
 
@@ -127,7 +208,9 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for CopyAndPaste {
 
             let (conds, blocks) = if_sequence(expr);
             lint_same_then_else(cx, &blocks);
+            lint_shared_code_in_branches(cx, expr, &conds, &blocks);
             lint_same_cond(cx, &conds);
+            lint_same_fns_in_if_cond(cx, &conds);
             lint_match_arms(cx, expr);
         }
     }
@@ -149,6 +232,101 @@ fn lint_same_then_else(cx: &LateContext<'_, '_>, blocks: &[&Block]) {
     }
 }
 
+/// Implementation of `BRANCHES_SHARING_CODE`.
+fn lint_shared_code_in_branches<'a, 'tcx>(
+    cx: &LateContext<'a, 'tcx>,
+    expr: &'tcx Expr,
+    conds: &[&'tcx Expr],
+    blocks: &[&'tcx Block],
+) {
+    // without a final `else`, the blocks are not executed in every case
+    if blocks.len() < 2 || blocks.len() == conds.len() || blocks.iter().any(|block| in_macro(block.span)) {
+        return;
+    }
+    // identical blocks are linted by `IF_SAME_THEN_ELSE`
+    if blocks.windows(2).all(|w| SpanlessEq::new(cx).eq_block(w[0], w[1])) {
+        return;
+    }
+
+    let first = blocks[0];
+    let min_len = blocks.iter().map(|block| block.stmts.len()).min().unwrap_or(0);
+    let eq_stmts = |index: &dyn Fn(&Block) -> usize| {
+        blocks[1..]
+            .iter()
+            .all(|block| SpanlessEq::new(cx).eq_stmt(&first.stmts[index(first)], &block.stmts[index(block)]))
+    };
+
+    // the code reading the bindings moved before or after the `if` must not see them
+    let outside_blocks =
+        |span: Span| span.lo() >= expr.span.lo() && !blocks.iter().any(|block| block.span.contains(span));
+    let scope = get_enclosing_block(cx, expr.id);
+    let shadows = |stmt: &Stmt| {
+        let names = stmt_bindings(cx, stmt);
+        if names.is_empty() {
+            return false;
+        }
+        match scope {
+            Some(scope) => uses_names(cx, &names, &outside_blocks, |v| v.visit_block(scope)),
+            None => conds
+                .iter()
+                .any(|&cond| uses_names(cx, &names, &outside_blocks, |v| v.visit_expr(cond))),
+        }
+    };
+
+    let mut start = (0..min_len).take_while(|&i| eq_stmts(&|_| i)).count();
+    if let Some(i) = first.stmts[..start].iter().position(&shadows) {
+        start = i;
+    }
+    // the statements moved before the `if` would not see what the conditions mutate
+    if conds.iter().any(|&cond| has_mutation(cx, cond)) {
+        start = 0;
+    }
+
+    // the end of a block with a value can't be moved
+    let mut end = 0;
+    if blocks.iter().all(|block| block.expr.is_none()) {
+        end = (0..min_len - start)
+            .take_while(|&i| eq_stmts(&|block| block.stmts.len() - 1 - i))
+            .count();
+        while end > 0
+            && blocks.iter().any(|block| {
+                let (kept, moved) = block.stmts[start..].split_at(block.stmts.len() - start - end);
+                // the moved statements can't use the bindings of the statements kept in the blocks
+                let names: Vec<_> = kept.iter().flat_map(|stmt| stmt_bindings(cx, stmt)).collect();
+                moved
+                    .iter()
+                    .any(|stmt| shadows(stmt) || uses_names(cx, &names, &|_| true, |v| v.visit_stmt(stmt)))
+            })
+        {
+            end -= 1;
+        }
+    }
+
+    if start > 0 {
+        span_lint_and_then(
+            cx,
+            BRANCHES_SHARING_CODE,
+            first.stmts[0].span.to(first.stmts[start - 1].span),
+            "all if blocks contain the same code at the start",
+            |db| {
+                db.help("consider moving these statements before the `if`");
+            },
+        );
+    }
+    if end > 0 {
+        let len = first.stmts.len();
+        span_lint_and_then(
+            cx,
+            BRANCHES_SHARING_CODE,
+            first.stmts[len - end].span.to(first.stmts[len - 1].span),
+            "all if blocks contain the same code at the end",
+            |db| {
+                db.help("consider moving these statements after the `if`");
+            },
+        );
+    }
+}
+
 /// Implementation of `IFS_SAME_COND`.
 fn lint_same_cond(cx: &LateContext<'_, '_>, conds: &[&Expr]) {
     let hash: &dyn Fn(&&Expr) -> u64 = &|expr| -> u64 {
@@ -171,6 +349,99 @@ fn lint_same_cond(cx: &LateContext<'_, '_>, conds: &[&Expr]) {
     }
 }
 
+/// Implementation of `SAME_FUNCTIONS_IN_IF_CONDITION`.
+fn lint_same_fns_in_if_cond<'a, 'tcx>(cx: &'a LateContext<'a, 'tcx>, conds: &[&'tcx Expr]) {
+    let hash: &dyn Fn(&&Expr) -> u64 = &|expr| -> u64 {
+        let mut h = SpanlessHash::new(cx, cx.tables);
+        h.hash_expr(expr);
+        h.finish()
+    };
+
+    let eq: &dyn Fn(&&'tcx Expr, &&'tcx Expr) -> bool = &|&lhs, &rhs| -> bool {
+        // do not lint if any expr originates from a macro
+        if in_macro(lhs.span) || in_macro(rhs.span) {
+            return false;
+        }
+        // do not spawn warning if `IFS_SAME_COND` already produced it
+        if SpanlessEq::new(cx).ignore_fn().eq_expr(lhs, rhs) {
+            return false;
+        }
+        // a mutating call may return a different value the second time
+        SpanlessEq::new(cx).eq_expr(lhs, rhs) && !has_mutation(cx, lhs)
+    };
+
+    if let Some((i, j)) = search_same(conds, hash, eq) {
+        span_note_and_lint(
+            cx,
+            SAME_FUNCTIONS_IN_IF_CONDITION,
+            j.span,
+            "this `if` has the same function call as a previous if",
+            i.span,
+            "same as this",
+        );
+    }
+}
+
+/// Checks if `expr` mutates something, by assigning it or by calling a function with a mutable
+/// reference
+fn has_mutation<'a, 'tcx>(cx: &'a LateContext<'a, 'tcx>, expr: &'tcx Expr) -> bool {
+    let mut visitor = MutationVisitor { cx, found: false };
+    visitor.visit_expr(expr);
+    visitor.found
+}
+
+struct MutationVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    found: bool,
+}
+
+impl<'a, 'tcx> MutationVisitor<'a, 'tcx> {
+    fn takes_mut_ref(&self, sig: ty::PolyFnSig<'_>) -> bool {
+        sig.skip_binder().inputs().iter().any(|ty| match ty.sty {
+            ty::Ref(_, _, MutMutable) => true,
+            _ => false,
+        })
+    }
+
+    fn is_mutation(&self, expr: &Expr) -> bool {
+        match expr.node {
+            ExprKind::AddrOf(MutMutable, _) | ExprKind::Assign(..) | ExprKind::AssignOp(..) => true,
+            ExprKind::MethodCall(..) => self
+                .cx
+                .tables
+                .type_dependent_defs()
+                .get(expr.hir_id)
+                .map_or(false, |def| self.takes_mut_ref(self.cx.tcx.fn_sig(def.def_id()))),
+            ExprKind::Call(ref fun, _) => {
+                let fun_ty = self.cx.tables.expr_ty(fun);
+                match fun_ty.sty {
+                    ty::FnDef(..) | ty::FnPtr(_) => self.takes_mut_ref(fun_ty.fn_sig(self.cx.tcx)),
+                    // closures and the other callable values may capture a mutable state
+                    _ => true,
+                }
+            },
+            _ => false,
+        }
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for MutationVisitor<'a, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        if self.found {
+            return;
+        }
+        if self.is_mutation(expr) {
+            self.found = true;
+            return;
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::None
+    }
+}
+
 /// Implementation of `MATCH_SAME_ARMS`.
 fn lint_match_arms(cx: &LateContext<'_, '_>, expr: &Expr) {
     if let ExprKind::Match(_, ref arms, MatchSource::Normal) = expr.node {
@@ -263,6 +534,65 @@ fn if_sequence(mut expr: &Expr) -> (OneVector<&Expr>, OneVector<&Block>) {
 }
 
 /// Return the list of bindings in a pattern.
+/// Return the names of the bindings declared by a `let` statement.
+fn stmt_bindings(cx: &LateContext<'_, '_>, stmt: &Stmt) -> Vec<LocalInternedString> {
+    if let StmtKind::Decl(ref decl, _) = stmt.node {
+        if let DeclKind::Local(ref local) = decl.node {
+            return bindings(cx, &local.pat).into_iter().map(|(name, _)| name).collect();
+        }
+    }
+    Vec::new()
+}
+
+/// Check whether a path of one of `names` is visited by `visit` in a span accepted by `filter`.
+fn uses_names<'a, 'tcx, V>(
+    cx: &LateContext<'a, 'tcx>,
+    names: &[LocalInternedString],
+    filter: &dyn Fn(Span) -> bool,
+    visit: V,
+) -> bool
+where
+    V: FnOnce(&mut NameUsageVisitor<'_, 'a, 'tcx>),
+{
+    let mut visitor = NameUsageVisitor {
+        cx,
+        names,
+        filter,
+        used: false,
+    };
+    visit(&mut visitor);
+    visitor.used
+}
+
+struct NameUsageVisitor<'v, 'a: 'v, 'tcx: 'a> {
+    cx: &'v LateContext<'a, 'tcx>,
+    names: &'v [LocalInternedString],
+    filter: &'v dyn Fn(Span) -> bool,
+    used: bool,
+}
+
+impl<'v, 'a, 'tcx> Visitor<'tcx> for NameUsageVisitor<'v, 'a, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        if self.used {
+            return;
+        }
+        if let ExprKind::Path(QPath::Resolved(None, ref path)) = expr.node {
+            if path.segments.len() == 1
+                && self.names.iter().any(|name| path.segments[0].ident.as_str() == *name)
+                && (self.filter)(expr.span)
+            {
+                self.used = true;
+                return;
+            }
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::OnlyBodies(&self.cx.tcx.hir)
+    }
+}
+
 fn bindings<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, pat: &Pat) -> HashMap<LocalInternedString, Ty<'tcx>> {
     fn bindings_impl<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, pat: &Pat, map: &mut HashMap<LocalInternedString, Ty<'tcx>>) {
         match pat.node {
@@ -0,0 +1,67 @@
+use crate::utils::{match_def_path, opt_def_id, paths, span_lint_and_then};
+use if_chain::if_chain;
+use rustc::hir::{Expr, ExprKind};
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::{declare_lint, lint_array};
+use rustc_errors::Applicability;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks usage of `std::fs::create_dir` and suggests using
+    /// `std::fs::create_dir_all` instead.
+    ///
+    /// **Why is this bad?** `create_dir` fails if the parent directories don't
+    /// exist, which is easy to overlook. `create_dir_all` creates them, and doesn't
+    /// fail if the directory already exists.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust,no_run
+    /// std::fs::create_dir("foo");
+    /// ```
+    /// Use instead:
+    /// ```rust,no_run
+    /// std::fs::create_dir_all("foo");
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub CREATE_DIR,
+    restriction,
+    "calling `std::fs::create_dir` instead of `std::fs::create_dir_all`"
+}
+
+#[derive(Copy, Clone)]
+pub struct CreateDir;
+
+impl LintPass for CreateDir {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(CREATE_DIR)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for CreateDir {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if_chain! {
+            if let ExprKind::Call(ref func, _) = expr.node;
+            if let ExprKind::Path(ref path) = func.node;
+            if let Some(def_id) = opt_def_id(cx.tables.qpath_def(path, func.hir_id));
+            if match_def_path(cx.tcx, def_id, &paths::CREATE_DIR);
+            then {
+                span_lint_and_then(
+                    cx,
+                    CREATE_DIR,
+                    func.span,
+                    "calling `std::fs::create_dir` where there may be a better way",
+                    |db| {
+                        db.span_suggestion_with_applicability(
+                            func.span,
+                            "consider calling `std::fs::create_dir_all` instead",
+                            "std::fs::create_dir_all".to_owned(),
+                            Applicability::MachineApplicable,
+                        );
+                    },
+                );
+            }
+        }
+    }
+}
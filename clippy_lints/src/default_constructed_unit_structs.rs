@@ -0,0 +1,116 @@
+use crate::utils::{get_trait_def_id, in_macro, is_automatically_derived, match_def_path, opt_def_id, paths,
+                   span_lint_and_then};
+use if_chain::if_chain;
+use rustc::hir::def::{CtorKind, Def};
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::ty;
+use rustc::{declare_lint, lint_array};
+use rustc_errors::Applicability;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for the construction of a unit struct using `default()`.
+    ///
+    /// **Why is this bad?** This adds code complexity and an unnecessary function call.
+    ///
+    /// **Known problems:** Only the `Default` implementations that are derived, and the one of
+    /// `PhantomData`, are known to return the unit struct.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # use std::marker::PhantomData;
+    /// #[derive(Default)]
+    /// struct S<T> {
+    ///     _marker: PhantomData<T>
+    /// }
+    ///
+    /// let _: S<i32> = S {
+    ///     _marker: PhantomData::default()
+    /// };
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::marker::PhantomData;
+    /// struct S<T> {
+    ///     _marker: PhantomData<T>
+    /// }
+    ///
+    /// let _: S<i32> = S {
+    ///     _marker: PhantomData
+    /// };
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub DEFAULT_CONSTRUCTED_UNIT_STRUCTS,
+    complexity,
+    "unit structs can be constructed without calling `default`"
+}
+
+#[derive(Copy, Clone)]
+pub struct DefaultConstructedUnitStructs;
+
+impl LintPass for DefaultConstructedUnitStructs {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(DEFAULT_CONSTRUCTED_UNIT_STRUCTS)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for DefaultConstructedUnitStructs {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if_chain! {
+            if !in_macro(expr.span);
+            if let ExprKind::Call(ref fn_expr, ref args) = expr.node;
+            if args.is_empty();
+            // only `Type::default()`, a generic `Default::default()` can't be replaced
+            if let ExprKind::Path(ref qpath) = fn_expr.node;
+            if let QPath::TypeRelative(ref base, _) = *qpath;
+            if is_struct_path(base);
+            if let Some(def_id) = opt_def_id(cx.tables.qpath_def(qpath, fn_expr.hir_id));
+            if match_def_path(cx.tcx, def_id, &paths::DEFAULT_TRAIT_METHOD);
+            let ty = cx.tables.expr_ty(expr);
+            if let ty::TyKind::Adt(def, _) = ty.sty;
+            if def.is_struct();
+            let variant = def.non_enum_variant();
+            if variant.fields.is_empty() && variant.ctor_kind == CtorKind::Const;
+            if match_def_path(cx.tcx, def.did, &paths::PHANTOM_DATA) || has_derived_default(cx, ty);
+            then {
+                let span = expr.span.with_lo(base.span.hi());
+                span_lint_and_then(
+                    cx,
+                    DEFAULT_CONSTRUCTED_UNIT_STRUCTS,
+                    span,
+                    "use of `default` to create a unit struct",
+                    |db| {
+                        db.span_suggestion_with_applicability(
+                            span,
+                            "remove this call to `default`",
+                            String::new(),
+                            Applicability::MachineApplicable,
+                        );
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// Only the path of the struct itself can be used as its value, not `Self` or a type alias
+fn is_struct_path(ty: &Ty) -> bool {
+    if let TyKind::Path(QPath::Resolved(None, ref path)) = ty.node {
+        if let Def::Struct(..) = path.def {
+            return true;
+        }
+    }
+    false
+}
+
+fn has_derived_default<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, ty: ty::Ty<'tcx>) -> bool {
+    let default_trait_id = match get_trait_def_id(cx, &paths::DEFAULT_TRAIT) {
+        Some(id) => id,
+        None => return false,
+    };
+    let mut derived = false;
+    cx.tcx.for_each_relevant_impl(default_trait_id, ty, |impl_id| {
+        derived |= is_automatically_derived(&cx.tcx.get_attrs(impl_id));
+    });
+    derived
+}
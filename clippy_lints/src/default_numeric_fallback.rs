@@ -0,0 +1,252 @@
+use crate::utils::{in_macro, snippet, span_lint_and_sugg};
+use if_chain::if_chain;
+use rustc::hir::def::Def;
+use rustc::hir::def_id::DefId;
+use rustc::hir::intravisit::{walk_expr, walk_stmt, NestedVisitorMap, Visitor};
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::ty::{self, Ty};
+use rustc::{declare_lint, lint_array};
+use syntax::ast::{FloatTy, IntTy, Lit, LitIntType, LitKind};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for usage of unconstrained numeric literals which
+    /// may cause default numeric fallback in type inference.
+    ///
+    /// Default numeric fallback means that if numeric types have not yet been
+    /// bound to concrete types at the end of type inference, then integer types
+    /// fall back to `i32` and float types fall back to `f64`.
+    ///
+    /// **Why is this bad?** For those who are very careful about the types, the
+    /// fallback can hide that a type was never decided, e.g. a literal meant to
+    /// be a `u64` silently overflowing as an `i32`.
+    ///
+    /// **Known problems:** This lint can only tell that the type of a literal is
+    /// explicit from its syntactic context: the argument of a non-generic
+    /// function, an annotated `let`, a binary operation with a typed operand, etc.
+    /// A literal whose type is inferred from a later use, e.g. through a generic
+    /// method like `Vec::push`, is linted.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let i = 10;
+    /// let f = 1.23;
+    /// ```
+    ///
+    /// Use instead:
+    /// ```rust
+    /// let i = 10_i32;
+    /// let f = 1.23_f64;
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub DEFAULT_NUMERIC_FALLBACK,
+    restriction,
+    "usage of unconstrained numeric literals which may cause default numeric fallback"
+}
+
+#[derive(Copy, Clone)]
+pub struct DefaultNumericFallback;
+
+impl LintPass for DefaultNumericFallback {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(DEFAULT_NUMERIC_FALLBACK)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for DefaultNumericFallback {
+    fn check_body(&mut self, cx: &LateContext<'a, 'tcx>, body: &'tcx Body) {
+        // the value of a function, constant or static has the declared type, not the one of a
+        // closure
+        let owner = cx.tcx.hir.body_owner(body.id());
+        let is_closure = match cx.tcx.hir.find(owner) {
+            Some(Node::Expr(_)) => true,
+            _ => false,
+        };
+
+        let mut visitor = NumericFallbackVisitor {
+            cx,
+            ty_bounds: vec![!is_closure],
+        };
+        visitor.visit_expr(&body.value);
+    }
+}
+
+struct NumericFallbackVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    /// Whether the type of the expression being visited is explicitly given by its context
+    ty_bounds: Vec<bool>,
+}
+
+impl<'a, 'tcx> NumericFallbackVisitor<'a, 'tcx> {
+    fn visit_bound(&mut self, bound: bool, expr: &'tcx Expr) {
+        self.ty_bounds.push(bound);
+        self.visit_expr(expr);
+        self.ty_bounds.pop();
+    }
+
+    fn is_bound(&self) -> bool {
+        *self.ty_bounds.last().expect("there should always be a bound")
+    }
+
+    /// Visits the arguments of a call to `def_id`, bound if the declared type of the parameter is
+    /// not generic. The first `skip` arguments are never bound, e.g. the receiver of a method.
+    fn visit_args(&mut self, def_id: Option<DefId>, args: &'tcx [Expr], skip: usize) {
+        let inputs = def_id.map(|def_id| self.cx.tcx.fn_sig(def_id).skip_binder().inputs());
+        for (i, arg) in args.iter().enumerate() {
+            let bound = i >= skip
+                && inputs
+                    .and_then(|inputs| inputs.get(i))
+                    .map_or(false, |ty| !ty.has_param_types());
+            self.visit_bound(bound, arg);
+        }
+    }
+
+    fn check_lit(&self, expr: &'tcx Expr, lit: &Lit) {
+        if_chain! {
+            if !self.is_bound();
+            if !in_macro(expr.span);
+            if let Some(suffix) = fallback_suffix(&lit.node, self.cx.tables.expr_ty(expr));
+            then {
+                span_lint_and_sugg(
+                    self.cx,
+                    DEFAULT_NUMERIC_FALLBACK,
+                    expr.span,
+                    "default numeric fallback might occur",
+                    "consider adding suffix",
+                    format!("{}_{}", snippet(self.cx, expr.span, ".."), suffix),
+                );
+            }
+        }
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for NumericFallbackVisitor<'a, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        match expr.node {
+            ExprKind::Lit(ref lit) => self.check_lit(expr, lit),
+            ExprKind::Call(ref fun, ref args) => {
+                self.visit_bound(false, fun);
+                let def_id = if let ExprKind::Path(ref qpath) = fun.node {
+                    match self.cx.tables.qpath_def(qpath, fun.hir_id) {
+                        Def::Fn(def_id) | Def::Method(def_id) | Def::StructCtor(def_id, _) |
+                        Def::VariantCtor(def_id, _) => Some(def_id),
+                        _ => None,
+                    }
+                } else {
+                    None
+                };
+                self.visit_args(def_id, args, 0);
+            },
+            ExprKind::MethodCall(_, _, ref args) => {
+                let def_id = self.cx.tables.type_dependent_defs().get(expr.hir_id).map(|def| def.def_id());
+                self.visit_args(def_id, args, 1);
+            },
+            ExprKind::Binary(op, ref left, ref right) => match op.node {
+                // the type of the shift amount is independent of the shifted value
+                BinOpKind::Shl | BinOpKind::Shr => {
+                    let bound = self.is_bound();
+                    self.visit_bound(bound, left);
+                    self.visit_bound(false, right);
+                },
+                BinOpKind::And | BinOpKind::Or => walk_expr(self, expr),
+                _ => {
+                    // both sides have the same type, decided by the operand that is not a literal,
+                    // or by the context if the result has the same type
+                    let bound = self.is_bound() && !is_comparison(op.node);
+                    self.visit_bound(bound || !is_literal_tree(right), left);
+                    self.visit_bound(bound || !is_literal_tree(left), right);
+                },
+            },
+            ExprKind::AssignOp(op, ref left, ref right) => {
+                self.visit_bound(false, left);
+                self.visit_bound(!is_shift(op.node), right);
+            },
+            ExprKind::Assign(ref left, ref right) => {
+                self.visit_bound(false, left);
+                self.visit_bound(true, right);
+            },
+            // the declaration of the struct, the return type and the target type of the cast are
+            // explicit
+            ExprKind::Struct(..) | ExprKind::Ret(..) | ExprKind::Cast(..) => {
+                self.ty_bounds.push(true);
+                walk_expr(self, expr);
+                self.ty_bounds.pop();
+            },
+            // the condition and the index have a type of their own
+            ExprKind::Index(ref array, ref index) => {
+                self.visit_bound(false, array);
+                self.visit_bound(true, index);
+            },
+            ExprKind::If(ref cond, ref then, ref otherwise) => {
+                self.visit_bound(true, cond);
+                self.visit_expr(then);
+                if let Some(ref otherwise) = *otherwise {
+                    self.visit_expr(otherwise);
+                }
+            },
+            _ => walk_expr(self, expr),
+        }
+    }
+
+    fn visit_stmt(&mut self, stmt: &'tcx Stmt) {
+        match stmt.node {
+            StmtKind::Decl(ref decl, _) => {
+                if let DeclKind::Local(ref local) = decl.node {
+                    self.ty_bounds.push(local.ty.is_some());
+                    walk_stmt(self, stmt);
+                    self.ty_bounds.pop();
+                    return;
+                }
+                walk_stmt(self, stmt);
+            },
+            // the value of an expression statement is discarded, nothing gives its type
+            StmtKind::Expr(..) | StmtKind::Semi(..) => {
+                self.ty_bounds.push(false);
+                walk_stmt(self, stmt);
+                self.ty_bounds.pop();
+            },
+        }
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::None
+    }
+}
+
+/// Returns the suffix of the type an unsuffixed literal falls back to, if it has that type
+fn fallback_suffix(lit: &LitKind, ty: Ty<'_>) -> Option<&'static str> {
+    match (lit, &ty.sty) {
+        (LitKind::Int(_, LitIntType::Unsuffixed), ty::Int(IntTy::I32)) => Some("i32"),
+        (LitKind::FloatUnsuffixed(_), ty::Float(FloatTy::F64)) => Some("f64"),
+        _ => None,
+    }
+}
+
+/// Returns true if the type of `expr` is only decided by unsuffixed literals, e.g. `1 + 2`
+fn is_literal_tree(expr: &Expr) -> bool {
+    match expr.node {
+        ExprKind::Lit(ref lit) => match lit.node {
+            LitKind::Int(_, LitIntType::Unsuffixed) | LitKind::FloatUnsuffixed(_) => true,
+            _ => false,
+        },
+        ExprKind::Unary(UnNeg, ref inner) | ExprKind::Unary(UnNot, ref inner) => is_literal_tree(inner),
+        ExprKind::Binary(op, ref left, ref right) if !is_comparison(op.node) && !is_shift(op.node) => {
+            is_literal_tree(left) && is_literal_tree(right)
+        },
+        _ => false,
+    }
+}
+
+fn is_comparison(op: BinOpKind) -> bool {
+    match op {
+        BinOpKind::Eq | BinOpKind::Ne | BinOpKind::Lt | BinOpKind::Le | BinOpKind::Gt | BinOpKind::Ge => true,
+        _ => false,
+    }
+}
+
+fn is_shift(op: BinOpKind) -> bool {
+    match op {
+        BinOpKind::Shl | BinOpKind::Shr => true,
+        _ => false,
+    }
+}
@@ -0,0 +1,96 @@
+use crate::utils::{in_macro, snippet_opt, span_lint_and_then};
+use rustc::lint::{EarlyContext, EarlyLintPass, LintArray, LintPass};
+use rustc::{declare_lint, lint_array};
+use rustc_errors::Applicability;
+use syntax::ast::*;
+use syntax::source_map::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Finds structs and enum variants without fields that are
+    /// declared with brackets.
+    ///
+    /// **Why is this bad?** Empty brackets after a struct declaration can be
+    /// omitted.
+    ///
+    /// **Known problems:** Changing a declaration also requires updating the places
+    /// where the struct is constructed or matched, e.g. `Foo {}` to `Foo`.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// struct Cookie {}
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// struct Cookie;
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub EMPTY_STRUCTS_WITH_BRACKETS,
+    restriction,
+    "finds struct declarations with empty brackets"
+}
+
+#[derive(Copy, Clone)]
+pub struct EmptyStructsWithBrackets;
+
+impl LintPass for EmptyStructsWithBrackets {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(EMPTY_STRUCTS_WITH_BRACKETS)
+    }
+}
+
+impl EarlyLintPass for EmptyStructsWithBrackets {
+    fn check_item(&mut self, cx: &EarlyContext<'_>, item: &Item) {
+        if let ItemKind::Struct(ref data, ref generics) = item.node {
+            // the brackets can't be removed if there are generics in between
+            if !generics.params.is_empty() || !generics.where_clause.predicates.is_empty() {
+                return;
+            }
+            let span_after_ident = item.span.with_lo(item.ident.span.hi());
+            // a tuple struct declaration already ends with a semicolon
+            check_data(cx, data, span_after_ident, ";", "found empty brackets on struct declaration");
+        }
+    }
+
+    fn check_variant(&mut self, cx: &EarlyContext<'_>, variant: &Variant, _: &Generics) {
+        let span_after_ident = variant.span.with_lo(variant.node.ident.span.hi());
+        check_data(
+            cx,
+            &variant.node.data,
+            span_after_ident,
+            "",
+            "found empty brackets on enum variant declaration",
+        );
+    }
+}
+
+fn check_data(cx: &EarlyContext<'_>, data: &VariantData, span_after_ident: Span, replacement: &str, msg: &str) {
+    if in_macro(span_after_ident) || !has_no_fields(data) || !has_empty_brackets(cx, span_after_ident) {
+        return;
+    }
+    span_lint_and_then(cx, EMPTY_STRUCTS_WITH_BRACKETS, span_after_ident, msg, |db| {
+        db.span_suggestion_with_applicability(
+            span_after_ident,
+            "remove the brackets",
+            replacement.to_owned(),
+            Applicability::MachineApplicable,
+        );
+    });
+}
+
+fn has_no_fields(data: &VariantData) -> bool {
+    match *data {
+        VariantData::Struct(ref fields, _) | VariantData::Tuple(ref fields, _) => fields.is_empty(),
+        VariantData::Unit(_) => false,
+    }
+}
+
+/// Checks that the brackets only contain whitespace, comments would be lost by the suggestion
+fn has_empty_brackets(cx: &EarlyContext<'_>, span: Span) -> bool {
+    let snippet = match snippet_opt(cx, span) {
+        Some(snippet) => snippet,
+        None => return false,
+    };
+    let brackets: String = snippet.chars().filter(|c| !c.is_whitespace()).collect();
+    let brackets = brackets.trim_end_matches(';');
+    brackets == "{}" || brackets == "()"
+}
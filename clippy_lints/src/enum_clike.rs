@@ -12,23 +12,24 @@ use crate::consts::{Constant, miri_to_const};
 use rustc::ty::util::IntTypeExt;
 use rustc::mir::interpret::GlobalId;
 
-/// **What it does:** Checks for C-like enumerations that are
-/// `repr(isize/usize)` and have values that don't fit into an `i32`.
-///
-/// **Why is this bad?** This will truncate the variant value on 32 bit
-/// architectures, but works fine on 64 bit.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// #[repr(usize)]
-/// enum NonPortable {
-///     X = 0x1_0000_0000,
-///     Y = 0
-/// }
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for C-like enumerations that are
+    /// `repr(isize/usize)` and have values that don't fit into an `i32`.
+    ///
+    /// **Why is this bad?** This will truncate the variant value on 32 bit
+    /// architectures, but works fine on 64 bit.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// #[repr(usize)]
+    /// enum NonPortable {
+    ///     X = 0x1_0000_0000,
+    ///     Y = 0
+    /// }
+    /// ```
+    #[clippy::version = "pre 0.0.213"]
     pub ENUM_CLIKE_UNPORTABLE_VARIANT,
     correctness,
     "C-like enums that are `repr(isize/usize)` and have values that don't fit into an `i32`"
@@ -3,21 +3,22 @@ use rustc::hir::*;
 use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
 use rustc::{declare_lint, lint_array};
 use syntax::source_map::Span;
-use crate::utils::{in_macro, span_lint};
+use crate::utils::{in_macro, is_lint_allowed, span_lint};
 
-/// **What it does:** Checks for erasing operations, e.g. `x * 0`.
-///
-/// **Why is this bad?** The whole expression can be replaced by zero.
-/// This is most likely not the intended outcome and should probably be
-/// corrected
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// 0 / x; 0 * x; x & 0
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for erasing operations, e.g. `x * 0`.
+    ///
+    /// **Why is this bad?** The whole expression can be replaced by zero.
+    /// This is most likely not the intended outcome and should probably be
+    /// corrected
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// 0 / x; 0 * x; x & 0
+    /// ```
+    #[clippy::version = "0.0.167"]
     pub ERASING_OP,
     correctness,
     "using erasing operations, e.g. `x * 0` or `y & 0`"
@@ -38,6 +39,14 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for ErasingOp {
             return;
         }
         if let ExprKind::Binary(ref cmp, ref left, ref right) = e.node {
+            // the level of the lint is only looked up for the operations which can be linted
+            let is_candidate = match cmp.node {
+                BinOpKind::Mul | BinOpKind::BitAnd | BinOpKind::Div => true,
+                _ => false,
+            };
+            if !is_candidate || is_lint_allowed(cx, ERASING_OP, e.hir_id) {
+                return;
+            }
             match cmp.node {
                 BinOpKind::Mul | BinOpKind::BitAnd => {
                     check(cx, left, e.span);
@@ -8,37 +8,64 @@ use std::f64;
 use std::fmt;
 use syntax::ast::*;
 use syntax_pos::symbol::Symbol;
-use crate::utils::span_lint_and_sugg;
+use crate::literal_representation::DigitInfo;
+use crate::utils::{snippet_opt, span_lint_and_sugg};
 
-/// **What it does:** Checks for float literals with a precision greater
-/// than that supported by the underlying type
-///
-/// **Why is this bad?** Rust will truncate the literal silently.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-///
-/// ```rust
-/// // Bad
-///    let v: f32 = 0.123_456_789_9;
-///    println!("{}", v); //  0.123_456_789
-///
-/// // Good
-///    let v: f64 = 0.123_456_789_9;
-///    println!("{}", v); //  0.123_456_789_9
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for float literals with a precision greater
+    /// than that supported by the underlying type
+    ///
+    /// **Why is this bad?** Rust will truncate the literal silently.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// // Bad
+    ///    let v: f32 = 0.123_456_789_9;
+    ///    println!("{}", v); //  0.123_456_789
+    ///
+    /// // Good
+    ///    let v: f64 = 0.123_456_789_9;
+    ///    println!("{}", v); //  0.123_456_789_9
+    /// ```
+    #[clippy::version = "pre 0.0.213"]
     pub EXCESSIVE_PRECISION,
     style,
     "excessive precision for float literal"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** Checks for whole number float literals that cannot be
+    /// represented exactly by their type.
+    ///
+    /// **Why is this bad?** Rust will silently round the literal to another whole
+    /// number, e.g. `16_777_217.0_f32` is actually `16_777_216.0`.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// // Bad
+    /// let x: f32 = 16_777_217.0; // 16_777_216.0
+    ///
+    /// // Good
+    /// let x: f32 = 16_777_216.0;
+    /// let x: f64 = 16_777_217.0;
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub LOSSY_FLOAT_LITERAL,
+    correctness,
+    "whole number float literal that cannot be represented exactly by its type"
+}
+
 pub struct ExcessivePrecision;
 
 impl LintPass for ExcessivePrecision {
     fn get_lints(&self) -> LintArray {
-        lint_array!(EXCESSIVE_PRECISION)
+        lint_array!(EXCESSIVE_PRECISION, LOSSY_FLOAT_LITERAL)
     }
 }
 
@@ -49,21 +76,71 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for ExcessivePrecision {
             if let TyKind::Float(fty) = ty.sty;
             if let hir::ExprKind::Lit(ref lit) = expr.node;
             if let LitKind::Float(sym, _) | LitKind::FloatUnsuffixed(sym) = lit.node;
-            if let Some(sugg) = self.check(sym, fty);
             then {
-                span_lint_and_sugg(
-                    cx,
-                    EXCESSIVE_PRECISION,
-                    expr.span,
-                    "float has excessive precision",
-                    "consider changing the type or truncating it to",
-                    sugg,
-                );
+                // a lossy whole number like `16_777_217f32` also has too many digits, report the more specific lint
+                if let Some(value) = lossy_whole_number(&sym.as_str(), fty) {
+                    // the suffix is kept, e.g. `_f32`, removing it could change the type of the literal
+                    let snip = match lit.node {
+                        LitKind::Float(..) => snippet_opt(cx, lit.span),
+                        _ => None,
+                    };
+                    let suffix = snip
+                        .as_ref()
+                        .and_then(|snip| DigitInfo::new(snip, true).suffix)
+                        .unwrap_or("");
+                    let value_lit = format!("{}.0{}", value, suffix);
+                    let di = DigitInfo::new(&value_lit, true);
+                    span_lint_and_sugg(
+                        cx,
+                        LOSSY_FLOAT_LITERAL,
+                        expr.span,
+                        &format!(
+                            "literal cannot be represented exactly as `{}`, its value is `{}.0`",
+                            fty.ty_to_string(),
+                            value
+                        ),
+                        "consider changing the type or the literal to",
+                        di.grouping_hint(),
+                    );
+                } else if let Some(sugg) = self.check(sym, fty) {
+                    span_lint_and_sugg(
+                        cx,
+                        EXCESSIVE_PRECISION,
+                        expr.span,
+                        "float has excessive precision",
+                        "consider changing the type or truncating it to",
+                        sugg,
+                    );
+                }
             }
         }
     }
 }
 
+/// Returns the actual value of a whole number float literal like `16_777_217.0` if its type
+/// cannot represent it exactly
+fn lossy_whole_number(s: &str, fty: FloatTy) -> Option<u128> {
+    let digits: String = s.chars().filter(|c| *c != '_').collect();
+    let (int_part, dec_part) = match digits.find('.') {
+        Some(dot) => (&digits[..dot], &digits[dot + 1..]),
+        None => (&digits[..], ""),
+    };
+    if int_part.is_empty() || !dec_part.chars().all(|c| c == '0') || !int_part.chars().all(|c| c.is_digit(10)) {
+        return None;
+    }
+    // larger literals do not fit in a `u128`, and are usually approximations anyway
+    let literal = int_part.parse::<u128>().ok()?;
+    let value = match fty {
+        FloatTy::F32 => int_part.parse::<f32>().ok().filter(|f| f.is_finite())? as u128,
+        FloatTy::F64 => int_part.parse::<f64>().ok().filter(|f| *f < 1e38)? as u128,
+    };
+    if literal == value {
+        None
+    } else {
+        Some(value)
+    }
+}
+
 impl ExcessivePrecision {
     // None if nothing to lint, Some(suggestion) if lint necessary
     fn check(&self, sym: Symbol, fty: FloatTy) -> Option<String> {
@@ -89,7 +166,7 @@ impl ExcessivePrecision {
             if sym_str == s {
                 None
             } else {
-                let di = super::literal_representation::DigitInfo::new(&s, true);
+                let di = DigitInfo::new(&s, true);
                 Some(di.grouping_hint())
             }
         } else {
@@ -0,0 +1,149 @@
+use crate::utils::msrvs::{self, MsrvStack, RustcVersion};
+use crate::utils::span_lint_and_then;
+use if_chain::if_chain;
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, Lint, LintArray, LintContext, LintPass};
+use rustc::{declare_lint, lint_array};
+use rustc_errors::Applicability;
+use syntax::ast;
+use syntax::attr;
+
+declare_clippy_lint! {
+    /// **What it does:** Warns on any exported `enum`s that are not tagged `#[non_exhaustive]`
+    ///
+    /// **Why is this bad?** Exhaustive enums are typically fine, but a project which does
+    /// not wish to make a stability commitment around exported enums may wish to
+    /// disable them by default.
+    ///
+    /// **Known problems:** `#[non_exhaustive]` needs Rust 1.40, the lint is disabled
+    /// if the `msrv` is older.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// enum Foo {
+    ///     Bar,
+    ///     Baz
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// #[non_exhaustive]
+    /// enum Foo {
+    ///     Bar,
+    ///     Baz
+    /// }
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub EXHAUSTIVE_ENUMS,
+    restriction,
+    "detects exported enums that have not been marked #[non_exhaustive]"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Warns on any exported `struct`s that are not tagged `#[non_exhaustive]`
+    ///
+    /// **Why is this bad?** Exhaustive structs are typically fine, but a project which does
+    /// not wish to make a stability commitment around exported structs may wish to
+    /// disable them by default.
+    ///
+    /// **Known problems:** Structs with a private field can't be built outside of
+    /// their crate either, they are not linted. `#[non_exhaustive]` needs Rust
+    /// 1.40, the lint is disabled if the `msrv` is older.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// struct Foo {
+    ///     bar: u8,
+    ///     baz: String,
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// #[non_exhaustive]
+    /// struct Foo {
+    ///     bar: u8,
+    ///     baz: String,
+    /// }
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub EXHAUSTIVE_STRUCTS,
+    restriction,
+    "detects exported structs that have not been marked #[non_exhaustive]"
+}
+
+pub struct ExhaustiveItems {
+    msrv: MsrvStack,
+}
+
+impl ExhaustiveItems {
+    pub fn new(msrv: Option<RustcVersion>) -> Self {
+        Self {
+            msrv: MsrvStack::new(msrv),
+        }
+    }
+}
+
+impl LintPass for ExhaustiveItems {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(EXHAUSTIVE_ENUMS, EXHAUSTIVE_STRUCTS)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for ExhaustiveItems {
+    fn enter_lint_attrs(&mut self, cx: &LateContext<'a, 'tcx>, attrs: &'tcx [ast::Attribute]) {
+        self.msrv.enter_lint_attrs(cx.sess(), attrs);
+    }
+
+    fn exit_lint_attrs(&mut self, _: &LateContext<'a, 'tcx>, attrs: &'tcx [ast::Attribute]) {
+        self.msrv.exit_lint_attrs(attrs);
+    }
+
+    fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx Item) {
+        if_chain! {
+            if self.msrv.meets(msrvs::NON_EXHAUSTIVE);
+            if cx.access_levels.is_exported(item.id);
+            if !attr::contains_name(&item.attrs, "non_exhaustive");
+            then {
+                let (lint, msg) = match item.node {
+                    ItemKind::Enum(ref def, _) => {
+                        if def.variants.iter().any(|variant| is_doc_hidden(&variant.node.attrs)) {
+                            return;
+                        }
+                        (EXHAUSTIVE_ENUMS, "exported enums should not be exhaustive")
+                    },
+                    ItemKind::Struct(ref data, _) => {
+                        if data.fields().iter().any(|field| !field.vis.node.is_pub()) {
+                            return;
+                        }
+                        (EXHAUSTIVE_STRUCTS, "exported structs should not be exhaustive")
+                    },
+                    _ => return,
+                };
+                lint_exhaustive(cx, lint, item, msg);
+            }
+        }
+    }
+}
+
+fn lint_exhaustive(cx: &LateContext<'_, '_>, lint: &'static Lint, item: &Item, msg: &str) {
+    let indent = " ".repeat(cx.sess().source_map().lookup_char_pos(item.span.lo()).col.0);
+    span_lint_and_then(cx, lint, item.span, msg, |db| {
+        db.span_suggestion_with_applicability(
+            item.span.shrink_to_lo(),
+            "try adding #[non_exhaustive]",
+            format!("#[non_exhaustive]\n{}", indent),
+            Applicability::MachineApplicable,
+        );
+    });
+}
+
+fn is_doc_hidden(attrs: &[ast::Attribute]) -> bool {
+    attrs.iter().any(|attr| {
+        attr.check_name("doc")
+            && attr
+                .meta_item_list()
+                .map_or(false, |items| attr::list_contains_name(&items, "hidden"))
+    })
+}
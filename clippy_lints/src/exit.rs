@@ -0,0 +1,63 @@
+use crate::utils::{is_entrypoint_fn, match_def_path, opt_def_id, paths, span_help_and_lint};
+use if_chain::if_chain;
+use rustc::hir::{Expr, ExprKind};
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::{declare_lint, lint_array};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for calls to `std::process::exit` outside of
+    /// `main`.
+    ///
+    /// **Why is this bad?** `exit` ends the process right away: the destructors
+    /// of the values on the stack are not run, and the code calling it cannot be
+    /// tested. Returning an error up to `main` lets it decide how to exit.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// fn check(config: &Config) {
+    ///     if config.is_invalid() {
+    ///         std::process::exit(1);
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub EXIT,
+    restriction,
+    "`std::process::exit` is called, terminating the program"
+}
+
+#[derive(Copy, Clone)]
+pub struct Pass;
+
+impl LintPass for Pass {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(EXIT)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, e: &'tcx Expr) {
+        if_chain! {
+            if let ExprKind::Call(ref path_expr, ref _args) = e.node;
+            if let ExprKind::Path(ref qpath) = path_expr.node;
+            if let Some(def_id) = opt_def_id(cx.tables.qpath_def(qpath, path_expr.hir_id));
+            if match_def_path(cx.tcx, def_id, &paths::EXIT);
+            then {
+                // `exit()` is fine in `main`, that's how the exit code is set
+                let parent = cx.tcx.hir.get_parent(e.id);
+                if is_entrypoint_fn(cx, cx.tcx.hir.local_def_id(parent)) {
+                    return;
+                }
+                span_help_and_lint(
+                    cx,
+                    EXIT,
+                    e.span,
+                    "usage of `process::exit`",
+                    "consider returning an error up to `main` instead",
+                );
+            }
+        }
+    }
+}
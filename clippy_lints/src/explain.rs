@@ -0,0 +1,499 @@
+//! The documentation of the lints printed by `cargo clippy --explain`.
+
+use crate::utils::conf::CONF_DOCS;
+use crate::LintInfo;
+use rustc_errors::Applicability;
+
+/// All the lints, with the applicabilities of their suggestions. `update_lints` finds them in the
+/// calls to the `span_lint*` functions naming the lint, or to a function of the same module which
+/// passes its `lint` parameter on to them. They are `None` if the lint is never named there, e.g.
+/// because it is passed to a helper function of another module or stored in a variable first.
+/// The applicabilities of the emitted suggestions are counted by `--suggestion-stats` instead.
+#[rustfmt::skip]
+static LINTS: &[(&LintInfo, Option<&[Applicability]>)] = &[
+    // begin lint list, do not remove this comment, it’s used in `update_lints`
+    (&crate::almost_complete_range::ALMOST_COMPLETE_RANGE::INFO, Some(&[Applicability::MachineApplicable])),
+    (&crate::approx_const::APPROX_CONSTANT::INFO, Some(&[])),
+    (&crate::arithmetic::FLOAT_ARITHMETIC::INFO, Some(&[])),
+    (&crate::arithmetic::INTEGER_ARITHMETIC::INFO, Some(&[])),
+    (&crate::arithmetic_side_effects::ARITHMETIC_SIDE_EFFECTS::INFO, Some(&[])),
+    (&crate::assign_ops::ASSIGN_OP_PATTERN::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::assign_ops::MISREFACTORED_ASSIGN_OP::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::attrs::DEPRECATED_SEMVER::INFO, Some(&[])),
+    (&crate::attrs::EMPTY_LINE_AFTER_OUTER_ATTR::INFO, Some(&[])),
+    (&crate::attrs::INLINE_ALWAYS::INFO, Some(&[])),
+    (&crate::attrs::SHOULD_PANIC_WITHOUT_EXPECT::INFO, Some(&[Applicability::HasPlaceholders])),
+    (&crate::attrs::USELESS_ATTRIBUTE::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::bit_mask::BAD_BIT_MASK::INFO, Some(&[])),
+    (&crate::bit_mask::INEFFECTIVE_BIT_MASK::INFO, Some(&[])),
+    (&crate::bit_mask::VERBOSE_BIT_MASK::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::blacklisted_name::BLACKLISTED_NAME::INFO, Some(&[])),
+    (&crate::block_in_if_condition::BLOCK_IN_IF_CONDITION_EXPR::INFO, Some(&[])),
+    (&crate::block_in_if_condition::BLOCK_IN_IF_CONDITION_STMT::INFO, Some(&[])),
+    (&crate::bool_to_int_with_if::BOOL_TO_INT_WITH_IF::INFO, Some(&[Applicability::MachineApplicable])),
+    (&crate::booleans::LOGIC_BUG::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::booleans::NONMINIMAL_BOOL::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::bytecount::NAIVE_BYTECOUNT::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::collapsible_if::COLLAPSIBLE_IF::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::const_comparisons::IMPOSSIBLE_COMPARISONS::INFO, Some(&[])),
+    (&crate::const_comparisons::REDUNDANT_COMPARISONS::INFO, Some(&[])),
+    (&crate::const_static_lifetime::CONST_STATIC_LIFETIME::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::copies::BRANCHES_SHARING_CODE::INFO, Some(&[])),
+    (&crate::copies::IF_SAME_THEN_ELSE::INFO, Some(&[])),
+    (&crate::copies::IFS_SAME_COND::INFO, Some(&[])),
+    (&crate::copies::MATCH_SAME_ARMS::INFO, Some(&[])),
+    (&crate::copies::SAME_FUNCTIONS_IN_IF_CONDITION::INFO, Some(&[])),
+    (&crate::copy_iterator::COPY_ITERATOR::INFO, Some(&[])),
+    (&crate::create_dir::CREATE_DIR::INFO, Some(&[Applicability::MachineApplicable])),
+    (&crate::cyclomatic_complexity::CYCLOMATIC_COMPLEXITY::INFO, Some(&[])),
+    (&crate::default_constructed_unit_structs::DEFAULT_CONSTRUCTED_UNIT_STRUCTS::INFO, Some(&[Applicability::MachineApplicable])),
+    (&crate::default_numeric_fallback::DEFAULT_NUMERIC_FALLBACK::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::default_trait_access::DEFAULT_TRAIT_ACCESS::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::derive::DERIVE_HASH_XOR_EQ::INFO, Some(&[])),
+    (&crate::derive::EXPL_IMPL_CLONE_ON_COPY::INFO, Some(&[])),
+    (&crate::doc::DOC_MARKDOWN::INFO, Some(&[])),
+    (&crate::double_comparison::DOUBLE_COMPARISONS::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::double_parens::DOUBLE_PARENS::INFO, Some(&[])),
+    (&crate::drop_forget_ref::DROP_COPY::INFO, None),
+    (&crate::drop_forget_ref::DROP_REF::INFO, None),
+    (&crate::drop_forget_ref::FORGET_COPY::INFO, None),
+    (&crate::drop_forget_ref::FORGET_REF::INFO, None),
+    (&crate::duration_subsec::DURATION_SUBSEC::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::else_if_without_else::ELSE_IF_WITHOUT_ELSE::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::empty_enum::EMPTY_ENUM::INFO, Some(&[])),
+    (&crate::empty_structs_with_brackets::EMPTY_STRUCTS_WITH_BRACKETS::INFO, Some(&[Applicability::MachineApplicable])),
+    (&crate::entry::MAP_ENTRY::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::enum_clike::ENUM_CLIKE_UNPORTABLE_VARIANT::INFO, Some(&[])),
+    (&crate::enum_glob_use::ENUM_GLOB_USE::INFO, Some(&[])),
+    (&crate::enum_variants::ENUM_VARIANT_NAMES::INFO, None),
+    (&crate::enum_variants::MODULE_INCEPTION::INFO, Some(&[])),
+    (&crate::enum_variants::PUB_ENUM_VARIANT_NAMES::INFO, None),
+    (&crate::enum_variants::STUTTER::INFO, Some(&[])),
+    (&crate::eq_op::EQ_OP::INFO, Some(&[])),
+    (&crate::eq_op::OP_REF::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::erasing_op::ERASING_OP::INFO, Some(&[])),
+    (&crate::escape::BOXED_LOCAL::INFO, Some(&[])),
+    (&crate::eta_reduction::REDUNDANT_CLOSURE::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::eval_order_dependence::DIVERGING_SUB_EXPRESSION::INFO, Some(&[])),
+    (&crate::eval_order_dependence::EVAL_ORDER_DEPENDENCE::INFO, Some(&[])),
+    (&crate::excessive_precision::EXCESSIVE_PRECISION::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::excessive_precision::LOSSY_FLOAT_LITERAL::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::exhaustive_items::EXHAUSTIVE_ENUMS::INFO, None),
+    (&crate::exhaustive_items::EXHAUSTIVE_STRUCTS::INFO, None),
+    (&crate::exit::EXIT::INFO, Some(&[])),
+    (&crate::explicit_auto_deref::EXPLICIT_AUTO_DEREF::INFO, Some(&[Applicability::MachineApplicable])),
+    (&crate::explicit_write::EXPLICIT_WRITE::INFO, Some(&[])),
+    (&crate::fallible_impl_from::FALLIBLE_IMPL_FROM::INFO, Some(&[])),
+    (&crate::feature_name::NEGATIVE_FEATURE_NAMES::INFO, Some(&[])),
+    (&crate::feature_name::REDUNDANT_DEPENDENCY_FEATURES::INFO, Some(&[])),
+    (&crate::feature_name::REDUNDANT_FEATURE_NAMES::INFO, Some(&[])),
+    (&crate::field_reassign_with_default::FIELD_REASSIGN_WITH_DEFAULT::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::floating_point_arithmetic::IMPRECISE_FLOPS::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::floating_point_arithmetic::SUBOPTIMAL_FLOPS::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::format::USELESS_FORMAT::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::format_args::FORMAT_IN_FORMAT_ARGS::INFO, Some(&[])),
+    (&crate::format_args::TO_STRING_IN_FORMAT_ARGS::INFO, Some(&[Applicability::MachineApplicable])),
+    (&crate::formatting::POSSIBLE_MISSING_COMMA::INFO, Some(&[])),
+    (&crate::formatting::SUSPICIOUS_ASSIGNMENT_FORMATTING::INFO, Some(&[])),
+    (&crate::formatting::SUSPICIOUS_ELSE_FORMATTING::INFO, Some(&[])),
+    (&crate::functions::NOT_UNSAFE_PTR_ARG_DEREF::INFO, Some(&[])),
+    (&crate::functions::TOO_MANY_ARGUMENTS::INFO, Some(&[])),
+    (&crate::identity_conversion::IDENTITY_CONVERSION::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::identity_op::IDENTITY_OP::INFO, Some(&[])),
+    (&crate::if_let_mutex::IF_LET_MUTEX::INFO, Some(&[])),
+    (&crate::if_let_redundant_pattern_matching::IF_LET_REDUNDANT_PATTERN_MATCHING::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::if_not_else::IF_NOT_ELSE::INFO, Some(&[])),
+    (&crate::if_then_some_else_none::IF_THEN_SOME_ELSE_NONE::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::impl_trait_in_params::IMPL_TRAIT_IN_PARAMS::INFO, Some(&[Applicability::MaybeIncorrect])),
+    (&crate::inconsistent_rust_version::INCONSISTENT_RUST_VERSION::INFO, Some(&[])),
+    (&crate::indexing_slicing::INDEXING_SLICING::INFO, Some(&[])),
+    (&crate::indexing_slicing::OUT_OF_BOUNDS_INDEXING::INFO, Some(&[])),
+    (&crate::infallible_destructuring_match::INFALLIBLE_DESTRUCTURING_MATCH::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::infinite_iter::INFINITE_ITER::INFO, None),
+    (&crate::infinite_iter::MAYBE_INFINITE_ITER::INFO, None),
+    (&crate::inherent_impl::MULTIPLE_INHERENT_IMPL::INFO, Some(&[])),
+    (&crate::inline_fn_without_body::INLINE_FN_WITHOUT_BODY::INFO, Some(&[])),
+    (&crate::int_plus_one::INT_PLUS_ONE::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::invalid_ref::INVALID_REF::INFO, Some(&[])),
+    (&crate::items_after_statements::ITEMS_AFTER_STATEMENTS::INFO, Some(&[])),
+    (&crate::join_absolute_paths::JOIN_ABSOLUTE_PATHS::INFO, Some(&[Applicability::MaybeIncorrect])),
+    (&crate::large_enum_variant::LARGE_ENUM_VARIANT::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::large_futures::LARGE_FUTURES::INFO, Some(&[Applicability::MachineApplicable])),
+    (&crate::len_zero::LEN_WITHOUT_IS_EMPTY::INFO, Some(&[])),
+    (&crate::len_zero::LEN_ZERO::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::let_if_seq::USELESS_LET_IF_SEQ::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::lifetimes::EXTRA_UNUSED_LIFETIMES::INFO, Some(&[])),
+    (&crate::lifetimes::NEEDLESS_LIFETIMES::INFO, Some(&[])),
+    (&crate::lint_groups_priority::LINT_GROUPS_PRIORITY::INFO, Some(&[])),
+    (&crate::literal_representation::DECIMAL_LITERAL_REPRESENTATION::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::literal_representation::INCONSISTENT_DIGIT_GROUPING::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::literal_representation::LARGE_DIGIT_GROUPS::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::literal_representation::UNREADABLE_LITERAL::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::loops::EMPTY_LOOP::INFO, Some(&[])),
+    (&crate::loops::EXPLICIT_COUNTER_LOOP::INFO, Some(&[])),
+    (&crate::loops::EXPLICIT_INTO_ITER_LOOP::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::loops::EXPLICIT_ITER_LOOP::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::loops::FOR_KV_MAP::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::loops::FOR_LOOP_OVER_OPTION::INFO, Some(&[])),
+    (&crate::loops::FOR_LOOP_OVER_RESULT::INFO, Some(&[])),
+    (&crate::loops::ITER_NEXT_LOOP::INFO, Some(&[])),
+    (&crate::loops::MANUAL_MEMCPY::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::loops::MANUAL_WHILE_LET_SOME::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::loops::MUT_RANGE_BOUND::INFO, Some(&[])),
+    (&crate::loops::NEEDLESS_RANGE_LOOP::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::loops::NEVER_LOOP::INFO, Some(&[])),
+    (&crate::loops::REVERSE_RANGE_LOOP::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::loops::UNUSED_COLLECT::INFO, Some(&[])),
+    (&crate::loops::WHILE_IMMUTABLE_CONDITION::INFO, Some(&[])),
+    (&crate::loops::WHILE_LET_LOOP::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::loops::WHILE_LET_ON_ITERATOR::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::macro_use::MACRO_USE_IMPORTS::INFO, Some(&[Applicability::MaybeIncorrect])),
+    (&crate::manual_async_fn::MANUAL_ASYNC_FN::INFO, Some(&[Applicability::MachineApplicable])),
+    (&crate::manual_async_recursion::MANUAL_ASYNC_RECURSION::INFO, Some(&[])),
+    (&crate::manual_bits::MANUAL_BITS::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::manual_is_ascii_check::MANUAL_IS_ASCII_CHECK::INFO, Some(&[Applicability::MachineApplicable])),
+    (&crate::manual_let_else::MANUAL_LET_ELSE::INFO, Some(&[Applicability::MaybeIncorrect])),
+    (&crate::manual_retain::MANUAL_RETAIN::INFO, Some(&[])),
+    (&crate::manual_string_new::MANUAL_STRING_NEW::INFO, Some(&[Applicability::MachineApplicable])),
+    (&crate::map_clone::MAP_CLONE::INFO, Some(&[])),
+    (&crate::map_unit_fn::OPTION_MAP_UNIT_FN::INFO, None),
+    (&crate::map_unit_fn::RESULT_MAP_UNIT_FN::INFO, None),
+    (&crate::matches::MATCH_AS_REF::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::matches::MATCH_BOOL::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::matches::MATCH_OVERLAPPING_ARM::INFO, Some(&[])),
+    (&crate::matches::MATCH_REF_PATS::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::matches::MATCH_WILD_ERR_ARM::INFO, Some(&[])),
+    (&crate::matches::REDUNDANT_AT_REST_PATTERN::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::matches::REDUNDANT_GUARDS::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::matches::REST_PAT_IN_FULLY_BOUND_STRUCTS::INFO, Some(&[])),
+    (&crate::matches::SINGLE_MATCH::INFO, None),
+    (&crate::matches::SINGLE_MATCH_ELSE::INFO, None),
+    (&crate::matches::WILDCARD_ENUM_MATCH_ARM::INFO, Some(&[Applicability::MaybeIncorrect])),
+    (&crate::mem_forget::MEM_FORGET::INFO, Some(&[])),
+    (&crate::methods::BIG_ENDIAN_BYTES::INFO, None),
+    (&crate::methods::CASE_SENSITIVE_FILE_EXTENSION_COMPARISONS::INFO, Some(&[Applicability::MaybeIncorrect])),
+    (&crate::methods::CHARS_LAST_CMP::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::methods::CHARS_NEXT_CMP::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::methods::CLONE_DOUBLE_REF::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::methods::CLONE_ON_COPY::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::methods::CLONE_ON_REF_PTR::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::methods::COLLAPSIBLE_STR_REPLACE::INFO, Some(&[Applicability::MachineApplicable])),
+    (&crate::methods::DRAIN_COLLECT::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::methods::EXPECT_FUN_CALL::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::methods::FILETYPE_IS_FILE::INFO, Some(&[Applicability::MaybeIncorrect])),
+    (&crate::methods::FILTER_MAP::INFO, Some(&[])),
+    (&crate::methods::FILTER_NEXT::INFO, Some(&[])),
+    (&crate::methods::GET_UNWRAP::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::methods::HOST_ENDIAN_BYTES::INFO, None),
+    (&crate::methods::ITER_CLONED_COLLECT::INFO, Some(&[])),
+    (&crate::methods::ITER_NTH::INFO, Some(&[])),
+    (&crate::methods::ITER_ON_SINGLE_OR_EMPTY_COLLECTIONS::INFO, Some(&[])),
+    (&crate::methods::ITER_OUT_OF_BOUNDS::INFO, Some(&[])),
+    (&crate::methods::ITER_OVEREAGER_CLONED::INFO, Some(&[Applicability::MaybeIncorrect])),
+    (&crate::methods::ITER_SKIP_NEXT::INFO, Some(&[])),
+    (&crate::methods::ITER_SKIP_ZERO::INFO, Some(&[Applicability::MaybeIncorrect])),
+    (&crate::methods::ITER_WITH_DRAIN::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::methods::LITTLE_ENDIAN_BYTES::INFO, None),
+    (&crate::methods::MANUAL_FILTER_MAP::INFO, None),
+    (&crate::methods::MANUAL_FIND_MAP::INFO, None),
+    (&crate::methods::MANUAL_SATURATING_ARITHMETIC::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::methods::NEEDLESS_OPTION_AS_DEREF::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::methods::NEEDLESS_REV::INFO, Some(&[])),
+    (&crate::methods::NEW_RET_NO_SELF::INFO, Some(&[])),
+    (&crate::methods::OBFUSCATED_IF_ELSE::INFO, Some(&[Applicability::MachineApplicable])),
+    (&crate::methods::OK_EXPECT::INFO, Some(&[])),
+    (&crate::methods::OPTION_AS_REF_DEREF::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::methods::OPTION_MAP_OR_NONE::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::methods::OPTION_MAP_UNWRAP_OR::INFO, Some(&[])),
+    (&crate::methods::OPTION_MAP_UNWRAP_OR_ELSE::INFO, None),
+    (&crate::methods::OPTION_UNWRAP_USED::INFO, None),
+    (&crate::methods::OR_FUN_CALL::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::methods::RESULT_MAP_UNWRAP_OR_ELSE::INFO, None),
+    (&crate::methods::RESULT_UNWRAP_USED::INFO, None),
+    (&crate::methods::SEARCH_IS_SOME::INFO, Some(&[])),
+    (&crate::methods::SEEK_FROM_CURRENT::INFO, Some(&[Applicability::MachineApplicable])),
+    (&crate::methods::SEEK_TO_START_INSTEAD_OF_REWIND::INFO, Some(&[Applicability::MachineApplicable])),
+    (&crate::methods::SHOULD_IMPLEMENT_TRAIT::INFO, Some(&[])),
+    (&crate::methods::SINGLE_CHAR_PATTERN::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::methods::STRING_EXTEND_CHARS::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::methods::SUSPICIOUS_TO_OWNED::INFO, Some(&[Applicability::MaybeIncorrect])),
+    (&crate::methods::TEMPORARY_CSTRING_AS_PTR::INFO, Some(&[])),
+    (&crate::methods::TO_DIGIT_IS_SOME::INFO, Some(&[Applicability::MachineApplicable])),
+    (&crate::methods::UNNECESSARY_FOLD::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::methods::USELESS_ASREF::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::methods::WRONG_PUB_SELF_CONVENTION::INFO, None),
+    (&crate::methods::WRONG_SELF_CONVENTION::INFO, None),
+    (&crate::methods::ZST_OFFSET::INFO, Some(&[])),
+    (&crate::minmax::CONSTANT_MIN_MAX_CHAIN::INFO, Some(&[Applicability::MaybeIncorrect])),
+    (&crate::minmax::MIN_MAX::INFO, Some(&[])),
+    (&crate::misc::CMP_NAN::INFO, Some(&[])),
+    (&crate::misc::CMP_OWNED::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::misc::FLOAT_CMP::INFO, None),
+    (&crate::misc::FLOAT_CMP_CONST::INFO, None),
+    (&crate::misc::MODULO_ONE::INFO, Some(&[])),
+    (&crate::misc::REDUNDANT_PATTERN::INFO, Some(&[])),
+    (&crate::misc::SHORT_CIRCUIT_STATEMENT::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::misc::TOPLEVEL_REF_ARG::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::misc::USED_UNDERSCORE_BINDING::INFO, Some(&[])),
+    (&crate::misc::ZERO_PTR::INFO, Some(&[])),
+    (&crate::misc_early::BUILTIN_TYPE_SHADOW::INFO, Some(&[])),
+    (&crate::misc_early::DOUBLE_NEG::INFO, Some(&[])),
+    (&crate::misc_early::DUPLICATE_UNDERSCORE_ARGUMENT::INFO, Some(&[])),
+    (&crate::misc_early::MIXED_CASE_HEX_LITERALS::INFO, Some(&[])),
+    (&crate::misc_early::REDUNDANT_CLOSURE_CALL::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::misc_early::UNNEEDED_FIELD_PATTERN::INFO, Some(&[])),
+    (&crate::misc_early::UNSEPARATED_LITERAL_SUFFIX::INFO, Some(&[])),
+    (&crate::misc_early::ZERO_PREFIXED_LITERAL::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::missing_assert_message::MISSING_ASSERT_MESSAGE::INFO, Some(&[])),
+    (&crate::missing_doc::MISSING_DOCS_IN_PRIVATE_ITEMS::INFO, Some(&[])),
+    (&crate::missing_inline::MISSING_INLINE_IN_PUBLIC_ITEMS::INFO, Some(&[])),
+    (&crate::multiple_crate_versions::MULTIPLE_CRATE_VERSIONS::INFO, Some(&[])),
+    (&crate::mut_mut::MUT_MUT::INFO, Some(&[])),
+    (&crate::mut_reference::UNNECESSARY_MUT_PASSED::INFO, Some(&[])),
+    (&crate::mutable_debug_assertion::DEBUG_ASSERT_WITH_MUT_CALL::INFO, Some(&[])),
+    (&crate::mutex_atomic::MUTEX_ATOMIC::INFO, Some(&[])),
+    (&crate::mutex_atomic::MUTEX_INTEGER::INFO, Some(&[])),
+    (&crate::needless_bool::BOOL_COMPARISON::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::needless_bool::NEEDLESS_BOOL::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::needless_borrow::NEEDLESS_BORROW::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::needless_borrowed_ref::NEEDLESS_BORROWED_REFERENCE::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::needless_continue::NEEDLESS_CONTINUE::INFO, Some(&[])),
+    (&crate::needless_else::EMPTY_IF_BRANCHES::INFO, Some(&[])),
+    (&crate::needless_else::NEEDLESS_ELSE::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::needless_late_init::NEEDLESS_LATE_INIT::INFO, Some(&[Applicability::MaybeIncorrect])),
+    (&crate::needless_pass_by_value::NEEDLESS_PASS_BY_VALUE::INFO, Some(&[])),
+    (&crate::needless_update::NEEDLESS_UPDATE::INFO, Some(&[])),
+    (&crate::neg_cmp_op_on_partial_ord::NEG_CMP_OP_ON_PARTIAL_ORD::INFO, Some(&[])),
+    (&crate::neg_multiply::NEG_MULTIPLY::INFO, Some(&[])),
+    (&crate::new_without_default::NEW_WITHOUT_DEFAULT::INFO, Some(&[])),
+    (&crate::new_without_default::NEW_WITHOUT_DEFAULT_DERIVE::INFO, Some(&[])),
+    (&crate::no_effect::NO_EFFECT::INFO, Some(&[])),
+    (&crate::no_effect::UNNECESSARY_OPERATION::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::non_copy_const::BORROW_INTERIOR_MUTABLE_CONST::INFO, None),
+    (&crate::non_copy_const::DECLARE_INTERIOR_MUTABLE_CONST::INFO, None),
+    (&crate::non_expressive_names::JUST_UNDERSCORES_AND_DIGITS::INFO, Some(&[])),
+    (&crate::non_expressive_names::MANY_SINGLE_CHAR_NAMES::INFO, Some(&[])),
+    (&crate::non_expressive_names::SIMILAR_NAMES::INFO, Some(&[])),
+    (&crate::non_octal_unix_permissions::NON_OCTAL_UNIX_PERMISSIONS::INFO, Some(&[Applicability::MaybeIncorrect])),
+    (&crate::ok_if_let::IF_LET_SOME_RESULT::INFO, Some(&[])),
+    (&crate::open_options::NONSENSICAL_OPEN_OPTIONS::INFO, Some(&[])),
+    (&crate::option_env_unwrap::OPTION_ENV_UNWRAP::INFO, Some(&[])),
+    (&crate::overflow_check_conditional::OVERFLOW_CHECK_CONDITIONAL::INFO, Some(&[])),
+    (&crate::panic_in_result_fn::PANIC_IN_RESULT_FN::INFO, Some(&[])),
+    (&crate::panic_in_result_fn::UNWRAP_IN_RESULT::INFO, Some(&[])),
+    (&crate::panic_unimplemented::PANIC_PARAMS::INFO, Some(&[])),
+    (&crate::panic_unimplemented::UNIMPLEMENTED::INFO, Some(&[])),
+    (&crate::partialeq_ne_impl::PARTIALEQ_NE_IMPL::INFO, Some(&[])),
+    (&crate::path_buf_push_overwrite::PATH_BUF_PUSH_OVERWRITE::INFO, Some(&[Applicability::MaybeIncorrect])),
+    (&crate::pattern_type_mismatch::PATTERN_TYPE_MISMATCH::INFO, Some(&[])),
+    (&crate::pow_of_two::INEFFICIENT_POW_OF_TWO::INFO, Some(&[Applicability::MaybeIncorrect])),
+    (&crate::pow_of_two::MANUAL_ROTATE::INFO, Some(&[Applicability::MaybeIncorrect])),
+    (&crate::precedence::PRECEDENCE::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::ptr::CMP_NULL::INFO, Some(&[])),
+    (&crate::ptr::MUT_FROM_REF::INFO, Some(&[])),
+    (&crate::ptr::PTR_ARG::INFO, Some(&[Applicability::MaybeIncorrect, Applicability::Unspecified])),
+    (&crate::question_mark::QUESTION_MARK::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::ranges::ITERATOR_STEP_BY_ZERO::INFO, Some(&[])),
+    (&crate::ranges::RANGE_MINUS_ONE::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::ranges::RANGE_PLUS_ONE::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::ranges::RANGE_ZIP_WITH_LEN::INFO, Some(&[])),
+    (&crate::read_zero_byte_vec::READ_ZERO_BYTE_VEC::INFO, Some(&[])),
+    (&crate::readonly_write_lock::READONLY_WRITE_LOCK::INFO, Some(&[Applicability::MaybeIncorrect])),
+    (&crate::recursive_format_impl::RECURSIVE_FORMAT_IMPL::INFO, Some(&[])),
+    (&crate::redundant_else::REDUNDANT_ELSE::INFO, Some(&[Applicability::MaybeIncorrect])),
+    (&crate::redundant_field_names::REDUNDANT_FIELD_NAMES::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::redundant_type_annotations::REDUNDANT_TYPE_ANNOTATIONS::INFO, Some(&[Applicability::MachineApplicable])),
+    (&crate::ref_option_ref::REF_OPTION_REF::INFO, Some(&[Applicability::MaybeIncorrect])),
+    (&crate::reference::DEREF_ADDROF::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::reference::REF_IN_DEREF::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::regex::INVALID_REGEX::INFO, Some(&[])),
+    (&crate::regex::REGEX_MACRO::INFO, Some(&[])),
+    (&crate::regex::TRIVIAL_REGEX::INFO, Some(&[])),
+    (&crate::replace_consts::REPLACE_CONSTS::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::returns::LET_AND_RETURN::INFO, Some(&[])),
+    (&crate::returns::NEEDLESS_RETURN::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::serde_api::SERDE_API_MISUSE::INFO, Some(&[])),
+    (&crate::shadow::SHADOW_REUSE::INFO, Some(&[])),
+    (&crate::shadow::SHADOW_SAME::INFO, Some(&[])),
+    (&crate::shadow::SHADOW_UNRELATED::INFO, Some(&[])),
+    (&crate::significant_drop_in_scrutinee::SIGNIFICANT_DROP_IN_SCRUTINEE::INFO, Some(&[])),
+    (&crate::size_of_in_element_count::SIZE_OF_IN_ELEMENT_COUNT::INFO, Some(&[])),
+    (&crate::slow_vector_initialization::SLOW_VECTOR_INITIALIZATION::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::strings::STRING_ADD::INFO, Some(&[])),
+    (&crate::strings::STRING_ADD_ASSIGN::INFO, Some(&[])),
+    (&crate::strings::STRING_LIT_AS_BYTES::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::strings::STRING_SLICE::INFO, Some(&[])),
+    (&crate::struct_field_names::STRUCT_FIELD_NAMES::INFO, Some(&[])),
+    (&crate::suspicious_operation_groupings::SUSPICIOUS_OPERATION_GROUPINGS::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::suspicious_trait_impl::SUSPICIOUS_ARITHMETIC_IMPL::INFO, Some(&[])),
+    (&crate::suspicious_trait_impl::SUSPICIOUS_OP_ASSIGN_IMPL::INFO, Some(&[])),
+    (&crate::swap::ALMOST_SWAPPED::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::swap::MANUAL_SWAP::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::swapped_arguments::SWAPPED_ARGUMENTS::INFO, Some(&[Applicability::MaybeIncorrect])),
+    (&crate::temporary_assignment::TEMPORARY_ASSIGNMENT::INFO, Some(&[])),
+    (&crate::trailing_empty_array::TRAILING_EMPTY_ARRAY::INFO, Some(&[])),
+    (&crate::trait_bounds::TRAIT_DUPLICATION_IN_BOUNDS::INFO, Some(&[])),
+    (&crate::trait_bounds::TYPE_REPETITION_IN_BOUNDS::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::transmute::CROSSPOINTER_TRANSMUTE::INFO, Some(&[])),
+    (&crate::transmute::TRANSMUTE_BYTES_TO_STR::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::transmute::TRANSMUTE_INT_TO_BOOL::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::transmute::TRANSMUTE_INT_TO_CHAR::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::transmute::TRANSMUTE_INT_TO_FLOAT::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::transmute::TRANSMUTE_PTR_TO_PTR::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::transmute::TRANSMUTE_PTR_TO_REF::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::transmute::TRANSMUTES_EXPRESSIBLE_AS_PTR_CASTS::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::transmute::UNSOUND_COLLECTION_TRANSMUTE::INFO, Some(&[])),
+    (&crate::transmute::USELESS_TRANSMUTE::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::transmute::WRONG_TRANSMUTE::INFO, Some(&[])),
+    (&crate::trivially_copy_pass_by_ref::TRIVIALLY_COPY_PASS_BY_REF::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::tuple_array_conversions::TUPLE_ARRAY_CONVERSIONS::INFO, Some(&[])),
+    (&crate::types::ABSURD_EXTREME_COMPARISONS::INFO, Some(&[])),
+    (&crate::types::AS_CONVERSIONS::INFO, Some(&[])),
+    (&crate::types::BORROWED_BOX::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::types::BOX_VEC::INFO, Some(&[])),
+    (&crate::types::CAST_LOSSLESS::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::types::CAST_POSSIBLE_TRUNCATION::INFO, Some(&[Applicability::MaybeIncorrect])),
+    (&crate::types::CAST_POSSIBLE_WRAP::INFO, Some(&[])),
+    (&crate::types::CAST_PRECISION_LOSS::INFO, Some(&[])),
+    (&crate::types::CAST_PTR_ALIGNMENT::INFO, Some(&[])),
+    (&crate::types::CAST_SIGN_LOSS::INFO, Some(&[Applicability::MaybeIncorrect])),
+    (&crate::types::CHAR_LIT_AS_U8::INFO, Some(&[])),
+    (&crate::types::FN_TO_NUMERIC_CAST::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::types::FN_TO_NUMERIC_CAST_WITH_TRUNCATION::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::types::IMPLICIT_HASHER::INFO, Some(&[])),
+    (&crate::types::INVALID_UPCAST_COMPARISONS::INFO, Some(&[])),
+    (&crate::types::LET_UNIT_VALUE::INFO, Some(&[])),
+    (&crate::types::LINKEDLIST::INFO, Some(&[])),
+    (&crate::types::OPTION_OPTION::INFO, Some(&[])),
+    (&crate::types::TYPE_COMPLEXITY::INFO, Some(&[])),
+    (&crate::types::UNIT_ARG::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::types::UNIT_CMP::INFO, Some(&[])),
+    (&crate::types::UNNECESSARY_CAST::INFO, Some(&[])),
+    (&crate::unbuffered_file_io::UNBUFFERED_FILE_IO::INFO, Some(&[])),
+    (&crate::unicode::NON_ASCII_LITERAL::INFO, Some(&[])),
+    (&crate::unicode::UNICODE_NOT_NFC::INFO, Some(&[])),
+    (&crate::unicode::ZERO_WIDTH_SPACE::INFO, Some(&[])),
+    (&crate::unnecessary_struct_initialization::UNNECESSARY_STRUCT_INITIALIZATION::INFO, Some(&[])),
+    (&crate::unsafe_removed_from_name::UNSAFE_REMOVED_FROM_NAME::INFO, Some(&[])),
+    (&crate::unused_async::UNUSED_ASYNC::INFO, Some(&[])),
+    (&crate::unused_io_amount::UNUSED_IO_AMOUNT::INFO, Some(&[Applicability::MaybeIncorrect])),
+    (&crate::unused_label::UNUSED_LABEL::INFO, Some(&[])),
+    (&crate::unused_self::UNUSED_SELF::INFO, Some(&[])),
+    (&crate::unwrap::PANICKING_UNWRAP::INFO, Some(&[])),
+    (&crate::unwrap::UNNECESSARY_UNWRAP::INFO, Some(&[])),
+    (&crate::use_self::USE_SELF::INFO, Some(&[Applicability::MachineApplicable])),
+    (&crate::vec::USELESS_VEC::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::write::PRINT_LITERAL::INFO, None),
+    (&crate::write::PRINT_STDERR::INFO, Some(&[])),
+    (&crate::write::PRINT_STDOUT::INFO, Some(&[])),
+    (&crate::write::PRINT_WITH_NEWLINE::INFO, Some(&[])),
+    (&crate::write::PRINTLN_EMPTY_STRING::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::write::USE_DEBUG::INFO, Some(&[])),
+    (&crate::write::WRITE_LITERAL::INFO, None),
+    (&crate::write::WRITE_WITH_NEWLINE::INFO, Some(&[])),
+    (&crate::write::WRITELN_EMPTY_STRING::INFO, Some(&[Applicability::Unspecified])),
+    (&crate::zero_div_zero::ZERO_DIVIDED_BY_ZERO::INFO, Some(&[])),
+    // end lint list, do not remove this comment, it’s used in `update_lints`
+];
+
+/// Returns the documentation of the lint named `name`, which is in lower case and without the
+/// `clippy::` prefix, or `None` if there is no such lint.
+pub fn explain(name: &str) -> Option<String> {
+    let &(info, applicabilities) = LINTS.iter().find(|(info, _)| info.name.to_lowercase() == name)?;
+
+    let mut explanation = format!("### {}\n\n", name);
+    explanation.push_str(&format!(
+        "Group: `{}`, {} by default",
+        group_name(info.category),
+        default_level(info.category)
+    ));
+    if info.version.starts_with("pre ") {
+        explanation.push_str(&format!(", added before Clippy {}", &info.version["pre ".len()..]));
+    } else {
+        explanation.push_str(&format!(", added in Clippy {}", info.version));
+    }
+    explanation.push_str("\n\n");
+    push_docs(&mut explanation, info.explanation);
+
+    let conf_keys: Vec<_> = CONF_DOCS
+        .iter()
+        .filter_map(|&(key, doc, default)| {
+            let doc = doc.trim_start();
+            let doc = if doc.starts_with("Lint: ") { &doc["Lint: ".len()..] } else { doc };
+            let (lints, doc) = doc.split_at(doc.find(". ")?);
+            if lints.split(", ").any(|lint| lint == info.name) {
+                Some((key, &doc[". ".len()..], default))
+            } else {
+                None
+            }
+        })
+        .collect();
+    if !conf_keys.is_empty() {
+        explanation.push_str("\n### Configuration\n\n");
+        for (key, doc, default) in conf_keys {
+            explanation.push_str(&format!(
+                "* `{}`: {} (defaults to `{}`)\n",
+                key.replace('_', "-"),
+                doc,
+                default
+            ));
+        }
+    }
+
+    explanation.push_str("\n### Suggestions\n\n");
+    if let Some(applicabilities) = applicabilities {
+        if applicabilities.is_empty() {
+            explanation.push_str("This lint doesn't suggest any fix.\n");
+        }
+        for &applicability in applicabilities {
+            let (name, meaning) = match applicability {
+                Applicability::MachineApplicable => ("MachineApplicable", "can be applied automatically"),
+                Applicability::MaybeIncorrect => ("MaybeIncorrect", "may change the meaning of the code"),
+                Applicability::HasPlaceholders => ("HasPlaceholders", "contain placeholders to fill in"),
+                Applicability::Unspecified => ("Unspecified", "haven't been checked, they may not compile"),
+            };
+            explanation.push_str(&format!("* `{}`: some suggestions {}\n", name, meaning));
+        }
+    } else {
+        explanation.push_str(
+            "The suggestions of this lint are not known, run `cargo clippy --suggestion-stats` to count them.\n",
+        );
+    }
+
+    Some(explanation)
+}
+
+/// Returns the name of the lint group registered for `category`, e.g. `clippy_style`
+fn group_name(category: &str) -> String {
+    match category {
+        "internal_warn" => "clippy_internal".to_string(),
+        _ => format!("clippy_{}", category),
+    }
+}
+
+fn default_level(category: &str) -> &'static str {
+    match category {
+        "correctness" => "deny",
+        "style" | "suspicious" | "complexity" | "perf" | "internal_warn" => "warn",
+        _ => "allow",
+    }
+}
+
+/// Appends the doc comment `docs` without the space following `///`, and without the lines of
+/// the Rust examples hidden by rustdoc
+fn push_docs(explanation: &mut String, docs: &str) {
+    let mut in_code = false;
+    let mut in_rust_code = false;
+    for line in docs.lines() {
+        let line = if line.starts_with(' ') { &line[1..] } else { line };
+        if line.starts_with("```") {
+            let lang = line["```".len()..].trim();
+            in_rust_code = !in_code && (lang.is_empty() || lang.starts_with("rust"));
+            in_code = !in_code;
+        } else if in_rust_code && (line == "#" || line.starts_with("# ")) {
+            continue;
+        }
+        explanation.push_str(line);
+        explanation.push('\n');
+    }
+}
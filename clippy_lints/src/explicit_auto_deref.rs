@@ -0,0 +1,197 @@
+use crate::utils::sugg::Sugg;
+use crate::utils::{in_macro, span_lint_and_then};
+use rustc::hir::def::Def;
+use rustc::hir::def_id::DefId;
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::ty::adjustment::Adjust;
+use rustc::ty::{self, Ty, TypeFoldable};
+use rustc::{declare_lint, lint_array};
+use rustc_errors::Applicability;
+use syntax::ast::Name;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for dereferencing expressions which would be covered
+    /// by auto-deref: the arguments of a parameter of reference type, and the
+    /// receivers of methods taking `&self` or `&mut self`.
+    ///
+    /// **Why is this bad?** This unnecessarily complicates the code.
+    ///
+    /// **Known problems:** An inherent method of the dereferenced type is assumed to
+    /// still be the one called without the dereference, which is wrong if a trait
+    /// in scope has a method of the same name implemented for the reference.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// fn f(_: &str) {}
+    /// let x = &String::new();
+    /// f(&**x);
+    /// let y = (*x).len();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn f(_: &str) {}
+    /// let x = &String::new();
+    /// f(x);
+    /// let y = x.len();
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub EXPLICIT_AUTO_DEREF,
+    complexity,
+    "dereferencing when the compiler would automatically dereference"
+}
+
+#[derive(Copy, Clone)]
+pub struct ExplicitAutoDeref;
+
+impl LintPass for ExplicitAutoDeref {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(EXPLICIT_AUTO_DEREF)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for ExplicitAutoDeref {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if in_macro(expr.span) {
+            return;
+        }
+        match expr.node {
+            ExprKind::Call(ref func, ref args) => {
+                if let ExprKind::Path(ref qpath) = func.node {
+                    if let Def::Fn(def_id) | Def::Method(def_id) = cx.tables.qpath_def(qpath, func.hir_id) {
+                        check_args(cx, def_id, args, 0);
+                    }
+                }
+            },
+            ExprKind::MethodCall(ref path, _, ref args) => {
+                if let Some(def) = cx.tables.type_dependent_defs().get(expr.hir_id) {
+                    check_receiver(cx, def.def_id(), path.ident.name, &args[0]);
+                    check_args(cx, def.def_id(), args, 1);
+                }
+            },
+            _ => (),
+        }
+    }
+}
+
+/// Checks the arguments of a call to `def_id`, skipping the first `skip` ones
+fn check_args(cx: &LateContext<'_, '_>, def_id: DefId, args: &[Expr], skip: usize) {
+    let inputs = cx.tcx.fn_sig(def_id).skip_binder().inputs();
+    for (arg, input) in args.iter().zip(inputs).skip(skip) {
+        // the argument is only coerced if the type of the parameter is known
+        if let ty::Ref(..) = input.sty {
+            if !input.has_param_types() && !input.has_projections() {
+                check_arg(cx, arg);
+            }
+        }
+    }
+}
+
+/// Checks `*x`, `&*x`, `&mut *x` and the same with more dereferences, given to a parameter of
+/// reference type
+fn check_arg(cx: &LateContext<'_, '_>, arg: &Expr) {
+    let (borrow, derefs) = match arg.node {
+        ExprKind::AddrOf(mutbl, ref inner) => (Some(mutbl), &**inner),
+        _ => (None, arg),
+    };
+    let base = match deref_base(cx, derefs) {
+        Some(base) => base,
+        None => return,
+    };
+    // other coercions, e.g. unsizing to `&dyn Any`, would apply to the type of `base` instead
+    let is_deref_coercion = cx.tables.expr_adjustments(arg).iter().all(|adjustment| match adjustment.kind {
+        Adjust::Deref(_) | Adjust::Borrow(_) => true,
+        _ => false,
+    });
+    if !is_deref_coercion {
+        return;
+    }
+    let base_is_ref = match cx.tables.expr_ty(base).sty {
+        ty::Ref(..) => true,
+        _ => false,
+    };
+    // deref coercion only applies from a reference
+    let sugg = match borrow {
+        _ if base_is_ref => Sugg::hir(cx, base, ".."),
+        Some(MutImmutable) => Sugg::hir(cx, base, "..").addr(),
+        Some(MutMutable) => Sugg::hir(cx, base, "..").mut_addr(),
+        None => return,
+    };
+    lint(cx, arg, sugg);
+}
+
+/// Checks `(*x).method()` where `method` is an inherent method taking `self` by reference
+fn check_receiver(cx: &LateContext<'_, '_>, def_id: DefId, name: Name, receiver: &Expr) {
+    let base = match deref_base(cx, receiver) {
+        Some(base) => base,
+        None => return,
+    };
+    let is_inherent = cx
+        .tcx
+        .impl_of_method(def_id)
+        .map_or(false, |impl_id| cx.tcx.trait_id_of_impl(impl_id).is_none());
+    let is_autoref = cx
+        .tables
+        .expr_adjustments(receiver)
+        .iter()
+        .any(|adjustment| if let Adjust::Borrow(_) = adjustment.kind { true } else { false });
+    if !is_inherent || !is_autoref {
+        return;
+    }
+
+    // the types auto-deref goes through before reaching the one of the receiver must not have
+    // an inherent method of the same name
+    let mut derefed = receiver;
+    while let ExprKind::Unary(UnDeref, ref inner) = derefed.node {
+        if has_inherent_item(cx, cx.tables.expr_ty(inner), name) {
+            return;
+        }
+        derefed = inner;
+    }
+
+    lint(cx, receiver, Sugg::hir(cx, base, "..").maybe_par());
+}
+
+/// Returns `x` for `*x`, `**x`, ..., if none of the dereferences is of a raw pointer
+fn deref_base<'a>(cx: &LateContext<'_, '_>, expr: &'a Expr) -> Option<&'a Expr> {
+    let mut base = expr;
+    while let ExprKind::Unary(UnDeref, ref inner) = base.node {
+        if let ty::RawPtr(_) = cx.tables.expr_ty(inner).sty {
+            return None;
+        }
+        base = inner;
+    }
+    if base.id == expr.id || in_macro(base.span) {
+        None
+    } else {
+        Some(base)
+    }
+}
+
+fn has_inherent_item(cx: &LateContext<'_, '_>, ty: Ty<'_>, name: Name) -> bool {
+    match ty.sty {
+        ty::Adt(adt, _) => cx
+            .tcx
+            .inherent_impls(adt.did)
+            .iter()
+            .any(|imp| cx.tcx.associated_items(*imp).any(|item| item.ident.name == name)),
+        _ => false,
+    }
+}
+
+fn lint(cx: &LateContext<'_, '_>, expr: &Expr, sugg: Sugg<'_>) {
+    span_lint_and_then(
+        cx,
+        EXPLICIT_AUTO_DEREF,
+        expr.span,
+        "deref which would be done by auto-deref",
+        |db| {
+            db.span_suggestion_with_applicability(
+                expr.span,
+                "try this",
+                sugg.to_string(),
+                Applicability::MachineApplicable,
+            );
+        },
+    );
+}
@@ -0,0 +1,305 @@
+//! lint on negative or redundant feature names, and on redundant feature declarations of
+//! dependencies
+
+use rustc::lint::{EarlyContext, EarlyLintPass, LintArray, LintPass};
+use rustc::{declare_lint, lint_array};
+use semver::{Version, VersionReq};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use syntax::ast::*;
+use crate::utils::{span_help_and_lint, span_lint};
+
+use cargo_metadata;
+use toml;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for feature names with prefix `use-`, `with-` or suffix `-support`
+    ///
+    /// **Why is this bad?** These prefixes and suffixes have no significant meaning.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```toml
+    /// # The `Cargo.toml` with feature name redundancy
+    /// [features]
+    /// default = ["use-abc", "with-def", "ghi-support"]
+    /// use-abc = []  # redundant
+    /// with-def = []  # redundant
+    /// ghi-support = []  # redundant
+    /// ```
+    ///
+    /// Use instead:
+    /// ```toml
+    /// [features]
+    /// default = ["abc", "def", "ghi"]
+    /// abc = []
+    /// def = []
+    /// ghi = []
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub REDUNDANT_FEATURE_NAMES,
+    cargo,
+    "usage of a redundant feature name"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for negative feature names with prefix `no-`, `not-` or `disable-`
+    ///
+    /// **Why is this bad?** Features are supposed to be additive, and negatively-named features
+    /// violate it: enabling `no-std` in one dependent crate disables `std` for all of them.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```toml
+    /// # The `Cargo.toml` with negative feature names
+    /// [features]
+    /// default = []
+    /// no-abc = []
+    /// not-def = []
+    /// ```
+    ///
+    /// Use instead:
+    /// ```toml
+    /// [features]
+    /// default = ["abc", "def"]
+    /// abc = []
+    /// def = []
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub NEGATIVE_FEATURE_NAMES,
+    cargo,
+    "usage of a negative feature name"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for dependencies declared with `default-features = true`,
+    /// and for features of dependencies which are already enabled by another feature in the
+    /// list, or by the default features of the dependency.
+    ///
+    /// **Why is this bad?** The default features are enabled unless `default-features = false`
+    /// is given, and a feature enabled by another one doesn't need to be listed: the manifest
+    /// can be kept minimal.
+    ///
+    /// **Known problems:** Only the `dependencies`, `dev-dependencies` and `build-dependencies`
+    /// tables of the workspace packages are checked, not the target-specific ones.
+    ///
+    /// **Example:**
+    /// ```toml
+    /// # If the `full` feature of `abc` enables its `derive` feature
+    /// [dependencies]
+    /// abc = { version = "1.0", default-features = true, features = ["full", "derive"] }
+    /// ```
+    ///
+    /// Use instead:
+    /// ```toml
+    /// [dependencies]
+    /// abc = { version = "1.0", features = ["full"] }
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub REDUNDANT_DEPENDENCY_FEATURES,
+    cargo,
+    "dependency feature declaration which has no effect"
+}
+
+pub struct FeatureName {
+    allowed: Vec<String>,
+}
+
+impl FeatureName {
+    pub fn new(allowed: Vec<String>) -> Self {
+        Self { allowed }
+    }
+}
+
+impl LintPass for FeatureName {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(REDUNDANT_FEATURE_NAMES, NEGATIVE_FEATURE_NAMES, REDUNDANT_DEPENDENCY_FEATURES)
+    }
+}
+
+/// The prefixes and suffixes linted by `REDUNDANT_FEATURE_NAMES`
+const REDUNDANT_PREFIXES: [&str; 4] = ["use-", "use_", "with-", "with_"];
+const REDUNDANT_SUFFIXES: [&str; 2] = ["-support", "_support"];
+
+/// The prefixes linted by `NEGATIVE_FEATURE_NAMES`
+const NEGATIVE_PREFIXES: [&str; 6] = ["no-", "no_", "not-", "not_", "disable-", "disable_"];
+
+impl EarlyLintPass for FeatureName {
+    fn check_crate(&mut self, cx: &EarlyContext<'_>, krate: &Crate) {
+        // without the dependencies, only the packages of the workspace are listed
+        let metadata = match cargo_metadata::metadata_deps(None, false) {
+            Ok(metadata) => metadata,
+            Err(_) => {
+                span_lint(cx, REDUNDANT_FEATURE_NAMES, krate.span, "could not read cargo metadata");
+                return;
+            },
+        };
+
+        for package in &metadata.packages {
+            let mut features: Vec<_> = package.features.keys().collect();
+            features.sort();
+            for feature in features {
+                if self.allowed.contains(feature) {
+                    continue;
+                }
+                if let Some(prefix) = NEGATIVE_PREFIXES.iter().find(|prefix| feature.starts_with(*prefix)) {
+                    span_help_and_lint(
+                        cx,
+                        NEGATIVE_FEATURE_NAMES,
+                        krate.span,
+                        &format!(
+                            "the \"{}\" prefix in the feature name \"{}\" is negative",
+                            prefix, feature
+                        ),
+                        &format!(
+                            "consider renaming the feature to \"{}\", but make sure the feature adds functionality",
+                            &feature[prefix.len()..]
+                        ),
+                    );
+                } else if let Some(prefix) = REDUNDANT_PREFIXES.iter().find(|prefix| feature.starts_with(*prefix)) {
+                    lint_redundant(cx, krate, feature, "prefix", prefix, &feature[prefix.len()..]);
+                } else if let Some(suffix) = REDUNDANT_SUFFIXES.iter().find(|suffix| feature.ends_with(*suffix)) {
+                    lint_redundant(cx, krate, feature, "suffix", suffix, &feature[..feature.len() - suffix.len()]);
+                }
+            }
+        }
+
+        check_dependency_features(cx, krate, &metadata.packages);
+    }
+}
+
+/// The tables of a manifest listing dependencies
+const DEPENDENCY_TABLES: [&str; 3] = ["dependencies", "dev-dependencies", "build-dependencies"];
+
+fn check_dependency_features(cx: &EarlyContext<'_>, krate: &Crate, packages: &[cargo_metadata::Package]) {
+    // the features of the dependencies are only listed with them
+    let dependencies = match cargo_metadata::metadata_deps(None, true) {
+        Ok(metadata) => metadata.packages,
+        Err(_) => {
+            span_lint(cx, REDUNDANT_DEPENDENCY_FEATURES, krate.span, "could not read cargo metadata");
+            return;
+        },
+    };
+
+    for package in packages {
+        let manifest = match fs::read_to_string(&package.manifest_path)
+            .ok()
+            .and_then(|manifest| manifest.parse::<toml::Value>().ok())
+        {
+            Some(manifest) => manifest,
+            None => continue,
+        };
+        for table in &DEPENDENCY_TABLES {
+            let entries = match manifest.get(table).and_then(toml::Value::as_table) {
+                Some(entries) => entries,
+                None => continue,
+            };
+            for (name, entry) in entries {
+                if let Some(entry) = entry.as_table() {
+                    check_dependency(cx, krate, &dependencies, name, entry);
+                }
+            }
+        }
+    }
+}
+
+fn check_dependency(
+    cx: &EarlyContext<'_>,
+    krate: &Crate,
+    dependencies: &[cargo_metadata::Package],
+    name: &str,
+    entry: &toml::value::Table,
+) {
+    let default_features = entry
+        .get("default-features")
+        .or_else(|| entry.get("default_features"))
+        .and_then(toml::Value::as_bool);
+    if default_features == Some(true) {
+        span_help_and_lint(
+            cx,
+            REDUNDANT_DEPENDENCY_FEATURES,
+            krate.span,
+            &format!("the default features of the dependency \"{}\" are enabled by default", name),
+            "consider removing `default-features = true`",
+        );
+    }
+
+    let listed: Vec<&str> = match entry.get("features").and_then(toml::Value::as_array) {
+        Some(features) => features.iter().filter_map(toml::Value::as_str).collect(),
+        None => return,
+    };
+    // the dependency may be renamed
+    let package_name = entry.get("package").and_then(toml::Value::as_str).unwrap_or(name);
+    let req = entry
+        .get("version")
+        .and_then(toml::Value::as_str)
+        .and_then(|req| VersionReq::parse(req).ok());
+    let dependency = dependencies.iter().find(|dependency| {
+        dependency.name == package_name
+            && req.as_ref().map_or(true, |req| {
+                Version::parse(&dependency.version.to_string()).map_or(false, |version| req.matches(&version))
+            })
+    });
+    let features = match dependency {
+        Some(dependency) => &dependency.features,
+        None => return,
+    };
+
+    let default = if default_features == Some(false) {
+        HashSet::new()
+    } else {
+        let mut default = enabled_features(features, "default");
+        default.insert("default");
+        default
+    };
+    for &feature in &listed {
+        let enabling = if default.contains(feature) {
+            Some("the default features")
+        } else {
+            listed
+                .iter()
+                .find(|&&other| other != feature && enabled_features(features, other).contains(feature))
+                .map(|_| "another feature")
+        };
+        if let Some(enabling) = enabling {
+            span_help_and_lint(
+                cx,
+                REDUNDANT_DEPENDENCY_FEATURES,
+                krate.span,
+                &format!(
+                    "the feature \"{}\" of the dependency \"{}\" is already enabled by {}",
+                    feature, name, enabling
+                ),
+                "consider removing it from the `features` list",
+            );
+        }
+    }
+}
+
+/// Returns the features transitively enabled by `feature`, except itself
+fn enabled_features<'a>(features: &'a HashMap<String, Vec<String>>, feature: &str) -> HashSet<&'a str> {
+    let mut enabled = HashSet::new();
+    let mut stack: Vec<&str> = vec![feature];
+    while let Some(current) = stack.pop() {
+        for enabled_feature in features.get(current).into_iter().flatten() {
+            // `dep/feature` enables a feature of a dependency of the dependency
+            if !enabled_feature.contains('/') && enabled.insert(&**enabled_feature) {
+                stack.push(enabled_feature);
+            }
+        }
+    }
+    enabled.remove(feature);
+    enabled
+}
+
+fn lint_redundant(cx: &EarlyContext<'_>, krate: &Crate, feature: &str, kind: &str, affix: &str, renamed: &str) {
+    span_help_and_lint(
+        cx,
+        REDUNDANT_FEATURE_NAMES,
+        krate.span,
+        &format!("the \"{}\" {} in the feature name \"{}\" is redundant", affix, kind, feature),
+        &format!("consider renaming the feature to \"{}\"", renamed),
+    );
+}
@@ -0,0 +1,141 @@
+use crate::utils::{contains_name, in_macro, match_def_path, match_var, multispan_sugg, opt_def_id, paths, snippet,
+                   span_lint_and_then};
+use if_chain::if_chain;
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::ty;
+use rustc::{declare_lint, lint_array};
+use syntax::ast;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for immediate reassignment of fields initialized
+    /// with Default::default().
+    ///
+    /// **Why is this bad?** It's more idiomatic to use the [functional update
+    /// syntax](https://doc.rust-lang.org/reference/expressions/struct-expr.html#functional-update-syntax).
+    ///
+    /// **Known problems:** Only the assignments directly following the `let` are
+    /// moved into the initializer.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// let mut a: A = Default::default();
+    /// a.i = 42;
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// let a = A {
+    ///     i: 42,
+    ///     ..Default::default()
+    /// };
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub FIELD_REASSIGN_WITH_DEFAULT,
+    style,
+    "binding initialized with Default should have its fields set in the initializer"
+}
+
+#[derive(Copy, Clone)]
+pub struct FieldReassignWithDefault;
+
+impl LintPass for FieldReassignWithDefault {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(FIELD_REASSIGN_WITH_DEFAULT)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for FieldReassignWithDefault {
+    fn check_block(&mut self, cx: &LateContext<'a, 'tcx>, block: &'tcx Block) {
+        for (i, stmt) in block.stmts.iter().enumerate() {
+            if_chain! {
+                if !in_macro(stmt.span);
+                if let StmtKind::Decl(ref decl, _) = stmt.node;
+                if let DeclKind::Local(ref local) = decl.node;
+                if let PatKind::Binding(_, _, ident, None) = local.pat.node;
+                if let Some(ref init) = local.init;
+                if let Some(type_name) = default_call_type(cx, init);
+                if let ty::Adt(adt, _) = cx.tables.expr_ty(init).sty;
+                if adt.is_struct();
+                // fields which are not set have to be visible to use the update syntax
+                let module = cx.tcx.hir.get_module_parent(local.id);
+                let fields = &adt.non_enum_variant().fields;
+                if fields.iter().all(|field| field.vis.is_accessible_from(module, cx.tcx));
+                let assigns = field_assignments(ident.name, &block.stmts[i + 1..]);
+                if let Some(&(_, _, first)) = assigns.first();
+                then {
+                    let mut inits: Vec<_> = assigns
+                        .iter()
+                        .map(|&(field, value, _)| format!("{}: {}", field, snippet(cx, value.span, "..")))
+                        .collect();
+                    if assigns.len() < fields.len() {
+                        inits.push("..Default::default()".to_owned());
+                    }
+                    let sugg = format!("{} {{ {} }}", type_name, inits.join(", "));
+
+                    // each assignment is removed with the whitespace before it
+                    let mut parts = vec![(init.span, sugg)];
+                    let mut prev = stmt.span;
+                    for &(_, _, assign) in &assigns {
+                        parts.push((assign.span.with_lo(prev.hi()), String::new()));
+                        prev = assign.span;
+                    }
+                    span_lint_and_then(
+                        cx,
+                        FIELD_REASSIGN_WITH_DEFAULT,
+                        first.span,
+                        "field assignment outside of initializer for an instance created with Default::default()",
+                        |db| {
+                            multispan_sugg(
+                                db,
+                                "consider initializing the variable with the struct update syntax".to_owned(),
+                                parts,
+                            );
+                        },
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Returns the name of the type built by a `Default::default()` or `Type::default()` call
+fn default_call_type(cx: &LateContext<'_, '_>, expr: &Expr) -> Option<String> {
+    if_chain! {
+        if let ExprKind::Call(ref func, ref args) = expr.node;
+        if args.is_empty();
+        if let ExprKind::Path(ref qpath) = func.node;
+        if let Some(def_id) = opt_def_id(cx.tables.qpath_def(qpath, func.hir_id));
+        if match_def_path(cx.tcx, def_id, &paths::DEFAULT_TRAIT_METHOD);
+        if let ty::Adt(adt, _) = cx.tables.expr_ty(expr).sty;
+        then {
+            return Some(match *qpath {
+                QPath::TypeRelative(ref ty, _) => snippet(cx, ty.span, "..").into_owned(),
+                QPath::Resolved(..) => cx.tcx.item_name(adt.did).to_string(),
+            });
+        }
+    }
+    None
+}
+
+/// The assignments to fields of the binding `name` directly following its declaration, with the
+/// field, the assigned value and the statement. A value using the binding stops the search.
+fn field_assignments(name: ast::Name, stmts: &[Stmt]) -> Vec<(ast::Ident, &Expr, &Stmt)> {
+    let mut assigns: Vec<(ast::Ident, &Expr, &Stmt)> = Vec::new();
+    for stmt in stmts {
+        if_chain! {
+            if !in_macro(stmt.span);
+            if let StmtKind::Semi(ref expr, _) = stmt.node;
+            if let ExprKind::Assign(ref lhs, ref value) = expr.node;
+            if let ExprKind::Field(ref base, field) = lhs.node;
+            if match_var(base, name);
+            if !contains_name(name, value);
+            if !assigns.iter().any(|&(other, _, _)| other.name == field.name);
+            then {
+                assigns.push((field, value, stmt));
+                continue;
+            }
+        }
+        break;
+    }
+    assigns
+}
@@ -0,0 +1,305 @@
+use crate::consts::{constant, Constant};
+use crate::utils::{in_macro, snippet, span_lint_and_sugg, sugg};
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, Lint, LintArray, LintPass};
+use rustc::ty;
+use rustc::{declare_lint, lint_array};
+use std::f32::consts as f32_consts;
+use std::f64::consts as f64_consts;
+use syntax::ast::LitKind;
+
+declare_clippy_lint! {
+    /// **What it does:** Looks for floating-point expressions that can be
+    /// expressed using built-in methods to improve accuracy, at the cost of
+    /// performance.
+    ///
+    /// **Why is this bad?** Negatively impacts accuracy: e.g. `(1.0 + x).ln()`
+    /// loses the low bits of a small `x`, which `x.ln_1p()` keeps.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let a = 3f32;
+    /// let _ = (1.0 + a).ln();
+    /// let _ = a.exp() - 1.0;
+    /// ```
+    ///
+    /// is better expressed as
+    ///
+    /// ```rust
+    /// let a = 3f32;
+    /// let _ = a.ln_1p();
+    /// let _ = a.exp_m1();
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub IMPRECISE_FLOPS,
+    nursery,
+    "usage of imprecise floating point operations"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Looks for floating-point expressions that can be
+    /// expressed using built-in methods to improve both accuracy and performance.
+    ///
+    /// **Why is this bad?** Negatively impacts accuracy and performance: e.g.
+    /// `a * b + c` rounds twice, `a.mul_add(b, c)` only once and is a single
+    /// instruction on most targets.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let (a, b, c) = (3f32, 4f32, 5f32);
+    /// let _ = a.powi(2);
+    /// let _ = a.powf(0.5);
+    /// let _ = a.log(2.0);
+    /// let _ = a * b + c;
+    /// ```
+    ///
+    /// is better expressed as
+    ///
+    /// ```rust
+    /// let (a, b, c) = (3f32, 4f32, 5f32);
+    /// let _ = a * a;
+    /// let _ = a.sqrt();
+    /// let _ = a.log2();
+    /// let _ = a.mul_add(b, c);
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub SUBOPTIMAL_FLOPS,
+    nursery,
+    "usage of sub-optimal floating point operations"
+}
+
+#[derive(Copy, Clone)]
+pub struct FloatingPointArithmetic;
+
+impl LintPass for FloatingPointArithmetic {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(IMPRECISE_FLOPS, SUBOPTIMAL_FLOPS)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for FloatingPointArithmetic {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if in_macro(expr.span) || !is_float(cx, expr) {
+            return;
+        }
+
+        match expr.node {
+            ExprKind::MethodCall(ref path, _, ref args) if is_float(cx, &args[0]) => {
+                match (&*path.ident.as_str(), args.len()) {
+                    ("powi", 2) => check_powi(cx, expr, args),
+                    ("powf", 2) => check_powf(cx, expr, args),
+                    ("log", 2) => check_log_base(cx, expr, args),
+                    ("ln", 1) => check_ln1p(cx, expr, args),
+                    _ => {},
+                }
+            },
+            ExprKind::Binary(ref op, ref lhs, ref rhs) => match op.node {
+                BinOpKind::Add => check_mul_add(cx, expr, lhs, rhs),
+                BinOpKind::Sub => check_expm1(cx, expr, lhs, rhs),
+                _ => {},
+            },
+            _ => {},
+        }
+    }
+}
+
+fn is_float(cx: &LateContext<'_, '_>, expr: &Expr) -> bool {
+    match cx.tables.expr_ty(expr).sty {
+        ty::Float(_) => true,
+        _ => false,
+    }
+}
+
+/// Returns the value of a float constant, whatever its width
+fn float_value(cx: &LateContext<'_, '_>, expr: &Expr) -> Option<f64> {
+    match constant(cx, cx.tables, expr) {
+        Some((Constant::F32(f), _)) => Some(f64::from(f)),
+        Some((Constant::F64(f), _)) => Some(f),
+        _ => None,
+    }
+}
+
+/// Returns whether `expr` is the constant `value` or `value_f32` if `expr` is an `f32`
+#[allow(float_cmp)]
+fn is_float_const(cx: &LateContext<'_, '_>, expr: &Expr, value: f64, value_f32: f32) -> bool {
+    match constant(cx, cx.tables, expr) {
+        Some((Constant::F32(f), _)) => f == value_f32,
+        Some((Constant::F64(f), _)) => f == value,
+        _ => false,
+    }
+}
+
+fn lint(cx: &LateContext<'_, '_>, lint: &'static Lint, expr: &Expr, msg: &str, sugg: String) {
+    span_lint_and_sugg(cx, lint, expr.span, msg, "consider using", sugg);
+}
+
+// `x.powi(2)` => `x * x`
+fn check_powi(cx: &LateContext<'_, '_>, expr: &Expr, args: &[Expr]) {
+    if let Some((Constant::Int(2), _)) = constant(cx, cx.tables, &args[1]) {
+        let recv = sugg::Sugg::hir(cx, &args[0], "..");
+        lint(
+            cx,
+            SUBOPTIMAL_FLOPS,
+            expr,
+            "square can be computed more efficiently",
+            format!("{} * {}", recv.maybe_par(), recv.maybe_par()),
+        );
+    }
+}
+
+// `x.powf(0.5)` => `x.sqrt()`, `x.powf(3.0)` => `x.powi(3)`, `2.0.powf(x)` => `x.exp2()`,
+// `E.powf(x)` => `x.exp()`
+#[allow(float_cmp)]
+fn check_powf(cx: &LateContext<'_, '_>, expr: &Expr, args: &[Expr]) {
+    let method = if is_float_const(cx, &args[0], 2.0, 2.0) {
+        Some("exp2")
+    } else if is_float_const(cx, &args[0], f64_consts::E, f32_consts::E) {
+        Some("exp")
+    } else {
+        None
+    };
+    if let Some(method) = method {
+        lint(
+            cx,
+            SUBOPTIMAL_FLOPS,
+            expr,
+            "exponent for bases 2 and e can be computed more efficiently",
+            format!("{}.{}()", sugg::Sugg::hir(cx, &args[1], "..").maybe_par(), method),
+        );
+        return;
+    }
+
+    if let Some(exponent) = float_value(cx, &args[1]) {
+        let recv = sugg::Sugg::hir(cx, &args[0], "..").maybe_par();
+        if exponent == 0.5 {
+            lint(
+                cx,
+                SUBOPTIMAL_FLOPS,
+                expr,
+                "square-root of a number can be computed more efficiently and accurately",
+                format!("{}.sqrt()", recv),
+            );
+        } else if exponent.fract() == 0.0 && exponent.abs() <= f64::from(i32::max_value()) {
+            lint(
+                cx,
+                SUBOPTIMAL_FLOPS,
+                expr,
+                "exponentiation with integer powers can be computed more efficiently",
+                format!("{}.powi({})", recv, exponent as i32),
+            );
+        }
+    }
+}
+
+// `x.log(2.0)` => `x.log2()`, `x.log(10.0)` => `x.log10()`, `x.log(E)` => `x.ln()`
+fn check_log_base(cx: &LateContext<'_, '_>, expr: &Expr, args: &[Expr]) {
+    let method = if is_float_const(cx, &args[1], 2.0, 2.0) {
+        "log2"
+    } else if is_float_const(cx, &args[1], 10.0, 10.0) {
+        "log10"
+    } else if is_float_const(cx, &args[1], f64_consts::E, f32_consts::E) {
+        "ln"
+    } else {
+        return;
+    };
+    lint(
+        cx,
+        SUBOPTIMAL_FLOPS,
+        expr,
+        "logarithm for bases 2, 10 and e can be computed more accurately",
+        format!("{}.{}()", sugg::Sugg::hir(cx, &args[0], "..").maybe_par(), method),
+    );
+}
+
+// `(1.0 + x).ln()` => `x.ln_1p()`
+fn check_ln1p(cx: &LateContext<'_, '_>, expr: &Expr, args: &[Expr]) {
+    if let ExprKind::Binary(ref op, ref lhs, ref rhs) = args[0].node {
+        if op.node != BinOpKind::Add {
+            return;
+        }
+        let x = if is_float_const(cx, lhs, 1.0, 1.0) {
+            rhs
+        } else if is_float_const(cx, rhs, 1.0, 1.0) {
+            lhs
+        } else {
+            return;
+        };
+        lint(
+            cx,
+            IMPRECISE_FLOPS,
+            expr,
+            "ln(1 + x) can be computed more accurately",
+            format!("{}.ln_1p()", sugg::Sugg::hir(cx, x, "..").maybe_par()),
+        );
+    }
+}
+
+// `x.exp() - 1.0` => `x.exp_m1()`
+fn check_expm1(cx: &LateContext<'_, '_>, expr: &Expr, lhs: &Expr, rhs: &Expr) {
+    if !is_float_const(cx, rhs, 1.0, 1.0) {
+        return;
+    }
+    if let ExprKind::MethodCall(ref path, _, ref args) = lhs.node {
+        if path.ident.name == "exp" && args.len() == 1 && is_float(cx, &args[0]) {
+            lint(
+                cx,
+                IMPRECISE_FLOPS,
+                expr,
+                "(e.pow(x) - 1) can be computed more accurately",
+                format!("{}.exp_m1()", sugg::Sugg::hir(cx, &args[0], "..").maybe_par()),
+            );
+        }
+    }
+}
+
+// `a * b + c` and `c + a * b` => `a.mul_add(b, c)`
+fn check_mul_add(cx: &LateContext<'_, '_>, expr: &Expr, lhs: &Expr, rhs: &Expr) {
+    let is_mul = |e: &Expr| match e.node {
+        ExprKind::Binary(ref op, _, _) => op.node == BinOpKind::Mul,
+        _ => false,
+    };
+    let (mul, addend) = if is_mul(lhs) {
+        (lhs, rhs)
+    } else if is_mul(rhs) {
+        (rhs, lhs)
+    } else {
+        return;
+    };
+    if let ExprKind::Binary(_, ref a, ref b) = mul.node {
+        lint(
+            cx,
+            SUBOPTIMAL_FLOPS,
+            expr,
+            "multiply and add expressions can be calculated more efficiently and accurately",
+            format!(
+                "{}.mul_add({}, {})",
+                receiver_sugg(cx, a),
+                sugg::Sugg::hir(cx, b, ".."),
+                sugg::Sugg::hir(cx, addend, "..")
+            ),
+        );
+    }
+}
+
+/// Returns the suggestion for the receiver of a method call. The type suffix is added to an
+/// unsuffixed float literal, as no method can be called on a float of an unknown type.
+fn receiver_sugg(cx: &LateContext<'_, '_>, expr: &Expr) -> String {
+    let (neg, operand) = match expr.node {
+        ExprKind::Unary(UnNeg, ref operand) => (true, &**operand),
+        _ => (false, expr),
+    };
+    if let ExprKind::Lit(ref lit) = operand.node {
+        if let LitKind::FloatUnsuffixed(_) = lit.node {
+            let snip = snippet(cx, operand.span, "..");
+            // `2.` needs a digit before the suffix
+            let zero = if snip.ends_with('.') { "0" } else { "" };
+            let lit = format!("{}{}_{}", snip, zero, cx.tables.expr_ty(operand));
+            return if neg { format!("(-{})", lit) } else { lit };
+        }
+    }
+    sugg::Sugg::hir(cx, expr, "..").maybe_par().to_string()
+}
@@ -0,0 +1,137 @@
+use crate::utils::higher::{FormatArgsArg, FormatArgsExpn};
+use crate::utils::{get_trait_def_id, implements_trait, in_macro, is_direct_expn_of, is_expn_of, match_trait_method,
+                   paths, span_lint_and_then};
+use if_chain::if_chain;
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::{declare_lint, lint_array};
+use rustc_errors::Applicability;
+use syntax::source_map::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Detects `format!` within the arguments of another macro that does
+    /// formatting such as `format!` itself, `write!` or `println!`. Suggests
+    /// inlining the `format!` call.
+    ///
+    /// **Why is this bad?** The recommended code is both shorter and avoids a temporary allocation.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// println!("error: {}", format!("something failed at {}", 42));
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// println!("error: something failed at {}", 42);
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub FORMAT_IN_FORMAT_ARGS,
+    perf,
+    "`format!` used in a macro that does formatting"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for [`ToString::to_string`](https://doc.rust-lang.org/std/string/trait.ToString.html#tymethod.to_string)
+    /// applied to a type that implements [`Display`](https://doc.rust-lang.org/std/fmt/trait.Display.html)
+    /// in a macro that does formatting.
+    ///
+    /// **Why is this bad?** Since the type implements `Display`, the use of `to_string` is
+    /// unnecessary.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// println!("error: something failed at {}", 42.to_string());
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// println!("error: something failed at {}", 42);
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub TO_STRING_IN_FORMAT_ARGS,
+    perf,
+    "`to_string` applied to a type that implements `Display` in format args"
+}
+
+#[derive(Copy, Clone)]
+pub struct FormatArgs;
+
+impl LintPass for FormatArgs {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(FORMAT_IN_FORMAT_ARGS, TO_STRING_IN_FORMAT_ARGS)
+    }
+}
+
+/// The macros forwarding their arguments to `format_args!`
+const FORMAT_MACROS: [&str; 7] = ["format", "print", "println", "eprint", "eprintln", "write", "writeln"];
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for FormatArgs {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if_chain! {
+            if let Some(format_args) = FormatArgsExpn::parse(cx, expr);
+            // the macro written by the user, e.g. `println!` and not the `print!` it expands to
+            if let Some((name, call_site)) = FORMAT_MACROS.iter().find_map(|name| {
+                is_expn_of(expr.span, name)
+                    .filter(|span| !in_macro(*span))
+                    .map(|span| (*name, span))
+            });
+            then {
+                for arg in &format_args.args {
+                    if !arg.is_display(cx) || !arg.has_default_spec() {
+                        continue;
+                    }
+                    check_format_in_format_args(cx, call_site, name, arg);
+                    check_to_string_in_format_args(cx, name, arg);
+                }
+            }
+        }
+    }
+}
+
+fn check_format_in_format_args(cx: &LateContext<'_, '_>, call_site: Span, name: &str, arg: &FormatArgsArg<'_>) {
+    if is_direct_expn_of(arg.value.span, "format").map_or(false, |span| !in_macro(span)) {
+        span_lint_and_then(
+            cx,
+            FORMAT_IN_FORMAT_ARGS,
+            call_site,
+            &format!("`format!` in `{}!` args", name),
+            |db| {
+                db.help(&format!(
+                    "combine the `format!(..)` arguments with the outer `{}!(..)` call",
+                    name
+                ));
+                db.help("or consider changing `format!` to `format_args!`");
+            },
+        );
+    }
+}
+
+fn check_to_string_in_format_args(cx: &LateContext<'_, '_>, name: &str, arg: &FormatArgsArg<'_>) {
+    if_chain! {
+        if !in_macro(arg.value.span);
+        if let ExprKind::MethodCall(ref path, _, ref args) = arg.value.node;
+        if path.ident.name == "to_string" && args.len() == 1;
+        if match_trait_method(cx, arg.value, &paths::TO_STRING);
+        if let Some(display_trait_id) = get_trait_def_id(cx, &paths::DISPLAY_TRAIT);
+        if implements_trait(cx, cx.tables.expr_ty(&args[0]), display_trait_id, &[]);
+        then {
+            let span = arg.value.span.with_lo(args[0].span.hi());
+            span_lint_and_then(
+                cx,
+                TO_STRING_IN_FORMAT_ARGS,
+                span,
+                &format!("`to_string` applied to a type that implements `Display` in `{}!` args", name),
+                |db| {
+                    db.span_suggestion_with_applicability(
+                        span,
+                        "remove this",
+                        String::new(),
+                        Applicability::MachineApplicable,
+                    );
+                },
+            );
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use matches::matches;
 use rustc::hir::intravisit;
 use rustc::hir;
-use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintContext, LintPass};
 use rustc::{declare_lint, lint_array};
 use rustc::ty;
 use rustc::hir::def::Def;
@@ -9,62 +9,63 @@ use std::collections::HashSet;
 use syntax::ast;
 use rustc_target::spec::abi::Abi;
 use syntax::source_map::Span;
-use crate::utils::{iter_input_pats, span_lint, type_is_unsafe_function};
-
-/// **What it does:** Checks for functions with too many parameters.
-///
-/// **Why is this bad?** Functions with lots of parameters are considered bad
-/// style and reduce readability (“what does the 5th parameter mean?”). Consider
-/// grouping some parameters into a new type.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// fn foo(x: u32, y: u32, name: &str, c: Color, w: f32, h: f32, a: f32, b:
-/// f32) { .. }
-/// ```
+use crate::utils::{iter_input_pats, span_lint, type_is_unsafe_function, LimitStack};
+
 declare_clippy_lint! {
+    /// **What it does:** Checks for functions with too many parameters.
+    ///
+    /// **Why is this bad?** Functions with lots of parameters are considered bad
+    /// style and reduce readability (“what does the 5th parameter mean?”). Consider
+    /// grouping some parameters into a new type.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// fn foo(x: u32, y: u32, name: &str, c: Color, w: f32, h: f32, a: f32, b:
+    /// f32) { .. }
+    /// ```
+    #[clippy::version = "pre 0.0.213"]
     pub TOO_MANY_ARGUMENTS,
     complexity,
     "functions with too many arguments"
 }
 
-/// **What it does:** Checks for public functions that dereferences raw pointer
-/// arguments but are not marked unsafe.
-///
-/// **Why is this bad?** The function should probably be marked `unsafe`, since
-/// for an arbitrary raw pointer, there is no way of telling for sure if it is
-/// valid.
-///
-/// **Known problems:**
-///
-/// * It does not check functions recursively so if the pointer is passed to a
-/// private non-`unsafe` function which does the dereferencing, the lint won't
-/// trigger.
-/// * It only checks for arguments whose type are raw pointers, not raw pointers
-/// got from an argument in some other way (`fn foo(bar: &[*const u8])` or
-/// `some_argument.get_raw_ptr()`).
-///
-/// **Example:**
-/// ```rust
-/// pub fn foo(x: *const u8) { println!("{}", unsafe { *x }); }
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for public functions that dereferences raw pointer
+    /// arguments but are not marked unsafe.
+    ///
+    /// **Why is this bad?** The function should probably be marked `unsafe`, since
+    /// for an arbitrary raw pointer, there is no way of telling for sure if it is
+    /// valid.
+    ///
+    /// **Known problems:**
+    ///
+    /// * It does not check functions recursively so if the pointer is passed to a
+    /// private non-`unsafe` function which does the dereferencing, the lint won't
+    /// trigger.
+    /// * It only checks for arguments whose type are raw pointers, not raw pointers
+    /// got from an argument in some other way (`fn foo(bar: &[*const u8])` or
+    /// `some_argument.get_raw_ptr()`).
+    ///
+    /// **Example:**
+    /// ```rust
+    /// pub fn foo(x: *const u8) { println!("{}", unsafe { *x }); }
+    /// ```
+    #[clippy::version = "0.0.76"]
     pub NOT_UNSAFE_PTR_ARG_DEREF,
     correctness,
     "public functions dereferencing raw pointer arguments but not marked `unsafe`"
 }
 
-#[derive(Copy, Clone)]
 pub struct Functions {
-    threshold: u64,
+    threshold: LimitStack,
 }
 
 impl Functions {
     pub fn new(threshold: u64) -> Self {
         Self {
-            threshold,
+            threshold: LimitStack::new(threshold),
         }
     }
 }
@@ -76,6 +77,14 @@ impl LintPass for Functions {
 }
 
 impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Functions {
+    fn enter_lint_attrs(&mut self, cx: &LateContext<'a, 'tcx>, attrs: &'tcx [ast::Attribute]) {
+        self.threshold.push_attrs(cx.sess(), attrs, "too_many_arguments");
+    }
+
+    fn exit_lint_attrs(&mut self, cx: &LateContext<'a, 'tcx>, attrs: &'tcx [ast::Attribute]) {
+        self.threshold.pop_attrs(cx.sess(), attrs, "too_many_arguments");
+    }
+
     fn check_fn(
         &mut self,
         cx: &LateContext<'a, 'tcx>,
@@ -126,14 +135,15 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Functions {
 }
 
 impl<'a, 'tcx> Functions {
-    fn check_arg_number(self, cx: &LateContext<'_, '_>, decl: &hir::FnDecl, span: Span) {
+    fn check_arg_number(&self, cx: &LateContext<'_, '_>, decl: &hir::FnDecl, span: Span) {
         let args = decl.inputs.len() as u64;
-        if args > self.threshold {
+        let threshold = self.threshold.limit();
+        if args > threshold {
             span_lint(
                 cx,
                 TOO_MANY_ARGUMENTS,
                 span,
-                &format!("this function has too many arguments ({}/{})", args, self.threshold),
+                &format!("this function has too many arguments ({}/{})", args, threshold),
             );
         }
     }
@@ -3,21 +3,22 @@ use rustc::hir::*;
 use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
 use rustc::{declare_lint, lint_array};
 use syntax::source_map::Span;
-use crate::utils::{in_macro, snippet, span_lint, unsext, clip};
+use crate::utils::{in_macro, is_lint_allowed, snippet, span_lint, unsext, clip};
 use rustc::ty;
 
-/// **What it does:** Checks for identity operations, e.g. `x + 0`.
-///
-/// **Why is this bad?** This code can be removed without changing the
-/// meaning. So it just obscures what's going on. Delete it mercilessly.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// x / 1 + 0 * 1 - 0 | 0
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for identity operations, e.g. `x + 0`.
+    ///
+    /// **Why is this bad?** This code can be removed without changing the
+    /// meaning. So it just obscures what's going on. Delete it mercilessly.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// x / 1 + 0 * 1 - 0 | 0
+    /// ```
+    #[clippy::version = "pre 0.0.213"]
     pub IDENTITY_OP,
     complexity,
     "using identity operations, e.g. `x + 0` or `y / 1`"
@@ -38,6 +39,22 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for IdentityOp {
             return;
         }
         if let ExprKind::Binary(ref cmp, ref left, ref right) = e.node {
+            // the level of the lint is only looked up for the operations which can be linted
+            let is_candidate = match cmp.node {
+                BinOpKind::Add
+                | BinOpKind::BitOr
+                | BinOpKind::BitXor
+                | BinOpKind::Shl
+                | BinOpKind::Shr
+                | BinOpKind::Sub
+                | BinOpKind::Mul
+                | BinOpKind::Div
+                | BinOpKind::BitAnd => true,
+                _ => false,
+            };
+            if !is_candidate || is_lint_allowed(cx, IDENTITY_OP, e.hir_id) {
+                return;
+            }
             match cmp.node {
                 BinOpKind::Add | BinOpKind::BitOr | BinOpKind::BitXor => {
                     check(cx, left, 0, e.span, right.span);
@@ -0,0 +1,173 @@
+use crate::utils::{match_def_path, match_type, match_var, opt_def_id, paths, span_lint_and_then, walk_ptrs_ty,
+                   SpanlessEq};
+use if_chain::if_chain;
+use rustc::hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::{declare_lint, lint_array};
+use syntax::ast;
+use syntax::source_map::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `Mutex::lock` calls in `if let` expression
+    /// with lock calls in any of the else blocks.
+    ///
+    /// **Why is this bad?** The Mutex lock remains held for the whole
+    /// `if let ... else` block and deadlocks.
+    ///
+    /// **Known problems:** The guard is only known to be released by a call to
+    /// `drop`, other ways of releasing it are ignored.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust,ignore
+    /// if let Ok(thing) = mutex.lock() {
+    ///     do_thing();
+    /// } else {
+    ///     mutex.lock();
+    /// }
+    /// ```
+    /// Should be written
+    /// ```rust,ignore
+    /// let locked = mutex.lock();
+    /// if let Ok(thing) = locked {
+    ///     do_thing(thing);
+    /// } else {
+    ///     use_locked(locked);
+    /// }
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub IF_LET_MUTEX,
+    correctness,
+    "locking a `Mutex` in an `if let` block can cause deadlocks"
+}
+
+#[derive(Copy, Clone)]
+pub struct IfLetMutex;
+
+impl LintPass for IfLetMutex {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(IF_LET_MUTEX)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for IfLetMutex {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if_chain! {
+            if let ExprKind::Match(ref op, ref arms, MatchSource::IfLetDesugar { .. }) = expr.node;
+            if let Some((lock, mutex)) = find_lock(cx, op);
+            then {
+                for arm in arms {
+                    let mut visitor = LockVisitor {
+                        cx,
+                        mutex,
+                        guard: guard_binding(&arm.pats[0]),
+                        released: false,
+                        found: None,
+                    };
+                    visitor.visit_expr(&arm.body);
+                    if let Some(span) = visitor.found {
+                        span_lint_and_then(
+                            cx,
+                            IF_LET_MUTEX,
+                            span,
+                            "calling `Mutex::lock` inside the scope of another `Mutex::lock` causes a deadlock",
+                            |db| {
+                                db.help("move the lock call outside of the `if let ...` expression");
+                                db.span_note(lock.span, "the mutex is already locked by this call");
+                            },
+                        );
+                        return;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Returns the `Mutex::lock` call and the mutex if `expr` is a lock call or a method chain
+/// starting with one
+fn find_lock<'a>(cx: &LateContext<'_, '_>, mut expr: &'a Expr) -> Option<(&'a Expr, &'a Expr)> {
+    while let ExprKind::MethodCall(_, _, ref args) = expr.node {
+        if let Some(mutex) = mutex_lock(cx, expr) {
+            return Some((expr, mutex));
+        }
+        expr = &args[0];
+    }
+    None
+}
+
+/// Returns the mutex if `expr` is a call to `Mutex::lock`
+fn mutex_lock<'a>(cx: &LateContext<'_, '_>, expr: &'a Expr) -> Option<&'a Expr> {
+    if_chain! {
+        if let ExprKind::MethodCall(ref path, _, ref args) = expr.node;
+        if path.ident.name == "lock" && args.len() == 1;
+        if match_type(cx, walk_ptrs_ty(cx.tables.expr_ty(&args[0])), &paths::MUTEX);
+        then {
+            return Some(&args[0]);
+        }
+    }
+    None
+}
+
+/// The binding holding the guard in patterns like `Ok(guard)`
+fn guard_binding(pat: &Pat) -> Option<ast::Name> {
+    if let PatKind::TupleStruct(_, ref pats, None) = pat.node {
+        if let [ref pat] = **pats {
+            if let PatKind::Binding(_, _, ident, None) = pat.node {
+                return Some(ident.name);
+            }
+        }
+    }
+    None
+}
+
+/// Looks for a lock of the mutex while it's still locked
+struct LockVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    mutex: &'tcx Expr,
+    guard: Option<ast::Name>,
+    /// Whether the guard was dropped, the mutex can be locked again after that
+    released: bool,
+    found: Option<Span>,
+}
+
+impl<'a, 'tcx> LockVisitor<'a, 'tcx> {
+    fn is_guard_drop(&self, expr: &Expr) -> bool {
+        if_chain! {
+            if let Some(guard) = self.guard;
+            if let ExprKind::Call(ref func, ref args) = expr.node;
+            if let ExprKind::Path(ref qpath) = func.node;
+            if let Some(def_id) = opt_def_id(self.cx.tables.qpath_def(qpath, func.hir_id));
+            if match_def_path(self.cx.tcx, def_id, &paths::DROP);
+            if args.len() == 1 && match_var(&args[0], guard);
+            then {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for LockVisitor<'a, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        if self.found.is_some() || self.released {
+            return;
+        }
+        if self.is_guard_drop(expr) {
+            self.released = true;
+            return;
+        }
+        if let Some(mutex) = mutex_lock(self.cx, expr) {
+            if SpanlessEq::new(self.cx).eq_expr(self.mutex, mutex) {
+                self.found = Some(expr.span);
+                return;
+            }
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::None
+    }
+}
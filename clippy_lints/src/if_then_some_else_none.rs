@@ -0,0 +1,160 @@
+use crate::utils::msrvs::{self, MsrvStack, RustcVersion};
+use crate::utils::{get_parent_expr, in_macro, is_copy, match_qpath, paths, snippet, span_lint_and_sugg, sugg};
+use if_chain::if_chain;
+use rustc::hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintContext, LintPass};
+use rustc::{declare_lint, lint_array};
+use syntax::ast::Attribute;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for if-else that could be written using `bool::then`.
+    ///
+    /// **Why is this bad?** Looks a little redundant. Using `bool::then` helps
+    /// readability. It is a restriction lint because some prefer the explicit
+    /// `if`.
+    ///
+    /// **Known problems:** `bool::then` needs Rust 1.50 and `bool::then_some` Rust
+    /// 1.62, the lint only suggests what the `msrv` allows. The `else if` branches and
+    /// the values using `?`, `return` or `break` are not linted.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let a = if v.is_empty() {
+    ///     Some(42)
+    /// } else {
+    ///     None
+    /// };
+    /// ```
+    ///
+    /// Could be written:
+    ///
+    /// ```rust
+    /// let a = v.is_empty().then_some(42);
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub IF_THEN_SOME_ELSE_NONE,
+    restriction,
+    "Finds if-else that could be written using `bool::then`"
+}
+
+pub struct IfThenSomeElseNone {
+    msrv: MsrvStack,
+}
+
+impl IfThenSomeElseNone {
+    pub fn new(msrv: Option<RustcVersion>) -> Self {
+        Self {
+            msrv: MsrvStack::new(msrv),
+        }
+    }
+}
+
+impl LintPass for IfThenSomeElseNone {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(IF_THEN_SOME_ELSE_NONE)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for IfThenSomeElseNone {
+    fn enter_lint_attrs(&mut self, cx: &LateContext<'a, 'tcx>, attrs: &'tcx [Attribute]) {
+        self.msrv.enter_lint_attrs(cx.sess(), attrs);
+    }
+
+    fn exit_lint_attrs(&mut self, _: &LateContext<'a, 'tcx>, attrs: &'tcx [Attribute]) {
+        self.msrv.exit_lint_attrs(attrs);
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if !self.msrv.meets(msrvs::BOOL_THEN) || in_macro(expr.span) || is_else_if(cx, expr) {
+            return;
+        }
+
+        if_chain! {
+            if let ExprKind::If(ref cond, ref then, Some(ref otherwise)) = expr.node;
+            if let Some(then_expr) = block_value(then);
+            if let ExprKind::Call(ref then_call, ref then_args) = then_expr.node;
+            if let ExprKind::Path(ref then_path) = then_call.node;
+            if match_qpath(then_path, &paths::OPTION_SOME) && then_args.len() == 1;
+            // `?`, `return` and `break` would exit the closure instead of the function
+            if !has_early_exit(&then_args[0]);
+            if let Some(otherwise_expr) = block_value(otherwise);
+            if let ExprKind::Path(ref otherwise_path) = otherwise_expr.node;
+            if match_qpath(otherwise_path, &paths::OPTION_NONE);
+            then {
+                let cond = sugg::Sugg::hir(cx, cond, "..").maybe_par();
+                let value = &then_args[0];
+                // the value is computed even if the condition is false with `then_some`
+                let sugg = if self.msrv.meets(msrvs::BOOL_THEN_SOME) && is_cheap(cx, value) {
+                    format!("{}.then_some({})", cond, snippet(cx, value.span, ".."))
+                } else {
+                    format!("{}.then(|| {})", cond, snippet(cx, value.span, ".."))
+                };
+                span_lint_and_sugg(
+                    cx,
+                    IF_THEN_SOME_ELSE_NONE,
+                    expr.span,
+                    "this could be simplified with `bool::then`",
+                    "try",
+                    sugg,
+                );
+            }
+        }
+    }
+}
+
+/// Returns true if `expr` is the `else if` of another `if`
+fn is_else_if(cx: &LateContext<'_, '_>, expr: &Expr) -> bool {
+    if let Some(parent) = get_parent_expr(cx, expr) {
+        if let ExprKind::If(_, _, Some(ref els)) = parent.node {
+            return els.id == expr.id;
+        }
+    }
+    false
+}
+
+/// Returns true if `expr` contains a `?`, `return`, `break` or `continue`
+fn has_early_exit(expr: &Expr) -> bool {
+    let mut visitor = EarlyExitVisitor { found: false };
+    visitor.visit_expr(expr);
+    visitor.found
+}
+
+struct EarlyExitVisitor {
+    found: bool,
+}
+
+impl<'tcx> Visitor<'tcx> for EarlyExitVisitor {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        match expr.node {
+            ExprKind::Ret(_)
+            | ExprKind::Break(..)
+            | ExprKind::Continue(_)
+            | ExprKind::Match(_, _, MatchSource::TryDesugar) => self.found = true,
+            _ => walk_expr(self, expr),
+        }
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::None
+    }
+}
+
+/// Returns the value of `{ value }`, a block without statements
+fn block_value(expr: &Expr) -> Option<&Expr> {
+    if let ExprKind::Block(ref block, _) = expr.node {
+        if block.stmts.is_empty() && block.rules == BlockCheckMode::DefaultBlock {
+            return block.expr.as_ref().map(|e| &**e);
+        }
+    }
+    None
+}
+
+/// Returns true if computing `expr` eagerly is free: a literal or a copy of a local
+fn is_cheap(cx: &LateContext<'_, '_>, expr: &Expr) -> bool {
+    match expr.node {
+        ExprKind::Lit(_) => true,
+        ExprKind::Path(QPath::Resolved(None, _)) => is_copy(cx, cx.tables.expr_ty(expr)),
+        _ => false,
+    }
+}
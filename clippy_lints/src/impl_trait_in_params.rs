@@ -0,0 +1,184 @@
+use crate::utils::{multispan_sugg_with_applicability, snippet, span_help_and_lint, span_lint_and_then};
+use rustc::hir::def::Def;
+use rustc::hir::intravisit::{walk_ty, NestedVisitorMap, Visitor};
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::{declare_lint, lint_array};
+use rustc_errors::Applicability;
+use syntax::ast::Ident;
+use syntax::source_map::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `impl Trait` in the parameters of exported
+    /// functions and methods.
+    ///
+    /// The return types are checked too if the `check-impl-trait-in-return`
+    /// configuration value is `true`.
+    ///
+    /// **Why is this bad?** The callers can't name the type of an `impl Trait`
+    /// parameter: they can't use the turbofish syntax, and turning the parameter
+    /// into a named generic later on breaks the calls which do. A returned
+    /// `impl Trait` can't be named by the callers either, e.g. to store it in a
+    /// struct field.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// pub fn foo(x: impl std::fmt::Debug) {
+    ///     println!("{:?}", x);
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// pub fn foo<T: std::fmt::Debug>(x: T) {
+    ///     println!("{:?}", x);
+    /// }
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub IMPL_TRAIT_IN_PARAMS,
+    restriction,
+    "`impl Trait` in the signature of an exported function"
+}
+
+pub struct ImplTraitInParams {
+    check_in_return: bool,
+}
+
+impl ImplTraitInParams {
+    pub fn new(check_in_return: bool) -> Self {
+        Self { check_in_return }
+    }
+
+    fn check_fn(&self, cx: &LateContext<'_, '_>, ident: Ident, decl: &FnDecl, header: FnHeader, generics: &Generics) {
+        check_params(cx, ident, generics);
+        // the return type of an `async fn` is an `impl Future` the user doesn't write
+        if self.check_in_return && header.asyncness == IsAsync::NotAsync {
+            if let FunctionRetTy::Return(ref ret_ty) = decl.output {
+                let mut visitor = ExistentialVisitor(Vec::new());
+                visitor.visit_ty(ret_ty);
+                for span in visitor.0 {
+                    span_help_and_lint(
+                        cx,
+                        IMPL_TRAIT_IN_PARAMS,
+                        span,
+                        "`impl Trait` used as a function return type",
+                        "return a named type instead",
+                    );
+                }
+            }
+        }
+    }
+}
+
+impl LintPass for ImplTraitInParams {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(IMPL_TRAIT_IN_PARAMS)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for ImplTraitInParams {
+    fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx Item) {
+        if let ItemKind::Fn(ref decl, header, ref generics, _) = item.node {
+            if cx.access_levels.is_exported(item.id) {
+                self.check_fn(cx, item.ident, decl, header, generics);
+            }
+        }
+    }
+
+    fn check_impl_item(&mut self, cx: &LateContext<'a, 'tcx>, impl_item: &'tcx ImplItem) {
+        if let ImplItemKind::Method(ref sig, _) = impl_item.node {
+            // the signature of a trait method is given by the trait
+            let impl_def_id = cx.tcx.hir.local_def_id(cx.tcx.hir.get_parent(impl_item.id));
+            if cx.tcx.impl_trait_ref(impl_def_id).is_none() && cx.access_levels.is_exported(impl_item.id) {
+                self.check_fn(cx, impl_item.ident, &sig.decl, sig.header, &impl_item.generics);
+            }
+        }
+    }
+
+    fn check_trait_item(&mut self, cx: &LateContext<'a, 'tcx>, trait_item: &'tcx TraitItem) {
+        if let TraitItemKind::Method(ref sig, _) = trait_item.node {
+            if cx.access_levels.is_exported(trait_item.id) {
+                self.check_fn(cx, trait_item.ident, &sig.decl, sig.header, &trait_item.generics);
+            }
+        }
+    }
+}
+
+/// Lints the `impl Trait` parameters, which are lowered to synthetic generic parameters, and
+/// suggests named generic parameters instead. All the parameters of a function are linted at once,
+/// as the suggestion has to add all their generics to the same list.
+fn check_params(cx: &LateContext<'_, '_>, ident: Ident, generics: &Generics) {
+    let (impl_traits, named): (Vec<_>, Vec<_>) = generics.params.iter().partition(|param| match param.kind {
+        GenericParamKind::Type {
+            synthetic: Some(SyntheticTyParamKind::ImplTrait),
+            ..
+        } => true,
+        _ => false,
+    });
+    let first = match impl_traits.first() {
+        Some(first) => first,
+        None => return,
+    };
+
+    let mut names = fresh_names(&named);
+    let mut new_generics = Vec::new();
+    let mut sugg = Vec::new();
+    for param in &impl_traits {
+        let name = names.next().expect("there are infinitely many names");
+        let bounds = snippet(cx, param.span, "..");
+        new_generics.push(format!("{}: {}", name, bounds.trim_start_matches("impl").trim_start()));
+        sugg.push((param.span, name));
+    }
+    let generics_sugg = if named.is_empty() {
+        (ident.span.shrink_to_hi(), format!("<{}>", new_generics.join(", ")))
+    } else {
+        let generics_snip = snippet(cx, generics.span, "<..>");
+        let params_snip = generics_snip.trim_end_matches('>').trim_end().trim_end_matches(',');
+        (generics.span, format!("{}, {}>", params_snip, new_generics.join(", ")))
+    };
+    sugg.insert(0, generics_sugg);
+
+    let msg = if impl_traits.len() == 1 {
+        "`impl Trait` used as a function parameter"
+    } else {
+        "`impl Trait` used as function parameters"
+    };
+    span_lint_and_then(cx, IMPL_TRAIT_IN_PARAMS, first.span, msg, |db| {
+        for param in &impl_traits[1..] {
+            db.span_label(param.span, "");
+        }
+        let help = if impl_traits.len() == 1 {
+            "add a type parameter"
+        } else {
+            "add type parameters"
+        };
+        multispan_sugg_with_applicability(db, help.to_owned(), Applicability::MaybeIncorrect, sugg);
+    });
+}
+
+/// Returns the names `T`, `T1`, `T2`.. which aren't already used by the generic parameters
+fn fresh_names(params: &[&GenericParam]) -> impl Iterator<Item = String> {
+    let used: Vec<_> = params.iter().map(|param| param.name.ident().name).collect();
+    (0..)
+        .map(|i| if i == 0 { "T".to_owned() } else { format!("T{}", i) })
+        .filter(move |name| !used.iter().any(|used| *used == name.as_str()))
+}
+
+/// Collects the spans of the `impl Trait` types, which are lowered to existential types
+struct ExistentialVisitor(Vec<Span>);
+
+impl<'tcx> Visitor<'tcx> for ExistentialVisitor {
+    fn visit_ty(&mut self, ty: &'tcx Ty) {
+        if let TyKind::Path(QPath::Resolved(_, ref path)) = ty.node {
+            if let Def::Existential(_) = path.def {
+                self.0.push(ty.span);
+            }
+        }
+        walk_ty(self, ty);
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::None
+    }
+}
@@ -0,0 +1,117 @@
+//! lint on a `msrv` configuration value disagreeing with the `rust-version` of the manifest
+
+use rustc::lint::{EarlyContext, EarlyLintPass, LintArray, LintPass};
+use rustc::{declare_lint, lint_array};
+use std::env;
+use std::fs;
+use std::path::Path;
+use syntax::ast::*;
+use crate::utils::conf::{check_rust_version, MsrvMismatch};
+use crate::utils::span_help_and_lint;
+
+use toml;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks that the `msrv` configuration value of `clippy.toml` and the
+    /// `rust-version` field of `Cargo.toml` are either both missing or set to the same version.
+    ///
+    /// **Why is this bad?** The `rust-version` is the MSRV advertised to the users of the crate,
+    /// and the `msrv` is the one the lints take into account. Without the `msrv`, the lints
+    /// suggest code which needs the latest Rust version. With a different `rust-version`, the
+    /// advertised MSRV is either not the one the crate is checked against or isn't enforced by
+    /// Cargo.
+    ///
+    /// **Known problems:** A `rust-version` inherited from the workspace is not checked.
+    ///
+    /// **Example:**
+    /// ```toml
+    /// # clippy.toml
+    /// msrv = "1.30"
+    /// ```
+    /// ```toml
+    /// # Cargo.toml
+    /// [package]
+    /// name = "foo"
+    /// version = "0.1.0"
+    /// ```
+    /// Use instead:
+    /// ```toml
+    /// # Cargo.toml
+    /// [package]
+    /// name = "foo"
+    /// version = "0.1.0"
+    /// rust-version = "1.30"
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub INCONSISTENT_RUST_VERSION,
+    cargo,
+    "a `msrv` configuration value missing from or contradicting the `rust-version` of `Cargo.toml`"
+}
+
+pub struct InconsistentRustVersion {
+    msrv: Option<String>,
+}
+
+impl InconsistentRustVersion {
+    pub fn new(msrv: Option<String>) -> Self {
+        Self { msrv }
+    }
+}
+
+impl LintPass for InconsistentRustVersion {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(INCONSISTENT_RUST_VERSION)
+    }
+}
+
+impl EarlyLintPass for InconsistentRustVersion {
+    fn check_crate(&mut self, cx: &EarlyContext<'_>, krate: &Crate) {
+        // only set when the crate is compiled by Cargo
+        let manifest = match env::var("CARGO_MANIFEST_DIR")
+            .ok()
+            .and_then(|dir| fs::read_to_string(Path::new(&dir).join("Cargo.toml")).ok())
+            .and_then(|manifest| manifest.parse::<toml::Value>().ok())
+        {
+            Some(manifest) => manifest,
+            None => return,
+        };
+        let rust_version = match manifest.get("package").and_then(|package| package.get("rust-version")) {
+            // `rust-version.workspace = true` is not a version
+            Some(rust_version) => match rust_version.as_str() {
+                Some(rust_version) => Some(rust_version),
+                None => return,
+            },
+            None => None,
+        };
+
+        let (msg, help) = match check_rust_version(self.msrv.as_ref().map(String::as_str), rust_version) {
+            Some(MsrvMismatch::MissingRustVersion(msrv)) => (
+                format!(
+                    "the `msrv` configuration value is `{}` but `Cargo.toml` has no `rust-version`",
+                    msrv
+                ),
+                format!("add `rust-version = \"{}\"` to the `[package]` table of `Cargo.toml`", msrv),
+            ),
+            Some(MsrvMismatch::MissingMsrv(rust_version)) => (
+                format!(
+                    "`Cargo.toml` has a `rust-version` of `{}` but the `msrv` configuration value is not set",
+                    rust_version
+                ),
+                format!(
+                    "add `msrv = \"{}\"` to `clippy.toml`, so that the lints don't suggest code needing a more \
+                     recent compiler",
+                    rust_version
+                ),
+            ),
+            Some(MsrvMismatch::Different { msrv, rust_version }) => (
+                format!(
+                    "the `msrv` configuration value `{}` differs from the `rust-version` of `Cargo.toml`, `{}`",
+                    msrv, rust_version
+                ),
+                "set them to the same version".to_owned(),
+            ),
+            None => return,
+        };
+        span_help_and_lint(cx, INCONSISTENT_RUST_VERSION, krate.span, &msg, &help);
+    }
+}
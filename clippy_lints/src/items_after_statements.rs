@@ -4,31 +4,32 @@ use matches::matches;
 use rustc::lint::{EarlyContext, EarlyLintPass, LintArray, LintPass};
 use rustc::{declare_lint, lint_array};
 use syntax::ast::*;
-use crate::utils::{in_macro, span_lint};
+use crate::utils::{in_macro, span_help_and_lint};
 
-/// **What it does:** Checks for items declared after some statement in a block.
-///
-/// **Why is this bad?** Items live for the entire scope they are declared
-/// in. But statements are processed in order. This might cause confusion as
-/// it's hard to figure out which item is meant in a statement.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// fn foo() {
-///     println!("cake");
-/// }
-///
-/// fn main() {
-///     foo(); // prints "foo"
-///     fn foo() {
-///         println!("foo");
-///     }
-///     foo(); // prints "foo"
-/// }
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for items declared after some statement in a block.
+    ///
+    /// **Why is this bad?** Items live for the entire scope they are declared
+    /// in. But statements are processed in order. This might cause confusion as
+    /// it's hard to figure out which item is meant in a statement.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// fn foo() {
+    ///     println!("cake");
+    /// }
+    ///
+    /// fn main() {
+    ///     foo(); // prints "foo"
+    ///     fn foo() {
+    ///         println!("foo");
+    ///     }
+    ///     foo(); // prints "foo"
+    /// }
+    /// ```
+    #[clippy::version = "pre 0.0.213"]
     pub ITEMS_AFTER_STATEMENTS,
     pedantic,
     "blocks where an item comes after a statement"
@@ -64,12 +65,13 @@ impl EarlyLintPass for ItemsAfterStatements {
                     // do not lint `macro_rules`, but continue processing further statements
                     continue;
                 }
-                span_lint(
+                span_help_and_lint(
                     cx,
                     ITEMS_AFTER_STATEMENTS,
                     it.span,
                     "adding items after statements is confusing, since items exist from the \
                      start of the scope",
+                    "consider moving the item to the top of the block, or out of the function",
                 );
             }
         }
@@ -0,0 +1,95 @@
+use crate::consts::{constant, Constant};
+use crate::utils::{has_drive_prefix, in_macro, match_type, paths, span_lint_and_then, walk_ptrs_ty};
+use if_chain::if_chain;
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::{declare_lint, lint_array};
+use rustc_errors::Applicability;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for [join](https://doc.rust-lang.org/std/path/struct.Path.html#method.join)
+    /// calls on `Path` or `PathBuf` with an absolute path, i.e. starting with a
+    /// file system root or a drive prefix on Windows.
+    ///
+    /// **Why is this bad?** Joining an absolute path replaces the whole receiver
+    /// instead of appending to it, the result is just the argument.
+    ///
+    /// **Known problems:** Only the string literals and constants are checked.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// use std::path::Path;
+    ///
+    /// let path = Path::new("/usr").join("/bin");
+    /// assert_eq!(path, Path::new("/bin"));
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// use std::path::Path;
+    ///
+    /// let path = Path::new("/usr").join("bin");
+    /// assert_eq!(path, Path::new("/usr/bin"));
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub JOIN_ABSOLUTE_PATHS,
+    correctness,
+    "calling `join` with an absolute path on a `Path` discards the receiver"
+}
+
+#[derive(Copy, Clone)]
+pub struct JoinAbsolutePaths;
+
+impl LintPass for JoinAbsolutePaths {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(JOIN_ABSOLUTE_PATHS)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for JoinAbsolutePaths {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if_chain! {
+            if !in_macro(expr.span);
+            if let ExprKind::MethodCall(ref path, _, ref args) = expr.node;
+            if path.ident.name == "join";
+            if args.len() == 2;
+            let recv_ty = walk_ptrs_ty(cx.tables.expr_ty(&args[0]));
+            if match_type(cx, recv_ty, &paths::PATH) || match_type(cx, recv_ty, &paths::PATH_BUF);
+            if let Some((Constant::Str(joined), _)) = constant(cx, cx.tables, &args[1]);
+            then {
+                let arg = &args[1];
+                if joined.starts_with('/') || joined.starts_with('\\') {
+                    span_lint_and_then(
+                        cx,
+                        JOIN_ABSOLUTE_PATHS,
+                        arg.span,
+                        "argument to `join` starts with a path separator",
+                        |db| {
+                            db.note("joining a path starting with a separator replaces the path instead");
+                            // a constant isn't replaced by a literal
+                            if let ExprKind::Lit(_) = arg.node {
+                                db.span_suggestion_with_applicability(
+                                    arg.span,
+                                    "if this is unintentional, try removing the starting separator",
+                                    format!("\"{}\"", joined.trim_start_matches(|c| c == '/' || c == '\\')),
+                                    Applicability::MaybeIncorrect,
+                                );
+                            }
+                            db.help("if this is intentional, consider using `Path::new` or `PathBuf::from` instead");
+                        },
+                    );
+                } else if has_drive_prefix(&joined) {
+                    span_lint_and_then(
+                        cx,
+                        JOIN_ABSOLUTE_PATHS,
+                        arg.span,
+                        "argument to `join` starts with a drive prefix",
+                        |db| {
+                            db.note("joining a path with a drive prefix replaces the path instead");
+                            db.help("consider joining a path relative to the current one instead");
+                        },
+                    );
+                }
+            }
+        }
+    }
+}
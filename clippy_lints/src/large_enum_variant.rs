@@ -1,42 +1,43 @@
 //! lint when there is a large size difference between variants on an enum
 
-use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintContext, LintPass};
 use rustc::{declare_lint, lint_array};
 use rustc::hir::*;
-use crate::utils::{snippet_opt, span_lint_and_then};
+use syntax::ast::Attribute;
+use crate::utils::{snippet_opt, span_lint_and_then, LimitStack};
 use rustc::ty::layout::LayoutOf;
 
-/// **What it does:** Checks for large size differences between variants on
-/// `enum`s.
-///
-/// **Why is this bad?** Enum size is bounded by the largest variant. Having a
-/// large variant
-/// can penalize the memory layout of that enum.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// enum Test {
-///    A(i32),
-///    B([i32; 8000]),
-/// }
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for large size differences between variants on
+    /// `enum`s.
+    ///
+    /// **Why is this bad?** Enum size is bounded by the largest variant. Having a
+    /// large variant
+    /// can penalize the memory layout of that enum.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// enum Test {
+    ///    A(i32),
+    ///    B([i32; 8000]),
+    /// }
+    /// ```
+    #[clippy::version = "0.0.113"]
     pub LARGE_ENUM_VARIANT,
     perf,
     "large size difference between variants on an enum"
 }
 
-#[derive(Copy, Clone)]
 pub struct LargeEnumVariant {
-    maximum_size_difference_allowed: u64,
+    maximum_size_difference_allowed: LimitStack,
 }
 
 impl LargeEnumVariant {
     pub fn new(maximum_size_difference_allowed: u64) -> Self {
         Self {
-            maximum_size_difference_allowed,
+            maximum_size_difference_allowed: LimitStack::new(maximum_size_difference_allowed),
         }
     }
 }
@@ -48,6 +49,16 @@ impl LintPass for LargeEnumVariant {
 }
 
 impl<'a, 'tcx> LateLintPass<'a, 'tcx> for LargeEnumVariant {
+    fn enter_lint_attrs(&mut self, cx: &LateContext<'a, 'tcx>, attrs: &'tcx [Attribute]) {
+        self.maximum_size_difference_allowed
+            .push_attrs(cx.sess(), attrs, "large_enum_variant");
+    }
+
+    fn exit_lint_attrs(&mut self, cx: &LateContext<'a, 'tcx>, attrs: &'tcx [Attribute]) {
+        self.maximum_size_difference_allowed
+            .pop_attrs(cx.sess(), attrs, "large_enum_variant");
+    }
+
     fn check_item(&mut self, cx: &LateContext<'_, '_>, item: &Item) {
         let did = cx.tcx.hir.local_def_id(item.id);
         if let ItemKind::Enum(ref def, _) = item.node {
@@ -79,7 +90,7 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for LargeEnumVariant {
             if let (Some(smallest), Some(largest)) = (smallest_variant, largest_variant) {
                 let difference = largest.0 - smallest.0;
 
-                if difference > self.maximum_size_difference_allowed {
+                if difference > self.maximum_size_difference_allowed.limit() {
                     let (i, variant) = largest.1;
 
                     span_lint_and_then(
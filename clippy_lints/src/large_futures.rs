@@ -0,0 +1,88 @@
+use crate::utils::{in_macro, is_direct_expn_of, snippet, span_lint_and_then};
+use if_chain::if_chain;
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::{declare_lint, lint_array};
+use rustc_errors::Applicability;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `await!`s of futures larger than the
+    /// `future-size-threshold` configuration value, 16384 bytes by default.
+    ///
+    /// **Why is this bad?** A future is stored in the future awaiting it, or on the
+    /// stack of the thread polling it: a large future bloats all its callers.
+    /// Boxing it with `Box::pin` only stores a pointer.
+    ///
+    /// **Known problems:** The size of generic futures can't be computed, they are
+    /// not linted.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// async fn large_future(_x: [u8; 16 * 1024]) {}
+    ///
+    /// async fn caller() {
+    ///     await!(large_future([0; 16 * 1024]));
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// async fn large_future(_x: [u8; 16 * 1024]) {}
+    ///
+    /// async fn caller() {
+    ///     await!(Box::pin(large_future([0; 16 * 1024])));
+    /// }
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub LARGE_FUTURES,
+    pedantic,
+    "large future awaited without being boxed"
+}
+
+#[derive(Copy, Clone)]
+pub struct LargeFutures {
+    future_size_threshold: u64,
+}
+
+impl LargeFutures {
+    pub fn new(future_size_threshold: u64) -> Self {
+        Self { future_size_threshold }
+    }
+}
+
+impl LintPass for LargeFutures {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(LARGE_FUTURES)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for LargeFutures {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        // `await!` binds its argument to a local before polling it
+        if_chain! {
+            if !in_macro(expr.span);
+            if let Some(Node::Local(local)) = cx.tcx.hir.find(cx.tcx.hir.get_parent_node(expr.id));
+            if local.init.as_ref().map_or(false, |init| init.id == expr.id);
+            if is_direct_expn_of(local.span, "await").is_some();
+            // the type of an `async fn` call is opaque, its layout is the one of the generator
+            let param_env = cx.param_env.with_reveal_all();
+            if let Ok(layout) = cx.tcx.layout_of(param_env.and(cx.tables.expr_ty(expr)));
+            if layout.size.bytes() > self.future_size_threshold;
+            then {
+                span_lint_and_then(
+                    cx,
+                    LARGE_FUTURES,
+                    expr.span,
+                    &format!("large future with a size of {} bytes", layout.size.bytes()),
+                    |db| {
+                        db.span_suggestion_with_applicability(
+                            expr.span,
+                            "consider `Box::pin` on it",
+                            format!("Box::pin({})", snippet(cx, expr.span, "..")),
+                            Applicability::MachineApplicable,
+                        );
+                    },
+                );
+            }
+        }
+    }
+}
@@ -1,5 +1,23 @@
 // error-pattern:cargo-clippy
 
+//! The lints of Clippy, and the functions to register them.
+//!
+//! Besides `cargo clippy`, the lints can be run by a custom `rustc` driver:
+//!
+//! 1. read the configuration with [`read_conf`], which looks for the `clippy.toml` files like
+//!    Clippy does, or build it programmatically with [`utils::conf::read_str`] or
+//!    `Conf::default()`,
+//! 2. register the passes running before macro expansion with [`register_pre_expansion_lints`],
+//! 3. register all the other passes and the lint groups with [`register_plugins`].
+//!
+//! The groups (`clippy`, `clippy_pedantic`, ...) are selected like any other lint, with the lint
+//! levels of the session, e.g. `-W clippy_pedantic`.
+//!
+//! [`read_conf`]: fn.read_conf.html
+//! [`utils::conf::read_str`]: utils/conf/fn.read_str.html
+//! [`register_pre_expansion_lints`]: fn.register_pre_expansion_lints.html
+//! [`register_plugins`]: fn.register_plugins.html
+
 #![feature(box_syntax)]
 #![feature(rustc_private)]
 #![feature(slice_patterns)]
@@ -15,58 +33,115 @@ use rustc_plugin;
 use rustc;
 
 
+/// Declares a Clippy lint. The documentation of the lint goes inside the macro, before the name,
+/// followed by the version of Clippy the lint was added in:
+///
+/// ```rust,ignore
+/// declare_clippy_lint! {
+///     /// **What it does:** ...
+///     #[clippy::version = "0.0.213"]
+///     pub LINT_NAME,
+///     style,
+///     "description"
+/// }
+/// ```
+///
+/// Besides the `LINT_NAME` static, the macro declares a `LINT_NAME` module with the [`LintInfo`]
+/// of the lint, which `cargo clippy --explain` prints.
+///
+/// [`LintInfo`]: struct.LintInfo.html
 macro_rules! declare_clippy_lint {
-    { pub $name:tt, style, $description:tt } => {
+    { @lint $name:tt, style, $description:tt } => {
         declare_lint! { pub $name, Warn, $description, report_in_external_macro: true }
     };
-    { pub $name:tt, correctness, $description:tt } => {
+    { @lint $name:tt, correctness, $description:tt } => {
         declare_lint! { pub $name, Deny, $description, report_in_external_macro: true }
     };
-    { pub $name:tt, complexity, $description:tt } => {
+    { @lint $name:tt, suspicious, $description:tt } => {
+        declare_lint! { pub $name, Warn, $description, report_in_external_macro: true }
+    };
+    { @lint $name:tt, complexity, $description:tt } => {
         declare_lint! { pub $name, Warn, $description, report_in_external_macro: true }
     };
-    { pub $name:tt, perf, $description:tt } => {
+    { @lint $name:tt, perf, $description:tt } => {
         declare_lint! { pub $name, Warn, $description, report_in_external_macro: true }
     };
-    { pub $name:tt, pedantic, $description:tt } => {
+    { @lint $name:tt, pedantic, $description:tt } => {
         declare_lint! { pub $name, Allow, $description, report_in_external_macro: true }
     };
-    { pub $name:tt, restriction, $description:tt } => {
+    { @lint $name:tt, restriction, $description:tt } => {
         declare_lint! { pub $name, Allow, $description, report_in_external_macro: true }
     };
-    { pub $name:tt, cargo, $description:tt } => {
+    { @lint $name:tt, cargo, $description:tt } => {
         declare_lint! { pub $name, Allow, $description, report_in_external_macro: true }
     };
-    { pub $name:tt, nursery, $description:tt } => {
+    { @lint $name:tt, nursery, $description:tt } => {
         declare_lint! { pub $name, Allow, $description, report_in_external_macro: true }
     };
-    { pub $name:tt, internal, $description:tt } => {
+    { @lint $name:tt, internal, $description:tt } => {
         declare_lint! { pub $name, Allow, $description, report_in_external_macro: true }
     };
-    { pub $name:tt, internal_warn, $description:tt } => {
+    { @lint $name:tt, internal_warn, $description:tt } => {
         declare_lint! { pub $name, Warn, $description, report_in_external_macro: true }
     };
+    { @info $name:tt, $category:tt, $description:tt, $explanation:expr, $version:expr } => {
+        declare_clippy_lint! { @lint $name, $category, $description }
+
+        #[doc(hidden)]
+        #[allow(non_snake_case)]
+        pub mod $name {
+            pub static INFO: $crate::LintInfo = $crate::LintInfo {
+                name: stringify!($name),
+                category: stringify!($category),
+                explanation: $explanation,
+                version: $version,
+            };
+        }
+    };
+    { $(#[doc = $doc:expr])* #[clippy::version = $version:expr] pub $name:tt, $category:tt, $description:tt } => {
+        declare_clippy_lint! { @info $name, $category, $description, concat!($($doc, "\n",)*), $version }
+    };
+}
+
+/// The documentation of a lint, as declared with `declare_clippy_lint!`.
+pub struct LintInfo {
+    /// The name of the lint, in upper case
+    pub name: &'static str,
+    pub category: &'static str,
+    /// The doc comment of the lint, each line still starts with the space following `///`
+    pub explanation: &'static str,
+    /// The version of Clippy the lint was added in, `pre 0.0.213` for the lints which are older
+    /// than the tracking of the versions
+    pub version: &'static str,
 }
 
 pub mod consts;
+pub mod explain;
 #[macro_use]
 pub mod utils;
 
 // begin lints modules, do not remove this comment, it’s used in `update_lints`
+pub mod almost_complete_range;
 pub mod approx_const;
 pub mod arithmetic;
+pub mod arithmetic_side_effects;
 pub mod assign_ops;
 pub mod attrs;
 pub mod bit_mask;
 pub mod blacklisted_name;
 pub mod block_in_if_condition;
+pub mod bool_to_int_with_if;
 pub mod booleans;
 pub mod bytecount;
 pub mod collapsible_if;
+pub mod const_comparisons;
 pub mod const_static_lifetime;
 pub mod copies;
 pub mod copy_iterator;
+pub mod create_dir;
 pub mod cyclomatic_complexity;
+pub mod default_constructed_unit_structs;
+pub mod default_numeric_fallback;
 pub mod default_trait_access;
 pub mod derive;
 pub mod doc;
@@ -76,6 +151,7 @@ pub mod drop_forget_ref;
 pub mod duration_subsec;
 pub mod else_if_without_else;
 pub mod empty_enum;
+pub mod empty_structs_with_brackets;
 pub mod entry;
 pub mod enum_clike;
 pub mod enum_glob_use;
@@ -86,15 +162,26 @@ pub mod escape;
 pub mod eta_reduction;
 pub mod eval_order_dependence;
 pub mod excessive_precision;
+pub mod exhaustive_items;
+pub mod exit;
+pub mod explicit_auto_deref;
 pub mod explicit_write;
 pub mod fallible_impl_from;
+pub mod feature_name;
+pub mod field_reassign_with_default;
+pub mod floating_point_arithmetic;
 pub mod format;
+pub mod format_args;
 pub mod formatting;
 pub mod functions;
 pub mod identity_conversion;
 pub mod identity_op;
+pub mod if_let_mutex;
 pub mod if_let_redundant_pattern_matching;
 pub mod if_not_else;
+pub mod if_then_some_else_none;
+pub mod impl_trait_in_params;
+pub mod inconsistent_rust_version;
 pub mod indexing_slicing;
 pub mod infallible_destructuring_match;
 pub mod infinite_iter;
@@ -103,12 +190,23 @@ pub mod inline_fn_without_body;
 pub mod int_plus_one;
 pub mod invalid_ref;
 pub mod items_after_statements;
+pub mod join_absolute_paths;
 pub mod large_enum_variant;
+pub mod large_futures;
 pub mod len_zero;
 pub mod let_if_seq;
 pub mod lifetimes;
+pub mod lint_groups_priority;
 pub mod literal_representation;
 pub mod loops;
+pub mod macro_use;
+pub mod manual_async_fn;
+pub mod manual_async_recursion;
+pub mod manual_bits;
+pub mod manual_is_ascii_check;
+pub mod manual_let_else;
+pub mod manual_retain;
+pub mod manual_string_new;
 pub mod map_clone;
 pub mod map_unit_fn;
 pub mod matches;
@@ -117,16 +215,20 @@ pub mod methods;
 pub mod minmax;
 pub mod misc;
 pub mod misc_early;
+pub mod missing_assert_message;
 pub mod missing_doc;
 pub mod missing_inline;
 pub mod multiple_crate_versions;
 pub mod mut_mut;
 pub mod mut_reference;
+pub mod mutable_debug_assertion;
 pub mod mutex_atomic;
 pub mod needless_bool;
 pub mod needless_borrow;
 pub mod needless_borrowed_ref;
 pub mod needless_continue;
+pub mod needless_else;
+pub mod needless_late_init;
 pub mod needless_pass_by_value;
 pub mod needless_update;
 pub mod neg_cmp_op_on_partial_ord;
@@ -135,33 +237,58 @@ pub mod new_without_default;
 pub mod no_effect;
 pub mod non_copy_const;
 pub mod non_expressive_names;
+pub mod non_octal_unix_permissions;
 pub mod ok_if_let;
 pub mod open_options;
+pub mod option_env_unwrap;
 pub mod overflow_check_conditional;
+pub mod panic_in_result_fn;
 pub mod panic_unimplemented;
 pub mod partialeq_ne_impl;
+pub mod path_buf_push_overwrite;
+pub mod pattern_type_mismatch;
+pub mod pow_of_two;
 pub mod precedence;
 pub mod ptr;
 pub mod question_mark;
 pub mod ranges;
+pub mod read_zero_byte_vec;
+pub mod readonly_write_lock;
+pub mod recursive_format_impl;
+pub mod redundant_else;
 pub mod redundant_field_names;
+pub mod redundant_type_annotations;
+pub mod ref_option_ref;
 pub mod reference;
 pub mod regex;
 pub mod replace_consts;
 pub mod returns;
 pub mod serde_api;
 pub mod shadow;
+pub mod significant_drop_in_scrutinee;
+pub mod size_of_in_element_count;
+pub mod slow_vector_initialization;
 pub mod strings;
+pub mod struct_field_names;
+pub mod suspicious_operation_groupings;
 pub mod suspicious_trait_impl;
 pub mod swap;
+pub mod swapped_arguments;
 pub mod temporary_assignment;
+pub mod trailing_empty_array;
+pub mod trait_bounds;
 pub mod transmute;
 pub mod trivially_copy_pass_by_ref;
+pub mod tuple_array_conversions;
 pub mod types;
+pub mod unbuffered_file_io;
 pub mod unicode;
+pub mod unnecessary_struct_initialization;
 pub mod unsafe_removed_from_name;
+pub mod unused_async;
 pub mod unused_io_amount;
 pub mod unused_label;
+pub mod unused_self;
 pub mod unwrap;
 pub mod use_self;
 pub mod vec;
@@ -169,38 +296,51 @@ pub mod write;
 pub mod zero_div_zero;
 // end lints modules, do not remove this comment, it’s used in `update_lints`
 
-use crate::utils::conf::Conf;
+pub use crate::utils::conf::Conf;
 
 mod reexport {
     crate use syntax::ast::{Name, NodeId};
 }
 
+/// Registers the passes which run before macro expansion.
+///
+/// They are not part of the plugin registry, so a driver has to register them in the lint store
+/// of the session itself.
 pub fn register_pre_expansion_lints(session: &rustc::session::Session, store: &mut rustc::lint::LintStore, conf: &Conf) {
     store.register_pre_expansion_pass(Some(session), box write::Pass);
-    store.register_pre_expansion_pass(Some(session), box redundant_field_names::RedundantFieldNames);
+    let msrv = conf.msrv.as_ref().and_then(|msrv| utils::msrvs::RustcVersion::parse(msrv));
+    store.register_pre_expansion_pass(Some(session), box redundant_field_names::RedundantFieldNames::new(msrv));
     store.register_pre_expansion_pass(Some(session), box non_expressive_names::NonExpressiveNames {
         single_char_binding_names_threshold: conf.single_char_binding_names_threshold,
     });
+    store.register_pre_expansion_pass(
+        Some(session),
+        box missing_assert_message::MissingAssertMessage::new(conf.missing_assert_message_in_tests),
+    );
 }
 
+/// Reads the configuration file given with the `conf_file` plugin argument, or else the
+/// `clippy.toml` files of the crate and of its parent directories.
+///
+/// The errors are reported to the session, the default values are used for the faulty keys.
 pub fn read_conf(reg: &rustc_plugin::Registry<'_>) -> Conf {
     match utils::conf::file_from_args(reg.args()) {
         Ok(file_name) => {
-            // if the user specified a file, it must exist, otherwise default to `clippy.toml` but
-            // do not require the file to exist
-            let file_name = if let Some(file_name) = file_name {
-                Some(file_name)
+            // if the user specified a file, it must exist, otherwise default to the `clippy.toml`
+            // files of the crate and of its parent directories but do not require them to exist
+            let file_names = if let Some(file_name) = file_name {
+                vec![file_name]
             } else {
-                match utils::conf::lookup_conf_file() {
-                    Ok(path) => path,
+                match utils::conf::lookup_conf_files() {
+                    Ok(paths) => paths,
                     Err(error) => {
                         reg.sess.struct_err(&format!("error finding Clippy's configuration file: {}", error)).emit();
-                        None
+                        Vec::new()
                     }
                 }
             };
 
-            let file_name = file_name.map(|file_name| if file_name.is_relative() {
+            let file_names: Vec<_> = file_names.into_iter().map(|file_name| if file_name.is_relative() {
                 reg.sess
                     .local_crate_source_file
                     .as_ref()
@@ -209,13 +349,13 @@ pub fn read_conf(reg: &rustc_plugin::Registry<'_>) -> Conf {
                     .join(file_name)
             } else {
                 file_name
-            });
+            }).collect();
 
-            let (conf, errors) = utils::conf::read(file_name.as_ref().map(|p| p.as_ref()));
+            let (conf, errors) = utils::conf::read_merged(&file_names);
 
             // all conf errors are non-fatal, we just use the default conf in case of error
-            for error in errors {
-                reg.sess.struct_err(&format!("error reading Clippy's configuration file `{}`: {}", file_name.as_ref().and_then(|p| p.to_str()).unwrap_or(""), error)).emit();
+            for (file_name, error) in errors {
+                reg.sess.struct_err(&format!("error reading Clippy's configuration file `{}`: {}", file_name.display(), error)).emit();
             }
 
             conf
@@ -229,6 +369,8 @@ pub fn read_conf(reg: &rustc_plugin::Registry<'_>) -> Conf {
     }
 }
 
+/// Registers all the late and early lint passes, the lint groups and the renamed and removed
+/// lints of Clippy.
 #[rustfmt::skip]
 pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
     let mut store = reg.sess.lint_store.borrow_mut();
@@ -270,6 +412,24 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
     );
     // end deprecated lints, do not remove this comment, it’s used in `update_lints`
 
+    let msrv = conf.msrv.as_ref().and_then(|msrv| {
+        let version = utils::msrvs::RustcVersion::parse(msrv);
+        if version.is_none() {
+            reg.sess.err(&format!("error reading Clippy's configuration file: `{}` is not a valid Rust version", msrv));
+        }
+        version
+    });
+    let enforced_endianness = conf.enforced_endianness.as_ref().and_then(|endianness| {
+        let parsed = methods::Endianness::parse(endianness);
+        if parsed.is_none() {
+            reg.sess.err(&format!(
+                "error reading Clippy's configuration file: `{}` is not a valid endianness, expected `little` or `big`",
+                endianness
+            ));
+        }
+        parsed
+    });
+
     reg.register_late_lint_pass(box serde_api::Serde);
     reg.register_early_lint_pass(box utils::internal_lints::Clippy);
     reg.register_late_lint_pass(box utils::internal_lints::LintWithoutLintPass::default());
@@ -279,7 +439,10 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
     reg.register_late_lint_pass(box types::TypePass);
     reg.register_late_lint_pass(box booleans::NonminimalBool);
     reg.register_late_lint_pass(box eq_op::EqOp);
+    reg.register_late_lint_pass(box suspicious_operation_groupings::SuspiciousOperationGroupings);
+    reg.register_late_lint_pass(box floating_point_arithmetic::FloatingPointArithmetic);
     reg.register_early_lint_pass(box enum_variants::EnumVariantNames::new(conf.enum_variant_name_threshold));
+    reg.register_early_lint_pass(box struct_field_names::StructFieldNames::new(conf.struct_field_name_threshold));
     reg.register_late_lint_pass(box enum_glob_use::EnumGlobUse);
     reg.register_late_lint_pass(box enum_clike::UnportableVariant);
     reg.register_late_lint_pass(box excessive_precision::ExcessivePrecision);
@@ -304,7 +467,7 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
     reg.register_late_lint_pass(box unicode::Unicode);
     reg.register_late_lint_pass(box strings::StringAdd);
     reg.register_early_lint_pass(box returns::ReturnPass);
-    reg.register_late_lint_pass(box methods::Pass);
+    reg.register_late_lint_pass(box methods::Pass::new(msrv, enforced_endianness));
     reg.register_late_lint_pass(box shadow::Pass);
     reg.register_late_lint_pass(box types::LetPass);
     reg.register_late_lint_pass(box types::UnitCmp);
@@ -312,7 +475,7 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
     reg.register_late_lint_pass(box lifetimes::LifetimePass);
     reg.register_late_lint_pass(box entry::HashMapLint);
     reg.register_late_lint_pass(box ranges::Pass);
-    reg.register_late_lint_pass(box types::CastPass);
+    reg.register_late_lint_pass(box types::CastPass::new(msrv));
     reg.register_late_lint_pass(box types::TypeComplexityPass::new(conf.type_complexity_threshold));
     reg.register_late_lint_pass(box matches::MatchPass);
     reg.register_late_lint_pass(box minmax::MinMaxPass);
@@ -357,7 +520,63 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
     reg.register_early_lint_pass(box doc::Doc::new(conf.doc_valid_idents.clone()));
     reg.register_late_lint_pass(box neg_multiply::NegMultiply);
     reg.register_early_lint_pass(box unsafe_removed_from_name::UnsafeNameRemoval);
-    reg.register_late_lint_pass(box mem_forget::MemForget);
+    reg.register_late_lint_pass(box mem_forget::MemForget::new(conf.allowed_forget_types.clone()));
+    reg.register_late_lint_pass(box exit::Pass);
+    reg.register_late_lint_pass(box default_numeric_fallback::DefaultNumericFallback);
+    reg.register_late_lint_pass(box if_then_some_else_none::IfThenSomeElseNone::new(msrv));
+    reg.register_late_lint_pass(box pattern_type_mismatch::PatternTypeMismatch);
+    reg.register_late_lint_pass(box unused_async::UnusedAsync);
+    reg.register_late_lint_pass(box unused_self::UnusedSelf);
+    reg.register_late_lint_pass(box trait_bounds::TraitBounds);
+    reg.register_late_lint_pass(box exhaustive_items::ExhaustiveItems::new(msrv));
+    reg.register_late_lint_pass(box macro_use::MacroUseImports::default());
+    reg.register_late_lint_pass(box create_dir::CreateDir);
+    reg.register_late_lint_pass(box path_buf_push_overwrite::PathBufPushOverwrite);
+    reg.register_late_lint_pass(box mutable_debug_assertion::DebugAssertWithMutCall);
+    reg.register_late_lint_pass(box manual_let_else::ManualLetElse::new(msrv));
+    reg.register_late_lint_pass(box slow_vector_initialization::SlowVectorInit);
+    reg.register_late_lint_pass(box field_reassign_with_default::FieldReassignWithDefault);
+    reg.register_late_lint_pass(box if_let_mutex::IfLetMutex);
+    reg.register_late_lint_pass(box redundant_else::RedundantElse);
+    reg.register_late_lint_pass(box manual_async_fn::ManualAsyncFn);
+    reg.register_late_lint_pass(box almost_complete_range::AlmostCompleteRange);
+    reg.register_late_lint_pass(box format_args::FormatArgs);
+    reg.register_late_lint_pass(box needless_late_init::NeedlessLateInit);
+    reg.register_late_lint_pass(box panic_in_result_fn::PanicInResultFn);
+    reg.register_late_lint_pass(box manual_is_ascii_check::ManualIsAsciiCheck);
+    reg.register_early_lint_pass(box feature_name::FeatureName::new(conf.allowed_feature_names.clone()));
+    reg.register_early_lint_pass(box manual_async_recursion::ManualAsyncRecursion);
+    reg.register_late_lint_pass(box default_constructed_unit_structs::DefaultConstructedUnitStructs);
+    reg.register_early_lint_pass(box lint_groups_priority::LintGroupsPriority);
+    reg.register_late_lint_pass(box explicit_auto_deref::ExplicitAutoDeref);
+    reg.register_late_lint_pass(box bool_to_int_with_if::BoolToIntWithIf);
+    reg.register_late_lint_pass(box read_zero_byte_vec::ReadZeroByteVec);
+    reg.register_late_lint_pass(box size_of_in_element_count::SizeOfInElementCount);
+    reg.register_late_lint_pass(box unnecessary_struct_initialization::UnnecessaryStructInitialization);
+    reg.register_late_lint_pass(box arithmetic_side_effects::ArithmeticSideEffects::new(
+        conf.arithmetic_side_effects_allowed.clone(),
+    ));
+    reg.register_late_lint_pass(box manual_string_new::ManualStringNew);
+    reg.register_late_lint_pass(box const_comparisons::ConstComparisons);
+    reg.register_late_lint_pass(box significant_drop_in_scrutinee::SignificantDropInScrutinee::new(
+        conf.significant_drop_types.clone(),
+    ));
+    reg.register_early_lint_pass(box needless_else::NeedlessElse);
+    reg.register_late_lint_pass(box non_octal_unix_permissions::NonOctalUnixPermissions);
+    reg.register_late_lint_pass(box manual_retain::ManualRetain::new(msrv));
+    reg.register_late_lint_pass(box trailing_empty_array::TrailingEmptyArray);
+    reg.register_late_lint_pass(box ref_option_ref::RefOptionRef);
+    reg.register_late_lint_pass(box swapped_arguments::SwappedArguments);
+    reg.register_late_lint_pass(box readonly_write_lock::ReadonlyWriteLock);
+    reg.register_late_lint_pass(box large_futures::LargeFutures::new(conf.future_size_threshold));
+    reg.register_late_lint_pass(box join_absolute_paths::JoinAbsolutePaths);
+    reg.register_late_lint_pass(box tuple_array_conversions::TupleArrayConversions::new(msrv));
+    reg.register_late_lint_pass(box pow_of_two::PowOfTwo);
+    reg.register_late_lint_pass(box recursive_format_impl::RecursiveFormatImpl::default());
+    reg.register_late_lint_pass(box redundant_type_annotations::RedundantTypeAnnotations);
+    reg.register_late_lint_pass(box impl_trait_in_params::ImplTraitInParams::new(conf.check_impl_trait_in_return));
+    reg.register_late_lint_pass(box unbuffered_file_io::UnbufferedFileIo);
+    reg.register_early_lint_pass(box inconsistent_rust_version::InconsistentRustVersion::new(conf.msrv.clone()));
     reg.register_late_lint_pass(box arithmetic::Arithmetic::default());
     reg.register_late_lint_pass(box assign_ops::AssignOps);
     reg.register_late_lint_pass(box let_if_seq::LetIfSeq);
@@ -384,14 +603,15 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
     reg.register_early_lint_pass(box literal_representation::LiteralRepresentation::new(
             conf.literal_representation_threshold
     ));
-    reg.register_late_lint_pass(box use_self::UseSelf);
+    reg.register_late_lint_pass(box use_self::UseSelf::default());
     reg.register_late_lint_pass(box bytecount::ByteCount);
     reg.register_late_lint_pass(box infinite_iter::Pass);
     reg.register_late_lint_pass(box inline_fn_without_body::Pass);
     reg.register_late_lint_pass(box invalid_ref::InvalidRef);
     reg.register_late_lint_pass(box identity_conversion::IdentityConversion::default());
     reg.register_late_lint_pass(box types::ImplicitHasher);
-    reg.register_early_lint_pass(box const_static_lifetime::StaticConst);
+    reg.register_early_lint_pass(box const_static_lifetime::StaticConst::new(msrv));
+    reg.register_late_lint_pass(box manual_bits::ManualBits::new(msrv));
     reg.register_late_lint_pass(box fallible_impl_from::FallibleImplFrom);
     reg.register_late_lint_pass(box replace_consts::ReplaceConsts);
     reg.register_late_lint_pass(box types::UnitArg);
@@ -406,34 +626,59 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
     reg.register_late_lint_pass(box unwrap::Pass);
     reg.register_late_lint_pass(box duration_subsec::DurationSubsec);
     reg.register_late_lint_pass(box default_trait_access::DefaultTraitAccess);
-    reg.register_late_lint_pass(box indexing_slicing::IndexingSlicing);
+    reg.register_late_lint_pass(box indexing_slicing::IndexingSlicing::new(conf.allow_indexing_slicing_in_tests));
     reg.register_late_lint_pass(box non_copy_const::NonCopyConst);
+    reg.register_early_lint_pass(box option_env_unwrap::OptionEnvUnwrap);
+    reg.register_early_lint_pass(box empty_structs_with_brackets::EmptyStructsWithBrackets);
 
     reg.register_lint_group("clippy_restriction", vec![
         arithmetic::FLOAT_ARITHMETIC,
         arithmetic::INTEGER_ARITHMETIC,
+        arithmetic_side_effects::ARITHMETIC_SIDE_EFFECTS,
+        create_dir::CREATE_DIR,
+        default_numeric_fallback::DEFAULT_NUMERIC_FALLBACK,
         else_if_without_else::ELSE_IF_WITHOUT_ELSE,
+        empty_structs_with_brackets::EMPTY_STRUCTS_WITH_BRACKETS,
+        exhaustive_items::EXHAUSTIVE_ENUMS,
+        exhaustive_items::EXHAUSTIVE_STRUCTS,
+        exit::EXIT,
+        if_then_some_else_none::IF_THEN_SOME_ELSE_NONE,
+        impl_trait_in_params::IMPL_TRAIT_IN_PARAMS,
         indexing_slicing::INDEXING_SLICING,
         inherent_impl::MULTIPLE_INHERENT_IMPL,
         literal_representation::DECIMAL_LITERAL_REPRESENTATION,
+        matches::REST_PAT_IN_FULLY_BOUND_STRUCTS,
+        matches::WILDCARD_ENUM_MATCH_ARM,
         mem_forget::MEM_FORGET,
+        methods::BIG_ENDIAN_BYTES,
         methods::CLONE_ON_REF_PTR,
+        methods::HOST_ENDIAN_BYTES,
+        methods::LITTLE_ENDIAN_BYTES,
         methods::OPTION_UNWRAP_USED,
         methods::RESULT_UNWRAP_USED,
         methods::WRONG_PUB_SELF_CONVENTION,
         misc::FLOAT_CMP_CONST,
+        missing_assert_message::MISSING_ASSERT_MESSAGE,
         missing_doc::MISSING_DOCS_IN_PRIVATE_ITEMS,
         missing_inline::MISSING_INLINE_IN_PUBLIC_ITEMS,
+        panic_in_result_fn::PANIC_IN_RESULT_FN,
+        panic_in_result_fn::UNWRAP_IN_RESULT,
         panic_unimplemented::UNIMPLEMENTED,
+        pattern_type_mismatch::PATTERN_TYPE_MISMATCH,
         shadow::SHADOW_REUSE,
         shadow::SHADOW_SAME,
         strings::STRING_ADD,
+        strings::STRING_SLICE,
+        types::AS_CONVERSIONS,
+        write::PRINT_STDERR,
         write::PRINT_STDOUT,
         write::USE_DEBUG,
     ]);
 
     reg.register_lint_group("clippy_pedantic", vec![
         attrs::INLINE_ALWAYS,
+        attrs::SHOULD_PANIC_WITHOUT_EXPECT,
+        bytecount::NAIVE_BYTECOUNT,
         copies::MATCH_SAME_ARMS,
         copy_iterator::COPY_ITERATOR,
         default_trait_access::DEFAULT_TRAIT_ACCESS,
@@ -446,7 +691,12 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         if_not_else::IF_NOT_ELSE,
         infinite_iter::MAYBE_INFINITE_ITER,
         items_after_statements::ITEMS_AFTER_STATEMENTS,
+        large_futures::LARGE_FUTURES,
+        macro_use::MACRO_USE_IMPORTS,
+        manual_let_else::MANUAL_LET_ELSE,
+        manual_string_new::MANUAL_STRING_NEW,
         matches::SINGLE_MATCH_ELSE,
+        methods::CASE_SENSITIVE_FILE_EXTENSION_COMPARISONS,
         methods::FILTER_MAP,
         methods::OPTION_MAP_UNWRAP_OR,
         methods::OPTION_MAP_UNWRAP_OR_ELSE,
@@ -456,9 +706,15 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         mut_mut::MUT_MUT,
         needless_continue::NEEDLESS_CONTINUE,
         non_expressive_names::SIMILAR_NAMES,
+        pow_of_two::INEFFICIENT_POW_OF_TWO,
+        pow_of_two::MANUAL_ROTATE,
+        redundant_else::REDUNDANT_ELSE,
+        ref_option_ref::REF_OPTION_REF,
         replace_consts::REPLACE_CONSTS,
         shadow::SHADOW_UNRELATED,
         strings::STRING_ADD_ASSIGN,
+        struct_field_names::STRUCT_FIELD_NAMES,
+        trait_bounds::TYPE_REPETITION_IN_BOUNDS,
         types::CAST_POSSIBLE_TRUNCATION,
         types::CAST_POSSIBLE_WRAP,
         types::CAST_PRECISION_LOSS,
@@ -467,6 +723,8 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         types::LINKEDLIST,
         unicode::NON_ASCII_LITERAL,
         unicode::UNICODE_NOT_NFC,
+        unused_async::UNUSED_ASYNC,
+        unused_self::UNUSED_SELF,
         use_self::USE_SELF,
     ]);
 
@@ -477,6 +735,7 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
     ]);
 
     reg.register_lint_group("clippy", vec![
+        almost_complete_range::ALMOST_COMPLETE_RANGE,
         approx_const::APPROX_CONSTANT,
         assign_ops::ASSIGN_OP_PATTERN,
         assign_ops::MISREFACTORED_ASSIGN_OP,
@@ -488,14 +747,19 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         blacklisted_name::BLACKLISTED_NAME,
         block_in_if_condition::BLOCK_IN_IF_CONDITION_EXPR,
         block_in_if_condition::BLOCK_IN_IF_CONDITION_STMT,
+        bool_to_int_with_if::BOOL_TO_INT_WITH_IF,
         booleans::LOGIC_BUG,
         booleans::NONMINIMAL_BOOL,
-        bytecount::NAIVE_BYTECOUNT,
         collapsible_if::COLLAPSIBLE_IF,
+        const_comparisons::IMPOSSIBLE_COMPARISONS,
+        const_comparisons::REDUNDANT_COMPARISONS,
         const_static_lifetime::CONST_STATIC_LIFETIME,
+        copies::BRANCHES_SHARING_CODE,
         copies::IF_SAME_THEN_ELSE,
         copies::IFS_SAME_COND,
+        copies::SAME_FUNCTIONS_IN_IF_CONDITION,
         cyclomatic_complexity::CYCLOMATIC_COMPLEXITY,
+        default_constructed_unit_structs::DEFAULT_CONSTRUCTED_UNIT_STRUCTS,
         derive::DERIVE_HASH_XOR_EQ,
         double_comparison::DOUBLE_COMPARISONS,
         double_parens::DOUBLE_PARENS,
@@ -516,8 +780,13 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         eval_order_dependence::DIVERGING_SUB_EXPRESSION,
         eval_order_dependence::EVAL_ORDER_DEPENDENCE,
         excessive_precision::EXCESSIVE_PRECISION,
+        excessive_precision::LOSSY_FLOAT_LITERAL,
+        explicit_auto_deref::EXPLICIT_AUTO_DEREF,
         explicit_write::EXPLICIT_WRITE,
+        field_reassign_with_default::FIELD_REASSIGN_WITH_DEFAULT,
         format::USELESS_FORMAT,
+        format_args::FORMAT_IN_FORMAT_ARGS,
+        format_args::TO_STRING_IN_FORMAT_ARGS,
         formatting::POSSIBLE_MISSING_COMMA,
         formatting::SUSPICIOUS_ASSIGNMENT_FORMATTING,
         formatting::SUSPICIOUS_ELSE_FORMATTING,
@@ -525,6 +794,7 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         functions::TOO_MANY_ARGUMENTS,
         identity_conversion::IDENTITY_CONVERSION,
         identity_op::IDENTITY_OP,
+        if_let_mutex::IF_LET_MUTEX,
         if_let_redundant_pattern_matching::IF_LET_REDUNDANT_PATTERN_MATCHING,
         indexing_slicing::OUT_OF_BOUNDS_INDEXING,
         infallible_destructuring_match::INFALLIBLE_DESTRUCTURING_MATCH,
@@ -532,6 +802,7 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         inline_fn_without_body::INLINE_FN_WITHOUT_BODY,
         int_plus_one::INT_PLUS_ONE,
         invalid_ref::INVALID_REF,
+        join_absolute_paths::JOIN_ABSOLUTE_PATHS,
         large_enum_variant::LARGE_ENUM_VARIANT,
         len_zero::LEN_WITHOUT_IS_EMPTY,
         len_zero::LEN_ZERO,
@@ -550,6 +821,7 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         loops::FOR_LOOP_OVER_RESULT,
         loops::ITER_NEXT_LOOP,
         loops::MANUAL_MEMCPY,
+        loops::MANUAL_WHILE_LET_SOME,
         loops::MUT_RANGE_BOUND,
         loops::NEEDLESS_RANGE_LOOP,
         loops::NEVER_LOOP,
@@ -558,6 +830,11 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         loops::WHILE_IMMUTABLE_CONDITION,
         loops::WHILE_LET_LOOP,
         loops::WHILE_LET_ON_ITERATOR,
+        manual_async_fn::MANUAL_ASYNC_FN,
+        manual_async_recursion::MANUAL_ASYNC_RECURSION,
+        manual_bits::MANUAL_BITS,
+        manual_is_ascii_check::MANUAL_IS_ASCII_CHECK,
+        manual_retain::MANUAL_RETAIN,
         map_clone::MAP_CLONE,
         map_unit_fn::OPTION_MAP_UNIT_FN,
         map_unit_fn::RESULT_MAP_UNIT_FN,
@@ -566,29 +843,51 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         matches::MATCH_OVERLAPPING_ARM,
         matches::MATCH_REF_PATS,
         matches::MATCH_WILD_ERR_ARM,
+        matches::REDUNDANT_AT_REST_PATTERN,
+        matches::REDUNDANT_GUARDS,
         matches::SINGLE_MATCH,
         methods::CHARS_LAST_CMP,
         methods::CHARS_NEXT_CMP,
         methods::CLONE_DOUBLE_REF,
         methods::CLONE_ON_COPY,
+        methods::COLLAPSIBLE_STR_REPLACE,
+        methods::DRAIN_COLLECT,
         methods::EXPECT_FUN_CALL,
+        methods::FILETYPE_IS_FILE,
         methods::FILTER_NEXT,
         methods::GET_UNWRAP,
         methods::ITER_CLONED_COLLECT,
         methods::ITER_NTH,
+        methods::ITER_OUT_OF_BOUNDS,
+        methods::ITER_OVEREAGER_CLONED,
         methods::ITER_SKIP_NEXT,
+        methods::ITER_SKIP_ZERO,
+        methods::ITER_WITH_DRAIN,
+        methods::MANUAL_FILTER_MAP,
+        methods::MANUAL_FIND_MAP,
+        methods::MANUAL_SATURATING_ARITHMETIC,
+        methods::NEEDLESS_OPTION_AS_DEREF,
+        methods::NEEDLESS_REV,
         methods::NEW_RET_NO_SELF,
+        methods::OBFUSCATED_IF_ELSE,
         methods::OK_EXPECT,
+        methods::OPTION_AS_REF_DEREF,
         methods::OPTION_MAP_OR_NONE,
         methods::OR_FUN_CALL,
         methods::SEARCH_IS_SOME,
+        methods::SEEK_FROM_CURRENT,
+        methods::SEEK_TO_START_INSTEAD_OF_REWIND,
         methods::SHOULD_IMPLEMENT_TRAIT,
         methods::SINGLE_CHAR_PATTERN,
         methods::STRING_EXTEND_CHARS,
+        methods::SUSPICIOUS_TO_OWNED,
         methods::TEMPORARY_CSTRING_AS_PTR,
+        methods::TO_DIGIT_IS_SOME,
         methods::UNNECESSARY_FOLD,
         methods::USELESS_ASREF,
         methods::WRONG_SELF_CONVENTION,
+        methods::ZST_OFFSET,
+        minmax::CONSTANT_MIN_MAX_CHAIN,
         minmax::MIN_MAX,
         misc::CMP_NAN,
         misc::CMP_OWNED,
@@ -610,6 +909,9 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         needless_bool::BOOL_COMPARISON,
         needless_bool::NEEDLESS_BOOL,
         needless_borrowed_ref::NEEDLESS_BORROWED_REFERENCE,
+        needless_else::EMPTY_IF_BRANCHES,
+        needless_else::NEEDLESS_ELSE,
+        needless_late_init::NEEDLESS_LATE_INIT,
         needless_pass_by_value::NEEDLESS_PASS_BY_VALUE,
         needless_update::NEEDLESS_UPDATE,
         neg_cmp_op_on_partial_ord::NEG_CMP_OP_ON_PARTIAL_ORD,
@@ -622,11 +924,14 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         non_copy_const::DECLARE_INTERIOR_MUTABLE_CONST,
         non_expressive_names::JUST_UNDERSCORES_AND_DIGITS,
         non_expressive_names::MANY_SINGLE_CHAR_NAMES,
+        non_octal_unix_permissions::NON_OCTAL_UNIX_PERMISSIONS,
         ok_if_let::IF_LET_SOME_RESULT,
         open_options::NONSENSICAL_OPEN_OPTIONS,
+        option_env_unwrap::OPTION_ENV_UNWRAP,
         overflow_check_conditional::OVERFLOW_CHECK_CONDITIONAL,
         panic_unimplemented::PANIC_PARAMS,
         partialeq_ne_impl::PARTIALEQ_NE_IMPL,
+        path_buf_push_overwrite::PATH_BUF_PUSH_OVERWRITE,
         precedence::PRECEDENCE,
         ptr::CMP_NULL,
         ptr::MUT_FROM_REF,
@@ -636,7 +941,11 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         ranges::RANGE_MINUS_ONE,
         ranges::RANGE_PLUS_ONE,
         ranges::RANGE_ZIP_WITH_LEN,
+        read_zero_byte_vec::READ_ZERO_BYTE_VEC,
+        readonly_write_lock::READONLY_WRITE_LOCK,
+        recursive_format_impl::RECURSIVE_FORMAT_IMPL,
         redundant_field_names::REDUNDANT_FIELD_NAMES,
+        redundant_type_annotations::REDUNDANT_TYPE_ANNOTATIONS,
         reference::DEREF_ADDROF,
         reference::REF_IN_DEREF,
         regex::INVALID_REGEX,
@@ -645,11 +954,14 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         returns::LET_AND_RETURN,
         returns::NEEDLESS_RETURN,
         serde_api::SERDE_API_MISUSE,
+        size_of_in_element_count::SIZE_OF_IN_ELEMENT_COUNT,
+        slow_vector_initialization::SLOW_VECTOR_INITIALIZATION,
         strings::STRING_LIT_AS_BYTES,
         suspicious_trait_impl::SUSPICIOUS_ARITHMETIC_IMPL,
         suspicious_trait_impl::SUSPICIOUS_OP_ASSIGN_IMPL,
         swap::ALMOST_SWAPPED,
         swap::MANUAL_SWAP,
+        swapped_arguments::SWAPPED_ARGUMENTS,
         temporary_assignment::TEMPORARY_ASSIGNMENT,
         transmute::CROSSPOINTER_TRANSMUTE,
         transmute::TRANSMUTE_BYTES_TO_STR,
@@ -658,6 +970,8 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         transmute::TRANSMUTE_INT_TO_FLOAT,
         transmute::TRANSMUTE_PTR_TO_PTR,
         transmute::TRANSMUTE_PTR_TO_REF,
+        transmute::TRANSMUTES_EXPRESSIBLE_AS_PTR_CASTS,
+        transmute::UNSOUND_COLLECTION_TRANSMUTE,
         transmute::USELESS_TRANSMUTE,
         transmute::WRONG_TRANSMUTE,
         trivially_copy_pass_by_ref::TRIVIALLY_COPY_PASS_BY_REF,
@@ -676,6 +990,7 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         types::UNIT_ARG,
         types::UNIT_CMP,
         types::UNNECESSARY_CAST,
+        unbuffered_file_io::UNBUFFERED_FILE_IO,
         unicode::ZERO_WIDTH_SPACE,
         unsafe_removed_from_name::UNSAFE_REMOVED_FROM_NAME,
         unused_io_amount::UNUSED_IO_AMOUNT,
@@ -696,6 +1011,7 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         blacklisted_name::BLACKLISTED_NAME,
         block_in_if_condition::BLOCK_IN_IF_CONDITION_EXPR,
         block_in_if_condition::BLOCK_IN_IF_CONDITION_STMT,
+        bool_to_int_with_if::BOOL_TO_INT_WITH_IF,
         collapsible_if::COLLAPSIBLE_IF,
         const_static_lifetime::CONST_STATIC_LIFETIME,
         enum_variants::ENUM_VARIANT_NAMES,
@@ -703,8 +1019,7 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         eq_op::OP_REF,
         eta_reduction::REDUNDANT_CLOSURE,
         excessive_precision::EXCESSIVE_PRECISION,
-        formatting::SUSPICIOUS_ASSIGNMENT_FORMATTING,
-        formatting::SUSPICIOUS_ELSE_FORMATTING,
+        field_reassign_with_default::FIELD_REASSIGN_WITH_DEFAULT,
         if_let_redundant_pattern_matching::IF_LET_REDUNDANT_PATTERN_MATCHING,
         infallible_destructuring_match::INFALLIBLE_DESTRUCTURING_MATCH,
         len_zero::LEN_WITHOUT_IS_EMPTY,
@@ -717,25 +1032,37 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         loops::EXPLICIT_INTO_ITER_LOOP,
         loops::EXPLICIT_ITER_LOOP,
         loops::FOR_KV_MAP,
+        loops::MANUAL_WHILE_LET_SOME,
         loops::NEEDLESS_RANGE_LOOP,
         loops::WHILE_LET_ON_ITERATOR,
+        manual_async_fn::MANUAL_ASYNC_FN,
+        manual_bits::MANUAL_BITS,
+        manual_is_ascii_check::MANUAL_IS_ASCII_CHECK,
         map_clone::MAP_CLONE,
         matches::MATCH_BOOL,
         matches::MATCH_OVERLAPPING_ARM,
         matches::MATCH_REF_PATS,
         matches::MATCH_WILD_ERR_ARM,
+        matches::REDUNDANT_AT_REST_PATTERN,
+        matches::REDUNDANT_GUARDS,
         matches::SINGLE_MATCH,
         methods::CHARS_LAST_CMP,
+        methods::COLLAPSIBLE_STR_REPLACE,
         methods::GET_UNWRAP,
         methods::ITER_CLONED_COLLECT,
         methods::ITER_SKIP_NEXT,
+        methods::MANUAL_SATURATING_ARITHMETIC,
+        methods::NEEDLESS_REV,
         methods::NEW_RET_NO_SELF,
+        methods::OBFUSCATED_IF_ELSE,
         methods::OK_EXPECT,
         methods::OPTION_MAP_OR_NONE,
         methods::SHOULD_IMPLEMENT_TRAIT,
         methods::STRING_EXTEND_CHARS,
+        methods::TO_DIGIT_IS_SOME,
         methods::UNNECESSARY_FOLD,
         methods::WRONG_SELF_CONVENTION,
+        minmax::CONSTANT_MIN_MAX_CHAIN,
         misc::REDUNDANT_PATTERN,
         misc::TOPLEVEL_REF_ARG,
         misc::ZERO_PTR,
@@ -745,6 +1072,9 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         misc_early::MIXED_CASE_HEX_LITERALS,
         misc_early::UNNEEDED_FIELD_PATTERN,
         mut_reference::UNNECESSARY_MUT_PASSED,
+        needless_else::EMPTY_IF_BRANCHES,
+        needless_else::NEEDLESS_ELSE,
+        needless_late_init::NEEDLESS_LATE_INIT,
         needless_pass_by_value::NEEDLESS_PASS_BY_VALUE,
         neg_multiply::NEG_MULTIPLY,
         new_without_default::NEW_WITHOUT_DEFAULT,
@@ -757,6 +1087,7 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         ptr::PTR_ARG,
         question_mark::QUESTION_MARK,
         redundant_field_names::REDUNDANT_FIELD_NAMES,
+        redundant_type_annotations::REDUNDANT_TYPE_ANNOTATIONS,
         regex::REGEX_MACRO,
         regex::TRIVIAL_REGEX,
         returns::LET_AND_RETURN,
@@ -774,15 +1105,28 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         write::WRITELN_EMPTY_STRING,
     ]);
 
-    reg.register_lint_group("clippy_complexity", vec![
+    reg.register_lint_group("clippy_suspicious", vec![
         assign_ops::MISREFACTORED_ASSIGN_OP,
+        eval_order_dependence::EVAL_ORDER_DEPENDENCE,
+        formatting::SUSPICIOUS_ASSIGNMENT_FORMATTING,
+        formatting::SUSPICIOUS_ELSE_FORMATTING,
+        loops::FOR_LOOP_OVER_OPTION,
+        loops::FOR_LOOP_OVER_RESULT,
+        loops::MUT_RANGE_BOUND,
+        suspicious_trait_impl::SUSPICIOUS_ARITHMETIC_IMPL,
+        suspicious_trait_impl::SUSPICIOUS_OP_ASSIGN_IMPL,
+    ]);
+
+    reg.register_lint_group("clippy_complexity", vec![
         booleans::NONMINIMAL_BOOL,
+        copies::BRANCHES_SHARING_CODE,
         cyclomatic_complexity::CYCLOMATIC_COMPLEXITY,
+        default_constructed_unit_structs::DEFAULT_CONSTRUCTED_UNIT_STRUCTS,
         double_comparison::DOUBLE_COMPARISONS,
         double_parens::DOUBLE_PARENS,
         duration_subsec::DURATION_SUBSEC,
         eval_order_dependence::DIVERGING_SUB_EXPRESSION,
-        eval_order_dependence::EVAL_ORDER_DEPENDENCE,
+        explicit_auto_deref::EXPLICIT_AUTO_DEREF,
         explicit_write::EXPLICIT_WRITE,
         format::USELESS_FORMAT,
         functions::TOO_MANY_ARGUMENTS,
@@ -792,7 +1136,6 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         lifetimes::EXTRA_UNUSED_LIFETIMES,
         lifetimes::NEEDLESS_LIFETIMES,
         loops::EXPLICIT_COUNTER_LOOP,
-        loops::MUT_RANGE_BOUND,
         loops::WHILE_LET_LOOP,
         map_unit_fn::OPTION_MAP_UNIT_FN,
         map_unit_fn::RESULT_MAP_UNIT_FN,
@@ -800,7 +1143,13 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         methods::CHARS_NEXT_CMP,
         methods::CLONE_ON_COPY,
         methods::FILTER_NEXT,
+        methods::MANUAL_FILTER_MAP,
+        methods::MANUAL_FIND_MAP,
+        methods::NEEDLESS_OPTION_AS_DEREF,
+        methods::OPTION_AS_REF_DEREF,
         methods::SEARCH_IS_SOME,
+        methods::SEEK_FROM_CURRENT,
+        methods::SEEK_TO_START_INSTEAD_OF_REWIND,
         methods::USELESS_ASREF,
         misc::SHORT_CIRCUIT_STATEMENT,
         misc_early::REDUNDANT_CLOSURE_CALL,
@@ -829,6 +1178,7 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         transmute::TRANSMUTE_INT_TO_FLOAT,
         transmute::TRANSMUTE_PTR_TO_PTR,
         transmute::TRANSMUTE_PTR_TO_REF,
+        transmute::TRANSMUTES_EXPRESSIBLE_AS_PTR_CASTS,
         transmute::USELESS_TRANSMUTE,
         types::BORROWED_BOX,
         types::CAST_LOSSLESS,
@@ -842,14 +1192,18 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
     ]);
 
     reg.register_lint_group("clippy_correctness", vec![
+        almost_complete_range::ALMOST_COMPLETE_RANGE,
         approx_const::APPROX_CONSTANT,
         attrs::DEPRECATED_SEMVER,
         attrs::USELESS_ATTRIBUTE,
         bit_mask::BAD_BIT_MASK,
         bit_mask::INEFFECTIVE_BIT_MASK,
         booleans::LOGIC_BUG,
+        const_comparisons::IMPOSSIBLE_COMPARISONS,
+        const_comparisons::REDUNDANT_COMPARISONS,
         copies::IF_SAME_THEN_ELSE,
         copies::IFS_SAME_COND,
+        copies::SAME_FUNCTIONS_IN_IF_CONDITION,
         derive::DERIVE_HASH_XOR_EQ,
         drop_forget_ref::DROP_COPY,
         drop_forget_ref::DROP_REF,
@@ -858,34 +1212,47 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         enum_clike::ENUM_CLIKE_UNPORTABLE_VARIANT,
         eq_op::EQ_OP,
         erasing_op::ERASING_OP,
+        excessive_precision::LOSSY_FLOAT_LITERAL,
         formatting::POSSIBLE_MISSING_COMMA,
         functions::NOT_UNSAFE_PTR_ARG_DEREF,
+        if_let_mutex::IF_LET_MUTEX,
         indexing_slicing::OUT_OF_BOUNDS_INDEXING,
         infinite_iter::INFINITE_ITER,
         inline_fn_without_body::INLINE_FN_WITHOUT_BODY,
         invalid_ref::INVALID_REF,
-        loops::FOR_LOOP_OVER_OPTION,
-        loops::FOR_LOOP_OVER_RESULT,
+        join_absolute_paths::JOIN_ABSOLUTE_PATHS,
         loops::ITER_NEXT_LOOP,
         loops::NEVER_LOOP,
         loops::REVERSE_RANGE_LOOP,
         loops::WHILE_IMMUTABLE_CONDITION,
+        manual_async_recursion::MANUAL_ASYNC_RECURSION,
         methods::CLONE_DOUBLE_REF,
+        methods::FILETYPE_IS_FILE,
+        methods::ITER_OUT_OF_BOUNDS,
+        methods::ITER_SKIP_ZERO,
+        methods::SUSPICIOUS_TO_OWNED,
         methods::TEMPORARY_CSTRING_AS_PTR,
+        methods::ZST_OFFSET,
         minmax::MIN_MAX,
         misc::CMP_NAN,
         misc::FLOAT_CMP,
         misc::MODULO_ONE,
         non_copy_const::BORROW_INTERIOR_MUTABLE_CONST,
         non_copy_const::DECLARE_INTERIOR_MUTABLE_CONST,
+        non_octal_unix_permissions::NON_OCTAL_UNIX_PERMISSIONS,
         open_options::NONSENSICAL_OPEN_OPTIONS,
+        option_env_unwrap::OPTION_ENV_UNWRAP,
+        path_buf_push_overwrite::PATH_BUF_PUSH_OVERWRITE,
         ptr::MUT_FROM_REF,
         ranges::ITERATOR_STEP_BY_ZERO,
+        read_zero_byte_vec::READ_ZERO_BYTE_VEC,
+        recursive_format_impl::RECURSIVE_FORMAT_IMPL,
         regex::INVALID_REGEX,
         serde_api::SERDE_API_MISUSE,
-        suspicious_trait_impl::SUSPICIOUS_ARITHMETIC_IMPL,
-        suspicious_trait_impl::SUSPICIOUS_OP_ASSIGN_IMPL,
+        size_of_in_element_count::SIZE_OF_IN_ELEMENT_COUNT,
         swap::ALMOST_SWAPPED,
+        swapped_arguments::SWAPPED_ARGUMENTS,
+        transmute::UNSOUND_COLLECTION_TRANSMUTE,
         transmute::WRONG_TRANSMUTE,
         types::ABSURD_EXTREME_COMPARISONS,
         types::CAST_PTR_ALIGNMENT,
@@ -896,32 +1263,55 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
     ]);
 
     reg.register_lint_group("clippy_perf", vec![
-        bytecount::NAIVE_BYTECOUNT,
         entry::MAP_ENTRY,
         escape::BOXED_LOCAL,
+        format_args::FORMAT_IN_FORMAT_ARGS,
+        format_args::TO_STRING_IN_FORMAT_ARGS,
         large_enum_variant::LARGE_ENUM_VARIANT,
         loops::MANUAL_MEMCPY,
         loops::UNUSED_COLLECT,
+        manual_retain::MANUAL_RETAIN,
+        methods::DRAIN_COLLECT,
         methods::EXPECT_FUN_CALL,
         methods::ITER_NTH,
+        methods::ITER_OVEREAGER_CLONED,
+        methods::ITER_WITH_DRAIN,
         methods::OR_FUN_CALL,
         methods::SINGLE_CHAR_PATTERN,
         misc::CMP_OWNED,
         mutex_atomic::MUTEX_ATOMIC,
+        readonly_write_lock::READONLY_WRITE_LOCK,
+        slow_vector_initialization::SLOW_VECTOR_INITIALIZATION,
         trivially_copy_pass_by_ref::TRIVIALLY_COPY_PASS_BY_REF,
         types::BOX_VEC,
+        unbuffered_file_io::UNBUFFERED_FILE_IO,
         vec::USELESS_VEC,
     ]);
 
     reg.register_lint_group("clippy_cargo", vec![
+        feature_name::NEGATIVE_FEATURE_NAMES,
+        feature_name::REDUNDANT_DEPENDENCY_FEATURES,
+        feature_name::REDUNDANT_FEATURE_NAMES,
+        inconsistent_rust_version::INCONSISTENT_RUST_VERSION,
+        lint_groups_priority::LINT_GROUPS_PRIORITY,
         multiple_crate_versions::MULTIPLE_CRATE_VERSIONS,
     ]);
 
     reg.register_lint_group("clippy_nursery", vec![
         attrs::EMPTY_LINE_AFTER_OUTER_ATTR,
         fallible_impl_from::FALLIBLE_IMPL_FROM,
+        floating_point_arithmetic::IMPRECISE_FLOPS,
+        floating_point_arithmetic::SUBOPTIMAL_FLOPS,
+        methods::ITER_ON_SINGLE_OR_EMPTY_COLLECTIONS,
+        mutable_debug_assertion::DEBUG_ASSERT_WITH_MUT_CALL,
         mutex_atomic::MUTEX_INTEGER,
         needless_borrow::NEEDLESS_BORROW,
+        significant_drop_in_scrutinee::SIGNIFICANT_DROP_IN_SCRUTINEE,
+        suspicious_operation_groupings::SUSPICIOUS_OPERATION_GROUPINGS,
+        trailing_empty_array::TRAILING_EMPTY_ARRAY,
+        trait_bounds::TRAIT_DUPLICATION_IN_BOUNDS,
+        tuple_array_conversions::TUPLE_ARRAY_CONVERSIONS,
+        unnecessary_struct_initialization::UNNECESSARY_STRUCT_INITIALIZATION,
         unwrap::PANICKING_UNWRAP,
         unwrap::UNNECESSARY_UNWRAP,
     ]);
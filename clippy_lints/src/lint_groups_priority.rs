@@ -0,0 +1,160 @@
+//! lint on lint groups sharing a priority with their lints in the `[lints]` table of the manifest
+
+use rustc::lint::{EarlyContext, EarlyLintPass, LintArray, LintContext, LintPass};
+use rustc::{declare_lint, lint_array};
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::path::Path;
+use syntax::ast::*;
+use crate::utils::span_lint_and_then;
+
+use toml;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for lint groups in the `[lints]` table of `Cargo.toml` with the
+    /// same priority as a lint of the group set to another level.
+    ///
+    /// **Why is this bad?** The order of the entries of the table is ignored by Cargo, so the level
+    /// of the group can override the level of the lint instead of the other way around.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```toml
+    /// [lints.clippy]
+    /// pedantic = "warn"
+    /// similar_names = "allow"
+    /// ```
+    /// Use instead:
+    /// ```toml
+    /// [lints.clippy]
+    /// pedantic = { level = "warn", priority = -1 }
+    /// similar_names = "allow"
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub LINT_GROUPS_PRIORITY,
+    cargo,
+    "a lint group in `Cargo.toml` at the same priority as a lint of the group"
+}
+
+#[derive(Copy, Clone)]
+pub struct LintGroupsPriority;
+
+impl LintPass for LintGroupsPriority {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(LINT_GROUPS_PRIORITY)
+    }
+}
+
+/// A `name = "level"` or `name = { level = "..", priority = .. }` entry of a `[lints]` table
+struct LintConfig<'a> {
+    name: String,
+    level: &'a str,
+    priority: i64,
+}
+
+impl<'a> LintConfig<'a> {
+    fn parse(name: &str, value: &'a toml::Value) -> Option<Self> {
+        let (level, priority) = match *value {
+            toml::Value::String(ref level) => (&**level, 0),
+            toml::Value::Table(ref table) => (
+                table.get("level")?.as_str()?,
+                table.get("priority").map_or(Some(0), toml::Value::as_integer)?,
+            ),
+            _ => return None,
+        };
+        Some(Self {
+            name: name.replace('-', "_"),
+            level,
+            priority,
+        })
+    }
+}
+
+impl EarlyLintPass for LintGroupsPriority {
+    fn check_crate(&mut self, cx: &EarlyContext<'_>, krate: &Crate) {
+        // only set when the crate is compiled by Cargo
+        let manifest = match env::var("CARGO_MANIFEST_DIR")
+            .ok()
+            .and_then(|dir| fs::read_to_string(Path::new(&dir).join("Cargo.toml")).ok())
+            .and_then(|manifest| manifest.parse::<toml::Value>().ok())
+        {
+            Some(manifest) => manifest,
+            None => return,
+        };
+
+        // the names of the lints of each group, e.g. `clippy_pedantic` => [`similar_names`, ..]
+        let groups: HashMap<_, Vec<_>> = cx
+            .lints()
+            .get_lint_groups()
+            .into_iter()
+            .map(|(name, lints, _)| (name, lints.iter().map(|lint| lint.to_string()).collect()))
+            .collect();
+
+        let tables = manifest.get("lints").into_iter().chain(
+            manifest
+                .get("workspace")
+                .and_then(|workspace| workspace.get("lints")),
+        );
+        for table in tables.filter_map(toml::Value::as_table) {
+            for (tool, lints) in table {
+                if tool != "rust" && tool != "clippy" {
+                    continue;
+                }
+                let lints: Vec<_> = match lints.as_table() {
+                    Some(lints) => lints
+                        .iter()
+                        .filter_map(|(name, value)| LintConfig::parse(name, value))
+                        .collect(),
+                    None => continue,
+                };
+                for group in &lints {
+                    let members = match groups.get(&*registered_group_name(tool, &group.name)) {
+                        Some(members) => members,
+                        None => continue,
+                    };
+                    // the lints are registered without the tool name, e.g. `similar_names`
+                    let conflict = lints.iter().find(|lint| {
+                        lint.priority == group.priority && lint.level != group.level && members.contains(&lint.name)
+                    });
+                    if let Some(lint) = conflict {
+                        lint_conflict(cx, krate, tool, group, lint);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Returns the name the group `name` of the `[lints.<tool>]` table is registered under, e.g.
+/// `clippy_pedantic` for the `pedantic` group of Clippy
+fn registered_group_name(tool: &str, name: &str) -> String {
+    match (tool, name) {
+        ("clippy", "all") => "clippy".to_owned(),
+        ("clippy", _) => format!("clippy_{}", name),
+        _ => name.to_owned(),
+    }
+}
+
+fn lint_conflict(cx: &EarlyContext<'_>, krate: &Crate, tool: &str, group: &LintConfig<'_>, lint: &LintConfig<'_>) {
+    span_lint_and_then(
+        cx,
+        LINT_GROUPS_PRIORITY,
+        krate.span,
+        &format!(
+            "lint group `{}` in `[lints.{}]` has the same priority ({}) as the lint `{}`",
+            group.name, tool, group.priority, lint.name
+        ),
+        |db| {
+            db.note("the order of the lints in the table is ignored by Cargo");
+            db.help(&format!(
+                "to have lints override the group set it to a lower priority: \
+                 `{} = {{ level = \"{}\", priority = {} }}`",
+                group.name,
+                group.level,
+                group.priority - 1
+            ));
+        },
+    );
+}
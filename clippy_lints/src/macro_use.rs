@@ -0,0 +1,142 @@
+use crate::utils::{in_macro, span_lint_and_then};
+use if_chain::if_chain;
+use rustc::hir::def_id::CrateNum;
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintContext, LintPass};
+use rustc::{declare_lint, lint_array};
+use rustc_errors::Applicability;
+use std::collections::{BTreeSet, HashMap};
+use syntax::ast;
+use syntax::source_map::{ExpnFormat, Span};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `#[macro_use] extern crate ...` in Rust 2018
+    /// crates.
+    ///
+    /// **Why is this bad?** Since the Rust 2018 edition macros can be imported
+    /// with `use` like any other item, which makes it clear where they come from.
+    ///
+    /// **Known problems:** Only the macros invoked directly in the crate are
+    /// suggested, macros re-exported from another crate are not found.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// #[macro_use]
+    /// extern crate lazy_static;
+    /// ```
+    ///
+    /// Could be written:
+    ///
+    /// ```rust,ignore
+    /// use lazy_static::lazy_static;
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub MACRO_USE_IMPORTS,
+    pedantic,
+    "#[macro_use] is no longer needed"
+}
+
+/// A `#[macro_use] extern crate` item
+struct MacroUseImport {
+    attr_span: Span,
+    krate: CrateNum,
+    name: ast::Name,
+}
+
+#[derive(Default)]
+pub struct MacroUseImports {
+    imports: Vec<MacroUseImport>,
+    /// The names of the macros invoked in the crate, by the crate they are defined in
+    used: HashMap<CrateNum, BTreeSet<String>>,
+}
+
+impl MacroUseImports {
+    /// Records the macro invoked by the user code `span` was expanded from, if any
+    fn push_macro(&mut self, cx: &LateContext<'_, '_>, mut span: Span) {
+        while let Some(info) = span.ctxt().outer().expn_info() {
+            // macros invoked from other macros don't need to be imported
+            if !in_macro(info.call_site) {
+                if let (ExpnFormat::MacroBang(name), Some(def_site)) = (&info.format, info.def_site) {
+                    let file = cx.sess().source_map().lookup_char_pos(def_site.lo()).file;
+                    self.used
+                        .entry(CrateNum::from_u32(file.crate_of_origin))
+                        .or_insert_with(BTreeSet::new)
+                        .insert(name.to_string());
+                }
+                return;
+            }
+            span = info.call_site;
+        }
+    }
+}
+
+impl LintPass for MacroUseImports {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(MACRO_USE_IMPORTS)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for MacroUseImports {
+    fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx Item) {
+        if_chain! {
+            if cx.sess().rust_2018();
+            if let ItemKind::ExternCrate(_) = item.node;
+            if let Some(attr) = item.attrs.iter().find(|attr| attr.name() == "macro_use");
+            if let Some(krate) = cx.tcx.extern_mod_stmt_cnum(cx.tcx.hir.local_def_id(item.id));
+            then {
+                self.imports.push(MacroUseImport {
+                    attr_span: attr.span,
+                    krate,
+                    name: item.name,
+                });
+            }
+        }
+        self.push_macro(cx, item.span);
+    }
+
+    fn check_stmt(&mut self, cx: &LateContext<'a, 'tcx>, stmt: &'tcx Stmt) {
+        self.push_macro(cx, stmt.span);
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        self.push_macro(cx, expr.span);
+    }
+
+    fn check_pat(&mut self, cx: &LateContext<'a, 'tcx>, pat: &'tcx Pat) {
+        self.push_macro(cx, pat.span);
+    }
+
+    fn check_ty(&mut self, cx: &LateContext<'a, 'tcx>, ty: &'tcx Ty) {
+        self.push_macro(cx, ty.span);
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'a, 'tcx>, _: &'tcx Crate) {
+        for import in &self.imports {
+            // without any macro to import, the `extern crate` may still be needed for other reasons
+            let macros = match self.used.get(&import.krate) {
+                Some(macros) => macros,
+                None => continue,
+            };
+            let sugg = if macros.len() == 1 {
+                format!("use {}::{};", import.name, macros.iter().next().unwrap())
+            } else {
+                let macros: Vec<_> = macros.iter().map(String::as_str).collect();
+                format!("use {}::{{{}}};", import.name, macros.join(", "))
+            };
+            span_lint_and_then(
+                cx,
+                MACRO_USE_IMPORTS,
+                import.attr_span,
+                "`macro_use` attributes are no longer needed in the Rust 2018 edition",
+                |db| {
+                    db.span_suggestion_with_applicability(
+                        import.attr_span,
+                        "remove the attribute and import the macros directly, try",
+                        sugg,
+                        Applicability::MaybeIncorrect,
+                    );
+                },
+            );
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use crate::utils::{match_def_path, multispan_sugg_with_applicability, opt_def_id, paths, snippet_opt,
+                   span_lint_and_then};
+use if_chain::if_chain;
+use rustc::hir::def::Def;
+use rustc::hir::intravisit::FnKind;
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::{declare_lint, lint_array};
+use rustc_errors::Applicability;
+use rustc_target::spec::abi::Abi;
+use syntax::ast::NodeId;
+use syntax::source_map::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** It checks for manual implementations of `async` functions.
+    ///
+    /// **Why is this bad?** It's more idiomatic to use the dedicated syntax.
+    ///
+    /// **Known problems:** Only free functions are checked.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust,ignore
+    /// use std::future::Future;
+    ///
+    /// fn foo() -> impl Future<Output = i32> { async { 42 } }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// async fn foo() -> i32 { 42 }
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub MANUAL_ASYNC_FN,
+    style,
+    "manual implementations of `async` functions can be simplified using the dedicated syntax"
+}
+
+#[derive(Copy, Clone)]
+pub struct ManualAsyncFn;
+
+impl LintPass for ManualAsyncFn {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(MANUAL_ASYNC_FN)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for ManualAsyncFn {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'a, 'tcx>,
+        kind: FnKind<'tcx>,
+        decl: &'tcx FnDecl,
+        body: &'tcx Body,
+        span: Span,
+        _: NodeId,
+    ) {
+        if_chain! {
+            if let FnKind::ItemFn(_, _, header, ..) = kind;
+            // `async` must be the first qualifier and can't be combined with all the others
+            if header.asyncness == IsAsync::NotAsync && header.constness == Constness::NotConst;
+            if header.unsafety == Unsafety::Normal && header.abi == Abi::Rust;
+            if let FunctionRetTy::Return(ref ret_ty) = decl.output;
+            if let Some(output) = future_output_ty(cx, ret_ty);
+            if let ExprKind::Block(ref block, _) = body.value.node;
+            if block.stmts.is_empty();
+            if let Some(ref async_block) = block.expr;
+            if is_async_block(cx, async_block);
+            let header_span = span.with_hi(ret_ty.span.hi());
+            if let Some(header_snip) = snippet_opt(cx, header_span.with_hi(ret_ty.span.lo()));
+            if let Some(fn_pos) = header_snip.find("fn");
+            if let Some(output_snip) = snippet_opt(cx, output.span);
+            if let Some(body_snip) = snippet_opt(cx, async_block.span);
+            if let Some(body_pos) = body_snip.find('{');
+            then {
+                let header_sugg = if output_snip == "()" {
+                    header_snip.trim_end().trim_end_matches("->").trim_end().to_owned()
+                } else {
+                    format!("{}{}", header_snip, output_snip)
+                };
+                let header_sugg = format!("{}async {}", &header_sugg[..fn_pos], &header_sugg[fn_pos..]);
+                span_lint_and_then(
+                    cx,
+                    MANUAL_ASYNC_FN,
+                    header_span,
+                    "this function can be simplified using the `async fn` syntax",
+                    |db| {
+                        multispan_sugg_with_applicability(
+                            db,
+                            "make the function `async` and move the body of the async block to the function".to_owned(),
+                            Applicability::MachineApplicable,
+                            vec![
+                                (header_span, header_sugg),
+                                (body.value.span, body_snip[body_pos..].to_owned()),
+                            ],
+                        );
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// Returns `T` if `ty` is `impl Future<Output = T>`, possibly with lifetime bounds
+fn future_output_ty<'tcx>(cx: &LateContext<'_, 'tcx>, ty: &'tcx Ty) -> Option<&'tcx Ty> {
+    if_chain! {
+        if let TyKind::Path(QPath::Resolved(_, ref path)) = ty.node;
+        if let Def::Existential(def_id) = path.def;
+        if let Some(node_id) = cx.tcx.hir.as_local_node_id(def_id);
+        if let ItemKind::Existential(ref exist_ty) = cx.tcx.hir.expect_item(node_id).node;
+        let mut traits = exist_ty.bounds.iter().filter_map(|bound| match *bound {
+            GenericBound::Trait(ref poly, _) => Some(poly),
+            GenericBound::Outlives(_) => None,
+        });
+        if let Some(poly) = traits.next();
+        if traits.next().is_none();
+        if let Def::Trait(trait_id) = poly.trait_ref.path.def;
+        if match_def_path(cx.tcx, trait_id, &paths::FUTURE_TRAIT);
+        if let Some(segment) = poly.trait_ref.path.segments.last();
+        if let Some(ref args) = segment.args;
+        if let [ref binding] = *args.bindings;
+        if binding.ident.name == "Output";
+        then {
+            return Some(&binding.ty);
+        }
+    }
+    None
+}
+
+/// Checks for `async { .. }` or `async move { .. }`, lowered to a call to
+/// `std::future::from_generator` with a generator
+fn is_async_block(cx: &LateContext<'_, '_>, expr: &Expr) -> bool {
+    if_chain! {
+        if let ExprKind::Call(ref func, ref args) = expr.node;
+        if let ExprKind::Path(ref qpath) = func.node;
+        if let Some(def_id) = opt_def_id(cx.tables.qpath_def(qpath, func.hir_id));
+        if match_def_path(cx.tcx, def_id, &paths::FROM_GENERATOR);
+        if let [ref closure] = **args;
+        if let ExprKind::Closure(_, _, _, _, Some(_)) = closure.node;
+        then {
+            return true;
+        }
+    }
+    false
+}
@@ -0,0 +1,153 @@
+use crate::utils::{in_macro, is_direct_expn_of, span_help_and_lint};
+use rustc::lint::{EarlyContext, EarlyLintPass, LintArray, LintPass};
+use rustc::{declare_lint, lint_array};
+use syntax::ast::*;
+use syntax::symbol::keywords;
+use syntax::visit::{walk_expr, Visitor};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `async fn`s awaiting a call to themselves.
+    ///
+    /// **Why is this bad?** The future of an `async fn` contains the futures it
+    /// awaits, so a directly recursive `async fn` would have an infinitely sized
+    /// type. This is rejected by the compiler with a cycle error that doesn't point
+    /// at the recursive call.
+    ///
+    /// **Known problems:** Only the direct recursion is detected, a cycle through
+    /// several functions isn't.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// async fn sum(n: u32) -> u32 {
+    ///     if n == 0 {
+    ///         0
+    ///     } else {
+    ///         n + await!(sum(n - 1))
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// fn sum(n: u32) -> Pin<Box<dyn Future<Output = u32>>> {
+    ///     Box::pin(async move {
+    ///         if n == 0 {
+    ///             0
+    ///         } else {
+    ///             n + await!(sum(n - 1))
+    ///         }
+    ///     })
+    /// }
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub MANUAL_ASYNC_RECURSION,
+    correctness,
+    "recursive `async fn` awaiting a call to itself without boxing"
+}
+
+#[derive(Copy, Clone)]
+pub struct ManualAsyncRecursion;
+
+impl LintPass for ManualAsyncRecursion {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(MANUAL_ASYNC_RECURSION)
+    }
+}
+
+impl EarlyLintPass for ManualAsyncRecursion {
+    fn check_item(&mut self, cx: &EarlyContext<'_>, item: &Item) {
+        if let ItemKind::Fn(_, ref header, _, ref block) = item.node {
+            check_fn(cx, header, item.ident, false, block);
+        }
+    }
+
+    fn check_impl_item(&mut self, cx: &EarlyContext<'_>, item: &ImplItem) {
+        if let ImplItemKind::Method(ref sig, ref block) = item.node {
+            check_fn(cx, &sig.header, item.ident, true, block);
+        }
+    }
+}
+
+fn check_fn(cx: &EarlyContext<'_>, header: &FnHeader, name: Ident, is_method: bool, block: &Block) {
+    if let IsAsync::Async { .. } = header.asyncness {
+        let mut visitor = RecursionVisitor {
+            cx,
+            name,
+            is_method,
+            awaited: false,
+        };
+        visitor.visit_block(block);
+    }
+}
+
+/// Looks for the calls to the function `name` that are the argument of an `await!`
+struct RecursionVisitor<'a, 'b: 'a> {
+    cx: &'a EarlyContext<'b>,
+    name: Ident,
+    is_method: bool,
+    /// Whether the visited expression is the one given to `await!`
+    awaited: bool,
+}
+
+impl<'a, 'b> RecursionVisitor<'a, 'b> {
+    /// Checks for `name(..)`, or `Self::name(..)` and `self.name(..)` in methods
+    fn is_recursive_call(&self, expr: &Expr) -> bool {
+        match expr.node {
+            ExprKind::Call(ref func, _) => match func.node {
+                ExprKind::Path(None, ref path) => match *path.segments {
+                    [ref segment] => !self.is_method && segment.ident.name == self.name.name,
+                    [ref ty, ref segment] => {
+                        self.is_method
+                            && ty.ident.name == keywords::SelfType.name()
+                            && segment.ident.name == self.name.name
+                    },
+                    _ => false,
+                },
+                _ => false,
+            },
+            ExprKind::MethodCall(ref segment, ref args) => match args[0].node {
+                ExprKind::Path(None, ref path) => {
+                    self.is_method
+                        && segment.ident.name == self.name.name
+                        && path.segments.len() == 1
+                        && path.segments[0].ident.name == keywords::SelfValue.name()
+                },
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+}
+
+impl<'a, 'b, 'ast> Visitor<'ast> for RecursionVisitor<'a, 'b> {
+    fn visit_expr(&mut self, expr: &'ast Expr) {
+        let awaited = self.awaited;
+        if in_macro(expr.span) {
+            // the code `await!` expands to, around its argument
+            if is_direct_expn_of(expr.span, "await").is_some() {
+                self.awaited = true;
+            }
+        } else {
+            if awaited && self.is_recursive_call(expr) {
+                span_help_and_lint(
+                    self.cx,
+                    MANUAL_ASYNC_RECURSION,
+                    expr.span,
+                    &format!("`async fn` `{}` awaits a call to itself", self.name),
+                    "recursion in an `async fn` requires boxing: consider returning a \
+                     `Pin<Box<dyn Future>>` from a function that isn't `async`, or rewriting it iteratively",
+                );
+            }
+            self.awaited = false;
+        }
+        walk_expr(self, expr);
+        self.awaited = awaited;
+    }
+
+    fn visit_item(&mut self, _: &Item) {
+        // do not recurse into inner items
+    }
+
+    fn visit_mac(&mut self, _mac: &Mac) {
+        // do not check macs
+    }
+}
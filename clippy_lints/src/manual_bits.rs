@@ -0,0 +1,147 @@
+use crate::consts::{constant, Constant};
+use crate::utils::msrvs::{self, MsrvStack, RustcVersion};
+use crate::utils::{get_parent_expr, in_macro, match_def_path, opt_def_id, paths, snippet, span_lint_and_sugg};
+use if_chain::if_chain;
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintContext, LintPass};
+use rustc::ty;
+use rustc::{declare_lint, lint_array};
+use syntax::ast;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for uses of `std::mem::size_of::<T>() * 8` when
+    /// `T::BITS` is available.
+    ///
+    /// **Why is this bad?** `T::BITS` says what is meant, and is already a `u32`,
+    /// the type that the bit manipulation methods of integers use.
+    ///
+    /// **Known problems:** `BITS` needs Rust 1.53, the lint is disabled if the `msrv` is older.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let _ = std::mem::size_of::<usize>() * 8;
+    /// let _ = (std::mem::size_of::<u64>() * 8) as u32;
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let _ = usize::BITS as usize;
+    /// let _ = u64::BITS;
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub MANUAL_BITS,
+    style,
+    "manual implementation of `size_of::<T>() * 8` can be simplified with `T::BITS`"
+}
+
+pub struct ManualBits {
+    msrv: MsrvStack,
+}
+
+impl ManualBits {
+    pub fn new(msrv: Option<RustcVersion>) -> Self {
+        Self {
+            msrv: MsrvStack::new(msrv),
+        }
+    }
+}
+
+impl LintPass for ManualBits {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(MANUAL_BITS)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for ManualBits {
+    fn enter_lint_attrs(&mut self, cx: &LateContext<'a, 'tcx>, attrs: &'tcx [ast::Attribute]) {
+        self.msrv.enter_lint_attrs(cx.sess(), attrs);
+    }
+
+    fn exit_lint_attrs(&mut self, _: &LateContext<'a, 'tcx>, attrs: &'tcx [ast::Attribute]) {
+        self.msrv.exit_lint_attrs(attrs);
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if in_macro(expr.span) || !self.msrv.meets(msrvs::MANUAL_BITS) {
+            return;
+        }
+
+        match expr.node {
+            // `(size_of::<T>() * 8) as u32` is exactly `T::BITS`
+            ExprKind::Cast(ref inner, _) if is_u32(cx, expr) => {
+                if let Some(ty_snippet) = manual_bits_ty(cx, inner) {
+                    lint(cx, expr, format!("{}::BITS", ty_snippet));
+                }
+            },
+            ExprKind::Binary(..) => {
+                if let Some(ty_snippet) = manual_bits_ty(cx, expr) {
+                    if let Some(parent) = get_parent_expr(cx, expr) {
+                        if let ExprKind::Cast(..) = parent.node {
+                            if is_u32(cx, parent) {
+                                return;
+                            }
+                        }
+                    }
+                    lint(cx, expr, format!("{}::BITS as usize", ty_snippet));
+                }
+            },
+            _ => {},
+        }
+    }
+}
+
+fn lint(cx: &LateContext<'_, '_>, expr: &Expr, sugg: String) {
+    span_lint_and_sugg(
+        cx,
+        MANUAL_BITS,
+        expr.span,
+        "usage of `mem::size_of::<T>()` to obtain the size of `T` in bits",
+        "consider using",
+        sugg,
+    );
+}
+
+fn is_u32(cx: &LateContext<'_, '_>, expr: &Expr) -> bool {
+    cx.tables.expr_ty(expr).sty == ty::Uint(ast::UintTy::U32)
+}
+
+/// If `expr` is `size_of::<T>() * 8` or `8 * size_of::<T>()` where `T` is an integer, returns
+/// the snippet of `T`
+fn manual_bits_ty(cx: &LateContext<'_, '_>, expr: &Expr) -> Option<String> {
+    if let ExprKind::Binary(op, ref left, ref right) = expr.node {
+        if op.node != BinOpKind::Mul {
+            return None;
+        }
+        let is_eight = |e: &Expr| match constant(cx, cx.tables, e) {
+            Some((Constant::Int(8), _)) => true,
+            _ => false,
+        };
+        if is_eight(right) {
+            return size_of_int_ty(cx, left);
+        } else if is_eight(left) {
+            return size_of_int_ty(cx, right);
+        }
+    }
+    None
+}
+
+/// If `expr` is `size_of::<T>()` where `T` is an integer, returns the snippet of `T`
+fn size_of_int_ty(cx: &LateContext<'_, '_>, expr: &Expr) -> Option<String> {
+    if_chain! {
+        if let ExprKind::Call(ref fun, ref args) = expr.node;
+        if args.is_empty();
+        if let ExprKind::Path(ref qpath) = fun.node;
+        if let QPath::Resolved(None, ref path) = *qpath;
+        if let Some(def_id) = opt_def_id(cx.tables.qpath_def(qpath, fun.hir_id));
+        if match_def_path(cx.tcx, def_id, &paths::MEM_SIZE_OF);
+        if let Some(ref generic_args) = path.segments.last().and_then(|segment| segment.args.as_ref());
+        if let Some(GenericArg::Type(ref ty)) = generic_args.args.get(0);
+        then {
+            match cx.tables.node_substs(fun.hir_id).type_at(0).sty {
+                ty::Int(_) | ty::Uint(_) => Some(snippet(cx, ty.span, "..").to_string()),
+                _ => None,
+            }
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,197 @@
+use crate::consts::{constant, Constant};
+use crate::utils::sugg::Sugg;
+use crate::utils::{get_parent_expr, higher, in_macro, span_lint_and_then, SpanlessEq};
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::{declare_lint, lint_array};
+use rustc_errors::Applicability;
+use syntax::ast::{LitKind, RangeLimits};
+
+declare_clippy_lint! {
+    /// **What it does:** Suggests to use dedicated built-in methods,
+    /// `is_ascii_(lowercase|uppercase|digit|alphabetic|alphanumeric|hexdigit)` for checking if a
+    /// given `char` or `u8` is within one of the ASCII ranges.
+    ///
+    /// **Why is this bad?** Using the built-in functions is more readable and makes it
+    /// clear that it's not a specific subset of characters, but all of them.
+    ///
+    /// **Known problems:** Only inclusive ranges and `>=`/`<=` comparisons are
+    /// checked.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let c = 'x';
+    /// assert!(('a'..='z').contains(&c));
+    /// assert!(c >= 'A' && c <= 'Z');
+    /// assert!(('0'..='9').contains(&c) || ('a'..='f').contains(&c) || ('A'..='F').contains(&c));
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let c = 'x';
+    /// assert!(c.is_ascii_lowercase());
+    /// assert!(c.is_ascii_uppercase());
+    /// assert!(c.is_ascii_hexdigit());
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub MANUAL_IS_ASCII_CHECK,
+    style,
+    "use dedicated method to check ascii range"
+}
+
+#[derive(Copy, Clone)]
+pub struct ManualIsAsciiCheck;
+
+impl LintPass for ManualIsAsciiCheck {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(MANUAL_IS_ASCII_CHECK)
+    }
+}
+
+/// The unions of ranges checked by the `is_ascii_*` methods, each sorted
+const ASCII_METHODS: [(&[(char, char)], &str); 6] = [
+    (&[('a', 'z')], "is_ascii_lowercase"),
+    (&[('A', 'Z')], "is_ascii_uppercase"),
+    (&[('0', '9')], "is_ascii_digit"),
+    (&[('A', 'Z'), ('a', 'z')], "is_ascii_alphabetic"),
+    (&[('0', '9'), ('A', 'Z'), ('a', 'z')], "is_ascii_alphanumeric"),
+    (&[('0', '9'), ('A', 'F'), ('a', 'f')], "is_ascii_hexdigit"),
+];
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for ManualIsAsciiCheck {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if in_macro(expr.span) {
+            return;
+        }
+        let mut check = match ascii_ranges(cx, expr) {
+            Some(check) => check,
+            None => return,
+        };
+        // only the whole `||` chain is checked
+        if let Some(parent) = get_parent_expr(cx, expr) {
+            if let ExprKind::Binary(op, ..) = parent.node {
+                if op.node == BinOpKind::Or && ascii_ranges(cx, parent).is_some() {
+                    return;
+                }
+            }
+        }
+
+        check.ranges.sort();
+        check.ranges.dedup();
+        if let Some(&(_, method)) = ASCII_METHODS.iter().find(|(ranges, _)| *ranges == &check.ranges[..]) {
+            span_lint_and_then(
+                cx,
+                MANUAL_IS_ASCII_CHECK,
+                expr.span,
+                "manual check for common ascii range",
+                |db| {
+                    db.span_suggestion_with_applicability(
+                        expr.span,
+                        "try",
+                        format!("{}.{}()", Sugg::hir(cx, check.operand, "..").maybe_par(), method),
+                        Applicability::MachineApplicable,
+                    );
+                },
+            );
+        }
+    }
+}
+
+/// The ranges a `char` or a byte is checked to be in
+struct AsciiRanges<'a> {
+    operand: &'a Expr,
+    ranges: Vec<(char, char)>,
+    is_byte: bool,
+}
+
+/// Parses `('a'..='z').contains(&c)`, `c >= 'a' && c <= 'z'` and their `||` combinations
+fn ascii_ranges<'a>(cx: &LateContext<'_, '_>, expr: &'a Expr) -> Option<AsciiRanges<'a>> {
+    match expr.node {
+        ExprKind::Binary(op, ref lhs, ref rhs) if op.node == BinOpKind::Or => {
+            let mut lhs = ascii_ranges(cx, lhs)?;
+            let rhs = ascii_ranges(cx, rhs)?;
+            if lhs.is_byte != rhs.is_byte || !SpanlessEq::new(cx).ignore_fn().eq_expr(lhs.operand, rhs.operand) {
+                return None;
+            }
+            lhs.ranges.extend(rhs.ranges);
+            Some(lhs)
+        },
+        ExprKind::Binary(op, ref lhs, ref rhs) if op.node == BinOpKind::And => {
+            let (lhs_operand, lhs_bound) = bound(cx, lhs)?;
+            let (rhs_operand, rhs_bound) = bound(cx, rhs)?;
+            let ((start, start_is_byte), (end, end_is_byte)) = match (lhs_bound, rhs_bound) {
+                (Bound::Lower(start), Bound::Upper(end)) | (Bound::Upper(end), Bound::Lower(start)) => (start, end),
+                _ => return None,
+            };
+            if start_is_byte != end_is_byte || !SpanlessEq::new(cx).ignore_fn().eq_expr(lhs_operand, rhs_operand) {
+                return None;
+            }
+            Some(AsciiRanges {
+                operand: lhs_operand,
+                ranges: vec![(start, end)],
+                is_byte: start_is_byte,
+            })
+        },
+        ExprKind::MethodCall(ref path, _, ref args) if path.ident.name == "contains" && args.len() == 2 => {
+            let range = higher::range(cx, &args[0])?;
+            let (start, end) = match (range.start, range.end, range.limits) {
+                (Some(start), Some(end), RangeLimits::Closed) => (endpoint(cx, start)?, endpoint(cx, end)?),
+                _ => return None,
+            };
+            if start.1 != end.1 {
+                return None;
+            }
+            let operand = match args[1].node {
+                ExprKind::AddrOf(_, ref operand) => &**operand,
+                _ => &args[1],
+            };
+            Some(AsciiRanges {
+                operand,
+                ranges: vec![(start.0, end.0)],
+                is_byte: start.1,
+            })
+        },
+        _ => None,
+    }
+}
+
+/// An inclusive bound of a range, with whether it's a byte
+enum Bound {
+    Lower((char, bool)),
+    Upper((char, bool)),
+}
+
+/// Parses `c >= 'a'`, `'a' <= c` and the same for an upper bound
+fn bound<'a>(cx: &LateContext<'_, '_>, expr: &'a Expr) -> Option<(&'a Expr, Bound)> {
+    if let ExprKind::Binary(op, ref lhs, ref rhs) = expr.node {
+        // `'a' <= c` is handled as `c >= 'a'`
+        let (operand, value, op) = match (endpoint(cx, lhs), endpoint(cx, rhs)) {
+            (None, Some(value)) => (&**lhs, value, op.node),
+            (Some(value), None) => match op.node {
+                BinOpKind::Ge => (&**rhs, value, BinOpKind::Le),
+                BinOpKind::Le => (&**rhs, value, BinOpKind::Ge),
+                _ => return None,
+            },
+            _ => return None,
+        };
+        return match op {
+            BinOpKind::Ge => Some((operand, Bound::Lower(value))),
+            BinOpKind::Le => Some((operand, Bound::Upper(value))),
+            _ => None,
+        };
+    }
+    None
+}
+
+/// Returns the ASCII character of a `char` or byte endpoint, and whether it's a byte
+fn endpoint(cx: &LateContext<'_, '_>, expr: &Expr) -> Option<(char, bool)> {
+    // integers are only checked when written as byte literals
+    if let ExprKind::Lit(ref lit) = expr.node {
+        if let LitKind::Byte(b) = lit.node {
+            return Some((char::from(b), true));
+        }
+    }
+    match constant(cx, cx.tables, expr) {
+        Some((Constant::Char(c), _)) if c.is_ascii() => Some((c, false)),
+        _ => None,
+    }
+}
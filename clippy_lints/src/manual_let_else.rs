@@ -0,0 +1,180 @@
+use crate::utils::msrvs::{self, MsrvStack, RustcVersion};
+use crate::utils::{in_macro, remove_blocks, snippet_opt, span_lint_and_then};
+use if_chain::if_chain;
+use rustc::hir::def::Def;
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintContext, LintPass};
+use rustc::{declare_lint, lint_array};
+use rustc_errors::Applicability;
+use syntax::ast;
+
+declare_clippy_lint! {
+    /// **What it does:** Warns when a `let` binding is initialized with a `match`
+    /// or an `if let` whose only non-diverging branch returns the value bound by
+    /// its pattern.
+    ///
+    /// **Why is this bad?** The `let...else` syntax binds the value directly and
+    /// makes the early return stand out.
+    ///
+    /// **Known problems:** `let...else` needs Rust 1.65, the lint is disabled if
+    /// the `msrv` is older. Only branches whose type is `!` are known to diverge,
+    /// e.g. `return`, `continue` or `panic!()`.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// let v = match opt {
+    ///     Some(v) => v,
+    ///     None => return,
+    /// };
+    /// let w = if let Some(w) = opt { w } else { return };
+    /// ```
+    /// Could be written:
+    /// ```rust,ignore
+    /// let Some(v) = opt else { return };
+    /// let Some(w) = opt else { return };
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub MANUAL_LET_ELSE,
+    pedantic,
+    "manual implementation of a let...else statement"
+}
+
+pub struct ManualLetElse {
+    msrv: MsrvStack,
+}
+
+impl ManualLetElse {
+    pub fn new(msrv: Option<RustcVersion>) -> Self {
+        Self {
+            msrv: MsrvStack::new(msrv),
+        }
+    }
+}
+
+impl LintPass for ManualLetElse {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(MANUAL_LET_ELSE)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for ManualLetElse {
+    fn enter_lint_attrs(&mut self, cx: &LateContext<'a, 'tcx>, attrs: &'tcx [ast::Attribute]) {
+        self.msrv.enter_lint_attrs(cx.sess(), attrs);
+    }
+
+    fn exit_lint_attrs(&mut self, _: &LateContext<'a, 'tcx>, attrs: &'tcx [ast::Attribute]) {
+        self.msrv.exit_lint_attrs(attrs);
+    }
+
+    fn check_stmt(&mut self, cx: &LateContext<'a, 'tcx>, stmt: &'tcx Stmt) {
+        if_chain! {
+            if self.msrv.meets(msrvs::LET_ELSE);
+            if !in_macro(stmt.span);
+            if let StmtKind::Decl(ref decl, _) = stmt.node;
+            if let DeclKind::Local(ref local) = decl.node;
+            // the type annotation would have to be moved into the pattern
+            if local.ty.is_none();
+            if let PatKind::Binding(_, _, _, None) = local.pat.node;
+            if let Some(ref init) = local.init;
+            if let ExprKind::Match(ref scrutinee, ref arms, ref source) = init.node;
+            if let MatchSource::Normal | MatchSource::IfLetDesugar { contains_else_clause: true } = *source;
+            if arms.len() == 2 && arms.iter().all(|arm| arm.guard.is_none() && arm.pats.len() == 1);
+            if let Some((pat, binding, diverging)) = binding_and_diverging_arms(cx, arms);
+            if let Some(pat_snip) = snippet_opt(cx, pat.span);
+            if let Some(binding_snip) = snippet_opt(cx, local.pat.span);
+            if let Some(scrutinee_snip) = snippet_opt(cx, scrutinee.span);
+            if let Some(else_snip) = snippet_opt(cx, diverging.span);
+            let start = (binding.span.lo() - pat.span.lo()).0 as usize;
+            let end = (binding.span.hi() - pat.span.lo()).0 as usize;
+            if end <= pat_snip.len();
+            then {
+                // the binding of the pattern takes the name and the mutability of the local
+                let pat_snip = format!("{}{}{}", &pat_snip[..start], binding_snip, &pat_snip[end..]);
+                let else_snip = match diverging.node {
+                    ExprKind::Block(..) => else_snip,
+                    _ => format!("{{ {} }}", else_snip),
+                };
+                span_lint_and_then(
+                    cx,
+                    MANUAL_LET_ELSE,
+                    stmt.span,
+                    "this could be rewritten as `let...else`",
+                    |db| {
+                        db.span_suggestion_with_applicability(
+                            stmt.span,
+                            "consider writing",
+                            format!("let {} = {} else {};", pat_snip, scrutinee_snip, else_snip),
+                            Applicability::MaybeIncorrect,
+                        );
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// Finds the arm returning the single binding of its pattern and the diverging arm, returns the
+/// pattern, the binding and the diverging expression
+fn binding_and_diverging_arms<'a>(cx: &LateContext<'_, '_>, arms: &'a [Arm]) -> Option<(&'a Pat, &'a Pat, &'a Expr)> {
+    for &(bind_arm, diverging_arm) in &[(&arms[0], &arms[1]), (&arms[1], &arms[0])] {
+        let pat = &bind_arm.pats[0];
+        if_chain! {
+            if let Some(binding) = single_binding(pat);
+            if let PatKind::Binding(BindingAnnotation::Unannotated, id, _, None)
+                | PatKind::Binding(BindingAnnotation::Mutable, id, _, None) = binding.node;
+            if let ExprKind::Path(QPath::Resolved(None, ref path)) = remove_blocks(&bind_arm.body).node;
+            if let Def::Local(local_id) = path.def;
+            if local_id == id;
+            if !has_bindings(&diverging_arm.pats[0]);
+            if cx.tables.expr_ty(&diverging_arm.body).is_never();
+            then {
+                return Some((pat, binding, &diverging_arm.body));
+            }
+        }
+    }
+    None
+}
+
+fn single_binding(pat: &Pat) -> Option<&Pat> {
+    let mut bindings = Vec::new();
+    collect_bindings(pat, &mut bindings);
+    if bindings.len() == 1 {
+        bindings.pop()
+    } else {
+        None
+    }
+}
+
+fn has_bindings(pat: &Pat) -> bool {
+    let mut bindings = Vec::new();
+    collect_bindings(pat, &mut bindings);
+    !bindings.is_empty()
+}
+
+fn collect_bindings<'a>(pat: &'a Pat, bindings: &mut Vec<&'a Pat>) {
+    match pat.node {
+        PatKind::Binding(_, _, _, ref sub) => {
+            bindings.push(pat);
+            if let Some(ref sub) = *sub {
+                collect_bindings(sub, bindings);
+            }
+        },
+        PatKind::Struct(_, ref fields, _) => {
+            for field in fields {
+                collect_bindings(&field.node.pat, bindings);
+            }
+        },
+        PatKind::TupleStruct(_, ref pats, _) | PatKind::Tuple(ref pats, _) => {
+            for pat in pats {
+                collect_bindings(pat, bindings);
+            }
+        },
+        PatKind::Box(ref pat) | PatKind::Ref(ref pat, _) => collect_bindings(pat, bindings),
+        PatKind::Slice(ref before, ref mid, ref after) => {
+            for pat in before.iter().chain(mid).chain(after) {
+                collect_bindings(pat, bindings);
+            }
+        },
+        PatKind::Wild | PatKind::Path(..) | PatKind::Lit(..) | PatKind::Range(..) => {},
+    }
+}
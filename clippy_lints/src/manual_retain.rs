@@ -0,0 +1,176 @@
+use crate::utils::msrvs::{self, MsrvStack, RustcVersion};
+use crate::utils::{in_macro, match_type, method_chain_args, paths, snippet_opt, span_lint_and_then, SpanlessEq};
+use if_chain::if_chain;
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintContext, LintPass};
+use rustc::{declare_lint, lint_array};
+use rustc_errors::Applicability;
+use syntax::ast;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for collections reassigned with a filtered copy of
+    /// themselves, like `v = v.into_iter().filter(f).collect()`, for `Vec`s,
+    /// `String`s, `HashSet`s and `HashMap`s.
+    ///
+    /// **Why is this bad?** `retain` filters the collection in place, without
+    /// allocating a new one.
+    ///
+    /// **Known problems:** Only the filters whose closure can be adapted to the
+    /// argument of `retain` are linted, e.g. `chars().filter(|&c| ..)` but not
+    /// `chars().filter(|c| ..)`.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let mut vec = vec![0, 1, 2];
+    /// vec = vec.into_iter().filter(|x| x % 2 == 0).collect();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let mut vec = vec![0, 1, 2];
+    /// vec.retain(|x| x % 2 == 0);
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub MANUAL_RETAIN,
+    perf,
+    "filtering a collection into itself instead of calling `retain`"
+}
+
+pub struct ManualRetain {
+    msrv: MsrvStack,
+}
+
+impl ManualRetain {
+    pub fn new(msrv: Option<RustcVersion>) -> Self {
+        Self {
+            msrv: MsrvStack::new(msrv),
+        }
+    }
+}
+
+impl LintPass for ManualRetain {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(MANUAL_RETAIN)
+    }
+}
+
+/// How the closure of `filter` is turned into the closure of `retain`
+#[derive(Copy, Clone, PartialEq)]
+enum Adapt {
+    /// Both closures take the same argument
+    Same,
+    /// The argument of `filter` is a reference to the one of `retain`: `|&x|` becomes `|x|`
+    Deref,
+    /// `filter` takes a `&(key, value)` tuple, `retain` takes `key, value`
+    Entry,
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for ManualRetain {
+    fn enter_lint_attrs(&mut self, cx: &LateContext<'a, 'tcx>, attrs: &'tcx [ast::Attribute]) {
+        self.msrv.enter_lint_attrs(cx.sess(), attrs);
+    }
+
+    fn exit_lint_attrs(&mut self, _: &LateContext<'a, 'tcx>, attrs: &'tcx [ast::Attribute]) {
+        self.msrv.exit_lint_attrs(attrs);
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if_chain! {
+            if !in_macro(expr.span);
+            if let ExprKind::Assign(ref target, ref value) = expr.node;
+            if let Some((source, filter, adapt)) = self.filter_chain(cx, target, value);
+            if SpanlessEq::new(cx).ignore_fn().eq_expr(target, source);
+            if let Some(target_snip) = snippet_opt(cx, target.span);
+            if let Some((closure_snip, applicability)) = retain_closure(cx, filter, adapt);
+            then {
+                span_lint_and_then(
+                    cx,
+                    MANUAL_RETAIN,
+                    expr.span,
+                    "this expression can be written more simply using `.retain()`",
+                    |db| {
+                        db.span_suggestion_with_applicability(
+                            expr.span,
+                            "consider calling `.retain()` instead",
+                            format!("{}.retain({})", target_snip, closure_snip),
+                            applicability,
+                        );
+                    },
+                );
+            }
+        }
+    }
+}
+
+impl ManualRetain {
+    /// Returns the collection filtered by `value`, the closure of `filter` and how to adapt it, if
+    /// the filtered collection can be collected into `target` with `retain`
+    fn filter_chain<'e>(
+        &self,
+        cx: &LateContext<'_, '_>,
+        target: &Expr,
+        value: &'e Expr,
+    ) -> Option<(&'e Expr, &'e Expr, Adapt)> {
+        let ty = cx.tables.expr_ty(target);
+        let is_vec = match_type(cx, ty, &paths::VEC);
+        let is_set = match_type(cx, ty, &paths::HASHSET) && self.msrv.meets(msrvs::HASH_RETAIN);
+        let is_map = match_type(cx, ty, &paths::HASHMAP) && self.msrv.meets(msrvs::HASH_RETAIN);
+        let is_string = match_type(cx, ty, &paths::STRING) && self.msrv.meets(msrvs::STRING_RETAIN);
+
+        if let Some(args) = method_chain_args(value, &["into_iter", "filter", "collect"]) {
+            if is_vec || is_set {
+                return Some((&args[0][0], &args[1][1], Adapt::Same));
+            }
+            if is_map {
+                return Some((&args[0][0], &args[1][1], Adapt::Entry));
+            }
+        }
+        if let Some(args) = method_chain_args(value, &["iter", "cloned", "filter", "collect"]) {
+            if is_vec || is_set {
+                return Some((&args[0][0], &args[2][1], Adapt::Same));
+            }
+        }
+        if let Some(args) = method_chain_args(value, &["iter", "filter", "cloned", "collect"]) {
+            if is_vec || is_set {
+                return Some((&args[0][0], &args[1][1], Adapt::Deref));
+            }
+        }
+        if let Some(args) = method_chain_args(value, &["chars", "filter", "collect"]) {
+            if is_string {
+                return Some((&args[0][0], &args[1][1], Adapt::Deref));
+            }
+        }
+        None
+    }
+}
+
+/// Returns the closure to pass to `retain`
+fn retain_closure(cx: &LateContext<'_, '_>, filter: &Expr, adapt: Adapt) -> Option<(String, Applicability)> {
+    if adapt == Adapt::Same {
+        return Some((snippet_opt(cx, filter.span)?, Applicability::MachineApplicable));
+    }
+    let body = match filter.node {
+        ExprKind::Closure(_, _, body_id, _, _) => cx.tcx.hir.body(body_id),
+        _ => return None,
+    };
+    if body.arguments.len() != 1 {
+        return None;
+    }
+    let body_snip = snippet_opt(cx, body.value.span)?;
+    let (params, applicability) = match (adapt, &body.arguments[0].pat.node) {
+        (Adapt::Deref, &PatKind::Ref(ref inner, _)) => (snippet_opt(cx, inner.span)?, Applicability::MachineApplicable),
+        (Adapt::Entry, &PatKind::Ref(ref inner, _)) => match inner.node {
+            PatKind::Tuple(ref pats, None) if pats.len() == 2 => (
+                format!("&{}, &mut {}", snippet_opt(cx, pats[0].span)?, snippet_opt(cx, pats[1].span)?),
+                Applicability::MachineApplicable,
+            ),
+            _ => return None,
+        },
+        // the value is now a mutable reference
+        (Adapt::Entry, &PatKind::Tuple(ref pats, None)) if pats.len() == 2 => (
+            format!("{}, {}", snippet_opt(cx, pats[0].span)?, snippet_opt(cx, pats[1].span)?),
+            Applicability::MaybeIncorrect,
+        ),
+        _ => return None,
+    };
+    Some((format!("|{}| {}", params, body_snip), applicability))
+}
@@ -0,0 +1,88 @@
+use crate::utils::{in_macro, match_def_path, match_trait_method, match_type, opt_def_id, paths, span_lint_and_then};
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::{declare_lint, lint_array};
+use rustc_errors::Applicability;
+use syntax::ast::LitKind;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for empty `String`s built from an empty string
+    /// literal, with `"".to_string()`, `"".to_owned()`, `"".into()` or
+    /// `String::from("")`.
+    ///
+    /// **Why is this bad?** `String::new()` is clearer, and obviously doesn't allocate.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let s = "".to_string();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let s = String::new();
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub MANUAL_STRING_NEW,
+    pedantic,
+    "building an empty `String` from an empty string literal instead of using `String::new()`"
+}
+
+#[derive(Copy, Clone)]
+pub struct ManualStringNew;
+
+impl LintPass for ManualStringNew {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(MANUAL_STRING_NEW)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for ManualStringNew {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if in_macro(expr.span) || !match_type(cx, cx.tables.expr_ty(expr), &paths::STRING) {
+            return;
+        }
+        let is_manual = match expr.node {
+            ExprKind::MethodCall(ref path, _, ref args) => {
+                is_empty_str(&args[0])
+                    && match &*path.ident.as_str() {
+                        "to_string" => match_trait_method(cx, expr, &paths::TO_STRING),
+                        "to_owned" => match_trait_method(cx, expr, &paths::TO_OWNED),
+                        "into" => match_trait_method(cx, expr, &paths::INTO),
+                        _ => false,
+                    }
+            },
+            ExprKind::Call(ref func, ref args) => args.len() == 1 && is_empty_str(&args[0]) && is_from(cx, func),
+            _ => false,
+        };
+        if is_manual {
+            span_lint_and_then(cx, MANUAL_STRING_NEW, expr.span, "empty String is being created manually", |db| {
+                db.span_suggestion_with_applicability(
+                    expr.span,
+                    "consider using",
+                    "String::new()".to_string(),
+                    Applicability::MachineApplicable,
+                );
+            });
+        }
+    }
+}
+
+fn is_empty_str(expr: &Expr) -> bool {
+    if let ExprKind::Lit(ref lit) = expr.node {
+        if let LitKind::Str(ref s, _) = lit.node {
+            return s.as_str().is_empty();
+        }
+    }
+    false
+}
+
+/// Checks for a path to `From::from`, e.g. `String::from`
+fn is_from(cx: &LateContext<'_, '_>, func: &Expr) -> bool {
+    if let ExprKind::Path(ref qpath) = func.node {
+        if let Some(def_id) = opt_def_id(cx.tables.qpath_def(qpath, func.hir_id)) {
+            return match_def_path(cx.tcx, def_id, &paths::FROM_FROM);
+        }
+    }
+    false
+}
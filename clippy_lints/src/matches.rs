@@ -1,178 +1,315 @@
 use rustc::hir::*;
+use rustc::hir::def::{CtorKind, Def};
+use rustc::hir::def_id::DefId;
 use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass, in_external_macro, LintContext};
 use rustc::{declare_lint, lint_array};
 use if_chain::if_chain;
 use rustc::ty::{self, Ty};
 use std::cmp::Ordering;
-use std::collections::Bound;
-use syntax::ast::LitKind;
+use std::collections::{Bound, HashSet};
+use syntax::ast::{LitKind, Name, NodeId};
+use rustc_errors::Applicability;
 use syntax::source_map::Span;
 use crate::utils::paths;
-use crate::utils::{expr_block, is_allowed, is_expn_of, match_qpath, match_type, multispan_sugg,
-            remove_blocks, snippet, span_lint_and_sugg, span_lint_and_then, span_note_and_lint, walk_ptrs_ty};
+use crate::utils::{contains_name, expr_block, in_macro, is_allowed, is_expn_of, match_qpath, match_type,
+            multispan_sugg, remove_blocks, same_tys, snippet, span_help_and_lint, span_lint_and_sugg,
+            span_lint_and_then, span_note_and_lint, walk_ptrs_ty};
 use crate::utils::sugg::Sugg;
 use crate::consts::{constant, Constant};
 
-/// **What it does:** Checks for matches with a single arm where an `if let`
-/// will usually suffice.
-///
-/// **Why is this bad?** Just readability – `if let` nests less than a `match`.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// match x {
-///     Some(ref foo) => bar(foo),
-///     _ => ()
-/// }
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for matches with a single arm where an `if let`
+    /// will usually suffice.
+    ///
+    /// **Why is this bad?** Just readability – `if let` nests less than a `match`.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// match x {
+    ///     Some(ref foo) => bar(foo),
+    ///     _ => ()
+    /// }
+    /// ```
+    #[clippy::version = "pre 0.0.213"]
     pub SINGLE_MATCH,
     style,
     "a match statement with a single nontrivial arm (i.e. where the other arm \
      is `_ => {}`) instead of `if let`"
 }
 
-/// **What it does:** Checks for matches with a two arms where an `if let` will
-/// usually suffice.
-///
-/// **Why is this bad?** Just readability – `if let` nests less than a `match`.
-///
-/// **Known problems:** Personal style preferences may differ.
-///
-/// **Example:**
-/// ```rust
-/// match x {
-///     Some(ref foo) => bar(foo),
-///     _ => bar(other_ref),
-/// }
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for matches with a two arms where an `if let` will
+    /// usually suffice.
+    ///
+    /// **Why is this bad?** Just readability – `if let` nests less than a `match`.
+    ///
+    /// **Known problems:** Personal style preferences may differ.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// match x {
+    ///     Some(ref foo) => bar(foo),
+    ///     _ => bar(other_ref),
+    /// }
+    /// ```
+    #[clippy::version = "pre 0.0.213"]
     pub SINGLE_MATCH_ELSE,
     pedantic,
     "a match statement with a two arms where the second arm's pattern is a wildcard \
      instead of `if let`"
 }
 
-/// **What it does:** Checks for matches where all arms match a reference,
-/// suggesting to remove the reference and deref the matched expression
-/// instead. It also checks for `if let &foo = bar` blocks.
-///
-/// **Why is this bad?** It just makes the code less readable. That reference
-/// destructuring adds nothing to the code.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// match x {
-///     &A(ref y) => foo(y),
-///     &B => bar(),
-///     _ => frob(&x),
-/// }
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for matches where all arms match a reference,
+    /// suggesting to remove the reference and deref the matched expression
+    /// instead. It also checks for `if let &foo = bar` blocks.
+    ///
+    /// **Why is this bad?** It just makes the code less readable. That reference
+    /// destructuring adds nothing to the code.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// match x {
+    ///     &A(ref y) => foo(y),
+    ///     &B => bar(),
+    ///     _ => frob(&x),
+    /// }
+    /// ```
+    #[clippy::version = "pre 0.0.213"]
     pub MATCH_REF_PATS,
     style,
     "a match or `if let` with all arms prefixed with `&` instead of deref-ing the match expression"
 }
 
-/// **What it does:** Checks for matches where match expression is a `bool`. It
-/// suggests to replace the expression with an `if...else` block.
-///
-/// **Why is this bad?** It makes the code less readable.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// let condition: bool = true;
-/// match condition {
-///     true => foo(),
-///     false => bar(),
-/// }
-/// ```
-/// Use if/else instead:
-/// ```rust
-/// let condition: bool = true;
-/// if condition {
-///     foo();
-/// } else {
-///     bar();
-/// }
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for matches where match expression is a `bool`. It
+    /// suggests to replace the expression with an `if...else` block.
+    ///
+    /// **Why is this bad?** It makes the code less readable.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let condition: bool = true;
+    /// match condition {
+    ///     true => foo(),
+    ///     false => bar(),
+    /// }
+    /// ```
+    /// Use if/else instead:
+    /// ```rust
+    /// let condition: bool = true;
+    /// if condition {
+    ///     foo();
+    /// } else {
+    ///     bar();
+    /// }
+    /// ```
+    #[clippy::version = "pre 0.0.213"]
     pub MATCH_BOOL,
     style,
     "a match on a boolean expression instead of an `if..else` block"
 }
 
-/// **What it does:** Checks for overlapping match arms.
-///
-/// **Why is this bad?** It is likely to be an error and if not, makes the code
-/// less obvious.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// let x = 5;
-/// match x {
-///     1 ... 10 => println!("1 ... 10"),
-///     5 ... 15 => println!("5 ... 15"),
-///     _ => (),
-/// }
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for overlapping match arms.
+    ///
+    /// **Why is this bad?** It is likely to be an error and if not, makes the code
+    /// less obvious.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let x = 5;
+    /// match x {
+    ///     1 ... 10 => println!("1 ... 10"),
+    ///     5 ... 15 => println!("5 ... 15"),
+    ///     _ => (),
+    /// }
+    /// ```
+    #[clippy::version = "pre 0.0.213"]
     pub MATCH_OVERLAPPING_ARM,
     style,
     "a match with overlapping arms"
 }
 
-/// **What it does:** Checks for arm which matches all errors with `Err(_)`
-/// and take drastic actions like `panic!`.
-///
-/// **Why is this bad?** It is generally a bad practice, just like
-/// catching all exceptions in java with `catch(Exception)`
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// let x : Result(i32, &str) = Ok(3);
-/// match x {
-///     Ok(_) => println!("ok"),
-///     Err(_) => panic!("err"),
-/// }
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for arm which matches all errors with `Err(_)`
+    /// and take drastic actions like `panic!`.
+    ///
+    /// **Why is this bad?** It is generally a bad practice, just like
+    /// catching all exceptions in java with `catch(Exception)`
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let x : Result(i32, &str) = Ok(3);
+    /// match x {
+    ///     Ok(_) => println!("ok"),
+    ///     Err(_) => panic!("err"),
+    /// }
+    /// ```
+    #[clippy::version = "pre 0.0.213"]
     pub MATCH_WILD_ERR_ARM,
     style,
     "a match with `Err(_)` arm and take drastic actions"
 }
 
-/// **What it does:** Checks for match which is used to add a reference to an
-/// `Option` value.
-///
-/// **Why is this bad?** Using `as_ref()` or `as_mut()` instead is shorter.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// let x: Option<()> = None;
-/// let r: Option<&()> = match x {
-///   None => None,
-///   Some(ref v) => Some(v),
-/// };
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for match which is used to add a reference to an
+    /// `Option` value.
+    ///
+    /// **Why is this bad?** Using `as_ref()` or `as_mut()` instead is shorter.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let x: Option<()> = None;
+    /// let r: Option<&()> = match x {
+    ///   None => None,
+    ///   Some(ref v) => Some(v),
+    /// };
+    /// ```
+    #[clippy::version = "0.0.177"]
     pub MATCH_AS_REF,
     complexity,
     "a match on an Option value instead of using `as_ref()` or `as_mut`"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** Checks for wildcard enum matches using `_`.
+    ///
+    /// **Why is this bad?** New enum variants added by library updates can be missed.
+    ///
+    /// **Known problems:** Suggested replacements may be incorrect if guards exhaustively cover some
+    /// variants, and also may not use correct path to enum if it's not present in the current scope.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # enum Foo { A(usize), B(usize) }
+    /// # let x = Foo::B(1);
+    /// match x {
+    ///     Foo::A(_) => {},
+    ///     _ => {},
+    /// }
+    /// ```
+    ///
+    /// Use instead:
+    /// ```rust
+    /// # enum Foo { A(usize), B(usize) }
+    /// # let x = Foo::B(1);
+    /// match x {
+    ///     Foo::A(_) => {},
+    ///     Foo::B(_) => {},
+    /// }
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub WILDCARD_ENUM_MATCH_ARM,
+    restriction,
+    "a wildcard enum match arm using `_`"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for struct patterns with a `..` rest pattern where
+    /// all the fields are already bound.
+    ///
+    /// **Why is this bad?** The `..` doesn't match anything, and without it adding
+    /// a field to the struct makes the pattern fail to compile, which helps to
+    /// update the code handling it.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # struct A { a: i32 }
+    /// let a = A { a: 5 };
+    ///
+    /// match a {
+    ///     A { a: 5, .. } => {},
+    ///     _ => {},
+    /// }
+    /// ```
+    ///
+    /// Use instead:
+    /// ```rust
+    /// # struct A { a: i32 }
+    /// let a = A { a: 5 };
+    ///
+    /// match a {
+    ///     A { a: 5 } => {},
+    ///     _ => {},
+    /// }
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub REST_PAT_IN_FULLY_BOUND_STRUCTS,
+    restriction,
+    "a match on a struct that binds all fields but still uses the wildcard pattern"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for slice patterns made of a single binding to the
+    /// rest of the slice, like `[x..]`.
+    ///
+    /// **Why is this bad?** The binding covers the whole slice, the pattern is
+    /// just `x` with extra steps.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// if let [x..] = slice {}
+    /// ```
+    ///
+    /// Use instead:
+    /// ```rust,ignore
+    /// if let x = slice {}
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub REDUNDANT_AT_REST_PATTERN,
+    style,
+    "slice pattern binding the whole slice with a rest pattern, like `[x..]`"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for match guards comparing a binding of the pattern
+    /// to a literal, like `Some(x) if x == 5`.
+    ///
+    /// **Why is this bad?** The literal can be matched by the pattern itself, which
+    /// is simpler and lets the compiler check the exhaustiveness of the match.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let x = Some(5);
+    /// match x {
+    ///     Some(y) if y == 5 => {},
+    ///     _ => {},
+    /// }
+    /// ```
+    ///
+    /// Use instead:
+    /// ```rust
+    /// # let x = Some(5);
+    /// match x {
+    ///     Some(5) => {},
+    ///     _ => {},
+    /// }
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub REDUNDANT_GUARDS,
+    style,
+    "match guards comparing a binding to a literal the pattern could match"
+}
+
 #[allow(missing_copy_implementations)]
 pub struct MatchPass;
 
@@ -185,7 +322,11 @@ impl LintPass for MatchPass {
             SINGLE_MATCH_ELSE,
             MATCH_OVERLAPPING_ARM,
             MATCH_WILD_ERR_ARM,
-            MATCH_AS_REF
+            MATCH_AS_REF,
+            WILDCARD_ENUM_MATCH_ARM,
+            REST_PAT_IN_FULLY_BOUND_STRUCTS,
+            REDUNDANT_AT_REST_PATTERN,
+            REDUNDANT_GUARDS
         )
     }
 }
@@ -200,12 +341,124 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for MatchPass {
             check_match_bool(cx, ex, arms, expr);
             check_overlapping_arms(cx, ex, arms);
             check_wild_err_arm(cx, ex, arms);
+            check_wild_enum_match(cx, ex, arms);
             check_match_as_ref(cx, ex, arms, expr);
+            check_redundant_guards(cx, arms);
         }
         if let ExprKind::Match(ref ex, ref arms, _) = expr.node {
             check_match_ref_pats(cx, ex, arms, expr);
         }
     }
+
+    fn check_pat(&mut self, cx: &LateContext<'a, 'tcx>, pat: &'tcx Pat) {
+        if_chain! {
+            if !in_external_macro(cx.sess(), pat.span);
+            if let PatKind::Struct(ref path, ref fields, true) = pat.node;
+            if let ty::Adt(adt_def, _) = cx.tables.pat_ty(pat).sty;
+            let variant = adt_def.variant_of_def(cx.tables.qpath_def(path, pat.hir_id));
+            if fields.len() == variant.fields.len();
+            then {
+                span_help_and_lint(
+                    cx,
+                    REST_PAT_IN_FULLY_BOUND_STRUCTS,
+                    pat.span,
+                    "unnecessary use of `..` pattern in struct binding. All fields were already bound",
+                    "consider removing `..` from this binding",
+                );
+            }
+        }
+        if_chain! {
+            if !in_macro(pat.span);
+            if let PatKind::Slice(ref before, Some(ref rest), ref after) = pat.node;
+            if before.is_empty() && after.is_empty();
+            if let PatKind::Binding(annotation, _, ident, None) = rest.node;
+            then {
+                let prefix = match annotation {
+                    BindingAnnotation::Unannotated => "",
+                    BindingAnnotation::Mutable => "mut ",
+                    BindingAnnotation::Ref => "ref ",
+                    BindingAnnotation::RefMut => "ref mut ",
+                };
+                span_lint_and_sugg(
+                    cx,
+                    REDUNDANT_AT_REST_PATTERN,
+                    pat.span,
+                    "using a rest pattern to bind an entire slice to a local",
+                    "this is better represented with just the binding",
+                    format!("{}{}", prefix, ident),
+                );
+            }
+        }
+    }
+}
+
+/// Lints the guards comparing a binding of the arm (not used in its body) to a literal
+fn check_redundant_guards(cx: &LateContext<'_, '_>, arms: &[Arm]) {
+    for arm in arms {
+        if_chain! {
+            if let [ref pat] = *arm.pats;
+            if let Some(ref guard) = arm.guard;
+            if !in_macro(guard.span);
+            if let ExprKind::Binary(op, ref left, ref right) = guard.node;
+            if op.node == BinOpKind::Eq;
+            if let Some((local, lit)) = local_and_lit(left, right).or_else(|| local_and_lit(right, left));
+            if let Some(binding) = find_binding(pat, local);
+            if !contains_name(binding.1, &arm.body);
+            if same_tys(cx, cx.tables.expr_ty(left), cx.tables.expr_ty(right));
+            then {
+                span_lint_and_then(cx, REDUNDANT_GUARDS, guard.span, "redundant guard", |db| {
+                    let lit = snippet(cx, lit.span, "..");
+                    let replacement = if binding.2 {
+                        format!("{}: {}", binding.1, lit)
+                    } else {
+                        lit.into_owned()
+                    };
+                    multispan_sugg(
+                        db,
+                        "try".to_owned(),
+                        vec![
+                            (binding.0, replacement),
+                            (pat.span.shrink_to_hi().to(guard.span), String::new()),
+                        ],
+                    );
+                });
+            }
+        }
+    }
+}
+
+/// Returns the local of `local` and the literal `lit`, if they are
+fn local_and_lit<'a>(local: &Expr, lit: &'a Expr) -> Option<(NodeId, &'a Expr)> {
+    if_chain! {
+        if let ExprKind::Path(QPath::Resolved(None, ref path)) = local.node;
+        if let Def::Local(id) = path.def;
+        if let ExprKind::Lit(ref l) = lit.node;
+        if !matches!(l.node, LitKind::Float(..) | LitKind::FloatUnsuffixed(_));
+        then {
+            return Some((id, lit));
+        }
+    }
+    None
+}
+
+/// Returns the span and the name of the binding of `local` in `pat`, if it's bound by value
+/// without a subpattern, and whether it's a shorthand struct field like `Point { x, .. }`
+fn find_binding(pat: &Pat, local: NodeId) -> Option<(Span, Name, bool)> {
+    let mut found = None;
+    let mut is_shorthand = false;
+    pat.walk(|p| {
+        match p.node {
+            PatKind::Binding(BindingAnnotation::Unannotated, _, ident, None) if p.id == local => {
+                found = Some((p.span, ident.name));
+            },
+            PatKind::Struct(_, ref fields, _) => {
+                is_shorthand |= fields.iter().any(|field| field.node.is_shorthand && field.node.pat.id == local);
+            },
+            _ => (),
+        }
+        found.is_none()
+    });
+    found.map(|(span, name)| (span, name, is_shorthand))
 }
 
 #[rustfmt::skip]
@@ -401,6 +654,109 @@ fn check_wild_err_arm(cx: &LateContext<'_, '_>, ex: &Expr, arms: &[Arm]) {
     }
 }
 
+fn check_wild_enum_match(cx: &LateContext<'_, '_>, ex: &Expr, arms: &[Arm]) {
+    let adt_def = match walk_ptrs_ty(cx.tables.expr_ty(ex)).sty {
+        ty::Adt(adt_def, _) if adt_def.is_enum() => adt_def,
+        _ => return,
+    };
+
+    let mut wildcard_span = None;
+    let mut covered = HashSet::new();
+    for arm in arms {
+        // a guarded arm doesn't cover its variants
+        if arm.guard.is_some() {
+            continue;
+        }
+        for pat in &arm.pats {
+            let pat = strip_pat_refs(pat);
+            match pat.node {
+                PatKind::Wild => wildcard_span = Some(pat.span),
+                PatKind::Path(ref path) => {
+                    if let Some(id) = variant_of(cx, path, pat.hir_id) {
+                        covered.insert(id);
+                    }
+                },
+                PatKind::TupleStruct(ref path, ref inner, _) => {
+                    if inner.iter().all(|pat| is_catch_all(pat)) {
+                        if let Some(id) = variant_of(cx, path, pat.hir_id) {
+                            covered.insert(id);
+                        }
+                    }
+                },
+                PatKind::Struct(ref path, ref fields, _) => {
+                    if fields.iter().all(|field| is_catch_all(&field.node.pat)) {
+                        if let Some(id) = variant_of(cx, path, pat.hir_id) {
+                            covered.insert(id);
+                        }
+                    }
+                },
+                _ => {},
+            }
+        }
+    }
+
+    if let Some(wildcard_span) = wildcard_span {
+        let mut missing_variants: Vec<_> = adt_def
+            .variants
+            .iter()
+            .filter(|variant| !covered.contains(&variant.did))
+            .map(|variant| {
+                let path = cx.tcx.item_path_str(variant.did);
+                match variant.ctor_kind {
+                    CtorKind::Fn => format!("{}(..)", path),
+                    CtorKind::Fictive => format!("{} {{ .. }}", path),
+                    CtorKind::Const => path,
+                }
+            })
+            .collect();
+        // the wildcard is unreachable, rustc already warns about it
+        if missing_variants.is_empty() {
+            return;
+        }
+        // foreign `#[non_exhaustive]` enums still need a wildcard arm
+        if !adt_def.did.is_local() && adt_def.is_variant_list_non_exhaustive() {
+            missing_variants.push("_".to_owned());
+        }
+        span_lint_and_then(
+            cx,
+            WILDCARD_ENUM_MATCH_ARM,
+            wildcard_span,
+            "wildcard match will miss any future added variants",
+            |db| {
+                db.span_suggestion_with_applicability(
+                    wildcard_span,
+                    "try this",
+                    missing_variants.join(" | "),
+                    Applicability::MaybeIncorrect,
+                );
+            },
+        );
+    }
+}
+
+fn strip_pat_refs(mut pat: &Pat) -> &Pat {
+    while let PatKind::Ref(ref inner, _) = pat.node {
+        pat = inner;
+    }
+    pat
+}
+
+/// The enum variant a pattern path refers to
+fn variant_of(cx: &LateContext<'_, '_>, path: &QPath, id: HirId) -> Option<DefId> {
+    match cx.tables.qpath_def(path, id) {
+        Def::Variant(id) | Def::VariantCtor(id, _) => Some(id),
+        _ => None,
+    }
+}
+
+/// Checks if a sub-pattern matches any value, e.g. `_` or `ref x`
+fn is_catch_all(pat: &Pat) -> bool {
+    match pat.node {
+        PatKind::Wild | PatKind::Binding(.., None) => true,
+        _ => false,
+    }
+}
+
 // If the block contains only a `panic!` macro (as expression or statement)
 fn is_panic_block(block: &Block) -> bool {
     match (&block.expr, block.stmts.len(), block.stmts.first()) {
@@ -1,27 +1,46 @@
 use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
 use rustc::{declare_lint, lint_array};
 use rustc::hir::{Expr, ExprKind};
+use rustc::ty::Ty;
 use crate::utils::{match_def_path, opt_def_id, paths, span_lint};
 
-/// **What it does:** Checks for usage of `std::mem::forget(t)` where `t` is
-/// `Drop`.
-///
-/// **Why is this bad?** `std::mem::forget(t)` prevents `t` from running its
-/// destructor, possibly causing leaks.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// mem::forget(Rc::new(55)))
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for usage of `std::mem::forget(t)` where `t` is
+    /// `Drop`, or owns a value that is.
+    ///
+    /// **Why is this bad?** `std::mem::forget(t)` prevents `t` from running its
+    /// destructor, possibly causing leaks, or keeping a lock or a guard held.
+    ///
+    /// **Known problems:** None. The types which are leaked on purpose can be listed
+    /// in the `allowed-forget-types` configuration value.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// mem::forget(Rc::new(55)))
+    /// ```
+    #[clippy::version = "0.0.64"]
     pub MEM_FORGET,
     restriction,
     "`mem::forget` usage on `Drop` types, likely to cause memory leaks"
 }
 
-pub struct MemForget;
+pub struct MemForget {
+    allowed: Vec<String>,
+}
+
+impl MemForget {
+    pub fn new(allowed: Vec<String>) -> Self {
+        Self { allowed }
+    }
+
+    fn is_allowed(&self, cx: &LateContext<'_, '_>, ty: Ty<'_>) -> bool {
+        ty.ty_adt_def().map_or(false, |def| {
+            self.allowed
+                .iter()
+                .any(|path| match_def_path(cx.tcx, def.did, &path.split("::").collect::<Vec<_>>()))
+        })
+    }
+}
 
 impl LintPass for MemForget {
     fn get_lints(&self) -> LintArray {
@@ -36,9 +55,14 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for MemForget {
                 if let Some(def_id) = opt_def_id(cx.tables.qpath_def(qpath, path_expr.hir_id)) {
                     if match_def_path(cx.tcx, def_id, &paths::MEM_FORGET) {
                         let forgot_ty = cx.tables.expr_ty(&args[0]);
+                        if self.is_allowed(cx, forgot_ty) {
+                            return;
+                        }
 
                         if forgot_ty.ty_adt_def().map_or(false, |def| def.has_dtor(cx.tcx)) {
                             span_lint(cx, MEM_FORGET, e.span, "usage of mem::forget on Drop type");
+                        } else if forgot_ty.needs_drop(cx.tcx, cx.param_env) {
+                            span_lint(cx, MEM_FORGET, e.span, "usage of mem::forget on type with Drop fields");
                         }
                     }
                 }
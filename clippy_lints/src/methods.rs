@@ -4,678 +4,1380 @@ use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass, in_external_ma
 use rustc::{declare_lint, lint_array};
 use if_chain::if_chain;
 use rustc::ty::{self, Ty};
+use rustc::ty::layout::LayoutOf;
 use rustc::hir::def::Def;
+use rustc::hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc_errors::Applicability;
 use std::borrow::Cow;
 use std::fmt;
 use std::iter;
 use syntax::ast;
 use syntax::source_map::{Span, BytePos};
-use crate::utils::{get_arg_name, get_trait_def_id, implements_trait, in_macro, is_copy, is_expn_of, is_self,
-            is_self_ty, iter_input_pats, last_path_segment, match_def_path, match_path, match_qpath, match_trait_method,
-            match_type, method_chain_args, match_var, return_ty, remove_blocks, same_tys, single_segment_path, snippet,
-            span_lint, span_lint_and_sugg, span_lint_and_then, span_note_and_lint, walk_ptrs_ty, walk_ptrs_ty_depth, SpanlessEq};
+use syntax::symbol::LocalInternedString;
+use crate::utils::{clip, deref_target, get_arg_name, get_enclosing_block, get_parent_expr, get_trait_def_id,
+            implements_trait, in_macro, int_bits, is_copy, is_expn_of, is_self, is_self_ty, iter_input_pats,
+            last_path_segment, match_def_path, match_path, match_qpath, match_trait_method, match_type, method_calls,
+            method_chain_args, match_var, opt_def_id, return_ty, remove_blocks, same_tys, sext, single_segment_path,
+            snippet, span_help_and_lint, span_lint, span_lint_and_sugg, span_lint_and_then, span_note_and_lint, unsext,
+            walk_ptrs_ty, walk_ptrs_ty_depth, SpanlessEq};
+use crate::utils::diag_items::{is_type_diag_item, DiagItem};
+use crate::utils::msrvs::{self, MsrvStack, RustcVersion};
+use crate::utils::higher;
 use crate::utils::paths;
 use crate::utils::sugg;
 use crate::consts::{constant, Constant};
 
-#[derive(Clone)]
-pub struct Pass;
-
-/// **What it does:** Checks for `.unwrap()` calls on `Option`s.
-///
-/// **Why is this bad?** Usually it is better to handle the `None` case, or to
-/// at least call `.expect(_)` with a more helpful message. Still, for a lot of
-/// quick-and-dirty code, `unwrap` is a good choice, which is why this lint is
-/// `Allow` by default.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// x.unwrap()
-/// ```
+pub struct Pass {
+    msrv: MsrvStack,
+    enforced_endianness: Option<Endianness>,
+}
+
+impl Pass {
+    pub fn new(msrv: Option<RustcVersion>, enforced_endianness: Option<Endianness>) -> Self {
+        Self {
+            msrv: MsrvStack::new(msrv),
+            enforced_endianness,
+        }
+    }
+}
+
+/// The byte orders of the `to_*_bytes` and `from_*_bytes` conversions
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Endianness {
+    Host,
+    Little,
+    Big,
+}
+
+impl Endianness {
+    /// Parses the `enforced-endianness` configuration value, `"little"` or `"big"`
+    pub fn parse(endianness: &str) -> Option<Self> {
+        match endianness {
+            "little" => Some(Endianness::Little),
+            "big" => Some(Endianness::Big),
+            _ => None,
+        }
+    }
+
+    /// The infix of the conversions, e.g. `le` in `to_le_bytes`
+    fn infix(self) -> &'static str {
+        match self {
+            Endianness::Host => "ne",
+            Endianness::Little => "le",
+            Endianness::Big => "be",
+        }
+    }
+}
+
 declare_clippy_lint! {
+    /// **What it does:** Checks for `.unwrap()` calls on `Option`s.
+    ///
+    /// **Why is this bad?** Usually it is better to handle the `None` case, or to
+    /// at least call `.expect(_)` with a more helpful message. Still, for a lot of
+    /// quick-and-dirty code, `unwrap` is a good choice, which is why this lint is
+    /// `Allow` by default.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// x.unwrap()
+    /// ```
+    #[clippy::version = "pre 0.0.213"]
     pub OPTION_UNWRAP_USED,
     restriction,
     "using `Option.unwrap()`, which should at least get a better message using `expect()`"
 }
 
-/// **What it does:** Checks for `.unwrap()` calls on `Result`s.
-///
-/// **Why is this bad?** `result.unwrap()` will let the thread panic on `Err`
-/// values. Normally, you want to implement more sophisticated error handling,
-/// and propagate errors upwards with `try!`.
-///
-/// Even if you want to panic on errors, not all `Error`s implement good
-/// messages on display.  Therefore it may be beneficial to look at the places
-/// where they may get displayed. Activate this lint to do just that.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// x.unwrap()
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for `.unwrap()` calls on `Result`s.
+    ///
+    /// **Why is this bad?** `result.unwrap()` will let the thread panic on `Err`
+    /// values. Normally, you want to implement more sophisticated error handling,
+    /// and propagate errors upwards with `try!`.
+    ///
+    /// Even if you want to panic on errors, not all `Error`s implement good
+    /// messages on display.  Therefore it may be beneficial to look at the places
+    /// where they may get displayed. Activate this lint to do just that.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// x.unwrap()
+    /// ```
+    #[clippy::version = "pre 0.0.213"]
     pub RESULT_UNWRAP_USED,
     restriction,
     "using `Result.unwrap()`, which might be better handled"
 }
 
-/// **What it does:** Checks for methods that should live in a trait
-/// implementation of a `std` trait (see [llogiq's blog
-/// post](http://llogiq.github.io/2015/07/30/traits.html) for further
-/// information) instead of an inherent implementation.
-///
-/// **Why is this bad?** Implementing the traits improve ergonomics for users of
-/// the code, often with very little cost. Also people seeing a `mul(...)`
-/// method
-/// may expect `*` to work equally, so you should have good reason to disappoint
-/// them.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// struct X;
-/// impl X {
-///    fn add(&self, other: &X) -> X { .. }
-/// }
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for methods that should live in a trait
+    /// implementation of a `std` trait (see [llogiq's blog
+    /// post](http://llogiq.github.io/2015/07/30/traits.html) for further
+    /// information) instead of an inherent implementation.
+    ///
+    /// **Why is this bad?** Implementing the traits improve ergonomics for users of
+    /// the code, often with very little cost. Also people seeing a `mul(...)`
+    /// method
+    /// may expect `*` to work equally, so you should have good reason to disappoint
+    /// them.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// struct X;
+    /// impl X {
+    ///    fn add(&self, other: &X) -> X { .. }
+    /// }
+    /// ```
+    #[clippy::version = "pre 0.0.213"]
     pub SHOULD_IMPLEMENT_TRAIT,
     style,
     "defining a method that should be implementing a std trait"
 }
 
-/// **What it does:** Checks for methods with certain name prefixes and which
-/// doesn't match how self is taken. The actual rules are:
-///
-/// |Prefix |`self` taken          |
-/// |-------|----------------------|
-/// |`as_`  |`&self` or `&mut self`|
-/// |`from_`| none                 |
-/// |`into_`|`self`                |
-/// |`is_`  |`&self` or none       |
-/// |`to_`  |`&self`               |
-///
-/// **Why is this bad?** Consistency breeds readability. If you follow the
-/// conventions, your users won't be surprised that they, e.g., need to supply a
-/// mutable reference to a `as_..` function.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// impl X {
-///     fn as_str(self) -> &str { .. }
-/// }
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for methods with certain name prefixes and which
+    /// doesn't match how self is taken. The actual rules are:
+    ///
+    /// |Prefix |`self` taken          |
+    /// |-------|----------------------|
+    /// |`as_`  |`&self` or `&mut self`|
+    /// |`from_`| none                 |
+    /// |`into_`|`self`                |
+    /// |`is_`  |`&self` or none       |
+    /// |`to_`  |`&self`               |
+    ///
+    /// **Why is this bad?** Consistency breeds readability. If you follow the
+    /// conventions, your users won't be surprised that they, e.g., need to supply a
+    /// mutable reference to a `as_..` function.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// impl X {
+    ///     fn as_str(self) -> &str { .. }
+    /// }
+    /// ```
+    #[clippy::version = "pre 0.0.213"]
     pub WRONG_SELF_CONVENTION,
     style,
     "defining a method named with an established prefix (like \"into_\") that takes \
      `self` with the wrong convention"
 }
 
-/// **What it does:** This is the same as
-/// [`wrong_self_convention`](#wrong_self_convention), but for public items.
-///
-/// **Why is this bad?** See [`wrong_self_convention`](#wrong_self_convention).
-///
-/// **Known problems:** Actually *renaming* the function may break clients if
-/// the function is part of the public interface. In that case, be mindful of
-/// the stability guarantees you've given your users.
-///
-/// **Example:**
-/// ```rust
-/// impl X {
-///     pub fn as_str(self) -> &str { .. }
-/// }
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** This is the same as
+    /// [`wrong_self_convention`](#wrong_self_convention), but for public items.
+    ///
+    /// **Why is this bad?** See [`wrong_self_convention`](#wrong_self_convention).
+    ///
+    /// **Known problems:** Actually *renaming* the function may break clients if
+    /// the function is part of the public interface. In that case, be mindful of
+    /// the stability guarantees you've given your users.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// impl X {
+    ///     pub fn as_str(self) -> &str { .. }
+    /// }
+    /// ```
+    #[clippy::version = "pre 0.0.213"]
     pub WRONG_PUB_SELF_CONVENTION,
     restriction,
     "defining a public method named with an established prefix (like \"into_\") that takes \
      `self` with the wrong convention"
 }
 
-/// **What it does:** Checks for usage of `ok().expect(..)`.
-///
-/// **Why is this bad?** Because you usually call `expect()` on the `Result`
-/// directly to get a better error message.
-///
-/// **Known problems:** The error type needs to implement `Debug`
-///
-/// **Example:**
-/// ```rust
-/// x.ok().expect("why did I do this again?")
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for usage of `ok().expect(..)`.
+    ///
+    /// **Why is this bad?** Because you usually call `expect()` on the `Result`
+    /// directly to get a better error message.
+    ///
+    /// **Known problems:** The error type needs to implement `Debug`
+    ///
+    /// **Example:**
+    /// ```rust
+    /// x.ok().expect("why did I do this again?")
+    /// ```
+    #[clippy::version = "pre 0.0.213"]
     pub OK_EXPECT,
     style,
     "using `ok().expect()`, which gives worse error messages than \
      calling `expect` directly on the Result"
 }
 
-/// **What it does:** Checks for usage of `_.map(_).unwrap_or(_)`.
-///
-/// **Why is this bad?** Readability, this can be written more concisely as
-/// `_.map_or(_, _)`.
-///
-/// **Known problems:** The order of the arguments is not in execution order
-///
-/// **Example:**
-/// ```rust
-/// x.map(|a| a + 1).unwrap_or(0)
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for usage of `_.map(_).unwrap_or(_)`.
+    ///
+    /// **Why is this bad?** Readability, this can be written more concisely as
+    /// `_.map_or(_, _)`.
+    ///
+    /// **Known problems:** The order of the arguments is not in execution order
+    ///
+    /// **Example:**
+    /// ```rust
+    /// x.map(|a| a + 1).unwrap_or(0)
+    /// ```
+    #[clippy::version = "pre 0.0.213"]
     pub OPTION_MAP_UNWRAP_OR,
     pedantic,
     "using `Option.map(f).unwrap_or(a)`, which is more succinctly expressed as \
      `map_or(a, f)`"
 }
 
-/// **What it does:** Checks for usage of `_.map(_).unwrap_or_else(_)`.
-///
-/// **Why is this bad?** Readability, this can be written more concisely as
-/// `_.map_or_else(_, _)`.
-///
-/// **Known problems:** The order of the arguments is not in execution order.
-///
-/// **Example:**
-/// ```rust
-/// x.map(|a| a + 1).unwrap_or_else(some_function)
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for usage of `_.map(_).unwrap_or_else(_)`.
+    ///
+    /// **Why is this bad?** Readability, this can be written more concisely as
+    /// `_.map_or_else(_, _)`.
+    ///
+    /// **Known problems:** The order of the arguments is not in execution order.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// x.map(|a| a + 1).unwrap_or_else(some_function)
+    /// ```
+    #[clippy::version = "pre 0.0.213"]
     pub OPTION_MAP_UNWRAP_OR_ELSE,
     pedantic,
     "using `Option.map(f).unwrap_or_else(g)`, which is more succinctly expressed as \
      `map_or_else(g, f)`"
 }
 
-/// **What it does:** Checks for usage of `result.map(_).unwrap_or_else(_)`.
-///
-/// **Why is this bad?** Readability, this can be written more concisely as
-/// `result.ok().map_or_else(_, _)`.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// x.map(|a| a + 1).unwrap_or_else(some_function)
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for usage of `result.map(_).unwrap_or_else(_)`.
+    ///
+    /// **Why is this bad?** Readability, this can be written more concisely as
+    /// `result.ok().map_or_else(_, _)`.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// x.map(|a| a + 1).unwrap_or_else(some_function)
+    /// ```
+    #[clippy::version = "0.0.169"]
     pub RESULT_MAP_UNWRAP_OR_ELSE,
     pedantic,
     "using `Result.map(f).unwrap_or_else(g)`, which is more succinctly expressed as \
      `.ok().map_or_else(g, f)`"
 }
 
-/// **What it does:** Checks for usage of `_.map_or(None, _)`.
-///
-/// **Why is this bad?** Readability, this can be written more concisely as
-/// `_.and_then(_)`.
-///
-/// **Known problems:** The order of the arguments is not in execution order.
-///
-/// **Example:**
-/// ```rust
-/// opt.map_or(None, |a| a + 1)
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for usage of `_.map_or(None, _)`.
+    ///
+    /// **Why is this bad?** Readability, this can be written more concisely as
+    /// `_.and_then(_)`.
+    ///
+    /// **Known problems:** The order of the arguments is not in execution order.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// opt.map_or(None, |a| a + 1)
+    /// ```
+    #[clippy::version = "0.0.166"]
     pub OPTION_MAP_OR_NONE,
     style,
     "using `Option.map_or(None, f)`, which is more succinctly expressed as \
      `and_then(f)`"
 }
 
-/// **What it does:** Checks for usage of `_.filter(_).next()`.
-///
-/// **Why is this bad?** Readability, this can be written more concisely as
-/// `_.find(_)`.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// iter.filter(|x| x == 0).next()
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for usage of `_.filter(_).next()`.
+    ///
+    /// **Why is this bad?** Readability, this can be written more concisely as
+    /// `_.find(_)`.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// iter.filter(|x| x == 0).next()
+    /// ```
+    #[clippy::version = "pre 0.0.213"]
     pub FILTER_NEXT,
     complexity,
     "using `filter(p).next()`, which is more succinctly expressed as `.find(p)`"
 }
 
-/// **What it does:** Checks for usage of `_.filter(_).map(_)`,
-/// `_.filter(_).flat_map(_)`, `_.filter_map(_).flat_map(_)` and similar.
-///
-/// **Why is this bad?** Readability, this can be written more concisely as a
-/// single method call.
-///
-/// **Known problems:** Often requires a condition + Option/Iterator creation
-/// inside the closure.
-///
-/// **Example:**
-/// ```rust
-/// iter.filter(|x| x == 0).map(|x| x * 2)
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for usage of `_.filter(_).map(_)`,
+    /// `_.filter(_).flat_map(_)`, `_.filter_map(_).flat_map(_)` and similar.
+    ///
+    /// **Why is this bad?** Readability, this can be written more concisely as a
+    /// single method call.
+    ///
+    /// **Known problems:** Often requires a condition + Option/Iterator creation
+    /// inside the closure.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// iter.filter(|x| x == 0).map(|x| x * 2)
+    /// ```
+    #[clippy::version = "0.0.78"]
     pub FILTER_MAP,
     pedantic,
     "using combinations of `filter`, `map`, `filter_map` and `flat_map` which can \
      usually be written as a single method call"
 }
 
-/// **What it does:** Checks for an iterator search (such as `find()`,
-/// `position()`, or `rposition()`) followed by a call to `is_some()`.
-///
-/// **Why is this bad?** Readability, this can be written more concisely as
-/// `_.any(_)`.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// iter.find(|x| x == 0).is_some()
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for an iterator search (such as `find()`,
+    /// `position()`, or `rposition()`) followed by a call to `is_some()`.
+    ///
+    /// **Why is this bad?** Readability, this can be written more concisely as
+    /// `_.any(_)`.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// iter.find(|x| x == 0).is_some()
+    /// ```
+    #[clippy::version = "pre 0.0.213"]
     pub SEARCH_IS_SOME,
     complexity,
     "using an iterator search followed by `is_some()`, which is more succinctly \
      expressed as a call to `any()`"
 }
 
-/// **What it does:** Checks for usage of `.chars().next()` on a `str` to check
-/// if it starts with a given char.
-///
-/// **Why is this bad?** Readability, this can be written more concisely as
-/// `_.starts_with(_)`.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// name.chars().next() == Some('_')
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for usage of `.chars().next()` on a `str` to check
+    /// if it starts with a given char.
+    ///
+    /// **Why is this bad?** Readability, this can be written more concisely as
+    /// `_.starts_with(_)`.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// name.chars().next() == Some('_')
+    /// ```
+    #[clippy::version = "pre 0.0.213"]
     pub CHARS_NEXT_CMP,
     complexity,
     "using `.chars().next()` to check if a string starts with a char"
 }
 
-/// **What it does:** Checks for calls to `.or(foo(..))`, `.unwrap_or(foo(..))`,
-/// etc., and suggests to use `or_else`, `unwrap_or_else`, etc., or
-/// `unwrap_or_default` instead.
-///
-/// **Why is this bad?** The function will always be called and potentially
-/// allocate an object acting as the default.
-///
-/// **Known problems:** If the function has side-effects, not calling it will
-/// change the semantic of the program, but you shouldn't rely on that anyway.
-///
-/// **Example:**
-/// ```rust
-/// foo.unwrap_or(String::new())
-/// ```
-/// this can instead be written:
-/// ```rust
-/// foo.unwrap_or_else(String::new)
-/// ```
-/// or
-/// ```rust
-/// foo.unwrap_or_default()
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for calls to `.or(foo(..))`, `.unwrap_or(foo(..))`,
+    /// etc., and suggests to use `or_else`, `unwrap_or_else`, etc., or
+    /// `unwrap_or_default` instead.
+    ///
+    /// **Why is this bad?** The function will always be called and potentially
+    /// allocate an object acting as the default.
+    ///
+    /// **Known problems:** If the function has side-effects, not calling it will
+    /// change the semantic of the program, but you shouldn't rely on that anyway.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// foo.unwrap_or(String::new())
+    /// ```
+    /// this can instead be written:
+    /// ```rust
+    /// foo.unwrap_or_else(String::new)
+    /// ```
+    /// or
+    /// ```rust
+    /// foo.unwrap_or_default()
+    /// ```
+    #[clippy::version = "pre 0.0.213"]
     pub OR_FUN_CALL,
     perf,
     "using any `*or` method with a function call, which suggests `*or_else`"
 }
 
-/// **What it does:** Checks for calls to `.expect(&format!(...))`, `.expect(foo(..))`,
-/// etc., and suggests to use `unwrap_or_else` instead
-///
-/// **Why is this bad?** The function will always be called.
-///
-/// **Known problems:** If the function has side-effects, not calling it will
-/// change the semantic of the program, but you shouldn't rely on that anyway.
-///
-/// **Example:**
-/// ```rust
-/// foo.expect(&format("Err {}: {}", err_code, err_msg))
-/// ```
-/// or
-/// ```rust
-/// foo.expect(format("Err {}: {}", err_code, err_msg).as_str())
-/// ```
-/// this can instead be written:
-/// ```rust
-/// foo.unwrap_or_else(|_| panic!("Err {}: {}", err_code, err_msg))
-/// ```
-/// or
-/// ```rust
-/// foo.unwrap_or_else(|_| panic!(format("Err {}: {}", err_code, err_msg).as_str()))
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for calls to `.expect(&format!(...))`, `.expect(foo(..))`,
+    /// etc., and suggests to use `unwrap_or_else` instead
+    ///
+    /// **Why is this bad?** The function will always be called.
+    ///
+    /// **Known problems:** If the function has side-effects, not calling it will
+    /// change the semantic of the program, but you shouldn't rely on that anyway.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// foo.expect(&format("Err {}: {}", err_code, err_msg))
+    /// ```
+    /// or
+    /// ```rust
+    /// foo.expect(format("Err {}: {}", err_code, err_msg).as_str())
+    /// ```
+    /// this can instead be written:
+    /// ```rust
+    /// foo.unwrap_or_else(|_| panic!("Err {}: {}", err_code, err_msg))
+    /// ```
+    /// or
+    /// ```rust
+    /// foo.unwrap_or_else(|_| panic!(format("Err {}: {}", err_code, err_msg).as_str()))
+    /// ```
+    #[clippy::version = "pre 0.0.213"]
     pub EXPECT_FUN_CALL,
     perf,
     "using any `expect` method with a function call"
 }
 
-/// **What it does:** Checks for usage of `.clone()` on a `Copy` type.
-///
-/// **Why is this bad?** The only reason `Copy` types implement `Clone` is for
-/// generics, not for using the `clone` method on a concrete type.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// 42u64.clone()
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for usage of `.clone()` on a `Copy` type.
+    ///
+    /// **Why is this bad?** The only reason `Copy` types implement `Clone` is for
+    /// generics, not for using the `clone` method on a concrete type.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// 42u64.clone()
+    /// ```
+    #[clippy::version = "pre 0.0.213"]
     pub CLONE_ON_COPY,
     complexity,
     "using `clone` on a `Copy` type"
 }
 
-/// **What it does:** Checks for usage of `.clone()` on a ref-counted pointer,
-/// (`Rc`, `Arc`, `rc::Weak`, or `sync::Weak`), and suggests calling Clone via unified
-/// function syntax instead (e.g. `Rc::clone(foo)`).
-///
-/// **Why is this bad?** Calling '.clone()' on an Rc, Arc, or Weak
-/// can obscure the fact that only the pointer is being cloned, not the underlying
-/// data.
-///
-/// **Example:**
-/// ```rust
-/// x.clone()
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for usage of `.clone()` on a ref-counted pointer,
+    /// (`Rc`, `Arc`, `rc::Weak`, or `sync::Weak`), and suggests calling Clone via unified
+    /// function syntax instead (e.g. `Rc::clone(foo)`).
+    ///
+    /// **Why is this bad?** Calling '.clone()' on an Rc, Arc, or Weak
+    /// can obscure the fact that only the pointer is being cloned, not the underlying
+    /// data.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// x.clone()
+    /// ```
+    #[clippy::version = "0.0.159"]
     pub CLONE_ON_REF_PTR,
     restriction,
     "using 'clone' on a ref-counted pointer"
 }
 
-/// **What it does:** Checks for usage of `.clone()` on an `&&T`.
-///
-/// **Why is this bad?** Cloning an `&&T` copies the inner `&T`, instead of
-/// cloning the underlying `T`.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// fn main() {
-///    let x = vec![1];
-///    let y = &&x;
-///    let z = y.clone();
-///    println!("{:p} {:p}",*y, z); // prints out the same pointer
-/// }
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for usage of `.clone()` on an `&&T`.
+    ///
+    /// **Why is this bad?** Cloning an `&&T` copies the inner `&T`, instead of
+    /// cloning the underlying `T`.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// fn main() {
+    ///    let x = vec![1];
+    ///    let y = &&x;
+    ///    let z = y.clone();
+    ///    println!("{:p} {:p}",*y, z); // prints out the same pointer
+    /// }
+    /// ```
+    #[clippy::version = "pre 0.0.213"]
     pub CLONE_DOUBLE_REF,
     correctness,
     "using `clone` on `&&T`"
 }
 
-/// **What it does:** Checks for `new` not returning `Self`.
-///
-/// **Why is this bad?** As a convention, `new` methods are used to make a new
-/// instance of a type.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// impl Foo {
-///     fn new(..) -> NotAFoo {
-///     }
-/// }
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for `new` not returning `Self`.
+    ///
+    /// **Why is this bad?** As a convention, `new` methods are used to make a new
+    /// instance of a type.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// impl Foo {
+    ///     fn new(..) -> NotAFoo {
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "pre 0.0.213"]
     pub NEW_RET_NO_SELF,
     style,
     "not returning `Self` in a `new` method"
 }
 
-/// **What it does:** Checks for string methods that receive a single-character
-/// `str` as an argument, e.g. `_.split("x")`.
-///
-/// **Why is this bad?** Performing these methods using a `char` is faster than
-/// using a `str`.
-///
-/// **Known problems:** Does not catch multi-byte unicode characters.
-///
-/// **Example:**
-/// `_.split("x")` could be `_.split('x')`
 declare_clippy_lint! {
+    /// **What it does:** Checks for string methods that receive a single-character
+    /// `str` as an argument, e.g. `_.split("x")`.
+    ///
+    /// **Why is this bad?** Performing these methods using a `char` is faster than
+    /// using a `str`.
+    ///
+    /// **Known problems:** Does not catch multi-byte unicode characters.
+    ///
+    /// **Example:**
+    /// `_.split("x")` could be `_.split('x')`
+    #[clippy::version = "pre 0.0.213"]
     pub SINGLE_CHAR_PATTERN,
     perf,
     "using a single-character str where a char could be used, e.g. \
      `_.split(\"x\")`"
 }
 
-/// **What it does:** Checks for getting the inner pointer of a temporary
-/// `CString`.
-///
-/// **Why is this bad?** The inner pointer of a `CString` is only valid as long
-/// as the `CString` is alive.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust,ignore
-/// let c_str = CString::new("foo").unwrap().as_ptr();
-/// unsafe {
-///     call_some_ffi_func(c_str);
-/// }
-/// ```
-/// Here `c_str` point to a freed address. The correct use would be:
-/// ```rust,ignore
-/// let c_str = CString::new("foo").unwrap();
-/// unsafe {
-///     call_some_ffi_func(c_str.as_ptr());
-/// }
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for getting the inner pointer of a temporary
+    /// `CString`.
+    ///
+    /// **Why is this bad?** The inner pointer of a `CString` is only valid as long
+    /// as the `CString` is alive.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// let c_str = CString::new("foo").unwrap().as_ptr();
+    /// unsafe {
+    ///     call_some_ffi_func(c_str);
+    /// }
+    /// ```
+    /// Here `c_str` point to a freed address. The correct use would be:
+    /// ```rust,ignore
+    /// let c_str = CString::new("foo").unwrap();
+    /// unsafe {
+    ///     call_some_ffi_func(c_str.as_ptr());
+    /// }
+    /// ```
+    #[clippy::version = "0.0.64"]
     pub TEMPORARY_CSTRING_AS_PTR,
     correctness,
     "getting the inner pointer of a temporary `CString`"
 }
 
-/// **What it does:** Checks for use of `.iter().nth()` (and the related
-/// `.iter_mut().nth()`) on standard library types with O(1) element access.
-///
-/// **Why is this bad?** `.get()` and `.get_mut()` are more efficient and more
-/// readable.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// let some_vec = vec![0, 1, 2, 3];
-/// let bad_vec = some_vec.iter().nth(3);
-/// let bad_slice = &some_vec[..].iter().nth(3);
-/// ```
-/// The correct use would be:
-/// ```rust
-/// let some_vec = vec![0, 1, 2, 3];
-/// let bad_vec = some_vec.get(3);
-/// let bad_slice = &some_vec[..].get(3);
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for use of `.iter().nth()` (and the related
+    /// `.iter_mut().nth()`) on standard library types with O(1) element access.
+    ///
+    /// **Why is this bad?** `.get()` and `.get_mut()` are more efficient and more
+    /// readable.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let some_vec = vec![0, 1, 2, 3];
+    /// let bad_vec = some_vec.iter().nth(3);
+    /// let bad_slice = &some_vec[..].iter().nth(3);
+    /// ```
+    /// The correct use would be:
+    /// ```rust
+    /// let some_vec = vec![0, 1, 2, 3];
+    /// let bad_vec = some_vec.get(3);
+    /// let bad_slice = &some_vec[..].get(3);
+    /// ```
+    #[clippy::version = "0.0.77"]
     pub ITER_NTH,
     perf,
     "using `.iter().nth()` on a standard library type with O(1) element access"
 }
 
-/// **What it does:** Checks for use of `.skip(x).next()` on iterators.
-///
-/// **Why is this bad?** `.nth(x)` is cleaner
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// let some_vec = vec![0, 1, 2, 3];
-/// let bad_vec = some_vec.iter().skip(3).next();
-/// let bad_slice = &some_vec[..].iter().skip(3).next();
-/// ```
-/// The correct use would be:
-/// ```rust
-/// let some_vec = vec![0, 1, 2, 3];
-/// let bad_vec = some_vec.iter().nth(3);
-/// let bad_slice = &some_vec[..].iter().nth(3);
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for use of `.skip(x).next()` on iterators.
+    ///
+    /// **Why is this bad?** `.nth(x)` is cleaner
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let some_vec = vec![0, 1, 2, 3];
+    /// let bad_vec = some_vec.iter().skip(3).next();
+    /// let bad_slice = &some_vec[..].iter().skip(3).next();
+    /// ```
+    /// The correct use would be:
+    /// ```rust
+    /// let some_vec = vec![0, 1, 2, 3];
+    /// let bad_vec = some_vec.iter().nth(3);
+    /// let bad_slice = &some_vec[..].iter().nth(3);
+    /// ```
+    #[clippy::version = "0.0.96"]
     pub ITER_SKIP_NEXT,
     style,
     "using `.skip(x).next()` on an iterator"
 }
 
-/// **What it does:** Checks for use of `.get().unwrap()` (or
-/// `.get_mut().unwrap`) on a standard library type which implements `Index`
-///
-/// **Why is this bad?** Using the Index trait (`[]`) is more clear and more
-/// concise.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// let some_vec = vec![0, 1, 2, 3];
-/// let last = some_vec.get(3).unwrap();
-/// *some_vec.get_mut(0).unwrap() = 1;
-/// ```
-/// The correct use would be:
-/// ```rust
-/// let some_vec = vec![0, 1, 2, 3];
-/// let last = some_vec[3];
-/// some_vec[0] = 1;
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for use of `.get().unwrap()` (or
+    /// `.get_mut().unwrap`) on a standard library type which implements `Index`
+    ///
+    /// **Why is this bad?** Using the Index trait (`[]`) is more clear and more
+    /// concise.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let some_vec = vec![0, 1, 2, 3];
+    /// let last = some_vec.get(3).unwrap();
+    /// *some_vec.get_mut(0).unwrap() = 1;
+    /// ```
+    /// The correct use would be:
+    /// ```rust
+    /// let some_vec = vec![0, 1, 2, 3];
+    /// let last = some_vec[3];
+    /// some_vec[0] = 1;
+    /// ```
+    #[clippy::version = "0.0.99"]
     pub GET_UNWRAP,
     style,
     "using `.get().unwrap()` or `.get_mut().unwrap()` when using `[]` would work instead"
 }
 
-/// **What it does:** Checks for the use of `.extend(s.chars())` where s is a
-/// `&str` or `String`.
-///
-/// **Why is this bad?** `.push_str(s)` is clearer
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// let abc = "abc";
-/// let def = String::from("def");
-/// let mut s = String::new();
-/// s.extend(abc.chars());
-/// s.extend(def.chars());
-/// ```
-/// The correct use would be:
-/// ```rust
-/// let abc = "abc";
-/// let def = String::from("def");
-/// let mut s = String::new();
-/// s.push_str(abc);
-/// s.push_str(&def));
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for the use of `.extend(s.chars())` where s is a
+    /// `&str` or `String`.
+    ///
+    /// **Why is this bad?** `.push_str(s)` is clearer
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let abc = "abc";
+    /// let def = String::from("def");
+    /// let mut s = String::new();
+    /// s.extend(abc.chars());
+    /// s.extend(def.chars());
+    /// ```
+    /// The correct use would be:
+    /// ```rust
+    /// let abc = "abc";
+    /// let def = String::from("def");
+    /// let mut s = String::new();
+    /// s.push_str(abc);
+    /// s.push_str(&def));
+    /// ```
+    #[clippy::version = "0.0.101"]
     pub STRING_EXTEND_CHARS,
     style,
     "using `x.extend(s.chars())` where s is a `&str` or `String`"
 }
 
-/// **What it does:** Checks for the use of `.cloned().collect()` on slice to
-/// create a `Vec`.
-///
-/// **Why is this bad?** `.to_vec()` is clearer
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// let s = [1,2,3,4,5];
-/// let s2 : Vec<isize> = s[..].iter().cloned().collect();
-/// ```
-/// The better use would be:
-/// ```rust
-/// let s = [1,2,3,4,5];
-/// let s2 : Vec<isize> = s.to_vec();
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for the use of `.cloned().collect()` on slice to
+    /// create a `Vec`.
+    ///
+    /// **Why is this bad?** `.to_vec()` is clearer
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let s = [1,2,3,4,5];
+    /// let s2 : Vec<isize> = s[..].iter().cloned().collect();
+    /// ```
+    /// The better use would be:
+    /// ```rust
+    /// let s = [1,2,3,4,5];
+    /// let s2 : Vec<isize> = s.to_vec();
+    /// ```
+    #[clippy::version = "pre 0.0.213"]
     pub ITER_CLONED_COLLECT,
     style,
     "using `.cloned().collect()` on slice to create a `Vec`"
 }
 
-/// **What it does:** Checks for usage of `.chars().last()` or
-/// `.chars().next_back()` on a `str` to check if it ends with a given char.
-///
-/// **Why is this bad?** Readability, this can be written more concisely as
-/// `_.ends_with(_)`.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// name.chars().last() == Some('_') || name.chars().next_back() == Some('-')
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for usage of `.chars().last()` or
+    /// `.chars().next_back()` on a `str` to check if it ends with a given char.
+    ///
+    /// **Why is this bad?** Readability, this can be written more concisely as
+    /// `_.ends_with(_)`.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// name.chars().last() == Some('_') || name.chars().next_back() == Some('-')
+    /// ```
+    #[clippy::version = "0.0.162"]
     pub CHARS_LAST_CMP,
     style,
     "using `.chars().last()` or `.chars().next_back()` to check if a string ends with a char"
 }
 
-/// **What it does:** Checks for usage of `.as_ref()` or `.as_mut()` where the
-/// types before and after the call are the same.
-///
-/// **Why is this bad?** The call is unnecessary.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// let x: &[i32] = &[1,2,3,4,5];
-/// do_stuff(x.as_ref());
-/// ```
-/// The correct use would be:
-/// ```rust
-/// let x: &[i32] = &[1,2,3,4,5];
-/// do_stuff(x);
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for usage of `.as_ref()` or `.as_mut()` where the
+    /// types before and after the call are the same.
+    ///
+    /// **Why is this bad?** The call is unnecessary.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let x: &[i32] = &[1,2,3,4,5];
+    /// do_stuff(x.as_ref());
+    /// ```
+    /// The correct use would be:
+    /// ```rust
+    /// let x: &[i32] = &[1,2,3,4,5];
+    /// do_stuff(x);
+    /// ```
+    #[clippy::version = "0.0.167"]
     pub USELESS_ASREF,
     complexity,
     "using `as_ref` where the types before and after the call are the same"
 }
 
 
-/// **What it does:** Checks for using `fold` when a more succinct alternative exists.
-/// Specifically, this checks for `fold`s which could be replaced by `any`, `all`,
-/// `sum` or `product`.
-///
-/// **Why is this bad?** Readability.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// let _ = (0..3).fold(false, |acc, x| acc || x > 2);
-/// ```
-/// This could be written as:
-/// ```rust
-/// let _ = (0..3).any(|x| x > 2);
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for using `fold` when a more succinct alternative exists.
+    /// Specifically, this checks for `fold`s which could be replaced by `any`, `all`,
+    /// `sum` or `product`.
+    ///
+    /// **Why is this bad?** Readability.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let _ = (0..3).fold(false, |acc, x| acc || x > 2);
+    /// ```
+    /// This could be written as:
+    /// ```rust
+    /// let _ = (0..3).any(|x| x > 2);
+    /// ```
+    #[clippy::version = "0.0.181"]
     pub UNNECESSARY_FOLD,
     style,
     "using `fold` when a more succinct alternative exists"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** Checks for `.checked_add/sub/mul(x).unwrap_or(MAX/MIN)`
+    /// on integers.
+    ///
+    /// **Why is this bad?** These can be written simply with `saturating_add/sub/mul`
+    /// methods.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let x: u32 = 100;
+    /// let add = x.checked_add(y).unwrap_or(u32::max_value());
+    /// let sub = x.checked_sub(y).unwrap_or(u32::min_value());
+    /// ```
+    /// can be written using dedicated methods for saturating addition/subtraction as:
+    /// ```rust
+    /// let x: u32 = 100;
+    /// let add = x.saturating_add(y);
+    /// let sub = x.saturating_sub(y);
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub MANUAL_SATURATING_ARITHMETIC,
+    style,
+    "`.checked_add/sub(x).unwrap_or(MAX/MIN)`"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `FileType::is_file()` used to decide whether a
+    /// path can be opened or read.
+    ///
+    /// **Why is this bad?** `is_file()` is only true for regular files: symlinks
+    /// (from `symlink_metadata`) and special files like named pipes or devices
+    /// are not files for it, even though they can be read. `!is_dir()` is usually
+    /// what is meant.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// let metadata = std::fs::metadata("foo.txt")?;
+    /// if metadata.file_type().is_file() {
+    ///     let contents = std::fs::read_to_string("foo.txt")?;
+    /// }
+    /// ```
+    ///
+    /// should be written as:
+    /// ```rust,ignore
+    /// let metadata = std::fs::metadata("foo.txt")?;
+    /// if !metadata.file_type().is_dir() {
+    ///     let contents = std::fs::read_to_string("foo.txt")?;
+    /// }
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub FILETYPE_IS_FILE,
+    correctness,
+    "`FileType::is_file` is checked before reading a path, which excludes symlinks and special files"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `.to_digit(..).is_some()` on `char`s.
+    ///
+    /// **Why is this bad?** This is a convoluted way of checking if a `char` is a digit. It's
+    /// more straight forward to use the dedicated `is_digit` method.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let c = 'c';
+    /// # let radix = 10;
+    /// let is_digit = c.to_digit(radix).is_some();
+    /// ```
+    /// can be written as:
+    /// ```rust
+    /// # let c = 'c';
+    /// # let radix = 10;
+    /// let is_digit = c.is_digit(radix);
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub TO_DIGIT_IS_SOME,
+    style,
+    "`char.is_digit()` is clearer"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `offset(_)`, `wrapping_`{`add`, `sub`}, etc. on raw pointers to
+    /// zero-sized types
+    ///
+    /// **Why is this bad?** This is a no-op, and likely unintended
+    ///
+    /// **Known problems:** None
+    ///
+    /// **Example:**
+    /// ```rust
+    /// unsafe { (&() as *const ()).offset(1) };
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub ZST_OFFSET,
+    correctness,
+    "Check for offset calculations on raw pointers to zero-sized types"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for usage of `_.filter(_).map(_)` that can be written more simply
+    /// as `filter_map(_)`.
+    ///
+    /// **Why is this bad?** Redundant code in the `filter` and `map` operations is poor style and
+    /// less performant.
+    ///
+    /// **Known problems:** Only closures using the same name for their argument
+    /// are linted.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// (0_i32..10)
+    ///     .filter(|n| n.checked_add(1).is_some())
+    ///     .map(|n| n.checked_add(1).unwrap());
+    /// ```
+    /// Could be written as:
+    /// ```rust
+    /// (0_i32..10).filter_map(|n| n.checked_add(1));
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub MANUAL_FILTER_MAP,
+    complexity,
+    "using `_.filter(_).map(_)` in a way that can be written more simply as `filter_map(_)`"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for usage of `_.find(_).map(_)` that can be written more simply
+    /// as `find_map(_)`.
+    ///
+    /// **Why is this bad?** Redundant code in the `find` and `map` operations is poor style and
+    /// less performant.
+    ///
+    /// **Known problems:** Only closures using the same name for their argument
+    /// are linted.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// (0_i32..10)
+    ///     .find(|n| n.checked_add(1).is_some())
+    ///     .map(|n| n.checked_add(1).unwrap());
+    /// ```
+    /// Could be written as:
+    /// ```rust
+    /// (0_i32..10).find_map(|n| n.checked_add(1));
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub MANUAL_FIND_MAP,
+    complexity,
+    "using `_.find(_).map(_)` in a way that can be written more simply as `find_map(_)`"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for usage of `_.cloned()` or `_.copied()` on an
+    /// iterator directly followed by an adapter or consumer that doesn't need the
+    /// items to be owned, e.g. `_.cloned().count()` or `_.cloned().last()`.
+    ///
+    /// **Why is this bad?** Every item of the iterator is cloned, even those that
+    /// are skipped or thrown away. The clone can be removed, or moved after the
+    /// adapter so that only the remaining items are cloned.
+    ///
+    /// **Known problems:** For `find`, `any` and `all`, the closure then gets a
+    /// reference instead of the cloned item, which may need some changes. Only the
+    /// adapter directly following the clone is checked: `_.cloned().filter(_).next()`
+    /// is first linted by `filter_next`.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let v = vec![String::from("a"), String::from("b")];
+    /// let last = v.iter().cloned().last();
+    /// let count = v.iter().cloned().count();
+    /// ```
+    /// Could be written as:
+    /// ```rust
+    /// let v = vec![String::from("a"), String::from("b")];
+    /// let last = v.iter().last().cloned();
+    /// let count = v.iter().count();
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub ITER_OVEREAGER_CLONED,
+    perf,
+    "using `cloned()` early with `Iterator::count()`, `last()`, `next()` and similar"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for usage of `.skip(0)` on iterators.
+    ///
+    /// **Why is this bad?** This doesn't skip anything and is useless, it was most
+    /// likely meant to be `.skip(1)`.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let v = vec![1, 2, 3];
+    /// let x = v.iter().skip(0).collect::<Vec<_>>();
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub ITER_SKIP_ZERO,
+    correctness,
+    "disallows `.skip(0)`"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Looks for iterator combinator calls such as `.take(x)` or
+    /// `.skip(x)` where `x` is greater than the amount of items that the iterator
+    /// will produce.
+    ///
+    /// **Why is this bad?** Taking or skipping more items than there are in an
+    /// iterator either creates an iterator with all items from the original
+    /// iterator or an empty iterator. This is most likely a bug.
+    ///
+    /// **Known problems:** Only arrays, `iter::once` and `iter::empty` are known
+    /// to have a fixed length.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// for _ in [1, 2, 3].iter().take(4) {}
+    ///
+    /// for _ in [1, 2, 3].iter().skip(4) {}
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub ITER_OUT_OF_BOUNDS,
+    correctness,
+    "calls to `.take()` or `.skip()` that are out of bounds"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for calls to `.drain(..)` on a `Vec`, a `VecDeque` or a
+    /// `String` collected into a container of the same type.
+    ///
+    /// **Why is this bad?** Using `mem::take` is faster as it avoids the allocation of a new
+    /// container and the copy of the elements.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// fn remove_all(v: &mut Vec<i32>) -> Vec<i32> {
+    ///     v.drain(..).collect()
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// use std::mem;
+    /// fn remove_all(v: &mut Vec<i32>) -> Vec<i32> {
+    ///     mem::take(v)
+    /// }
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub DRAIN_COLLECT,
+    perf,
+    "calling `.drain(..).collect()` to move all elements into a new collection"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for calls to `.drain(..)` on a local `Vec` or `VecDeque`
+    /// that isn't used afterwards.
+    ///
+    /// **Why is this bad?** `.into_iter()` is simpler and faster, as it doesn't have to
+    /// keep the container usable.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let mut v = vec![1, 2, 3];
+    /// let w: Vec<_> = v.drain(..).map(|x| x * 2).collect();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let v = vec![1, 2, 3];
+    /// let w: Vec<_> = v.into_iter().map(|x| x * 2).collect();
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub ITER_WITH_DRAIN,
+    perf,
+    "replace `.drain(..)` with `.into_iter()`"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for usage of `_.as_ref().map(Deref::deref)` or its aliases
+    /// (such as `String::as_str`) on an `Option`.
+    ///
+    /// **Why is this bad?** Readability, this can be written more concisely as
+    /// `_.as_deref()`.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let opt = Some(String::new());
+    /// opt.as_ref().map(String::as_str);
+    /// opt.as_ref().map(|s| s.as_str());
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let opt = Some(String::new());
+    /// opt.as_deref();
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub OPTION_AS_REF_DEREF,
+    complexity,
+    "using `as_ref().map(Deref::deref)`, which is more succinctly expressed as `as_deref()`"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for calls to `as_deref()` on an `Option<&T>`, which
+    /// return the same `Option<&T>`.
+    ///
+    /// **Why is this bad?** The call does nothing.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let a = Some(&1);
+    /// let b = a.as_deref();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let a = Some(&1);
+    /// let b = a;
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub NEEDLESS_OPTION_AS_DEREF,
+    complexity,
+    "calling `as_deref()` on an `Option` of a reference, which does nothing"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for calls to `ends_with` with a file extension, e.g.
+    /// `name.ends_with(".rs")`, on a `String` or a `str`.
+    ///
+    /// **Why is this bad?** The comparison is case-sensitive, so `"main.RS"` wouldn't
+    /// match, while file extensions usually aren't.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// fn is_rust_file(filename: &str) -> bool {
+    ///     filename.ends_with(".rs")
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// fn is_rust_file(filename: &str) -> bool {
+    ///     std::path::Path::new(filename)
+    ///         .extension()
+    ///         .map_or(false, |ext| ext.eq_ignore_ascii_case("rs"))
+    /// }
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub CASE_SENSITIVE_FILE_EXTENSION_COMPARISONS,
+    pedantic,
+    "checks for calls to ends_with with case-sensitive file extensions"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for calls to `iter`, `iter_mut` or `into_iter` on
+    /// collections built with a single item or none, like `[x]`, `Some(x)`, `None`
+    /// or `vec![]`.
+    ///
+    /// **Why is this bad?** `std::iter::once` and `std::iter::empty` state the
+    /// intent directly and don't build a collection just to iterate over it.
+    ///
+    /// **Known problems:** The type of the iterator changes, which breaks the code if
+    /// it's required to be, e.g., a `std::slice::Iter`. The suggestion for `iter_mut`
+    /// mutably borrows the item itself rather than a copy of it.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let x = 1;
+    /// for i in [x].iter() {}
+    /// for i in Some(x).into_iter() {}
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let x = 1;
+    /// for i in std::iter::once(&x) {}
+    /// for i in std::iter::once(x) {}
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub ITER_ON_SINGLE_OR_EMPTY_COLLECTIONS,
+    nursery,
+    "iterating on a collection of a single item or none, instead of using `std::iter::once` or `std::iter::empty`"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for calls to `seek(SeekFrom::Current(0))` on a type
+    /// implementing `std::io::Seek`.
+    ///
+    /// **Why is this bad?** `stream_position()` returns the current position of the
+    /// stream the same way, and says so.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// let position = f.seek(SeekFrom::Current(0))?;
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// let position = f.stream_position()?;
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub SEEK_FROM_CURRENT,
+    complexity,
+    "using `seek(SeekFrom::Current(0))` to get the current position of a stream"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for calls to `seek(SeekFrom::Start(0))` on a type
+    /// implementing `std::io::Seek`, whose result is unused.
+    ///
+    /// **Why is this bad?** `rewind()` goes back to the start of the stream the same
+    /// way, and says so.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// f.seek(SeekFrom::Start(0))?;
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// f.rewind()?;
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub SEEK_TO_START_INSTEAD_OF_REWIND,
+    complexity,
+    "using `seek(SeekFrom::Start(0))` instead of `rewind()`"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for consecutive calls to `str::replace` replacing
+    /// different `char`s with the same string.
+    ///
+    /// **Why is this bad?** A single call with an array of `char`s as pattern
+    /// replaces all of them, without building the intermediate `String`s.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let hello = "hesuo worpd".replace('s', "l").replace('u', "l").replace('p', "l");
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// let hello = "hesuo worpd".replace(['s', 'u', 'p'], "l");
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub COLLAPSIBLE_STR_REPLACE,
+    style,
+    "consecutive `str::replace` calls replacing `char`s with the same string"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `cond.then_some(a).unwrap_or(b)` and
+    /// `cond.then(|| a).unwrap_or_else(|| b)`.
+    ///
+    /// **Why is this bad?** It's an `if` expression in disguise, an `if` is easier
+    /// to read.
+    ///
+    /// **Known problems:** `a` and `b` are both evaluated with `then_some` and
+    /// `unwrap_or`, only one of them is with an `if`.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let x = 1;
+    /// let sign = (x >= 0).then_some("+").unwrap_or("-");
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let x = 1;
+    /// let sign = if x >= 0 { "+" } else { "-" };
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub OBFUSCATED_IF_ELSE,
+    style,
+    "use of `.then_some(..).unwrap_or(..)` or `.then(..).unwrap_or_else(..)` instead of an `if`"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `to_owned` calls on a `Cow<'_, T>`.
+    ///
+    /// **Why is this bad?** `Cow<'_, T>` is `Clone`, so `to_owned` clones the `Cow`
+    /// itself, borrowed or not, rather than producing an owned `T`.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # use std::borrow::Cow;
+    /// let s = "Hello world!";
+    /// let cow = Cow::Borrowed(s);
+    /// let data = cow.to_owned();
+    /// ```
+    /// Use instead, depending on the intent:
+    /// ```rust
+    /// # use std::borrow::Cow;
+    /// # let s = "Hello world!";
+    /// # let cow = Cow::Borrowed(s);
+    /// let data = cow.clone();
+    /// let data = cow.into_owned();
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub SUSPICIOUS_TO_OWNED,
+    correctness,
+    "calls to `to_owned` on a `Cow<'_, _>`, which clone the `Cow` itself"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `.rev().next()`, `.rev().last()` and
+    /// `.rev().rev()` on a `DoubleEndedIterator`.
+    ///
+    /// **Why is this bad?** Reversing the iterator is unneeded: `.rev().next()` is
+    /// `.next_back()`, `.rev().last()` is `.next()` and the second `.rev()` cancels
+    /// the first one. `.rev().last()` also needlessly walks the whole iterator.
+    ///
+    /// **Known problems:** `.next()` doesn't run the side effects of the remaining
+    /// items, e.g. in a `map` closure, while `.rev().last()` does.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let v = vec![1, 2, 3];
+    /// let last = v.iter().rev().next();
+    /// let first = v.iter().rev().last();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let v = vec![1, 2, 3];
+    /// let last = v.iter().next_back();
+    /// let first = v.iter().next();
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub NEEDLESS_REV,
+    style,
+    "reversing a `DoubleEndedIterator` only to take an item from its other end, or to reverse it again"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for the `to_ne_bytes` and `from_ne_bytes` conversions
+    /// of integers and floats.
+    ///
+    /// If the `enforced-endianness` configuration value is set, the conversions with
+    /// the other byte orders are suggested instead.
+    ///
+    /// **Why is this bad?** The native byte order depends on the target, the bytes
+    /// written on one machine may be read differently on another: this is a latent
+    /// bug in portable file or wire formats.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let bytes = 2u32.to_ne_bytes();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let bytes = 2u32.to_le_bytes();
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub HOST_ENDIAN_BYTES,
+    restriction,
+    "disallows usage of the `to_ne_bytes` and `from_ne_bytes` conversions"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for the `to_le_bytes` and `from_le_bytes` conversions
+    /// of integers and floats.
+    ///
+    /// They aren't linted if the `enforced-endianness` configuration value is
+    /// `"little"`, and the big-endian conversions are suggested if it is `"big"`.
+    ///
+    /// **Why is this bad?** A format using only one byte order is simpler to
+    /// implement and to check.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let bytes = 2u32.to_le_bytes();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let bytes = 2u32.to_be_bytes();
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub LITTLE_ENDIAN_BYTES,
+    restriction,
+    "disallows usage of the `to_le_bytes` and `from_le_bytes` conversions"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for the `to_be_bytes` and `from_be_bytes` conversions
+    /// of integers and floats.
+    ///
+    /// They aren't linted if the `enforced-endianness` configuration value is
+    /// `"big"`, and the little-endian conversions are suggested if it is `"little"`.
+    ///
+    /// **Why is this bad?** A format using only one byte order is simpler to
+    /// implement and to check.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let bytes = 2u32.to_be_bytes();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let bytes = 2u32.to_le_bytes();
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub BIG_ENDIAN_BYTES,
+    restriction,
+    "disallows usage of the `to_be_bytes` and `from_be_bytes` conversions"
+}
+
 impl LintPass for Pass {
     fn get_lints(&self) -> LintArray {
         lint_array!(
@@ -708,12 +1410,44 @@ impl LintPass for Pass {
             STRING_EXTEND_CHARS,
             ITER_CLONED_COLLECT,
             USELESS_ASREF,
-            UNNECESSARY_FOLD
+            UNNECESSARY_FOLD,
+            MANUAL_SATURATING_ARITHMETIC,
+            FILETYPE_IS_FILE,
+            TO_DIGIT_IS_SOME,
+            ZST_OFFSET,
+            MANUAL_FILTER_MAP,
+            MANUAL_FIND_MAP,
+            ITER_OVEREAGER_CLONED,
+            ITER_SKIP_ZERO,
+            ITER_OUT_OF_BOUNDS,
+            DRAIN_COLLECT,
+            ITER_WITH_DRAIN,
+            OPTION_AS_REF_DEREF,
+            NEEDLESS_OPTION_AS_DEREF,
+            CASE_SENSITIVE_FILE_EXTENSION_COMPARISONS,
+            ITER_ON_SINGLE_OR_EMPTY_COLLECTIONS,
+            SEEK_FROM_CURRENT,
+            SEEK_TO_START_INSTEAD_OF_REWIND,
+            COLLAPSIBLE_STR_REPLACE,
+            OBFUSCATED_IF_ELSE,
+            SUSPICIOUS_TO_OWNED,
+            NEEDLESS_REV,
+            HOST_ENDIAN_BYTES,
+            LITTLE_ENDIAN_BYTES,
+            BIG_ENDIAN_BYTES
         )
     }
 }
 
 impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
+    fn enter_lint_attrs(&mut self, cx: &LateContext<'a, 'tcx>, attrs: &'tcx [ast::Attribute]) {
+        self.msrv.enter_lint_attrs(cx.sess(), attrs);
+    }
+
+    fn exit_lint_attrs(&mut self, _: &LateContext<'a, 'tcx>, attrs: &'tcx [ast::Attribute]) {
+        self.msrv.exit_lint_attrs(attrs);
+    }
+
     #[allow(cyclomatic_complexity)]
     fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx hir::Expr) {
         if in_macro(expr.span) {
@@ -722,61 +1456,115 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
 
         match expr.node {
             hir::ExprKind::MethodCall(ref method_call, ref method_span, ref args) => {
-                // Chain calls
-                // GET_UNWRAP needs to be checked before general `UNWRAP` lints
-                if let Some(arglists) = method_chain_args(expr, &["get", "unwrap"]) {
-                    lint_get_unwrap(cx, expr, arglists[0], false);
-                } else if let Some(arglists) = method_chain_args(expr, &["get_mut", "unwrap"]) {
-                    lint_get_unwrap(cx, expr, arglists[0], true);
-                } else if let Some(arglists) = method_chain_args(expr, &["unwrap"]) {
-                    lint_unwrap(cx, expr, arglists[0]);
-                } else if let Some(arglists) = method_chain_args(expr, &["ok", "expect"]) {
-                    lint_ok_expect(cx, expr, arglists[0]);
-                } else if let Some(arglists) = method_chain_args(expr, &["map", "unwrap_or"]) {
-                    lint_map_unwrap_or(cx, expr, arglists[0], arglists[1]);
-                } else if let Some(arglists) = method_chain_args(expr, &["map", "unwrap_or_else"]) {
-                    lint_map_unwrap_or_else(cx, expr, arglists[0], arglists[1]);
-                } else if let Some(arglists) = method_chain_args(expr, &["map_or"]) {
-                    lint_map_or_none(cx, expr, arglists[0]);
-                } else if let Some(arglists) = method_chain_args(expr, &["filter", "next"]) {
-                    lint_filter_next(cx, expr, arglists[0]);
-                } else if let Some(arglists) = method_chain_args(expr, &["filter", "map"]) {
-                    lint_filter_map(cx, expr, arglists[0], arglists[1]);
-                } else if let Some(arglists) = method_chain_args(expr, &["filter_map", "map"]) {
-                    lint_filter_map_map(cx, expr, arglists[0], arglists[1]);
-                } else if let Some(arglists) = method_chain_args(expr, &["filter", "flat_map"]) {
-                    lint_filter_flat_map(cx, expr, arglists[0], arglists[1]);
-                } else if let Some(arglists) = method_chain_args(expr, &["filter_map", "flat_map"]) {
-                    lint_filter_map_flat_map(cx, expr, arglists[0], arglists[1]);
-                } else if let Some(arglists) = method_chain_args(expr, &["find", "is_some"]) {
-                    lint_search_is_some(cx, expr, "find", arglists[0], arglists[1]);
-                } else if let Some(arglists) = method_chain_args(expr, &["position", "is_some"]) {
-                    lint_search_is_some(cx, expr, "position", arglists[0], arglists[1]);
-                } else if let Some(arglists) = method_chain_args(expr, &["rposition", "is_some"]) {
-                    lint_search_is_some(cx, expr, "rposition", arglists[0], arglists[1]);
-                } else if let Some(arglists) = method_chain_args(expr, &["extend"]) {
-                    lint_extend(cx, expr, arglists[0]);
-                } else if let Some(arglists) = method_chain_args(expr, &["unwrap", "as_ptr"]) {
-                    lint_cstring_as_ptr(cx, expr, &arglists[0][0], &arglists[1][0]);
-                } else if let Some(arglists) = method_chain_args(expr, &["iter", "nth"]) {
-                    lint_iter_nth(cx, expr, arglists[0], false);
-                } else if let Some(arglists) = method_chain_args(expr, &["iter_mut", "nth"]) {
-                    lint_iter_nth(cx, expr, arglists[0], true);
-                } else if method_chain_args(expr, &["skip", "next"]).is_some() {
-                    lint_iter_skip_next(cx, expr);
-                } else if let Some(arglists) = method_chain_args(expr, &["cloned", "collect"]) {
-                    lint_iter_cloned_collect(cx, expr, arglists[0]);
-                } else if let Some(arglists) = method_chain_args(expr, &["as_ref"]) {
-                    lint_asref(cx, expr, "as_ref", arglists[0]);
-                } else if let Some(arglists) = method_chain_args(expr, &["as_mut"]) {
-                    lint_asref(cx, expr, "as_mut", arglists[0]);
-                } else if let Some(arglists) = method_chain_args(expr, &["fold"]) {
-                    lint_unnecessary_fold(cx, expr, arglists[0]);
+                // All the chain lints look at the last two calls at most, the chain is walked once
+                // and matched against all of them. The first matching lint wins.
+                let (method_names, arg_lists) = method_calls(expr, 2);
+                let method_names: Vec<LocalInternedString> = method_names.iter().map(|s| s.as_str()).collect();
+                let method_names: Vec<&str> = method_names.iter().map(|s| &**s).collect();
+
+                match method_names.as_slice() {
+                    // GET_UNWRAP needs to be checked before general `UNWRAP` lints
+                    ["unwrap", "get"] => lint_get_unwrap(cx, expr, arg_lists[1], false),
+                    ["unwrap", "get_mut"] => lint_get_unwrap(cx, expr, arg_lists[1], true),
+                    ["unwrap", ..] => lint_unwrap(cx, expr, arg_lists[0]),
+                    ["expect", "ok"] => lint_ok_expect(cx, expr, arg_lists[1]),
+                    ["unwrap_or", "checked_add"] => {
+                        lint_manual_saturating_arithmetic(cx, expr, arg_lists[1], arg_lists[0], "add")
+                    },
+                    ["unwrap_or", "checked_sub"] => {
+                        lint_manual_saturating_arithmetic(cx, expr, arg_lists[1], arg_lists[0], "sub")
+                    },
+                    ["unwrap_or", "checked_mul"] => {
+                        lint_manual_saturating_arithmetic(cx, expr, arg_lists[1], arg_lists[0], "mul")
+                    },
+                    ["unwrap_or", "then_some"] => lint_obfuscated_if_else(cx, expr, arg_lists[1], arg_lists[0], false),
+                    ["unwrap_or_else", "then"] => lint_obfuscated_if_else(cx, expr, arg_lists[1], arg_lists[0], true),
+                    ["unwrap_or", "map"] => lint_map_unwrap_or(cx, expr, arg_lists[1], arg_lists[0]),
+                    ["unwrap_or_else", "map"] => lint_map_unwrap_or_else(cx, expr, arg_lists[1], arg_lists[0]),
+                    ["map_or", ..] => lint_map_or_none(cx, expr, arg_lists[0]),
+                    ["next", "filter"] => {
+                        lint_filter_next(cx, expr, arg_lists[1]);
+                        lint_filter_next_overeager_cloned(cx, expr, arg_lists[1]);
+                    },
+                    ["map", "filter"] => {
+                        lint_filter_map(cx, expr, arg_lists[1], arg_lists[0]);
+                        lint_manual_filter_map(cx, expr, false);
+                    },
+                    ["map", "find"] => lint_manual_filter_map(cx, expr, true),
+                    ["map", "as_ref"] | ["map", "as_mut"] => {
+                        if self.msrv.meets(msrvs::OPTION_AS_DEREF) {
+                            lint_option_as_ref_deref(cx, expr, arg_lists[1], arg_lists[0], method_names[1] == "as_mut");
+                        }
+                    },
+                    ["map", "filter_map"] => lint_filter_map_map(cx, expr, arg_lists[1], arg_lists[0]),
+                    ["flat_map", "filter"] => lint_filter_flat_map(cx, expr, arg_lists[1], arg_lists[0]),
+                    ["flat_map", "filter_map"] => lint_filter_map_flat_map(cx, expr, arg_lists[1], arg_lists[0]),
+                    ["is_some", "find"] => lint_search_is_some(cx, expr, "find", arg_lists[1], arg_lists[0]),
+                    ["is_some", "position"] => lint_search_is_some(cx, expr, "position", arg_lists[1], arg_lists[0]),
+                    ["is_some", "rposition"] => lint_search_is_some(cx, expr, "rposition", arg_lists[1], arg_lists[0]),
+                    ["extend", ..] => lint_extend(cx, expr, arg_lists[0]),
+                    ["as_ptr", "unwrap"] => lint_cstring_as_ptr(cx, expr, &arg_lists[1][0], &arg_lists[0][0]),
+                    ["nth", "iter"] => lint_iter_nth(cx, expr, arg_lists[1], false),
+                    ["nth", "iter_mut"] => lint_iter_nth(cx, expr, arg_lists[1], true),
+                    ["next", "skip"] => lint_iter_skip_next(cx, expr),
+                    ["next", "rev"] | ["last", "rev"] | ["rev", "rev"] => {
+                        lint_needless_rev(cx, expr, method_names[0], arg_lists[1])
+                    },
+                    ["collect", "cloned"] => lint_iter_cloned_collect(cx, expr, arg_lists[1]),
+                    ["collect", "drain"] => {
+                        if self.msrv.meets(msrvs::MEM_TAKE) {
+                            lint_drain_collect(cx, expr, arg_lists[1]);
+                        }
+                    },
+                    [method, clone]
+                        if (*clone == "cloned" || *clone == "copied") && OVEREAGER_CLONED_METHODS.contains(method) =>
+                    {
+                        lint_iter_overeager_cloned(cx, expr, method, clone, arg_lists[0])
+                    },
+                    ["as_deref", ..] => lint_needless_option_as_deref(cx, expr, arg_lists[0]),
+                    ["to_owned", ..] => lint_suspicious_to_owned(cx, expr, arg_lists[0]),
+                    ["as_ref", ..] => lint_asref(cx, expr, "as_ref", arg_lists[0]),
+                    ["as_mut", ..] => lint_asref(cx, expr, "as_mut", arg_lists[0]),
+                    ["fold", ..] => lint_unnecessary_fold(cx, expr, arg_lists[0]),
+                    ["is_file", ..] => lint_filetype_is_file(cx, expr, arg_lists[0]),
+                    ["ends_with", ..] => lint_case_sensitive_file_extension(cx, expr, arg_lists[0]),
+                    ["iter", ..] | ["iter_mut", ..] | ["into_iter", ..] => {
+                        lint_iter_on_single_or_empty_collection(cx, expr, method_names[0], arg_lists[0])
+                    },
+                    ["replace", ..] => {
+                        if self.msrv.meets(msrvs::PATTERN_TRAIT_CHAR_ARRAY) {
+                            lint_collapsible_str_replace(cx, expr);
+                        }
+                    },
+                    ["seek", ..] => {
+                        if self.msrv.meets(msrvs::SEEK_FROM_CURRENT) {
+                            lint_seek_from_current(cx, expr, arg_lists[0]);
+                        }
+                        if self.msrv.meets(msrvs::SEEK_REWIND) {
+                            lint_seek_to_start_instead_of_rewind(cx, expr, arg_lists[0]);
+                        }
+                    },
+                    ["is_some", "to_digit"] => lint_to_digit_is_some(cx, expr, arg_lists[1]),
+                    ["add", ..] | ["offset", ..] | ["sub", ..] | ["wrapping_offset", ..] |
+                    ["wrapping_add", ..] | ["wrapping_sub", ..] => check_pointer_offset(cx, expr, arg_lists[0]),
+                    ["to_ne_bytes", ..] | ["to_le_bytes", ..] | ["to_be_bytes", ..] => {
+                        let ty = cx.tables.expr_ty(&arg_lists[0][0]);
+                        lint_endian_bytes(cx, expr, method_names[0], ty, self.enforced_endianness);
+                    },
+                    _ => {},
                 }
 
                 lint_or_fun_call(cx, expr, *method_span, &method_call.ident.as_str(), args);
                 lint_expect_fun_call(cx, expr, *method_span, &method_call.ident.as_str(), args);
 
+                if args.len() == 2 && (method_call.ident.name == "skip" || method_call.ident.name == "take") {
+                    lint_iter_skip_take(cx, expr, &method_call.ident.as_str(), args);
+                }
+
+                if args.len() == 2 && method_call.ident.name == "drain" {
+                    lint_iter_with_drain(cx, expr, *method_span, args);
+                }
+
                 let self_ty = cx.tables.expr_ty_adjusted(&args[0]);
                 if args.len() == 1 && method_call.ident.name == "clone" {
                     lint_clone_on_copy(cx, expr, &args[0], self_ty);
@@ -801,6 +1589,20 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
                 };
                 lint_binary_expr_with_method_call(cx, &mut info);
             },
+            hir::ExprKind::Call(ref fun, _) => {
+                if_chain! {
+                    if let hir::ExprKind::Path(ref qpath) = fun.node;
+                    if let hir::QPath::TypeRelative(_, ref segment) = *qpath;
+                    if let Some(def_id) = opt_def_id(cx.tables.qpath_def(qpath, fun.hir_id));
+                    // the self type of an inherent function, not the type it returns
+                    if let Some(impl_id) = cx.tcx.impl_of_method(def_id);
+                    if cx.tcx.impl_trait_ref(impl_id).is_none();
+                    then {
+                        let ty = cx.tcx.type_of(impl_id);
+                        lint_endian_bytes(cx, expr, &segment.ident.as_str(), ty, self.enforced_endianness);
+                    }
+                }
+            },
             _ => (),
         }
     }
@@ -1040,10 +1842,10 @@ fn lint_expect_fun_call(cx: &LateContext<'_, '_>, expr: &hir::Expr, method_span:
         }
 
         let self_type = cx.tables.expr_ty(self_expr);
-        let known_types = &[&paths::OPTION, &paths::RESULT];
+        let known_types = &[DiagItem::Option, DiagItem::Result];
 
         // if not a known type, return early
-        if known_types.iter().all(|&k| !match_type(cx, self_type, k)) {
+        if known_types.iter().all(|&k| !is_type_diag_item(cx, self_type, k)) {
             return;
         }
 
@@ -1065,7 +1867,7 @@ fn lint_expect_fun_call(cx: &LateContext<'_, '_>, expr: &hir::Expr, method_span:
             return;
         }
 
-        let closure = if match_type(cx, self_type, &paths::OPTION) { "||" } else { "|_|" };
+        let closure = if is_type_diag_item(cx, self_type, DiagItem::Option) { "||" } else { "|_|" };
         let span_replace_word = method_span.with_hi(span.hi());
 
         if let Some(format_args) = extract_format_args(arg) {
@@ -1212,7 +2014,7 @@ fn lint_string_extend(cx: &LateContext<'_, '_>, expr: &hir::Expr, args: &[hir::E
         let self_ty = walk_ptrs_ty(cx.tables.expr_ty(target));
         let ref_str = if self_ty.sty == ty::Str {
             ""
-        } else if match_type(cx, self_ty, &paths::STRING) {
+        } else if is_type_diag_item(cx, self_ty, DiagItem::String) {
             "&"
         } else {
             return;
@@ -1236,7 +2038,7 @@ fn lint_string_extend(cx: &LateContext<'_, '_>, expr: &hir::Expr, args: &[hir::E
 
 fn lint_extend(cx: &LateContext<'_, '_>, expr: &hir::Expr, args: &[hir::Expr]) {
     let obj_ty = walk_ptrs_ty(cx.tables.expr_ty(&args[0]));
-    if match_type(cx, obj_ty, &paths::STRING) {
+    if is_type_diag_item(cx, obj_ty, DiagItem::String) {
         lint_string_extend(cx, expr, args);
     }
 }
@@ -1263,7 +2065,7 @@ fn lint_cstring_as_ptr(cx: &LateContext<'_, '_>, expr: &hir::Expr, new: &hir::Ex
 }
 
 fn lint_iter_cloned_collect(cx: &LateContext<'_, '_>, expr: &hir::Expr, iter_args: &[hir::Expr]) {
-    if match_type(cx, cx.tables.expr_ty(expr), &paths::VEC)
+    if is_type_diag_item(cx, cx.tables.expr_ty(expr), DiagItem::Vec)
         && derefs_to_slice(cx, &iter_args[0], cx.tables.expr_ty(&iter_args[0])).is_some()
     {
         span_lint(
@@ -1276,6 +2078,258 @@ fn lint_iter_cloned_collect(cx: &LateContext<'_, '_>, expr: &hir::Expr, iter_arg
     }
 }
 
+/// The iterator methods which don't need the items to be cloned before them, handled by
+/// `lint_iter_overeager_cloned`
+const OVEREAGER_CLONED_METHODS: [&str; 9] = ["count", "next", "last", "nth", "skip", "take", "find", "any", "all"];
+
+/// lint use of `cloned().<method>(..)` or `copied().<method>(..)` for `Iterator`s when
+/// `<method>` doesn't need owned items
+fn lint_iter_overeager_cloned(
+    cx: &LateContext<'_, '_>,
+    expr: &hir::Expr,
+    method: &str,
+    clone: &str,
+    method_args: &[hir::Expr],
+) {
+    let clone_expr = &method_args[0];
+    let clone_span = match clone_expr.node {
+        hir::ExprKind::MethodCall(_, span, _) => span,
+        _ => return,
+    };
+    if !match_trait_method(cx, expr, &paths::ITERATOR) || !match_trait_method(cx, clone_expr, &paths::ITERATOR) {
+        return;
+    }
+
+    let args: Vec<_> = method_args[1..]
+        .iter()
+        .map(|arg| snippet(cx, arg.span, "..").into_owned())
+        .collect();
+    let args = args.join(", ");
+    let (sugg, applicability) = match method {
+        // the items are only counted, they don't need to be cloned at all
+        "count" => ("count()".to_owned(), Applicability::MachineApplicable),
+        "next" | "last" | "nth" | "skip" | "take" => (
+            format!("{}({}).{}()", method, args, clone),
+            Applicability::MachineApplicable,
+        ),
+        // the closure sees a reference to the original item instead of the clone
+        "find" => (format!("find({}).{}()", args, clone), Applicability::MaybeIncorrect),
+        "any" | "all" => (format!("{}({})", method, args), Applicability::MaybeIncorrect),
+        _ => return,
+    };
+    let msg = match applicability {
+        Applicability::MachineApplicable => "try",
+        _ => "try, the closure may need to be adapted to take a reference",
+    };
+    let span = clone_span.with_hi(expr.span.hi());
+    span_lint_and_then(
+        cx,
+        ITER_OVEREAGER_CLONED,
+        span,
+        "unnecessarily eager cloning of iterator items",
+        |db| {
+            db.span_suggestion_with_applicability(span, msg, sugg, applicability);
+        },
+    );
+}
+
+/// lint `.cloned().filter(p).next()`, which only needs to clone the item that is found
+fn lint_filter_next_overeager_cloned(cx: &LateContext<'_, '_>, expr: &hir::Expr, filter_args: &[hir::Expr]) {
+    let clone_expr = &filter_args[0];
+    let (clone, clone_span) = match clone_expr.node {
+        hir::ExprKind::MethodCall(ref path, span, _) => (path.ident.name, span),
+        _ => return,
+    };
+    if (clone != "cloned" && clone != "copied")
+        || !match_trait_method(cx, expr, &paths::ITERATOR)
+        || !match_trait_method(cx, clone_expr, &paths::ITERATOR)
+    {
+        return;
+    }
+
+    let span = clone_span.with_hi(expr.span.hi());
+    span_lint_and_then(
+        cx,
+        ITER_OVEREAGER_CLONED,
+        span,
+        "unnecessarily eager cloning of iterator items",
+        |db| {
+            db.span_suggestion_with_applicability(
+                span,
+                "try, the closure may need to be adapted to take a reference",
+                format!("filter({}).next().{}()", snippet(cx, filter_args[1].span, ".."), clone),
+                Applicability::MaybeIncorrect,
+            );
+        },
+    );
+}
+
+/// lint use of `skip(0)`, and of `skip(n)` or `take(n)` with more items than the iterator has
+fn lint_iter_skip_take(cx: &LateContext<'_, '_>, expr: &hir::Expr, method: &str, args: &[hir::Expr]) {
+    if !match_trait_method(cx, expr, &paths::ITERATOR) {
+        return;
+    }
+    let count = match constant(cx, cx.tables, &args[1]) {
+        Some((Constant::Int(count), _)) => count,
+        _ => return,
+    };
+
+    if count == 0 && method == "skip" {
+        span_lint_and_then(cx, ITER_SKIP_ZERO, args[1].span, "usage of `.skip(0)`", |db| {
+            db.span_suggestion_with_applicability(
+                args[1].span,
+                "if you meant to skip the first element, use",
+                "1".to_owned(),
+                Applicability::MaybeIncorrect,
+            );
+            db.note("this call to `skip` does nothing and is useless; remove it");
+        });
+    } else if known_iter_len(cx, &args[0]).map_or(false, |len| count > len) {
+        let (msg, note) = if method == "skip" {
+            (
+                "this `.skip()` call skips more items than the iterator will produce",
+                "this operation is useless and will create an empty iterator",
+            )
+        } else {
+            (
+                "this `.take()` call takes more items than the iterator will produce",
+                "this operation is useless and the returned iterator will simply yield the same items",
+            )
+        };
+        span_note_and_lint(cx, ITER_OUT_OF_BOUNDS, expr.span, msg, expr.span, note);
+    }
+}
+
+/// The number of items of an iterator over an array, `iter::once(_)` or `iter::empty()`
+fn known_iter_len(cx: &LateContext<'_, '_>, iter: &hir::Expr) -> Option<u128> {
+    match iter.node {
+        hir::ExprKind::MethodCall(ref path, _, ref args)
+            if path.ident.name == "iter" || path.ident.name == "iter_mut" || path.ident.name == "into_iter" =>
+        {
+            match walk_ptrs_ty(cx.tables.expr_ty(&args[0])).sty {
+                ty::Array(_, len) => len.assert_usize(cx.tcx).map(u128::from),
+                _ => None,
+            }
+        },
+        hir::ExprKind::Call(ref func, _) => {
+            if let hir::ExprKind::Path(ref qpath) = func.node {
+                let def_id = opt_def_id(cx.tables.qpath_def(qpath, func.hir_id))?;
+                if match_def_path(cx.tcx, def_id, &paths::ITER_ONCE) {
+                    return Some(1);
+                } else if match_def_path(cx.tcx, def_id, &paths::ITER_EMPTY) {
+                    return Some(0);
+                }
+            }
+            None
+        },
+        _ => None,
+    }
+}
+
+#[derive(PartialEq, Eq)]
+enum Extremum {
+    Min,
+    Max,
+}
+
+/// Returns whether `expr` is the minimum or the maximum of the integer type `ty`, either as a
+/// constant or as a call to its `min_value()` or `max_value()`
+fn int_extremum<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, expr: &hir::Expr, ty: Ty<'tcx>) -> Option<Extremum> {
+    if_chain! {
+        if let hir::ExprKind::Call(ref fun, ref args) = expr.node;
+        if args.is_empty();
+        if let hir::ExprKind::Path(ref qpath) = fun.node;
+        if let Some(def_id) = opt_def_id(cx.tables.qpath_def(qpath, fun.hir_id));
+        // an associated function of the inherent impl of the integer type, not any function
+        // with the same name
+        if let Some(impl_id) = cx.tcx.impl_of_method(def_id);
+        if cx.tcx.impl_trait_ref(impl_id).is_none();
+        if cx.tcx.type_of(impl_id) == ty;
+        then {
+            let name = last_path_segment(qpath).ident.name;
+            return if name == "min_value" {
+                Some(Extremum::Min)
+            } else if name == "max_value" {
+                Some(Extremum::Max)
+            } else {
+                None
+            };
+        }
+    }
+
+    let bits = match constant(cx, cx.tables, expr) {
+        Some((Constant::Int(bits), _)) => bits,
+        _ => return None,
+    };
+    let (min, max) = match ty.sty {
+        ty::Int(ity) => {
+            let min = unsext(cx.tcx, i128::min_value() >> (128 - int_bits(cx.tcx, ity)), ity);
+            (min, min - 1)
+        },
+        ty::Uint(uty) => (0, clip(cx.tcx, u128::max_value(), uty)),
+        _ => return None,
+    };
+    if bits == min {
+        Some(Extremum::Min)
+    } else if bits == max {
+        Some(Extremum::Max)
+    } else {
+        None
+    }
+}
+
+fn lint_manual_saturating_arithmetic<'a, 'tcx>(
+    cx: &LateContext<'a, 'tcx>,
+    expr: &hir::Expr,
+    arith_args: &[hir::Expr],
+    unwrap_args: &[hir::Expr],
+    arith: &str,
+) {
+    let ty = cx.tables.expr_ty(&arith_args[0]);
+    let extremum = match int_extremum(cx, &unwrap_args[1], ty) {
+        Some(extremum) => extremum,
+        None => return,
+    };
+
+    // the bound that is reached on overflow depends on the sign of the right-hand side for signed
+    // integers, it must be known
+    let expected = if let ty::Int(ity) = ty.sty {
+        let rhs = match constant(cx, cx.tables, &arith_args[1]) {
+            Some((Constant::Int(bits), _)) => sext(cx.tcx, bits, ity),
+            _ => return,
+        };
+        if rhs == 0 {
+            return;
+        }
+        match (arith, rhs > 0) {
+            ("add", true) | ("sub", false) => Extremum::Max,
+            ("add", false) | ("sub", true) => Extremum::Min,
+            _ => return,
+        }
+    } else if arith == "sub" {
+        Extremum::Min
+    } else {
+        Extremum::Max
+    };
+    if extremum != expected {
+        return;
+    }
+
+    span_lint_and_sugg(
+        cx,
+        MANUAL_SATURATING_ARITHMETIC,
+        expr.span,
+        "manual saturating arithmetic",
+        &format!("try using `saturating_{}`", arith),
+        format!(
+            "{}.saturating_{}({})",
+            sugg::Sugg::hir(cx, &arith_args[0], "..").maybe_par(),
+            arith,
+            snippet(cx, arith_args[1].span, ".."),
+        ),
+    );
+}
+
 fn lint_unnecessary_fold(cx: &LateContext<'_, '_>, expr: &hir::Expr, fold_args: &[hir::Expr]) {
     // Check that this is a call to Iterator::fold rather than just some function called fold
     if !match_trait_method(cx, expr, &paths::ITERATOR) {
@@ -1368,7 +2422,7 @@ fn lint_iter_nth(cx: &LateContext<'_, '_>, expr: &hir::Expr, iter_args: &[hir::E
     let mut_str = if is_mut { "_mut" } else { "" };
     let caller_type = if derefs_to_slice(cx, &iter_args[0], cx.tables.expr_ty(&iter_args[0])).is_some() {
         "slice"
-    } else if match_type(cx, cx.tables.expr_ty(&iter_args[0]), &paths::VEC) {
+    } else if is_type_diag_item(cx, cx.tables.expr_ty(&iter_args[0]), DiagItem::Vec) {
         "Vec"
     } else if match_type(cx, cx.tables.expr_ty(&iter_args[0]), &paths::VEC_DEQUE) {
         "VecDeque"
@@ -1394,7 +2448,7 @@ fn lint_get_unwrap(cx: &LateContext<'_, '_>, expr: &hir::Expr, get_args: &[hir::
     let expr_ty = cx.tables.expr_ty(&get_args[0]);
     let caller_type = if derefs_to_slice(cx, &get_args[0], expr_ty).is_some() {
         "slice"
-    } else if match_type(cx, expr_ty, &paths::VEC) {
+    } else if is_type_diag_item(cx, expr_ty, DiagItem::Vec) {
         "Vec"
     } else if match_type(cx, expr_ty, &paths::VEC_DEQUE) {
         "VecDeque"
@@ -1444,7 +2498,7 @@ fn derefs_to_slice(cx: &LateContext<'_, '_>, expr: &hir::Expr, ty: Ty<'_>) -> Op
         match ty.sty {
             ty::Slice(_) => true,
             ty::Adt(def, _) if def.is_box() => may_slice(cx, ty.boxed_ty()),
-            ty::Adt(..) => match_type(cx, ty, &paths::VEC),
+            ty::Adt(..) => is_type_diag_item(cx, ty, DiagItem::Vec),
             ty::Array(_, size) => size.assert_usize(cx.tcx).expect("array length") < 32,
             ty::Ref(_, inner, _) => may_slice(cx, inner),
             _ => false,
@@ -1475,9 +2529,9 @@ fn derefs_to_slice(cx: &LateContext<'_, '_>, expr: &hir::Expr, ty: Ty<'_>) -> Op
 fn lint_unwrap(cx: &LateContext<'_, '_>, expr: &hir::Expr, unwrap_args: &[hir::Expr]) {
     let obj_ty = walk_ptrs_ty(cx.tables.expr_ty(&unwrap_args[0]));
 
-    let mess = if match_type(cx, obj_ty, &paths::OPTION) {
+    let mess = if is_type_diag_item(cx, obj_ty, DiagItem::Option) {
         Some((OPTION_UNWRAP_USED, "an Option", "None"))
-    } else if match_type(cx, obj_ty, &paths::RESULT) {
+    } else if is_type_diag_item(cx, obj_ty, DiagItem::Result) {
         Some((RESULT_UNWRAP_USED, "a Result", "Err"))
     } else {
         None
@@ -1502,7 +2556,7 @@ fn lint_unwrap(cx: &LateContext<'_, '_>, expr: &hir::Expr, unwrap_args: &[hir::E
 /// lint use of `ok().expect()` for `Result`s
 fn lint_ok_expect(cx: &LateContext<'_, '_>, expr: &hir::Expr, ok_args: &[hir::Expr]) {
     // lint if the caller of `ok()` is a `Result`
-    if match_type(cx, cx.tables.expr_ty(&ok_args[0]), &paths::RESULT) {
+    if is_type_diag_item(cx, cx.tables.expr_ty(&ok_args[0]), DiagItem::Result) {
         let result_type = cx.tables.expr_ty(&ok_args[0]);
         if let Some(error_type) = get_error_type(cx, result_type) {
             if has_debug_impl(error_type, cx) {
@@ -1520,7 +2574,7 @@ fn lint_ok_expect(cx: &LateContext<'_, '_>, expr: &hir::Expr, ok_args: &[hir::Ex
 /// lint use of `map().unwrap_or()` for `Option`s
 fn lint_map_unwrap_or(cx: &LateContext<'_, '_>, expr: &hir::Expr, map_args: &[hir::Expr], unwrap_args: &[hir::Expr]) {
     // lint if the caller of `map()` is an `Option`
-    if match_type(cx, cx.tables.expr_ty(&map_args[0]), &paths::OPTION) {
+    if is_type_diag_item(cx, cx.tables.expr_ty(&map_args[0]), DiagItem::Option) {
         // get snippets for args to map() and unwrap_or()
         let map_snippet = snippet(cx, map_args[1].span, "..");
         let unwrap_snippet = snippet(cx, unwrap_args[1].span, "..");
@@ -1574,8 +2628,8 @@ fn lint_map_unwrap_or_else<'a, 'tcx>(
     unwrap_args: &'tcx [hir::Expr],
 ) {
     // lint if the caller of `map()` is an `Option`
-    let is_option = match_type(cx, cx.tables.expr_ty(&map_args[0]), &paths::OPTION);
-    let is_result = match_type(cx, cx.tables.expr_ty(&map_args[0]), &paths::RESULT);
+    let is_option = is_type_diag_item(cx, cx.tables.expr_ty(&map_args[0]), DiagItem::Option);
+    let is_result = is_type_diag_item(cx, cx.tables.expr_ty(&map_args[0]), DiagItem::Result);
     if is_option || is_result {
         // lint message
         let msg = if is_option {
@@ -1627,7 +2681,7 @@ fn lint_map_unwrap_or_else<'a, 'tcx>(
 
 /// lint use of `_.map_or(None, _)` for `Option`s
 fn lint_map_or_none<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, expr: &'tcx hir::Expr, map_or_args: &'tcx [hir::Expr]) {
-    if match_type(cx, cx.tables.expr_ty(&map_or_args[0]), &paths::OPTION) {
+    if is_type_diag_item(cx, cx.tables.expr_ty(&map_or_args[0]), DiagItem::Option) {
         // check if the first non-self argument to map_or() is None
         let map_or_arg_is_none = if let hir::ExprKind::Path(ref qpath) = map_or_args[1].node {
             match_qpath(qpath, &paths::OPTION_NONE)
@@ -1687,6 +2741,75 @@ fn lint_filter_map<'a, 'tcx>(
     }
 }
 
+/// lint use of `filter().map()` or `find().map()` where the `map` unwraps what the
+/// `filter` checked, e.g. `.filter(|x| x.get().is_some()).map(|x| x.get().unwrap())`
+fn lint_manual_filter_map(cx: &LateContext<'_, '_>, expr: &hir::Expr, is_find: bool) {
+    let (lint, name) = if is_find {
+        (MANUAL_FIND_MAP, "find")
+    } else {
+        (MANUAL_FILTER_MAP, "filter")
+    };
+    if_chain! {
+        if let hir::ExprKind::MethodCall(_, _, ref map_args) = expr.node;
+        let filter_expr = &map_args[0];
+        if let hir::ExprKind::MethodCall(_, filter_span, ref filter_args) = filter_expr.node;
+        if match_trait_method(cx, filter_expr, &paths::ITERATOR);
+        if let Some((filter_param, filter_body)) = closure_with_binding(cx, &filter_args[1]);
+        if let Some((map_param, map_body)) = closure_with_binding(cx, &map_args[1]);
+        if filter_param.name == map_param.name;
+
+        // `.filter(|x| <recv>.is_some())`
+        if let hir::ExprKind::MethodCall(ref check, _, ref check_args) = filter_body.node;
+        let checked = &check_args[0];
+        let checked_ty = walk_ptrs_ty(cx.tables.expr_ty(checked));
+        let is_option = check.ident.name == "is_some" && match_type(cx, checked_ty, &paths::OPTION);
+        let is_result = check.ident.name == "is_ok" && match_type(cx, checked_ty, &paths::RESULT);
+        if is_option || is_result;
+
+        // `.map(|x| <recv>.unwrap())`
+        if let hir::ExprKind::MethodCall(ref unwrap, _, ref unwrap_args) = map_body.node;
+        if unwrap.ident.name == "unwrap";
+        if SpanlessEq::new(cx).ignore_fn().eq_expr(checked, &unwrap_args[0]);
+        then {
+            let unwrapped = sugg::Sugg::hir(cx, &unwrap_args[0], "..");
+            let body = if is_result {
+                format!("{}.ok()", unwrapped.maybe_par())
+            } else {
+                unwrapped.to_string()
+            };
+            let span = filter_span.with_hi(expr.span.hi());
+            span_lint_and_then(
+                cx,
+                lint,
+                span,
+                &format!("`{}(..).map(..)` can be simplified as `{}_map(..)`", name, name),
+                |db| {
+                    db.span_suggestion_with_applicability(
+                        span,
+                        "try",
+                        format!("{}_map(|{}| {})", name, map_param.name, body),
+                        // the value may borrow the argument, which the new closure takes by value
+                        Applicability::MaybeIncorrect,
+                    );
+                },
+            );
+        }
+    }
+}
+
+/// The binding and the body of a closure with a single argument, e.g. `|x| x + 1`
+fn closure_with_binding<'a>(cx: &'a LateContext<'_, '_>, expr: &hir::Expr) -> Option<(ast::Ident, &'a hir::Expr)> {
+    if let hir::ExprKind::Closure(_, _, body_id, _, _) = expr.node {
+        let body = cx.tcx.hir.body(body_id);
+        if let [ref arg] = *body.arguments {
+            if let hir::PatKind::Binding(_, _, ident, None) = arg.pat.node {
+                return Some((ident, remove_blocks(&body.value)));
+            }
+        }
+    }
+    None
+}
+
 /// lint use of `filter().map()` for `Iterators`
 fn lint_filter_map_map<'a, 'tcx>(
     cx: &LateContext<'a, 'tcx>,
@@ -1937,10 +3060,873 @@ fn lint_asref(cx: &LateContext<'_, '_>, expr: &hir::Expr, call_name: &str, as_re
     }
 }
 
+/// lint `_.ends_with(".ext")` on a `String` or a `str`
+fn lint_case_sensitive_file_extension(cx: &LateContext<'_, '_>, expr: &hir::Expr, ends_with_args: &[hir::Expr]) {
+    if_chain! {
+        if ends_with_args.len() == 2;
+        if let hir::ExprKind::Lit(ref lit) = ends_with_args[1].node;
+        if let ast::LitKind::Str(ref ext, _) = lit.node;
+        let ext = ext.as_str();
+        if ext.starts_with('.') && ext.len() > 1;
+        let ext = &ext[1..];
+        // extensions written in a single case, as an uppercase extension is likely to be the
+        // one of a differently named file
+        if ext.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+            || ext.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit());
+        if !ext.chars().all(|c| c.is_ascii_digit());
+        let recv_ty = walk_ptrs_ty(cx.tables.expr_ty(&ends_with_args[0]));
+        if recv_ty.sty == ty::Str || match_type(cx, recv_ty, &paths::STRING);
+        then {
+            span_lint_and_then(
+                cx,
+                CASE_SENSITIVE_FILE_EXTENSION_COMPARISONS,
+                expr.span,
+                "case-sensitive file extension comparison",
+                |db| {
+                    db.span_suggestion_with_applicability(
+                        expr.span,
+                        "use `std::path::Path` to compare the extension ignoring the case",
+                        format!(
+                            "std::path::Path::new({}).extension().map_or(false, |ext| \
+                             ext.eq_ignore_ascii_case(\"{}\"))",
+                            snippet(cx, ends_with_args[0].span, ".."),
+                            ext.to_lowercase()
+                        ),
+                        Applicability::MaybeIncorrect,
+                    );
+                },
+            );
+        }
+    }
+}
+
+/// lint `[x].iter()`, `Some(x).into_iter()`, `vec![].into_iter()` and the like
+fn lint_iter_on_single_or_empty_collection(
+    cx: &LateContext<'_, '_>,
+    expr: &hir::Expr,
+    method: &str,
+    iter_args: &[hir::Expr],
+) {
+    if iter_args.len() != 1 {
+        return;
+    }
+    let recv = &iter_args[0];
+    // items of `Option` and `Vec` are moved by `into_iter`, the ones of arrays are borrowed
+    let (items, by_value): (&[hir::Expr], bool) = match higher::vec_macro(cx, recv) {
+        Some(higher::VecArgs::Vec(items)) => (items, true),
+        Some(higher::VecArgs::Repeat(..)) => return,
+        None => match recv.node {
+            _ if in_macro(recv.span) => return,
+            hir::ExprKind::Array(ref items) => (items, false),
+            hir::ExprKind::Call(ref func, ref args) if is_path_to_def(cx, func, &paths::OPTION_SOME) => (args, true),
+            hir::ExprKind::Path(_) if is_path_to_def(cx, recv, &paths::OPTION_NONE) => (&[], true),
+            _ => return,
+        },
+    };
+
+    let (msg, sugg, applicability) = match items {
+        [] => (
+            "an empty collection",
+            "std::iter::empty()".to_string(),
+            // the item type may need an annotation
+            Applicability::MaybeIncorrect,
+        ),
+        [item] => {
+            let item = sugg::Sugg::hir(cx, item, "..");
+            let (item, applicability) = match method {
+                // the item was copied or moved into the collection, it's now borrowed in place
+                "iter_mut" => (item.mut_addr(), Applicability::MaybeIncorrect),
+                "into_iter" if by_value => (item, Applicability::MachineApplicable),
+                _ => (item.addr(), Applicability::MachineApplicable),
+            };
+            (
+                "a collection with only one item",
+                format!("std::iter::once({})", item),
+                applicability,
+            )
+        },
+        _ => return,
+    };
+    span_lint_and_then(
+        cx,
+        ITER_ON_SINGLE_OR_EMPTY_COLLECTIONS,
+        expr.span,
+        &format!("`{}` call on {}", method, msg),
+        |db| {
+            db.span_suggestion_with_applicability(expr.span, "try", sugg, applicability);
+        },
+    );
+}
+
+/// lint `_.replace('a', s).replace('b', s)`, starting from the last call of the chain with the
+/// same replacement
+fn lint_collapsible_str_replace(cx: &LateContext<'_, '_>, expr: &hir::Expr) {
+    let to = match replace_args(cx, expr) {
+        Some(args) => &args[2],
+        None => return,
+    };
+    if let Some(parent) = get_parent_expr(cx, expr) {
+        if let Some(args) = replace_args(cx, parent) {
+            if args[0].id == expr.id && SpanlessEq::new(cx).ignore_fn().eq_expr(&args[2], to) {
+                return;
+            }
+        }
+    }
+
+    let mut from = Vec::new();
+    let mut recv = expr;
+    while let Some(args) = replace_args(cx, recv) {
+        if !SpanlessEq::new(cx).ignore_fn().eq_expr(&args[2], to) {
+            break;
+        }
+        from.push(&args[1]);
+        recv = &args[0];
+    }
+    if from.len() < 2 || !is_str_or_string(cx, recv) {
+        return;
+    }
+
+    let from = from.iter().rev().map(|c| snippet(cx, c.span, "..")).collect::<Vec<_>>();
+    span_lint_and_then(cx, COLLAPSIBLE_STR_REPLACE, expr.span, "used consecutive `str::replace` call", |db| {
+        db.span_suggestion_with_applicability(
+            expr.span,
+            "replace with",
+            format!(
+                "{}.replace([{}], {})",
+                snippet(cx, recv.span, ".."),
+                from.join(", "),
+                snippet(cx, to.span, "..")
+            ),
+            Applicability::MachineApplicable,
+        );
+    });
+}
+
+/// lint `cond.then_some(a).unwrap_or(b)` and `cond.then(|| a).unwrap_or_else(|| b)`
+fn lint_obfuscated_if_else(
+    cx: &LateContext<'_, '_>,
+    expr: &hir::Expr,
+    then_args: &[hir::Expr],
+    unwrap_args: &[hir::Expr],
+    lazy: bool,
+) {
+    if cx.tables.expr_ty(&then_args[0]).sty != ty::Bool {
+        return;
+    }
+
+    // the arguments of `then` and `unwrap_or_else` are called, the body of a closure is inlined
+    let value = |arg: &hir::Expr| match arg.node {
+        hir::ExprKind::Closure(_, _, body_id, _, _) if lazy => {
+            snippet(cx, cx.tcx.hir.body(body_id).value.span, "..").into_owned()
+        },
+        _ if lazy => format!("{}()", snippet(cx, arg.span, "..")),
+        _ => snippet(cx, arg.span, "..").into_owned(),
+    };
+    let sugg = format!(
+        "if {} {{ {} }} else {{ {} }}",
+        without_parens(&snippet(cx, then_args[0].span, "..")),
+        value(&then_args[1]),
+        value(&unwrap_args[1])
+    );
+    let needs_parens = get_parent_expr(cx, expr).map_or(false, |parent| match parent.node {
+        hir::ExprKind::Binary(..)
+        | hir::ExprKind::Unary(..)
+        | hir::ExprKind::Cast(..)
+        | hir::ExprKind::Field(..)
+        | hir::ExprKind::Index(..) => true,
+        hir::ExprKind::MethodCall(_, _, ref args) => args[0].id == expr.id,
+        _ => false,
+    });
+    let sugg = if needs_parens { format!("({})", sugg) } else { sugg };
+
+    span_lint_and_then(
+        cx,
+        OBFUSCATED_IF_ELSE,
+        expr.span,
+        "this method chain can be written more clearly with `if .. else ..`",
+        |db| {
+            db.span_suggestion_with_applicability(expr.span, "try", sugg, Applicability::MachineApplicable);
+        },
+    );
+}
+
+/// lint `to_owned` on a `Cow`, which returns a `Cow`
+fn lint_suspicious_to_owned(cx: &LateContext<'_, '_>, expr: &hir::Expr, to_owned_args: &[hir::Expr]) {
+    if !match_trait_method(cx, expr, &paths::TO_OWNED) {
+        return;
+    }
+    let inner_ty = match cx.tables.expr_ty(expr).sty {
+        ty::Adt(def, substs) if match_def_path(cx.tcx, def.did, &paths::COW) => substs.type_at(0),
+        _ => return,
+    };
+    let recv = sugg::Sugg::hir(cx, &to_owned_args[0], "..").maybe_par();
+    span_lint_and_then(
+        cx,
+        SUSPICIOUS_TO_OWNED,
+        expr.span,
+        &format!(
+            "this `to_owned` call clones the `Cow<'_, {}>` itself and does not cause its contents to become owned",
+            inner_ty
+        ),
+        |db| {
+            db.span_suggestion_with_applicability(
+                expr.span,
+                "depending on intent, either make the `Cow` an `Owned` variant",
+                format!("{}.into_owned()", recv),
+                Applicability::MaybeIncorrect,
+            );
+            db.span_suggestion_with_applicability(
+                expr.span,
+                "or clone the `Cow` itself",
+                format!("{}.clone()", recv),
+                Applicability::MaybeIncorrect,
+            );
+        },
+    );
+}
+
+/// lint `.rev()` followed by `.next()`, `.last()` or `.rev()` on a `DoubleEndedIterator`
+fn lint_needless_rev(cx: &LateContext<'_, '_>, expr: &hir::Expr, method: &str, rev_args: &[hir::Expr]) {
+    if !match_trait_method(cx, expr, &paths::ITERATOR) || !match_trait_method(cx, &rev_args[0], &paths::ITERATOR) {
+        return;
+    }
+    let recv = &rev_args[0];
+    match get_trait_def_id(cx, &paths::DOUBLE_ENDED_ITERATOR) {
+        Some(trait_id) if implements_trait(cx, cx.tables.expr_ty(recv), trait_id, &[]) => {},
+        _ => return,
+    }
+    let recv_snip = sugg::Sugg::hir(cx, recv, "..");
+    let (msg, help, sugg, applicability) = match method {
+        "next" => (
+            "manual backwards iteration",
+            "use",
+            format!("{}.next_back()", recv_snip.maybe_par()),
+            Applicability::MachineApplicable,
+        ),
+        "last" => (
+            "called `.rev().last()` on a `DoubleEndedIterator`, which is its first item",
+            "use",
+            format!("{}.next()", recv_snip.maybe_par()),
+            Applicability::MaybeIncorrect,
+        ),
+        _ => (
+            "the second `.rev()` cancels the first one",
+            "remove both calls",
+            recv_snip.to_string(),
+            Applicability::MachineApplicable,
+        ),
+    };
+    span_lint_and_then(cx, NEEDLESS_REV, expr.span, msg, |db| {
+        db.span_suggestion_with_applicability(expr.span, help, sugg, applicability);
+    });
+}
+
+/// lint the `to_*_bytes` methods and `from_*_bytes` functions of integers and floats
+fn lint_endian_bytes(
+    cx: &LateContext<'_, '_>,
+    expr: &hir::Expr,
+    name: &str,
+    ty: Ty<'_>,
+    enforced: Option<Endianness>,
+) {
+    let (prefix, endianness) = match name {
+        "to_ne_bytes" => ("to", Endianness::Host),
+        "to_le_bytes" => ("to", Endianness::Little),
+        "to_be_bytes" => ("to", Endianness::Big),
+        "from_ne_bytes" => ("from", Endianness::Host),
+        "from_le_bytes" => ("from", Endianness::Little),
+        "from_be_bytes" => ("from", Endianness::Big),
+        _ => return,
+    };
+    match ty.sty {
+        ty::Int(_) | ty::Uint(_) | ty::Float(_) => {},
+        _ => return,
+    }
+    if enforced == Some(endianness) {
+        return;
+    }
+    let lint = match endianness {
+        Endianness::Host => HOST_ENDIAN_BYTES,
+        Endianness::Little => LITTLE_ENDIAN_BYTES,
+        Endianness::Big => BIG_ENDIAN_BYTES,
+    };
+    let msg = if prefix == "to" {
+        format!("usage of the `{}::{}` method", ty, name)
+    } else {
+        format!("usage of the `{}::{}` function", ty, name)
+    };
+    match enforced {
+        Some(enforced) => span_help_and_lint(
+            cx,
+            lint,
+            expr.span,
+            &msg,
+            &format!("use `{}_{}_bytes` instead", prefix, enforced.infix()),
+        ),
+        None if endianness == Endianness::Host => span_help_and_lint(
+            cx,
+            lint,
+            expr.span,
+            &msg,
+            "specify the desired endianness explicitly",
+        ),
+        None => span_lint(cx, lint, expr.span, &msg),
+    }
+}
+
+/// Removes the parentheses around a whole expression, e.g. `(x > 0)`: they are kept in its span
+fn without_parens(snip: &str) -> &str {
+    if !snip.starts_with('(') || !snip.ends_with(')') {
+        return snip;
+    }
+    let mut depth = 0;
+    for (i, c) in snip.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            _ => (),
+        }
+        // the first parenthesis is closed before the end
+        if depth == 0 && i + 1 < snip.len() {
+            return snip;
+        }
+    }
+    &snip[1..snip.len() - 1]
+}
+
+/// Returns the arguments of `_.replace(c, _)` on a `str` or a `String`, where `c` is a `char`
+/// literal or variable
+fn replace_args<'e>(cx: &LateContext<'_, '_>, expr: &'e hir::Expr) -> Option<&'e [hir::Expr]> {
+    if_chain! {
+        if let hir::ExprKind::MethodCall(ref path, _, ref args) = expr.node;
+        if path.ident.name == "replace" && args.len() == 3;
+        if is_str_or_string(cx, &args[0]);
+        if cx.tables.expr_ty(&args[1]).sty == ty::Char;
+        if match args[1].node {
+            hir::ExprKind::Lit(_) | hir::ExprKind::Path(_) => true,
+            _ => false,
+        };
+        then {
+            return Some(args);
+        }
+    }
+    None
+}
+
+fn is_str_or_string(cx: &LateContext<'_, '_>, expr: &hir::Expr) -> bool {
+    let ty = walk_ptrs_ty(cx.tables.expr_ty(expr));
+    ty.sty == ty::Str || match_type(cx, ty, &paths::STRING)
+}
+
+/// Checks whether `expr` is a path to the item with the path `path`
+fn is_path_to_def(cx: &LateContext<'_, '_>, expr: &hir::Expr, path: &[&str]) -> bool {
+    if let hir::ExprKind::Path(ref qpath) = expr.node {
+        if let Some(def_id) = opt_def_id(cx.tables.qpath_def(qpath, expr.hir_id)) {
+            return match_def_path(cx.tcx, def_id, path);
+        }
+    }
+    false
+}
+
+/// lint `_.seek(SeekFrom::Current(0))`
+fn lint_seek_from_current(cx: &LateContext<'_, '_>, expr: &hir::Expr, seek_args: &[hir::Expr]) {
+    if seek_args.len() == 2
+        && is_seek_from_zero(cx, &seek_args[1], &paths::IO_SEEK_FROM_CURRENT)
+        && match_trait_method(cx, expr, &paths::IO_SEEK)
+    {
+        span_lint_and_then(
+            cx,
+            SEEK_FROM_CURRENT,
+            expr.span,
+            "using `SeekFrom::Current` to start from current position",
+            |db| {
+                db.span_suggestion_with_applicability(
+                    expr.span,
+                    "use `stream_position` instead",
+                    format!("{}.stream_position()", snippet(cx, seek_args[0].span, "..")),
+                    Applicability::MachineApplicable,
+                );
+            },
+        );
+    }
+}
+
+/// lint `_.seek(SeekFrom::Start(0))` whose result is unused
+fn lint_seek_to_start_instead_of_rewind(cx: &LateContext<'_, '_>, expr: &hir::Expr, seek_args: &[hir::Expr]) {
+    // `rewind` doesn't return the new position
+    if seek_args.len() == 2
+        && is_seek_from_zero(cx, &seek_args[1], &paths::IO_SEEK_FROM_START)
+        && match_trait_method(cx, expr, &paths::IO_SEEK)
+        && is_position_unused(cx, expr)
+    {
+        span_lint_and_then(
+            cx,
+            SEEK_TO_START_INSTEAD_OF_REWIND,
+            expr.span,
+            "used `seek` to go to the start of the stream",
+            |db| {
+                db.span_suggestion_with_applicability(
+                    expr.span,
+                    "replace with",
+                    format!("{}.rewind()", snippet(cx, seek_args[0].span, "..")),
+                    Applicability::MachineApplicable,
+                );
+            },
+        );
+    }
+}
+
+/// Checks for `SeekFrom::Current(0)` or `SeekFrom::Start(0)`, given the path of the variant
+fn is_seek_from_zero(cx: &LateContext<'_, '_>, expr: &hir::Expr, variant: &[&str]) -> bool {
+    if_chain! {
+        if let hir::ExprKind::Call(ref func, ref args) = expr.node;
+        if args.len() == 1 && is_path_to_def(cx, func, variant);
+        then {
+            return match constant(cx, cx.tables, &args[0]) {
+                Some((Constant::Int(0), _)) => true,
+                _ => false,
+            };
+        }
+    }
+    false
+}
+
+/// Checks whether the position returned by a call to `seek` is ignored, going through `?`,
+/// `unwrap()` and `expect(_)`
+fn is_position_unused(cx: &LateContext<'_, '_>, expr: &hir::Expr) -> bool {
+    let mut expr = expr;
+    while let Some(parent) = get_parent_expr(cx, expr) {
+        match parent.node {
+            hir::ExprKind::MethodCall(ref path, _, ref args)
+                if args[0].id == expr.id && (path.ident.name == "unwrap" || path.ident.name == "expect") => {},
+            hir::ExprKind::Call(ref func, _) if match_qpath_expr(func, &paths::TRY_INTO_RESULT) => {},
+            hir::ExprKind::Match(ref scrutinee, _, hir::MatchSource::TryDesugar) if scrutinee.id == expr.id => {},
+            _ => return false,
+        }
+        expr = parent;
+    }
+    match cx.tcx.hir.get(cx.tcx.hir.get_parent_node(expr.id)) {
+        hir::Node::Stmt(stmt) => matches!(stmt.node, hir::StmtKind::Semi(..)),
+        _ => false,
+    }
+}
+
+fn match_qpath_expr(expr: &hir::Expr, path: &[&str]) -> bool {
+    if let hir::ExprKind::Path(ref qpath) = expr.node {
+        match_qpath(qpath, path)
+    } else {
+        false
+    }
+}
+
+/// lint use of `FileType::is_file()` guarding code that opens or reads a path
+fn lint_filetype_is_file(cx: &LateContext<'_, '_>, expr: &hir::Expr, is_file_args: &[hir::Expr]) {
+    let ty = cx.tables.expr_ty(&is_file_args[0]);
+    if !match_type(cx, ty, &paths::FILE_TYPE) {
+        return;
+    }
+
+    // `!ft.is_file()` reads the path in the `else` branch
+    let (cond, negated) = match get_parent_expr(cx, expr) {
+        Some(parent) if matches!(parent.node, hir::ExprKind::Unary(hir::UnNot, _)) => (parent, true),
+        _ => (expr, false),
+    };
+    if_chain! {
+        if let Some(if_expr) = get_parent_expr(cx, cond);
+        if let hir::ExprKind::If(ref check, ref then, ref els) = if_expr.node;
+        if check.id == cond.id;
+        let branch = if negated { els.as_ref().map(|els| &**els) } else { Some(&**then) };
+        if let Some(branch) = branch;
+        if reads_path(cx, branch);
+        then {
+            let recv = snippet(cx, is_file_args[0].span, "..");
+            let (help, sugg) = if negated {
+                ("use `FileType::is_dir()` instead", format!("{}.is_dir()", recv))
+            } else {
+                ("use `!FileType::is_dir()` instead", format!("!{}.is_dir()", recv))
+            };
+            span_lint_and_then(
+                cx,
+                FILETYPE_IS_FILE,
+                cond.span,
+                "`FileType::is_file()` only covers regular files",
+                |db| {
+                    db.span_suggestion_with_applicability(cond.span, help, sugg, Applicability::MaybeIncorrect);
+                },
+            );
+        }
+    }
+}
+
+/// Checks if an expression opens or reads a file
+fn reads_path(cx: &LateContext<'_, '_>, expr: &hir::Expr) -> bool {
+    let mut visitor = ReadsPathVisitor { cx, found: false };
+    visitor.visit_expr(expr);
+    visitor.found
+}
+
+struct ReadsPathVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    found: bool,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for ReadsPathVisitor<'a, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx hir::Expr) {
+        if self.found {
+            return;
+        }
+        match expr.node {
+            hir::ExprKind::Call(ref func, _) => {
+                if let hir::ExprKind::Path(ref path) = func.node {
+                    if let Some(def_id) = opt_def_id(self.cx.tables.qpath_def(path, func.hir_id)) {
+                        self.found = [&paths::FILE_OPEN[..], &paths::FS_READ, &paths::FS_READ_TO_STRING]
+                            .iter()
+                            .any(|path| match_def_path(self.cx.tcx, def_id, path));
+                    }
+                }
+            },
+            hir::ExprKind::MethodCall(ref method, _, ref args) => {
+                self.found = method.ident.name == "open"
+                    && match_type(self.cx, walk_ptrs_ty(self.cx.tables.expr_ty(&args[0])), &paths::OPEN_OPTIONS);
+            },
+            _ => {},
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::None
+    }
+}
+
+/// lint use of `.to_digit(radix).is_some()` for `char`s
+fn lint_to_digit_is_some(cx: &LateContext<'_, '_>, expr: &hir::Expr, to_digit_args: &[hir::Expr]) {
+    if cx.tables.expr_ty_adjusted(&to_digit_args[0]).sty != ty::Char {
+        return;
+    }
+    let sugg = format!(
+        "{}.is_digit({})",
+        snippet(cx, to_digit_args[0].span, "_"),
+        snippet(cx, to_digit_args[1].span, "_")
+    );
+    span_lint_and_then(cx, TO_DIGIT_IS_SOME, expr.span, "use of `.to_digit(..).is_some()`", |db| {
+        db.span_suggestion_with_applicability(expr.span, "try this", sugg, Applicability::MachineApplicable);
+    });
+}
+
+/// lint pointer arithmetic on raw pointers to zero-sized types
+fn check_pointer_offset(cx: &LateContext<'_, '_>, expr: &hir::Expr, args: &[hir::Expr]) {
+    if_chain! {
+        if args.len() == 2;
+        if let ty::RawPtr(ty::TypeAndMut { ref ty, .. }) = cx.tables.expr_ty(&args[0]).sty;
+        if let Ok(layout) = cx.layout_of(ty);
+        if layout.size.bytes() == 0;
+        then {
+            span_lint(cx, ZST_OFFSET, expr.span, "offset calculation on zero-sized value");
+        }
+    }
+}
+
+/// Checks whether `range` is `..`, `0..` or `..recv.len()`, draining the whole `recv`
+fn is_full_range(cx: &LateContext<'_, '_>, recv: &hir::Expr, range: &hir::Expr) -> bool {
+    let range = match higher::range(cx, range) {
+        Some(range) => range,
+        None => return false,
+    };
+    let full_start = range.start.map_or(true, |start| match constant(cx, cx.tables, start) {
+        Some((Constant::Int(0), _)) => true,
+        _ => false,
+    });
+    let full_end = match (range.end, range.limits) {
+        (None, _) => true,
+        (Some(end), ast::RangeLimits::HalfOpen) => match end.node {
+            hir::ExprKind::MethodCall(ref path, _, ref args) => {
+                path.ident.name == "len" && args.len() == 1 && SpanlessEq::new(cx).eq_expr(&args[0], recv)
+            },
+            _ => false,
+        },
+        _ => false,
+    };
+    full_start && full_end
+}
+
+/// lint `.drain(..).collect()` into the drained collection type
+fn lint_drain_collect(cx: &LateContext<'_, '_>, expr: &hir::Expr, drain_args: &[hir::Expr]) {
+    let recv = &drain_args[0];
+    let (recv_ty, ref_depth) = walk_ptrs_ty_depth(cx.tables.expr_ty(recv));
+    let name = if match_type(cx, recv_ty, &paths::VEC) {
+        "Vec"
+    } else if match_type(cx, recv_ty, &paths::VEC_DEQUE) {
+        "VecDeque"
+    } else if match_type(cx, recv_ty, &paths::STRING) {
+        "String"
+    } else {
+        return;
+    };
+    if drain_args.len() != 2
+        || !is_full_range(cx, recv, &drain_args[1])
+        || !same_tys(cx, cx.tables.expr_ty(expr), recv_ty)
+    {
+        return;
+    }
+
+    let recv_snip = snippet(cx, recv.span, "..");
+    let sugg = match ref_depth {
+        0 => format!("std::mem::take(&mut {})", recv_snip),
+        1 => format!("std::mem::take({})", recv_snip),
+        _ => return,
+    };
+    span_lint_and_sugg(
+        cx,
+        DRAIN_COLLECT,
+        expr.span,
+        &format!("you seem to be trying to move all elements into a new `{}`", name),
+        "consider using `mem::take`",
+        sugg,
+    );
+}
+
+/// lint `.drain(..)` on a local collection that isn't used afterwards
+fn lint_iter_with_drain<'a, 'tcx>(
+    cx: &LateContext<'a, 'tcx>,
+    expr: &'tcx hir::Expr,
+    method_span: Span,
+    drain_args: &'tcx [hir::Expr],
+) {
+    let recv = &drain_args[0];
+    let recv_ty = cx.tables.expr_ty(recv);
+    if !match_type(cx, recv_ty, &paths::VEC) && !match_type(cx, recv_ty, &paths::VEC_DEQUE) {
+        return;
+    }
+    if !is_full_range(cx, recv, &drain_args[1]) {
+        return;
+    }
+    // the drained elements must be iterated over, with an iterator method or a `for` loop
+    let parent = match get_parent_expr(cx, expr) {
+        Some(parent) => parent,
+        None => return,
+    };
+    match parent.node {
+        hir::ExprKind::MethodCall(ref path, ..) => {
+            // `.drain(..).collect()` into the same type is linted by `DRAIN_COLLECT`
+            if path.ident.name == "collect" && same_tys(cx, cx.tables.expr_ty(parent), recv_ty) {
+                return;
+            }
+        },
+        hir::ExprKind::Call(ref func, _) => {
+            let def_id = match func.node {
+                hir::ExprKind::Path(ref qpath) => opt_def_id(cx.tables.qpath_def(qpath, func.hir_id)),
+                _ => None,
+            };
+            if !def_id.map_or(false, |def_id| match_def_path(cx.tcx, def_id, &paths::INTO_ITER)) {
+                return;
+            }
+        },
+        _ => return,
+    }
+    let local_id = match recv.node {
+        hir::ExprKind::Path(hir::QPath::Resolved(None, ref path)) => match path.def {
+            Def::Local(local_id) => local_id,
+            _ => return,
+        },
+        _ => return,
+    };
+    if let Some(block) = get_enclosing_block(cx, local_id) {
+        if is_in_loop_or_closure(cx, expr.id, block.id) || is_used_after(cx, block, local_id, expr.span) {
+            return;
+        }
+        let span = method_span.with_hi(expr.span.hi());
+        span_lint_and_sugg(
+            cx,
+            ITER_WITH_DRAIN,
+            span,
+            "`drain(..)` used on a collection that isn't used afterwards",
+            "try this",
+            "into_iter()".to_string(),
+        );
+    }
+}
+
+/// Checks whether the node `id` may be evaluated several times before leaving the block `scope`
+fn is_in_loop_or_closure(cx: &LateContext<'_, '_>, mut id: ast::NodeId, scope: ast::NodeId) -> bool {
+    loop {
+        let parent = cx.tcx.hir.get_parent_node(id);
+        if parent == id || parent == scope {
+            return false;
+        }
+        match cx.tcx.hir.find(parent) {
+            Some(hir::Node::Expr(expr)) => match expr.node {
+                hir::ExprKind::Loop(..) | hir::ExprKind::While(..) | hir::ExprKind::Closure(..) => return true,
+                _ => (),
+            },
+            Some(hir::Node::Item(_)) | Some(hir::Node::ImplItem(_)) | Some(hir::Node::TraitItem(_)) | None => {
+                return false
+            },
+            _ => (),
+        }
+        id = parent;
+    }
+}
+
+fn is_used_after<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, block: &'tcx hir::Block, id: ast::NodeId, span: Span) -> bool {
+    let mut visitor = UsedAfterVisitor {
+        cx,
+        id,
+        span,
+        used: false,
+    };
+    visitor.visit_block(block);
+    visitor.used
+}
+
+struct UsedAfterVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    id: ast::NodeId,
+    span: Span,
+    used: bool,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for UsedAfterVisitor<'a, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx hir::Expr) {
+        if self.used {
+            return;
+        }
+        if let hir::ExprKind::Path(hir::QPath::Resolved(None, ref path)) = expr.node {
+            if path.def == Def::Local(self.id) && expr.span.lo() >= self.span.hi() {
+                self.used = true;
+                return;
+            }
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::OnlyBodies(&self.cx.tcx.hir)
+    }
+}
+
+/// The functions `Option::as_deref` replaces in `_.as_ref().map(_)`
+const DEREF_ALIASES: [&[&str]; 6] = [
+    &paths::DEREF_TRAIT_METHOD,
+    &paths::STRING_AS_STR,
+    &paths::VEC_AS_SLICE,
+    &paths::OS_STRING_AS_OS_STR,
+    &paths::PATH_BUF_AS_PATH,
+    &paths::CSTRING_AS_C_STR,
+];
+
+/// The functions `Option::as_deref_mut` replaces in `_.as_mut().map(_)`
+const DEREF_MUT_ALIASES: [&[&str]; 3] = [
+    &paths::DEREF_MUT_TRAIT_METHOD,
+    &paths::STRING_AS_MUT_STR,
+    &paths::VEC_AS_MUT_SLICE,
+];
+
+/// lint `_.as_ref().map(Deref::deref)` and `_.as_mut().map(DerefMut::deref_mut)` on an `Option`
+fn lint_option_as_ref_deref<'a, 'tcx>(
+    cx: &LateContext<'a, 'tcx>,
+    expr: &hir::Expr,
+    as_ref_args: &[hir::Expr],
+    map_args: &[hir::Expr],
+    is_mut: bool,
+) {
+    let recv = &as_ref_args[0];
+    let option_ty = walk_ptrs_ty(cx.tables.expr_ty(recv));
+    let inner_ty = match option_ty.sty {
+        ty::Adt(_, substs) if match_type(cx, option_ty, &paths::OPTION) => substs.type_at(0),
+        _ => return,
+    };
+    let target_ty = match deref_target(cx, inner_ty) {
+        Some(target_ty) => target_ty,
+        None => return,
+    };
+
+    if_chain! {
+        // the `map` must give references to the type `as_deref` would dereference to
+        if let ty::Adt(_, substs) = cx.tables.expr_ty(expr).sty;
+        if let ty::Ref(_, mapped_ty, mutbl) = substs.type_at(0).sty;
+        if (mutbl == hir::MutMutable) == is_mut;
+        if same_tys(cx, mapped_ty, target_ty);
+        if map_args.len() == 2 && is_deref_fn(cx, &map_args[1], is_mut);
+        then {
+            let (method, replacement) = if is_mut {
+                ("as_mut", "as_deref_mut")
+            } else {
+                ("as_ref", "as_deref")
+            };
+            span_lint_and_sugg(
+                cx,
+                OPTION_AS_REF_DEREF,
+                expr.span,
+                &format!(
+                    "called `.{}().map(..)` on an `Option` value, which can be done more directly by calling `.{}()`",
+                    method, replacement
+                ),
+                "try using",
+                format!("{}.{}()", snippet(cx, recv.span, ".."), replacement),
+            );
+        }
+    }
+}
+
+/// Checks for a path to one of the `DEREF_ALIASES`, or `DEREF_MUT_ALIASES` if `is_mut`, a closure
+/// calling one of them on its argument, or a closure reborrowing its argument, e.g. `|x| &**x`
+fn is_deref_fn(cx: &LateContext<'_, '_>, expr: &hir::Expr, is_mut: bool) -> bool {
+    let aliases: &[&[&str]] = if is_mut { &DEREF_MUT_ALIASES } else { &DEREF_ALIASES };
+    let is_alias = |def_id| aliases.iter().any(|path| match_def_path(cx.tcx, def_id, path));
+
+    if let hir::ExprKind::Path(ref qpath) = expr.node {
+        return opt_def_id(cx.tables.qpath_def(qpath, expr.hir_id)).map_or(false, is_alias);
+    }
+    let (arg, body) = match closure_with_binding(cx, expr) {
+        Some(closure) => closure,
+        None => return false,
+    };
+    match body.node {
+        hir::ExprKind::MethodCall(_, _, ref args) => {
+            args.len() == 1
+                && match_var(&args[0], arg.name)
+                && cx
+                    .tables
+                    .type_dependent_defs()
+                    .get(body.hir_id)
+                    .map_or(false, |def| is_alias(def.def_id()))
+        },
+        hir::ExprKind::AddrOf(mutbl, ref inner) => {
+            if_chain! {
+                if (mutbl == hir::MutMutable) == is_mut;
+                if let hir::ExprKind::Unary(hir::UnDeref, ref inner) = inner.node;
+                if let hir::ExprKind::Unary(hir::UnDeref, ref inner) = inner.node;
+                then {
+                    return match_var(inner, arg.name);
+                }
+            }
+            false
+        },
+        _ => false,
+    }
+}
+
+/// lint `_.as_deref()` on an `Option<&T>`, which returns it unchanged
+fn lint_needless_option_as_deref(cx: &LateContext<'_, '_>, expr: &hir::Expr, as_deref_args: &[hir::Expr]) {
+    let recv = &as_deref_args[0];
+    let recv_ty = cx.tables.expr_ty(recv);
+    if match_type(cx, recv_ty, &paths::OPTION) && same_tys(cx, recv_ty, cx.tables.expr_ty(expr)) {
+        span_lint_and_sugg(
+            cx,
+            NEEDLESS_OPTION_AS_DEREF,
+            expr.span,
+            "derefed type is same as origin",
+            "try this",
+            snippet(cx, recv.span, "..").into_owned(),
+        );
+    }
+}
+
 /// Given a `Result<T, E>` type, return its error type (`E`).
 fn get_error_type<'a>(cx: &LateContext<'_, '_>, ty: Ty<'a>) -> Option<Ty<'a>> {
     if let ty::Adt(_, substs) = ty.sty {
-        if match_type(cx, ty, &paths::RESULT) {
+        if is_type_diag_item(cx, ty, DiagItem::Result) {
             substs.types().nth(1)
         } else {
             None
@@ -1,41 +1,77 @@
 use crate::consts::{constant_simple, Constant};
-use crate::utils::{match_def_path, opt_def_id, paths, span_lint};
+use crate::utils::{is_lint_allowed, match_def_path, match_trait_method, multispan_sugg_with_applicability, opt_def_id,
+                   paths, snippet, span_lint, span_lint_and_then};
 use rustc::hir::*;
 use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::ty;
 use rustc::{declare_lint, lint_array};
+use rustc_errors::Applicability;
 use std::cmp::Ordering;
 
-/// **What it does:** Checks for expressions where `std::cmp::min` and `max` are
-/// used to clamp values, but switched so that the result is constant.
-///
-/// **Why is this bad?** This is in all probability not the intended outcome. At
-/// the least it hurts readability of the code.
-///
-/// **Known problems:** None
-///
-/// **Example:**
-/// ```rust
-/// min(0, max(100, x))
-/// ```
-/// It will always be equal to `0`. Probably the author meant to clamp the value
-/// between 0 and 100, but has erroneously swapped `min` and `max`.
 declare_clippy_lint! {
+    /// **What it does:** Checks for expressions where `std::cmp::min` and `max` are
+    /// used to clamp values, but switched so that the result is constant.
+    ///
+    /// **Why is this bad?** This is in all probability not the intended outcome. At
+    /// the least it hurts readability of the code.
+    ///
+    /// **Known problems:** None
+    ///
+    /// **Example:**
+    /// ```rust
+    /// min(0, max(100, x))
+    /// ```
+    /// It will always be equal to `0`. Probably the author meant to clamp the value
+    /// between 0 and 100, but has erroneously swapped `min` and `max`.
+    #[clippy::version = "pre 0.0.213"]
     pub MIN_MAX,
     correctness,
     "`min(_, max(_, _))` (or vice versa) with bounds clamping the result to a constant"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** Checks for chains of `min`, `max` and `clamp` method
+    /// calls with constant bounds whose result is always one of the bounds, like
+    /// `x.max(5).min(3)`.
+    ///
+    /// **Why is this bad?** The bounds are probably swapped: the chain ignores
+    /// `x` and always returns `3`.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let x = 4;
+    /// let clamped = x.max(5).min(3);
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let x = 4;
+    /// let clamped = x.max(3).min(5);
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub CONSTANT_MIN_MAX_CHAIN,
+    style,
+    "chains of `min`, `max` or `clamp` calls with bounds clamping the result to a constant"
+}
+
 #[allow(missing_copy_implementations)]
 pub struct MinMaxPass;
 
 impl LintPass for MinMaxPass {
     fn get_lints(&self) -> LintArray {
-        lint_array!(MIN_MAX)
+        lint_array!(MIN_MAX, CONSTANT_MIN_MAX_CHAIN)
     }
 }
 
 impl<'a, 'tcx> LateLintPass<'a, 'tcx> for MinMaxPass {
     fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        check_method_chain(cx, expr);
+        // only calls can be `min(..)` or `max(..)`, the level of the lint is looked up for them only
+        match expr.node {
+            ExprKind::Call(..) if !is_lint_allowed(cx, MIN_MAX, expr.hir_id) => (),
+            _ => return,
+        }
         if let Some((outer_max, outer_c, oe)) = min_max(cx, expr) {
             if let Some((inner_max, inner_c, ie)) = min_max(cx, oe) {
                 if outer_max == inner_max {
@@ -103,3 +139,88 @@ fn fetch_const<'a>(cx: &LateContext<'_, '_>, args: &'a [Expr], m: MinMax) -> Opt
         None
     }
 }
+
+/// A `min`, `max` or `clamp` method call with constant bounds, which are given with their
+/// expressions
+enum MinMaxCall<'a> {
+    Min((Constant, &'a Expr)),
+    Max((Constant, &'a Expr)),
+    Clamp((Constant, &'a Expr), (Constant, &'a Expr)),
+}
+
+fn check_method_chain(cx: &LateContext<'_, '_>, expr: &Expr) {
+    let (outer, receiver) = match min_max_call(cx, expr) {
+        Some(call) => call,
+        None => return,
+    };
+    let (inner, value) = match min_max_call(cx, receiver) {
+        Some(call) => call,
+        None => return,
+    };
+    if constant_simple(cx, cx.tables, value).is_some() {
+        return;
+    }
+    let sty = &cx.tables.expr_ty(expr).sty;
+    let at_most = |a: &Constant, b: &Constant| match Constant::partial_cmp(cx.tcx, sty, a, b) {
+        Some(Ordering::Less) | Some(Ordering::Equal) => true,
+        _ => false,
+    };
+    // `x.max(l).min(c)` is always `c` if `c <= l`, and the other way around for
+    // `x.min(h).max(c)`, the bounds of the `min` and `max` calls are probably swapped
+    let (result, swapped) = match (&outer, &inner) {
+        (MinMaxCall::Min(c), MinMaxCall::Max(l)) if at_most(&c.0, &l.0) => (c.1, Some((c.1, l.1))),
+        (MinMaxCall::Max(c), MinMaxCall::Min(h)) if at_most(&h.0, &c.0) => (c.1, Some((c.1, h.1))),
+        (MinMaxCall::Min(c), MinMaxCall::Clamp(l, _)) if at_most(&c.0, &l.0) => (c.1, None),
+        (MinMaxCall::Max(c), MinMaxCall::Clamp(_, h)) if at_most(&h.0, &c.0) => (c.1, None),
+        (MinMaxCall::Clamp(l, _), MinMaxCall::Min(h)) if at_most(&h.0, &l.0) => (l.1, None),
+        (MinMaxCall::Clamp(_, h), MinMaxCall::Max(l)) if at_most(&h.0, &l.0) => (h.1, None),
+        _ => return,
+    };
+    span_lint_and_then(
+        cx,
+        CONSTANT_MIN_MAX_CHAIN,
+        expr.span,
+        &format!("this chain always results in `{}`", snippet(cx, result.span, "..")),
+        |db| {
+            if let Some((outer_bound, inner_bound)) = swapped {
+                let outer_snip = snippet(cx, outer_bound.span, "..");
+                let inner_snip = snippet(cx, inner_bound.span, "..");
+                if outer_snip != inner_snip {
+                    multispan_sugg_with_applicability(
+                        db,
+                        "if the bounds are swapped, try".to_owned(),
+                        Applicability::MaybeIncorrect,
+                        vec![
+                            (inner_bound.span, outer_snip.to_string()),
+                            (outer_bound.span, inner_snip.to_string()),
+                        ],
+                    );
+                }
+            }
+        },
+    );
+}
+
+/// Returns the call and its receiver if `expr` is a `min`, `max` or `clamp` call of `Ord` or
+/// of a float with constant bounds
+fn min_max_call<'a>(cx: &LateContext<'_, '_>, expr: &'a Expr) -> Option<(MinMaxCall<'a>, &'a Expr)> {
+    if let ExprKind::MethodCall(ref path, _, ref args) = expr.node {
+        let is_float = match cx.tables.expr_ty(&args[0]).sty {
+            ty::Float(_) => true,
+            _ => false,
+        };
+        if !is_float && !match_trait_method(cx, expr, &paths::ORD) {
+            return None;
+        }
+        let bound = |arg: &'a Expr| constant_simple(cx, cx.tables, arg).map(|c| (c, arg));
+        let call = match (&*path.ident.name.as_str(), &**args) {
+            ("min", [_, bound_arg]) => MinMaxCall::Min(bound(bound_arg)?),
+            ("max", [_, bound_arg]) => MinMaxCall::Max(bound(bound_arg)?),
+            ("clamp", [_, low, high]) => MinMaxCall::Clamp(bound(low)?, bound(high)?),
+            _ => return None,
+        };
+        Some((call, &args[0]))
+    } else {
+        None
+    }
+}
@@ -0,0 +1,130 @@
+use crate::utils::span_help_and_lint;
+use rustc::lint::{EarlyContext, EarlyLintPass, LintArray, LintPass};
+use rustc::{declare_lint, lint_array};
+use syntax::ast::*;
+use syntax::parse::{parser, token};
+use syntax::tokenstream::TokenStream;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `assert!`, `assert_eq!` and `assert_ne!`, and
+    /// their `debug_` versions, without a custom panic message.
+    ///
+    /// The `#[test]` functions and `#[cfg(test)]` items are not linted, unless the
+    /// `missing-assert-message-in-tests` configuration value is `true`.
+    ///
+    /// **Why is this bad?** Without a message, a failing assertion only shows the
+    /// asserted expression: the message can explain what went wrong and help to
+    /// investigate it.
+    ///
+    /// **Known problems:** The asserts in macros are not linted.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let foo = 0;
+    /// assert!(foo == 0);
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let foo = 0;
+    /// assert!(foo == 0, "the count of foos should be reset");
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub MISSING_ASSERT_MESSAGE,
+    restriction,
+    "checks assertions without a custom panic message"
+}
+
+/// The assertion macros, with the number of their arguments before the message
+const ASSERT_MACROS: [(&str, usize); 6] = [
+    ("assert", 1),
+    ("assert_eq", 2),
+    ("assert_ne", 2),
+    ("debug_assert", 1),
+    ("debug_assert_eq", 2),
+    ("debug_assert_ne", 2),
+];
+
+pub struct MissingAssertMessage {
+    check_in_tests: bool,
+    /// The number of enclosing `#[test]` or `#[cfg(test)]` items
+    test_depth: usize,
+}
+
+impl MissingAssertMessage {
+    pub fn new(check_in_tests: bool) -> Self {
+        Self {
+            check_in_tests,
+            test_depth: 0,
+        }
+    }
+}
+
+impl LintPass for MissingAssertMessage {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(MISSING_ASSERT_MESSAGE)
+    }
+}
+
+impl EarlyLintPass for MissingAssertMessage {
+    fn check_item(&mut self, _: &EarlyContext<'_>, item: &Item) {
+        if is_test_item(item) {
+            self.test_depth += 1;
+        }
+    }
+
+    fn check_item_post(&mut self, _: &EarlyContext<'_>, item: &Item) {
+        if is_test_item(item) {
+            self.test_depth -= 1;
+        }
+    }
+
+    fn check_mac(&mut self, cx: &EarlyContext<'_>, mac: &Mac) {
+        if self.test_depth > 0 && !self.check_in_tests {
+            return;
+        }
+        let required = match ASSERT_MACROS.iter().find(|&&(name, _)| mac.node.path == name) {
+            Some(&(_, required)) => required,
+            None => return,
+        };
+        if let Some(count) = count_args(cx, mac) {
+            if count <= required {
+                span_help_and_lint(
+                    cx,
+                    MISSING_ASSERT_MESSAGE,
+                    mac.span,
+                    "assert without any message",
+                    "consider describing why the failing assert is problematic",
+                );
+            }
+        }
+    }
+}
+
+fn is_test_item(item: &Item) -> bool {
+    item.attrs.iter().any(|attr| {
+        attr.check_name("test")
+            || (attr.check_name("cfg")
+                && attr
+                    .meta_item_list()
+                    .map_or(false, |items| items.iter().any(|item| item.check_name("test"))))
+    })
+}
+
+/// Returns the number of comma separated expressions given to the macro, `None` if they can't be
+/// parsed
+fn count_args(cx: &EarlyContext<'_>, mac: &Mac) -> Option<usize> {
+    let tts = TokenStream::from(mac.node.tts.clone());
+    let mut parser = parser::Parser::new(&cx.sess.parse_sess, tts, None, false, false);
+    let mut count = 0;
+    while parser.token != token::Eof {
+        if parser.parse_expr().map_err(|mut err| err.cancel()).is_err() {
+            return None;
+        }
+        count += 1;
+        // the last argument may be followed by a comma
+        if !parser.eat(&token::Comma) && parser.token != token::Eof {
+            return None;
+        }
+    }
+    Some(count)
+}
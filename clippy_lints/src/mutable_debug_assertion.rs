@@ -0,0 +1,108 @@
+use crate::utils::{in_macro, is_direct_expn_of, span_lint};
+use rustc::hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::ty;
+use rustc::{declare_lint, lint_array};
+use syntax::source_map::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for function/method calls with a mutable
+    /// parameter in `debug_assert!`, `debug_assert_eq!` and `debug_assert_ne!` macros.
+    ///
+    /// **Why is this bad?** In release builds `debug_assert!` macros are optimized out by the
+    /// compiler, so mutating something inside of them results in different behaviour between
+    /// a release and a debug build.
+    ///
+    /// **Known problems:** Functions mutating their arguments through interior
+    /// mutability, e.g. with a `RefCell`, are not detected.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// debug_assert_eq!(vec![3].pop(), Some(3));
+    /// // or
+    /// fn take_a_mut_parameter(_: &mut u32) -> bool { unimplemented!() }
+    /// debug_assert!(take_a_mut_parameter(&mut 5));
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub DEBUG_ASSERT_WITH_MUT_CALL,
+    nursery,
+    "mutable arguments in `debug_assert{,_ne,_eq}!`"
+}
+
+const DEBUG_MACRO_NAMES: [&str; 3] = ["debug_assert", "debug_assert_eq", "debug_assert_ne"];
+
+#[derive(Copy, Clone)]
+pub struct DebugAssertWithMutCall;
+
+impl LintPass for DebugAssertWithMutCall {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(DEBUG_ASSERT_WITH_MUT_CALL)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for DebugAssertWithMutCall {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, e: &'tcx Expr) {
+        // `debug_assert!(..)` expands to `if cfg!(debug_assertions) { assert!(..) }`
+        if let ExprKind::If(_, ref then, _) = e.node {
+            for &name in &DEBUG_MACRO_NAMES {
+                if is_direct_expn_of(e.span, name).is_some() {
+                    let mut visitor = MutArgVisitor { cx, found: None };
+                    visitor.visit_expr(then);
+                    if let Some(span) = visitor.found {
+                        span_lint(
+                            cx,
+                            DEBUG_ASSERT_WITH_MUT_CALL,
+                            span,
+                            &format!("do not call a function with mutable arguments inside of `{}!`", name),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Finds the first mutation in the arguments of the macro, ignoring the code it generates
+struct MutArgVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    found: Option<Span>,
+}
+
+impl<'a, 'tcx> MutArgVisitor<'a, 'tcx> {
+    fn is_mutation(&self, expr: &Expr) -> bool {
+        match expr.node {
+            ExprKind::AddrOf(MutMutable, _) | ExprKind::Assign(..) | ExprKind::AssignOp(..) => true,
+            ExprKind::MethodCall(..) => self
+                .cx
+                .tables
+                .type_dependent_defs()
+                .get(expr.hir_id)
+                .map_or(false, |def| {
+                    let sig = self.cx.tcx.fn_sig(def.def_id());
+                    match sig.skip_binder().inputs().first().map(|ty| &ty.sty) {
+                        Some(&ty::Ref(_, _, MutMutable)) => true,
+                        _ => false,
+                    }
+                }),
+            _ => false,
+        }
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for MutArgVisitor<'a, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        if self.found.is_some() {
+            return;
+        }
+        if !in_macro(expr.span) && self.is_mutation(expr) {
+            self.found = Some(expr.span);
+            return;
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::OnlyBodies(&self.cx.tcx.hir)
+    }
+}
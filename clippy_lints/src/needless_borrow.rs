@@ -10,30 +10,31 @@ use rustc::ty;
 use rustc::ty::adjustment::{Adjust, Adjustment};
 use crate::utils::{in_macro, snippet_opt, span_lint_and_then};
 
-/// **What it does:** Checks for address of operations (`&`) that are going to
-/// be dereferenced immediately by the compiler.
-///
-/// **Why is this bad?** Suggests that the receiver of the expression borrows
-/// the expression.
-///
-/// **Example:**
-/// ```rust
-/// let x: &i32 = &&&&&&5;
-/// ```
-///
-/// **Known problems:** This will cause false positives in code generated by `derive`.
-/// For instance in the following snippet:
-/// ```rust
-/// #[derive(Debug)]
-/// pub enum Error {
-///     Type(
-///         &'static str,
-///     ),
-/// }
-/// ```
-/// A warning will be emitted that `&'static str` should be replaced with `&'static str`,
-/// however there is nothing that can or should be done to fix this.
 declare_clippy_lint! {
+    /// **What it does:** Checks for address of operations (`&`) that are going to
+    /// be dereferenced immediately by the compiler.
+    ///
+    /// **Why is this bad?** Suggests that the receiver of the expression borrows
+    /// the expression.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let x: &i32 = &&&&&&5;
+    /// ```
+    ///
+    /// **Known problems:** This will cause false positives in code generated by `derive`.
+    /// For instance in the following snippet:
+    /// ```rust
+    /// #[derive(Debug)]
+    /// pub enum Error {
+    ///     Type(
+    ///         &'static str,
+    ///     ),
+    /// }
+    /// ```
+    /// A warning will be emitted that `&'static str` should be replaced with `&'static str`,
+    /// however there is nothing that can or should be done to fix this.
+    #[clippy::version = "0.0.66"]
     pub NEEDLESS_BORROW,
     nursery,
     "taking a reference that is going to be automatically dereferenced"
@@ -0,0 +1,137 @@
+use crate::utils::{in_macro, snippet_opt, span_help_and_lint, span_lint_and_sugg};
+use rustc::lint::{in_external_macro, EarlyContext, EarlyLintPass, LintArray, LintContext, LintPass};
+use rustc::{declare_lint, lint_array};
+use syntax::ast::*;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for empty `else` branches.
+    ///
+    /// **Why is this bad?** An empty `else` branch does nothing, it's usually left
+    /// over from a refactoring.
+    ///
+    /// **Known problems:** `else` branches containing a comment are not linted, the
+    /// comment may explain why there's nothing to do.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let x = true;
+    /// if x {
+    ///     println!("yes");
+    /// } else {
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let x = true;
+    /// if x {
+    ///     println!("yes");
+    /// }
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub NEEDLESS_ELSE,
+    style,
+    "empty `else` branch"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `if` expressions whose then-branch is empty and
+    /// whose condition has no side effects.
+    ///
+    /// **Why is this bad?** An empty `if` without an `else` does nothing, and one with
+    /// an `else` is clearer with the condition negated.
+    ///
+    /// **Known problems:** Overloaded operators in the condition are assumed to have
+    /// no side effects. Branches containing a comment are not linted.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let x = 1;
+    /// if x == 0 {
+    /// } else {
+    ///     println!("not zero");
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let x = 1;
+    /// if x != 0 {
+    ///     println!("not zero");
+    /// }
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub EMPTY_IF_BRANCHES,
+    style,
+    "`if` with an empty then-branch and a condition without side effects"
+}
+
+#[derive(Copy, Clone)]
+pub struct NeedlessElse;
+
+impl LintPass for NeedlessElse {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(NEEDLESS_ELSE, EMPTY_IF_BRANCHES)
+    }
+}
+
+impl EarlyLintPass for NeedlessElse {
+    fn check_expr(&mut self, cx: &EarlyContext<'_>, expr: &Expr) {
+        if in_external_macro(cx.sess(), expr.span) || in_macro(expr.span) {
+            return;
+        }
+        match expr.node {
+            ExprKind::If(ref cond, ref then, ref else_) => {
+                if is_empty_block(cx, then) && has_no_side_effects(cond) {
+                    let help = if else_.is_some() {
+                        "consider negating the condition and removing the empty branch"
+                    } else {
+                        "consider removing the `if` expression"
+                    };
+                    span_help_and_lint(cx, EMPTY_IF_BRANCHES, then.span, "this `if` branch is empty", help);
+                }
+                if let Some(ref else_) = *else_ {
+                    check_else(cx, then, else_);
+                }
+            },
+            ExprKind::IfLet(_, _, ref then, Some(ref else_)) => check_else(cx, then, else_),
+            _ => (),
+        }
+    }
+}
+
+fn check_else(cx: &EarlyContext<'_>, then: &Block, else_: &Expr) {
+    if let ExprKind::Block(ref block, None) = else_.node {
+        if is_empty_block(cx, block) && !in_macro(else_.span) {
+            span_lint_and_sugg(
+                cx,
+                NEEDLESS_ELSE,
+                else_.span.with_lo(then.span.hi()),
+                "this `else` branch is empty",
+                "you can remove it",
+                String::new(),
+            );
+        }
+    }
+}
+
+/// Checks for a block without statements nor comments
+fn is_empty_block(cx: &EarlyContext<'_>, block: &Block) -> bool {
+    block.stmts.is_empty()
+        && block.rules == BlockCheckMode::Default
+        && snippet_opt(cx, block.span).map_or(false, |snip| {
+            snip.trim_start_matches('{').trim_end_matches('}').trim().is_empty()
+        })
+}
+
+/// Checks for conditions made of paths, literals, fields and operators
+fn has_no_side_effects(expr: &Expr) -> bool {
+    match expr.node {
+        ExprKind::Lit(_) | ExprKind::Path(..) => true,
+        ExprKind::Binary(_, ref left, ref right) => has_no_side_effects(left) && has_no_side_effects(right),
+        ExprKind::Unary(_, ref inner)
+        | ExprKind::Paren(ref inner)
+        | ExprKind::Field(ref inner, _)
+        | ExprKind::AddrOf(_, ref inner)
+        | ExprKind::Cast(ref inner, _) => has_no_side_effects(inner),
+        _ => false,
+    }
+}
@@ -0,0 +1,252 @@
+use crate::utils::{in_macro, multispan_sugg_with_applicability, snippet, span_lint_and_then};
+use if_chain::if_chain;
+use rustc::hir::def::Def;
+use rustc::hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::{declare_lint, lint_array};
+use rustc_errors::Applicability;
+use syntax::ast::NodeId;
+use syntax::source_map::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for late initializations that can be replaced by a `let` statement
+    /// with an initializer.
+    ///
+    /// **Why is this bad?** Assigning in the `let` statement is less repetitive.
+    ///
+    /// **Known problems:** Moving the declaration changes the order in which the
+    /// bindings declared in between are dropped. An `if` directly following the
+    /// declaration is left to `useless_let_if_seq`.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let a;
+    /// a = 1;
+    ///
+    /// let b;
+    /// match 3 {
+    ///     0 => b = "zero",
+    ///     1 => b = "one",
+    ///     _ => b = "many",
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let a = 1;
+    ///
+    /// let b = match 3 {
+    ///     0 => "zero",
+    ///     1 => "one",
+    ///     _ => "many",
+    /// };
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub NEEDLESS_LATE_INIT,
+    style,
+    "late initializations that can be replaced by a `let` statement with an initializer"
+}
+
+#[derive(Copy, Clone)]
+pub struct NeedlessLateInit;
+
+impl LintPass for NeedlessLateInit {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(NEEDLESS_LATE_INIT)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for NeedlessLateInit {
+    fn check_block(&mut self, cx: &LateContext<'a, 'tcx>, block: &'tcx Block) {
+        for (i, stmt) in block.stmts.iter().enumerate() {
+            if_chain! {
+                if !in_macro(stmt.span);
+                if let StmtKind::Decl(ref decl, _) = stmt.node;
+                if let DeclKind::Local(ref local) = decl.node;
+                if local.init.is_none();
+                if let PatKind::Binding(_, id, ident, None) = local.pat.node;
+                // the first statement using the binding has to initialize it
+                if let Some((j, usage)) = block.stmts[i + 1..]
+                    .iter()
+                    .enumerate()
+                    .find(|(_, usage)| is_used_in_stmt(cx, id, usage));
+                then {
+                    let decl_sugg = match local.ty {
+                        Some(ref ty) => format!(
+                            "let {}: {} = ",
+                            snippet(cx, local.pat.span, ".."),
+                            snippet(cx, ty.span, "..")
+                        ),
+                        None => format!("let {} = ", snippet(cx, local.pat.span, "..")),
+                    };
+                    let (expr, needs_semi) = match usage.node {
+                        StmtKind::Semi(ref expr, _) => (&**expr, false),
+                        StmtKind::Expr(ref expr, _) => (&**expr, true),
+                        StmtKind::Decl(..) => continue,
+                    };
+                    if in_macro(expr.span) {
+                        continue;
+                    }
+
+                    let mut parts = vec![(stmt.span, String::new())];
+                    if let ExprKind::Assign(ref lhs, ref value) = expr.node {
+                        if !is_local(cx, id, lhs) || is_used(cx, id, value) {
+                            continue;
+                        }
+                        parts.push((expr.span, format!("{}{}", decl_sugg, snippet(cx, value.span, ".."))));
+                    } else {
+                        if is_let_if_seq(j, expr) {
+                            continue;
+                        }
+                        let assigns = match branch_assignments(cx, id, expr) {
+                            Some(assigns) => assigns,
+                            None => continue,
+                        };
+                        parts.push((expr.span.shrink_to_lo(), decl_sugg));
+                        parts.extend(
+                            assigns
+                                .into_iter()
+                                .map(|(span, value)| (span, snippet(cx, value.span, "..").into_owned())),
+                        );
+                        if needs_semi {
+                            parts.push((expr.span.shrink_to_hi(), ";".to_owned()));
+                        }
+                    }
+
+                    span_lint_and_then(cx, NEEDLESS_LATE_INIT, stmt.span, "unneeded late initialization", |db| {
+                        multispan_sugg_with_applicability(
+                            db,
+                            format!("declare `{}` here", ident.name),
+                            Applicability::MaybeIncorrect,
+                            parts,
+                        );
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// `let x; if c { x = 1; } else { x = 2; }` is linted by `useless_let_if_seq`
+fn is_let_if_seq(usage_index: usize, expr: &Expr) -> bool {
+    if usage_index != 0 {
+        return false;
+    }
+    match expr.node {
+        ExprKind::If(_, _, Some(ref els)) => match els.node {
+            ExprKind::Block(..) => true,
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Returns the assignments to the binding `id` ending every branch of an `if` or a `match`, with
+/// the code to replace by the assigned value and the value. The binding must not be used
+/// elsewhere in `expr`.
+fn branch_assignments<'a, 'tcx>(
+    cx: &'a LateContext<'a, 'tcx>,
+    id: NodeId,
+    expr: &'tcx Expr,
+) -> Option<Vec<(Span, &'tcx Expr)>> {
+    match expr.node {
+        ExprKind::Assign(ref lhs, ref value) if is_local(cx, id, lhs) && !is_used(cx, id, value) => {
+            Some(vec![(expr.span, &**value)])
+        },
+        ExprKind::Block(ref block, _) => {
+            let (assigns, stmts) = match block.expr {
+                Some(ref tail) => (branch_assignments(cx, id, tail)?, &block.stmts[..]),
+                None => {
+                    let (last, stmts) = block.stmts.split_last()?;
+                    let expr = match last.node {
+                        StmtKind::Semi(ref expr, _) => expr,
+                        _ => return None,
+                    };
+                    match expr.node {
+                        // the `;` is removed with the assignment
+                        ExprKind::Assign(ref lhs, ref value) if is_local(cx, id, lhs) && !is_used(cx, id, value) => {
+                            (vec![(last.span, &**value)], stmts)
+                        },
+                        _ => return None,
+                    }
+                },
+            };
+            if stmts.iter().any(|stmt| is_used_in_stmt(cx, id, stmt)) {
+                return None;
+            }
+            Some(assigns)
+        },
+        ExprKind::If(ref cond, ref then, Some(ref els)) => {
+            if is_used(cx, id, cond) {
+                return None;
+            }
+            let mut assigns = branch_assignments(cx, id, then)?;
+            assigns.extend(branch_assignments(cx, id, els)?);
+            Some(assigns)
+        },
+        ExprKind::Match(ref scrutinee, ref arms, MatchSource::Normal)
+        | ExprKind::Match(ref scrutinee, ref arms, MatchSource::IfLetDesugar { contains_else_clause: true }) => {
+            if is_used(cx, id, scrutinee) {
+                return None;
+            }
+            let mut assigns = Vec::new();
+            for arm in arms {
+                if let Some(ref guard) = arm.guard {
+                    if is_used(cx, id, guard) {
+                        return None;
+                    }
+                }
+                assigns.extend(branch_assignments(cx, id, &arm.body)?);
+            }
+            Some(assigns)
+        },
+        _ => None,
+    }
+}
+
+fn is_local(cx: &LateContext<'_, '_>, id: NodeId, expr: &Expr) -> bool {
+    if_chain! {
+        if let ExprKind::Path(ref qpath) = expr.node;
+        if let Def::Local(local_id) = cx.tables.qpath_def(qpath, expr.hir_id);
+        then {
+            return local_id == id;
+        }
+    }
+    false
+}
+
+fn is_used<'a, 'tcx>(cx: &'a LateContext<'a, 'tcx>, id: NodeId, expr: &'tcx Expr) -> bool {
+    let mut visitor = UsedVisitor { cx, id, used: false };
+    visitor.visit_expr(expr);
+    visitor.used
+}
+
+fn is_used_in_stmt<'a, 'tcx>(cx: &'a LateContext<'a, 'tcx>, id: NodeId, stmt: &'tcx Stmt) -> bool {
+    let mut visitor = UsedVisitor { cx, id, used: false };
+    visitor.visit_stmt(stmt);
+    visitor.used
+}
+
+struct UsedVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    id: NodeId,
+    used: bool,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for UsedVisitor<'a, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        if self.used {
+            return;
+        }
+        if is_local(self.cx, self.id, expr) {
+            self.used = true;
+            return;
+        }
+        walk_expr(self, expr);
+    }
+
+    // closures have to be visited too
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::OnlyBodies(&self.cx.tcx.hir)
+    }
+}
@@ -0,0 +1,102 @@
+use crate::utils::{in_macro, match_type, paths, snippet_opt, span_lint_and_then, walk_ptrs_ty};
+use if_chain::if_chain;
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::{declare_lint, lint_array};
+use rustc_errors::Applicability;
+use syntax::ast::LitKind;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for decimal literals passed as the mode of
+    /// `PermissionsExt::set_mode`, `OpenOptionsExt::mode` and `DirBuilderExt::mode`.
+    ///
+    /// **Why is this bad?** Unix permissions are written in octal: `set_mode(644)`
+    /// is probably meant to be `set_mode(0o644)`, it sets the permissions `0o1204`.
+    ///
+    /// **Known problems:** Modes written in decimal on purpose, like `420` for
+    /// `0o644`, are linted too.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// use std::fs::OpenOptions;
+    /// use std::os::unix::fs::OpenOptionsExt;
+    ///
+    /// let mut options = OpenOptions::new();
+    /// options.mode(644);
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// use std::fs::OpenOptions;
+    /// use std::os::unix::fs::OpenOptionsExt;
+    ///
+    /// let mut options = OpenOptions::new();
+    /// options.mode(0o644);
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub NON_OCTAL_UNIX_PERMISSIONS,
+    correctness,
+    "use of a decimal literal as unix permissions"
+}
+
+#[derive(Copy, Clone)]
+pub struct NonOctalUnixPermissions;
+
+impl LintPass for NonOctalUnixPermissions {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(NON_OCTAL_UNIX_PERMISSIONS)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for NonOctalUnixPermissions {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if_chain! {
+            if !in_macro(expr.span);
+            if let ExprKind::MethodCall(ref path, _, ref args) = expr.node;
+            if args.len() == 2;
+            let receiver_ty = walk_ptrs_ty(cx.tables.expr_ty(&args[0]));
+            if match &*path.ident.as_str() {
+                "set_mode" => match_type(cx, receiver_ty, &paths::PERMISSIONS),
+                "mode" => {
+                    match_type(cx, receiver_ty, &paths::OPEN_OPTIONS)
+                        || match_type(cx, receiver_ty, &paths::DIR_BUILDER)
+                },
+                _ => false,
+            };
+            if let ExprKind::Lit(ref lit) = args[1].node;
+            // the modes below 8 are the same in decimal and in octal
+            if let LitKind::Int(value, _) = lit.node;
+            if value >= 8;
+            if let Some(snip) = snippet_opt(cx, lit.span);
+            if is_decimal_octal(&snip);
+            then {
+                span_lint_and_then(
+                    cx,
+                    NON_OCTAL_UNIX_PERMISSIONS,
+                    lit.span,
+                    "using a non-octal value to set unix file permissions",
+                    |db| {
+                        db.span_suggestion_with_applicability(
+                            lit.span,
+                            "consider using an octal literal instead",
+                            format!("0o{}", snip),
+                            Applicability::MaybeIncorrect,
+                        );
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// Checks whether a literal is written in decimal with octal digits only, e.g. `644`
+fn is_decimal_octal(snip: &str) -> bool {
+    if snip.starts_with("0x") || snip.starts_with("0o") || snip.starts_with("0b") {
+        return false;
+    }
+    // the digits before the type suffix, if any
+    let digits = snip.split(|c: char| c.is_alphabetic()).next().unwrap_or("");
+    digits.chars().all(|c| match c {
+        '0'..='7' | '_' => true,
+        _ => false,
+    })
+}
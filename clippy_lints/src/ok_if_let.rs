@@ -4,31 +4,32 @@ use if_chain::if_chain;
 use rustc::hir::*;
 use crate::utils::{match_type, method_chain_args, paths, snippet, span_help_and_lint};
 
-/// **What it does:*** Checks for unnecessary `ok()` in if let.
-///
-/// **Why is this bad?** Calling `ok()` in if let is unnecessary, instead match
-/// on `Ok(pat)`
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// for result in iter {
-///     if let Some(bench) = try!(result).parse().ok() {
-///         vec.push(bench)
-///     }
-/// }
-/// ```
-/// Could be written:
-///
-/// ```rust
-/// for result in iter {
-///     if let Ok(bench) = try!(result).parse() {
-///         vec.push(bench)
-///     }
-/// }
-/// ```
 declare_clippy_lint! {
+    /// **What it does:*** Checks for unnecessary `ok()` in if let.
+    ///
+    /// **Why is this bad?** Calling `ok()` in if let is unnecessary, instead match
+    /// on `Ok(pat)`
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// for result in iter {
+    ///     if let Some(bench) = try!(result).parse().ok() {
+    ///         vec.push(bench)
+    ///     }
+    /// }
+    /// ```
+    /// Could be written:
+    ///
+    /// ```rust
+    /// for result in iter {
+    ///     if let Ok(bench) = try!(result).parse() {
+    ///         vec.push(bench)
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "pre 0.0.213"]
     pub IF_LET_SOME_RESULT,
     style,
     "usage of `ok()` in `if let Some(pat)` statements is unnecessary, match on `Ok(pat)` instead"
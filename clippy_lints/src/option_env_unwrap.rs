@@ -0,0 +1,61 @@
+use crate::utils::{is_direct_expn_of, span_help_and_lint};
+use if_chain::if_chain;
+use rustc::lint::{in_external_macro, EarlyContext, EarlyLintPass, LintArray, LintContext, LintPass};
+use rustc::{declare_lint, lint_array};
+use syntax::ast::*;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for usage of `option_env!(...).unwrap()` and
+    /// suggests usage of the `env!` macro.
+    ///
+    /// **Why is this bad?** `option_env!` is evaluated at compile time: unwrapping
+    /// it makes the program panic at run time if the variable was missing when the
+    /// program was *built*, whereas `env!` makes the build fail with a clear error.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust,no_run
+    /// let _ = option_env!("HOME").unwrap();
+    /// ```
+    ///
+    /// Is better expressed as:
+    ///
+    /// ```rust,no_run
+    /// let _ = env!("HOME");
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub OPTION_ENV_UNWRAP,
+    correctness,
+    "using `option_env!(...).unwrap()` to get environment variable"
+}
+
+pub struct OptionEnvUnwrap;
+
+impl LintPass for OptionEnvUnwrap {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(OPTION_ENV_UNWRAP)
+    }
+}
+
+impl EarlyLintPass for OptionEnvUnwrap {
+    fn check_expr(&mut self, cx: &EarlyContext<'_>, expr: &Expr) {
+        if_chain! {
+            if !in_external_macro(cx.sess(), expr.span);
+            if let ExprKind::MethodCall(ref path_segment, ref args) = expr.node;
+            if path_segment.ident.name == "expect" || path_segment.ident.name == "unwrap";
+            // `option_env!` expands to either `Some("value")` or `None`
+            if is_direct_expn_of(args[0].span, "option_env").is_some();
+            then {
+                span_help_and_lint(
+                    cx,
+                    OPTION_ENV_UNWRAP,
+                    expr.span,
+                    "this will panic at run time if the environment variable doesn't exist at compile time",
+                    "consider using the `env!` macro instead",
+                );
+            }
+        }
+    }
+}
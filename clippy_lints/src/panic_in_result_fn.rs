@@ -0,0 +1,182 @@
+use crate::utils::{in_macro, is_expn_of, match_def_path, match_type, opt_def_id, paths, span_help_and_lint,
+                   walk_ptrs_ty};
+use if_chain::if_chain;
+use rustc::hir::intravisit::{walk_expr, FnKind, NestedVisitorMap, Visitor};
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::{declare_lint, lint_array};
+use syntax::ast::NodeId;
+use syntax::source_map::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for functions of type `Result` that contain `expect()` or `unwrap()`
+    ///
+    /// **Why is this bad?** These functions promote recoverable errors to non-recoverable errors which
+    /// may be undesirable in code bases which wish to avoid panics.
+    ///
+    /// **Known problems:** This can cause false positives in functions that handle both recoverable
+    /// and non recoverable errors.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// fn divisible_by_3(i_str: String) -> Result<(), String> {
+    ///     let i = i_str.parse::<i32>().expect("cannot divide the input by three");
+    ///
+    ///     if i % 3 != 0 {
+    ///         Err("Number is not divisible by 3")?
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// fn divisible_by_3(i_str: String) -> Result<(), String> {
+    ///     let i = i_str.parse::<i32>().map_err(|e| format!("cannot divide the input by three: {}", e))?;
+    ///
+    ///     if i % 3 != 0 {
+    ///         Err("Number is not divisible by 3")?
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub UNWRAP_IN_RESULT,
+    restriction,
+    "functions of type `Result<..>` that contain `expect()` or `unwrap()`"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for usage of `panic!`, `unimplemented!` or `unreachable!` in a
+    /// function of type result.
+    ///
+    /// **Why is this bad?** For some codebases, it is desirable for functions of type result to
+    /// return an error instead of crashing. Hence unimplemented, panic and unreachable should be
+    /// avoided.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// fn result_with_panic() -> Result<bool, String> {
+    ///     panic!("error");
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn result_without_panic() -> Result<bool, String> {
+    ///     Err(String::from("error"))
+    /// }
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub PANIC_IN_RESULT_FN,
+    restriction,
+    "functions of type `Result<..>` that contain `panic!()`, `unimplemented!()` or `unreachable!()`"
+}
+
+#[derive(Copy, Clone)]
+pub struct PanicInResultFn;
+
+impl LintPass for PanicInResultFn {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(UNWRAP_IN_RESULT, PANIC_IN_RESULT_FN)
+    }
+}
+
+/// The macros expanding to a `panic!`, the outer ones first
+const PANIC_MACROS: [&str; 3] = ["unimplemented", "unreachable", "panic"];
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for PanicInResultFn {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'a, 'tcx>,
+        kind: FnKind<'tcx>,
+        _: &'tcx FnDecl,
+        body: &'tcx Body,
+        span: Span,
+        id: NodeId,
+    ) {
+        if in_macro(span) {
+            return;
+        }
+        let ret_ty = match kind {
+            FnKind::ItemFn(..) | FnKind::Method(..) => {
+                *cx.tcx.fn_sig(cx.tcx.hir.local_def_id(id)).output().skip_binder()
+            },
+            // closures are checked on their own, with the type of their body
+            FnKind::Closure(_) => cx.tables.expr_ty(&body.value),
+        };
+        if match_type(cx, ret_ty, &paths::RESULT) {
+            let mut visitor = PanicVisitor { cx };
+            visitor.visit_expr(&body.value);
+        }
+    }
+}
+
+/// Lints the panics of a function body, not looking in the closures it contains
+struct PanicVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for PanicVisitor<'a, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        if let ExprKind::MethodCall(ref path, _, ref args) = expr.node {
+            let name = path.ident.name;
+            if_chain! {
+                if name == "unwrap" || name == "expect";
+                let receiver_ty = walk_ptrs_ty(self.cx.tables.expr_ty(&args[0]));
+                if match_type(self.cx, receiver_ty, &paths::OPTION)
+                    || match_type(self.cx, receiver_ty, &paths::RESULT);
+                if !in_macro(expr.span);
+                then {
+                    span_help_and_lint(
+                        self.cx,
+                        UNWRAP_IN_RESULT,
+                        expr.span,
+                        &format!("used `{}()` in a function that returns `Result`", name),
+                        "consider propagating the error with the `?` operator, converting it with `.map_err()` \
+                         or `.ok_or()` first",
+                    );
+                }
+            }
+        } else if is_panic_call(self.cx, expr) {
+            let call_site = PANIC_MACROS.iter().find_map(|name| {
+                is_expn_of(expr.span, name)
+                    .filter(|span| !in_macro(*span))
+                    .map(|span| (*name, span))
+            });
+            if let Some((name, span)) = call_site {
+                span_help_and_lint(
+                    self.cx,
+                    PANIC_IN_RESULT_FN,
+                    span,
+                    &format!("used `{}!()` in a function that returns `Result`", name),
+                    &format!(
+                        "`{}!()` should not be used in a function that returns `Result`, return an `Err` instead",
+                        name
+                    ),
+                );
+            }
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::None
+    }
+}
+
+/// Checks for the call to `begin_panic` or `begin_panic_fmt` a `panic!` expands to
+fn is_panic_call(cx: &LateContext<'_, '_>, expr: &Expr) -> bool {
+    if_chain! {
+        if let ExprKind::Call(ref func, _) = expr.node;
+        if let ExprKind::Path(ref qpath) = func.node;
+        if let Some(def_id) = opt_def_id(cx.tables.qpath_def(qpath, func.hir_id));
+        then {
+            return match_def_path(cx.tcx, def_id, &paths::BEGIN_PANIC)
+                || match_def_path(cx.tcx, def_id, &paths::BEGIN_PANIC_FMT);
+        }
+    }
+    false
+}
@@ -0,0 +1,89 @@
+use crate::utils::{has_drive_prefix, match_type, paths, span_help_and_lint, span_lint_and_then, walk_ptrs_ty};
+use if_chain::if_chain;
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::{declare_lint, lint_array};
+use rustc_errors::Applicability;
+use syntax::ast::LitKind;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for [push](https://doc.rust-lang.org/std/path/struct.PathBuf.html#method.push)
+    /// calls on `PathBuf` that can cause overwrites.
+    ///
+    /// **Why is this bad?** Calling `push` with a root path at the start, or a
+    /// drive prefix on Windows, replaces the whole path instead of appending to it.
+    ///
+    /// **Known problems:** Only string literals are checked.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// use std::path::PathBuf;
+    ///
+    /// let mut x = PathBuf::from("/foo");
+    /// x.push("/bar");
+    /// assert_eq!(x, PathBuf::from("/bar"));
+    /// ```
+    /// Could be written:
+    ///
+    /// ```rust
+    /// use std::path::PathBuf;
+    ///
+    /// let mut x = PathBuf::from("/foo");
+    /// x.push("bar");
+    /// assert_eq!(x, PathBuf::from("/foo/bar"));
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub PATH_BUF_PUSH_OVERWRITE,
+    correctness,
+    "calling `push` with file system root on `PathBuf` can overwrite it"
+}
+
+#[derive(Copy, Clone)]
+pub struct PathBufPushOverwrite;
+
+impl LintPass for PathBufPushOverwrite {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(PATH_BUF_PUSH_OVERWRITE)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for PathBufPushOverwrite {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if_chain! {
+            if let ExprKind::MethodCall(ref path, _, ref args) = expr.node;
+            if path.ident.name == "push";
+            if args.len() == 2;
+            if match_type(cx, walk_ptrs_ty(cx.tables.expr_ty(&args[0])), &paths::PATH_BUF);
+            if let ExprKind::Lit(ref lit) = args[1].node;
+            if let LitKind::Str(ref pushed, _) = lit.node;
+            then {
+                let pushed = pushed.as_str();
+                if pushed.starts_with('/') || pushed.starts_with('\\') {
+                    span_lint_and_then(
+                        cx,
+                        PATH_BUF_PUSH_OVERWRITE,
+                        lit.span,
+                        "calling `push` with '/' or '\\' (file system root) will overwrite the previous path \
+                         definition",
+                        |db| {
+                            db.span_suggestion_with_applicability(
+                                lit.span,
+                                "try",
+                                format!("\"{}\"", pushed.trim_start_matches(|c| c == '/' || c == '\\')),
+                                Applicability::MaybeIncorrect,
+                            );
+                        },
+                    );
+                } else if has_drive_prefix(&pushed) {
+                    span_help_and_lint(
+                        cx,
+                        PATH_BUF_PUSH_OVERWRITE,
+                        lit.span,
+                        "calling `push` with a drive prefix will overwrite the previous path definition",
+                        "consider pushing a path relative to the current one instead",
+                    );
+                }
+            }
+        }
+    }
+}
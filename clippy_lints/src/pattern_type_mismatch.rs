@@ -0,0 +1,79 @@
+use crate::utils::{in_macro, span_help_and_lint};
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::ty;
+use rustc::{declare_lint, lint_array};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for patterns that aren't exact representations of
+    /// the types they are applied to, i.e. patterns relying on "default binding
+    /// modes" to match a reference, like `Some(x)` matched against a
+    /// `&Option<T>`.
+    ///
+    /// **Why is this bad?** The binding modes are implicit: `x` is a reference
+    /// above although the pattern does not say so. Some codebases want `&` and
+    /// `ref` to be written everywhere they apply.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let value = &Some(Box::new(23));
+    /// match value {
+    ///     Some(inner) => println!("{}", inner),
+    ///     None => println!("none"),
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let value = &Some(Box::new(23));
+    /// match *value {
+    ///     Some(ref inner) => println!("{}", inner),
+    ///     None => println!("none"),
+    /// }
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub PATTERN_TYPE_MISMATCH,
+    restriction,
+    "type of pattern does not match the expression type"
+}
+
+#[derive(Copy, Clone)]
+pub struct PatternTypeMismatch;
+
+impl LintPass for PatternTypeMismatch {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(PATTERN_TYPE_MISMATCH)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for PatternTypeMismatch {
+    fn check_pat(&mut self, cx: &LateContext<'a, 'tcx>, pat: &'tcx Pat) {
+        if in_macro(pat.span) {
+            return;
+        }
+
+        // the references the compiler derefed to match `pat`, the outermost first
+        let adjustments = match cx.tables.pat_adjustments().get(pat.hir_id) {
+            Some(adjustments) if !adjustments.is_empty() => adjustments,
+            _ => return,
+        };
+        let explicit: String = adjustments
+            .iter()
+            .map(|ty| match ty.sty {
+                ty::Ref(_, _, MutMutable) => "&mut ",
+                _ => "&",
+            })
+            .collect();
+        span_help_and_lint(
+            cx,
+            PATTERN_TYPE_MISMATCH,
+            pat.span,
+            "type of pattern does not match the expression type",
+            &format!(
+                "explicitly match against a `{}_` pattern and adjust the enclosed variable bindings",
+                explicit
+            ),
+        );
+    }
+}
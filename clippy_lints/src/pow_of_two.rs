@@ -0,0 +1,209 @@
+use crate::consts::{constant_simple, Constant};
+use crate::utils::sugg::Sugg;
+use crate::utils::{in_macro, snippet, span_lint_and_then, uint_bits, SpanlessEq};
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::ty;
+use rustc::{declare_lint, lint_array};
+use rustc_errors::Applicability;
+use syntax::ast;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for multiplications and divisions of unsigned
+    /// integers by a constant power of two in functions marked `#[inline]`.
+    ///
+    /// **Why is this bad?** `#[inline]` functions are usually the hot ones, and a
+    /// shift states that the operation is a cheap one.
+    ///
+    /// **Known problems:** The compiler already turns these operations into shifts
+    /// in optimized builds. A shift doesn't panic on overflow in debug builds while
+    /// a multiplication does.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// #[inline]
+    /// fn offset(index: u32) -> u32 {
+    ///     index * 8
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// #[inline]
+    /// fn offset(index: u32) -> u32 {
+    ///     index << 3
+    /// }
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub INEFFICIENT_POW_OF_TWO,
+    pedantic,
+    "multiplying or dividing an unsigned integer by a power of two in an `#[inline]` function"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for bit rotations written with shifts, like
+    /// `(x << s) | (x >> (32 - s))` on a `u32`.
+    ///
+    /// **Why is this bad?** `rotate_left` and `rotate_right` are easier to read,
+    /// and they don't overflow when the rotation is a multiple of the bit width.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let x: u32 = 12;
+    /// let rotated = (x << 3) | (x >> 29);
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let x: u32 = 12;
+    /// let rotated = x.rotate_left(3);
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub MANUAL_ROTATE,
+    pedantic,
+    "manual implementation of `rotate_left` or `rotate_right`"
+}
+
+#[derive(Copy, Clone)]
+pub struct PowOfTwo;
+
+impl LintPass for PowOfTwo {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(INEFFICIENT_POW_OF_TWO, MANUAL_ROTATE)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for PowOfTwo {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if in_macro(expr.span) {
+            return;
+        }
+        let uty = match cx.tables.expr_ty(expr).sty {
+            ty::Uint(uty) => uty,
+            _ => return,
+        };
+        if let ExprKind::Binary(op, ref left, ref right) = expr.node {
+            match op.node {
+                BinOpKind::Mul | BinOpKind::Div => check_pow_of_two(cx, expr, op.node, left, right),
+                BinOpKind::BitOr => check_rotate(cx, expr, uty, left, right),
+                _ => {},
+            }
+        }
+    }
+}
+
+fn check_pow_of_two(cx: &LateContext<'_, '_>, expr: &Expr, op: BinOpKind, left: &Expr, right: &Expr) {
+    let parent_item = cx.tcx.hir.get_parent(expr.id);
+    if !cx.tcx.hir.attrs(parent_item).iter().any(|attr| attr.name() == "inline") {
+        return;
+    }
+    // only the right operand of a division can be the divisor
+    let (value, exponent) = match (pow_of_two(cx, left), pow_of_two(cx, right)) {
+        (_, Some(exponent)) => (left, exponent),
+        (Some(exponent), None) if op == BinOpKind::Mul => (right, exponent),
+        _ => return,
+    };
+    if constant_simple(cx, cx.tables, value).is_some() {
+        return;
+    }
+    let (what, shift) = if op == BinOpKind::Mul {
+        ("multiplication", "<<")
+    } else {
+        ("division", ">>")
+    };
+    span_lint_and_then(
+        cx,
+        INEFFICIENT_POW_OF_TWO,
+        expr.span,
+        &format!("this {} by a power of two can be written as a shift", what),
+        |db| {
+            // the shifts have a lower precedence than the arithmetic operators
+            let sugg = format!("{} {} {}", Sugg::hir(cx, value, "..").maybe_par(), shift, exponent);
+            db.span_suggestion_with_applicability(expr.span, "try", sugg, Applicability::MaybeIncorrect);
+        },
+    );
+}
+
+/// Returns `k` if `expr` is the constant `2^k`, with `k > 0`
+fn pow_of_two(cx: &LateContext<'_, '_>, expr: &Expr) -> Option<u32> {
+    match constant_simple(cx, cx.tables, expr) {
+        Some(Constant::Int(n)) if n > 1 && n.is_power_of_two() => Some(n.trailing_zeros()),
+        _ => None,
+    }
+}
+
+fn check_rotate(cx: &LateContext<'_, '_>, expr: &Expr, uty: ast::UintTy, left: &Expr, right: &Expr) {
+    let (shl, shr) = match (shift(left), shift(right)) {
+        (Some((BinOpKind::Shl, shl_value, shl_amount)), Some((BinOpKind::Shr, shr_value, shr_amount)))
+        | (Some((BinOpKind::Shr, shr_value, shr_amount)), Some((BinOpKind::Shl, shl_value, shl_amount))) => {
+            ((shl_value, shl_amount), (shr_value, shr_amount))
+        },
+        _ => return,
+    };
+    if !SpanlessEq::new(cx).ignore_fn().eq_expr(shl.0, shr.0) {
+        return;
+    }
+    let bits = u128::from(uint_bits(cx.tcx, uty));
+
+    let (method, amount) = match (constant_simple(cx, cx.tables, shl.1), constant_simple(cx, cx.tables, shr.1)) {
+        (Some(Constant::Int(shl_bits)), Some(Constant::Int(shr_bits))) => {
+            if shl_bits == 0 || shr_bits == 0 || shl_bits + shr_bits != bits {
+                return;
+            }
+            ("rotate_left", shl_bits.to_string())
+        },
+        // `(x << s) | (x >> (BITS - s))` or `(x >> s) | (x << (BITS - s))`
+        _ => if is_complement(cx, shr.1, shl.1, bits) {
+            ("rotate_left", rotate_amount(cx, shl.1))
+        } else if is_complement(cx, shl.1, shr.1, bits) {
+            ("rotate_right", rotate_amount(cx, shr.1))
+        } else {
+            return;
+        },
+    };
+    span_lint_and_then(
+        cx,
+        MANUAL_ROTATE,
+        expr.span,
+        "there is no need to manually implement bit rotation",
+        |db| {
+            db.span_suggestion_with_applicability(
+                expr.span,
+                "this expression can be rewritten as",
+                format!("{}.{}({})", Sugg::hir(cx, shl.0, "..").maybe_par(), method, amount),
+                Applicability::MaybeIncorrect,
+            );
+        },
+    );
+}
+
+/// Returns the argument of `rotate_left` or `rotate_right` for a shift by `amount`, which is
+/// cast if the shift isn't by a `u32` already
+fn rotate_amount(cx: &LateContext<'_, '_>, amount: &Expr) -> String {
+    if let ty::Uint(ast::UintTy::U32) = cx.tables.expr_ty(amount).sty {
+        snippet(cx, amount.span, "..").to_string()
+    } else {
+        Sugg::hir(cx, amount, "..").as_ty("u32").to_string()
+    }
+}
+
+/// Returns the operator, value and amount of a shift
+fn shift(expr: &Expr) -> Option<(BinOpKind, &Expr, &Expr)> {
+    match expr.node {
+        ExprKind::Binary(op, ref value, ref amount) if op.node == BinOpKind::Shl || op.node == BinOpKind::Shr => {
+            Some((op.node, value, amount))
+        },
+        _ => None,
+    }
+}
+
+/// Checks whether `complement` is `bits - amount`
+fn is_complement(cx: &LateContext<'_, '_>, complement: &Expr, amount: &Expr, bits: u128) -> bool {
+    match complement.node {
+        ExprKind::Binary(op, ref minuend, ref subtrahend) if op.node == BinOpKind::Sub => {
+            constant_simple(cx, cx.tables, minuend) == Some(Constant::Int(bits))
+                && SpanlessEq::new(cx).ignore_fn().eq_expr(subtrahend, amount)
+        },
+        _ => false,
+    }
+}
@@ -3,31 +3,46 @@ use rustc::{declare_lint, lint_array};
 use if_chain::if_chain;
 use rustc::hir::*;
 use rustc::hir::def::Def;
+use rustc::ty;
+use syntax::ast::NodeId;
 use crate::utils::sugg::Sugg;
 use syntax::ptr::P;
 
-use crate::utils::{match_def_path, match_type, span_lint_and_then};
+use crate::utils::{
+    get_trait_def_id, implements_trait, in_macro, is_copy, match_def_path, match_trait_method, match_type,
+    remove_blocks, span_lint_and_then,
+};
 use crate::utils::paths::*;
 
-/// **What it does:** Checks for expressions that could be replaced by the question mark operator
-///
-/// **Why is this bad?** Question mark usage is more idiomatic
-///
-/// **Known problems:** None
-///
-/// **Example:**
-/// ```rust
-/// if option.is_none() {
-///     return None;
-/// }
-/// ```
-///
-/// Could be written:
-///
-/// ```rust
-/// option?;
-/// ```
-declare_clippy_lint!{
+declare_clippy_lint! {
+    /// **What it does:** Checks for expressions that could be replaced by the question mark operator,
+    /// like early returns of `None` when an `Option` is `None`, or matches returning the error of a
+    /// `Result`
+    ///
+    /// **Why is this bad?** Question mark usage is more idiomatic
+    ///
+    /// **Known problems:** None
+    ///
+    /// **Example:**
+    /// ```rust
+    /// if option.is_none() {
+    ///     return None;
+    /// }
+    ///
+    /// let value = match result {
+    ///     Ok(value) => value,
+    ///     Err(err) => return Err(err),
+    /// };
+    /// ```
+    ///
+    /// Could be written:
+    ///
+    /// ```rust
+    /// option?;
+    ///
+    /// let value = result?;
+    /// ```
+    #[clippy::version = "0.0.185"]
     pub QUESTION_MARK,
     style,
     "checks for expressions that could be replaced by the question mark operator"
@@ -54,7 +69,7 @@ impl QuestionMarkPass {
     /// If it matches, it will suggest to use the question mark operator instead
     fn check_is_none_and_early_return_none(cx: &LateContext<'_, '_>, expr: &Expr) {
         if_chain! {
-            if let ExprKind::If(ref if_expr, ref body, _) = expr.node;
+            if let ExprKind::If(ref if_expr, ref body, None) = expr.node;
             if let ExprKind::MethodCall(ref segment, _, ref args) = if_expr.node;
             if segment.ident.name == "is_none";
             if Self::expression_returns_none(cx, body);
@@ -69,11 +84,17 @@ impl QuestionMarkPass {
                     "this block may be rewritten with the `?` operator",
                     |db| {
                         let receiver_str = &Sugg::hir(cx, subject, "..");
+                        // moving the option out of a place could make it unusable afterwards
+                        let sugg = if Self::is_place(subject) && !is_copy(cx, cx.tables.expr_ty(subject)) {
+                            format!("{}.as_ref()?;", receiver_str.maybe_par())
+                        } else {
+                            format!("{}?;", receiver_str)
+                        };
 
                         db.span_suggestion(
                             expr.span,
                             "replace_it_with",
-                            format!("{}?;", receiver_str),
+                            sugg,
                         );
                     }
                 )
@@ -81,6 +102,150 @@ impl QuestionMarkPass {
         }
     }
 
+    /// Check if the given expression on the given context matches the following structure:
+    ///
+    /// ```ignore
+    /// match result {
+    ///    Ok(value) => value,
+    ///    Err(err) => return Err(err),
+    /// }
+    /// ```
+    ///
+    /// The error may also be converted with `From::from(err)` or `err.into()`, if the error type
+    /// returned implements `From` of the matched one, as `?` does.
+    fn check_match_and_early_return_err(cx: &LateContext<'_, '_>, expr: &Expr) {
+        if_chain! {
+            if let ExprKind::Match(ref subject, ref arms, MatchSource::Normal) = expr.node;
+            if arms.len() == 2 && arms.iter().all(|arm| arm.pats.len() == 1 && arm.guard.is_none());
+            if match_type(cx, cx.tables.expr_ty(subject), &RESULT);
+            if (Self::is_ok_arm(cx, &arms[0]) && Self::is_err_arm(cx, &arms[1]))
+                || (Self::is_ok_arm(cx, &arms[1]) && Self::is_err_arm(cx, &arms[0]));
+
+            then {
+                span_lint_and_then(
+                    cx,
+                    QUESTION_MARK,
+                    expr.span,
+                    "this match expression may be rewritten with the `?` operator",
+                    |db| {
+                        db.span_suggestion(
+                            expr.span,
+                            "replace_it_with",
+                            format!("{}?", Sugg::hir(cx, subject, "..").maybe_par()),
+                        );
+                    }
+                )
+            }
+        }
+    }
+
+    /// Returns the binding of a `Ok(value)` or `Err(err)` pattern, if its variant has the given path
+    fn variant_binding(cx: &LateContext<'_, '_>, pat: &Pat, path: &[&str]) -> Option<NodeId> {
+        if_chain! {
+            if let PatKind::TupleStruct(ref qpath, ref pats, None) = pat.node;
+            if pats.len() == 1;
+            if let Def::VariantCtor(def_id, _) = cx.tables.qpath_def(qpath, pat.hir_id);
+            if match_def_path(cx.tcx, def_id, path);
+            if let PatKind::Binding(BindingAnnotation::Unannotated, id, _, None) = pats[0].node;
+
+            then {
+                return Some(id);
+            }
+        }
+
+        None
+    }
+
+    fn is_ok_arm(cx: &LateContext<'_, '_>, arm: &Arm) -> bool {
+        match Self::variant_binding(cx, &arm.pats[0], &RESULT_OK) {
+            Some(id) => Self::is_local(remove_blocks(&arm.body), id),
+            None => false,
+        }
+    }
+
+    fn is_err_arm(cx: &LateContext<'_, '_>, arm: &Arm) -> bool {
+        if_chain! {
+            if let Some(id) = Self::variant_binding(cx, &arm.pats[0], &RESULT_ERR);
+            if let Some(ret) = Self::returned_expression(&arm.body);
+            if let ExprKind::Call(ref func, ref args) = ret.node;
+            if args.len() == 1;
+            if let ExprKind::Path(ref qpath) = func.node;
+            if let Def::VariantCtor(def_id, _) = cx.tables.qpath_def(qpath, func.hir_id);
+            if match_def_path(cx.tcx, def_id, &RESULT_ERR);
+
+            then {
+                if Self::is_local(&args[0], id) {
+                    return true;
+                }
+                // `?` converts the error with `From::from`
+                let conversion = &args[0];
+                let arg = match conversion.node {
+                    ExprKind::Call(ref func, ref inner) if inner.len() == 1 && Self::is_from_from(cx, func) => {
+                        &inner[0]
+                    },
+                    ExprKind::MethodCall(ref segment, _, ref inner)
+                        if segment.ident.name == "into" && match_trait_method(cx, conversion, &INTO) =>
+                    {
+                        &inner[0]
+                    },
+                    _ => return false,
+                };
+                if !Self::is_local(arg, id) {
+                    return false;
+                }
+                let err_ty = match cx.tables.expr_ty(ret).sty {
+                    ty::Adt(_, substs) => substs.type_at(1),
+                    _ => return false,
+                };
+                return get_trait_def_id(cx, &FROM_TRAIT).map_or(false, |from| {
+                    implements_trait(cx, err_ty, from, &[cx.tables.expr_ty(arg).into()])
+                });
+            }
+        }
+
+        false
+    }
+
+    fn is_from_from(cx: &LateContext<'_, '_>, func: &Expr) -> bool {
+        if let ExprKind::Path(ref qpath) = func.node {
+            if let Def::Method(def_id) = cx.tables.qpath_def(qpath, func.hir_id) {
+                return match_def_path(cx.tcx, def_id, &FROM_FROM);
+            }
+        }
+
+        false
+    }
+
+    fn is_local(expr: &Expr, id: NodeId) -> bool {
+        match expr.node {
+            ExprKind::Path(QPath::Resolved(None, ref path)) => path.def == Def::Local(id),
+            _ => false,
+        }
+    }
+
+    fn is_place(expr: &Expr) -> bool {
+        match expr.node {
+            ExprKind::Path(_) | ExprKind::Field(..) | ExprKind::Index(..) | ExprKind::Unary(UnDeref, _) => true,
+            _ => false,
+        }
+    }
+
+    /// Returns the value of `return value`, possibly in a block
+    fn returned_expression(expression: &Expr) -> Option<&Expr> {
+        match expression.node {
+            ExprKind::Ret(Some(ref expr)) => Some(&**expr),
+            ExprKind::Block(ref block, _) => match (&block.stmts[..], &block.expr) {
+                ([], Some(expr)) => Self::returned_expression(expr),
+                ([stmt], None) => match stmt.node {
+                    StmtKind::Semi(ref expr, _) => Self::returned_expression(expr),
+                    _ => None,
+                },
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
     fn is_option(cx: &LateContext<'_, '_>, expression: &Expr) -> bool {
         let expr_ty = cx.tables.expr_ty(expression);
 
@@ -135,6 +300,10 @@ impl QuestionMarkPass {
 
 impl<'a, 'tcx> LateLintPass<'a, 'tcx> for QuestionMarkPass {
     fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if in_macro(expr.span) {
+            return;
+        }
         Self::check_is_none_and_early_return_none(cx, expr);
+        Self::check_match_and_early_return_err(cx, expr);
     }
 }
@@ -0,0 +1,186 @@
+use crate::utils::{match_def_path, match_trait_method, opt_def_id, paths, snippet, span_lint_and_then};
+use if_chain::if_chain;
+use rustc::hir::def::Def;
+use rustc::hir::intravisit::{walk_expr, walk_stmt, NestedVisitorMap, Visitor};
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::{declare_lint, lint_array};
+use syntax::ast::{Ident, NodeId};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for reads into an empty `Vec`, created with `Vec::new()`
+    /// or `Vec::with_capacity(len)` and not resized before the read.
+    ///
+    /// **Why is this bad?** `Read::read` and `Read::read_exact` fill the slice they are
+    /// given, which is empty here (the capacity of a `Vec` is not part of its slice): the
+    /// read always returns 0 bytes.
+    ///
+    /// **Known problems:** Only the first use of the vector after its creation is
+    /// checked.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// let mut data = Vec::with_capacity(len);
+    /// r.read_exact(&mut data)?;
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// let mut data = vec![0; len];
+    /// r.read_exact(&mut data)?;
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub READ_ZERO_BYTE_VEC,
+    correctness,
+    "reading into a zero-length `Vec`"
+}
+
+#[derive(Copy, Clone)]
+pub struct ReadZeroByteVec;
+
+impl LintPass for ReadZeroByteVec {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(READ_ZERO_BYTE_VEC)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for ReadZeroByteVec {
+    fn check_block(&mut self, cx: &LateContext<'a, 'tcx>, block: &'tcx Block) {
+        for (i, stmt) in block.stmts.iter().enumerate() {
+            if_chain! {
+                if let StmtKind::Decl(ref decl, _) = stmt.node;
+                if let DeclKind::Local(ref local) = decl.node;
+                if let PatKind::Binding(BindingAnnotation::Mutable, id, ident, None) = local.pat.node;
+                if let Some(ref init) = local.init;
+                if let Some(capacity) = empty_vec(cx, init);
+                if let Some(expr) = first_use(cx, id, &block.stmts[i + 1..], block.expr.as_ref().map(|e| &**e));
+                if let Some(read) = find_read(cx, id, expr);
+                then {
+                    lint(cx, read, ident, capacity);
+                }
+            }
+        }
+    }
+}
+
+/// Checks for `Vec::new()` or `Vec::with_capacity(len)`, returning `len` for the latter
+fn empty_vec<'e>(cx: &LateContext<'_, '_>, expr: &'e Expr) -> Option<Option<&'e Expr>> {
+    if_chain! {
+        if let ExprKind::Call(ref func, ref args) = expr.node;
+        if let ExprKind::Path(ref qpath) = func.node;
+        if let Some(def_id) = opt_def_id(cx.tables.qpath_def(qpath, func.hir_id));
+        then {
+            if match_def_path(cx.tcx, def_id, &paths::VEC_NEW) {
+                return Some(None);
+            }
+            if match_def_path(cx.tcx, def_id, &paths::VEC_WITH_CAPACITY) {
+                return Some(Some(&args[0]));
+            }
+        }
+    }
+    None
+}
+
+/// Returns the first statement using the local `id`, as an expression, or the final expression
+/// of the block
+fn first_use<'a, 'tcx: 'a>(
+    cx: &LateContext<'a, 'tcx>,
+    id: NodeId,
+    stmts: &'tcx [Stmt],
+    expr: Option<&'tcx Expr>,
+) -> Option<&'tcx Expr> {
+    for stmt in stmts {
+        let mut visitor = UseVisitor { cx, id, found: None };
+        walk_stmt(&mut visitor, stmt);
+        if visitor.found.is_some() {
+            return match stmt.node {
+                StmtKind::Expr(ref expr, _) | StmtKind::Semi(ref expr, _) => Some(expr),
+                StmtKind::Decl(ref decl, _) => match decl.node {
+                    DeclKind::Local(ref local) => local.init.as_ref().map(|init| &**init),
+                    DeclKind::Item(_) => None,
+                },
+            };
+        }
+    }
+    expr
+}
+
+/// Returns the read into the local `id` in `expr`, if it's the first use of the local
+fn find_read<'a, 'tcx: 'a>(cx: &LateContext<'a, 'tcx>, id: NodeId, expr: &'tcx Expr) -> Option<&'tcx Expr> {
+    let mut visitor = UseVisitor { cx, id, found: None };
+    visitor.visit_expr(expr);
+    match visitor.found {
+        Some(Use::Read(read)) => Some(read),
+        _ => None,
+    }
+}
+
+fn lint(cx: &LateContext<'_, '_>, read: &Expr, ident: Ident, capacity: Option<&Expr>) {
+    span_lint_and_then(
+        cx,
+        READ_ZERO_BYTE_VEC,
+        read.span,
+        "reading zero byte data to `Vec`",
+        |db| match capacity {
+            Some(len) => {
+                db.help(&format!(
+                    "resize the `Vec` before reading into it: `{}.resize({}, 0)`",
+                    ident,
+                    snippet(cx, len.span, "len")
+                ));
+            },
+            None => {
+                db.help("resize the `Vec` before reading into it, or use `read_to_end`");
+            },
+        },
+    );
+}
+
+fn is_local(cx: &LateContext<'_, '_>, id: NodeId, expr: &Expr) -> bool {
+    if let ExprKind::Path(ref qpath) = expr.node {
+        if let Def::Local(local_id) = cx.tables.qpath_def(qpath, expr.hir_id) {
+            return local_id == id;
+        }
+    }
+    false
+}
+
+enum Use<'tcx> {
+    /// `r.read(&mut vec)` or `r.read_exact(&mut vec)`
+    Read(&'tcx Expr),
+    Other,
+}
+
+/// Finds the first use of the local `id`
+struct UseVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    id: NodeId,
+    found: Option<Use<'tcx>>,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for UseVisitor<'a, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        if self.found.is_some() {
+            return;
+        }
+        if_chain! {
+            if let ExprKind::MethodCall(ref path, _, ref args) = expr.node;
+            if (path.ident.name == "read" || path.ident.name == "read_exact") && args.len() == 2;
+            if let ExprKind::AddrOf(MutMutable, ref buf) = args[1].node;
+            if is_local(self.cx, self.id, buf);
+            if match_trait_method(self.cx, expr, &paths::IO_READ);
+            then {
+                self.found = Some(Use::Read(expr));
+                return;
+            }
+        }
+        if is_local(self.cx, self.id, expr) {
+            self.found = Some(Use::Other);
+            return;
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::None
+    }
+}
@@ -0,0 +1,170 @@
+use crate::utils::{in_macro, match_type, paths, span_lint_and_then};
+use if_chain::if_chain;
+use rustc::hir::intravisit::{walk_local, NestedVisitorMap, Visitor};
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::middle::expr_use_visitor::*;
+use rustc::middle::mem_categorization::{cmt_, Categorization};
+use rustc::ty;
+use rustc::{declare_lint, lint_array};
+use rustc_errors::Applicability;
+use std::collections::HashSet;
+use syntax::ast::NodeId;
+use syntax::source_map::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `RwLock` write guards, like
+    /// `let guard = lock.write().unwrap()`, which are only read from.
+    ///
+    /// **Why is this bad?** A write lock is exclusive, it blocks all the other
+    /// readers while a read lock doesn't.
+    ///
+    /// **Known problems:** Only the guards bound to a local are checked. The guards
+    /// moved out of the local, e.g. by an explicit `drop`, aren't linted.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # use std::sync::RwLock;
+    /// let lock = RwLock::new(1);
+    /// let num = lock.write().unwrap();
+    /// println!("{}", *num);
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::sync::RwLock;
+    /// let lock = RwLock::new(1);
+    /// let num = lock.read().unwrap();
+    /// println!("{}", *num);
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub READONLY_WRITE_LOCK,
+    perf,
+    "acquiring a write lock on a `RwLock` only to read from it"
+}
+
+#[derive(Copy, Clone)]
+pub struct ReadonlyWriteLock;
+
+impl LintPass for ReadonlyWriteLock {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(READONLY_WRITE_LOCK)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for ReadonlyWriteLock {
+    fn check_body(&mut self, cx: &LateContext<'a, 'tcx>, body: &'tcx Body) {
+        let mut visitor = GuardVisitor {
+            cx,
+            guards: Vec::new(),
+        };
+        visitor.visit_expr(&body.value);
+        if visitor.guards.is_empty() {
+            return;
+        }
+
+        let mut delegate = WriteDelegate {
+            guards: visitor.guards.iter().map(|&(id, _)| id).collect(),
+            written: HashSet::new(),
+        };
+        let def_id = cx.tcx.hir.body_owner_def_id(body.id());
+        let region_scope_tree = &cx.tcx.region_scope_tree(def_id);
+        ExprUseVisitor::new(&mut delegate, cx.tcx, cx.param_env, region_scope_tree, cx.tables, None)
+            .walk_expr(&body.value);
+
+        for (id, write) in visitor.guards {
+            if !delegate.written.contains(&id) {
+                span_lint_and_then(
+                    cx,
+                    READONLY_WRITE_LOCK,
+                    write,
+                    "this write lock is used only for reading",
+                    |db| {
+                        db.span_suggestion_with_applicability(
+                            write,
+                            "consider using a read lock instead",
+                            "read()".to_string(),
+                            Applicability::MaybeIncorrect,
+                        );
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// Finds the locals bound to a write guard, with the span of the `write()` call
+struct GuardVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    guards: Vec<(NodeId, Span)>,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for GuardVisitor<'a, 'tcx> {
+    fn visit_local(&mut self, local: &'tcx Local) {
+        if_chain! {
+            if !in_macro(local.span);
+            if let PatKind::Binding(_, id, ..) = local.pat.node;
+            if let Some(ref init) = local.init;
+            if let ExprKind::MethodCall(ref unwrap, _, ref unwrap_args) = init.node;
+            if unwrap.ident.name == "unwrap" || unwrap.ident.name == "expect";
+            if let ExprKind::MethodCall(ref write, _, ref write_args) = unwrap_args[0].node;
+            if write.ident.name == "write" && write_args.len() == 1;
+            if match_type(self.cx, self.cx.tables.expr_ty(&unwrap_args[0]), &paths::RESULT);
+            if match_type(self.cx, self.cx.tables.expr_ty(init), &paths::RWLOCK_WRITE_GUARD);
+            then {
+                self.guards.push((id, write.ident.span.with_hi(unwrap_args[0].span.hi())));
+            }
+        }
+        walk_local(self, local);
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::None
+    }
+}
+
+/// Collects the guards which are mutably borrowed, assigned through or moved
+struct WriteDelegate {
+    guards: HashSet<NodeId>,
+    written: HashSet<NodeId>,
+}
+
+impl WriteDelegate {
+    fn update(&mut self, cat: &Categorization<'_>) {
+        match *cat {
+            Categorization::Local(id) => {
+                if self.guards.contains(&id) {
+                    self.written.insert(id);
+                }
+            },
+            Categorization::Deref(ref cmt, _) | Categorization::Interior(ref cmt, _) => self.update(&cmt.cat),
+            _ => {},
+        }
+    }
+}
+
+impl<'tcx> Delegate<'tcx> for WriteDelegate {
+    fn consume(&mut self, _: NodeId, _: Span, cmt: &cmt_<'tcx>, mode: ConsumeMode) {
+        if let ConsumeMode::Move(_) = mode {
+            if let Categorization::Local(_) = cmt.cat {
+                self.update(&cmt.cat);
+            }
+        }
+    }
+
+    fn matched_pat(&mut self, _: &Pat, _: &cmt_<'tcx>, _: MatchMode) {}
+
+    fn consume_pat(&mut self, _: &Pat, _: &cmt_<'tcx>, _: ConsumeMode) {}
+
+    fn borrow(&mut self, _: NodeId, _: Span, cmt: &cmt_<'tcx>, _: ty::Region<'_>, bk: ty::BorrowKind, _: LoanCause) {
+        match bk {
+            ty::BorrowKind::MutBorrow | ty::BorrowKind::UniqueImmBorrow => self.update(&cmt.cat),
+            ty::BorrowKind::ImmBorrow => {},
+        }
+    }
+
+    fn mutate(&mut self, _: NodeId, _: Span, cmt: &cmt_<'tcx>, _: MutateMode) {
+        self.update(&cmt.cat);
+    }
+
+    fn decl_without_init(&mut self, _: NodeId, _: Span) {}
+}
@@ -0,0 +1,148 @@
+use crate::utils::higher::FormatArgsExpn;
+use crate::utils::{is_self, match_def_path, match_trait_method, paths, span_lint, walk_ptrs_ty};
+use if_chain::if_chain;
+use rustc::hir::def::Def;
+use rustc::hir::def_id::DefId;
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::{declare_lint, lint_array};
+use syntax::ast::NodeId;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `impl Display` and `impl Debug` whose `fmt`
+    /// method formats `self` with the trait being implemented, or calls
+    /// `self.to_string()` in `impl Display`.
+    ///
+    /// **Why is this bad?** The formatting calls the `fmt` method being
+    /// implemented, which recurses infinitely and overflows the stack at
+    /// runtime.
+    ///
+    /// **Known problems:** Only `self` itself is checked, not e.g. a clone of it.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// use std::fmt;
+    ///
+    /// struct Structure(i32);
+    /// impl fmt::Display for Structure {
+    ///     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    ///         write!(f, "{}", self.to_string())
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// use std::fmt;
+    ///
+    /// struct Structure(i32);
+    /// impl fmt::Display for Structure {
+    ///     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    ///         write!(f, "{}", self.0)
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub RECURSIVE_FORMAT_IMPL,
+    correctness,
+    "formatting `self` in its own `impl Display` or `impl Debug`, which recurses infinitely"
+}
+
+#[derive(Default)]
+pub struct RecursiveFormatImpl {
+    /// The trait implemented by the `fmt` method being checked, `"Display"` or `"Debug"`, with
+    /// the id of its `self` argument and the id of the `impl`
+    format_impl: Option<(&'static str, NodeId, DefId)>,
+}
+
+impl LintPass for RecursiveFormatImpl {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(RECURSIVE_FORMAT_IMPL)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for RecursiveFormatImpl {
+    fn check_impl_item(&mut self, cx: &LateContext<'a, 'tcx>, impl_item: &'tcx ImplItem) {
+        if_chain! {
+            if impl_item.ident.name == "fmt";
+            if let ImplItemKind::Method(_, body_id) = impl_item.node;
+            let impl_def_id = cx.tcx.hir.local_def_id(cx.tcx.hir.get_parent(impl_item.id));
+            if let Some(trait_ref) = cx.tcx.impl_trait_ref(impl_def_id);
+            let body = cx.tcx.hir.body(body_id);
+            if let Some(self_arg) = body.arguments.get(0);
+            if is_self(self_arg);
+            if let PatKind::Binding(_, self_id, _, _) = self_arg.pat.node;
+            then {
+                if match_def_path(cx.tcx, trait_ref.def_id, &paths::DISPLAY_TRAIT) {
+                    self.format_impl = Some(("Display", self_id, impl_def_id));
+                } else if match_def_path(cx.tcx, trait_ref.def_id, &paths::DEBUG_TRAIT) {
+                    self.format_impl = Some(("Debug", self_id, impl_def_id));
+                }
+            }
+        }
+    }
+
+    fn check_impl_item_post(&mut self, _: &LateContext<'a, 'tcx>, _: &'tcx ImplItem) {
+        self.format_impl = None;
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        let (trait_name, self_id, impl_def_id) = match self.format_impl {
+            Some(format_impl) => format_impl,
+            None => return,
+        };
+
+        if_chain! {
+            if trait_name == "Display";
+            if let ExprKind::MethodCall(ref path, _, ref args) = expr.node;
+            if path.ident.name == "to_string" && args.len() == 1;
+            if is_self_local(cx, &args[0], self_id, impl_def_id);
+            if match_trait_method(cx, expr, &paths::TO_STRING);
+            then {
+                span_lint(
+                    cx,
+                    RECURSIVE_FORMAT_IMPL,
+                    expr.span,
+                    "using `self.to_string` in `impl Display` will cause infinite recursion",
+                );
+            }
+        }
+
+        if let Some(format_args) = FormatArgsExpn::parse(cx, expr) {
+            let fmt_method = if trait_name == "Display" {
+                &paths::DISPLAY_FMT_METHOD
+            } else {
+                &paths::DEBUG_FMT_METHOD
+            };
+            for arg in &format_args.args {
+                if is_self_local(cx, arg.value, self_id, impl_def_id)
+                    && match_def_path(cx.tcx, arg.fmt_fn, fmt_method)
+                {
+                    span_lint(
+                        cx,
+                        RECURSIVE_FORMAT_IMPL,
+                        arg.value.span,
+                        &format!(
+                            "using `self` as `{0}` in `impl {0}` will cause infinite recursion",
+                            trait_name
+                        ),
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Checks whether `expr` is the `self` argument, possibly borrowed or dereferenced, like
+/// `&self` or `*self`, and still has the type of the `impl`, unlike e.g. `**self` through a
+/// `Deref` implementation
+fn is_self_local(cx: &LateContext<'_, '_>, expr: &Expr, self_id: NodeId, impl_def_id: DefId) -> bool {
+    is_self_root(expr, self_id) && walk_ptrs_ty(cx.tables.expr_ty(expr)) == cx.tcx.type_of(impl_def_id)
+}
+
+fn is_self_root(expr: &Expr, self_id: NodeId) -> bool {
+    match expr.node {
+        ExprKind::AddrOf(_, ref inner) | ExprKind::Unary(UnOp::UnDeref, ref inner) => is_self_root(inner, self_id),
+        ExprKind::Path(QPath::Resolved(None, ref path)) => path.def == Def::Local(self_id),
+        _ => false,
+    }
+}
@@ -0,0 +1,147 @@
+use crate::utils::{in_macro, snippet_opt, span_lint_and_then, trim_multiline};
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintContext, LintPass};
+use rustc::{declare_lint, lint_array};
+use rustc_errors::Applicability;
+use std::borrow::Cow;
+use syntax::source_map::{BytePos, Span};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `else` blocks that can be removed without changing semantics.
+    ///
+    /// **Why is this bad?** The `else` block adds unnecessary indentation and verbosity.
+    ///
+    /// **Known problems:** Some may prefer to keep the `else` block for clarity.
+    /// The bindings declared in the `else` block are moved to the enclosing
+    /// scope, where they may shadow other bindings.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// fn my_func(count: u32) {
+    ///     if count == 0 {
+    ///         print!("Nothing to do");
+    ///         return;
+    ///     } else {
+    ///         print!("Moving on...");
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn my_func(count: u32) {
+    ///     if count == 0 {
+    ///         print!("Nothing to do");
+    ///         return;
+    ///     }
+    ///     print!("Moving on...");
+    /// }
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub REDUNDANT_ELSE,
+    pedantic,
+    "`else` branch that can be removed without changing semantics"
+}
+
+#[derive(Copy, Clone)]
+pub struct RedundantElse;
+
+impl LintPass for RedundantElse {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(REDUNDANT_ELSE)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for RedundantElse {
+    fn check_stmt(&mut self, cx: &LateContext<'a, 'tcx>, stmt: &'tcx Stmt) {
+        match stmt.node {
+            // the `;` after the `if` is removed with the `else` block
+            StmtKind::Semi(ref expr, _) => check_if(cx, expr, stmt.span.hi(), true),
+            StmtKind::Expr(ref expr, _) => check_if(cx, expr, expr.span.hi(), true),
+            StmtKind::Decl(..) => {},
+        }
+    }
+
+    fn check_block(&mut self, cx: &LateContext<'a, 'tcx>, block: &'tcx Block) {
+        if let Some(ref expr) = block.expr {
+            check_if(cx, expr, expr.span.hi(), false);
+        }
+    }
+}
+
+/// `end` is the end of the code replaced by the content of the `else` block, `needs_semi` is
+/// whether the final expression of the `else` block becomes a statement
+fn check_if(cx: &LateContext<'_, '_>, expr: &Expr, end: BytePos, needs_semi: bool) {
+    if in_macro(expr.span) {
+        return;
+    }
+    let (then, els) = match expr.node {
+        ExprKind::If(_, ref then, Some(ref els)) => (&**then, &**els),
+        ExprKind::Match(_, ref arms, MatchSource::IfLetDesugar { contains_else_clause: true }) => {
+            (&arms[0].body, &arms[1].body)
+        },
+        _ => return,
+    };
+    // `else if` can't be flattened
+    let else_block = match els.node {
+        ExprKind::Block(ref block, _) => block,
+        _ => return,
+    };
+    if in_macro(els.span) || !cx.tables.expr_ty(then).is_never() {
+        return;
+    }
+    let else_snip = match snippet_opt(cx, els.span) {
+        Some(snip) => snip,
+        None => return,
+    };
+
+    let indent = " ".repeat(cx.sess().source_map().lookup_char_pos(expr.span.lo()).col.0);
+    let mut body = reindent(&else_snip, &indent);
+    if needs_semi && else_block.expr.is_some() && !body.is_empty() {
+        body.push(';');
+    }
+    let span = Span::new(then.span.hi(), end, expr.span.ctxt());
+    span_lint_and_then(
+        cx,
+        REDUNDANT_ELSE,
+        els.span.with_lo(then.span.hi()),
+        "redundant else block",
+        |db| {
+            db.span_suggestion_with_applicability(
+                span,
+                "remove the `else` and move the contents of the `else` block",
+                body,
+                Applicability::MaybeIncorrect,
+            );
+        },
+    );
+}
+
+/// Removes the braces of a block snippet and indents its content with `indent`, each line
+/// starting with a newline
+fn reindent(block: &str, indent: &str) -> String {
+    let inner = block
+        .trim()
+        .trim_start_matches('{')
+        .trim_end_matches('}')
+        .trim_matches(|c| c == '\n' || c == '\r');
+    if inner.trim().is_empty() {
+        return String::new();
+    }
+    // the first line may follow the opening brace without indentation
+    let inner = if inner.contains('\n') {
+        inner.trim_end().to_owned()
+    } else {
+        inner.trim().to_owned()
+    };
+    trim_multiline(Cow::Owned(inner), false)
+        .lines()
+        .map(|line| {
+            if line.is_empty() {
+                "\n".to_owned()
+            } else {
+                format!("\n{}{}", indent, line)
+            }
+        })
+        .collect()
+}
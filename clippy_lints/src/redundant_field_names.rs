@@ -1,37 +1,50 @@
-use rustc::lint::{EarlyContext, EarlyLintPass, LintArray, LintPass};
+use rustc::lint::{EarlyContext, EarlyLintPass, LintArray, LintContext, LintPass};
 use rustc::{declare_lint, lint_array};
 use syntax::ast::*;
 use crate::utils::{span_lint_and_sugg};
+use crate::utils::msrvs::{self, MsrvStack, RustcVersion};
 
-/// **What it does:** Checks for fields in struct literals where shorthands
-/// could be used.
-///
-/// **Why is this bad?** If the field and variable names are the same,
-/// the field name is redundant.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// let bar: u8 = 123;
-///
-/// struct Foo {
-///     bar: u8,
-/// }
-///
-/// let foo = Foo{ bar: bar }
-/// ```
-/// the last line can be simplified to
-/// ```rust
-/// let foo = Foo{ bar }
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for fields in struct literals where shorthands
+    /// could be used.
+    ///
+    /// **Why is this bad?** If the field and variable names are the same,
+    /// the field name is redundant.
+    ///
+    /// **Known problems:** The shorthand needs Rust 1.17, the lint is disabled if the `msrv` is
+    /// older.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let bar: u8 = 123;
+    ///
+    /// struct Foo {
+    ///     bar: u8,
+    /// }
+    ///
+    /// let foo = Foo{ bar: bar }
+    /// ```
+    /// the last line can be simplified to
+    /// ```rust
+    /// let foo = Foo{ bar }
+    /// ```
+    #[clippy::version = "0.0.187"]
     pub REDUNDANT_FIELD_NAMES,
     style,
     "checks for fields in struct literals where shorthands could be used"
 }
 
-pub struct RedundantFieldNames;
+pub struct RedundantFieldNames {
+    msrv: MsrvStack,
+}
+
+impl RedundantFieldNames {
+    pub fn new(msrv: Option<RustcVersion>) -> Self {
+        Self {
+            msrv: MsrvStack::new(msrv),
+        }
+    }
+}
 
 impl LintPass for RedundantFieldNames {
     fn get_lints(&self) -> LintArray {
@@ -40,7 +53,18 @@ impl LintPass for RedundantFieldNames {
 }
 
 impl EarlyLintPass for RedundantFieldNames {
+    fn enter_lint_attrs(&mut self, cx: &EarlyContext<'_>, attrs: &[Attribute]) {
+        self.msrv.enter_lint_attrs(cx.sess(), attrs);
+    }
+
+    fn exit_lint_attrs(&mut self, _: &EarlyContext<'_>, attrs: &[Attribute]) {
+        self.msrv.exit_lint_attrs(attrs);
+    }
+
     fn check_expr(&mut self, cx: &EarlyContext<'_>, expr: &Expr) {
+        if !self.msrv.meets(msrvs::FIELD_INIT_SHORTHAND) {
+            return;
+        }
         if let ExprKind::Struct(_, ref fields, _) = expr.node {
             for field in fields {
                 if field.is_shorthand {
@@ -0,0 +1,134 @@
+use crate::utils::{in_macro, span_lint_and_then};
+use if_chain::if_chain;
+use rustc::hir::def::Def;
+use rustc::hir::intravisit::{walk_ty, NestedVisitorMap, Visitor};
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::ty::{self, TypeFoldable};
+use rustc::{declare_lint, lint_array};
+use rustc_errors::Applicability;
+use syntax::ast::{LitIntType, LitKind};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for type annotations on `let` bindings whose
+    /// initializer already has exactly this type on its own: a string, `bool`,
+    /// `char` or suffixed number literal, or a call to a function or method
+    /// whose return type doesn't depend on any generic parameter.
+    ///
+    /// **Why is this bad?** The annotation repeats the type the initializer
+    /// states anyway.
+    ///
+    /// **Known problems:** The annotation is sometimes considered documentation
+    /// of the type of a binding.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let name: String = String::new();
+    /// let len: usize = name.len();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let name = String::new();
+    /// let len = name.len();
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub REDUNDANT_TYPE_ANNOTATIONS,
+    style,
+    "type annotations on `let` bindings whose initializer already has this type"
+}
+
+#[derive(Copy, Clone)]
+pub struct RedundantTypeAnnotations;
+
+impl LintPass for RedundantTypeAnnotations {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(REDUNDANT_TYPE_ANNOTATIONS)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for RedundantTypeAnnotations {
+    fn check_local(&mut self, cx: &LateContext<'a, 'tcx>, local: &'tcx Local) {
+        if_chain! {
+            if !in_macro(local.span);
+            if let Some(ref ty) = local.ty;
+            if let Some(ref init) = local.init;
+            if !in_macro(init.span) && !has_infer(ty);
+            if let Some(init_ty) = self_determined_ty(cx, init);
+            let annotated_ty = cx.tables.pat_ty(&local.pat);
+            if cx.tcx.erase_regions(&annotated_ty) == cx.tcx.erase_regions(&init_ty);
+            then {
+                span_lint_and_then(
+                    cx,
+                    REDUNDANT_TYPE_ANNOTATIONS,
+                    ty.span,
+                    "redundant type annotation",
+                    |db| {
+                        db.span_suggestion_with_applicability(
+                            ty.span.with_lo(local.pat.span.hi()),
+                            "remove the type annotation",
+                            String::new(),
+                            Applicability::MachineApplicable,
+                        );
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// Returns the type of `init` if it doesn't depend on the type expected for it
+fn self_determined_ty<'tcx>(cx: &LateContext<'_, 'tcx>, init: &Expr) -> Option<ty::Ty<'tcx>> {
+    let def_id = match init.node {
+        ExprKind::Lit(ref lit) => {
+            // an unsuffixed number literal falls back to `i32` or `f64` without the annotation
+            return match lit.node {
+                LitKind::Str(..)
+                | LitKind::Bool(_)
+                | LitKind::Char(_)
+                | LitKind::Int(_, LitIntType::Signed(_))
+                | LitKind::Int(_, LitIntType::Unsigned(_))
+                | LitKind::Float(..) => Some(cx.tables.expr_ty(init)),
+                _ => None,
+            };
+        },
+        ExprKind::Call(ref func, _) => match func.node {
+            ExprKind::Path(ref qpath) => match cx.tables.qpath_def(qpath, func.hir_id) {
+                Def::Fn(def_id) | Def::Method(def_id) => def_id,
+                _ => return None,
+            },
+            _ => return None,
+        },
+        ExprKind::MethodCall(..) => cx.tables.type_dependent_defs().get(init.hir_id)?.def_id(),
+        _ => return None,
+    };
+    let sig = cx.tcx.erase_late_bound_regions(&cx.tcx.fn_sig(def_id));
+    let output = sig.output();
+    // the output of a generic function may be inferred from the annotation
+    if output.has_param_types() || output.has_projections() {
+        None
+    } else {
+        Some(output)
+    }
+}
+
+/// Checks whether the type contains a `_` placeholder
+fn has_infer(ty: &Ty) -> bool {
+    let mut visitor = InferVisitor(false);
+    visitor.visit_ty(ty);
+    visitor.0
+}
+
+struct InferVisitor(bool);
+
+impl<'tcx> Visitor<'tcx> for InferVisitor {
+    fn visit_ty(&mut self, ty: &'tcx Ty) {
+        if let TyKind::Infer = ty.node {
+            self.0 = true;
+        }
+        walk_ty(self, ty);
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::None
+    }
+}
@@ -0,0 +1,200 @@
+use crate::utils::{in_macro, match_def_path, opt_def_id, paths, snippet, span_lint_and_then};
+use if_chain::if_chain;
+use rustc::hir::intravisit::{walk_ty, NestedVisitorMap, Visitor};
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::{declare_lint, lint_array};
+use rustc_errors::Applicability;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for usage of `&Option<&T>` in function signatures,
+    /// fields and type aliases, and for function arguments of type `Option<&String>`
+    /// or `Option<&Vec<T>>`.
+    ///
+    /// **Why is this bad?** `&Option<&T>` is an unneeded extra level of reference:
+    /// `Option<&T>` is `Copy` and has the same size. Like for `&String` and `&Vec<T>`
+    /// arguments, `Option<&str>` and `Option<&[T]>` accept more types than
+    /// `Option<&String>` and `Option<&Vec<T>>`.
+    ///
+    /// **Known problems:** The callers have to be changed too, and the signature of a
+    /// public function is part of the interface of the crate.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// fn foo(bar: &Option<&u32>, baz: Option<&String>) {}
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn foo(bar: Option<&u32>, baz: Option<&str>) {}
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub REF_OPTION_REF,
+    pedantic,
+    "usage of `&Option<&T>`, or of `Option<&String>` and `Option<&Vec<T>>` arguments"
+}
+
+#[derive(Copy, Clone)]
+pub struct RefOptionRef;
+
+impl LintPass for RefOptionRef {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(REF_OPTION_REF)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for RefOptionRef {
+    fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx Item) {
+        match item.node {
+            ItemKind::Fn(ref decl, ..) => check_fn_decl(cx, decl),
+            ItemKind::Ty(ref ty, _) | ItemKind::Const(ref ty, _) | ItemKind::Static(ref ty, ..) => check_ty(cx, ty),
+            _ => (),
+        }
+    }
+
+    fn check_impl_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx ImplItem) {
+        if let Some(Node::Item(parent)) = cx.tcx.hir.find(cx.tcx.hir.get_parent(item.id)) {
+            if let ItemKind::Impl(_, _, _, _, Some(_), _, _) = parent.node {
+                return; // the signature is imposed by the trait
+            }
+        }
+        match item.node {
+            ImplItemKind::Method(ref sig, _) => check_fn_decl(cx, &sig.decl),
+            ImplItemKind::Const(ref ty, _) | ImplItemKind::Type(ref ty) => check_ty(cx, ty),
+            _ => (),
+        }
+    }
+
+    fn check_trait_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx TraitItem) {
+        match item.node {
+            TraitItemKind::Method(ref sig, _) => check_fn_decl(cx, &sig.decl),
+            TraitItemKind::Const(ref ty, _) | TraitItemKind::Type(_, Some(ref ty)) => check_ty(cx, ty),
+            _ => (),
+        }
+    }
+
+    fn check_struct_field(&mut self, cx: &LateContext<'a, 'tcx>, field: &'tcx StructField) {
+        check_ty(cx, &field.ty);
+    }
+}
+
+fn check_fn_decl(cx: &LateContext<'_, '_>, decl: &FnDecl) {
+    for input in &decl.inputs {
+        check_owned_arg(cx, input);
+        check_ty(cx, input);
+    }
+    if let FunctionRetTy::Return(ref ty) = decl.output {
+        check_ty(cx, ty);
+    }
+}
+
+/// Lints the `&Option<&T>`s in `ty`
+fn check_ty(cx: &LateContext<'_, '_>, ty: &Ty) {
+    RefOptionRefVisitor { cx }.visit_ty(ty);
+}
+
+struct RefOptionRefVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+}
+
+impl<'a, 'tcx, 'v> Visitor<'v> for RefOptionRefVisitor<'a, 'tcx> {
+    fn visit_ty(&mut self, ty: &'v Ty) {
+        if_chain! {
+            if !in_macro(ty.span);
+            if let TyKind::Rptr(_, MutTy { ty: ref option, mutbl: MutImmutable }) = ty.node;
+            if let Some(inner) = option_arg(self.cx, option);
+            if let TyKind::Rptr(..) = inner.node;
+            then {
+                span_lint_and_then(
+                    self.cx,
+                    REF_OPTION_REF,
+                    ty.span,
+                    "since `&` implements the `Copy` trait, `&Option<&T>` can be simplified to `Option<&T>`",
+                    |db| {
+                        db.span_suggestion_with_applicability(
+                            ty.span,
+                            "try",
+                            snippet(self.cx, option.span, "..").into_owned(),
+                            Applicability::MaybeIncorrect,
+                        );
+                    },
+                );
+            }
+        }
+        walk_ty(self, ty);
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'v> {
+        NestedVisitorMap::None
+    }
+}
+
+/// Lints an argument of type `Option<&String>` or `Option<&Vec<T>>`
+fn check_owned_arg(cx: &LateContext<'_, '_>, arg: &Ty) {
+    if_chain! {
+        if !in_macro(arg.span);
+        if let Some(inner) = option_arg(cx, arg);
+        if let TyKind::Rptr(_, MutTy { ty: ref owned, mutbl: MutImmutable }) = inner.node;
+        if let TyKind::Path(QPath::Resolved(None, ref path)) = owned.node;
+        if let Some(def_id) = opt_def_id(path.def);
+        then {
+            let (owned_name, borrowed_name, borrowed) = if match_def_path(cx.tcx, def_id, &paths::STRING) {
+                ("String", "str", "str".to_owned())
+            } else if match_def_path(cx.tcx, def_id, &paths::VEC) {
+                match type_args(path).as_slice() {
+                    [elem] => ("Vec<T>", "[T]", format!("[{}]", snippet(cx, elem.span, ".."))),
+                    _ => return,
+                }
+            } else {
+                return;
+            };
+            span_lint_and_then(
+                cx,
+                REF_OPTION_REF,
+                arg.span,
+                &format!(
+                    "writing `Option<&{}>` instead of `Option<&{}>` requires the callers to own a `{}`",
+                    owned_name, borrowed_name, owned_name
+                ),
+                |db| {
+                    db.span_suggestion_with_applicability(
+                        arg.span,
+                        "change this to",
+                        format!(
+                            "{}{}{}>",
+                            snippet(cx, arg.span.until(inner.span), "Option<"),
+                            snippet(cx, inner.span.until(owned.span), "&"),
+                            borrowed
+                        ),
+                        Applicability::MaybeIncorrect,
+                    );
+                },
+            );
+        }
+    }
+}
+
+/// Returns the type parameter of `ty` if it's an `Option`
+fn option_arg<'a>(cx: &LateContext<'_, '_>, ty: &'a Ty) -> Option<&'a Ty> {
+    if_chain! {
+        if let TyKind::Path(QPath::Resolved(None, ref path)) = ty.node;
+        if let Some(def_id) = opt_def_id(path.def);
+        if match_def_path(cx.tcx, def_id, &paths::OPTION);
+        if let [arg] = type_args(path).as_slice();
+        then {
+            return Some(*arg);
+        }
+    }
+    None
+}
+
+fn type_args(path: &Path) -> Vec<&Ty> {
+    path.segments.last().and_then(|seg| seg.args.as_ref()).map_or_else(Vec::new, |args| {
+        args.args
+            .iter()
+            .filter_map(|arg| match arg {
+                GenericArg::Type(ty) => Some(ty),
+                GenericArg::Lifetime(_) => None,
+            })
+            .collect()
+    })
+}
@@ -4,62 +4,67 @@ use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
 use rustc::{declare_lint, lint_array};
 use if_chain::if_chain;
 use std::collections::HashSet;
+use std::fmt::Display;
 use syntax::ast::{LitKind, NodeId, StrStyle};
 use syntax::source_map::{BytePos, Span};
-use crate::utils::{is_expn_of, match_def_path, match_type, opt_def_id, paths, span_help_and_lint, span_lint};
+use crate::utils::{is_expn_of, match_def_path, match_type, opt_def_id, paths, snippet_opt, span_help_and_lint, span_lint};
 use crate::consts::{constant, Constant};
 
-/// **What it does:** Checks [regex](https://crates.io/crates/regex) creation
-/// (with `Regex::new`,`RegexBuilder::new` or `RegexSet::new`) for correct
-/// regex syntax.
-///
-/// **Why is this bad?** This will lead to a runtime panic.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// Regex::new("|")
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks [regex](https://crates.io/crates/regex) creation
+    /// (with `Regex::new`,`RegexBuilder::new` or `RegexSet::new`) for correct
+    /// regex syntax.
+    ///
+    /// **Why is this bad?** This will lead to a runtime panic.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// Regex::new("|")
+    /// ```
+    #[clippy::version = "pre 0.0.213"]
     pub INVALID_REGEX,
     correctness,
     "invalid regular expressions"
 }
 
-/// **What it does:** Checks for trivial [regex](https://crates.io/crates/regex)
-/// creation (with `Regex::new`, `RegexBuilder::new` or `RegexSet::new`).
-///
-/// **Why is this bad?** Matching the regex can likely be replaced by `==` or
-/// `str::starts_with`, `str::ends_with` or `std::contains` or other `str`
-/// methods.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// Regex::new("^foobar")
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for trivial [regex](https://crates.io/crates/regex)
+    /// creation (with `Regex::new`, `RegexBuilder::new` or `RegexSet::new`).
+    ///
+    /// **Why is this bad?** Matching the regex can likely be replaced by `==` or
+    /// `str::starts_with`, `str::ends_with` or `str::contains` or other `str`
+    /// methods, which do not need to compile the regex first.
+    ///
+    /// **Known problems:** The options of a `RegexBuilder`, e.g. case insensitivity,
+    /// are not taken into account.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// Regex::new("^foobar")
+    /// ```
+    #[clippy::version = "pre 0.0.213"]
     pub TRIVIAL_REGEX,
     style,
     "trivial regular expressions"
 }
 
-/// **What it does:** Checks for usage of `regex!(_)` which (as of now) is
-/// usually slower than `Regex::new(_)` unless called in a loop (which is a bad
-/// idea anyway).
-///
-/// **Why is this bad?** Performance, at least for now. The macro version is
-/// likely to catch up long-term, but for now the dynamic version is faster.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// regex!("foo|bar")
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for usage of `regex!(_)` which (as of now) is
+    /// usually slower than `Regex::new(_)` unless called in a loop (which is a bad
+    /// idea anyway).
+    ///
+    /// **Why is this bad?** Performance, at least for now. The macro version is
+    /// likely to catch up long-term, but for now the dynamic version is faster.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// regex!("foo|bar")
+    /// ```
+    #[clippy::version = "pre 0.0.213"]
     pub REGEX_MACRO,
     style,
     "use of `regex!(_)` instead of `Regex::new(_)`"
@@ -131,12 +136,79 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
     }
 }
 
-fn str_span(base: Span, c: regex_syntax::ast::Span, offset: u16) -> Span {
-    let offset = u32::from(offset);
-    let end = base.lo() + BytePos(c.end.offset as u32 + offset);
-    let start = base.lo() + BytePos(c.start.offset as u32 + offset);
-    assert!(start <= end);
-    Span::new(start, end, base.ctxt())
+/// Returns the span of the part `c` of the regex in the string literal `lit`, or the span of the
+/// whole literal if its source cannot be matched with the regex, e.g. if it comes from a macro.
+fn str_span<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, lit: Span, style: StrStyle, c: regex_syntax::ast::Span) -> Span {
+    let (prefix, suffix) = if let StrStyle::Raw(n) = style {
+        (2 + usize::from(n), 1 + usize::from(n))
+    } else {
+        (1, 1)
+    };
+    if_chain! {
+        if let Some(snippet) = snippet_opt(cx, lit);
+        if let Some(content) = snippet.get(prefix..snippet.len().saturating_sub(suffix));
+        if let Some(start) = source_offset(content, style, c.start.offset);
+        if let Some(end) = source_offset(content, style, c.end.offset);
+        if start <= end;
+        then {
+            let start = lit.lo() + BytePos((prefix + start) as u32);
+            let end = lit.lo() + BytePos((prefix + end) as u32);
+            return Span::new(start, end, lit.ctxt());
+        }
+    }
+    lit
+}
+
+/// Maps the byte offset `offset` of the value of a string literal to the offset in its source
+/// `content` (without the quotes). Both are different if the literal contains escapes, e.g.
+/// `"\\d"` is the regex `\d`.
+fn source_offset(content: &str, style: StrStyle, offset: usize) -> Option<usize> {
+    if let StrStyle::Raw(_) = style {
+        return if offset <= content.len() { Some(offset) } else { None };
+    }
+
+    let mut value_pos = 0;
+    let mut chars = content.char_indices().peekable();
+    while let Some((source_pos, c)) = chars.next() {
+        if value_pos >= offset {
+            return if value_pos == offset { Some(source_pos) } else { None };
+        }
+        if c != '\\' {
+            value_pos += c.len_utf8();
+            continue;
+        }
+        match chars.next()?.1 {
+            'x' => {
+                chars.next()?;
+                chars.next()?;
+                value_pos += 1;
+            },
+            'u' => {
+                let mut code = String::new();
+                for (_, c) in &mut chars {
+                    match c {
+                        '{' | '_' => {},
+                        '}' => break,
+                        c => code.push(c),
+                    }
+                }
+                value_pos += std::char::from_u32(u32::from_str_radix(&code, 16).ok()?)?.len_utf8();
+            },
+            // an escaped newline skips the leading whitespace of the next line
+            '\n' | '\r' => {
+                while chars.peek().map_or(false, |&(_, c)| c.is_whitespace()) {
+                    chars.next();
+                }
+            },
+            _ => value_pos += 1,
+        }
+    }
+
+    if value_pos == offset {
+        Some(content.len())
+    } else {
+        None
+    }
 }
 
 fn const_str<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, e: &'tcx Expr) -> Option<String> {
@@ -196,79 +268,44 @@ fn check_regex<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr, utf8: boo
 
     if let ExprKind::Lit(ref lit) = expr.node {
         if let LitKind::Str(ref r, style) = lit.node {
-            let r = &r.as_str();
-            let offset = if let StrStyle::Raw(n) = style { 2 + n } else { 1 };
-            match parser.parse(r) {
-                Ok(r) => if let Some(repl) = is_trivial_regex(&r) {
-                    span_help_and_lint(
-                        cx,
-                        TRIVIAL_REGEX,
-                        expr.span,
-                        "trivial regex",
-                        repl,
-                    );
-                },
-                Err(regex_syntax::Error::Parse(e)) => {
-                    span_lint(
-                        cx,
-                        INVALID_REGEX,
-                        str_span(expr.span, *e.span(), offset),
-                        &format!("regex syntax error: {}", e.kind()),
-                    );
-                },
-                Err(regex_syntax::Error::Translate(e)) => {
-                    span_lint(
-                        cx,
-                        INVALID_REGEX,
-                        str_span(expr.span, *e.span(), offset),
-                        &format!("regex syntax error: {}", e.kind()),
-                    );
-                },
-                Err(e) => {
-                    span_lint(
-                        cx,
-                        INVALID_REGEX,
-                        expr.span,
-                        &format!("regex syntax error: {}", e),
-                    );
-                },
-            }
+            lint_parsed_regex(cx, expr, parser.parse(&r.as_str()), |c, kind| {
+                (str_span(cx, expr.span, style, c), format!("regex syntax error: {}", kind))
+            });
         }
     } else if let Some(r) = const_str(cx, expr) {
-        match parser.parse(&r) {
-            Ok(r) => if let Some(repl) = is_trivial_regex(&r) {
-                span_help_and_lint(
-                    cx,
-                    TRIVIAL_REGEX,
-                    expr.span,
-                    "trivial regex",
-                    repl,
-                );
-            },
-            Err(regex_syntax::Error::Parse(e)) => {
-                span_lint(
-                    cx,
-                    INVALID_REGEX,
-                    expr.span,
-                    &format!("regex syntax error on position {}: {}", e.span().start.offset, e.kind()),
-                );
-            },
-            Err(regex_syntax::Error::Translate(e)) => {
-                span_lint(
-                    cx,
-                    INVALID_REGEX,
-                    expr.span,
-                    &format!("regex syntax error on position {}: {}", e.span().start.offset, e.kind()),
-                );
-            },
-            Err(e) => {
-                span_lint(
-                    cx,
-                    INVALID_REGEX,
-                    expr.span,
-                    &format!("regex syntax error: {}", e),
-                );
-            },
-        }
+        lint_parsed_regex(cx, expr, parser.parse(&r), |c, kind| {
+            (
+                expr.span,
+                format!("regex syntax error on position {}: {}", c.start.offset, kind),
+            )
+        });
+    }
+}
+
+/// Lints the regex `expr` once parsed, be it a literal or a constant. `error` returns where and
+/// how to report a syntax error at the given part of the regex.
+fn lint_parsed_regex<'a, 'tcx, F>(
+    cx: &LateContext<'a, 'tcx>,
+    expr: &'tcx Expr,
+    parsed: Result<regex_syntax::hir::Hir, regex_syntax::Error>,
+    error: F,
+) where
+    F: Fn(regex_syntax::ast::Span, &dyn Display) -> (Span, String),
+{
+    match parsed {
+        Ok(r) => if let Some(repl) = is_trivial_regex(&r) {
+            span_help_and_lint(cx, TRIVIAL_REGEX, expr.span, "trivial regex", repl);
+        },
+        Err(regex_syntax::Error::Parse(e)) => {
+            let (span, msg) = error(*e.span(), e.kind());
+            span_lint(cx, INVALID_REGEX, span, &msg);
+        },
+        Err(regex_syntax::Error::Translate(e)) => {
+            let (span, msg) = error(*e.span(), e.kind());
+            span_lint(cx, INVALID_REGEX, span, &msg);
+        },
+        Err(e) => {
+            span_lint(cx, INVALID_REGEX, expr.span, &format!("regex syntax error: {}", e));
+        },
     }
 }
@@ -0,0 +1,180 @@
+use crate::utils::attrs::get_attr;
+use crate::utils::{get_parent_expr, in_macro, match_def_path, paths, span_lint_and_then};
+use rustc::hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::ty::adjustment::Adjust;
+use rustc::ty::{self, Ty};
+use rustc::{declare_lint, lint_array};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `match`, `if let` and `while let` scrutinees
+    /// creating a temporary whose `Drop` is significant, like a `MutexGuard` or a
+    /// `RefCell` borrow, e.g. `match mutex.lock().unwrap().len() { .. }`.
+    ///
+    /// The types whose `Drop` is significant are the lock guards and the `RefCell`
+    /// borrows, the types with a `#[clippy::has_significant_drop]` attribute and the
+    /// ones listed in the `significant-drop-types` configuration value, and the types
+    /// with one of them as a type parameter, like `Option<MutexGuard<'_, T>>`.
+    ///
+    /// **Why is this bad?** The temporaries of a scrutinee live until the end of the
+    /// whole `match`, the lock or the borrow is held in every arm: locking the mutex
+    /// again or borrowing the `RefCell` mutably in an arm deadlocks or panics.
+    ///
+    /// **Known problems:** The `Drop` of a type may be significant without the lint
+    /// knowing it, and the other way round.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// match mutex.lock().unwrap().len() {
+    ///     0 => mutex.lock().unwrap().push(1),
+    ///     _ => (),
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// let len = mutex.lock().unwrap().len();
+    /// match len {
+    ///     0 => mutex.lock().unwrap().push(1),
+    ///     _ => (),
+    /// }
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub SIGNIFICANT_DROP_IN_SCRUTINEE,
+    nursery,
+    "temporary with significant `Drop` in a `match` scrutinee, alive in all the arms"
+}
+
+/// The types whose `Drop` is known to be significant
+const KNOWN_TYPES: [&[&str]; 5] = [
+    &paths::CELL_REF,
+    &paths::CELL_REF_MUT,
+    &paths::MUTEX_GUARD,
+    &paths::RWLOCK_READ_GUARD,
+    &paths::RWLOCK_WRITE_GUARD,
+];
+
+pub struct SignificantDropInScrutinee {
+    types: Vec<String>,
+}
+
+impl SignificantDropInScrutinee {
+    pub fn new(types: Vec<String>) -> Self {
+        Self { types }
+    }
+
+    fn has_significant_drop(&self, cx: &LateContext<'_, '_>, ty: Ty<'_>) -> bool {
+        match ty.sty {
+            ty::Adt(def, substs) => {
+                KNOWN_TYPES.iter().any(|path| match_def_path(cx.tcx, def.did, path))
+                    || self
+                        .types
+                        .iter()
+                        .any(|path| match_def_path(cx.tcx, def.did, &path.split("::").collect::<Vec<_>>()))
+                    || get_attr(&cx.tcx.get_attrs(def.did), "has_significant_drop").next().is_some()
+                    || substs.types().any(|ty| self.has_significant_drop(cx, ty))
+            },
+            ty::Tuple(tys) => tys.iter().any(|&ty| self.has_significant_drop(cx, ty)),
+            ty::Array(ty, _) => self.has_significant_drop(cx, ty),
+            _ => false,
+        }
+    }
+}
+
+impl LintPass for SignificantDropInScrutinee {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(SIGNIFICANT_DROP_IN_SCRUTINEE)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for SignificantDropInScrutinee {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if in_macro(expr.span) {
+            return;
+        }
+        if let ExprKind::Match(ref scrutinee, _, source) = expr.node {
+            let kind = match source {
+                MatchSource::Normal => "match",
+                MatchSource::IfLetDesugar { .. } => "if let",
+                MatchSource::WhileLetDesugar => "while let",
+                _ => return,
+            };
+            let mut visitor = TemporaryVisitor {
+                cx,
+                pass: self,
+                scrutinee: &**scrutinee,
+                found: None,
+            };
+            visitor.visit_expr(scrutinee);
+            if let Some(temporary) = visitor.found {
+                span_lint_and_then(
+                    cx,
+                    SIGNIFICANT_DROP_IN_SCRUTINEE,
+                    temporary.span,
+                    &format!(
+                        "temporary with significant `Drop` in `{}` scrutinee will live until the end of the `{}` \
+                         expression",
+                        kind, kind
+                    ),
+                    |db| {
+                        db.note("this might lead to deadlocks or other unexpected behavior");
+                        db.help(&format!("try moving the temporary above the `{}` into a `let` binding", kind));
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// Finds the first temporary with a significant `Drop` in a scrutinee
+struct TemporaryVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    pass: &'a SignificantDropInScrutinee,
+    scrutinee: &'tcx Expr,
+    found: Option<&'tcx Expr>,
+}
+
+impl<'a, 'tcx> TemporaryVisitor<'a, 'tcx> {
+    /// Checks whether the value of `expr` is only borrowed, which makes a temporary of it
+    fn is_temporary(&self, expr: &Expr) -> bool {
+        match expr.node {
+            ExprKind::Call(..) | ExprKind::MethodCall(..) => (),
+            _ => return false,
+        }
+        if expr.id == self.scrutinee.id {
+            // the value is moved into the scrutinee
+            return false;
+        }
+        let is_autoref = self.cx.tables.expr_adjustments(expr).iter().any(|adjustment| match adjustment.kind {
+            Adjust::Deref(_) | Adjust::Borrow(_) => true,
+            _ => false,
+        });
+        if is_autoref {
+            return true;
+        }
+        get_parent_expr(self.cx, expr).map_or(false, |parent| match parent.node {
+            ExprKind::Field(ref base, _) | ExprKind::Index(ref base, _) | ExprKind::Unary(UnDeref, ref base) => {
+                base.id == expr.id
+            },
+            ExprKind::AddrOf(..) => true,
+            _ => false,
+        })
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for TemporaryVisitor<'a, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        if self.found.is_some() {
+            return;
+        }
+        if self.is_temporary(expr) && self.pass.has_significant_drop(self.cx, self.cx.tables.expr_ty(expr)) {
+            self.found = Some(expr);
+            return;
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::None
+    }
+}
@@ -0,0 +1,144 @@
+use crate::utils::{in_macro, match_def_path, opt_def_id, paths, same_tys, span_help_and_lint};
+use if_chain::if_chain;
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::ty::{self, Ty};
+use rustc::{declare_lint, lint_array};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for calls to functions taking a count of elements, like
+    /// `ptr::copy_nonoverlapping` or `slice::from_raw_parts`, with a count computed with
+    /// `size_of::<T>()` or `size_of_val`, where `T` is the type of the elements.
+    ///
+    /// **Why is this bad?** These functions multiply the count by the size of the type
+    /// themselves: passing a count of bytes reads or writes past the end of the buffer.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// const SIZE: usize = 128;
+    /// let x = [2u8; SIZE];
+    /// let mut y = [2u8; SIZE];
+    /// unsafe { copy_nonoverlapping(x.as_ptr(), y.as_mut_ptr(), size_of::<u8>() * SIZE) };
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// unsafe { copy_nonoverlapping(x.as_ptr(), y.as_mut_ptr(), SIZE) };
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub SIZE_OF_IN_ELEMENT_COUNT,
+    correctness,
+    "using `size_of::<T>` or `size_of_val::<T>` where a count of elements of `T` is expected"
+}
+
+#[derive(Copy, Clone)]
+pub struct SizeOfInElementCount;
+
+impl LintPass for SizeOfInElementCount {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(SIZE_OF_IN_ELEMENT_COUNT)
+    }
+}
+
+/// The functions taking a count of elements of their first type parameter, with the index of the
+/// count argument
+const FUNCTIONS: [(&[&str], usize); 7] = [
+    (&paths::PTR_COPY, 2),
+    (&paths::PTR_COPY_NONOVERLAPPING, 2),
+    (&paths::PTR_WRITE_BYTES, 2),
+    (&paths::PTR_SWAP_NONOVERLAPPING, 2),
+    (&paths::SLICE_FROM_RAW_PARTS, 1),
+    (&paths::SLICE_FROM_RAW_PARTS_MUT, 1),
+    (&paths::VEC_FROM_RAW_PARTS, 1),
+];
+
+/// The methods of raw pointers taking a count of elements of the pointee, with the index of the
+/// count argument, the receiver being the argument 0
+const METHODS: [(&str, usize); 11] = [
+    ("copy_to", 2),
+    ("copy_to_nonoverlapping", 2),
+    ("copy_from", 2),
+    ("copy_from_nonoverlapping", 2),
+    ("write_bytes", 2),
+    ("add", 1),
+    ("offset", 1),
+    ("sub", 1),
+    ("wrapping_add", 1),
+    ("wrapping_offset", 1),
+    ("wrapping_sub", 1),
+];
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for SizeOfInElementCount {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if in_macro(expr.span) {
+            return;
+        }
+        if let Some((pointee_ty, count)) = element_count(cx, expr) {
+            if let Some(size_of_ty) = size_of_ty(cx, count) {
+                if same_tys(cx, pointee_ty, size_of_ty) {
+                    span_help_and_lint(
+                        cx,
+                        SIZE_OF_IN_ELEMENT_COUNT,
+                        count.span,
+                        "found a count of bytes instead of a count of elements of `T`",
+                        "use a count of elements instead of a count of bytes, it already gets multiplied by the \
+                         size of the type",
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Returns the type of the elements and the count argument of a call taking a count of elements
+fn element_count<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) -> Option<(Ty<'tcx>, &'tcx Expr)> {
+    match expr.node {
+        ExprKind::Call(ref func, ref args) => {
+            if let ExprKind::Path(ref qpath) = func.node {
+                let def_id = opt_def_id(cx.tables.qpath_def(qpath, func.hir_id))?;
+                let &(_, index) = FUNCTIONS
+                    .iter()
+                    .find(|&&(path, _)| match_def_path(cx.tcx, def_id, path))?;
+                let substs = cx.tables.node_substs(func.hir_id);
+                return Some((substs.type_at(0), args.get(index)?));
+            }
+            None
+        },
+        ExprKind::MethodCall(ref path, _, ref args) => {
+            let &(_, index) = METHODS.iter().find(|&&(name, _)| path.ident.name == name)?;
+            match cx.tables.expr_ty(&args[0]).sty {
+                ty::RawPtr(ty::TypeAndMut { ty, .. }) => Some((ty, args.get(index)?)),
+                _ => None,
+            }
+        },
+        _ => None,
+    }
+}
+
+/// Returns `T` if `expr` is `size_of::<T>()` or `size_of_val::<T>(_)`, or a product or quotient
+/// of it, possibly cast
+fn size_of_ty<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) -> Option<Ty<'tcx>> {
+    match expr.node {
+        ExprKind::Call(ref func, _) => {
+            if_chain! {
+                if let ExprKind::Path(ref qpath) = func.node;
+                if let Some(def_id) = opt_def_id(cx.tables.qpath_def(qpath, func.hir_id));
+                if match_def_path(cx.tcx, def_id, &paths::MEM_SIZE_OF)
+                    || match_def_path(cx.tcx, def_id, &paths::MEM_SIZE_OF_VAL);
+                then {
+                    return Some(cx.tables.node_substs(func.hir_id).type_at(0));
+                }
+            }
+            None
+        },
+        ExprKind::Binary(op, ref left, ref right) => match op.node {
+            BinOpKind::Mul => size_of_ty(cx, left).or_else(|| size_of_ty(cx, right)),
+            // `size_of::<T>() / n` is still a count of bytes, not `n / size_of::<T>()`
+            BinOpKind::Div => size_of_ty(cx, left),
+            _ => None,
+        },
+        ExprKind::Cast(ref inner, _) => size_of_ty(cx, inner),
+        _ => None,
+    }
+}
@@ -0,0 +1,213 @@
+use crate::consts::{constant, Constant};
+use crate::utils::{higher, match_def_path, match_qpath, opt_def_id, paths, snippet, span_lint_and_then, SpanlessEq};
+use if_chain::if_chain;
+use rustc::hir::def::Def;
+use rustc::hir::intravisit::{walk_expr, walk_stmt, NestedVisitorMap, Visitor};
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::{declare_lint, lint_array};
+use rustc_errors::Applicability;
+use syntax::ast::{NodeId, RangeLimits};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for vectors created with `Vec::with_capacity(len)`
+    /// and then filled with zeros up to `len`, with `resize`, `extend` or a loop of
+    /// `push`.
+    ///
+    /// **Why is this bad?** `vec![0; len]` is shorter and can use a zeroed
+    /// allocation, which is much faster than writing the zeros one by one.
+    ///
+    /// **Known problems:** Only the first use of the vector after its creation is
+    /// checked, and only integer zeros are filled.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// let mut vec1 = Vec::with_capacity(len);
+    /// vec1.resize(len, 0);
+    ///
+    /// let mut vec2 = Vec::with_capacity(len);
+    /// vec2.extend(std::iter::repeat(0).take(len));
+    /// ```
+    /// Could be written:
+    /// ```rust,ignore
+    /// let vec1 = vec![0; len];
+    /// let vec2 = vec![0; len];
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub SLOW_VECTOR_INITIALIZATION,
+    perf,
+    "slow vector initialization"
+}
+
+#[derive(Copy, Clone)]
+pub struct SlowVectorInit;
+
+impl LintPass for SlowVectorInit {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(SLOW_VECTOR_INITIALIZATION)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for SlowVectorInit {
+    fn check_block(&mut self, cx: &LateContext<'a, 'tcx>, block: &'tcx Block) {
+        for (i, stmt) in block.stmts.iter().enumerate() {
+            if_chain! {
+                if let StmtKind::Decl(ref decl, _) = stmt.node;
+                if let DeclKind::Local(ref local) = decl.node;
+                if let PatKind::Binding(BindingAnnotation::Mutable, id, _, None) = local.pat.node;
+                if let Some(ref init) = local.init;
+                if let Some(len) = vec_with_capacity(cx, init);
+                if let Some(fill) = first_use(cx, id, &block.stmts[i + 1..], block.expr.as_ref().map(|e| &**e));
+                if is_zero_fill(cx, id, len, fill);
+                then {
+                    span_lint_and_then(
+                        cx,
+                        SLOW_VECTOR_INITIALIZATION,
+                        fill.span,
+                        "slow zero-filling initialization",
+                        |db| {
+                            db.span_suggestion_with_applicability(
+                                init.span,
+                                "consider replacing this with",
+                                format!("vec![0; {}]", snippet(cx, len.span, "len")),
+                                Applicability::Unspecified,
+                            );
+                        },
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Returns the capacity of a `Vec::with_capacity(len)` call
+fn vec_with_capacity<'e>(cx: &LateContext<'_, '_>, expr: &'e Expr) -> Option<&'e Expr> {
+    if_chain! {
+        if let ExprKind::Call(ref func, ref args) = expr.node;
+        if let ExprKind::Path(ref qpath) = func.node;
+        if let Some(def_id) = opt_def_id(cx.tables.qpath_def(qpath, func.hir_id));
+        if match_def_path(cx.tcx, def_id, &paths::VEC_WITH_CAPACITY);
+        then {
+            return Some(&args[0]);
+        }
+    }
+    None
+}
+
+/// Returns the first expression using the local `id` in the statements, or in the final
+/// expression of the block
+fn first_use<'a, 'tcx: 'a>(
+    cx: &LateContext<'a, 'tcx>,
+    id: NodeId,
+    stmts: &'tcx [Stmt],
+    expr: Option<&'tcx Expr>,
+) -> Option<&'tcx Expr> {
+    for stmt in stmts {
+        let mut visitor = UsedVisitor { cx, id, used: false };
+        walk_stmt(&mut visitor, stmt);
+        if visitor.used {
+            return match stmt.node {
+                StmtKind::Expr(ref expr, _) | StmtKind::Semi(ref expr, _) => Some(expr),
+                StmtKind::Decl(..) => None,
+            };
+        }
+    }
+    expr
+}
+
+/// Checks for `vec.resize(len, 0)`, `vec.extend(repeat(0).take(len))` or
+/// `for _ in 0..len { vec.push(0); }`
+fn is_zero_fill(cx: &LateContext<'_, '_>, id: NodeId, len: &Expr, expr: &Expr) -> bool {
+    if let Some((_, arg, body)) = higher::for_loop(expr) {
+        return is_zero_push_loop(cx, id, len, arg, body);
+    }
+
+    if let ExprKind::MethodCall(ref path, _, ref args) = expr.node {
+        if !is_local(cx, id, &args[0]) {
+            return false;
+        }
+        if path.ident.name == "resize" && args.len() == 3 {
+            return SpanlessEq::new(cx).eq_expr(len, &args[1]) && is_zero(cx, &args[2]);
+        }
+        if_chain! {
+            if path.ident.name == "extend" && args.len() == 2;
+            if let ExprKind::MethodCall(ref take, _, ref take_args) = args[1].node;
+            if take.ident.name == "take";
+            if let ExprKind::Call(ref func, ref repeat_args) = take_args[0].node;
+            if let ExprKind::Path(ref qpath) = func.node;
+            if match_qpath(qpath, &paths::REPEAT);
+            then {
+                return is_zero(cx, &repeat_args[0]) && SpanlessEq::new(cx).eq_expr(len, &take_args[1]);
+            }
+        }
+    }
+    false
+}
+
+fn is_zero_push_loop(cx: &LateContext<'_, '_>, id: NodeId, len: &Expr, arg: &Expr, body: &Expr) -> bool {
+    if_chain! {
+        if let Some(higher::Range { start: Some(start), end: Some(end), limits: RangeLimits::HalfOpen }) =
+            higher::range(cx, arg);
+        if is_zero(cx, start);
+        if SpanlessEq::new(cx).eq_expr(len, end);
+        if let ExprKind::Block(ref block, _) = body.node;
+        if let Some(push) = single_expr(block);
+        if let ExprKind::MethodCall(ref path, _, ref args) = push.node;
+        if path.ident.name == "push" && is_local(cx, id, &args[0]);
+        then {
+            return is_zero(cx, &args[1]);
+        }
+    }
+    false
+}
+
+/// The only statement or expression of a block
+fn single_expr(block: &Block) -> Option<&Expr> {
+    match (&block.stmts[..], &block.expr) {
+        ([], &Some(ref expr)) => Some(expr),
+        ([ref stmt], &None) => match stmt.node {
+            StmtKind::Expr(ref expr, _) | StmtKind::Semi(ref expr, _) => Some(expr),
+            StmtKind::Decl(..) => None,
+        },
+        _ => None,
+    }
+}
+
+fn is_zero(cx: &LateContext<'_, '_>, expr: &Expr) -> bool {
+    match constant(cx, cx.tables, expr) {
+        Some((Constant::Int(0), _)) => true,
+        _ => false,
+    }
+}
+
+fn is_local(cx: &LateContext<'_, '_>, id: NodeId, expr: &Expr) -> bool {
+    if let ExprKind::Path(ref qpath) = expr.node {
+        if let Def::Local(local_id) = cx.tables.qpath_def(qpath, expr.hir_id) {
+            return local_id == id;
+        }
+    }
+    false
+}
+
+struct UsedVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    id: NodeId,
+    used: bool,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for UsedVisitor<'a, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        if self.used {
+            return;
+        }
+        if is_local(self.cx, self.id, expr) {
+            self.used = true;
+            return;
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::None
+    }
+}
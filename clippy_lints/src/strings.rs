@@ -1,72 +1,106 @@
+use if_chain::if_chain;
 use rustc::hir::*;
 use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::ty;
 use rustc::{declare_lint, lint_array};
 use syntax::source_map::Spanned;
-use crate::utils::SpanlessEq;
-use crate::utils::{get_parent_expr, is_allowed, match_type, paths, span_lint, span_lint_and_sugg, walk_ptrs_ty};
+use crate::utils::{higher, SpanlessEq};
+use crate::utils::{
+    get_parent_expr, in_macro, is_allowed, match_type, paths, span_help_and_lint, span_lint, span_lint_and_sugg,
+    walk_ptrs_ty,
+};
 
-/// **What it does:** Checks for string appends of the form `x = x + y` (without
-/// `let`!).
-///
-/// **Why is this bad?** It's not really bad, but some people think that the
-/// `.push_str(_)` method is more readable. Also creates a new heap allocation and throws
-/// away the old one.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-///
-/// ```rust
-/// let mut x = "Hello".to_owned();
-/// x = x + ", World";
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for string appends of the form `x = x + y` (without
+    /// `let`!).
+    ///
+    /// **Why is this bad?** It's not really bad, but some people think that the
+    /// `.push_str(_)` method is more readable. Also creates a new heap allocation and throws
+    /// away the old one.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// let mut x = "Hello".to_owned();
+    /// x = x + ", World";
+    /// ```
+    #[clippy::version = "pre 0.0.213"]
     pub STRING_ADD_ASSIGN,
     pedantic,
     "using `x = x + ..` where x is a `String` instead of `push_str()`"
 }
 
-/// **What it does:** Checks for all instances of `x + _` where `x` is of type
-/// `String`, but only if [`string_add_assign`](#string_add_assign) does *not*
-/// match.
-///
-/// **Why is this bad?** It's not bad in and of itself. However, this particular
-/// `Add` implementation is asymmetric (the other operand need not be `String`,
-/// but `x` does), while addition as mathematically defined is symmetric, also
-/// the `String::push_str(_)` function is a perfectly good replacement.
-/// Therefore some dislike it and wish not to have it in their code.
-///
-/// That said, other people think that string addition, having a long tradition
-/// in other languages is actually fine, which is why we decided to make this
-/// particular lint `allow` by default.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-///
-/// ```rust
-/// let x = "Hello".to_owned();
-/// x + ", World"
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for all instances of `x + _` where `x` is of type
+    /// `String`, but only if [`string_add_assign`](#string_add_assign) does *not*
+    /// match.
+    ///
+    /// **Why is this bad?** It's not bad in and of itself. However, this particular
+    /// `Add` implementation is asymmetric (the other operand need not be `String`,
+    /// but `x` does), while addition as mathematically defined is symmetric, also
+    /// the `String::push_str(_)` function is a perfectly good replacement.
+    /// Therefore some dislike it and wish not to have it in their code.
+    ///
+    /// That said, other people think that string addition, having a long tradition
+    /// in other languages is actually fine, which is why we decided to make this
+    /// particular lint `allow` by default.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// let x = "Hello".to_owned();
+    /// x + ", World"
+    /// ```
+    #[clippy::version = "pre 0.0.213"]
     pub STRING_ADD,
     restriction,
     "using `x + ..` where x is a `String` instead of `push_str()`"
 }
 
-/// **What it does:** Checks for the `as_bytes` method called on string literals
-/// that contain only ASCII characters.
-///
-/// **Why is this bad?** Byte string literals (e.g. `b"foo"`) can be used
-/// instead. They are shorter but less discoverable than `as_bytes()`.
-///
-/// **Known Problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// let bs = "a byte string".as_bytes();
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for slice operations on strings, e.g. `&s[2..8]`.
+    ///
+    /// **Why is this bad?** The ranges are byte offsets, slicing panics if one of
+    /// them is not on a character boundary. Non-ASCII characters are more than one
+    /// byte long.
+    ///
+    /// **Known problems:** Offsets returned by methods like `find` or
+    /// `char_indices` are character boundaries, slicing with them cannot panic.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let s = "Ölkanne";
+    /// let sub = &s[1..];
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let s = "Ölkanne";
+    /// let sub = s.get(1..);
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub STRING_SLICE,
+    restriction,
+    "slicing a string"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for the `as_bytes` method called on string literals
+    /// that contain only ASCII characters.
+    ///
+    /// **Why is this bad?** Byte string literals (e.g. `b"foo"`) can be used
+    /// instead. They are shorter but less discoverable than `as_bytes()`.
+    ///
+    /// **Known Problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let bs = "a byte string".as_bytes();
+    /// ```
+    #[clippy::version = "pre 0.0.213"]
     pub STRING_LIT_AS_BYTES,
     style,
     "calling `as_bytes` on a string literal instead of using a byte string literal"
@@ -77,7 +111,7 @@ pub struct StringAdd;
 
 impl LintPass for StringAdd {
     fn get_lints(&self) -> LintArray {
-        lint_array!(STRING_ADD, STRING_ADD_ASSIGN)
+        lint_array!(STRING_ADD, STRING_ADD_ASSIGN, STRING_SLICE)
     }
 }
 
@@ -113,6 +147,23 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for StringAdd {
                      `String::push_str()` instead",
                 );
             }
+        } else if let ExprKind::Index(ref target, ref index) = e.node {
+            if_chain! {
+                if !in_macro(e.span);
+                if is_str_or_string(cx, target);
+                if let Some(range) = higher::range(cx, index);
+                // `s[..]` cannot panic
+                if range.start.is_some() || range.end.is_some();
+                then {
+                    span_help_and_lint(
+                        cx,
+                        STRING_SLICE,
+                        e.span,
+                        "indexing into a string may panic if the index is within a UTF-8 character",
+                        "consider using `.get(..)`, `.char_indices()` or checking `.is_char_boundary()` first",
+                    );
+                }
+            }
         }
     }
 }
@@ -121,6 +172,11 @@ fn is_string(cx: &LateContext<'_, '_>, e: &Expr) -> bool {
     match_type(cx, walk_ptrs_ty(cx.tables.expr_ty(e)), &paths::STRING)
 }
 
+fn is_str_or_string(cx: &LateContext<'_, '_>, e: &Expr) -> bool {
+    let ty = walk_ptrs_ty(cx.tables.expr_ty(e));
+    ty.sty == ty::Str || match_type(cx, ty, &paths::STRING)
+}
+
 fn is_add(cx: &LateContext<'_, '_>, src: &Expr, target: &Expr) -> bool {
     match src.node {
         ExprKind::Binary(Spanned { node: BinOpKind::Add, .. }, ref left, _) => SpanlessEq::new(cx).eq_expr(target, left),
@@ -147,7 +203,7 @@ impl LintPass for StringLitAsBytes {
 impl<'a, 'tcx> LateLintPass<'a, 'tcx> for StringLitAsBytes {
     fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, e: &'tcx Expr) {
         use syntax::ast::LitKind;
-        use crate::utils::{in_macro, snippet};
+        use crate::utils::snippet;
 
         if let ExprKind::MethodCall(ref path, _, ref args) = e.node {
             if path.ident.name == "as_bytes" {
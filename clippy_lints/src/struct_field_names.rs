@@ -0,0 +1,158 @@
+//! lint on struct fields that are prefixed or suffixed by the same words
+
+use crate::utils::{in_macro, partial_match, partial_rmatch, span_help_and_lint, span_lint};
+use rustc::lint::{EarlyContext, EarlyLintPass, LintArray, LintPass};
+use rustc::{declare_lint, lint_array};
+use syntax::ast::*;
+use syntax::symbol::LocalInternedString;
+
+declare_clippy_lint! {
+    /// **What it does:** Detects structs whose field names all share a prefix or a
+    /// suffix, or whose field names start or end with the struct's name.
+    ///
+    /// The common prefixes and suffixes are only checked on structs with at least
+    /// `struct-field-name-threshold` fields, 3 by default.
+    ///
+    /// **Why is this bad?** The fields are accessed through the struct, repeating
+    /// a word in all of them, or the struct's name, only makes them longer.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// struct Config {
+    ///     config_path: String,
+    ///     config_port: u16,
+    ///     config_timeout: u64,
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// struct Config {
+    ///     path: String,
+    ///     port: u16,
+    ///     timeout: u64,
+    /// }
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub STRUCT_FIELD_NAMES,
+    pedantic,
+    "structs where all fields share a prefix/postfix or contain the name of the struct"
+}
+
+pub struct StructFieldNames {
+    threshold: u64,
+}
+
+impl StructFieldNames {
+    pub fn new(threshold: u64) -> Self {
+        Self { threshold }
+    }
+}
+
+impl LintPass for StructFieldNames {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(STRUCT_FIELD_NAMES)
+    }
+}
+
+impl EarlyLintPass for StructFieldNames {
+    fn check_item(&mut self, cx: &EarlyContext<'_>, item: &Item) {
+        if in_macro(item.span) {
+            return;
+        }
+        let fields = match item.node {
+            ItemKind::Struct(VariantData::Struct(ref fields, _), _) => fields,
+            _ => return,
+        };
+        let names: Vec<_> = fields
+            .iter()
+            .filter_map(|field| field.ident.map(|ident| (ident.as_str(), field)))
+            .collect();
+        if names.is_empty() {
+            return;
+        }
+        if (names.len() as u64) >= self.threshold && check_common_affix(cx, item, &names) {
+            return;
+        }
+
+        let item_name = to_snake_case(&item.ident.as_str());
+        let item_name_chars = item_name.chars().count();
+        for (name, field) in &names {
+            if partial_match(&item_name, name) == item_name_chars && name[item_name.len()..].starts_with('_') {
+                span_lint(cx, STRUCT_FIELD_NAMES, field.span, "field name starts with the struct's name");
+            }
+            if partial_rmatch(&item_name, name) == item_name_chars
+                && name[..name.len() - item_name.len()].ends_with('_')
+            {
+                span_lint(cx, STRUCT_FIELD_NAMES, field.span, "field name ends with the struct's name");
+            }
+        }
+    }
+}
+
+/// Lints the prefix or suffix of words shared by all the fields, returns `true` if there is one
+fn check_common_affix(cx: &EarlyContext<'_>, item: &Item, names: &[(LocalInternedString, &StructField)]) -> bool {
+    let first = &*names[0].0;
+    let mut pre = first;
+    let mut post = first;
+    for (name, _) in names {
+        pre = &pre[..char_index(pre, partial_match(pre, name))];
+        post = &post[char_index(post, post.chars().count() - partial_rmatch(post, name))..];
+    }
+    // only whole words are removed, with their separator
+    pre = &pre[..pre.rfind('_').map_or(0, |i| i + 1)];
+    post = &post[post.find('_').unwrap_or_else(|| post.len())..];
+
+    // the remaining names must still be identifiers
+    let trimmed_pre = pre.trim_matches('_');
+    let trimmed_post = post.trim_matches('_');
+    let (what, value) = if !trimmed_pre.is_empty()
+        && names
+            .iter()
+            .all(|(name, _)| !name[pre.len()..].starts_with(|c: char| c.is_numeric()))
+    {
+        ("pre", trimmed_pre)
+    } else if !trimmed_post.is_empty() {
+        ("post", trimmed_post)
+    } else {
+        return false;
+    };
+    span_help_and_lint(
+        cx,
+        STRUCT_FIELD_NAMES,
+        item.span,
+        &format!("all fields have the same {}fix: `{}`", what, value),
+        &format!("remove the {}fixes", what),
+    );
+    true
+}
+
+/// Returns the byte index of the `n`th char of `s`, or its length if there are fewer chars
+fn char_index(s: &str, n: usize) -> usize {
+    match s.char_indices().nth(n) {
+        Some((i, _)) => i,
+        None => s.len(),
+    }
+}
+
+/// Turns a CamelCase name into snake_case, e.g. `HttpConfig` into `http_config`
+fn to_snake_case(name: &str) -> String {
+    let mut s = String::new();
+    let chars: Vec<char> = name.chars().collect();
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() {
+            // a new word starts after a lowercase char, or at the last capital of an acronym
+            let prev_lower = i > 0 && (chars[i - 1].is_lowercase() || chars[i - 1].is_numeric());
+            let acronym_end =
+                i > 0 && chars[i - 1].is_uppercase() && chars.get(i + 1).map_or(false, |c| c.is_lowercase());
+            if prev_lower || acronym_end {
+                s.push('_');
+            }
+            s.extend(c.to_lowercase());
+        } else {
+            s.push(c);
+        }
+    }
+    s
+}
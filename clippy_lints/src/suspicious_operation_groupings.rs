@@ -0,0 +1,178 @@
+use crate::utils::{get_parent_expr, in_macro, snippet, span_lint_and_sugg, SpanlessEq};
+use if_chain::if_chain;
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::{declare_lint, lint_array};
+use syntax::ast::Name;
+use syntax::source_map::Spanned;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for sums, conjunctions, etc. of binary operations
+    /// on fields where all the operations but one use the same field on both
+    /// sides, e.g. `a.x * b.x + a.y * b.x`.
+    ///
+    /// **Why is this bad?** Such symmetric expressions are usually written by
+    /// copying and pasting the first operation, the odd one out is likely a field
+    /// that was not renamed.
+    ///
+    /// **Known problems:** The lint may fire on intentionally asymmetric code.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// struct Vec3 {
+    ///     x: f64,
+    ///     y: f64,
+    ///     z: f64,
+    /// }
+    ///
+    /// fn dot(a: &Vec3, b: &Vec3) -> f64 {
+    ///     a.x * b.x + a.y * b.x + a.z * b.z
+    /// }
+    /// ```
+    /// Could be written as:
+    /// ```rust
+    /// # struct Vec3 {
+    /// #     x: f64,
+    /// #     y: f64,
+    /// #     z: f64,
+    /// # }
+    /// fn dot(a: &Vec3, b: &Vec3) -> f64 {
+    ///     a.x * b.x + a.y * b.y + a.z * b.z
+    /// }
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub SUSPICIOUS_OPERATION_GROUPINGS,
+    nursery,
+    "groupings of binary operations that look suspiciously like typos"
+}
+
+#[derive(Copy, Clone)]
+pub struct SuspiciousOperationGroupings;
+
+impl LintPass for SuspiciousOperationGroupings {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(SUSPICIOUS_OPERATION_GROUPINGS)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for SuspiciousOperationGroupings {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        let op = match expr.node {
+            ExprKind::Binary(op, ..) if is_chain_operator(op.node) => op.node,
+            _ => return,
+        };
+        if in_macro(expr.span) {
+            return;
+        }
+        // only look at the whole chain, not at each of its parts
+        if let Some(parent) = get_parent_expr(cx, expr) {
+            if let ExprKind::Binary(Spanned { node: parent_op, .. }, ..) = parent.node {
+                if parent_op == op {
+                    return;
+                }
+            }
+        }
+
+        let mut operands = Vec::new();
+        collect_operands(op, expr, &mut operands);
+        let operations = match operands.into_iter().map(FieldOperation::new).collect::<Option<Vec<_>>>() {
+            Some(operations) => operations,
+            None => return,
+        };
+        let first = &operations[0];
+        let mut spanless_eq = SpanlessEq::new(cx).ignore_fn();
+        if !operations.iter().all(|operation| {
+            operation.op == first.op
+                && spanless_eq.eq_expr(operation.lhs, first.lhs)
+                && spanless_eq.eq_expr(operation.rhs, first.rhs)
+        }) {
+            return;
+        }
+
+        let (symmetric, odd): (Vec<_>, Vec<_>) = operations.iter().partition(|operation| operation.is_symmetric());
+        if symmetric.is_empty() || odd.len() != 1 {
+            return;
+        }
+        let odd = odd[0];
+
+        // the field that is already used by another operation is the one that was not renamed
+        let field = if symmetric.iter().any(|operation| operation.lhs_field == odd.rhs_field) {
+            odd.lhs_field
+        } else if symmetric.iter().any(|operation| operation.lhs_field == odd.lhs_field) {
+            odd.rhs_field
+        } else {
+            odd.lhs_field
+        };
+        span_lint_and_sugg(
+            cx,
+            SUSPICIOUS_OPERATION_GROUPINGS,
+            odd.expr.span,
+            "this sequence of operators looks suspiciously like a bug",
+            "did you mean",
+            format!(
+                "{}.{} {} {}.{}",
+                snippet(cx, odd.lhs.span, ".."),
+                field,
+                odd.op.as_str(),
+                snippet(cx, odd.rhs.span, ".."),
+                field
+            ),
+        );
+    }
+}
+
+/// Operators that are commonly used to combine a sequence of similar operations
+fn is_chain_operator(op: BinOpKind) -> bool {
+    match op {
+        BinOpKind::Add | BinOpKind::And | BinOpKind::Or | BinOpKind::BitAnd | BinOpKind::BitOr | BinOpKind::BitXor => {
+            true
+        },
+        _ => false,
+    }
+}
+
+/// Flattens `a + b + c` into `[a, b, c]`
+fn collect_operands<'a>(op: BinOpKind, expr: &'a Expr, operands: &mut Vec<&'a Expr>) {
+    match expr.node {
+        ExprKind::Binary(Spanned { node, .. }, ref left, ref right) if node == op => {
+            collect_operands(op, left, operands);
+            collect_operands(op, right, operands);
+        },
+        _ => operands.push(expr),
+    }
+}
+
+/// A binary operation between fields like `a.x * b.x`
+struct FieldOperation<'a> {
+    expr: &'a Expr,
+    op: BinOpKind,
+    lhs: &'a Expr,
+    lhs_field: Name,
+    rhs: &'a Expr,
+    rhs_field: Name,
+}
+
+impl<'a> FieldOperation<'a> {
+    fn new(expr: &'a Expr) -> Option<Self> {
+        if_chain! {
+            if let ExprKind::Binary(op, ref left, ref right) = expr.node;
+            if let ExprKind::Field(ref lhs, lhs_field) = left.node;
+            if let ExprKind::Field(ref rhs, rhs_field) = right.node;
+            then {
+                return Some(Self {
+                    expr,
+                    op: op.node,
+                    lhs,
+                    lhs_field: lhs_field.name,
+                    rhs,
+                    rhs_field: rhs_field.name,
+                });
+            }
+        }
+        None
+    }
+
+    fn is_symmetric(&self) -> bool {
+        self.lhs_field == self.rhs_field
+    }
+}
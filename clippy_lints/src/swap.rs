@@ -4,44 +4,47 @@ use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
 use rustc::{declare_lint, lint_array};
 use if_chain::if_chain;
 use rustc::ty;
+use rustc::ty::adjustment::Adjust;
 use crate::utils::{differing_macro_contexts, match_type, paths, snippet, span_lint_and_then, walk_ptrs_ty, SpanlessEq};
 use crate::utils::sugg::Sugg;
 
-/// **What it does:** Checks for manual swapping.
-///
-/// **Why is this bad?** The `std::mem::swap` function exposes the intent better
-/// without deinitializing or copying either variable.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust,ignore
-/// let t = b;
-/// b = a;
-/// a = t;
-/// ```
-/// Use std::mem::swap():
-/// ```rust
-/// std::mem::swap(&mut a, &mut b);
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for manual swapping.
+    ///
+    /// **Why is this bad?** The `std::mem::swap` function exposes the intent better
+    /// without deinitializing or copying either variable.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// let t = b;
+    /// b = a;
+    /// a = t;
+    /// ```
+    /// Use std::mem::swap():
+    /// ```rust
+    /// std::mem::swap(&mut a, &mut b);
+    /// ```
+    #[clippy::version = "pre 0.0.213"]
     pub MANUAL_SWAP,
     complexity,
     "manual swap of two variables"
 }
 
-/// **What it does:** Checks for `foo = bar; bar = foo` sequences.
-///
-/// **Why is this bad?** This looks like a failed attempt to swap.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust,ignore
-/// a = b;
-/// b = a;
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for `foo = bar; bar = foo` sequences.
+    ///
+    /// **Why is this bad?** This looks like a failed attempt to swap.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// a = b;
+    /// b = a;
+    /// ```
+    #[clippy::version = "pre 0.0.213"]
     pub ALMOST_SWAPPED,
     correctness,
     "`foo = bar; bar = foo` sequence"
@@ -122,8 +125,15 @@ fn check_manual_swap(cx: &LateContext<'_, '_>, block: &Block) {
                         (false, "".to_owned(), "".to_owned())
                     }
                 } else if let (Some(first), Some(second)) = (Sugg::hir_opt(cx, lhs1), Sugg::hir_opt(cx, rhs1)) {
-                    (true, format!(" `{}` and `{}`", first, second),
-                        format!("std::mem::swap({}, {})", first.mut_addr(), second.mut_addr()))
+                    let what = format!(" `{}` and `{}`", first, second);
+                    match can_mut_borrow_both(cx, lhs1, rhs1) {
+                        Some(true) => {
+                            (true, what, format!("std::mem::swap({}, {})", first.mut_addr(), second.mut_addr()))
+                        },
+                        // `std::mem::swap` would not compile
+                        Some(false) => (false, what, "".to_owned()),
+                        None => continue,
+                    }
                 } else {
                     (true, "".to_owned(), "".to_owned())
                 };
@@ -148,6 +158,59 @@ fn check_manual_swap(cx: &LateContext<'_, '_>, block: &Block) {
     }
 }
 
+/// Checks if both places can be borrowed mutably at the same time, that is if they have
+/// different roots or only differ by their fields, e.g. `a.x` and `a.y`. Returns `None` if either
+/// place is behind an overloaded dereference.
+fn can_mut_borrow_both(cx: &LateContext<'_, '_>, e1: &Expr, e2: &Expr) -> Option<bool> {
+    let (root1, only_fields1) = place_root(cx, e1)?;
+    let (root2, only_fields2) = place_root(cx, e2)?;
+    Some(!SpanlessEq::new(cx).ignore_fn().eq_expr(root1, root2) || (only_fields1 && only_fields2))
+}
+
+/// The expression a place is projected from, and whether all the projections are field accesses.
+/// Returns `None` at a dereference of a `Box` or through `Deref`, e.g. of a lock guard: the places
+/// aren't known to be disjoint, and the swap may be intended to go through the guard.
+fn place_root<'e>(cx: &LateContext<'_, '_>, mut expr: &'e Expr) -> Option<(&'e Expr, bool)> {
+    let mut only_fields = true;
+    loop {
+        match expr.node {
+            ExprKind::Field(ref base, _) => {
+                if is_overloaded_autoderef(cx, base) {
+                    return None;
+                }
+                expr = base;
+            },
+            ExprKind::Unary(UnDeref, ref base) => {
+                if cx.tables.is_method_call(expr) || cx.tables.expr_ty(base).is_box() {
+                    return None;
+                }
+                only_fields = false;
+                expr = base;
+            },
+            ExprKind::Index(ref base, _) => {
+                only_fields = false;
+                expr = base;
+            },
+            _ => return Some((expr, only_fields)),
+        }
+    }
+}
+
+/// Checks if `expr` is automatically dereferenced through a `Box` or a `Deref` implementation,
+/// e.g. to access one of its fields
+fn is_overloaded_autoderef(cx: &LateContext<'_, '_>, expr: &Expr) -> bool {
+    let mut ty = cx.tables.expr_ty(expr);
+    for adjustment in cx.tables.expr_adjustments(expr) {
+        if let Adjust::Deref(ref overloaded) = adjustment.kind {
+            if overloaded.is_some() || ty.is_box() {
+                return true;
+            }
+        }
+        ty = adjustment.target;
+    }
+    false
+}
+
 /// Implementation of the `ALMOST_SWAPPED` lint.
 fn check_suspicious_swap(cx: &LateContext<'_, '_>, block: &Block) {
     for w in block.stmts.windows(2) {
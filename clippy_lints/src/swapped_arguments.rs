@@ -0,0 +1,147 @@
+use crate::utils::{in_macro, same_tys, snippet_opt, span_lint_and_then};
+use if_chain::if_chain;
+use rustc::hir::def::Def;
+use rustc::hir::def_id::DefId;
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::{declare_lint, lint_array};
+use rustc_errors::Applicability;
+use syntax::ast::Name;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for calls passing two local variables of the same type
+    /// to parameters of the other one's name, like `resize(height, width)` for a
+    /// function declared as `fn resize(width: u32, height: u32)`.
+    ///
+    /// **Why is this bad?** The arguments were most likely swapped by mistake, and
+    /// since they have the same type the compiler can't catch it.
+    ///
+    /// **Known problems:** Only the arguments which are local variables are checked.
+    /// The names of the parameters may be misleading.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// fn resize(width: u32, height: u32) {}
+    ///
+    /// let (width, height) = (640, 480);
+    /// resize(height, width);
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn resize(width: u32, height: u32) {}
+    ///
+    /// let (width, height) = (640, 480);
+    /// resize(width, height);
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub SWAPPED_ARGUMENTS,
+    correctness,
+    "two arguments of the same type named after each other's parameter"
+}
+
+#[derive(Copy, Clone)]
+pub struct SwappedArguments;
+
+impl LintPass for SwappedArguments {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(SWAPPED_ARGUMENTS)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for SwappedArguments {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if in_macro(expr.span) {
+            return;
+        }
+        // the receiver of a method call is its first argument, like in the signature
+        let (def_id, args) = match expr.node {
+            ExprKind::Call(ref func, ref args) => match func.node {
+                ExprKind::Path(ref qpath) => match cx.tables.qpath_def(qpath, func.hir_id) {
+                    Def::Fn(def_id) | Def::Method(def_id) => (def_id, &**args),
+                    _ => return,
+                },
+                _ => return,
+            },
+            ExprKind::MethodCall(_, _, ref args) => match cx.tables.type_dependent_defs().get(expr.hir_id) {
+                Some(def) => (def.def_id(), &**args),
+                None => return,
+            },
+            _ => return,
+        };
+        let params = match param_names(cx, def_id) {
+            Some(params) if params.len() == args.len() => params,
+            _ => return,
+        };
+        let arg_names: Vec<_> = args.iter().map(local_name).collect();
+
+        for i in 0..args.len() {
+            for j in i + 1..args.len() {
+                if_chain! {
+                    if let (Some(first), Some(second)) = (arg_names[i], arg_names[j]);
+                    if params[i] == Some(second) && params[j] == Some(first);
+                    if same_tys(cx, cx.tables.expr_ty(&args[i]), cx.tables.expr_ty(&args[j]));
+                    then {
+                        lint_swapped(cx, &args[i], &args[j]);
+                        return;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Returns the names of the parameters of the function `def_id`, `None` for the
+/// parameters bound to patterns
+fn param_names(cx: &LateContext<'_, '_>, def_id: DefId) -> Option<Vec<Option<Name>>> {
+    let node_id = match cx.tcx.hir.as_local_node_id(def_id) {
+        Some(node_id) => node_id,
+        None => return Some(cx.tcx.fn_arg_names(def_id).into_iter().map(Some).collect()),
+    };
+    if let Some(body_id) = cx.tcx.hir.maybe_body_owned_by(node_id) {
+        let names = cx.tcx.hir.body(body_id).arguments.iter().map(|arg| match arg.pat.node {
+            PatKind::Binding(_, _, ident, None) => Some(ident.name),
+            _ => None,
+        });
+        return Some(names.collect());
+    }
+    if let Node::TraitItem(item) = cx.tcx.hir.get(node_id) {
+        if let TraitItemKind::Method(_, TraitMethod::Required(ref names)) = item.node {
+            return Some(names.iter().map(|ident| Some(ident.name)).collect());
+        }
+    }
+    None
+}
+
+/// Returns the name of the local variable `expr` refers to
+fn local_name(expr: &Expr) -> Option<Name> {
+    match expr.node {
+        ExprKind::Path(QPath::Resolved(None, ref path)) => match path.def {
+            Def::Local(_) if path.segments.len() == 1 => Some(path.segments[0].ident.name),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn lint_swapped(cx: &LateContext<'_, '_>, first: &Expr, second: &Expr) {
+    span_lint_and_then(
+        cx,
+        SWAPPED_ARGUMENTS,
+        first.span.to(second.span),
+        "these arguments are named after each other's parameters, they may be swapped",
+        |db| {
+            if let (Some(first_snip), Some(between), Some(second_snip)) = (
+                snippet_opt(cx, first.span),
+                snippet_opt(cx, first.span.between(second.span)),
+                snippet_opt(cx, second.span),
+            ) {
+                db.span_suggestion_with_applicability(
+                    first.span.to(second.span),
+                    "if they are, swap them",
+                    format!("{}{}{}", second_snip, between, first_snip),
+                    Applicability::MaybeIncorrect,
+                );
+            }
+        },
+    );
+}
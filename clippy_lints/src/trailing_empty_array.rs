@@ -0,0 +1,81 @@
+use crate::utils::{in_macro, span_help_and_lint};
+use if_chain::if_chain;
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::ty;
+use rustc::{declare_lint, lint_array};
+use syntax::ast::{Attribute, NestedMetaItemKind};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for structs whose last field is a zero-length array,
+    /// like `[T; 0]`, but which aren't `#[repr(C)]`.
+    ///
+    /// **Why is this bad?** A trailing `[T; 0]` is the C trick to declare a flexible
+    /// array member, the items of the array being allocated after the struct. The
+    /// layout of a struct is only defined with `#[repr(C)]`: without it, the field
+    /// isn't guaranteed to be at the end of the struct.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// struct RarelyUseful {
+    ///     some_field: u32,
+    ///     last: [u32; 0],
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// #[repr(C)]
+    /// struct MoreOftenUseful {
+    ///     some_field: u32,
+    ///     last: [u32; 0],
+    /// }
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub TRAILING_EMPTY_ARRAY,
+    nursery,
+    "struct with a trailing zero-sized array but without `#[repr(C)]`"
+}
+
+#[derive(Copy, Clone)]
+pub struct TrailingEmptyArray;
+
+impl LintPass for TrailingEmptyArray {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(TRAILING_EMPTY_ARRAY)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for TrailingEmptyArray {
+    fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx Item) {
+        if_chain! {
+            if !in_macro(item.span);
+            if let ItemKind::Struct(ref data, _) = item.node;
+            if let Some(last) = data.fields().last();
+            if let ty::Array(_, len) = cx.tcx.type_of(cx.tcx.hir.local_def_id(last.id)).sty;
+            if len.assert_usize(cx.tcx) == Some(0);
+            if !item.attrs.iter().any(is_repr_c);
+            then {
+                span_help_and_lint(
+                    cx,
+                    TRAILING_EMPTY_ARRAY,
+                    item.span,
+                    "trailing zero-sized array in a struct which is not marked with `#[repr(C)]`",
+                    &format!("consider annotating `{}` with `#[repr(C)]`", item.name),
+                );
+            }
+        }
+    }
+}
+
+/// Checks for `#[repr(C)]`, possibly with other representation hints
+fn is_repr_c(attr: &Attribute) -> bool {
+    attr.name() == "repr"
+        && attr.meta_item_list().map_or(false, |items| {
+            items.iter().any(|item| match item.node {
+                NestedMetaItemKind::MetaItem(ref mi) => mi.name() == "C",
+                NestedMetaItemKind::Literal(_) => false,
+            })
+        })
+}
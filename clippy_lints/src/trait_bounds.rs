@@ -0,0 +1,230 @@
+use crate::utils::{in_macro, multispan_sugg, snippet, span_help_and_lint, span_lint_and_then, SpanlessEq};
+use rustc::hir::def::Def;
+use rustc::hir::def_id::DefId;
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::{declare_lint, lint_array};
+use syntax_pos::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** This lint warns about unnecessary type repetitions in trait bounds
+    ///
+    /// **Why is this bad?** Repeating the type for every bound makes the code
+    /// less readable than combining the bounds
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// pub fn foo<T>(t: T) where T: Copy, T: Clone {}
+    /// ```
+    ///
+    /// Could be written as:
+    ///
+    /// ```rust
+    /// pub fn foo<T>(t: T) where T: Copy + Clone {}
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub TYPE_REPETITION_IN_BOUNDS,
+    pedantic,
+    "types are repeated unnecessarily in trait bounds, use `+` instead of using `T: _, T: _`"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for cases where generics are being used and multiple
+    /// syntax specifications for trait bounds are used simultaneously.
+    ///
+    /// **Why is this bad?** Duplicate bounds makes the code
+    /// less readable than specifing them only once.
+    ///
+    /// **Known problems:** Bounds on the same trait with different generic
+    /// arguments, e.g. `T: From<u8> + From<u16>`, are different bounds and are
+    /// not linted.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// fn func<T: Clone + Default>(arg: T) where T: Clone + Default {}
+    /// ```
+    ///
+    /// Could be written as:
+    ///
+    /// ```rust
+    /// fn func<T: Clone + Default>(arg: T) {}
+    /// ```
+    /// or
+    ///
+    /// ```rust
+    /// fn func<T>(arg: T) where T: Clone + Default {}
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub TRAIT_DUPLICATION_IN_BOUNDS,
+    nursery,
+    "Check if the same trait bounds are specified twice during a function declaration"
+}
+
+#[derive(Copy, Clone)]
+pub struct TraitBounds;
+
+impl LintPass for TraitBounds {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(TYPE_REPETITION_IN_BOUNDS, TRAIT_DUPLICATION_IN_BOUNDS)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for TraitBounds {
+    fn check_generics(&mut self, cx: &LateContext<'a, 'tcx>, gen: &'tcx Generics) {
+        if in_macro(gen.span) {
+            return;
+        }
+        check_type_repetition(cx, gen);
+        check_trait_duplication(cx, gen);
+    }
+}
+
+fn check_type_repetition(cx: &LateContext<'_, '_>, gen: &Generics) {
+    let predicates = &gen.where_clause.predicates;
+    let mut seen: Vec<&WhereBoundPredicate> = Vec::new();
+    for (i, predicate) in predicates.iter().enumerate() {
+        let predicate = match *predicate {
+            // `for<'a> T: Trait<'a>` can't always be merged with another bound on `T`
+            WherePredicate::BoundPredicate(ref p) if p.bound_generic_params.is_empty() => p,
+            _ => continue,
+        };
+        if in_macro(predicate.span) {
+            continue;
+        }
+
+        let first = seen
+            .iter()
+            .find(|first| SpanlessEq::new(cx).eq_ty(&first.bounded_ty, &predicate.bounded_ty));
+        if let Some(first) = first {
+            let combined = format!(
+                "{}: {} + {}",
+                snippet(cx, first.bounded_ty.span, "_"),
+                snippet(cx, bounds_span(first), ".."),
+                snippet(cx, bounds_span(predicate), "..")
+            );
+            // remove the predicate with the comma before it
+            let removed = predicate.span.with_lo(predicates[i - 1].span().hi());
+            span_lint_and_then(
+                cx,
+                TYPE_REPETITION_IN_BOUNDS,
+                predicate.span,
+                "this type has already been used as a bound predicate",
+                |db| {
+                    multispan_sugg(
+                        db,
+                        "consider combining the bounds".to_owned(),
+                        vec![(first.span, combined), (removed, String::new())],
+                    );
+                },
+            );
+        } else {
+            seen.push(predicate);
+        }
+    }
+}
+
+/// The span of all the bounds of a predicate, e.g. `Clone + Default` in `T: Clone + Default`
+fn bounds_span(predicate: &WhereBoundPredicate) -> Span {
+    match (predicate.bounds.first(), predicate.bounds.last()) {
+        (Some(first), Some(last)) => first.span().to(last.span()),
+        _ => predicate.span,
+    }
+}
+
+/// A list of bounds: of a type parameter, e.g. `Clone + Default` in `<T: Clone + Default>`,
+/// or of a where predicate
+struct BoundList<'a> {
+    /// The type parameter the bounds apply to, `None` for other types
+    param: Option<DefId>,
+    in_where_clause: bool,
+    bounds: &'a [GenericBound],
+}
+
+fn check_trait_duplication(cx: &LateContext<'_, '_>, gen: &Generics) {
+    let mut lists = Vec::new();
+    for param in &gen.params {
+        if let GenericParamKind::Type { .. } = param.kind {
+            lists.push(BoundList {
+                param: Some(cx.tcx.hir.local_def_id(param.id)),
+                in_where_clause: false,
+                bounds: &param.bounds,
+            });
+        }
+    }
+    for predicate in &gen.where_clause.predicates {
+        if let WherePredicate::BoundPredicate(ref p) = *predicate {
+            if !p.bound_generic_params.is_empty() {
+                continue;
+            }
+            let param = match p.bounded_ty.node {
+                TyKind::Path(QPath::Resolved(None, ref path)) => match path.def {
+                    Def::TyParam(def_id) => Some(def_id),
+                    _ => None,
+                },
+                _ => None,
+            };
+            lists.push(BoundList {
+                param,
+                in_where_clause: true,
+                bounds: &p.bounds,
+            });
+        }
+    }
+
+    for (i, list) in lists.iter().enumerate() {
+        for (j, bound) in list.bounds.iter().enumerate() {
+            let trait_ref = match trait_ref_of(bound) {
+                Some(trait_ref) => trait_ref,
+                None => continue,
+            };
+            if in_macro(bound.span()) {
+                continue;
+            }
+
+            let is_same = |other: &GenericBound| {
+                trait_ref_of(other).map_or(false, |other| eq_trait_ref(cx, trait_ref, other))
+            };
+            let earlier = lists[..i]
+                .iter()
+                .filter(|other| list.param.is_some() && other.param == list.param)
+                .find(|other| other.bounds.iter().any(&is_same));
+            let msg = if list.bounds[..j].iter().any(&is_same) {
+                "this trait bound is already specified"
+            } else {
+                match earlier {
+                    Some(earlier) if !earlier.in_where_clause => {
+                        "this trait bound is already specified in the generic parameter list"
+                    },
+                    Some(_) => "this trait bound is already specified in the where clause",
+                    None => continue,
+                }
+            };
+            span_help_and_lint(
+                cx,
+                TRAIT_DUPLICATION_IN_BOUNDS,
+                bound.span(),
+                msg,
+                "consider removing this trait bound",
+            );
+        }
+    }
+}
+
+/// The trait of a bound, `None` for lifetimes and `?Sized`
+fn trait_ref_of(bound: &GenericBound) -> Option<&TraitRef> {
+    match *bound {
+        GenericBound::Trait(ref poly_trait_ref, TraitBoundModifier::None) => Some(&poly_trait_ref.trait_ref),
+        _ => None,
+    }
+}
+
+/// Compares two traits and their generic arguments, e.g. `Into<u8>` and `std::convert::Into<u8>`
+fn eq_trait_ref(cx: &LateContext<'_, '_>, left: &TraitRef, right: &TraitRef) -> bool {
+    left.path.def == right.path.def
+        && match (left.path.segments.last(), right.path.segments.last()) {
+            (Some(left), Some(right)) => SpanlessEq::new(cx).eq_path_segment(left, right),
+            _ => false,
+        }
+}
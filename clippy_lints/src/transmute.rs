@@ -2,201 +2,268 @@ use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
 use rustc::{declare_lint, lint_array};
 use if_chain::if_chain;
 use rustc::ty::{self, Ty};
+use rustc::ty::layout::LayoutOf;
 use rustc::hir::*;
 use std::borrow::Cow;
 use syntax::ast;
 use crate::utils::{last_path_segment, match_def_path, paths, snippet, span_lint, span_lint_and_then};
 use crate::utils::{opt_def_id, sugg};
 
-/// **What it does:** Checks for transmutes that can't ever be correct on any
-/// architecture.
-///
-/// **Why is this bad?** It's basically guaranteed to be undefined behaviour.
-///
-/// **Known problems:** When accessing C, users might want to store pointer
-/// sized objects in `extradata` arguments to save an allocation.
-///
-/// **Example:**
-/// ```rust
-/// let ptr: *const T = core::intrinsics::transmute('x')
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for transmutes that can't ever be correct on any
+    /// architecture.
+    ///
+    /// **Why is this bad?** It's basically guaranteed to be undefined behaviour.
+    ///
+    /// **Known problems:** When accessing C, users might want to store pointer
+    /// sized objects in `extradata` arguments to save an allocation.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let ptr: *const T = core::intrinsics::transmute('x')
+    /// ```
+    #[clippy::version = "0.0.78"]
     pub WRONG_TRANSMUTE,
     correctness,
     "transmutes that are confusing at best, undefined behaviour at worst and always useless"
 }
 
-/// **What it does:** Checks for transmutes to the original type of the object
-/// and transmutes that could be a cast.
-///
-/// **Why is this bad?** Readability. The code tricks people into thinking that
-/// something complex is going on.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// core::intrinsics::transmute(t) // where the result type is the same as `t`'s
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for transmutes to the original type of the object
+    /// and transmutes that could be a cast.
+    ///
+    /// **Why is this bad?** Readability. The code tricks people into thinking that
+    /// something complex is going on.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// core::intrinsics::transmute(t) // where the result type is the same as `t`'s
+    /// ```
+    #[clippy::version = "pre 0.0.213"]
     pub USELESS_TRANSMUTE,
     complexity,
     "transmutes that have the same to and from types or could be a cast/coercion"
 }
 
-/// **What it does:** Checks for transmutes between a type `T` and `*T`.
-///
-/// **Why is this bad?** It's easy to mistakenly transmute between a type and a
-/// pointer to that type.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// core::intrinsics::transmute(t) // where the result type is the same as
-/// // `*t` or `&t`'s
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for transmutes between a type `T` and `*T`.
+    ///
+    /// **Why is this bad?** It's easy to mistakenly transmute between a type and a
+    /// pointer to that type.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// core::intrinsics::transmute(t) // where the result type is the same as
+    /// // `*t` or `&t`'s
+    /// ```
+    #[clippy::version = "0.0.57"]
     pub CROSSPOINTER_TRANSMUTE,
     complexity,
     "transmutes that have to or from types that are a pointer to the other"
 }
 
-/// **What it does:** Checks for transmutes from a pointer to a reference.
-///
-/// **Why is this bad?** This can always be rewritten with `&` and `*`.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// let _: &T = std::mem::transmute(p); // where p: *const T
-/// // can be written:
-/// let _: &T = &*p;
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for transmutes from a pointer to a reference.
+    ///
+    /// **Why is this bad?** This can always be rewritten with `&` and `*`.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let _: &T = std::mem::transmute(p); // where p: *const T
+    /// // can be written:
+    /// let _: &T = &*p;
+    /// ```
+    #[clippy::version = "pre 0.0.213"]
     pub TRANSMUTE_PTR_TO_REF,
     complexity,
     "transmutes from a pointer to a reference type"
 }
 
-/// **What it does:** Checks for transmutes from an integer to a `char`.
-///
-/// **Why is this bad?** Not every integer is a Unicode scalar value.
-///
-/// **Known problems:**
-/// - [`from_u32`] which this lint suggests using is slower than `transmute`
-/// as it needs to validate the input.
-/// If you are certain that the input is always a valid Unicode scalar value,
-/// use [`from_u32_unchecked`] which is as fast as `transmute`
-/// but has a semantically meaningful name.
-/// - You might want to handle `None` returned from [`from_u32`] instead of calling `unwrap`.
-///
-/// [`from_u32`]: https://doc.rust-lang.org/std/char/fn.from_u32.html
-/// [`from_u32_unchecked`]: https://doc.rust-lang.org/std/char/fn.from_u32_unchecked.html
-///
-/// **Example:**
-/// ```rust
-/// let _: char = std::mem::transmute(x); // where x: u32
-/// // should be:
-/// let _ = std::char::from_u32(x).unwrap();
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for transmutes from an integer to a `char`.
+    ///
+    /// **Why is this bad?** Not every integer is a Unicode scalar value.
+    ///
+    /// **Known problems:**
+    /// - [`from_u32`] which this lint suggests using is slower than `transmute`
+    /// as it needs to validate the input.
+    /// If you are certain that the input is always a valid Unicode scalar value,
+    /// use [`from_u32_unchecked`] which is as fast as `transmute`
+    /// but has a semantically meaningful name.
+    /// - You might want to handle `None` returned from [`from_u32`] instead of calling `unwrap`.
+    ///
+    /// [`from_u32`]: https://doc.rust-lang.org/std/char/fn.from_u32.html
+    /// [`from_u32_unchecked`]: https://doc.rust-lang.org/std/char/fn.from_u32_unchecked.html
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let _: char = std::mem::transmute(x); // where x: u32
+    /// // should be:
+    /// let _ = std::char::from_u32(x).unwrap();
+    /// ```
+    #[clippy::version = "0.0.166"]
     pub TRANSMUTE_INT_TO_CHAR,
     complexity,
     "transmutes from an integer to a `char`"
 }
 
-/// **What it does:** Checks for transmutes from a `&[u8]` to a `&str`.
-///
-/// **Why is this bad?** Not every byte slice is a valid UTF-8 string.
-///
-/// **Known problems:**
-/// - [`from_utf8`] which this lint suggests using is slower than `transmute`
-/// as it needs to validate the input.
-/// If you are certain that the input is always a valid UTF-8,
-/// use [`from_utf8_unchecked`] which is as fast as `transmute`
-/// but has a semantically meaningful name.
-/// - You might want to handle errors returned from [`from_utf8`] instead of calling `unwrap`.
-///
-/// [`from_utf8`]: https://doc.rust-lang.org/std/str/fn.from_utf8.html
-/// [`from_utf8_unchecked`]: https://doc.rust-lang.org/std/str/fn.from_utf8_unchecked.html
-///
-/// **Example:**
-/// ```rust
-/// let _: &str = std::mem::transmute(b); // where b: &[u8]
-/// // should be:
-/// let _ = std::str::from_utf8(b).unwrap();
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for transmutes from a `&[u8]` to a `&str`.
+    ///
+    /// **Why is this bad?** Not every byte slice is a valid UTF-8 string.
+    ///
+    /// **Known problems:**
+    /// - [`from_utf8`] which this lint suggests using is slower than `transmute`
+    /// as it needs to validate the input.
+    /// If you are certain that the input is always a valid UTF-8,
+    /// use [`from_utf8_unchecked`] which is as fast as `transmute`
+    /// but has a semantically meaningful name.
+    /// - You might want to handle errors returned from [`from_utf8`] instead of calling `unwrap`.
+    ///
+    /// [`from_utf8`]: https://doc.rust-lang.org/std/str/fn.from_utf8.html
+    /// [`from_utf8_unchecked`]: https://doc.rust-lang.org/std/str/fn.from_utf8_unchecked.html
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let _: &str = std::mem::transmute(b); // where b: &[u8]
+    /// // should be:
+    /// let _ = std::str::from_utf8(b).unwrap();
+    /// ```
+    #[clippy::version = "0.0.169"]
     pub TRANSMUTE_BYTES_TO_STR,
     complexity,
     "transmutes from a `&[u8]` to a `&str`"
 }
 
-/// **What it does:** Checks for transmutes from an integer to a `bool`.
-///
-/// **Why is this bad?** This might result in an invalid in-memory representation of a `bool`.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// let _: bool = std::mem::transmute(x); // where x: u8
-/// // should be:
-/// let _: bool = x != 0;
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for transmutes from an integer to a `bool`.
+    ///
+    /// **Why is this bad?** This might result in an invalid in-memory representation of a `bool`.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let _: bool = std::mem::transmute(x); // where x: u8
+    /// // should be:
+    /// let _: bool = x != 0;
+    /// ```
+    #[clippy::version = "0.0.166"]
     pub TRANSMUTE_INT_TO_BOOL,
     complexity,
     "transmutes from an integer to a `bool`"
 }
 
-/// **What it does:** Checks for transmutes from an integer to a float.
-///
-/// **Why is this bad?** This might result in an invalid in-memory representation of a float.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// let _: f32 = std::mem::transmute(x); // where x: u32
-/// // should be:
-/// let _: f32 = f32::from_bits(x);
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for transmutes from an integer to a float.
+    ///
+    /// **Why is this bad?** This might result in an invalid in-memory representation of a float.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let _: f32 = std::mem::transmute(x); // where x: u32
+    /// // should be:
+    /// let _: f32 = f32::from_bits(x);
+    /// ```
+    #[clippy::version = "0.0.166"]
     pub TRANSMUTE_INT_TO_FLOAT,
     complexity,
     "transmutes from an integer to a float"
 }
 
-/// **What it does:** Checks for transmutes from a pointer to a pointer, or
-/// from a reference to a reference.
-///
-/// **Why is this bad?** Transmutes are dangerous, and these can instead be
-/// written as casts.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// let ptr = &1u32 as *const u32;
-/// unsafe {
-///     // pointer-to-pointer transmute
-///     let _: *const f32 = std::mem::transmute(ptr);
-///     // ref-ref transmute
-///     let _: &f32 = std::mem::transmute(&1u32);
-/// }
-/// // These can be respectively written:
-/// let _ = ptr as *const f32
-/// let _ = unsafe{ &*(&1u32 as *const u32 as *const f32) };
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for transmutes from a pointer to a pointer, or
+    /// from a reference to a reference.
+    ///
+    /// **Why is this bad?** Transmutes are dangerous, and these can instead be
+    /// written as casts.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let ptr = &1u32 as *const u32;
+    /// unsafe {
+    ///     // pointer-to-pointer transmute
+    ///     let _: *const f32 = std::mem::transmute(ptr);
+    ///     // ref-ref transmute
+    ///     let _: &f32 = std::mem::transmute(&1u32);
+    /// }
+    /// // These can be respectively written:
+    /// let _ = ptr as *const f32
+    /// let _ = unsafe{ &*(&1u32 as *const u32 as *const f32) };
+    /// ```
+    #[clippy::version = "0.0.194"]
     pub TRANSMUTE_PTR_TO_PTR,
     complexity,
     "transmutes from a pointer to a pointer / a reference to a reference"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** Checks for transmutes that could be a pointer cast, or a
+    /// chain of pointer casts.
+    ///
+    /// **Why is this bad?** Readability. The `as` casts are checked by the
+    /// compiler, which can't verify much about a transmute.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// let r = &1u32;
+    /// unsafe {
+    ///     let _: usize = std::mem::transmute(r);
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let r = &1u32;
+    /// let _ = r as *const u32 as usize;
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub TRANSMUTES_EXPRESSIBLE_AS_PTR_CASTS,
+    complexity,
+    "transmutes that could be a pointer cast"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for transmutes between collections whose
+    /// types have different ABI, size or alignment.
+    ///
+    /// **Why is this bad?** This is undefined behavior.
+    ///
+    /// **Known problems:** Currently, we cannot know whether a type is a
+    /// collection, so we just lint the ones that come with `std`.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// // different size, therefore likely out-of-bounds memory access
+    /// // You absolutely do not want this in your code!
+    /// unsafe {
+    ///     std::mem::transmute::<_, Vec<u32>>(vec![2_u16])
+    /// };
+    /// ```
+    ///
+    /// You must always iterate, map and collect the values:
+    ///
+    /// ```rust
+    /// vec![2_u16].into_iter().map(u32::from).collect::<Vec<_>>();
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub UNSOUND_COLLECTION_TRANSMUTE,
+    correctness,
+    "transmute between collections of layout-incompatible types"
+}
+
 pub struct Transmute;
 
 impl LintPass for Transmute {
@@ -211,6 +278,8 @@ impl LintPass for Transmute {
             TRANSMUTE_BYTES_TO_STR,
             TRANSMUTE_INT_TO_BOOL,
             TRANSMUTE_INT_TO_FLOAT,
+            TRANSMUTES_EXPRESSIBLE_AS_PTR_CASTS,
+            UNSOUND_COLLECTION_TRANSMUTE,
         )
     }
 }
@@ -440,6 +509,60 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Transmute {
                                     },
                                 )
                             },
+                            (&ty::Ref(_, rty, rty_mutbl), &ty::Uint(ast::UintTy::Usize)) |
+                            (&ty::Ref(_, rty, rty_mutbl), &ty::Int(ast::IntTy::Isize)) => span_lint_and_then(
+                                cx,
+                                TRANSMUTES_EXPRESSIBLE_AS_PTR_CASTS,
+                                e.span,
+                                &format!(
+                                    "transmute from `{}` to `{}` which could be expressed as a pointer cast instead",
+                                    from_ty,
+                                    to_ty
+                                ),
+                                |db| if let Some(arg) = sugg::Sugg::hir_opt(cx, &args[0]) {
+                                    let ptr_ty = cx.tcx.mk_ptr(ty::TypeAndMut { ty: rty, mutbl: rty_mutbl });
+                                    db.span_suggestion(e.span, "try", arg.as_ty(ptr_ty).as_ty(to_ty).to_string());
+                                },
+                            ),
+                            (&ty::RawPtr(_), &ty::Uint(ast::UintTy::Usize)) |
+                            (&ty::RawPtr(_), &ty::Int(ast::IntTy::Isize)) |
+                            (&ty::FnPtr(_), &ty::Uint(ast::UintTy::Usize)) |
+                            (&ty::FnPtr(_), &ty::Int(ast::IntTy::Isize)) |
+                            (&ty::FnPtr(_), &ty::RawPtr(_)) => span_lint_and_then(
+                                cx,
+                                TRANSMUTES_EXPRESSIBLE_AS_PTR_CASTS,
+                                e.span,
+                                &format!(
+                                    "transmute from `{}` to `{}` which could be expressed as a pointer cast instead",
+                                    from_ty,
+                                    to_ty
+                                ),
+                                |db| if let Some(arg) = sugg::Sugg::hir_opt(cx, &args[0]) {
+                                    db.span_suggestion(e.span, "try", arg.as_ty(to_ty).to_string());
+                                },
+                            ),
+                            (&ty::Adt(from_adt, from_substs), &ty::Adt(to_adt, to_substs)) => {
+                                if from_adt.did != to_adt.did ||
+                                    !COLLECTIONS.iter().any(|path| match_def_path(cx.tcx, to_adt.did, path)) {
+                                    return;
+                                }
+                                if from_substs
+                                    .types()
+                                    .zip(to_substs.types())
+                                    .any(|(from_ty, to_ty)| is_layout_incompatible(cx, from_ty, to_ty))
+                                {
+                                    span_lint(
+                                        cx,
+                                        UNSOUND_COLLECTION_TRANSMUTE,
+                                        e.span,
+                                        &format!(
+                                            "transmute from `{}` to `{}` with mismatched layout is unsound",
+                                            from_ty,
+                                            to_ty
+                                        ),
+                                    );
+                                }
+                            },
                             _ => return,
                         };
                     }
@@ -449,6 +572,28 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Transmute {
     }
 }
 
+// Collections from `std`, their elements are stored out of line so `transmute`
+// only checks the size of the collection itself
+const COLLECTIONS: [&[&str]; 8] = [
+    &paths::VEC,
+    &paths::VEC_DEQUE,
+    &paths::BINARY_HEAP,
+    &paths::BTREESET,
+    &paths::BTREEMAP,
+    &paths::HASHSET,
+    &paths::HASHMAP,
+    &paths::LINKED_LIST,
+];
+
+/// Check if the type parameters of two collections can be exchanged, that is
+/// if they have the same size, alignment and ABI
+fn is_layout_incompatible<'tcx>(cx: &LateContext<'_, 'tcx>, from: Ty<'tcx>, to: Ty<'tcx>) -> bool {
+    match (cx.layout_of(from), cx.layout_of(to)) {
+        (Ok(from), Ok(to)) => from.size != to.size || from.align != to.align || from.abi != to.abi,
+        _ => false,
+    }
+}
+
 /// Get the snippet of `Bar` in `…::transmute<Foo, &Bar>`. If that snippet is
 /// not available , use
 /// the type's `ToString` implementation. In weird cases it could lead to types
@@ -0,0 +1,161 @@
+use crate::consts::{constant_simple, Constant};
+use crate::utils::msrvs::{self, MsrvStack, RustcVersion};
+use crate::utils::{in_macro, same_tys, span_help_and_lint};
+use rustc::hir::def::Def;
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintContext, LintPass};
+use rustc::ty;
+use rustc::{declare_lint, lint_array};
+use syntax::ast::{self, NodeId};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for tuples built from all the elements of an array
+    /// in order, like `(a[0], a[1])`, and for arrays built from all the fields of a
+    /// tuple in order, like `[t.0, t.1]`.
+    ///
+    /// **Why is this bad?** Arrays and tuples of the same length and item type can
+    /// be converted with `From` and `Into`, which is shorter and can't mix up the
+    /// indices.
+    ///
+    /// **Known problems:** The conversions need Rust 1.71, the lint is disabled if
+    /// the `msrv` is older. They are only implemented up to 12 elements.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let t = (1, 2);
+    /// let a = [t.0, t.1];
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// let t = (1, 2);
+    /// let a: [i32; 2] = t.into();
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub TUPLE_ARRAY_CONVERSIONS,
+    nursery,
+    "manual conversions between tuples and arrays"
+}
+
+/// The largest arrays and tuples converted with `From`
+const MAX_CONVERSION_LEN: usize = 12;
+
+pub struct TupleArrayConversions {
+    msrv: MsrvStack,
+}
+
+impl TupleArrayConversions {
+    pub fn new(msrv: Option<RustcVersion>) -> Self {
+        Self {
+            msrv: MsrvStack::new(msrv),
+        }
+    }
+}
+
+impl LintPass for TupleArrayConversions {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(TUPLE_ARRAY_CONVERSIONS)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for TupleArrayConversions {
+    fn enter_lint_attrs(&mut self, cx: &LateContext<'a, 'tcx>, attrs: &'tcx [ast::Attribute]) {
+        self.msrv.enter_lint_attrs(cx.sess(), attrs);
+    }
+
+    fn exit_lint_attrs(&mut self, _: &LateContext<'a, 'tcx>, attrs: &'tcx [ast::Attribute]) {
+        self.msrv.exit_lint_attrs(attrs);
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if in_macro(expr.span) || !self.msrv.meets(msrvs::TUPLE_ARRAY_CONVERSIONS) {
+            return;
+        }
+
+        match expr.node {
+            ExprKind::Array(ref elements) if is_tuple_to_array(cx, elements) => span_help_and_lint(
+                cx,
+                TUPLE_ARRAY_CONVERSIONS,
+                expr.span,
+                "it looks like you're trying to convert a tuple to an array",
+                "use `.into()` instead, or `<[T; N]>::from` if type annotations are needed",
+            ),
+            ExprKind::Tup(ref elements) if is_array_to_tuple(cx, elements) => span_help_and_lint(
+                cx,
+                TUPLE_ARRAY_CONVERSIONS,
+                expr.span,
+                "it looks like you're trying to convert an array to a tuple",
+                "use `.into()` instead, or `<(T0, T1, ..., Tn)>::from` if type annotations are needed",
+            ),
+            _ => {},
+        }
+    }
+}
+
+/// Checks for `[t.0, t.1, ..]` with all the fields of the tuple `t`, all of the same type
+fn is_tuple_to_array(cx: &LateContext<'_, '_>, elements: &[Expr]) -> bool {
+    let local = match same_local(elements, |element| match element.node {
+        ExprKind::Field(ref base, _) => Some(&**base),
+        _ => None,
+    }) {
+        Some(local) => local,
+        None => return false,
+    };
+    let field_tys = match cx.tables.expr_ty(local).sty {
+        ty::Tuple(tys) => tys,
+        _ => return false,
+    };
+    field_tys.len() == elements.len()
+        && field_tys.iter().all(|&ty| same_tys(cx, ty, field_tys[0]))
+        && elements.iter().enumerate().all(|(i, element)| match element.node {
+            ExprKind::Field(_, ident) => ident.name == i.to_string().as_str(),
+            _ => false,
+        })
+}
+
+/// Checks for `(a[0], a[1], ..)` with all the items of the array `a`
+fn is_array_to_tuple(cx: &LateContext<'_, '_>, elements: &[Expr]) -> bool {
+    let local = match same_local(elements, |element| match element.node {
+        ExprKind::Index(ref base, _) => Some(&**base),
+        _ => None,
+    }) {
+        Some(local) => local,
+        None => return false,
+    };
+    match cx.tables.expr_ty(local).sty {
+        ty::Array(_, len) if len.assert_usize(cx.tcx) == Some(elements.len() as u64) => {},
+        _ => return false,
+    }
+    elements.iter().enumerate().all(|(i, element)| match element.node {
+        ExprKind::Index(_, ref index) => match constant_simple(cx, cx.tables, index) {
+            Some(Constant::Int(index)) => index == i as u128,
+            _ => false,
+        },
+        _ => false,
+    })
+}
+
+/// Returns the base of the projections of the elements if it is the same local variable for
+/// all of them, and if there are few enough elements to be converted
+fn same_local<'e>(elements: &'e [Expr], base: impl Fn(&'e Expr) -> Option<&'e Expr>) -> Option<&'e Expr> {
+    if elements.is_empty() || elements.len() > MAX_CONVERSION_LEN {
+        return None;
+    }
+    let first = base(&elements[0])?;
+    let id = local_id(first)?;
+    if elements[1..].iter().all(|element| base(element).and_then(local_id) == Some(id)) {
+        Some(first)
+    } else {
+        None
+    }
+}
+
+/// Returns the id of the local variable `expr` refers to
+fn local_id(expr: &Expr) -> Option<NodeId> {
+    match expr.node {
+        ExprKind::Path(QPath::Resolved(None, ref path)) => match path.def {
+            Def::Local(id) => Some(id),
+            _ => None,
+        },
+        _ => None,
+    }
+}
@@ -2,21 +2,23 @@ use crate::reexport::*;
 use rustc::hir;
 use rustc::hir::*;
 use rustc::hir::intravisit::{walk_body, walk_expr, walk_ty, FnKind, NestedVisitorMap, Visitor};
-use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass, in_external_macro, LintContext};
+use rustc::lint::{LateContext, LateLintPass, Lint, LintArray, LintPass, in_external_macro, LintContext};
 use rustc::{declare_lint, lint_array};
 use if_chain::if_chain;
 use rustc::ty::{self, Ty, TyCtxt, TypeckTables};
 use rustc::ty::layout::LayoutOf;
+use rustc_errors::Applicability;
 use rustc_typeck::hir_ty_to_ty;
 use std::cmp::Ordering;
 use std::collections::BTreeMap;
 use std::borrow::Cow;
-use syntax::ast::{FloatTy, IntTy, UintTy};
+use syntax::ast::{Attribute, FloatTy, IntTy, UintTy};
 use syntax::source_map::Span;
 use syntax::errors::DiagnosticBuilder;
 use crate::utils::{comparisons, differing_macro_contexts, higher, in_constant, in_macro, last_path_segment, match_def_path, match_path,
             match_type, multispan_sugg, opt_def_id, same_tys, snippet, snippet_opt, span_help_and_lint, span_lint,
-            span_lint_and_sugg, span_lint_and_then, clip, unsext, sext, int_bits};
+            span_lint_and_sugg, span_lint_and_then, clip, unsext, sext, int_bits, LimitStack};
+use crate::utils::msrvs::{self, MsrvStack, RustcVersion};
 use crate::utils::paths;
 use crate::consts::{constant, Constant};
 
@@ -24,108 +26,112 @@ use crate::consts::{constant, Constant};
 #[allow(missing_copy_implementations)]
 pub struct TypePass;
 
-/// **What it does:** Checks for use of `Box<Vec<_>>` anywhere in the code.
-///
-/// **Why is this bad?** `Vec` already keeps its contents in a separate area on
-/// the heap. So if you `Box` it, you just add another level of indirection
-/// without any benefit whatsoever.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// struct X {
-///     values: Box<Vec<Foo>>,
-/// }
-/// ```
-///
-/// Better:
-///
-/// ```rust
-/// struct X {
-///     values: Vec<Foo>,
-/// }
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for use of `Box<Vec<_>>` anywhere in the code.
+    ///
+    /// **Why is this bad?** `Vec` already keeps its contents in a separate area on
+    /// the heap. So if you `Box` it, you just add another level of indirection
+    /// without any benefit whatsoever.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// struct X {
+    ///     values: Box<Vec<Foo>>,
+    /// }
+    /// ```
+    ///
+    /// Better:
+    ///
+    /// ```rust
+    /// struct X {
+    ///     values: Vec<Foo>,
+    /// }
+    /// ```
+    #[clippy::version = "pre 0.0.213"]
     pub BOX_VEC,
     perf,
     "usage of `Box<Vec<T>>`, vector elements are already on the heap"
 }
 
-/// **What it does:** Checks for use of `Option<Option<_>>` in function signatures and type
-/// definitions
-///
-/// **Why is this bad?** `Option<_>` represents an optional value. `Option<Option<_>>`
-/// represents an optional optional value which is logically the same thing as an optional
-/// value but has an unneeded extra level of wrapping.
-///
-/// **Known problems:** None.
-///
-/// **Example**
-/// ```rust
-/// fn x() -> Option<Option<u32>> {
-///     None
-/// }
 declare_clippy_lint! {
+    /// **What it does:** Checks for use of `Option<Option<_>>` in function signatures and type
+    /// definitions
+    ///
+    /// **Why is this bad?** `Option<_>` represents an optional value. `Option<Option<_>>`
+    /// represents an optional optional value which is logically the same thing as an optional
+    /// value but has an unneeded extra level of wrapping.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example**
+    /// ```rust
+    /// fn x() -> Option<Option<u32>> {
+    ///     None
+    /// }
+    #[clippy::version = "0.0.181"]
     pub OPTION_OPTION,
     complexity,
     "usage of `Option<Option<T>>`"
 }
 
-/// **What it does:** Checks for usage of any `LinkedList`, suggesting to use a
-/// `Vec` or a `VecDeque` (formerly called `RingBuf`).
-///
-/// **Why is this bad?** Gankro says:
-///
-/// > The TL;DR of `LinkedList` is that it's built on a massive amount of
-/// pointers and indirection.
-/// > It wastes memory, it has terrible cache locality, and is all-around slow.
-/// `RingBuf`, while
-/// > "only" amortized for push/pop, should be faster in the general case for
-/// almost every possible
-/// > workload, and isn't even amortized at all if you can predict the capacity
-/// you need.
-/// >
-/// > `LinkedList`s are only really good if you're doing a lot of merging or
-/// splitting of lists.
-/// > This is because they can just mangle some pointers instead of actually
-/// copying the data. Even
-/// > if you're doing a lot of insertion in the middle of the list, `RingBuf`
-/// can still be better
-/// > because of how expensive it is to seek to the middle of a `LinkedList`.
-///
-/// **Known problems:** False positives – the instances where using a
-/// `LinkedList` makes sense are few and far between, but they can still happen.
-///
-/// **Example:**
-/// ```rust
-/// let x = LinkedList::new();
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for usage of any `LinkedList`, suggesting to use a
+    /// `Vec` or a `VecDeque` (formerly called `RingBuf`).
+    ///
+    /// **Why is this bad?** Gankro says:
+    ///
+    /// > The TL;DR of `LinkedList` is that it's built on a massive amount of
+    /// pointers and indirection.
+    /// > It wastes memory, it has terrible cache locality, and is all-around slow.
+    /// `RingBuf`, while
+    /// > "only" amortized for push/pop, should be faster in the general case for
+    /// almost every possible
+    /// > workload, and isn't even amortized at all if you can predict the capacity
+    /// you need.
+    /// >
+    /// > `LinkedList`s are only really good if you're doing a lot of merging or
+    /// splitting of lists.
+    /// > This is because they can just mangle some pointers instead of actually
+    /// copying the data. Even
+    /// > if you're doing a lot of insertion in the middle of the list, `RingBuf`
+    /// can still be better
+    /// > because of how expensive it is to seek to the middle of a `LinkedList`.
+    ///
+    /// **Known problems:** False positives – the instances where using a
+    /// `LinkedList` makes sense are few and far between, but they can still happen.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let x = LinkedList::new();
+    /// ```
+    #[clippy::version = "pre 0.0.213"]
     pub LINKEDLIST,
     pedantic,
     "usage of LinkedList, usually a vector is faster, or a more specialized data \
      structure like a VecDeque"
 }
 
-/// **What it does:** Checks for use of `&Box<T>` anywhere in the code.
-///
-/// **Why is this bad?** Any `&Box<T>` can also be a `&T`, which is more
-/// general.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// fn foo(bar: &Box<T>) { ... }
-/// ```
-///
-/// Better:
-///
-/// ```rust
-/// fn foo(bar: &T) { ... }
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for use of `&Box<T>` anywhere in the code.
+    ///
+    /// **Why is this bad?** Any `&Box<T>` can also be a `&T`, which is more
+    /// general.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// fn foo(bar: &Box<T>) { ... }
+    /// ```
+    ///
+    /// Better:
+    ///
+    /// ```rust
+    /// fn foo(bar: &T) { ... }
+    /// ```
+    #[clippy::version = "pre 0.0.213"]
     pub BORROWED_BOX,
     complexity,
     "a borrow of a boxed type"
@@ -361,18 +367,19 @@ fn is_any_trait(t: &hir::Ty) -> bool {
 #[allow(missing_copy_implementations)]
 pub struct LetPass;
 
-/// **What it does:** Checks for binding a unit value.
-///
-/// **Why is this bad?** A unit value cannot usefully be used anywhere. So
-/// binding one is kind of pointless.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// let x = { 1; };
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for binding a unit value.
+    ///
+    /// **Why is this bad?** A unit value cannot usefully be used anywhere. So
+    /// binding one is kind of pointless.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let x = { 1; };
+    /// ```
+    #[clippy::version = "pre 0.0.213"]
     pub LET_UNIT_VALUE,
     style,
     "creating a let binding to a value of unit type, which usually can't be used afterwards"
@@ -412,23 +419,24 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for LetPass {
     }
 }
 
-/// **What it does:** Checks for comparisons to unit.
-///
-/// **Why is this bad?** Unit is always equal to itself, and thus is just a
-/// clumsily written constant. Mostly this happens when someone accidentally
-/// adds semicolons at the end of the operands.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// if { foo(); } == { bar(); } { baz(); }
-/// ```
-/// is equal to
-/// ```rust
-/// { foo(); bar(); baz(); }
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for comparisons to unit.
+    ///
+    /// **Why is this bad?** Unit is always equal to itself, and thus is just a
+    /// clumsily written constant. Mostly this happens when someone accidentally
+    /// adds semicolons at the end of the operands.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// if { foo(); } == { bar(); } { baz(); }
+    /// ```
+    /// is equal to
+    /// ```rust
+    /// { foo(); bar(); baz(); }
+    /// ```
+    #[clippy::version = "pre 0.0.213"]
     pub UNIT_CMP,
     correctness,
     "comparing unit values"
@@ -470,20 +478,21 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for UnitCmp {
     }
 }
 
-/// **What it does:** Checks for passing a unit value as an argument to a function without using a unit literal (`()`).
-///
-/// **Why is this bad?** This is likely the result of an accidental semicolon.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// foo({
-///   let a = bar();
-///   baz(a);
-/// })
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for passing a unit value as an argument to a function without using a unit literal (`()`).
+    ///
+    /// **Why is this bad?** This is likely the result of an accidental semicolon.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// foo({
+    ///   let a = bar();
+    ///   baz(a);
+    /// })
+    /// ```
+    #[clippy::version = "0.0.181"]
     pub UNIT_ARG,
     complexity,
     "passing unit to a function"
@@ -562,195 +571,261 @@ fn is_unit_literal(expr: &Expr) -> bool {
     }
 }
 
-pub struct CastPass;
+pub struct CastPass {
+    msrv: MsrvStack,
+}
+
+impl CastPass {
+    pub fn new(msrv: Option<RustcVersion>) -> Self {
+        Self {
+            msrv: MsrvStack::new(msrv),
+        }
+    }
+}
 
-/// **What it does:** Checks for casts from any numerical to a float type where
-/// the receiving type cannot store all values from the original type without
-/// rounding errors. This possible rounding is to be expected, so this lint is
-/// `Allow` by default.
-///
-/// Basically, this warns on casting any integer with 32 or more bits to `f32`
-/// or any 64-bit integer to `f64`.
-///
-/// **Why is this bad?** It's not bad at all. But in some applications it can be
-/// helpful to know where precision loss can take place. This lint can help find
-/// those places in the code.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// let x = u64::MAX; x as f64
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for casts from any numerical to a float type where
+    /// the receiving type cannot store all values from the original type without
+    /// rounding errors. This possible rounding is to be expected, so this lint is
+    /// `Allow` by default.
+    ///
+    /// Basically, this warns on casting any integer with 32 or more bits to `f32`
+    /// or any 64-bit integer to `f64`.
+    ///
+    /// **Why is this bad?** It's not bad at all. But in some applications it can be
+    /// helpful to know where precision loss can take place. This lint can help find
+    /// those places in the code.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let x = u64::MAX; x as f64
+    /// ```
+    #[clippy::version = "pre 0.0.213"]
     pub CAST_PRECISION_LOSS,
     pedantic,
     "casts that cause loss of precision, e.g. `x as f32` where `x: u64`"
 }
 
-/// **What it does:** Checks for casts from a signed to an unsigned numerical
-/// type. In this case, negative values wrap around to large positive values,
-/// which can be quite surprising in practice. However, as the cast works as
-/// defined, this lint is `Allow` by default.
-///
-/// **Why is this bad?** Possibly surprising results. You can activate this lint
-/// as a one-time check to see where numerical wrapping can arise.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// let y: i8 = -1;
-/// y as u128  // will return 18446744073709551615
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for casts from a signed to an unsigned numerical
+    /// type. In this case, negative values wrap around to large positive values,
+    /// which can be quite surprising in practice. However, as the cast works as
+    /// defined, this lint is `Allow` by default.
+    ///
+    /// **Why is this bad?** Possibly surprising results. You can activate this lint
+    /// as a one-time check to see where numerical wrapping can arise.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let y: i8 = -1;
+    /// y as u128  // will return 18446744073709551615
+    /// ```
+    ///
+    /// If a negative value is an error, use `TryFrom` (Rust 1.34+):
+    /// ```rust
+    /// use std::convert::TryFrom;
+    ///
+    /// let y: i8 = -1;
+    /// u128::try_from(y) // returns an error
+    /// ```
+    #[clippy::version = "pre 0.0.213"]
     pub CAST_SIGN_LOSS,
     pedantic,
     "casts from signed types to unsigned types, e.g. `x as u32` where `x: i32`"
 }
 
-/// **What it does:** Checks for on casts between numerical types that may
-/// truncate large values. This is expected behavior, so the cast is `Allow` by
-/// default.
-///
-/// **Why is this bad?** In some problem domains, it is good practice to avoid
-/// truncation. This lint can be activated to help assess where additional
-/// checks could be beneficial.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// fn as_u8(x: u64) -> u8 { x as u8 }
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for on casts between numerical types that may
+    /// truncate large values. This is expected behavior, so the cast is `Allow` by
+    /// default.
+    ///
+    /// **Why is this bad?** In some problem domains, it is good practice to avoid
+    /// truncation. This lint can be activated to help assess where additional
+    /// checks could be beneficial.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// fn as_u8(x: u64) -> u8 { x as u8 }
+    /// ```
+    ///
+    /// If a value that does not fit is an error, use `TryFrom` (Rust 1.34+):
+    /// ```rust
+    /// use std::convert::TryFrom;
+    /// use std::num::TryFromIntError;
+    ///
+    /// fn as_u8(x: u64) -> Result<u8, TryFromIntError> { u8::try_from(x) }
+    /// ```
+    #[clippy::version = "pre 0.0.213"]
     pub CAST_POSSIBLE_TRUNCATION,
     pedantic,
     "casts that may cause truncation of the value, e.g. `x as u8` where `x: u32`, \
      or `x as i32` where `x: f32`"
 }
 
-/// **What it does:** Checks for casts from an unsigned type to a signed type of
-/// the same size. Performing such a cast is a 'no-op' for the compiler,
-/// i.e. nothing is changed at the bit level, and the binary representation of
-/// the value is reinterpreted. This can cause wrapping if the value is too big
-/// for the target signed type. However, the cast works as defined, so this lint
-/// is `Allow` by default.
-///
-/// **Why is this bad?** While such a cast is not bad in itself, the results can
-/// be surprising when this is not the intended behavior, as demonstrated by the
-/// example below.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// u32::MAX as i32  // will yield a value of `-1`
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for casts from an unsigned type to a signed type of
+    /// the same size. Performing such a cast is a 'no-op' for the compiler,
+    /// i.e. nothing is changed at the bit level, and the binary representation of
+    /// the value is reinterpreted. This can cause wrapping if the value is too big
+    /// for the target signed type. However, the cast works as defined, so this lint
+    /// is `Allow` by default.
+    ///
+    /// **Why is this bad?** While such a cast is not bad in itself, the results can
+    /// be surprising when this is not the intended behavior, as demonstrated by the
+    /// example below.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// u32::MAX as i32  // will yield a value of `-1`
+    /// ```
+    #[clippy::version = "pre 0.0.213"]
     pub CAST_POSSIBLE_WRAP,
     pedantic,
     "casts that may cause wrapping around the value, e.g. `x as i32` where `x: u32` \
      and `x > i32::MAX`"
 }
 
-/// **What it does:** Checks for on casts between numerical types that may
-/// be replaced by safe conversion functions.
-///
-/// **Why is this bad?** Rust's `as` keyword will perform many kinds of
-/// conversions, including silently lossy conversions. Conversion functions such
-/// as `i32::from` will only perform lossless conversions. Using the conversion
-/// functions prevents conversions from turning into silent lossy conversions if
-/// the types of the input expressions ever change, and make it easier for
-/// people reading the code to know that the conversion is lossless.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// fn as_u64(x: u8) -> u64 { x as u64 }
-/// ```
-///
-/// Using `::from` would look like this:
-///
-/// ```rust
-/// fn as_u64(x: u8) -> u64 { u64::from(x) }
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for on casts between numerical types that may
+    /// be replaced by safe conversion functions.
+    ///
+    /// **Why is this bad?** Rust's `as` keyword will perform many kinds of
+    /// conversions, including silently lossy conversions. Conversion functions such
+    /// as `i32::from` will only perform lossless conversions. Using the conversion
+    /// functions prevents conversions from turning into silent lossy conversions if
+    /// the types of the input expressions ever change, and make it easier for
+    /// people reading the code to know that the conversion is lossless.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// fn as_u64(x: u8) -> u64 { x as u64 }
+    /// ```
+    ///
+    /// Using `::from` would look like this:
+    ///
+    /// ```rust
+    /// fn as_u64(x: u8) -> u64 { u64::from(x) }
+    /// ```
+    #[clippy::version = "0.0.155"]
     pub CAST_LOSSLESS,
     complexity,
     "casts using `as` that are known to be lossless, e.g. `x as u64` where `x: u8`"
 }
 
-/// **What it does:** Checks for casts to the same type.
-///
-/// **Why is this bad?** It's just unnecessary.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// let _ = 2i32 as i32
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for casts to the same type.
+    ///
+    /// **Why is this bad?** It's just unnecessary.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let _ = 2i32 as i32
+    /// ```
+    #[clippy::version = "pre 0.0.213"]
     pub UNNECESSARY_CAST,
     complexity,
     "cast to the same type, e.g. `x as i32` where `x: i32`"
 }
 
-/// **What it does:** Checks for casts of a function pointer to a numeric type not enough to store address.
-///
-/// **Why is this bad?** Casting a function pointer to not eligible type could truncate the address value.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// fn test_fn() -> i16;
-/// let _ = test_fn as i32
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for casts of a function pointer to a numeric type not enough to store address.
+    ///
+    /// **Why is this bad?** Casting a function pointer to not eligible type could truncate the address value.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// fn test_fn() -> i16;
+    /// let _ = test_fn as i32
+    /// ```
+    #[clippy::version = "pre 0.0.213"]
     pub FN_TO_NUMERIC_CAST_WITH_TRUNCATION,
     correctness,
     "cast function pointer to the numeric type with value truncation"
 }
 
-/// **What it does:** Checks for casts of a function pointer to a numeric type except `usize`.
-///
-/// **Why is this bad?** Casting a function pointer to something other than `usize` is not a good style.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// fn test_fn() -> i16;
-/// let _ = test_fn as i128
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for casts of a function pointer to a numeric type except `usize`.
+    ///
+    /// **Why is this bad?** Casting a function pointer to something other than `usize` is not a good style.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// fn test_fn() -> i16;
+    /// let _ = test_fn as i128
+    /// ```
+    #[clippy::version = "pre 0.0.213"]
     pub FN_TO_NUMERIC_CAST,
     style,
     "cast function pointer to the numeric type"
 }
 
-/// **What it does:** Checks for casts from a less-strictly-aligned pointer to a
-/// more-strictly-aligned pointer
-///
-/// **Why is this bad?** Dereferencing the resulting pointer may be undefined
-/// behavior.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// let _ = (&1u8 as *const u8) as *const u16;
-/// let _ = (&mut 1u8 as *mut u8) as *mut u16;
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for casts from a less-strictly-aligned pointer to a
+    /// more-strictly-aligned pointer
+    ///
+    /// **Why is this bad?** Dereferencing the resulting pointer may be undefined
+    /// behavior.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let _ = (&1u8 as *const u8) as *const u16;
+    /// let _ = (&mut 1u8 as *mut u8) as *mut u16;
+    /// ```
+    #[clippy::version = "0.0.194"]
     pub CAST_PTR_ALIGNMENT,
     correctness,
     "cast from a pointer to a more-strictly-aligned pointer"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** Checks for usage of `as` conversions.
+    ///
+    /// **Why is this bad?** `as` conversions silently truncate, wrap or round
+    /// values, whereas `From`, `TryFrom` and the methods of pointers and floats
+    /// make it explicit whether the conversion can lose information. Some
+    /// codebases forbid `as` for this reason.
+    ///
+    /// **Known problems:** Some conversions, e.g. from a float to an integer, have
+    /// no alternative that is as short.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let a: u32 = 42;
+    /// let b = a as u64;
+    /// let c = a as u8;
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// use std::convert::TryFrom;
+    ///
+    /// let a: u32 = 42;
+    /// let b = u64::from(a);
+    /// let c = u8::try_from(a);
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub AS_CONVERSIONS,
+    restriction,
+    "using a potentially dangerous silent `as` conversion"
+}
+
 /// Returns the size in bits of an integral type.
 /// Will return 0 if the type is not an int or uint variant
 fn int_ty_to_nbits(typ: Ty<'_>, tcx: TyCtxt<'_, '_, '_>) -> u64 {
@@ -782,6 +857,47 @@ fn is_isize_or_usize(typ: Ty<'_>) -> bool {
     }
 }
 
+/// Returns true if `T::from` exists for the numerical conversion from `cast_from` to `cast_to`
+fn is_lossless_numeric_cast(cx: &LateContext<'_, '_>, cast_from: Ty<'_>, cast_to: Ty<'_>) -> bool {
+    let from_nbits = int_ty_to_nbits(cast_from, cx.tcx);
+    match (cast_from.is_integral(), &cast_to.sty) {
+        (true, ty::Float(fty)) => {
+            let to_nbits = if *fty == FloatTy::F32 { 32 } else { 64 };
+            !is_isize_or_usize(cast_from) && from_nbits < to_nbits
+        },
+        (true, _) => {
+            !is_isize_or_usize(cast_from)
+                && !is_isize_or_usize(cast_to)
+                && from_nbits < int_ty_to_nbits(cast_to, cx.tcx)
+                && !(cast_from.is_signed() && !cast_to.is_signed())
+        },
+        (false, _) => cast_from.sty == ty::Float(FloatTy::F32) && cast_to.sty == ty::Float(FloatTy::F64),
+    }
+}
+
+/// Returns the help message of `AS_CONVERSIONS`, naming the alternative to the `as` conversion
+fn as_conversion_alternative(cx: &LateContext<'_, '_>, cast_from: Ty<'_>, cast_to: Ty<'_>) -> &'static str {
+    match (&cast_from.sty, &cast_to.sty) {
+        _ if cast_from.is_numeric() && cast_to.is_numeric() => {
+            if is_lossless_numeric_cast(cx, cast_from, cast_to) {
+                "consider using `From`, the conversion is lossless"
+            } else if cast_from.is_integral() && cast_to.is_integral() {
+                "consider using `TryFrom`, the conversion may fail"
+            } else if cast_to.is_integral() {
+                "consider rounding the float explicitly and checking that it is in the range of the integer type"
+            } else {
+                "consider checking that the value can be represented exactly by the target type"
+            }
+        },
+        (ty::Bool, _) | (ty::Char, ty::Uint(UintTy::U32)) | (ty::Char, ty::Uint(UintTy::U64)) |
+        (ty::Char, ty::Uint(UintTy::U128)) | (ty::Uint(UintTy::U8), ty::Char) => {
+            "consider using `From`, the conversion is lossless"
+        },
+        (ty::RawPtr(_), ty::RawPtr(_)) => "consider using the `cast` method of pointers",
+        _ => "consider using a conversion method instead",
+    }
+}
+
 fn span_precision_loss_lint(cx: &LateContext<'_, '_>, expr: &Expr, cast_from: Ty<'_>, cast_to_f64: bool) {
     let mantissa_nbits = if cast_to_f64 { 52 } else { 23 };
     let arch_dependent = is_isize_or_usize(cast_from) && cast_to_f64;
@@ -854,7 +970,55 @@ enum ArchSuffix {
     None,
 }
 
-fn check_truncation_and_wrapping(cx: &LateContext<'_, '_>, expr: &Expr, cast_from: Ty<'_>, cast_to: Ty<'_>) {
+/// Lints an integer cast that may lose information. If `try_from` is set, the cast is suggested
+/// to be replaced with `<T as TryFrom<_>>::try_from(x)`, which makes the error explicit. The
+/// trait is named by its full path as it isn't in the prelude.
+fn span_lossy_int_cast_lint(
+    cx: &LateContext<'_, '_>,
+    lint: &'static Lint,
+    expr: &Expr,
+    op: &Expr,
+    cast_to: Ty<'_>,
+    msg: &str,
+    try_from: bool,
+) {
+    if !try_from {
+        span_lint(cx, lint, expr.span, msg);
+        return;
+    }
+    span_lint_and_then(cx, lint, expr.span, msg, |db| {
+        db.span_suggestion_with_applicability(
+            expr.span,
+            "if this is not intended, try",
+            format!(
+                "<{} as std::convert::TryFrom<_>>::try_from({})",
+                cast_to,
+                snippet(cx, op.span, "..")
+            ),
+            Applicability::MaybeIncorrect,
+        );
+        db.note("`try_from` returns an error if the value does not fit, which then needs to be handled");
+    });
+}
+
+/// Returns true if `TryFrom` can be suggested instead of the cast of `op`: it is a simple place,
+/// so that the suggestion stays readable, and the call is allowed where the cast is.
+fn can_suggest_try_from(cx: &LateContext<'_, '_>, msrv: &MsrvStack, expr: &Expr, op: &Expr) -> bool {
+    let is_place = match op.node {
+        ExprKind::Path(QPath::Resolved(None, _)) | ExprKind::Field(..) => true,
+        _ => false,
+    };
+    is_place && msrv.meets(msrvs::TRY_FROM) && !in_constant(cx, expr.id)
+}
+
+fn check_truncation_and_wrapping(
+    cx: &LateContext<'_, '_>,
+    expr: &Expr,
+    op: &Expr,
+    cast_from: Ty<'_>,
+    cast_to: Ty<'_>,
+    try_from: bool,
+) {
     let arch_64_suffix = " on targets with 64-bit wide pointers";
     let arch_32_suffix = " on targets with 32-bit wide pointers";
     let cast_unsigned_to_signed = !cast_from.is_signed() && cast_to.is_signed();
@@ -890,10 +1054,12 @@ fn check_truncation_and_wrapping(cx: &LateContext<'_, '_>, expr: &Expr, cast_fro
             ),
         };
     if span_truncation {
-        span_lint(
+        span_lossy_int_cast_lint(
             cx,
             CAST_POSSIBLE_TRUNCATION,
-            expr.span,
+            expr,
+            op,
+            cast_to,
             &format!(
                 "casting {} to {} may truncate the value{}",
                 cast_from,
@@ -904,6 +1070,7 @@ fn check_truncation_and_wrapping(cx: &LateContext<'_, '_>, expr: &Expr, cast_fro
                     ArchSuffix::None => "",
                 }
             ),
+            try_from,
         );
     }
     if span_wrap {
@@ -947,14 +1114,32 @@ impl LintPass for CastPass {
             CAST_PTR_ALIGNMENT,
             FN_TO_NUMERIC_CAST,
             FN_TO_NUMERIC_CAST_WITH_TRUNCATION,
+            AS_CONVERSIONS,
         )
     }
 }
 
 impl<'a, 'tcx> LateLintPass<'a, 'tcx> for CastPass {
+    fn enter_lint_attrs(&mut self, cx: &LateContext<'a, 'tcx>, attrs: &'tcx [Attribute]) {
+        self.msrv.enter_lint_attrs(cx.sess(), attrs);
+    }
+
+    fn exit_lint_attrs(&mut self, _: &LateContext<'a, 'tcx>, attrs: &'tcx [Attribute]) {
+        self.msrv.exit_lint_attrs(attrs);
+    }
+
     fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
         if let ExprKind::Cast(ref ex, _) = expr.node {
             let (cast_from, cast_to) = (cx.tables.expr_ty(ex), cx.tables.expr_ty(expr));
+            if !in_external_macro(cx.sess(), expr.span) {
+                span_help_and_lint(
+                    cx,
+                    AS_CONVERSIONS,
+                    expr.span,
+                    "using a potentially dangerous silent `as` conversion",
+                    as_conversion_alternative(cx, cast_from, cast_to),
+                );
+            }
             if let ExprKind::Lit(ref lit) = ex.node {
                 use syntax::ast::{LitIntType, LitKind};
                 match lit.node {
@@ -1002,15 +1187,19 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for CastPass {
                         }
                     },
                     (true, true) => {
+                        let try_from = can_suggest_try_from(cx, &self.msrv, expr, ex);
                         if cast_from.is_signed() && !cast_to.is_signed() {
-                            span_lint(
+                            span_lossy_int_cast_lint(
                                 cx,
                                 CAST_SIGN_LOSS,
-                                expr.span,
+                                expr,
+                                ex,
+                                cast_to,
                                 &format!("casting {} to {} may lose the sign of the value", cast_from, cast_to),
+                                try_from,
                             );
                         }
-                        check_truncation_and_wrapping(cx, expr, cast_from, cast_to);
+                        check_truncation_and_wrapping(cx, expr, ex, cast_from, cast_to, try_from);
                         check_lossless(cx, expr, ex, cast_from, cast_to);
                     },
                     (false, false) => {
@@ -1086,19 +1275,20 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for CastPass {
     }
 }
 
-/// **What it does:** Checks for types used in structs, parameters and `let`
-/// declarations above a certain complexity threshold.
-///
-/// **Why is this bad?** Too complex types make the code less readable. Consider
-/// using a `type` definition to simplify them.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// struct Foo { inner: Rc<Vec<Vec<Box<(u32, u32, u32, u32)>>>> }
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for types used in structs, parameters and `let`
+    /// declarations above a certain complexity threshold.
+    ///
+    /// **Why is this bad?** Too complex types make the code less readable. Consider
+    /// using a `type` definition to simplify them.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// struct Foo { inner: Rc<Vec<Vec<Box<(u32, u32, u32, u32)>>>> }
+    /// ```
+    #[clippy::version = "pre 0.0.213"]
     pub TYPE_COMPLEXITY,
     complexity,
     "usage of very complex types that might be better factored into `type` definitions"
@@ -1106,13 +1296,13 @@ declare_clippy_lint! {
 
 #[allow(missing_copy_implementations)]
 pub struct TypeComplexityPass {
-    threshold: u64,
+    threshold: LimitStack,
 }
 
 impl TypeComplexityPass {
     pub fn new(threshold: u64) -> Self {
         Self {
-            threshold,
+            threshold: LimitStack::new(threshold),
         }
     }
 }
@@ -1124,6 +1314,14 @@ impl LintPass for TypeComplexityPass {
 }
 
 impl<'a, 'tcx> LateLintPass<'a, 'tcx> for TypeComplexityPass {
+    fn enter_lint_attrs(&mut self, cx: &LateContext<'a, 'tcx>, attrs: &'tcx [Attribute]) {
+        self.threshold.push_attrs(cx.sess(), attrs, "type_complexity");
+    }
+
+    fn exit_lint_attrs(&mut self, cx: &LateContext<'a, 'tcx>, attrs: &'tcx [Attribute]) {
+        self.threshold.pop_attrs(cx.sess(), attrs, "type_complexity");
+    }
+
     fn check_fn(
         &mut self,
         cx: &LateContext<'a, 'tcx>,
@@ -1193,7 +1391,7 @@ impl<'a, 'tcx> TypeComplexityPass {
             visitor.score
         };
 
-        if score > self.threshold {
+        if score > self.threshold.limit() {
             span_lint(
                 cx,
                 TYPE_COMPLEXITY,
@@ -1252,28 +1450,29 @@ impl<'tcx> Visitor<'tcx> for TypeComplexityVisitor {
     }
 }
 
-/// **What it does:** Checks for expressions where a character literal is cast
-/// to `u8` and suggests using a byte literal instead.
-///
-/// **Why is this bad?** In general, casting values to smaller types is
-/// error-prone and should be avoided where possible. In the particular case of
-/// converting a character literal to u8, it is easy to avoid by just using a
-/// byte literal instead. As an added bonus, `b'a'` is even slightly shorter
-/// than `'a' as u8`.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// 'x' as u8
-/// ```
-///
-/// A better version, using the byte literal:
-///
-/// ```rust
-/// b'x'
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for expressions where a character literal is cast
+    /// to `u8` and suggests using a byte literal instead.
+    ///
+    /// **Why is this bad?** In general, casting values to smaller types is
+    /// error-prone and should be avoided where possible. In the particular case of
+    /// converting a character literal to u8, it is easy to avoid by just using a
+    /// byte literal instead. As an added bonus, `b'a'` is even slightly shorter
+    /// than `'a' as u8`.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// 'x' as u8
+    /// ```
+    ///
+    /// A better version, using the byte literal:
+    ///
+    /// ```rust
+    /// b'x'
+    /// ```
+    #[clippy::version = "pre 0.0.213"]
     pub CHAR_LIT_AS_U8,
     complexity,
     "casting a character literal to u8"
@@ -1307,28 +1506,29 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for CharLitAsU8 {
     }
 }
 
-/// **What it does:** Checks for comparisons where one side of the relation is
-/// either the minimum or maximum value for its type and warns if it involves a
-/// case that is always true or always false. Only integer and boolean types are
-/// checked.
-///
-/// **Why is this bad?** An expression like `min <= x` may misleadingly imply
-/// that is is possible for `x` to be less than the minimum. Expressions like
-/// `max < x` are probably mistakes.
-///
-/// **Known problems:** For `usize` the size of the current compile target will
-/// be assumed (e.g. 64 bits on 64 bit systems). This means code that uses such
-/// a comparison to detect target pointer width will trigger this lint. One can
-/// use `mem::sizeof` and compare its value or conditional compilation
-/// attributes
-/// like `#[cfg(target_pointer_width = "64")] ..` instead.
-///
-/// **Example:**
-/// ```rust
-/// vec.len() <= 0
-/// 100 > std::i32::MAX
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for comparisons where one side of the relation is
+    /// either the minimum or maximum value for its type and warns if it involves a
+    /// case that is always true or always false. Only integer and boolean types are
+    /// checked.
+    ///
+    /// **Why is this bad?** An expression like `min <= x` may misleadingly imply
+    /// that is is possible for `x` to be less than the minimum. Expressions like
+    /// `max < x` are probably mistakes.
+    ///
+    /// **Known problems:** For `usize` the size of the current compile target will
+    /// be assumed (e.g. 64 bits on 64 bit systems). This means code that uses such
+    /// a comparison to detect target pointer width will trigger this lint. One can
+    /// use `mem::sizeof` and compare its value or conditional compilation
+    /// attributes
+    /// like `#[cfg(target_pointer_width = "64")] ..` instead.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// vec.len() <= 0
+    /// 100 > std::i32::MAX
+    /// ```
+    #[clippy::version = "pre 0.0.213"]
     pub ABSURD_EXTREME_COMPARISONS,
     correctness,
     "a comparison with a maximum or minimum value that is always true or false"
@@ -1489,22 +1689,23 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for AbsurdExtremeComparisons {
     }
 }
 
-/// **What it does:** Checks for comparisons where the relation is always either
-/// true or false, but where one side has been upcast so that the comparison is
-/// necessary. Only integer types are checked.
-///
-/// **Why is this bad?** An expression like `let x : u8 = ...; (x as u32) > 300`
-/// will mistakenly imply that it is possible for `x` to be outside the range of
-/// `u8`.
-///
-/// **Known problems:**
-/// https://github.com/rust-lang-nursery/rust-clippy/issues/886
-///
-/// **Example:**
-/// ```rust
-/// let x : u8 = ...; (x as u32) > 300
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for comparisons where the relation is always either
+    /// true or false, but where one side has been upcast so that the comparison is
+    /// necessary. Only integer types are checked.
+    ///
+    /// **Why is this bad?** An expression like `let x : u8 = ...; (x as u32) > 300`
+    /// will mistakenly imply that it is possible for `x` to be outside the range of
+    /// `u8`.
+    ///
+    /// **Known problems:**
+    /// https://github.com/rust-lang-nursery/rust-clippy/issues/886
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let x : u8 = ...; (x as u32) > 300
+    /// ```
+    #[clippy::version = "0.0.61"]
     pub INVALID_UPCAST_COMPARISONS,
     pedantic,
     "a comparison involving an upcast which is always true or false"
@@ -1712,24 +1913,25 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for InvalidUpcastComparisons {
     }
 }
 
-/// **What it does:** Checks for public `impl` or `fn` missing generalization
-/// over different hashers and implicitly defaulting to the default hashing
-/// algorithm (SipHash).
-///
-/// **Why is this bad?** `HashMap` or `HashSet` with custom hashers cannot be
-/// used with them.
-///
-/// **Known problems:** Suggestions for replacing constructors can contain
-/// false-positives. Also applying suggestions can require modification of other
-/// pieces of code, possibly including external crates.
-///
-/// **Example:**
-/// ```rust
-/// impl<K: Hash + Eq, V> Serialize for HashMap<K, V> { ... }
-///
-/// pub foo(map: &mut HashMap<i32, i32>) { .. }
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for public `impl` or `fn` missing generalization
+    /// over different hashers and implicitly defaulting to the default hashing
+    /// algorithm (SipHash).
+    ///
+    /// **Why is this bad?** `HashMap` or `HashSet` with custom hashers cannot be
+    /// used with them.
+    ///
+    /// **Known problems:** Suggestions for replacing constructors can contain
+    /// false-positives. Also applying suggestions can require modification of other
+    /// pieces of code, possibly including external crates.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// impl<K: Hash + Eq, V> Serialize for HashMap<K, V> { ... }
+    ///
+    /// pub foo(map: &mut HashMap<i32, i32>) { .. }
+    /// ```
+    #[clippy::version = "0.0.166"]
     pub IMPLICIT_HASHER,
     style,
     "missing generalization over different hashers"
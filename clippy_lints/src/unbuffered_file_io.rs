@@ -0,0 +1,146 @@
+use crate::utils::{in_macro, is_expn_of, match_trait_method, match_type, paths, span_help_and_lint, walk_ptrs_ty};
+use if_chain::if_chain;
+use rustc::hir::def::Def;
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::ty;
+use rustc::{declare_lint, lint_array};
+use rustc_target::abi::LayoutOf;
+use syntax::ast::NodeId;
+use syntax::source_map::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `write`, `write_all`, `read` and `read_exact`
+    /// calls, and for `write!` and `writeln!`, on a `std::fs::File` inside a loop,
+    /// when the file is opened before the loop.
+    ///
+    /// **Why is this bad?** Each of these calls is a system call on an unbuffered
+    /// `File`. Wrapping the file in a `BufWriter` or a `BufReader` before the loop
+    /// groups the small writes and reads into a few large ones.
+    ///
+    /// **Known problems:** Only files stored in local variables are checked. The
+    /// `write`, `write_all`, `read` and `read_exact` calls are only linted with an
+    /// array buffer smaller than 1 KiB, e.g. `&[0]` or `b"..."`, the buffers of
+    /// unknown size are not.
+    ///
+    /// **Example:**
+    /// ```rust,no_run
+    /// use std::fs::File;
+    /// use std::io::Write;
+    ///
+    /// let mut file = File::create("numbers.txt").unwrap();
+    /// for i in 0..1000 {
+    ///     writeln!(file, "{}", i).unwrap();
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,no_run
+    /// use std::fs::File;
+    /// use std::io::{BufWriter, Write};
+    ///
+    /// let mut file = BufWriter::new(File::create("numbers.txt").unwrap());
+    /// for i in 0..1000 {
+    ///     writeln!(file, "{}", i).unwrap();
+    /// }
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub UNBUFFERED_FILE_IO,
+    perf,
+    "writing to or reading from an unbuffered `File` in a loop"
+}
+
+#[derive(Copy, Clone)]
+pub struct UnbufferedFileIo;
+
+impl LintPass for UnbufferedFileIo {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(UNBUFFERED_FILE_IO)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for UnbufferedFileIo {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if_chain! {
+            if let ExprKind::MethodCall(ref path, _, ref args) = expr.node;
+            if let Some(recv) = args.get(0);
+            // the receiver of `write!` is written by the user, the call is expanded
+            if !in_macro(recv.span);
+            if let Some((span, msg, help)) = io_call(cx, expr, &*path.ident.name.as_str(), args);
+            if match_type(cx, walk_ptrs_ty(cx.tables.expr_ty(recv)), &paths::FILE);
+            if let ExprKind::Path(QPath::Resolved(None, ref recv_path)) = recv.node;
+            if let Def::Local(local_id) = recv_path.def;
+            if let Some(loop_expr) = enclosing_loop(cx, expr.id);
+            // a file opened in the loop is usually only used a few times
+            if !loop_expr.span.contains(cx.tcx.hir.span(local_id));
+            then {
+                span_help_and_lint(cx, UNBUFFERED_FILE_IO, span, msg, help);
+            }
+        }
+    }
+}
+
+/// Returns the span to lint, the message and the help of a write or read call on a raw
+/// `Read` or `Write` implementation
+fn io_call(
+    cx: &LateContext<'_, '_>,
+    expr: &Expr,
+    method: &str,
+    args: &[Expr],
+) -> Option<(Span, &'static str, &'static str)> {
+    const WRITE_HELP: &str = "wrap the file in a `std::io::BufWriter` before the loop";
+    const READ_HELP: &str = "wrap the file in a `std::io::BufReader` before the loop";
+
+    match method {
+        "write_fmt" if match_trait_method(cx, expr, &paths::IO_WRITE) => {
+            let span = is_expn_of(expr.span, "writeln").or_else(|| is_expn_of(expr.span, "write"))?;
+            Some((span, "unbuffered write to a `File` in a loop", WRITE_HELP))
+        },
+        "write" | "write_all" if match_trait_method(cx, expr, &paths::IO_WRITE) && !in_macro(expr.span) => {
+            if is_small_buffer(cx, &args[1]) {
+                Some((expr.span, "unbuffered write to a `File` in a loop", WRITE_HELP))
+            } else {
+                None
+            }
+        },
+        "read" | "read_exact" if match_trait_method(cx, expr, &paths::IO_READ) && !in_macro(expr.span) => {
+            if is_small_buffer(cx, &args[1]) {
+                Some((expr.span, "unbuffered read from a `File` in a loop", READ_HELP))
+            } else {
+                None
+            }
+        },
+        _ => None,
+    }
+}
+
+/// Returns true if the buffer `arg` is an array smaller than 1 KiB, or a reference to one, e.g.
+/// `&[0]` or `b"..."`, a larger buffer gains nothing from the buffering
+fn is_small_buffer(cx: &LateContext<'_, '_>, arg: &Expr) -> bool {
+    let ty = walk_ptrs_ty(cx.tables.expr_ty(arg));
+    if let ty::Array(..) = ty.sty {
+        cx.layout_of(ty).ok().map_or(false, |layout| layout.size.bytes() < 1024)
+    } else {
+        false
+    }
+}
+
+/// Returns the innermost loop around the node `id`, unless there's a closure or an item in
+/// between
+fn enclosing_loop<'tcx>(cx: &LateContext<'_, 'tcx>, mut id: NodeId) -> Option<&'tcx Expr> {
+    loop {
+        let parent = cx.tcx.hir.get_parent_node(id);
+        if parent == id {
+            return None;
+        }
+        match cx.tcx.hir.find(parent) {
+            Some(Node::Expr(expr)) => match expr.node {
+                ExprKind::Loop(..) | ExprKind::While(..) => return Some(expr),
+                ExprKind::Closure(..) => return None,
+                _ => (),
+            },
+            Some(Node::Item(_)) | Some(Node::ImplItem(_)) | Some(Node::TraitItem(_)) | None => return None,
+            _ => (),
+        }
+        id = parent;
+    }
+}
@@ -0,0 +1,206 @@
+use crate::utils::sugg::Sugg;
+use crate::utils::{implements_trait, in_macro, is_copy, same_tys, snippet, span_lint_and_then, SpanlessEq};
+use rustc::hir::def::Def;
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::ty::{self, Ty};
+use rustc::{declare_lint, lint_array};
+use rustc_errors::Applicability;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for struct literals building a value identical to an
+    /// existing one, either with only a base, `Foo { ..base }`, or by copying each field
+    /// of the same value, `Foo { a: other.a, b: other.b }`.
+    ///
+    /// **Why is this bad?** The value can be used, copied or cloned directly.
+    ///
+    /// **Known problems:** Moving the whole value instead of its fields makes the
+    /// fields not moved by the literal unavailable.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # #[derive(Clone, Copy)]
+    /// # struct Point { x: i32, y: i32 }
+    /// # let p = Point { x: 0, y: 0 };
+    /// # let r = &p;
+    /// let a = Point { ..p };
+    /// let b = Point { x: r.x, y: r.y };
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # #[derive(Clone, Copy)]
+    /// # struct Point { x: i32, y: i32 }
+    /// # let p = Point { x: 0, y: 0 };
+    /// # let r = &p;
+    /// let a = p;
+    /// let b = *r;
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub UNNECESSARY_STRUCT_INITIALIZATION,
+    nursery,
+    "struct built from the fields of a value of the same type"
+}
+
+#[derive(Copy, Clone)]
+pub struct UnnecessaryStructInitialization;
+
+impl LintPass for UnnecessaryStructInitialization {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(UNNECESSARY_STRUCT_INITIALIZATION)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for UnnecessaryStructInitialization {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if in_macro(expr.span) {
+            return;
+        }
+        if let ExprKind::Struct(_, ref fields, ref base) = expr.node {
+            let ty = cx.tables.expr_ty(expr);
+            let value = match (fields.is_empty(), base) {
+                (true, Some(base)) => Some(&**base),
+                (false, _) => copied_value(cx, ty, fields, base.as_ref().map(|base| &**base)),
+                (true, None) => None,
+            };
+            if let Some(value) = value {
+                if let Some((sugg, applicability)) = suggestion(cx, ty, value) {
+                    span_lint_and_then(
+                        cx,
+                        UNNECESSARY_STRUCT_INITIALIZATION,
+                        expr.span,
+                        "unnecessary struct building",
+                        |db| {
+                            db.span_suggestion_with_applicability(expr.span, "replace with", sugg, applicability);
+                        },
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Returns `other` if all the fields are `name: other.name`, and the base, if any, is `other` too.
+/// `other` may be a reference to a value of type `ty`.
+fn copied_value<'a, 'tcx, 'e>(
+    cx: &LateContext<'a, 'tcx>,
+    ty: Ty<'tcx>,
+    fields: &'e [Field],
+    base: Option<&'e Expr>,
+) -> Option<&'e Expr> {
+    let mut value = None;
+    for field in fields {
+        match field.expr.node {
+            ExprKind::Field(ref inner, ident) if ident.name == field.ident.name && !in_macro(field.expr.span) => {
+                match value {
+                    Some(value) if !SpanlessEq::new(cx).ignore_fn().eq_expr(value, inner) => return None,
+                    _ => value = Some(&**inner),
+                }
+            },
+            _ => return None,
+        }
+    }
+    let value = value?;
+    if let Some(base) = base {
+        if !SpanlessEq::new(cx).ignore_fn().eq_expr(value, base) {
+            return None;
+        }
+    }
+    // the value is evaluated once per field
+    if !is_plain_place(value) {
+        return None;
+    }
+    let value_ty = match cx.tables.expr_ty(value).sty {
+        ty::Ref(_, inner, _) => inner,
+        _ => cx.tables.expr_ty(value),
+    };
+    if same_tys(cx, ty, value_ty) {
+        Some(value)
+    } else {
+        None
+    }
+}
+
+/// Checks for a local variable, or fields or dereferences of one
+fn is_plain_place(expr: &Expr) -> bool {
+    match expr.node {
+        ExprKind::Path(QPath::Resolved(None, ref path)) => match path.def {
+            Def::Local(_) | Def::Upvar(..) => true,
+            _ => false,
+        },
+        ExprKind::Field(ref inner, _) | ExprKind::Unary(UnDeref, ref inner) => is_plain_place(inner),
+        _ => false,
+    }
+}
+
+/// Checks whether the value of `expr` can be moved out, i.e. it's not behind a reference or in a
+/// static
+fn is_movable(cx: &LateContext<'_, '_>, expr: &Expr) -> bool {
+    match expr.node {
+        ExprKind::Path(ref qpath) => match cx.tables.qpath_def(qpath, expr.hir_id) {
+            Def::Static(..) => false,
+            _ => true,
+        },
+        ExprKind::Field(ref inner, _) => cx.tables.expr_adjustments(inner).is_empty() && is_movable(cx, inner),
+        ExprKind::Unary(UnDeref, ref inner) => cx.tables.expr_ty(inner).is_box() && is_movable(cx, inner),
+        ExprKind::Index(..) => false,
+        _ => true,
+    }
+}
+
+fn is_place(expr: &Expr) -> bool {
+    match expr.node {
+        ExprKind::Path(_) | ExprKind::Field(..) | ExprKind::Unary(UnDeref, _) | ExprKind::Index(..) => true,
+        _ => false,
+    }
+}
+
+/// Returns the expression to use instead of the struct literal, if the value can be used
+fn suggestion<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, ty: Ty<'tcx>, value: &Expr) -> Option<(String, Applicability)> {
+    let is_clone = cx
+        .tcx
+        .lang_items()
+        .clone_trait()
+        .map_or(false, |clone| implements_trait(cx, ty, clone, &[]));
+    let is_ref = match cx.tables.expr_ty(value).sty {
+        ty::Ref(..) => true,
+        _ => false,
+    };
+
+    if is_copy(cx, ty) {
+        let value = if is_ref {
+            Sugg::hir(cx, value, "..").deref()
+        } else {
+            Sugg::hir(cx, value, "..")
+        };
+        return Some((value.to_string(), Applicability::MachineApplicable));
+    }
+    if !is_ref && is_movable(cx, value) {
+        // the fields of the value not moved by the struct literal were still usable
+        let applicability = if is_place(value) {
+            Applicability::MaybeIncorrect
+        } else {
+            Applicability::MachineApplicable
+        };
+        return Some((snippet(cx, value.span, "..").into_owned(), applicability));
+    }
+    if is_clone {
+        // `(*r).clone()` is `r.clone()`
+        let value = match value.node {
+            ExprKind::Unary(UnDeref, ref inner) if is_single_ref(cx, inner) => &**inner,
+            _ => value,
+        };
+        let sugg = format!("{}.clone()", Sugg::hir(cx, value, "..").maybe_par());
+        return Some((sugg, Applicability::MachineApplicable));
+    }
+    None
+}
+
+fn is_single_ref(cx: &LateContext<'_, '_>, expr: &Expr) -> bool {
+    match cx.tables.expr_ty(expr).sty {
+        ty::Ref(_, inner, _) => match inner.sty {
+            ty::Ref(..) => false,
+            _ => true,
+        },
+        _ => false,
+    }
+}
@@ -0,0 +1,136 @@
+use crate::utils::{in_macro, span_help_and_lint};
+use rustc::hir::intravisit::{walk_expr, FnKind, NestedVisitorMap, Visitor};
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::{declare_lint, lint_array};
+use syntax::ast::NodeId;
+use syntax::source_map::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for functions that are declared `async` but have
+    /// no `await`s inside of them.
+    ///
+    /// **Why is this bad?** Async functions with no async code create overhead,
+    /// both mentally and computationally. Callers of async methods either need to
+    /// be calling from an async function themselves or run it on an executor,
+    /// both of which cause runtime overhead and hassle for the caller.
+    ///
+    /// **Known problems:** Functions implementing a trait whose signature requires
+    /// a future are not linted.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// async fn get_random_number() -> i64 {
+    ///     4 // Chosen by fair dice roll. Guaranteed to be random.
+    /// }
+    /// let number_future = get_random_number();
+    /// ```
+    ///
+    /// Use instead:
+    /// ```rust
+    /// fn get_random_number_improved() -> i64 {
+    ///     4 // Chosen by fair dice roll. Guaranteed to be random.
+    /// }
+    /// let number_future = async { get_random_number_improved() };
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub UNUSED_ASYNC,
+    pedantic,
+    "finds async functions with no await statements"
+}
+
+#[derive(Copy, Clone)]
+pub struct UnusedAsync;
+
+impl LintPass for UnusedAsync {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(UNUSED_ASYNC)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for UnusedAsync {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'a, 'tcx>,
+        kind: FnKind<'tcx>,
+        _: &'tcx FnDecl,
+        body: &'tcx Body,
+        span: Span,
+        id: NodeId,
+    ) {
+        let asyncness = match kind {
+            FnKind::ItemFn(_, _, FnHeader { asyncness, .. }, _, _) => asyncness,
+            FnKind::Method(_, sig, _, _) => sig.header.asyncness,
+            FnKind::Closure(_) => return,
+        };
+        if asyncness != IsAsync::Async || in_macro(span) {
+            return;
+        }
+
+        // the signature of a trait method is imposed by the trait
+        if let Some(Node::Item(item)) = cx.tcx.hir.find(cx.tcx.hir.get_parent_node(id)) {
+            if let ItemKind::Impl(_, _, _, _, Some(_), _, _) = item.node {
+                return;
+            }
+        }
+
+        // the body of an `async fn` is lowered to a generator, its suspension points are `yield`s
+        let mut generator = GeneratorFinder { body_id: None };
+        generator.visit_expr(&body.value);
+        if let Some(body_id) = generator.body_id {
+            let mut visitor = YieldFinder { found: false };
+            visitor.visit_expr(&cx.tcx.hir.body(body_id).value);
+            if !visitor.found {
+                span_help_and_lint(
+                    cx,
+                    UNUSED_ASYNC,
+                    span,
+                    "unused `async` for function with no await statements",
+                    "consider removing the `async` from this function",
+                );
+            }
+        }
+    }
+}
+
+/// Finds the generator an `async fn` body is lowered to
+struct GeneratorFinder {
+    body_id: Option<BodyId>,
+}
+
+impl<'tcx> Visitor<'tcx> for GeneratorFinder {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        if self.body_id.is_some() {
+            return;
+        }
+        if let ExprKind::Closure(_, _, body_id, _, Some(_)) = expr.node {
+            self.body_id = Some(body_id);
+            return;
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::None
+    }
+}
+
+/// Looks for suspension points, not in nested closures or `async` blocks that are bodies of
+/// their own
+struct YieldFinder {
+    found: bool,
+}
+
+impl<'tcx> Visitor<'tcx> for YieldFinder {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        if let ExprKind::Yield(_) = expr.node {
+            self.found = true;
+            return;
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::None
+    }
+}
@@ -1,30 +1,32 @@
 use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
 use rustc::{declare_lint, lint_array};
 use rustc::hir;
-use crate::utils::{is_try, match_qpath, match_trait_method, paths, span_lint};
+use crate::utils::{in_macro, is_try, match_qpath, match_trait_method, paths, snippet, span_lint_and_then};
+use rustc_errors::Applicability;
 
-/// **What it does:** Checks for unused written/read amount.
-///
-/// **Why is this bad?** `io::Write::write` and `io::Read::read` are not
-/// guaranteed to
-/// process the entire buffer. They return how many bytes were processed, which
-/// might be smaller
-/// than a given buffer's length. If you don't need to deal with
-/// partial-write/read, use
-/// `write_all`/`read_exact` instead.
-///
-/// **Known problems:** Detects only common patterns.
-///
-/// **Example:**
-/// ```rust,ignore
-/// use std::io;
-/// fn foo<W: io::Write>(w: &mut W) -> io::Result<()> {
-///     // must be `w.write_all(b"foo")?;`
-///     w.write(b"foo")?;
-///     Ok(())
-/// }
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for unused written/read amount.
+    ///
+    /// **Why is this bad?** `io::Write::write` and `io::Read::read` are not
+    /// guaranteed to
+    /// process the entire buffer. They return how many bytes were processed, which
+    /// might be smaller
+    /// than a given buffer's length. If you don't need to deal with
+    /// partial-write/read, use
+    /// `write_all`/`read_exact` instead.
+    ///
+    /// **Known problems:** Detects only common patterns.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// use std::io;
+    /// fn foo<W: io::Write>(w: &mut W) -> io::Result<()> {
+    ///     // must be `w.write_all(b"foo")?;`
+    ///     w.write(b"foo")?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[clippy::version = "0.0.107"]
     pub UNUSED_IO_AMOUNT,
     correctness,
     "unused written/read amount"
@@ -40,53 +42,75 @@ impl LintPass for UnusedIoAmount {
 
 impl<'a, 'tcx> LateLintPass<'a, 'tcx> for UnusedIoAmount {
     fn check_stmt(&mut self, cx: &LateContext<'_, '_>, s: &hir::Stmt) {
-        let expr = match s.node {
-            hir::StmtKind::Semi(ref expr, _) | hir::StmtKind::Expr(ref expr, _) => &**expr,
-            _ => return,
-        };
+        match s.node {
+            hir::StmtKind::Semi(ref expr, _) | hir::StmtKind::Expr(ref expr, _) => check_unused(cx, expr),
+            _ => (),
+        }
+    }
+
+    fn check_local(&mut self, cx: &LateContext<'_, '_>, local: &hir::Local) {
+        // `let _ = ..` discards the result too
+        if let (hir::PatKind::Wild, Some(ref init)) = (&local.pat.node, &local.init) {
+            check_unused(cx, init);
+        }
+    }
+}
 
-        match expr.node {
-            hir::ExprKind::Match(ref res, _, _) if is_try(expr).is_some() => {
-                if let hir::ExprKind::Call(ref func, ref args) = res.node {
-                    if let hir::ExprKind::Path(ref path) = func.node {
-                        if match_qpath(path, &paths::TRY_INTO_RESULT) && args.len() == 1 {
-                            check_method_call(cx, &args[0], expr);
-                        }
+/// Checks the discarded expression `expr`
+fn check_unused(cx: &LateContext<'_, '_>, expr: &hir::Expr) {
+    match expr.node {
+        hir::ExprKind::Match(ref res, _, _) if is_try(expr).is_some() => {
+            if let hir::ExprKind::Call(ref func, ref args) = res.node {
+                if let hir::ExprKind::Path(ref path) = func.node {
+                    if match_qpath(path, &paths::TRY_INTO_RESULT) && args.len() == 1 {
+                        check_method_call(cx, &args[0], expr, true);
                     }
-                } else {
-                    check_method_call(cx, res, expr);
                 }
-            },
+            } else {
+                check_method_call(cx, res, expr, true);
+            }
+        },
 
-            hir::ExprKind::MethodCall(ref path, _, ref args) => match &*path.ident.as_str() {
-                "expect" | "unwrap" | "unwrap_or" | "unwrap_or_else" => {
-                    check_method_call(cx, &args[0], expr);
-                },
-                _ => (),
+        hir::ExprKind::MethodCall(ref path, _, ref args) => match &*path.ident.as_str() {
+            "expect" | "unwrap" => {
+                check_method_call(cx, &args[0], expr, true);
+            },
+            "unwrap_or" | "unwrap_or_else" => {
+                check_method_call(cx, &args[0], expr, false);
             },
-
             _ => (),
-        }
+        },
+
+        _ => (),
     }
 }
 
-fn check_method_call(cx: &LateContext<'_, '_>, call: &hir::Expr, expr: &hir::Expr) {
+/// Lints `expr` if it discards the amount returned by `call`. The suggestion to call `write_all`
+/// or `read_exact` instead is only given if it compiles as is, i.e. `suggest` is true.
+fn check_method_call(cx: &LateContext<'_, '_>, call: &hir::Expr, expr: &hir::Expr, suggest: bool) {
     if let hir::ExprKind::MethodCall(ref path, _, _) = call.node {
         let symbol = &*path.ident.as_str();
-        if match_trait_method(cx, call, &paths::IO_READ) && symbol == "read" {
-            span_lint(
-                cx,
-                UNUSED_IO_AMOUNT,
-                expr.span,
-                "handle read amount returned or use `Read::read_exact` instead",
-            );
+        let (msg, replacement) = if match_trait_method(cx, call, &paths::IO_READ) && symbol == "read" {
+            ("handle read amount returned or use `Read::read_exact` instead", "read_exact")
         } else if match_trait_method(cx, call, &paths::IO_WRITE) && symbol == "write" {
-            span_lint(
-                cx,
-                UNUSED_IO_AMOUNT,
-                expr.span,
-                "handle written amount returned or use `Write::write_all` instead",
-            );
-        }
+            ("handle written amount returned or use `Write::write_all` instead", "write_all")
+        } else {
+            return;
+        };
+        span_lint_and_then(cx, UNUSED_IO_AMOUNT, expr.span, msg, |db| {
+            if suggest && !in_macro(expr.span) {
+                db.span_suggestion_with_applicability(
+                    expr.span,
+                    &format!("if the whole buffer should be processed, use `{}`", replacement),
+                    format!(
+                        "{}{}{}",
+                        snippet(cx, expr.span.until(path.ident.span), ".."),
+                        replacement,
+                        snippet(cx, path.ident.span.shrink_to_hi().to(expr.span.shrink_to_hi()), "..")
+                    ),
+                    Applicability::MaybeIncorrect,
+                );
+            }
+        });
     }
 }
@@ -0,0 +1,111 @@
+use crate::utils::{in_macro, span_help_and_lint};
+use if_chain::if_chain;
+use rustc::hir::def::Def;
+use rustc::hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::{declare_lint, lint_array};
+use syntax::ast::NodeId;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks methods that contain a `self` argument but don't use it
+    ///
+    /// **Why is this bad?** It may be clearer to define the method as an associated function instead
+    /// of an instance method if it doesn't require `self`.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// struct A;
+    /// impl A {
+    ///     fn method(&self) {}
+    /// }
+    /// ```
+    ///
+    /// Could be written:
+    ///
+    /// ```rust
+    /// struct A;
+    /// impl A {
+    ///     fn method() {}
+    /// }
+    /// ```
+    #[clippy::version = "0.0.213"]
+    pub UNUSED_SELF,
+    pedantic,
+    "methods that contain a `self` argument but don't use it"
+}
+
+#[derive(Copy, Clone)]
+pub struct UnusedSelf;
+
+impl LintPass for UnusedSelf {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(UNUSED_SELF)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for UnusedSelf {
+    fn check_impl_item(&mut self, cx: &LateContext<'a, 'tcx>, impl_item: &'tcx ImplItem) {
+        if in_macro(impl_item.span) {
+            return;
+        }
+        let parent = cx.tcx.hir.get_parent(impl_item.id);
+        let item = cx.tcx.hir.expect_item(parent);
+        if_chain! {
+            // the signature of a trait method is imposed by the trait
+            if let ItemKind::Impl(_, _, _, _, None, _, _) = item.node;
+            if let ImplItemKind::Method(_, body_id) = impl_item.node;
+            if cx.tcx.associated_item(cx.tcx.hir.local_def_id(impl_item.id)).method_has_self_argument;
+            let body = cx.tcx.hir.body(body_id);
+            if let Some(self_arg) = body.arguments.get(0);
+            then {
+                let mut visitor = SelfUseVisitor {
+                    cx,
+                    self_id: self_arg.pat.id,
+                    used: false,
+                };
+                visitor.visit_expr(&body.value);
+                if !visitor.used {
+                    span_help_and_lint(
+                        cx,
+                        UNUSED_SELF,
+                        self_arg.pat.span,
+                        "unused `self` argument",
+                        "consider refactoring to an associated function",
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Looks for uses of the `self` binding, also in closures
+struct SelfUseVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    self_id: NodeId,
+    used: bool,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for SelfUseVisitor<'a, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        if self.used {
+            return;
+        }
+        if let ExprKind::Path(ref qpath) = expr.node {
+            match self.cx.tables.qpath_def(qpath, expr.hir_id) {
+                Def::Local(id) | Def::Upvar(id, ..) if id == self.self_id => {
+                    self.used = true;
+                    return;
+                },
+                _ => {},
+            }
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::OnlyBodies(&self.cx.tcx.hir)
+    }
+}
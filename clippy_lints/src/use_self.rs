@@ -1,47 +1,73 @@
-use crate::utils::{in_macro, span_lint_and_sugg};
+use crate::utils::{in_macro, snippet_opt, span_lint_and_then};
 use if_chain::if_chain;
-use rustc::hir::intravisit::{walk_path, walk_ty, NestedVisitorMap, Visitor};
+use rustc::hir::def::Def;
+use rustc::hir::def_id::DefId;
+use rustc::hir::intravisit::{walk_ty, NestedVisitorMap, Visitor};
 use rustc::hir::*;
 use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
-use rustc::ty;
+use rustc::ty::{self, TypeFlags};
 use rustc::{declare_lint, lint_array};
-use syntax_pos::symbol::keywords::SelfType;
-
-/// **What it does:** Checks for unnecessary repetition of structure name when a
-/// replacement with `Self` is applicable.
-///
-/// **Why is this bad?** Unnecessary repetition. Mixed use of `Self` and struct
-/// name
-/// feels inconsistent.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// struct Foo {}
-/// impl Foo {
-///     fn new() -> Foo {
-///         Foo {}
-///     }
-/// }
-/// ```
-/// could be
-/// ```
-/// struct Foo {}
-/// impl Foo {
-///     fn new() -> Self {
-///         Self {}
-///     }
-/// }
-/// ```
+use rustc_errors::Applicability;
+use rustc_typeck::hir_ty_to_ty;
+use std::collections::HashSet;
+use syntax::ast::NodeId;
+use syntax_pos::{BytePos, Span};
+
 declare_clippy_lint! {
+    /// **What it does:** Checks for unnecessary repetition of structure name when a
+    /// replacement with `Self` is applicable.
+    ///
+    /// **Why is this bad?** Unnecessary repetition. Mixed use of `Self` and struct
+    /// name
+    /// feels inconsistent.
+    ///
+    /// **Known problems:** In generic impls and impls with lifetimes, bodies are
+    /// only linted where the type of the expression is known to be the type of the
+    /// impl, e.g. in struct literals, not in paths like `Foo::new()`. Tuple struct
+    /// constructors are not linted, `Self` can't be used as a constructor.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// struct Foo {}
+    /// impl Foo {
+    ///     fn new() -> Foo {
+    ///         Foo {}
+    ///     }
+    /// }
+    /// ```
+    /// could be
+    /// ```
+    /// struct Foo {}
+    /// impl Foo {
+    ///     fn new() -> Self {
+    ///         Self {}
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "0.0.153"]
     pub USE_SELF,
     pedantic,
     "Unnecessary structure name repetition whereas `Self` is applicable"
 }
 
-#[derive(Copy, Clone, Default)]
-pub struct UseSelf;
+#[derive(Default)]
+pub struct UseSelf {
+    stack: Vec<StackItem>,
+}
+
+enum StackItem {
+    /// In an impl item, where `Self` is the type of the impl
+    Check {
+        impl_def_id: DefId,
+        /// The types of the method signature in trait impls, they are checked against the trait
+        types_to_skip: HashSet<NodeId>,
+        /// The depth of nested bodies (closures, constants), types in bodies are not resolved
+        /// the same way
+        in_body: usize,
+    },
+    /// In any other item, including items nested in impl items
+    NoCheck,
+}
 
 impl LintPass for UseSelf {
     fn get_lints(&self) -> LintArray {
@@ -49,45 +75,86 @@ impl LintPass for UseSelf {
     }
 }
 
-const SEGMENTS_MSG: &str = "segments should be composed of at least 1 element";
-
-fn span_use_self_lint(cx: &LateContext<'_, '_>, path: &Path) {
-    span_lint_and_sugg(
-        cx,
-        USE_SELF,
-        path.span,
-        "unnecessary structure name repetition",
-        "use the applicable keyword",
-        "Self".to_owned(),
-    );
+fn span_use_self_lint(cx: &LateContext<'_, '_>, span: Span) {
+    span_lint_and_then(cx, USE_SELF, span, "unnecessary structure name repetition", |db| {
+        db.span_suggestion_with_applicability(
+            span,
+            "use the applicable keyword",
+            "Self".to_owned(),
+            Applicability::MachineApplicable,
+        );
+    });
+}
+
+/// The span of the path itself, not of the type-relative path it is the
+/// qualifier of, e.g. `Foo` in `Foo::new`
+fn path_span(cx: &LateContext<'_, '_>, path: &Path) -> Span {
+    let segment = match path.segments.last() {
+        Some(segment) => segment,
+        None => return path.span,
+    };
+    let mut hi = segment.ident.span.hi();
+    if let Some(ref args) = segment.args {
+        let args_hi = args
+            .args
+            .iter()
+            .filter_map(|arg| match arg {
+                GenericArg::Lifetime(lt) if lt.is_elided() => None,
+                GenericArg::Lifetime(lt) => Some(lt.span.hi()),
+                GenericArg::Type(ty) => Some(ty.span.hi()),
+            })
+            .chain(args.bindings.iter().map(|binding| binding.span.hi()))
+            .max();
+        // include the closing `>`
+        if let Some(args_hi) = args_hi {
+            if let Some(rest) = snippet_opt(cx, path.span.with_lo(args_hi)) {
+                if let Some(offset) = rest.find('>') {
+                    hi = args_hi + BytePos(offset as u32 + 1);
+                }
+            }
+        }
+    }
+    path.span.with_hi(hi)
+}
+
+/// Returns true if `path` names the type definition of `self_ty`, without generic arguments
+fn is_adt_path(path: &Path, self_ty: ty::Ty<'_>) -> bool {
+    match (path.def, &self_ty.sty) {
+        (Def::Struct(def_id), ty::Adt(adt, _))
+        | (Def::Enum(def_id), ty::Adt(adt, _))
+        | (Def::Union(def_id), ty::Adt(adt, _)) => {
+            def_id == adt.did && path.segments.iter().all(|segment| segment.args.is_none())
+        },
+        _ => false,
+    }
 }
 
 struct TraitImplTyVisitor<'a, 'tcx: 'a> {
-    item_path: &'a Path,
+    self_ty: ty::Ty<'tcx>,
     cx: &'a LateContext<'a, 'tcx>,
     trait_type_walker: ty::walk::TypeWalker<'tcx>,
     impl_type_walker: ty::walk::TypeWalker<'tcx>,
+    visited: HashSet<NodeId>,
 }
 
 impl<'a, 'tcx> Visitor<'tcx> for TraitImplTyVisitor<'a, 'tcx> {
     fn visit_ty(&mut self, t: &'tcx Ty) {
         let trait_ty = self.trait_type_walker.next();
         let impl_ty = self.impl_type_walker.next();
+        self.visited.insert(t.id);
 
         if let TyKind::Path(QPath::Resolved(_, path)) = &t.node {
-            if self.item_path.def == path.def {
-                let is_self_ty = if let def::Def::SelfTy(..) = path.def {
-                    true
-                } else {
-                    false
-                };
+            let is_self_ty = if let Def::SelfTy(..) = path.def {
+                true
+            } else {
+                false
+            };
 
-                if !is_self_ty && impl_ty != trait_ty {
-                    // The implementation and trait types don't match which means that
-                    // the concrete type was specified by the implementation but
-                    // it didn't use `Self`
-                    span_use_self_lint(self.cx, path);
-                }
+            if !is_self_ty && impl_ty == Some(self.self_ty) && impl_ty != trait_ty {
+                // The implementation and trait types don't match which means that
+                // the concrete type was specified by the implementation but
+                // it didn't use `Self`
+                span_use_self_lint(self.cx, path_span(self.cx, path));
             }
         }
         walk_ty(self, t)
@@ -100,11 +167,11 @@ impl<'a, 'tcx> Visitor<'tcx> for TraitImplTyVisitor<'a, 'tcx> {
 
 fn check_trait_method_impl_decl<'a, 'tcx: 'a>(
     cx: &'a LateContext<'a, 'tcx>,
-    item_path: &'a Path,
+    self_ty: ty::Ty<'tcx>,
     impl_item: &ImplItem,
     impl_decl: &'tcx FnDecl,
     impl_trait_ref: &ty::TraitRef<'_>,
-) {
+) -> HashSet<NodeId> {
     let trait_method = cx
         .tcx
         .associated_items(impl_trait_ref.def_id)
@@ -133,6 +200,7 @@ fn check_trait_method_impl_decl<'a, 'tcx: 'a>(
     // `impl_ty` (of type `ty:TyS`) is the concrete type that the compiler has determined for
     // that declaration.  We use `impl_decl_ty` to see if the type was declared as `Self`
     // and use `impl_ty` to check its concrete type.
+    let mut visited = HashSet::new();
     for (impl_decl_ty, (impl_ty, trait_ty)) in impl_decl.inputs.iter().chain(output_ty).zip(
         impl_method_sig
             .inputs_and_output
@@ -140,82 +208,136 @@ fn check_trait_method_impl_decl<'a, 'tcx: 'a>(
             .zip(trait_method_sig.inputs_and_output),
     ) {
         let mut visitor = TraitImplTyVisitor {
+            self_ty,
             cx,
-            item_path,
             trait_type_walker: trait_ty.walk(),
             impl_type_walker: impl_ty.walk(),
+            visited: HashSet::new(),
         };
 
         visitor.visit_ty(&impl_decl_ty);
+        visited.extend(visitor.visited);
+    }
+    visited
+}
+
+impl UseSelf {
+    /// Returns the type of the impl, and whether the node being checked is in a body, if `Self`
+    /// is usable there
+    fn self_ty<'tcx>(&self, cx: &LateContext<'_, 'tcx>, id: Option<NodeId>) -> Option<(ty::Ty<'tcx>, bool)> {
+        match self.stack.last() {
+            Some(StackItem::Check {
+                impl_def_id,
+                types_to_skip,
+                in_body,
+            }) => {
+                if id.map_or(false, |id| types_to_skip.contains(&id)) {
+                    return None;
+                }
+                Some((cx.tcx.type_of(*impl_def_id), *in_body > 0))
+            },
+            _ => None,
+        }
     }
 }
 
 impl<'a, 'tcx> LateLintPass<'a, 'tcx> for UseSelf {
-    fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx Item) {
+    fn check_item(&mut self, _: &LateContext<'a, 'tcx>, _: &'tcx Item) {
+        self.stack.push(StackItem::NoCheck);
+    }
+
+    fn check_item_post(&mut self, _: &LateContext<'a, 'tcx>, _: &'tcx Item) {
+        self.stack.pop();
+    }
+
+    fn check_impl_item(&mut self, cx: &LateContext<'a, 'tcx>, impl_item: &'tcx ImplItem) {
+        let parent = cx.tcx.hir.get_parent(impl_item.id);
+        let item = cx.tcx.hir.expect_item(parent);
         if in_macro(item.span) {
+            self.stack.push(StackItem::NoCheck);
             return;
         }
+        let impl_def_id = cx.tcx.hir.local_def_id(parent);
+        let types_to_skip = match (cx.tcx.impl_trait_ref(impl_def_id), &impl_item.node) {
+            (Some(impl_trait_ref), ImplItemKind::Method(MethodSig { decl: impl_decl, .. }, _)) => {
+                let self_ty = cx.tcx.type_of(impl_def_id);
+                check_trait_method_impl_decl(cx, self_ty, impl_item, impl_decl, &impl_trait_ref)
+            },
+            _ => HashSet::new(),
+        };
+        self.stack.push(StackItem::Check {
+            impl_def_id,
+            types_to_skip,
+            in_body: 0,
+        });
+    }
+
+    fn check_impl_item_post(&mut self, _: &LateContext<'a, 'tcx>, _: &'tcx ImplItem) {
+        self.stack.pop();
+    }
+
+    fn check_body(&mut self, _: &LateContext<'a, 'tcx>, _: &'tcx Body) {
+        if let Some(StackItem::Check { in_body, .. }) = self.stack.last_mut() {
+            *in_body += 1;
+        }
+    }
+
+    fn check_body_post(&mut self, _: &LateContext<'a, 'tcx>, _: &'tcx Body) {
+        if let Some(StackItem::Check { in_body, .. }) = self.stack.last_mut() {
+            *in_body -= 1;
+        }
+    }
+
+    fn check_ty(&mut self, cx: &LateContext<'a, 'tcx>, hir_ty: &'tcx Ty) {
         if_chain! {
-            if let ItemKind::Impl(.., ref item_type, ref refs) = item.node;
-            if let TyKind::Path(QPath::Resolved(_, ref item_path)) = item_type.node;
+            if !in_macro(hir_ty.span);
+            if let TyKind::Path(QPath::Resolved(None, ref path)) = hir_ty.node;
+            if let Some((self_ty, in_body)) = self.self_ty(cx, Some(hir_ty.id));
             then {
-                let parameters = &item_path.segments.last().expect(SEGMENTS_MSG).args;
-                let should_check = if let Some(ref params) = *parameters {
-                    !params.parenthesized && !params.args.iter().any(|arg| match arg {
-                        GenericArg::Lifetime(_) => true,
-                        GenericArg::Type(_) => false,
-                    })
+                match path.def {
+                    Def::SelfTy(..) | Def::TyAlias(..) => return,
+                    _ => {},
+                }
+                let is_self = if in_body {
+                    // without the generic arguments, the path refers to the type of the impl only if it
+                    // has none
+                    !self_ty.flags.intersects(TypeFlags::HAS_PARAMS | TypeFlags::HAS_FREE_REGIONS)
+                        && is_adt_path(path, self_ty)
                 } else {
-                    true
+                    hir_ty_to_ty(cx.tcx, hir_ty) == self_ty
                 };
-
-                if should_check {
-                    let visitor = &mut UseSelfVisitor {
-                        item_path,
-                        cx,
-                    };
-                    let impl_def_id = cx.tcx.hir.local_def_id(item.id);
-                    let impl_trait_ref = cx.tcx.impl_trait_ref(impl_def_id);
-
-                    if let Some(impl_trait_ref) = impl_trait_ref {
-                        for impl_item_ref in refs {
-                            let impl_item = cx.tcx.hir.impl_item(impl_item_ref.id);
-                            if let ImplItemKind::Method(MethodSig{ decl: impl_decl, .. }, impl_body_id)
-                                    = &impl_item.node {
-                                check_trait_method_impl_decl(cx, item_path, impl_item, impl_decl, &impl_trait_ref);
-                                let body = cx.tcx.hir.body(*impl_body_id);
-                                visitor.visit_body(body);
-                            } else {
-                                visitor.visit_impl_item(impl_item);
-                            }
-                        }
-                    } else {
-                        for impl_item_ref in refs {
-                            let impl_item = cx.tcx.hir.impl_item(impl_item_ref.id);
-                            visitor.visit_impl_item(impl_item);
-                        }
-                    }
+                if is_self {
+                    span_use_self_lint(cx, path_span(cx, path));
                 }
             }
         }
     }
-}
-
-struct UseSelfVisitor<'a, 'tcx: 'a> {
-    item_path: &'a Path,
-    cx: &'a LateContext<'a, 'tcx>,
-}
 
-impl<'a, 'tcx> Visitor<'tcx> for UseSelfVisitor<'a, 'tcx> {
-    fn visit_path(&mut self, path: &'tcx Path, _id: HirId) {
-        if self.item_path.def == path.def && path.segments.last().expect(SEGMENTS_MSG).ident.name != SelfType.name() {
-            span_use_self_lint(self.cx, path);
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if let ExprKind::Struct(QPath::Resolved(None, ref path), ..) = expr.node {
+            check_struct_path(self, cx, path, cx.tables.expr_ty(expr));
         }
+    }
 
-        walk_path(self, path);
+    fn check_pat(&mut self, cx: &LateContext<'a, 'tcx>, pat: &'tcx Pat) {
+        if let PatKind::Struct(QPath::Resolved(None, ref path), ..) = pat.node {
+            check_struct_path(self, cx, path, cx.tables.pat_ty(pat));
+        }
     }
+}
 
-    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
-        NestedVisitorMap::OnlyBodies(&self.cx.tcx.hir)
+/// Checks the path of a struct literal or pattern of type `ty`
+fn check_struct_path<'a, 'tcx>(pass: &UseSelf, cx: &LateContext<'a, 'tcx>, path: &Path, ty: ty::Ty<'tcx>) {
+    if_chain! {
+        if !in_macro(path.span);
+        // `Self::Variant` is not a valid path for an enum variant
+        if let Def::Struct(_) = path.def;
+        if let Some((self_ty, _)) = pass.self_ty(cx, None);
+        // the regions of the types of expressions are erased, they can't be compared
+        if !self_ty.flags.intersects(TypeFlags::HAS_FREE_REGIONS);
+        if ty == self_ty;
+        then {
+            span_use_self_lint(cx, path_span(cx, path));
+        }
     }
 }
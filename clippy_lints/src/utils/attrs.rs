@@ -0,0 +1,71 @@
+//! Clippy's own attributes, `#[clippy::name]` and `#[clippy::name = "value"]`.
+//!
+//! The thresholds of some lints can be overridden for a single item (and everything inside it)
+//! with an attribute named like the lint:
+//!
+//! ```rust,ignore
+//! #[clippy::too_many_arguments = "10"]
+//! fn draw(x: u32, y: u32, z: u32, width: u32, height: u32, depth: u32, color: u32, alpha: u32) {}
+//! ```
+//!
+//! The lints keep track of the innermost value with a `LimitStack`, see
+//! `cyclomatic_complexity`, `too_many_arguments`, `type_complexity` and `large_enum_variant`.
+
+use rustc::session::Session;
+use std::str::FromStr;
+use syntax::ast;
+
+/// A threshold whose value can be overridden by attributes, the innermost attribute wins. The
+/// limit must be pushed in `enter_lint_attrs` and popped in `exit_lint_attrs`.
+pub struct LimitStack {
+    stack: Vec<u64>,
+}
+
+impl Drop for LimitStack {
+    fn drop(&mut self) {
+        assert_eq!(self.stack.len(), 1);
+    }
+}
+
+impl LimitStack {
+    pub fn new(limit: u64) -> Self {
+        Self { stack: vec![limit] }
+    }
+    pub fn limit(&self) -> u64 {
+        *self.stack
+            .last()
+            .expect("there should always be a value in the stack")
+    }
+    pub fn push_attrs(&mut self, sess: &Session, attrs: &[ast::Attribute], name: &'static str) {
+        let stack = &mut self.stack;
+        parse_attrs(sess, attrs, name, |val| stack.push(val));
+    }
+    pub fn pop_attrs(&mut self, sess: &Session, attrs: &[ast::Attribute], name: &'static str) {
+        let stack = &mut self.stack;
+        parse_attrs(sess, attrs, name, |val| assert_eq!(stack.pop(), Some(val)));
+    }
+}
+
+pub fn get_attr<'a>(attrs: &'a [ast::Attribute], name: &'static str) -> impl Iterator<Item = &'a ast::Attribute> {
+    attrs.iter().filter_map(move |attr| {
+        if attr.path.segments.len() == 2 && attr.path.segments[0].ident.to_string() == "clippy" && attr.path.segments[1].ident.to_string() == name {
+            Some(attr)
+        } else {
+            None
+        }
+    })
+}
+
+fn parse_attrs<F: FnMut(u64)>(sess: &Session, attrs: &[ast::Attribute], name: &'static str, mut f: F) {
+    for attr in get_attr(attrs, name) {
+        if let Some(ref value) = attr.value_str() {
+            if let Ok(value) = FromStr::from_str(&value.as_str()) {
+                f(value)
+            } else {
+                sess.span_err(attr.span, "not a number");
+            }
+        } else {
+            sess.span_err(attr.span, "bad clippy attribute");
+        }
+    }
+}
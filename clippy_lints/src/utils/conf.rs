@@ -2,6 +2,7 @@
 
 #![deny(missing_docs_in_private_items)]
 
+use crate::utils::msrvs::RustcVersion;
 use lazy_static::lazy_static;
 use std::{env, fmt, fs, io, path};
 use std::io::Read;
@@ -75,15 +76,20 @@ lazy_static! {
 }
 
 macro_rules! define_Conf {
-    ($(#[$doc: meta] ($rust_name: ident, $rust_name_str: expr, $default: expr => $($ty: tt)+),)+) => {
+    ($(#[doc = $doc: expr] ($rust_name: ident, $rust_name_str: expr, $default: expr => $($ty: tt)+),)+) => {
         pub use self::helpers::Conf;
+
+        /// The name, documentation and default value of all the configuration keys, printed by
+        /// `cargo clippy --explain`. The documentation starts with `Lint: LINT_NAME[, OTHER_LINT].`
+        pub const CONF_DOCS: &[(&str, &str, &str)] = &[$(($rust_name_str, $doc, stringify!($default)),)+];
+
         mod helpers {
             use serde_derive::Deserialize;
             /// Type used to store lint configuration.
             #[derive(Deserialize)]
             #[serde(rename_all="kebab-case", deny_unknown_fields)]
             pub struct Conf {
-                $(#[$doc] #[serde(default=$rust_name_str)] #[serde(with=$rust_name_str)]
+                $(#[doc = $doc] #[serde(default=$rust_name_str)] #[serde(with=$rust_name_str)]
                           pub $rust_name: define_Conf!(TY $($ty)+),)+
                 #[allow(dead_code)]
                 #[serde(default)]
@@ -151,6 +157,8 @@ define_Conf! {
     (too_large_for_stack, "too_large_for_stack", 200 => u64),
     /// Lint: ENUM_VARIANT_NAMES. The minimum number of enum variants for the lints about variant names to trigger
     (enum_variant_name_threshold, "enum_variant_name_threshold", 3 => u64),
+    /// Lint: STRUCT_FIELD_NAMES. The minimum number of struct fields for the lints about field names to trigger
+    (struct_field_name_threshold, "struct_field_name_threshold", 3 => u64),
     /// Lint: LARGE_ENUM_VARIANT. The maximum size of a emum's variant to avoid box suggestion
     (enum_variant_size_threshold, "enum_variant_size_threshold", 200 => u64),
     /// Lint: VERBOSE_BIT_MASK. The maximum allowed size of a bit mask before suggesting to use 'trailing_zeros'
@@ -159,38 +167,124 @@ define_Conf! {
     (literal_representation_threshold, "literal_representation_threshold", 16384 => u64),
     /// Lint: TRIVIALLY_COPY_PASS_BY_REF. The maximum size (in bytes) to consider a `Copy` type for passing by value instead of by reference.
     (trivial_copy_size_limit, "trivial_copy_size_limit", None => Option<u64>),
+    /// Lint: REDUNDANT_FIELD_NAMES, CONST_STATIC_LIFETIME, INCONSISTENT_RUST_VERSION, CAST_POSSIBLE_TRUNCATION, CAST_POSSIBLE_WRAP, CAST_SIGN_LOSS, OPTION_AS_REF_DEREF, DRAIN_COLLECT, COLLAPSIBLE_STR_REPLACE, SEEK_FROM_CURRENT, SEEK_TO_START_INSTEAD_OF_REWIND, EXHAUSTIVE_ENUMS, EXHAUSTIVE_STRUCTS, MANUAL_BITS, IF_THEN_SOME_ELSE_NONE, MANUAL_LET_ELSE, MANUAL_RETAIN, TUPLE_ARRAY_CONVERSIONS. The minimum Rust version the project supports, e.g. `"1.16"`. Lints whose suggestions need a more recent compiler are disabled
+    (msrv, "msrv", None => Option<String>),
+    /// Lint: INDEXING_SLICING. Whether indexing and slicing are allowed in `#[test]` functions and `#[cfg(test)]` items
+    (allow_indexing_slicing_in_tests, "allow_indexing_slicing_in_tests", false => bool),
+    /// Lint: NEGATIVE_FEATURE_NAMES, REDUNDANT_FEATURE_NAMES. The feature names to accept even if they are negative or redundant
+    (allowed_feature_names, "allowed_feature_names", [] => Vec<String>),
+    /// Lint: MEM_FORGET. The paths of the types which are intentionally leaked with `mem::forget`, e.g. `"std::fs::File"`
+    (allowed_forget_types, "allowed_forget_types", [] => Vec<String>),
+    /// Lint: ARITHMETIC_SIDE_EFFECTS. The paths of the types whose arithmetic operators never overflow nor panic, e.g. `"my_crate::Money"`
+    (arithmetic_side_effects_allowed, "arithmetic_side_effects_allowed", [] => Vec<String>),
+    /// Lint: SIGNIFICANT_DROP_IN_SCRUTINEE. The paths of the types whose `Drop` is significant, in addition to the lock guards and the `RefCell` borrows, e.g. `"my_crate::Transaction"`
+    (significant_drop_types, "significant_drop_types", [] => Vec<String>),
+    /// Lint: LARGE_FUTURES. The maximum size (in bytes) of a future awaited without boxing it
+    (future_size_threshold, "future_size_threshold", 16384 => u64),
+    /// Lint: MISSING_ASSERT_MESSAGE. Whether the asserts in `#[test]` functions and `#[cfg(test)]` items are linted too
+    (missing_assert_message_in_tests, "missing_assert_message_in_tests", false => bool),
+    /// Lint: HOST_ENDIAN_BYTES, LITTLE_ENDIAN_BYTES, BIG_ENDIAN_BYTES. The byte order to enforce, `"little"` or `"big"`: its conversions aren't linted and the others suggest it
+    (enforced_endianness, "enforced_endianness", None => Option<String>),
+    /// Lint: IMPL_TRAIT_IN_PARAMS. Whether the `impl Trait` in the return types of exported functions are linted too
+    (check_impl_trait_in_return, "check_impl_trait_in_return", false => bool),
+}
+
+/// A disagreement between the `msrv` configuration value and the `rust-version` field of the
+/// package manifest.
+#[derive(Debug, PartialEq)]
+pub enum MsrvMismatch {
+    /// Only the `msrv` is set.
+    MissingRustVersion(String),
+    /// Only the `rust-version` is set.
+    MissingMsrv(String),
+    /// Both are set, to different versions.
+    Different {
+        /// The `msrv` configuration value.
+        msrv: String,
+        /// The `rust-version` of the manifest.
+        rust_version: String,
+    },
 }
 
-/// Search for the configuration file.
-pub fn lookup_conf_file() -> io::Result<Option<path::PathBuf>> {
-    /// Possible filename to search for.
-    const CONFIG_FILE_NAMES: [&str; 2] = [".clippy.toml", "clippy.toml"];
+/// Cross-checks the `msrv` configuration value with the `rust-version` of the package manifest.
+///
+/// The versions are compared once parsed, `1.30` and `1.30.0` are the same. The versions which
+/// can't be parsed are ignored: the invalid `msrv`s are reported when the configuration is read
+/// and the invalid `rust-version`s by Cargo.
+pub fn check_rust_version(msrv: Option<&str>, rust_version: Option<&str>) -> Option<MsrvMismatch> {
+    let is_invalid = |version: Option<&str>| version.map_or(false, |version| RustcVersion::parse(version).is_none());
+    if is_invalid(msrv) || is_invalid(rust_version) {
+        return None;
+    }
+    match (msrv, rust_version) {
+        (Some(msrv), None) => Some(MsrvMismatch::MissingRustVersion(msrv.to_owned())),
+        (None, Some(rust_version)) => Some(MsrvMismatch::MissingMsrv(rust_version.to_owned())),
+        (Some(msrv), Some(rust_version)) if RustcVersion::parse(msrv) != RustcVersion::parse(rust_version) => {
+            Some(MsrvMismatch::Different {
+                msrv: msrv.to_owned(),
+                rust_version: rust_version.to_owned(),
+            })
+        },
+        _ => None,
+    }
+}
 
-    let mut current = path::PathBuf::from(env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR not set"));
+/// Possible filename to search for.
+const CONFIG_FILE_NAMES: [&str; 2] = [".clippy.toml", "clippy.toml"];
+
+/// Search for the configuration file in `dir` only.
+fn conf_file_in(dir: &path::Path) -> io::Result<Option<path::PathBuf>> {
+    for config_file_name in &CONFIG_FILE_NAMES {
+        let config_file = dir.join(config_file_name);
+        match fs::metadata(&config_file) {
+            // Only return if it's a file to handle the unlikely situation of a directory named
+            // `clippy.toml`.
+            Ok(ref md) if md.is_file() => return Ok(Some(config_file)),
+            // Return the error if it's something other than `NotFound`; otherwise we didn't
+            // find the project file yet, and continue searching.
+            Err(e) => if e.kind() != io::ErrorKind::NotFound {
+                return Err(e);
+            },
+            _ => (),
+        }
+    }
+
+    Ok(None)
+}
+
+/// The root directory of the crate being checked.
+fn manifest_dir() -> path::PathBuf {
+    path::PathBuf::from(env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR not set"))
+}
+
+/// Search for all the configuration files, from the crate root up to the filesystem root.
+///
+/// The files are returned from the outermost to the innermost one, which is the order they have
+/// to be merged in: e.g. the configuration of a workspace member overrides the configuration of
+/// the workspace.
+pub fn lookup_conf_files() -> io::Result<Vec<path::PathBuf>> {
+    let mut current = manifest_dir();
+    let mut config_files = Vec::new();
 
     loop {
-        for config_file_name in &CONFIG_FILE_NAMES {
-            let config_file = current.join(config_file_name);
-            match fs::metadata(&config_file) {
-                // Only return if it's a file to handle the unlikely situation of a directory named
-                // `clippy.toml`.
-                Ok(ref md) if md.is_file() => return Ok(Some(config_file)),
-                // Return the error if it's something other than `NotFound`; otherwise we didn't
-                // find the project file yet, and continue searching.
-                Err(e) => if e.kind() != io::ErrorKind::NotFound {
-                    return Err(e);
-                },
-                _ => (),
-            }
+        if let Some(config_file) = conf_file_in(&current)? {
+            config_files.push(config_file);
         }
 
         // If the current directory has no parent, we're done searching.
         if !current.pop() {
-            return Ok(None);
+            config_files.reverse();
+            return Ok(config_files);
         }
     }
 }
 
+impl Default for Conf {
+    fn default() -> Self {
+        default(Vec::new()).0
+    }
+}
+
 /// Produces a `Conf` filled with the default values and forwards the errors
 ///
 /// Used internally for convenience
@@ -198,6 +292,14 @@ fn default(errors: Vec<Error>) -> (Conf, Vec<Error>) {
     (toml::from_str("").expect("we never error on empty config files"), errors)
 }
 
+/// Takes the errors reported while deserializing a `Conf`.
+fn take_errors() -> Vec<Error> {
+    ERRORS
+        .lock()
+        .expect("no threading -> mutex always safe")
+        .split_off(0)
+}
+
 /// Read the `toml` configuration file.
 ///
 /// In case of error, the function tries to continue as much as possible.
@@ -208,40 +310,88 @@ pub fn read(path: Option<&path::Path>) -> (Conf, Vec<Error>) {
         return default(Vec::new());
     };
 
+    let (conf, errors) = read_merged(&[path.to_path_buf()]);
+    (conf, errors.into_iter().map(|(_, error)| error).collect())
+}
+
+/// Read and merge several `toml` configuration files, the values of a file override the values
+/// of the files before it in `paths`.
+///
+/// In case of error, the function tries to continue as much as possible. The errors are returned
+/// along with the file they occurred in.
+pub fn read_merged(paths: &[path::PathBuf]) -> (Conf, Vec<(path::PathBuf, Error)>) {
+    let mut merged = toml::value::Table::new();
+    let mut errors = Vec::new();
+
+    for path in paths {
+        let (table, file_errors) = read_table(path);
+        errors.extend(file_errors.into_iter().map(|error| (path.clone(), error)));
+        if let Some(table) = table {
+            merged.extend(table);
+        }
+    }
+
+    let conf = toml::Value::Table(merged)
+        .try_into()
+        .unwrap_or_else(|_| default(Vec::new()).0);
+    // these errors have already been reported by `read_table` for the file they come from
+    take_errors();
+
+    (conf, errors)
+}
+
+/// Read a configuration from a `toml` string, e.g. for a driver which doesn't use configuration
+/// files.
+///
+/// In case of error, the function tries to continue as much as possible.
+pub fn read_str(toml: &str) -> (Conf, Vec<Error>) {
+    let (table, errors) = parse_table(toml);
+    let conf = table
+        .and_then(|table| toml::Value::Table(table).try_into().ok())
+        .unwrap_or_else(|| default(Vec::new()).0);
+    // these errors have already been reported by `parse_table`
+    take_errors();
+
+    (conf, errors)
+}
+
+/// Read a single configuration file as a `toml` table, after having checked that it is a valid
+/// configuration on its own.
+///
+/// Returns `None` if the file cannot be used at all, e.g. because it contains an unknown key.
+fn read_table(path: &path::Path) -> (Option<toml::value::Table>, Vec<Error>) {
     let file = match fs::File::open(path) {
         Ok(mut file) => {
             let mut buf = String::new();
 
             if let Err(err) = file.read_to_string(&mut buf) {
-                return default(vec![err.into()]);
+                return (None, vec![err.into()]);
             }
 
             buf
         },
-        Err(err) => return default(vec![err.into()]),
+        Err(err) => return (None, vec![err.into()]),
     };
 
+    parse_table(&file)
+}
+
+/// Parse a configuration as a `toml` table, after having checked that it is a valid
+/// configuration on its own.
+fn parse_table(toml: &str) -> (Option<toml::value::Table>, Vec<Error>) {
     assert!(
         ERRORS
             .lock()
             .expect("no threading -> mutex always safe")
             .is_empty()
     );
-    match toml::from_str(&file) {
-        Ok(toml) => (
-            toml,
-            ERRORS
-                .lock()
-                .expect("no threading -> mutex always safe")
-                .split_off(0),
-        ),
+    match toml::from_str::<Conf>(toml) {
+        // a valid configuration is a valid table
+        Ok(_) => (toml::from_str(toml).ok(), take_errors()),
         Err(e) => {
-            let mut errors = ERRORS
-                .lock()
-                .expect("no threading -> mutex always safe")
-                .split_off(0);
+            let mut errors = take_errors();
             errors.push(Error::Toml(e.to_string()));
-            default(errors)
+            (None, errors)
         },
     }
 }
@@ -0,0 +1,57 @@
+//! Items of the standard library that lints need to recognize.
+//!
+//! Lints should identify those items with `is_diag_item` and `is_type_diag_item` rather than by
+//! matching their paths, which break whenever the standard library moves things around. The
+//! compiler cannot mark these items with `#[rustc_diagnostic_item]` yet, so lang items are used
+//! where they exist and the paths of `utils::paths` otherwise. When the compiler gets diagnostic
+//! items, only this module has to change.
+
+use crate::utils::{match_def_path, paths};
+use rustc::hir::def_id::DefId;
+use rustc::lint::LateContext;
+use rustc::ty::{self, Ty, TyCtxt};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DiagItem {
+    Box,
+    Option,
+    Result,
+    String,
+    Vec,
+}
+
+impl DiagItem {
+    fn lang_item(self, tcx: TyCtxt<'_, '_, '_>) -> Option<DefId> {
+        match self {
+            DiagItem::Box => tcx.lang_items().owned_box(),
+            DiagItem::Option | DiagItem::Result | DiagItem::String | DiagItem::Vec => None,
+        }
+    }
+
+    fn path(self) -> &'static [&'static str] {
+        match self {
+            DiagItem::Box => &paths::BOX,
+            DiagItem::Option => &paths::OPTION,
+            DiagItem::Result => &paths::RESULT,
+            DiagItem::String => &paths::STRING,
+            DiagItem::Vec => &paths::VEC,
+        }
+    }
+}
+
+/// Check if `def_id` is the given item, e.g. `is_diag_item(cx, adt.did, DiagItem::Option)`.
+pub fn is_diag_item(cx: &LateContext<'_, '_>, def_id: DefId, item: DiagItem) -> bool {
+    match item.lang_item(cx.tcx) {
+        Some(lang_item) => lang_item == def_id,
+        None => match_def_path(cx.tcx, def_id, item.path()),
+    }
+}
+
+/// Check if the type is the given item, ignoring its type parameters, e.g. `Option<_>` for
+/// `DiagItem::Option`.
+pub fn is_type_diag_item(cx: &LateContext<'_, '_>, ty: Ty<'_>, item: DiagItem) -> bool {
+    match ty.sty {
+        ty::Adt(adt, _) => is_diag_item(cx, adt.did, item),
+        _ => false,
+    }
+}
@@ -5,9 +5,11 @@
 
 use if_chain::if_chain;
 use rustc::{hir, ty};
+use rustc::hir::def::Def;
 use rustc::lint::LateContext;
 use syntax::ast;
-use crate::utils::{is_expn_of, match_def_path, match_qpath, opt_def_id, paths, resolve_node};
+use syntax::ptr::P;
+use crate::utils::{is_expn_of, last_path_segment, match_def_path, match_qpath, opt_def_id, paths, resolve_node};
 
 /// Convert a hir binary operator to the corresponding `ast` type.
 pub fn binop(op: hir::BinOpKind) -> ast::BinOpKind {
@@ -243,3 +245,205 @@ pub fn vec_macro<'e>(cx: &LateContext<'_, '_>, expr: &'e hir::Expr) -> Option<Ve
 
     None
 }
+
+/// A parsed `format_args!` expansion, i.e. a call to `Arguments::new_v1` or
+/// `Arguments::new_v1_formatted`.
+pub struct FormatArgsExpn<'a> {
+    /// The literal pieces of the format string, between the placeholders
+    pub format_string_parts: Vec<ast::Name>,
+    /// The values passed after the format string, in order
+    pub value_args: Vec<&'a hir::Expr>,
+    /// The placeholders of the format string, in order
+    pub args: Vec<FormatArgsArg<'a>>,
+}
+
+/// A placeholder of a `format_args!` format string.
+pub struct FormatArgsArg<'a> {
+    /// The value formatted by the placeholder
+    pub value: &'a hir::Expr,
+    /// The `fmt` method used, e.g. `Display::fmt` for `{}`
+    pub fmt_fn: hir::def_id::DefId,
+    /// The `rt::v1::Argument` describing the placeholder, only present for
+    /// `Arguments::new_v1_formatted`
+    pub spec: Option<&'a hir::Expr>,
+}
+
+impl<'a> FormatArgsExpn<'a> {
+    /// Parses the expansion of `format_args!` if `expr` is the call to
+    /// `Arguments::new_v1` or `Arguments::new_v1_formatted` it expands to.
+    pub fn parse(cx: &LateContext<'_, '_>, expr: &'a hir::Expr) -> Option<Self> {
+        if_chain! {
+            if let hir::ExprKind::Call(ref fun, ref args) = expr.node;
+            if let hir::ExprKind::Path(ref qpath) = fun.node;
+            if let Some(fun_def_id) = opt_def_id(resolve_node(cx, qpath, fun.hir_id));
+            let formatted = match_def_path(cx.tcx, fun_def_id, &paths::FMT_ARGUMENTS_NEWV1FORMATTED);
+            if (formatted && args.len() == 3)
+                || (match_def_path(cx.tcx, fun_def_id, &paths::FMT_ARGUMENTS_NEWV1) && args.len() == 2);
+            // `&["piece", ..]`
+            if let hir::ExprKind::AddrOf(_, ref pieces) = args[0].node;
+            if let hir::ExprKind::Array(ref pieces) = pieces.node;
+            if let Some(format_string_parts) = pieces.iter().map(str_lit).collect::<Option<Vec<_>>>();
+            // `&match (&a, &b) { (arg0, arg1) => [ArgumentV1::new(arg0, Display::fmt), ..] }`
+            if let hir::ExprKind::AddrOf(_, ref matched) = args[1].node;
+            if let hir::ExprKind::Match(ref tup, ref arms, _) = matched.node;
+            if let hir::ExprKind::Tup(ref values) = tup.node;
+            if let [ref arm] = **arms;
+            if let hir::PatKind::Tuple(ref bindings, None) = arm.pats[0].node;
+            if let hir::ExprKind::Array(ref arguments) = arm.body.node;
+            then {
+                let value_args = values
+                    .iter()
+                    .map(|value| match value.node {
+                        hir::ExprKind::AddrOf(_, ref value) => Some(&**value),
+                        _ => None,
+                    })
+                    .collect::<Option<Vec<_>>>()?;
+                let arguments = arguments
+                    .iter()
+                    .map(|argument| format_argument(cx, argument, bindings, &value_args))
+                    .collect::<Option<Vec<_>>>()?;
+                let args = if formatted {
+                    // `&[rt::v1::Argument { position: rt::v1::Position::At(0), format: .. }, ..]`
+                    match args[2].node {
+                        hir::ExprKind::AddrOf(_, ref specs) => match specs.node {
+                            hir::ExprKind::Array(ref specs) => specs
+                                .iter()
+                                .map(|spec| {
+                                    let (value, fmt_fn) = *arguments.get(spec_position(spec)?)?;
+                                    Some(FormatArgsArg {
+                                        value,
+                                        fmt_fn,
+                                        spec: Some(spec),
+                                    })
+                                })
+                                .collect::<Option<Vec<_>>>()?,
+                            _ => return None,
+                        },
+                        _ => return None,
+                    }
+                } else {
+                    arguments
+                        .into_iter()
+                        .map(|(value, fmt_fn)| FormatArgsArg {
+                            value,
+                            fmt_fn,
+                            spec: None,
+                        })
+                        .collect()
+                };
+                return Some(Self {
+                    format_string_parts,
+                    value_args,
+                    args,
+                });
+            }
+        }
+        None
+    }
+}
+
+impl<'a> FormatArgsArg<'a> {
+    /// Returns true if the placeholder formats its value with `Display`.
+    pub fn is_display(&self, cx: &LateContext<'_, '_>) -> bool {
+        match_def_path(cx.tcx, self.fmt_fn, &paths::DISPLAY_FMT_METHOD)
+    }
+
+    /// Returns true if the placeholder has no width, precision, flags, fill
+    /// or alignment, like `{}` or `{:?}`.
+    pub fn has_default_spec(&self) -> bool {
+        let spec = match self.spec {
+            Some(spec) => spec,
+            None => return true,
+        };
+        if_chain! {
+            if let hir::ExprKind::Struct(_, ref fields, _) = spec.node;
+            if let Some(format) = fields.iter().find(|f| f.ident.name == "format");
+            if let hir::ExprKind::Struct(_, ref fields, _) = format.expr.node;
+            then {
+                return fields.iter().all(|field| match &*field.ident.as_str() {
+                    "fill" => is_lit(&field.expr, |lit| *lit == ast::LitKind::Char(' ')),
+                    "flags" => is_lit(&field.expr, |lit| match *lit {
+                        ast::LitKind::Int(flags, _) => flags == 0,
+                        _ => false,
+                    }),
+                    "align" => is_path_to(&field.expr, "Unknown"),
+                    "width" | "precision" => is_path_to(&field.expr, "Implied"),
+                    _ => false,
+                });
+            }
+        }
+        false
+    }
+}
+
+/// Returns the value and `fmt` method of an `ArgumentV1::new(arg0, Display::fmt)` call, the
+/// value being found through the binding it's matched to.
+fn format_argument<'a>(
+    cx: &LateContext<'_, '_>,
+    argument: &hir::Expr,
+    bindings: &[P<hir::Pat>],
+    values: &[&'a hir::Expr],
+) -> Option<(&'a hir::Expr, hir::def_id::DefId)> {
+    if_chain! {
+        if let hir::ExprKind::Call(ref fun, ref args) = argument.node;
+        if let hir::ExprKind::Path(ref qpath) = fun.node;
+        if let Some(fun_def_id) = opt_def_id(resolve_node(cx, qpath, fun.hir_id));
+        if match_def_path(cx.tcx, fun_def_id, &paths::FMT_ARGUMENTV1_NEW);
+        if let [ref binding, ref fmt_fn] = **args;
+        if let hir::ExprKind::Path(hir::QPath::Resolved(None, ref path)) = binding.node;
+        if let Def::Local(binding_id) = path.def;
+        if let Some(index) = bindings.iter().position(|pat| match pat.node {
+            hir::PatKind::Binding(_, id, _, None) => id == binding_id,
+            _ => false,
+        });
+        if let hir::ExprKind::Path(ref qpath) = fmt_fn.node;
+        if let Some(fmt_fn_def_id) = opt_def_id(resolve_node(cx, qpath, fmt_fn.hir_id));
+        then {
+            return values.get(index).map(|value| (*value, fmt_fn_def_id));
+        }
+    }
+    None
+}
+
+/// Returns the index of the `ArgumentV1` used by an `rt::v1::Argument`
+fn spec_position(spec: &hir::Expr) -> Option<usize> {
+    if_chain! {
+        if let hir::ExprKind::Struct(_, ref fields, _) = spec.node;
+        if let Some(position) = fields.iter().find(|f| f.ident.name == "position");
+        if let hir::ExprKind::Call(_, ref args) = position.expr.node;
+        if let [ref index] = **args;
+        if let hir::ExprKind::Lit(ref lit) = index.node;
+        if let ast::LitKind::Int(index, _) = lit.node;
+        then {
+            return Some(index as usize);
+        }
+    }
+    None
+}
+
+/// Returns the content of a string literal
+fn str_lit(expr: &hir::Expr) -> Option<ast::Name> {
+    match expr.node {
+        hir::ExprKind::Lit(ref lit) => match lit.node {
+            ast::LitKind::Str(name, _) => Some(name),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Checks if `expr` is a literal satisfying `pred`
+fn is_lit(expr: &hir::Expr, pred: impl FnOnce(&ast::LitKind) -> bool) -> bool {
+    match expr.node {
+        hir::ExprKind::Lit(ref lit) => pred(&lit.node),
+        _ => false,
+    }
+}
+
+/// Checks if `expr` is a path ending with `name`, e.g. `rt::v1::Count::Implied`
+fn is_path_to(expr: &hir::Expr, name: &str) -> bool {
+    match expr.node {
+        hir::ExprKind::Path(ref qpath) => last_path_segment(qpath).ident.name == name,
+        _ => false,
+    }
+}
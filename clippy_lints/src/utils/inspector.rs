@@ -9,23 +9,24 @@ use rustc::hir::print;
 use syntax::ast::Attribute;
 use crate::utils::get_attr;
 
-/// **What it does:** Dumps every ast/hir node which has the `#[clippy_dump]`
-/// attribute
-///
-/// **Example:**
-/// ```rust
-/// #[clippy_dump]
-/// extern crate foo;
-/// ```
-///
-/// prints
-///
-/// ```
-/// item `foo`
-/// visibility inherited from outer item
-/// extern crate dylib source: "/path/to/foo.so"
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Dumps every ast/hir node which has the `#[clippy_dump]`
+    /// attribute
+    ///
+    /// **Example:**
+    /// ```rust
+    /// #[clippy_dump]
+    /// extern crate foo;
+    /// ```
+    ///
+    /// prints
+    ///
+    /// ```
+    /// item `foo`
+    /// visibility inherited from outer item
+    /// extern crate dylib source: "/path/to/foo.so"
+    /// ```
+    #[clippy::version = "pre 0.0.213"]
     pub DEEP_CODE_INSPECTION,
     internal_warn,
     "helper to dump info about code"
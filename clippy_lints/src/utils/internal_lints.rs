@@ -11,56 +11,59 @@ use syntax::source_map::Span;
 use std::collections::{HashMap, HashSet};
 
 
-/// **What it does:** Checks for various things we like to keep tidy in clippy.
-///
-/// **Why is this bad?** We like to pretend we're an example of tidy code.
-///
-/// **Known problems:** None.
-///
-/// **Example:** Wrong ordering of the util::paths constants.
 declare_clippy_lint! {
+    /// **What it does:** Checks for various things we like to keep tidy in clippy.
+    ///
+    /// **Why is this bad?** We like to pretend we're an example of tidy code.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:** Wrong ordering of the util::paths constants.
+    #[clippy::version = "pre 0.0.213"]
     pub CLIPPY_LINTS_INTERNAL,
     internal,
     "various things that will negatively affect your clippy experience"
 }
 
 
-/// **What it does:** Ensures every lint is associated to a `LintPass`.
-///
-/// **Why is this bad?** The compiler only knows lints via a `LintPass`. Without
-/// putting a lint to a `LintPass::get_lints()`'s return, the compiler will not
-/// know the name of the lint.
-///
-/// **Known problems:** Only checks for lints associated using the `lint_array!`
-/// macro.
-///
-/// **Example:**
-/// ```rust
-/// declare_lint! { pub LINT_1, ... }
-/// declare_lint! { pub LINT_2, ... }
-/// declare_lint! { pub FORGOTTEN_LINT, ... }
-/// // ...
-/// pub struct Pass;
-/// impl LintPass for Pass {
-///     fn get_lints(&self) -> LintArray {
-///         lint_array![LINT_1, LINT_2]
-///         // missing FORGOTTEN_LINT
-///     }
-/// }
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Ensures every lint is associated to a `LintPass`.
+    ///
+    /// **Why is this bad?** The compiler only knows lints via a `LintPass`. Without
+    /// putting a lint to a `LintPass::get_lints()`'s return, the compiler will not
+    /// know the name of the lint.
+    ///
+    /// **Known problems:** Only checks for lints associated using the `lint_array!`
+    /// macro.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// declare_lint! { pub LINT_1, ... }
+    /// declare_lint! { pub LINT_2, ... }
+    /// declare_lint! { pub FORGOTTEN_LINT, ... }
+    /// // ...
+    /// pub struct Pass;
+    /// impl LintPass for Pass {
+    ///     fn get_lints(&self) -> LintArray {
+    ///         lint_array![LINT_1, LINT_2]
+    ///         // missing FORGOTTEN_LINT
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "pre 0.0.213"]
     pub LINT_WITHOUT_LINT_PASS,
     internal,
     "declaring a lint without associating it in a LintPass"
 }
 
 
-/// **What it does:** Checks for the presence of the default hash types "HashMap" or "HashSet"
-/// and recommends the FxHash* variants.
-///
-/// **Why is this bad?** The FxHash variants have better performance
-/// and we don't need any collision prevention in clippy.
 declare_clippy_lint! {
+    /// **What it does:** Checks for the presence of the default hash types "HashMap" or "HashSet"
+    /// and recommends the FxHash* variants.
+    ///
+    /// **Why is this bad?** The FxHash variants have better performance
+    /// and we don't need any collision prevention in clippy.
+    #[clippy::version = "pre 0.0.213"]
     pub DEFAULT_HASH_TYPES,
     internal,
     "forbid HashMap and HashSet and suggest the FxHash* variants"
@@ -3,19 +3,17 @@ use matches::matches;
 use if_chain::if_chain;
 use rustc::hir;
 use rustc::hir::*;
-use rustc::hir::def_id::{DefId, CRATE_DEF_INDEX};
+use rustc::hir::def_id::{DefId, CRATE_DEF_INDEX, LOCAL_CRATE};
 use rustc::hir::def::Def;
 use rustc::hir::intravisit::{NestedVisitorMap, Visitor};
 use rustc::hir::Node;
 use rustc::lint::{LateContext, Level, Lint, LintContext};
-use rustc::session::Session;
 use rustc::traits;
 use rustc::ty::{self, Binder, Ty, TyCtxt, layout::{self, IntegerExt}, subst::Kind};
 use rustc_errors::{Applicability, CodeSuggestion, Substitution, SubstitutionPart};
 use std::borrow::Cow;
 use std::env;
 use std::mem;
-use std::str::FromStr;
 use std::rc::Rc;
 use syntax::ast::{self, LitKind};
 use syntax::attr;
@@ -27,14 +25,19 @@ use syntax::symbol::keywords;
 pub mod comparisons;
 pub mod conf;
 pub mod constants;
+pub mod diag_items;
 mod hir_utils;
 pub mod paths;
 pub mod sugg;
+pub mod suggestion_stats;
 pub mod inspector;
 pub mod internal_lints;
+pub mod msrvs;
+pub mod attrs;
 pub mod author;
 pub mod ptr;
 pub mod usage;
+pub use self::attrs::{get_attr, LimitStack};
 pub use self::hir_utils::{SpanlessEq, SpanlessHash};
 
 pub type MethodArgs = HirVec<P<Expr>>;
@@ -261,6 +264,23 @@ pub fn implements_trait<'a, 'tcx>(
     cx.tcx.infer_ctxt().enter(|infcx| infcx.predicate_must_hold(&obligation))
 }
 
+/// Returns the type `ty` dereferences to, i.e. `<ty as Deref>::Target`, if it implements `Deref`.
+pub fn deref_target<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, ty: Ty<'tcx>) -> Option<Ty<'tcx>> {
+    if let Some(ty::TypeAndMut { ty, .. }) = ty.builtin_deref(true) {
+        return Some(ty);
+    }
+    let deref_trait = cx.tcx.lang_items().deref_trait()?;
+    if !implements_trait(cx, ty, deref_trait, &[]) {
+        return None;
+    }
+    let target = cx
+        .tcx
+        .associated_items(deref_trait)
+        .find(|item| item.ident.name == "Target")?;
+    let projection = cx.tcx.mk_projection(target.def_id, cx.tcx.mk_substs_trait(ty, &[]));
+    Some(cx.tcx.normalize_erasing_regions(cx.param_env, projection))
+}
+
 /// Check whether this type implements Drop.
 pub fn has_drop(cx: &LateContext<'_, '_>, expr: &Expr) -> bool {
     let struct_ty = cx.tables.expr_ty(expr);
@@ -304,6 +324,33 @@ pub fn method_chain_args<'a>(expr: &'a Expr, methods: &[&str]) -> Option<Vec<&'a
     Some(matched)
 }
 
+/// Returns the method names and argument lists of up to `max_depth` method calls of a chain, the
+/// outermost call first. The chain stops at the first call with an argument coming from a macro.
+///
+/// For example, if `expr` represents the `.baz()` in `foo.bar().baz()`, `method_calls(expr, 2)`
+/// returns `(["baz", "bar"], [[foo.bar()], [foo]])`. This is meant to be matched against
+/// multiple chains at once, where `method_chain_args` would walk the chain for each of them.
+pub fn method_calls<'a>(expr: &'a Expr, max_depth: usize) -> (Vec<Name>, Vec<&'a [Expr]>) {
+    let mut method_names = Vec::with_capacity(max_depth);
+    let mut arg_lists = Vec::with_capacity(max_depth);
+
+    let mut current = expr;
+    for _ in 0..max_depth {
+        if let ExprKind::MethodCall(ref path, _, ref args) = current.node {
+            if args.iter().any(|e| in_macro(e.span)) {
+                break;
+            }
+            method_names.push(path.ident.name);
+            arg_lists.push(&**args);
+            current = &args[0];
+        } else {
+            break;
+        }
+    }
+
+    (method_names, arg_lists)
+}
+
 
 /// Get the name of the item the expression is in, if available.
 pub fn get_item_name(cx: &LateContext<'_, '_>, expr: &Expr) -> Option<Name> {
@@ -496,28 +543,34 @@ pub fn get_enclosing_block<'a, 'tcx: 'a>(cx: &LateContext<'a, 'tcx>, node: NodeI
     }
 }
 
-pub struct DiagnosticWrapper<'a>(pub DiagnosticBuilder<'a>);
+/// Emits the diagnostic of the lint when dropped, once it has been recorded for
+/// `--suggestion-stats`.
+pub struct DiagnosticWrapper<'a>(pub DiagnosticBuilder<'a>, pub &'static Lint);
 
 impl<'a> Drop for DiagnosticWrapper<'a> {
     fn drop(&mut self) {
+        // the diagnostics of allowed lints are cancelled
+        if !self.0.cancelled() {
+            suggestion_stats::record(self.1, &self.0);
+        }
         self.0.emit();
     }
 }
 
 impl<'a> DiagnosticWrapper<'a> {
-    fn docs_link(&mut self, lint: &'static Lint) {
+    fn docs_link(&mut self) {
         if env::var("CLIPPY_DISABLE_DOCS_LINKS").is_err() {
             self.0.help(&format!(
                 "for further information visit https://rust-lang-nursery.github.io/rust-clippy/v{}/index.html#{}",
                 env!("CARGO_PKG_VERSION"),
-                lint.name_lower()
+                self.1.name_lower()
             ));
         }
     }
 }
 
 pub fn span_lint<'a, T: LintContext<'a>>(cx: &T, lint: &'static Lint, sp: Span, msg: &str) {
-    DiagnosticWrapper(cx.struct_span_lint(lint, sp, msg)).docs_link(lint);
+    DiagnosticWrapper(cx.struct_span_lint(lint, sp, msg), lint).docs_link();
 }
 
 pub fn span_help_and_lint<'a, 'tcx: 'a, T: LintContext<'tcx>>(
@@ -527,9 +580,9 @@ pub fn span_help_and_lint<'a, 'tcx: 'a, T: LintContext<'tcx>>(
     msg: &str,
     help: &str,
 ) {
-    let mut db = DiagnosticWrapper(cx.struct_span_lint(lint, span, msg));
+    let mut db = DiagnosticWrapper(cx.struct_span_lint(lint, span, msg), lint);
     db.0.help(help);
-    db.docs_link(lint);
+    db.docs_link();
 }
 
 pub fn span_note_and_lint<'a, 'tcx: 'a, T: LintContext<'tcx>>(
@@ -540,13 +593,13 @@ pub fn span_note_and_lint<'a, 'tcx: 'a, T: LintContext<'tcx>>(
     note_span: Span,
     note: &str,
 ) {
-    let mut db = DiagnosticWrapper(cx.struct_span_lint(lint, span, msg));
+    let mut db = DiagnosticWrapper(cx.struct_span_lint(lint, span, msg), lint);
     if note_span == span {
         db.0.note(note);
     } else {
         db.0.span_note(note_span, note);
     }
-    db.docs_link(lint);
+    db.docs_link();
 }
 
 pub fn span_lint_and_then<'a, 'tcx: 'a, T: LintContext<'tcx>, F>(
@@ -558,9 +611,9 @@ pub fn span_lint_and_then<'a, 'tcx: 'a, T: LintContext<'tcx>, F>(
 ) where
     F: for<'b> FnOnce(&mut DiagnosticBuilder<'b>),
 {
-    let mut db = DiagnosticWrapper(cx.struct_span_lint(lint, sp, msg));
+    let mut db = DiagnosticWrapper(cx.struct_span_lint(lint, sp, msg), lint);
     f(&mut db.0);
-    db.docs_link(lint);
+    db.docs_link();
 }
 
 /// Add a span lint with a suggestion on how to fix it.
@@ -590,6 +643,40 @@ pub fn span_lint_and_sugg<'a, 'tcx: 'a, T: LintContext<'tcx>>(
     });
 }
 
+/// Add a span lint with a suggestion made of several `span → replacement`, which are applied
+/// together: use this rather than several `span_suggestion`s when the edits only make sense as a
+/// whole (e.g. changing the type of an argument and the calls on it).
+pub fn span_lint_and_multipart_sugg<'a, 'tcx: 'a, T: LintContext<'tcx>>(
+    cx: &'a T,
+    lint: &'static Lint,
+    sp: Span,
+    msg: &str,
+    help: &str,
+    applicability: Applicability,
+    sugg: Vec<(Span, String)>,
+) {
+    span_lint_and_then(cx, lint, sp, msg, |db| {
+        multispan_sugg_with_applicability(db, help.to_owned(), applicability, sugg);
+    });
+}
+
+/// Like `span_lint_and_then`, but the lint level is the one at the node `hir_id` instead of the
+/// one of the node being visited. This is needed when the lint is emitted for a node which is
+/// not the one being checked, e.g. from `check_crate_post`.
+pub fn span_lint_hir_and_then(
+    cx: &LateContext<'_, '_>,
+    lint: &'static Lint,
+    hir_id: HirId,
+    sp: Span,
+    msg: &str,
+    f: impl FnOnce(&mut DiagnosticBuilder<'_>),
+) {
+    let node_id = cx.tcx.hir.hir_to_node_id(hir_id);
+    let mut db = DiagnosticWrapper(cx.tcx.struct_span_lint_node(lint, node_id, sp, msg), lint);
+    f(&mut db.0);
+    db.docs_link();
+}
+
 /// Create a suggestion made from several `span → replacement`.
 ///
 /// Note: in the JSON format (used by `compiletest_rs`), the help message will
@@ -599,6 +686,19 @@ pub fn span_lint_and_sugg<'a, 'tcx: 'a, T: LintContext<'tcx>>(
 pub fn multispan_sugg<I>(db: &mut DiagnosticBuilder<'_>, help_msg: String, sugg: I)
 where
     I: IntoIterator<Item = (Span, String)>,
+{
+    multispan_sugg_with_applicability(db, help_msg, Applicability::Unspecified, sugg);
+}
+
+/// Create a suggestion made from several `span → replacement`, all the replacements sharing the
+/// given applicability.
+pub fn multispan_sugg_with_applicability<I>(
+    db: &mut DiagnosticBuilder<'_>,
+    help_msg: String,
+    applicability: Applicability,
+    sugg: I,
+) where
+    I: IntoIterator<Item = (Span, String)>,
 {
     let sugg = CodeSuggestion {
         substitutions: vec![
@@ -615,7 +715,7 @@ where
         ],
         msg: help_msg,
         show_code_when_inline: true,
-        applicability: Applicability::Unspecified,
+        applicability,
     };
     db.suggestions.push(sugg);
 }
@@ -663,59 +763,6 @@ pub fn is_adjusted(cx: &LateContext<'_, '_>, e: &Expr) -> bool {
     cx.tables.adjustments().get(e.hir_id).is_some()
 }
 
-pub struct LimitStack {
-    stack: Vec<u64>,
-}
-
-impl Drop for LimitStack {
-    fn drop(&mut self) {
-        assert_eq!(self.stack.len(), 1);
-    }
-}
-
-impl LimitStack {
-    pub fn new(limit: u64) -> Self {
-        Self { stack: vec![limit] }
-    }
-    pub fn limit(&self) -> u64 {
-        *self.stack
-            .last()
-            .expect("there should always be a value in the stack")
-    }
-    pub fn push_attrs(&mut self, sess: &Session, attrs: &[ast::Attribute], name: &'static str) {
-        let stack = &mut self.stack;
-        parse_attrs(sess, attrs, name, |val| stack.push(val));
-    }
-    pub fn pop_attrs(&mut self, sess: &Session, attrs: &[ast::Attribute], name: &'static str) {
-        let stack = &mut self.stack;
-        parse_attrs(sess, attrs, name, |val| assert_eq!(stack.pop(), Some(val)));
-    }
-}
-
-pub fn get_attr<'a>(attrs: &'a [ast::Attribute], name: &'static str) -> impl Iterator<Item = &'a ast::Attribute> {
-    attrs.iter().filter_map(move |attr| {
-        if attr.path.segments.len() == 2 && attr.path.segments[0].ident.to_string() == "clippy" && attr.path.segments[1].ident.to_string() == name {
-            Some(attr)
-        } else {
-            None
-        }
-    })
-}
-
-fn parse_attrs<F: FnMut(u64)>(sess: &Session, attrs: &[ast::Attribute], name: &'static str, mut f: F) {
-    for attr in get_attr(attrs, name) {
-        if let Some(ref value) = attr.value_str() {
-            if let Ok(value) = FromStr::from_str(&value.as_str()) {
-                f(value)
-            } else {
-                sess.span_err(attr.span, "not a number");
-            }
-        } else {
-            sess.span_err(attr.span, "bad clippy attribute");
-        }
-    }
-}
-
 /// Return the pre-expansion span if is this comes from an expansion of the
 /// macro `name`.
 /// See also `is_direct_expn_of`.
@@ -818,6 +865,29 @@ pub fn camel_case_from(s: &str) -> usize {
     last_i
 }
 
+/// Returns the number of chars of `pre` that match the start of `name`, which is
+/// never matched entirely
+pub fn partial_match(pre: &str, name: &str) -> usize {
+    let mut name_iter = name.chars();
+    let _ = name_iter.next_back(); // make sure the name is never fully matched
+    pre.chars()
+        .zip(name_iter)
+        .take_while(|&(l, r)| l == r)
+        .count()
+}
+
+/// Returns the number of chars of `post` that match the end of `name`, which is
+/// never matched entirely
+pub fn partial_rmatch(post: &str, name: &str) -> usize {
+    let mut name_iter = name.chars();
+    let _ = name_iter.next(); // make sure the name is never fully matched
+    post.chars()
+        .rev()
+        .zip(name_iter.rev())
+        .take_while(|&(l, r)| l == r)
+        .count()
+}
+
 /// Convenience function to get the return type of a function
 pub fn return_ty<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, fn_item: NodeId) -> Ty<'tcx> {
     let fn_def_id = cx.tcx.hir.local_def_id(fn_item);
@@ -1024,6 +1094,44 @@ pub fn is_allowed(cx: &LateContext<'_, '_>, lint: &'static Lint, id: NodeId) ->
     cx.tcx.lint_level_at_node(lint, id).0 == Level::Allow
 }
 
+/// Returns true if the lint is allowed at the node with the given `HirId`
+///
+/// Lints doing costly work before they know whether they fire (constant evaluation, snippets)
+/// should return early if this is true: whole lint groups are often allowed.
+pub fn is_lint_allowed(cx: &LateContext<'_, '_>, lint: &'static Lint, id: HirId) -> bool {
+    is_allowed(cx, lint, cx.tcx.hir.hir_to_node_id(id))
+}
+
+/// Returns true if `def_id` is the entry point of the crate, i.e. `main` or the function marked
+/// with `#[start]`
+pub fn is_entrypoint_fn(cx: &LateContext<'_, '_>, def_id: DefId) -> bool {
+    cx.tcx.entry_fn(LOCAL_CRATE).map_or(false, |(entry_fn_def_id, _)| def_id == entry_fn_def_id)
+}
+
+/// Returns true if the node is in a `#[test]` function or in a `#[cfg(test)]` item, e.g. a
+/// `tests` module
+pub fn is_in_test(cx: &LateContext<'_, '_>, id: NodeId) -> bool {
+    let is_test_attr = |attr: &ast::Attribute| {
+        attr.check_name("test")
+            || (attr.check_name("cfg")
+                && attr
+                    .meta_item_list()
+                    .map_or(false, |items| items.iter().any(|item| item.check_name("test"))))
+    };
+
+    let mut id = id;
+    loop {
+        let parent = cx.tcx.hir.get_parent(id);
+        if cx.tcx.hir.attrs(parent).iter().any(is_test_attr) {
+            return true;
+        }
+        if parent == id || parent == ast::CRATE_NODE_ID {
+            return false;
+        }
+        id = parent;
+    }
+}
+
 pub fn get_arg_name(pat: &Pat) -> Option<ast::Name> {
     match pat.node {
         PatKind::Binding(_, _, ident, None) => Some(ident.name),
@@ -1056,10 +1164,13 @@ pub fn unsext(tcx: TyCtxt<'_, '_, '_>, u: i128, ity: ast::IntTy) -> u128 {
     ((u as u128) << amt) >> amt
 }
 
+pub fn uint_bits(tcx: TyCtxt<'_, '_, '_>, uty: ast::UintTy) -> u64 {
+    layout::Integer::from_attr(tcx, attr::IntType::UnsignedInt(uty)).size().bits()
+}
+
 /// clip unused bytes
 pub fn clip(tcx: TyCtxt<'_, '_, '_>, u: u128, ity: ast::UintTy) -> u128 {
-    let bits = layout::Integer::from_attr(tcx, attr::IntType::UnsignedInt(ity)).size().bits();
-    let amt = 128 - bits;
+    let amt = 128 - uint_bits(tcx, ity);
     (u << amt) >> amt
 }
 
@@ -1109,3 +1220,12 @@ pub fn any_parent_is_automatically_derived(tcx: TyCtxt<'_, '_, '_>, node: NodeId
     }
     false
 }
+
+/// Checks for a Windows drive prefix, e.g. `C:`
+pub fn has_drive_prefix(path: &str) -> bool {
+    let mut chars = path.chars();
+    match (chars.next(), chars.next()) {
+        (Some(letter), Some(':')) => letter.is_ascii_alphabetic(),
+        _ => false,
+    }
+}
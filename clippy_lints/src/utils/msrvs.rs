@@ -0,0 +1,116 @@
+//! Minimum supported Rust versions.
+//!
+//! Lints whose suggestions need a recent compiler must not fire for projects supporting older
+//! ones. The MSRV of a project is the `msrv` configuration value. It can be overridden for a
+//! crate, a module or a single item with `#![clippy::msrv = "1.16"]`, the innermost attribute
+//! wins. Lints keep track of it with a `MsrvStack`.
+
+use crate::utils::get_attr;
+use rustc::session::Session;
+use syntax::ast;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct RustcVersion {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+}
+
+impl RustcVersion {
+    /// Parses versions like `1.17` or `1.17.1`, the patch version defaults to 0.
+    pub fn parse(version: &str) -> Option<Self> {
+        let mut parts = version.trim().split('.').map(|part| part.parse::<u64>().ok());
+        let major = parts.next()??;
+        let minor = parts.next()??;
+        let patch = match parts.next() {
+            Some(patch) => patch?,
+            None => 0,
+        };
+        if parts.next().is_some() {
+            return None;
+        }
+
+        Some(Self { major, minor, patch })
+    }
+}
+
+/// The field init shorthand, `Foo { bar }`.
+pub const FIELD_INIT_SHORTHAND: RustcVersion = RustcVersion { major: 1, minor: 17, patch: 0 };
+/// The elision of `'static` in `const` and `static` types.
+pub const STATIC_IN_CONST: RustcVersion = RustcVersion { major: 1, minor: 17, patch: 0 };
+/// The `TryFrom` trait.
+pub const TRY_FROM: RustcVersion = RustcVersion { major: 1, minor: 34, patch: 0 };
+/// `std::mem::take`.
+pub const MEM_TAKE: RustcVersion = RustcVersion { major: 1, minor: 40, patch: 0 };
+/// `Option::as_deref` and `Option::as_deref_mut`.
+pub const OPTION_AS_DEREF: RustcVersion = RustcVersion { major: 1, minor: 40, patch: 0 };
+/// The `#[non_exhaustive]` attribute.
+pub const NON_EXHAUSTIVE: RustcVersion = RustcVersion { major: 1, minor: 40, patch: 0 };
+/// `Seek::stream_position`.
+pub const SEEK_FROM_CURRENT: RustcVersion = RustcVersion { major: 1, minor: 51, patch: 0 };
+/// `Seek::rewind`.
+pub const SEEK_REWIND: RustcVersion = RustcVersion { major: 1, minor: 55, patch: 0 };
+/// The `BITS` associated constant of integers.
+pub const MANUAL_BITS: RustcVersion = RustcVersion { major: 1, minor: 53, patch: 0 };
+/// `bool::then`.
+pub const BOOL_THEN: RustcVersion = RustcVersion { major: 1, minor: 50, patch: 0 };
+/// Arrays of `char`s as string patterns.
+pub const PATTERN_TRAIT_CHAR_ARRAY: RustcVersion = RustcVersion { major: 1, minor: 58, patch: 0 };
+/// `bool::then_some`.
+pub const BOOL_THEN_SOME: RustcVersion = RustcVersion { major: 1, minor: 62, patch: 0 };
+/// `let...else` statements.
+pub const LET_ELSE: RustcVersion = RustcVersion { major: 1, minor: 65, patch: 0 };
+/// `HashMap::retain` and `HashSet::retain`.
+pub const HASH_RETAIN: RustcVersion = RustcVersion { major: 1, minor: 18, patch: 0 };
+/// `String::retain`.
+pub const STRING_RETAIN: RustcVersion = RustcVersion { major: 1, minor: 26, patch: 0 };
+/// The `From` conversions between arrays and tuples.
+pub const TUPLE_ARRAY_CONVERSIONS: RustcVersion = RustcVersion { major: 1, minor: 71, patch: 0 };
+
+/// The MSRV at the node being visited. The attributes must be pushed in `enter_lint_attrs` and
+/// popped in `exit_lint_attrs`.
+pub struct MsrvStack {
+    stack: Vec<Option<RustcVersion>>,
+}
+
+impl Drop for MsrvStack {
+    fn drop(&mut self) {
+        assert_eq!(self.stack.len(), 1);
+    }
+}
+
+impl MsrvStack {
+    pub fn new(msrv: Option<RustcVersion>) -> Self {
+        Self { stack: vec![msrv] }
+    }
+
+    /// Returns true if the code may use features stabilized in `version`, which is the case if
+    /// no MSRV is set.
+    pub fn meets(&self, version: RustcVersion) -> bool {
+        self.stack
+            .last()
+            .expect("there should always be a value in the stack")
+            .map_or(true, |msrv| msrv >= version)
+    }
+
+    pub fn enter_lint_attrs(&mut self, sess: &Session, attrs: &[ast::Attribute]) {
+        for attr in get_attr(attrs, "msrv") {
+            match parse_attr(attr) {
+                Some(version) => self.stack.push(Some(version)),
+                None => sess.span_err(attr.span, "`msrv` must be a Rust version, e.g. `#[clippy::msrv = \"1.17\"]`"),
+            }
+        }
+    }
+
+    pub fn exit_lint_attrs(&mut self, attrs: &[ast::Attribute]) {
+        for attr in get_attr(attrs, "msrv") {
+            if let Some(version) = parse_attr(attr) {
+                assert_eq!(self.stack.pop(), Some(Some(version)));
+            }
+        }
+    }
+}
+
+fn parse_attr(attr: &ast::Attribute) -> Option<RustcVersion> {
+    attr.value_str().and_then(|value| RustcVersion::parse(&value.as_str()))
+}
@@ -0,0 +1,133 @@
+//! Statistics about the suggestions of the emitted lints, printed by `--suggestion-stats`.
+//!
+//! Every diagnostic emitted through the `span_lint*` helpers is recorded with the applicability
+//! of its suggestions, or as a diagnostic without suggestion, to find the lints which can't be
+//! fixed automatically yet.
+
+use lazy_static::lazy_static;
+use rustc::lint::Lint;
+use rustc_errors::Applicability;
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+use syntax::errors::DiagnosticBuilder;
+
+/// The number of diagnostics of a lint, by the applicability of their most applicable
+/// suggestion.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SuggestionStats {
+    pub machine_applicable: usize,
+    pub maybe_incorrect: usize,
+    pub has_placeholders: usize,
+    pub unspecified: usize,
+    /// The diagnostics without any suggestion.
+    pub without_suggestion: usize,
+}
+
+impl SuggestionStats {
+    pub fn total(&self) -> usize {
+        self.machine_applicable
+            + self.maybe_incorrect
+            + self.has_placeholders
+            + self.unspecified
+            + self.without_suggestion
+    }
+
+    fn add(&mut self, applicability: Option<Applicability>) {
+        let count = match applicability {
+            Some(Applicability::MachineApplicable) => &mut self.machine_applicable,
+            Some(Applicability::MaybeIncorrect) => &mut self.maybe_incorrect,
+            Some(Applicability::HasPlaceholders) => &mut self.has_placeholders,
+            Some(Applicability::Unspecified) => &mut self.unspecified,
+            None => &mut self.without_suggestion,
+        };
+        *count += 1;
+    }
+
+    /// The counts in the order of `COLUMNS`
+    fn columns(&self) -> [usize; 6] {
+        [
+            self.total(),
+            self.machine_applicable,
+            self.maybe_incorrect,
+            self.has_placeholders,
+            self.unspecified,
+            self.without_suggestion,
+        ]
+    }
+}
+
+/// The headers of the columns of the report, after the lint names
+const COLUMNS: [&str; 6] = [
+    "total",
+    "machine-applicable",
+    "maybe-incorrect",
+    "has-placeholders",
+    "unspecified",
+    "no suggestion",
+];
+
+lazy_static! {
+    static ref STATS: Mutex<BTreeMap<String, SuggestionStats>> = Mutex::new(BTreeMap::new());
+}
+
+/// Records a diagnostic of `lint` which is being emitted.
+pub fn record(lint: &'static Lint, db: &DiagnosticBuilder<'_>) {
+    // a tool fixing the code would pick the most applicable suggestion
+    let applicability = db
+        .suggestions
+        .iter()
+        .map(|sugg| sugg.applicability)
+        .max_by_key(|&applicability| rank(applicability));
+    STATS
+        .lock()
+        .expect("no threading -> mutex always safe")
+        .entry(lint.name_lower())
+        .or_default()
+        .add(applicability);
+}
+
+/// Orders the applicabilities from the least to the most applicable
+fn rank(applicability: Applicability) -> u8 {
+    match applicability {
+        Applicability::Unspecified => 0,
+        Applicability::HasPlaceholders => 1,
+        Applicability::MaybeIncorrect => 2,
+        Applicability::MachineApplicable => 3,
+    }
+}
+
+/// Takes the statistics recorded so far, by lint name.
+pub fn take() -> BTreeMap<String, SuggestionStats> {
+    let mut stats = STATS.lock().expect("no threading -> mutex always safe");
+    std::mem::replace(&mut *stats, BTreeMap::new())
+}
+
+/// Renders the statistics as a table. The lints with the most diagnostics lacking a
+/// machine-applicable suggestion come first.
+pub fn render(stats: &BTreeMap<String, SuggestionStats>) -> String {
+    let mut lints: Vec<_> = stats.iter().collect();
+    let missing = |counts: &SuggestionStats| Reverse(counts.total() - counts.machine_applicable);
+    lints.sort_by(|(name, counts), (other_name, other_counts)| {
+        missing(counts)
+            .cmp(&missing(other_counts))
+            .then_with(|| name.cmp(other_name))
+    });
+
+    let width = lints.iter().map(|(name, _)| name.len()).fold("lint".len(), usize::max);
+    let mut report = row(width, "lint", COLUMNS.iter().map(ToString::to_string));
+    for (name, counts) in lints {
+        report.push_str(&row(width, name, counts.columns().iter().map(usize::to_string)));
+    }
+    report
+}
+
+/// Renders a line of the table, the cells are aligned to the right of their column
+fn row(width: usize, name: &str, cells: impl Iterator<Item = String>) -> String {
+    let mut line = format!("{:width$}", name, width = width);
+    for (cell, column) in cells.zip(COLUMNS.iter()) {
+        line.push_str(&format!("  {:>width$}", cell, width = column.len()));
+    }
+    line.push('\n');
+    line
+}
@@ -0,0 +1,176 @@
+//! Downloading, extracting and linting the crates of the corpus.
+
+use crate::report::ClippyWarning;
+use flate2::read::GzDecoder;
+use serde_derive::Deserialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::{self, ErrorKind};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use tar::Archive;
+
+const DOWNLOAD_DIR: &str = "target/lintcheck/downloads";
+const EXTRACT_DIR: &str = "target/lintcheck/crates";
+/// All the crates share a target directory, so that common dependencies are only built once. The
+/// artifacts of the crates themselves are removed before linting them, see `Krate::run_clippy`.
+const TARGET_DIR: &str = "target/lintcheck/target";
+
+/// The content of `lintcheck_crates.toml`.
+#[derive(Deserialize)]
+struct CrateList {
+    crates: BTreeMap<String, TomlCrate>,
+}
+
+#[derive(Deserialize)]
+struct TomlCrate {
+    name: String,
+    versions: Vec<String>,
+}
+
+/// A version of a crate of the corpus.
+pub struct Krate {
+    pub name: String,
+    pub version: String,
+}
+
+/// Reads the list of crates to check, every version of a crate being a separate `Krate`.
+pub fn read_crate_list(path: &Path) -> io::Result<Vec<Krate>> {
+    let content = fs::read_to_string(path)?;
+    let list: CrateList = toml::from_str(&content).map_err(|err| {
+        io::Error::new(
+            ErrorKind::InvalidData,
+            format!("could not parse `{}`: {}", path.display(), err),
+        )
+    })?;
+
+    Ok(list
+        .crates
+        .into_iter()
+        .flat_map(|(_, krate)| {
+            let name = krate.name;
+            krate.versions.into_iter().map(move |version| Krate {
+                name: name.clone(),
+                version,
+            })
+        })
+        .collect())
+}
+
+impl Krate {
+    /// Downloads and extracts the crate. Both steps are skipped if they have already been done by
+    /// a previous run.
+    pub fn download_and_extract(&self) -> io::Result<PathBuf> {
+        let dir = Path::new(EXTRACT_DIR).join(format!("{}-{}", self.name, self.version));
+        if dir.is_dir() {
+            return Ok(dir);
+        }
+
+        fs::create_dir_all(DOWNLOAD_DIR)?;
+        let archive = Path::new(DOWNLOAD_DIR).join(format!("{}-{}.crate", self.name, self.version));
+        if !archive.is_file() {
+            let url = format!(
+                "https://crates.io/api/v1/crates/{}/{}/download",
+                self.name, self.version
+            );
+            println!("Downloading {} {}", self.name, self.version);
+            let mut response = reqwest::get(&url)
+                .and_then(|response| response.error_for_status())
+                .map_err(|err| io::Error::new(ErrorKind::Other, format!("could not download `{}`: {}", url, err)))?;
+            let mut file = File::create(&archive)?;
+            response
+                .copy_to(&mut file)
+                .map_err(|err| io::Error::new(ErrorKind::Other, err))?;
+        }
+
+        // the archive contains a single `name-version` directory
+        Archive::new(GzDecoder::new(File::open(&archive)?)).unpack(EXTRACT_DIR)?;
+
+        Ok(dir)
+    }
+
+    /// Runs the locally built `cargo-clippy` over the crate and returns the warnings of all lints,
+    /// including the rustc ones.
+    pub fn run_clippy(&self, dir: &Path, cargo_clippy: &Path) -> io::Result<Vec<ClippyWarning>> {
+        println!("Linting {} {}", self.name, self.version);
+        let target_dir = Path::new(TARGET_DIR).canonicalize().or_else(|_| {
+            fs::create_dir_all(TARGET_DIR)?;
+            Path::new(TARGET_DIR).canonicalize()
+        })?;
+
+        // cargo doesn't replay the warnings of a crate which is already built, e.g. by a previous
+        // run: it has to be checked again
+        let clean = Command::new("cargo")
+            .args(&["clean", "-p", &format!("{}:{}", self.name, self.version)])
+            .env("CARGO_TARGET_DIR", &target_dir)
+            .current_dir(dir)
+            .output()?;
+        if !clean.status.success() {
+            return Err(io::Error::new(
+                ErrorKind::Other,
+                format!(
+                    "could not clean {} {}: {}",
+                    self.name,
+                    self.version,
+                    String::from_utf8_lossy(&clean.stderr)
+                ),
+            ));
+        }
+
+        let output = Command::new(cargo_clippy)
+            .arg("clippy")
+            .arg("--message-format=json")
+            .args(&["--", "--cap-lints=warn", "-W", "clippy_pedantic"])
+            .env("CARGO_TARGET_DIR", target_dir)
+            .current_dir(dir)
+            .output()?;
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let warnings: Vec<_> = stdout
+            .lines()
+            .filter_map(|line| serde_json::from_str::<Value>(line).ok())
+            .filter(|message| message["reason"] == "compiler-message")
+            .filter_map(|message| self.to_warning(&message["message"], dir))
+            .collect();
+
+        if !output.status.success() && warnings.is_empty() {
+            eprintln!(
+                "warning: clippy failed on {} {}:\n{}",
+                self.name,
+                self.version,
+                String::from_utf8_lossy(&output.stderr)
+            );
+        }
+
+        Ok(warnings)
+    }
+
+    fn to_warning(&self, diagnostic: &Value, dir: &Path) -> Option<ClippyWarning> {
+        let lint = diagnostic["code"]["code"].as_str()?;
+        // hard errors like `E0308` are not lints, they mean the crate does not build
+        if lint.starts_with('E') && lint[1..].chars().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+        let span = diagnostic["spans"]
+            .as_array()?
+            .iter()
+            .find(|span| span["is_primary"] == true)?;
+
+        let file = span["file_name"].as_str()?;
+        let file = Path::new(file)
+            .strip_prefix(dir.canonicalize().ok()?)
+            .map(|path| path.to_string_lossy().replace('\\', "/"))
+            .unwrap_or_else(|_| file.to_owned());
+
+        Some(ClippyWarning {
+            krate: self.name.clone(),
+            version: self.version.clone(),
+            file,
+            line: span["line_start"].as_u64()?,
+            column: span["column_start"].as_u64()?,
+            lint: lint.trim_left_matches("clippy::").to_owned(),
+            message: diagnostic["message"].as_str()?.to_owned(),
+        })
+    }
+}
@@ -0,0 +1,113 @@
+//! Runs the locally built Clippy over a list of crates.io crates and writes a report of all the
+//! warnings, so that the effect of lint changes on real code can be checked before merging them.
+
+use clap::{App, Arg};
+use std::path::Path;
+use std::process;
+
+mod krate;
+mod report;
+
+use crate::report::{Diff, Report};
+
+const CARGO_CLIPPY: &str = "target/debug/cargo-clippy";
+
+fn main() {
+    let matches = App::new("lintcheck")
+        .about("Run Clippy over popular crates and compare the warnings of two runs")
+        .arg(
+            Arg::with_name("crates-toml")
+                .long("crates-toml")
+                .help("The list of crates to check")
+                .takes_value(true)
+                .default_value("lintcheck/lintcheck_crates.toml"),
+        )
+        .arg(
+            Arg::with_name("output")
+                .short("o")
+                .long("output")
+                .help("Where to write the report")
+                .takes_value(true)
+                .default_value("target/lintcheck/report.json"),
+        )
+        .arg(
+            Arg::with_name("diff")
+                .long("diff")
+                .help("Compare two reports instead of running Clippy")
+                .takes_value(true)
+                .number_of_values(2)
+                .value_names(&["OLD", "NEW"]),
+        )
+        .get_matches();
+
+    let result = if let Some(mut reports) = matches.values_of("diff") {
+        let old = reports.next().expect("`diff` takes two values");
+        let new = reports.next().expect("`diff` takes two values");
+        diff(Path::new(old), Path::new(new))
+    } else {
+        run(
+            Path::new(matches.value_of("crates-toml").expect("has a default value")),
+            Path::new(matches.value_of("output").expect("has a default value")),
+        )
+    };
+
+    match result {
+        Ok(true) => {},
+        Ok(false) => process::exit(1),
+        Err(err) => {
+            eprintln!("error: {}", err);
+            process::exit(2);
+        },
+    }
+}
+
+/// Lints all the crates of the list and writes the report. Crates that cannot be downloaded or
+/// linted are skipped, so that a single missing crate does not waste a whole run.
+fn run(crates_toml: &Path, output: &Path) -> std::io::Result<bool> {
+    let cargo_clippy = Path::new(CARGO_CLIPPY);
+    if !cargo_clippy.is_file() {
+        eprintln!("error: `{}` not found, run `cargo build` first", CARGO_CLIPPY);
+        return Ok(false);
+    }
+    let cargo_clippy = cargo_clippy.canonicalize()?;
+
+    let mut warnings = Vec::new();
+    for krate in krate::read_crate_list(crates_toml)? {
+        match krate
+            .download_and_extract()
+            .and_then(|dir| krate.run_clippy(&dir, &cargo_clippy))
+        {
+            Ok(krate_warnings) => warnings.extend(krate_warnings),
+            Err(err) => eprintln!("warning: skipping {} {}: {}", krate.name, krate.version, err),
+        }
+    }
+
+    let report = Report::new(warnings);
+    report.write(output)?;
+
+    println!();
+    for (lint, count) in report.lint_counts() {
+        println!("{:>6} {}", count, lint);
+    }
+    println!("{} warnings written to `{}`", report.warnings.len(), output.display());
+
+    Ok(true)
+}
+
+/// Prints the warnings that differ between two reports. Fails if there is any, so that it can be
+/// used in scripts.
+fn diff(old: &Path, new: &Path) -> std::io::Result<bool> {
+    let old = Report::read(old)?;
+    let new = Report::read(new)?;
+    let diff = Diff::new(&old, &new);
+
+    for warning in &diff.added {
+        println!("+ {}", warning);
+    }
+    for warning in &diff.removed {
+        println!("- {}", warning);
+    }
+    println!("{} added, {} removed", diff.added.len(), diff.removed.len());
+
+    Ok(diff.is_empty())
+}
@@ -0,0 +1,164 @@
+//! The report of a lintcheck run and the comparison of two reports.
+
+use serde_derive::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::path::Path;
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct ClippyWarning {
+    pub krate: String,
+    pub version: String,
+    pub file: String,
+    pub line: u64,
+    pub column: u64,
+    pub lint: String,
+    pub message: String,
+}
+
+impl fmt::Display for ClippyWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}-{}/{}:{}:{} {} \"{}\"",
+            self.krate, self.version, self.file, self.line, self.column, self.lint, self.message
+        )
+    }
+}
+
+/// The warnings of a run, sorted so that reports of two runs can be diffed by any tool.
+#[derive(Default, Serialize, Deserialize)]
+pub struct Report {
+    pub warnings: Vec<ClippyWarning>,
+}
+
+impl Report {
+    pub fn new(mut warnings: Vec<ClippyWarning>) -> Self {
+        warnings.sort();
+        Self { warnings }
+    }
+
+    pub fn read(path: &Path) -> io::Result<Self> {
+        serde_json::from_str(&fs::read_to_string(path)?).map_err(|err| {
+            io::Error::new(
+                ErrorKind::InvalidData,
+                format!("could not parse the report `{}`: {}", path.display(), err),
+            )
+        })
+    }
+
+    pub fn write(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let json = serde_json::to_string_pretty(self).expect("a report can always be serialized");
+        fs::write(path, json)
+    }
+
+    /// The number of warnings of each lint, most frequent first.
+    pub fn lint_counts(&self) -> Vec<(&str, usize)> {
+        let mut counts = BTreeMap::new();
+        for warning in &self.warnings {
+            *counts.entry(warning.lint.as_str()).or_insert(0) += 1;
+        }
+
+        let mut counts: Vec<_> = counts.into_iter().collect();
+        counts.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+        counts
+    }
+}
+
+/// The warnings that only appear in one of two reports.
+pub struct Diff<'a> {
+    /// Warnings of the new report only, probably new false positives if the change to the lints
+    /// was not meant to make them appear.
+    pub added: Vec<&'a ClippyWarning>,
+    /// Warnings of the old report only, probably new false negatives if the change to the lints
+    /// was not meant to fix them.
+    pub removed: Vec<&'a ClippyWarning>,
+}
+
+impl<'a> Diff<'a> {
+    /// Compares two reports. The line and column of a warning are ignored, so that a lint change
+    /// moving the span of a warning around does not show it as both added and removed; a warning
+    /// whose message changed does.
+    pub fn new(old: &'a Report, new: &'a Report) -> Self {
+        let key = |warning: &'a ClippyWarning| {
+            (
+                &warning.krate,
+                &warning.version,
+                &warning.file,
+                &warning.lint,
+                &warning.message,
+            )
+        };
+        let multiset = |report: &'a Report| {
+            let mut set = BTreeMap::new();
+            for warning in &report.warnings {
+                set.entry(key(warning)).or_insert_with(Vec::new).push(warning);
+            }
+            set
+        };
+        let old_set = multiset(old);
+        let new_set = multiset(new);
+
+        let keys: BTreeSet<_> = old_set.keys().chain(new_set.keys()).collect();
+        let mut added = Vec::new();
+        let mut removed = Vec::new();
+        for key in keys {
+            let old_warnings = old_set.get(key).map_or(&[][..], |warnings| &warnings[..]);
+            let new_warnings = new_set.get(key).map_or(&[][..], |warnings| &warnings[..]);
+            // with several identical warnings in a file, the extra ones are the last ones
+            if new_warnings.len() > old_warnings.len() {
+                added.extend(&new_warnings[old_warnings.len()..]);
+            } else {
+                removed.extend(&old_warnings[new_warnings.len()..]);
+            }
+        }
+
+        Self { added, removed }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn warning(lint: &str, line: u64) -> ClippyWarning {
+        ClippyWarning {
+            krate: "foo".to_owned(),
+            version: "1.0.0".to_owned(),
+            file: "src/lib.rs".to_owned(),
+            line,
+            column: 5,
+            lint: lint.to_owned(),
+            message: "message".to_owned(),
+        }
+    }
+
+    #[test]
+    fn test_diff() {
+        let old = Report::new(vec![warning("needless_return", 3), warning("len_zero", 10)]);
+        let new = Report::new(vec![
+            warning("needless_return", 4),
+            warning("needless_return", 20),
+            warning("new_lint", 7),
+        ]);
+        let diff = Diff::new(&old, &new);
+
+        assert_eq!(diff.added, vec![&warning("needless_return", 20), &warning("new_lint", 7)]);
+        assert_eq!(diff.removed, vec![&warning("len_zero", 10)]);
+    }
+
+    #[test]
+    fn test_lint_counts() {
+        let report = Report::new(vec![warning("b", 1), warning("a", 2), warning("b", 3)]);
+        assert_eq!(report.lint_counts(), vec![("b", 2), ("a", 1)]);
+    }
+}
@@ -0,0 +1,356 @@
+//! Baselines: a baseline file records the lint findings of a code base at some point, later runs
+//! only report the findings that are not in the baseline. This allows turning Clippy on for
+//! large legacy code bases without fixing thousands of pre-existing warnings first.
+//!
+//! A finding is identified by its fingerprint, see `fingerprint`.
+
+use crate::messages::{is_error, is_lint};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::env;
+use std::ffi::OsStr;
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::path::Path;
+use std::process::Command;
+
+const HEADER: &str = "# Clippy baseline, regenerate it with `cargo clippy --baseline=<file> --update-baseline`";
+
+/// Records the findings if there is no baseline at `path` yet or `update` is set, otherwise
+/// removes the findings that are in the baseline from `diagnostics`.
+///
+/// Returns whether the run has to be considered successful: a build that only failed because of
+/// lint errors that are all in the baseline is.
+pub fn apply(path: &Path, update: bool, diagnostics: &mut Vec<Value>, success: bool) -> bool {
+    let lint_errors = count_lint_errors(diagnostics);
+
+    let result = if records(path, update) {
+        record(path, diagnostics)
+    } else {
+        suppress(path, diagnostics)
+    };
+    if let Err(err) = result {
+        eprintln!("error: could not use the baseline `{}`: {}", path.display(), err);
+        return false;
+    }
+
+    if success || count_lint_errors(diagnostics) == lint_errors {
+        return success;
+    }
+
+    if diagnostics.iter().any(|diagnostic| is_error(diagnostic) && !is_abort_message(diagnostic)) {
+        false
+    } else {
+        // all the errors were suppressed, “aborting due to N previous errors” would be confusing
+        diagnostics.retain(|diagnostic| !is_abort_message(diagnostic));
+        true
+    }
+}
+
+/// Returns true if running with the baseline at `path` records the findings.
+pub fn records(path: &Path, update: bool) -> bool {
+    update || !path.exists()
+}
+
+/// Removes the build artifacts of the packages of the workspace, their dependencies are kept.
+/// cargo doesn't replay the warnings of the crates that are already built, e.g. by a previous
+/// `cargo clippy`, so they have to be checked again before recording their findings or printing
+/// them as a SARIF log.
+pub fn clean_workspace(manifest_path: Option<&str>, target_dir: Option<&OsStr>) -> io::Result<()> {
+    let cargo = |subcommand: &str| {
+        let mut command = Command::new("cargo");
+        command.arg(subcommand);
+        if let Some(manifest_path) = manifest_path {
+            command.args(&["--manifest-path", manifest_path]);
+        }
+        if let Some(target_dir) = target_dir {
+            command.env("CARGO_TARGET_DIR", target_dir);
+        }
+        command
+    };
+
+    let output = cargo("metadata").args(&["--no-deps", "--format-version", "1"]).output()?;
+    if !output.status.success() {
+        return Err(io::Error::new(
+            ErrorKind::Other,
+            format!("`cargo metadata` failed: {}", String::from_utf8_lossy(&output.stderr)),
+        ));
+    }
+    let metadata: Value = serde_json::from_slice(&output.stdout)?;
+
+    let mut clean = cargo("clean");
+    for package in metadata["packages"].as_array().into_iter().flatten() {
+        if let (Some(name), Some(version)) = (package["name"].as_str(), package["version"].as_str()) {
+            clean.arg("-p").arg(format!("{}:{}", name, version));
+        }
+    }
+    if clean.status()?.success() {
+        Ok(())
+    } else {
+        Err(io::Error::new(ErrorKind::Other, "`cargo clean` failed"))
+    }
+}
+
+/// Writes the fingerprints of all lint findings to `path`, the findings themselves are no
+/// longer reported.
+fn record(path: &Path, diagnostics: &mut Vec<Value>) -> io::Result<()> {
+    let mut fingerprints: Vec<_> = diagnostics
+        .iter()
+        .filter(|diagnostic| is_lint(diagnostic))
+        .filter_map(fingerprint)
+        .collect();
+    fingerprints.sort();
+
+    let mut content = HEADER.to_owned();
+    content.push('\n');
+    for fingerprint in &fingerprints {
+        content.push_str(fingerprint);
+        content.push('\n');
+    }
+    fs::write(path, content)?;
+
+    diagnostics.retain(|diagnostic| !is_lint(diagnostic));
+    eprintln!("recorded {} findings in the baseline `{}`", fingerprints.len(), path.display());
+
+    Ok(())
+}
+
+/// Removes the findings recorded in the baseline at `path` from `diagnostics`. A fingerprint
+/// recorded `n` times suppresses at most `n` findings.
+fn suppress(path: &Path, diagnostics: &mut Vec<Value>) -> io::Result<()> {
+    let mut known = HashMap::new();
+    for line in fs::read_to_string(path)?.lines() {
+        if !line.is_empty() && !line.starts_with('#') {
+            *known.entry(line.to_owned()).or_insert(0) += 1;
+        }
+    }
+
+    let count = diagnostics.len();
+    diagnostics.retain(|diagnostic| {
+        if !is_lint(diagnostic) {
+            return true;
+        }
+        let suppressed = fingerprint(diagnostic).map_or(false, |fingerprint| match known.get_mut(&fingerprint) {
+            Some(remaining) if *remaining > 0 => {
+                *remaining -= 1;
+                true
+            },
+            _ => false,
+        });
+        !suppressed
+    });
+
+    let suppressed = count - diagnostics.len();
+    if suppressed > 0 {
+        eprintln!("{} findings suppressed by the baseline `{}`", suppressed, path.display());
+    }
+
+    Ok(())
+}
+
+/// The fingerprint of a finding is made of the lint name, the path of the file relative to the
+/// current directory and a hash of the code snippet with normalized whitespace. Line numbers are
+/// not part of it, so that unrelated changes to a file do not invalidate its findings.
+fn fingerprint(diagnostic: &Value) -> Option<String> {
+    let lint = diagnostic["code"]["code"].as_str()?;
+    let span = diagnostic["spans"]
+        .as_array()?
+        .iter()
+        .find(|span| span["is_primary"] == true)?;
+    let path = relative_path(span["file_name"].as_str()?);
+
+    Some(format!("{}\t{}\t{:016x}", lint, path, fnv1a(&normalized_snippet(span))))
+}
+
+fn relative_path(file_name: &str) -> String {
+    let path = Path::new(file_name);
+    let path = env::current_dir()
+        .ok()
+        .and_then(|dir| path.strip_prefix(dir).ok().map(Path::to_path_buf))
+        .unwrap_or_else(|| path.to_path_buf());
+
+    // the same baseline must work on all platforms
+    path.to_string_lossy().replace('\\', "/")
+}
+
+/// Returns the highlighted code of a span, all whitespace sequences being collapsed to a single
+/// space.
+fn normalized_snippet(span: &Value) -> String {
+    let mut snippet = String::new();
+    for line in span["text"].as_array().into_iter().flatten() {
+        let text = line["text"].as_str().unwrap_or("");
+        // highlights are 1-based char columns, the end being exclusive
+        let start = line["highlight_start"].as_u64().unwrap_or(1) as usize;
+        let end = line["highlight_end"].as_u64().map_or(usize::max_value(), |end| end as usize);
+        snippet.extend(text.chars().skip(start.saturating_sub(1)).take(end.saturating_sub(start)));
+        snippet.push(' ');
+    }
+
+    snippet.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// 64-bit FNV-1a. `DefaultHasher` is not used because its algorithm may change between Rust
+/// releases, which would invalidate all baselines.
+fn fnv1a(s: &str) -> u64 {
+    s.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+fn count_lint_errors(diagnostics: &[Value]) -> usize {
+    diagnostics
+        .iter()
+        .filter(|diagnostic| is_lint(diagnostic) && is_error(diagnostic))
+        .count()
+}
+
+fn is_abort_message(diagnostic: &Value) -> bool {
+    diagnostic["code"].is_null()
+        && diagnostic["message"]
+            .as_str()
+            .map_or(false, |message| message.starts_with("aborting due to"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::path::PathBuf;
+    use std::process;
+
+    /// A diagnostic of `lint` (or of a hard error like `E0308`) highlighting `snippet` on `line`
+    /// of `src/lib.rs`
+    fn diagnostic(lint: &str, level: &str, line: u64, snippet: &str) -> Value {
+        let file_name = env::current_dir().unwrap().join("src").join("lib.rs");
+        json!({
+            "message": format!("{} finding", lint),
+            "code": { "code": lint },
+            "level": level,
+            "spans": [{
+                "file_name": file_name.to_string_lossy(),
+                "is_primary": true,
+                "line_start": line,
+                "text": [{
+                    "text": format!("    let _ = {};", snippet),
+                    "highlight_start": 13,
+                    "highlight_end": 13 + snippet.chars().count(),
+                }],
+            }],
+            "children": [],
+        })
+    }
+
+    fn abort_message() -> Value {
+        json!({
+            "message": "aborting due to 2 previous errors",
+            "code": null,
+            "level": "error",
+            "spans": [],
+            "children": [],
+        })
+    }
+
+    /// A path in the temporary directory, removed when dropped
+    struct TempFile(PathBuf);
+
+    impl TempFile {
+        fn new(name: &str) -> Self {
+            TempFile(env::temp_dir().join(format!("clippy-baseline-{}-{}", name, process::id())))
+        }
+    }
+
+    impl Drop for TempFile {
+        fn drop(&mut self) {
+            let _ = fs::remove_file(&self.0);
+        }
+    }
+
+    #[test]
+    fn test_fingerprint() {
+        let finding = fingerprint(&diagnostic("len_zero", "warning", 3, "x.len() == 0")).unwrap();
+        assert_eq!(finding, format!("len_zero\tsrc/lib.rs\t{:016x}", fnv1a("x.len() == 0")));
+
+        // the line and the whitespace of the snippet don't matter
+        let moved = diagnostic("len_zero", "warning", 30, "x.len()   ==\t0");
+        assert_eq!(fingerprint(&moved).unwrap(), finding);
+
+        let other_lint = diagnostic("needless_return", "warning", 3, "x.len() == 0");
+        assert_ne!(fingerprint(&other_lint).unwrap(), finding);
+        let other_code = diagnostic("len_zero", "warning", 3, "y.len() == 0");
+        assert_ne!(fingerprint(&other_code).unwrap(), finding);
+    }
+
+    #[test]
+    fn test_record() {
+        let baseline = TempFile::new("record");
+        let mut diagnostics = vec![
+            diagnostic("len_zero", "warning", 3, "x.len() == 0"),
+            diagnostic("E0308", "error", 5, "1u8"),
+            diagnostic("len_zero", "warning", 8, "x.len() == 0"),
+        ];
+        let expected = diagnostics[1].clone();
+
+        assert!(records(&baseline.0, false));
+        assert!(apply(&baseline.0, false, &mut diagnostics, true));
+        // the hard error is still reported
+        assert_eq!(diagnostics, vec![expected]);
+
+        let finding = fingerprint(&diagnostic("len_zero", "warning", 3, "x.len() == 0")).unwrap();
+        let content = fs::read_to_string(&baseline.0).unwrap();
+        assert_eq!(content, format!("{}\n{}\n{}\n", HEADER, finding, finding));
+        assert!(!records(&baseline.0, false));
+        assert!(records(&baseline.0, true));
+    }
+
+    #[test]
+    fn test_suppress() {
+        let baseline = TempFile::new("suppress");
+        let mut recorded = vec![
+            diagnostic("len_zero", "error", 3, "x.len() == 0"),
+            diagnostic("len_zero", "error", 8, "x.len() == 0"),
+            abort_message(),
+        ];
+        apply(&baseline.0, true, &mut recorded, false);
+
+        // a fingerprint recorded twice suppresses two findings
+        let mut diagnostics = vec![
+            diagnostic("len_zero", "error", 4, "x.len() == 0"),
+            diagnostic("len_zero", "error", 9, "x.len() == 0"),
+            diagnostic("len_zero", "error", 12, "x.len() == 0"),
+            abort_message(),
+        ];
+        assert!(!apply(&baseline.0, false, &mut diagnostics, false));
+        assert_eq!(
+            diagnostics,
+            vec![diagnostic("len_zero", "error", 12, "x.len() == 0"), abort_message()]
+        );
+
+        // a build failing only because of lint errors in the baseline succeeds
+        let mut diagnostics = vec![
+            diagnostic("len_zero", "error", 4, "x.len() == 0"),
+            diagnostic("needless_return", "warning", 6, "return 1"),
+            abort_message(),
+        ];
+        assert!(apply(&baseline.0, false, &mut diagnostics, false));
+        assert_eq!(diagnostics, vec![diagnostic("needless_return", "warning", 6, "return 1")]);
+    }
+
+    #[test]
+    fn test_suppress_keeps_hard_errors() {
+        let baseline = TempFile::new("hard_errors");
+        apply(
+            &baseline.0,
+            true,
+            &mut vec![diagnostic("len_zero", "error", 3, "x.len() == 0")],
+            false,
+        );
+
+        let mut diagnostics = vec![
+            diagnostic("len_zero", "error", 3, "x.len() == 0"),
+            diagnostic("E0308", "error", 5, "1u8"),
+            abort_message(),
+        ];
+        assert!(!apply(&baseline.0, false, &mut diagnostics, false));
+        assert_eq!(diagnostics, vec![diagnostic("E0308", "error", 5, "1u8"), abort_message()]);
+    }
+}
@@ -3,8 +3,10 @@
 #![feature(rustc_private)]
 #![allow(unknown_lints, missing_docs_in_private_items)]
 
+use rustc::lint::{EarlyLintPass, LintArray, LintId, LintPass};
 use rustc_driver::{self, driver::CompileController, Compilation};
 use rustc_plugin;
+use std::collections::HashMap;
 use std::process::{exit, Command};
 
 #[allow(print_stdout)]
@@ -12,6 +14,121 @@ fn show_version() {
     println!(env!("CARGO_PKG_VERSION"));
 }
 
+/// Prints the documentation of the lint `name` for `--explain`, returns the exit code.
+#[allow(print_stdout)]
+fn explain(name: &str) -> i32 {
+    match clippy_lints::explain::explain(&normalize_lint_name(name)) {
+        Some(explanation) => {
+            print!("{}", explanation);
+            0
+        },
+        None => {
+            eprintln!("error: `{}` is not a Clippy lint", name);
+            1
+        },
+    }
+}
+
+/// Returns the value of `--explain` if it is not a rustc error code like `E0308`, which rustc
+/// explains itself.
+fn explained_lint(args: &[String]) -> Option<&str> {
+    let pos = args
+        .iter()
+        .position(|arg| arg == "--explain" || arg.starts_with("--explain="))?;
+    let name = if args[pos] == "--explain" {
+        args.get(pos + 1)?.as_str()
+    } else {
+        &args[pos]["--explain=".len()..]
+    };
+    let is_error_code = name.len() == 5 && name.starts_with('E') && name[1..].chars().all(|c| c.is_ascii_digit());
+    if is_error_code {
+        None
+    } else {
+        Some(name)
+    }
+}
+
+/// Normalizes a lint name given on the command line, so that `clippy::foo_bar`,
+/// `foo-bar` and `foo_bar` all refer to the same lint.
+fn normalize_lint_name(name: &str) -> String {
+    let name = name.trim();
+    let name = if name.starts_with("clippy::") {
+        &name["clippy::".len()..]
+    } else {
+        name
+    };
+    name.replace('-', "_").to_lowercase()
+}
+
+/// Removes all `--only=lint[,lint]` flags from `args` (rustc does not know about them) and
+/// returns the selected lints, if any.
+fn extract_only_lints(args: &mut Vec<String>) -> Option<Vec<String>> {
+    let mut only = None;
+    args.retain(|arg| {
+        if arg.starts_with("--only=") {
+            only.get_or_insert_with(Vec::new).extend(
+                arg["--only=".len()..]
+                    .split(',')
+                    .filter(|lint| !lint.trim().is_empty())
+                    .map(normalize_lint_name),
+            );
+            false
+        } else {
+            true
+        }
+    });
+    only
+}
+
+/// Replaces the names of the lint groups in `only`, e.g. `clippy_pedantic`, by the names of their
+/// lints.
+fn expand_lint_groups(only: &[String], groups: &HashMap<&'static str, Vec<LintId>>) -> Vec<String> {
+    only.iter()
+        .flat_map(|name| match groups.get(name.as_str()) {
+            Some(lints) => lints.iter().map(LintId::to_string).collect(),
+            None => vec![name.clone()],
+        })
+        .collect()
+}
+
+/// Removes the `--suggestion-stats` flag from `args` (rustc does not know about it) and returns
+/// whether it was present.
+fn extract_suggestion_stats(args: &mut Vec<String>) -> bool {
+    let len = args.len();
+    args.retain(|arg| arg != "--suggestion-stats");
+    args.len() != len
+}
+
+/// Prints how many diagnostics of each lint have a suggestion, by applicability, for
+/// `--suggestion-stats`.
+fn print_suggestion_stats() {
+    let stats = clippy_lints::utils::suggestion_stats::take();
+    if stats.is_empty() {
+        eprintln!("no Clippy lint was emitted");
+    } else {
+        eprint!("{}", clippy_lints::utils::suggestion_stats::render(&stats));
+    }
+}
+
+/// Returns true if a pass emitting `lints` has to be registered, i.e. there is no `--only`
+/// restriction or the pass emits at least one of the selected lints.
+fn is_pass_needed(lints: &LintArray, only: &Option<Vec<String>>) -> bool {
+    only.as_ref()
+        .map_or(true, |only| lints.iter().any(|lint| only.contains(&lint.name_lower())))
+}
+
+/// Pass that does nothing but register the lints of the passes skipped by `--only`, so that
+/// attributes mentioning them do not trigger `unknown_lints`.
+struct SkippedLints(LintArray);
+
+impl LintPass for SkippedLints {
+    fn get_lints(&self) -> LintArray {
+        self.0.clone()
+    }
+}
+
+impl EarlyLintPass for SkippedLints {}
+
 pub fn main() {
     exit(rustc_driver::run(move || {
         use std::env;
@@ -21,6 +138,11 @@ pub fn main() {
             exit(0);
         }
 
+        let args: Vec<String> = env::args().collect();
+        if let Some(name) = explained_lint(&args) {
+            exit(explain(name));
+        }
+
         let sys_root = option_env!("SYSROOT")
             .map(String::from)
             .or_else(|| std::env::var("SYSROOT").ok())
@@ -82,6 +204,19 @@ pub fn main() {
             }
         }
 
+        // `--only` runs allow every warn-by-default Clippy lint and only warn about the selected
+        // ones. The passes not emitting any of the selected lints are not even registered below.
+        let only = extract_only_lints(&mut args);
+        let suggestion_stats = extract_suggestion_stats(&mut args) && clippy_enabled;
+        if clippy_enabled {
+            if let Some(ref only) = only {
+                args.extend_from_slice(&["-A".to_owned(), "clippy".to_owned()]);
+                for lint in only {
+                    args.extend_from_slice(&["-W".to_owned(), lint.clone()]);
+                }
+            }
+        }
+
         let mut controller = CompileController::basic();
         if clippy_enabled {
             controller.after_parse.callback = Box::new(move |state| {
@@ -111,11 +246,26 @@ pub fn main() {
                 } = registry;
                 let sess = &state.session;
                 let mut ls = sess.lint_store.borrow_mut();
+                let only = only.as_ref().map(|only| expand_lint_groups(only, &lint_groups));
+                let mut skipped_lints = Vec::new();
                 for pass in early_lint_passes {
-                    ls.register_early_pass(Some(sess), true, pass);
+                    let lints = pass.get_lints();
+                    if is_pass_needed(&lints, &only) {
+                        ls.register_early_pass(Some(sess), true, pass);
+                    } else {
+                        skipped_lints.extend(lints);
+                    }
                 }
                 for pass in late_lint_passes {
-                    ls.register_late_pass(Some(sess), true, pass);
+                    let lints = pass.get_lints();
+                    if is_pass_needed(&lints, &only) {
+                        ls.register_late_pass(Some(sess), true, pass);
+                    } else {
+                        skipped_lints.extend(lints);
+                    }
+                }
+                if !skipped_lints.is_empty() {
+                    ls.register_early_pass(Some(sess), true, box SkippedLints(skipped_lints));
                 }
 
                 for (name, to) in lint_groups {
@@ -123,6 +273,32 @@ pub fn main() {
                 }
                 clippy_lints::register_pre_expansion_lints(sess, &mut ls, &conf);
 
+                // `cargo clippy --message-format=sarif` needs the metadata of all the lints to
+                // describe the rules of the report
+                if let Ok(path) = env::var("CLIPPY_SARIF_RULES") {
+                    let rules: Vec<_> = ls
+                        .get_lints()
+                        .iter()
+                        .map(|&(lint, from_plugin)| {
+                            serde_json::json!({
+                                "name": lint.name_lower(),
+                                "desc": lint.desc,
+                                "level": lint.default_level.as_str(),
+                                "clippy": from_plugin,
+                            })
+                        })
+                        .collect();
+                    // cargo runs several clippy-driver processes at once: the list is written to
+                    // a file of this process, which then atomically replaces the shared one
+                    let tmp_path = format!("{}.{}", path, std::process::id());
+                    let written = std::fs::write(&tmp_path, serde_json::Value::Array(rules).to_string())
+                        .and_then(|()| std::fs::rename(&tmp_path, &path));
+                    if let Err(err) = written {
+                        let _ = std::fs::remove_file(&tmp_path);
+                        sess.warn(&format!("could not write the list of lints to `{}`: {}", path, err));
+                    }
+                }
+
                 sess.plugin_llvm_passes.borrow_mut().extend(llvm_passes);
                 sess.plugin_attributes.borrow_mut().extend(attributes);
             });
@@ -130,6 +306,10 @@ pub fn main() {
         controller.compilation_done.stop = Compilation::Stop;
 
         let args = args;
-        rustc_driver::run_compiler(&args, Box::new(controller), None, None)
+        let result = rustc_driver::run_compiler(&args, Box::new(controller), None, None);
+        if suggestion_stats {
+            print_suggestion_stats();
+        }
+        result
     }) as i32)
 }
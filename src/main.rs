@@ -3,6 +3,10 @@
 #![feature(rustc_private)]
 #![allow(unknown_lints, missing_docs_in_private_items)]
 
+mod baseline;
+mod messages;
+mod sarif;
+
 const CARGO_CLIPPY_HELP: &str = r#"Checks a package to catch common mistakes and improve your Rust code.
 
 Usage:
@@ -11,6 +15,14 @@ Usage:
 Common options:
     -h, --help               Print this message
     -V, --version            Print version info and exit
+    --explain LINT           Print the documentation of a lint and exit
+    --only=LINT[,LINT...]    Only run the given lints, all other lint passes are skipped
+    --message-format=sarif   Print the diagnostics as a SARIF log on stdout
+    --baseline=FILE          Only report the findings not recorded in FILE, record all the
+                             findings in FILE if it doesn't exist yet
+    --update-baseline        Record all the findings in the baseline FILE again
+    --suggestion-stats       Print how many findings of each lint have a suggestion, by
+                             applicability, after checking each crate
 
 Other options are the same as `cargo check`.
 
@@ -60,17 +72,38 @@ where
 {
     let mut args = vec!["check".to_owned()];
 
-    let mut found_dashes = false;
-    for arg in old_args.by_ref() {
-        found_dashes |= arg == "--";
-        if found_dashes {
+    // `--only` and `--suggestion-stats` are not cargo flags, they are forwarded to clippy-driver
+    // together with the arguments following `--`
+    let mut driver_args = Vec::new();
+    let mut baseline = None;
+    let mut update_baseline = false;
+    let mut explain = None;
+
+    while let Some(arg) = old_args.next() {
+        if arg == "--" {
             break;
         }
-        args.push(arg);
+        if arg.starts_with("--only=") || arg == "--suggestion-stats" {
+            driver_args.push(arg);
+        } else if arg.starts_with("--baseline=") {
+            baseline = Some(std::path::PathBuf::from(&arg["--baseline=".len()..]));
+        } else if arg == "--baseline" {
+            baseline = old_args.next().map(std::path::PathBuf::from);
+        } else if arg == "--update-baseline" {
+            update_baseline = true;
+        } else if arg.starts_with("--explain=") {
+            explain = Some(arg["--explain=".len()..].to_owned());
+        } else if arg == "--explain" {
+            explain = old_args.next();
+            if explain.is_none() {
+                eprintln!("error: `--explain` requires the name of a lint");
+                return Err(1);
+            }
+        } else {
+            args.push(arg);
+        }
     }
 
-    let clippy_args: String = old_args.map(|arg| format!("{}__CLIPPY_HACKERY__", arg)).collect();
-
     let mut path = std::env::current_exe()
         .expect("current executable path invalid")
         .with_file_name("clippy-driver");
@@ -78,6 +111,37 @@ where
         path.set_extension("exe");
     }
 
+    // the documentation of the lints is only known by clippy-driver
+    if let Some(lint) = explain {
+        let exit_status = std::process::Command::new(path)
+            .arg("--explain")
+            .arg(lint)
+            .status()
+            .expect("could not run clippy-driver");
+        return if exit_status.success() {
+            Ok(())
+        } else {
+            Err(exit_status.code().unwrap_or(-1))
+        };
+    }
+
+    if update_baseline && baseline.is_none() {
+        eprintln!("error: `--update-baseline` requires `--baseline=FILE`");
+        return Err(1);
+    }
+
+    let sarif = use_sarif_format(&mut args);
+    if baseline.is_some() && !sarif {
+        // the findings are filtered before being printed
+        args.push("--message-format=json".to_owned());
+    }
+
+    let clippy_args: String = driver_args
+        .into_iter()
+        .chain(old_args)
+        .map(|arg| format!("{}__CLIPPY_HACKERY__", arg))
+        .collect();
+
     let target_dir = std::env::var_os("CLIPPY_DOGFOOD")
         .map(|_| {
             std::env::var_os("CARGO_MANIFEST_DIR").map_or_else(
@@ -96,19 +160,90 @@ where
         })
         .map(|p| ("CARGO_TARGET_DIR", p));
 
-    let exit_status = std::process::Command::new("cargo")
+    // the SARIF log and the recorded baseline need the findings of every crate of the workspace
+    let records_baseline = baseline
+        .as_ref()
+        .map_or(false, |baseline| baseline::records(baseline, update_baseline));
+    if sarif || records_baseline {
+        let target_dir = target_dir.as_ref().map(|&(_, ref dir)| dir.as_os_str());
+        if let Err(err) = baseline::clean_workspace(manifest_path(&args), target_dir) {
+            eprintln!("error: could not check the workspace again to collect its findings: {}", err);
+            return Err(1);
+        }
+    }
+
+    let mut command = std::process::Command::new("cargo");
+    command
         .args(&args)
         .env("RUSTC_WRAPPER", path)
         .env("CLIPPY_ARGS", clippy_args)
-        .envs(target_dir)
-        .spawn()
-        .expect("could not run cargo")
-        .wait()
-        .expect("failed to wait for cargo?");
+        .envs(target_dir);
+
+    if !sarif && baseline.is_none() {
+        let exit_status = command
+            .spawn()
+            .expect("could not run cargo")
+            .wait()
+            .expect("failed to wait for cargo?");
+
+        return if exit_status.success() {
+            Ok(())
+        } else {
+            Err(exit_status.code().unwrap_or(-1))
+        };
+    }
 
-    if exit_status.success() {
+    let rules_file = sarif::rules_file();
+    if sarif {
+        command.env("CLIPPY_SARIF_RULES", &rules_file);
+    }
+
+    let (exit_status, mut diagnostics) = messages::run(command);
+
+    let success = match baseline {
+        Some(ref path) => baseline::apply(path, update_baseline, &mut diagnostics, exit_status.success()),
+        None => exit_status.success(),
+    };
+
+    if sarif {
+        sarif::print(&rules_file, &diagnostics);
+    } else {
+        messages::print_rendered(&diagnostics);
+    }
+
+    if success {
         Ok(())
     } else {
-        Err(exit_status.code().unwrap_or(-1))
+        Err(exit_status.code().filter(|&code| code != 0).unwrap_or(1))
+    }
+}
+
+/// Returns the value of the `--manifest-path` argument of cargo.
+fn manifest_path(args: &[String]) -> Option<&str> {
+    args.iter().enumerate().find_map(|(i, arg)| {
+        if arg.starts_with("--manifest-path=") {
+            Some(&arg["--manifest-path=".len()..])
+        } else if arg == "--manifest-path" {
+            args.get(i + 1).map(String::as_str)
+        } else {
+            None
+        }
+    })
+}
+
+/// Replaces `--message-format=sarif` (which cargo does not know about) by
+/// `--message-format=json`, whose output is then converted by the `sarif` module. Returns
+/// whether the flag was present.
+fn use_sarif_format(args: &mut [String]) -> bool {
+    let mut sarif = false;
+    for i in 0..args.len() {
+        if args[i] == "--message-format=sarif" {
+            args[i] = "--message-format=json".to_owned();
+            sarif = true;
+        } else if args[i] == "sarif" && i > 0 && args[i - 1] == "--message-format" {
+            args[i] = "json".to_owned();
+            sarif = true;
+        }
     }
+    sarif
 }
@@ -0,0 +1,51 @@
+//! Collection of the diagnostics emitted by `cargo check --message-format=json`, for the output
+//! modes that need to post-process them (SARIF output, baselines).
+
+use serde_json::Value;
+use std::io::{BufRead, BufReader};
+use std::process::{Command, ExitStatus, Stdio};
+
+/// Runs `cargo` (which must have been told to use `--message-format=json`) and returns the
+/// diagnostics of all compiler messages.
+pub fn run(mut command: Command) -> (ExitStatus, Vec<Value>) {
+    let mut child = command
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("could not run cargo");
+
+    let mut diagnostics = Vec::new();
+    let stdout = child.stdout.take().expect("cargo's stdout is piped");
+    for line in BufReader::new(stdout).lines() {
+        let line = line.expect("could not read cargo's output");
+        if let Ok(message) = serde_json::from_str::<Value>(&line) {
+            if message["reason"] == "compiler-message" {
+                diagnostics.push(message["message"].clone());
+            }
+        }
+    }
+
+    (child.wait().expect("failed to wait for cargo?"), diagnostics)
+}
+
+/// Prints the diagnostics the way rustc would have, on stderr.
+pub fn print_rendered(diagnostics: &[Value]) {
+    for diagnostic in diagnostics {
+        if let Some(rendered) = diagnostic["rendered"].as_str() {
+            eprint!("{}", rendered);
+        }
+    }
+}
+
+/// Returns true if the diagnostic is a lint, as opposed to a hard error like `E0308`: lints are
+/// the only diagnostics that can be suppressed.
+pub fn is_lint(diagnostic: &Value) -> bool {
+    match diagnostic["code"]["code"].as_str() {
+        Some(code) => !(code.len() == 5 && code.starts_with('E') && code[1..].chars().all(|c| c.is_ascii_digit())),
+        None => false,
+    }
+}
+
+/// Returns true if the diagnostic is an error.
+pub fn is_error(diagnostic: &Value) -> bool {
+    diagnostic["level"].as_str().map_or(false, |level| level.starts_with("error"))
+}
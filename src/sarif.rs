@@ -0,0 +1,317 @@
+//! Conversion of the JSON diagnostics emitted by `cargo check --message-format=json` to a
+//! [SARIF 2.1](https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html) log, so that
+//! code scanning services can ingest Clippy's results.
+
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+
+const SARIF_SCHEMA: &str = "https://schemastore.azurewebsites.net/schemas/json/sarif-2.1.0-rtm.5.json";
+
+/// Path of the file clippy-driver writes the metadata of all lints to when asked to through
+/// the `CLIPPY_SARIF_RULES` environment variable.
+pub fn rules_file() -> PathBuf {
+    env::temp_dir().join(format!("clippy-sarif-rules-{}.json", process::id()))
+}
+
+/// Prints the diagnostics as a SARIF log on stdout. The lint metadata is read from (and then
+/// removes) `rules_file`.
+#[allow(print_stdout)]
+pub fn print(rules_file: &Path, diagnostics: &[Value]) {
+    // the rules are missing if no crate had to be checked by clippy-driver
+    let rules = fs::read_to_string(rules_file)
+        .ok()
+        .and_then(|rules| serde_json::from_str::<Vec<Value>>(&rules).ok())
+        .unwrap_or_default();
+    let _ = fs::remove_file(rules_file);
+
+    let log = to_sarif(&rules, diagnostics);
+    println!("{}", serde_json::to_string_pretty(&log).expect("a `Value` can always be serialized"));
+}
+
+/// Build the SARIF log from the lint metadata written by clippy-driver and the rustc
+/// diagnostics.
+pub fn to_sarif(rules: &[Value], diagnostics: &[Value]) -> Value {
+    let mut sarif_rules = Vec::new();
+    let mut rule_indices = HashMap::new();
+
+    let mut results = Vec::new();
+    for diagnostic in diagnostics {
+        // diagnostics without a code (e.g. “aborting due to previous error”) are not results
+        let rule_id = match diagnostic["code"]["code"].as_str() {
+            Some(code) => code.to_owned(),
+            None => continue,
+        };
+
+        let index = *rule_indices.entry(rule_id.clone()).or_insert_with(|| {
+            let rule = rules.iter().find(|rule| rule["name"] == rule_id.as_str());
+            sarif_rules.push(to_rule(&rule_id, rule));
+            sarif_rules.len() - 1
+        });
+
+        results.push(to_result(&rule_id, index, diagnostic));
+    }
+
+    json!({
+        "$schema": SARIF_SCHEMA,
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "clippy",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "informationUri": "https://github.com/rust-lang-nursery/rust-clippy",
+                    "rules": sarif_rules,
+                },
+            },
+            "results": results,
+        }],
+    })
+}
+
+fn to_rule(rule_id: &str, rule: Option<&Value>) -> Value {
+    let mut sarif_rule = json!({ "id": rule_id });
+
+    if let Some(rule) = rule {
+        sarif_rule["shortDescription"] = json!({ "text": rule["desc"] });
+        sarif_rule["defaultConfiguration"] = json!({ "level": to_level(rule["level"].as_str().unwrap_or("warn")) });
+        if rule["clippy"] == true {
+            sarif_rule["helpUri"] = json!(format!(
+                "https://rust-lang-nursery.github.io/rust-clippy/v{}/index.html#{}",
+                env!("CARGO_PKG_VERSION"),
+                rule_id
+            ));
+        }
+    }
+
+    sarif_rule
+}
+
+fn to_result(rule_id: &str, rule_index: usize, diagnostic: &Value) -> Value {
+    let locations: Vec<_> = spans(diagnostic)
+        .filter(|span| span["is_primary"] == true)
+        .map(|span| json!({ "physicalLocation": to_physical_location(span) }))
+        .collect();
+
+    let fixes: Vec<_> = diagnostic["children"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(to_fix)
+        .collect();
+
+    json!({
+        "ruleId": rule_id,
+        "ruleIndex": rule_index,
+        "level": to_level(diagnostic["level"].as_str().unwrap_or("warning")),
+        "message": { "text": diagnostic["message"] },
+        "locations": locations,
+        "fixes": fixes,
+    })
+}
+
+/// Converts the suggestions of a child diagnostic (a `help: try` message) to a SARIF fix.
+fn to_fix(child: &Value) -> Option<Value> {
+    let replacements: Vec<_> = spans(child)
+        .filter(|span| span["suggested_replacement"].is_string())
+        .collect();
+
+    if replacements.is_empty() {
+        return None;
+    }
+
+    // SARIF groups replacements by file
+    let mut changes: Vec<(&Value, Vec<Value>)> = Vec::new();
+    for span in replacements {
+        let replacement = json!({
+            "deletedRegion": to_region(span),
+            "insertedContent": { "text": span["suggested_replacement"] },
+        });
+        match changes.iter_mut().find(|&&mut (file_name, _)| *file_name == span["file_name"]) {
+            Some(&mut (_, ref mut replacements)) => replacements.push(replacement),
+            None => changes.push((&span["file_name"], vec![replacement])),
+        }
+    }
+
+    Some(json!({
+        "description": { "text": child["message"] },
+        "artifactChanges": changes
+            .into_iter()
+            .map(|(file_name, replacements)| json!({
+                "artifactLocation": { "uri": file_name },
+                "replacements": replacements,
+            }))
+            .collect::<Vec<_>>(),
+    }))
+}
+
+fn spans<'a>(diagnostic: &'a Value) -> impl Iterator<Item = &'a Value> {
+    diagnostic["spans"].as_array().into_iter().flatten()
+}
+
+fn to_physical_location(span: &Value) -> Value {
+    json!({
+        "artifactLocation": { "uri": span["file_name"] },
+        "region": to_region(span),
+    })
+}
+
+fn to_region(span: &Value) -> Value {
+    json!({
+        "startLine": span["line_start"],
+        "startColumn": span["column_start"],
+        "endLine": span["line_end"],
+        "endColumn": span["column_end"],
+    })
+}
+
+/// Converts a rustc level (either a lint level or a diagnostic level) to a SARIF level.
+fn to_level(level: &str) -> &'static str {
+    match level {
+        "deny" | "forbid" | "error" | "error: internal compiler error" => "error",
+        "warn" | "warning" => "warning",
+        "allow" => "none",
+        _ => "note",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn span(file_name: &str, line: u64, replacement: Option<&str>) -> Value {
+        json!({
+            "file_name": file_name,
+            "is_primary": true,
+            "line_start": line,
+            "column_start": 5,
+            "line_end": line,
+            "column_end": 17,
+            "suggested_replacement": replacement,
+        })
+    }
+
+    fn diagnostic(code: Option<&str>, level: &str, spans: Vec<Value>, children: Vec<Value>) -> Value {
+        json!({
+            "message": "message",
+            "code": code.map(|code| json!({ "code": code })),
+            "level": level,
+            "spans": spans,
+            "children": children,
+        })
+    }
+
+    fn rules() -> Vec<Value> {
+        vec![
+            json!({ "name": "len_zero", "desc": "checking `.len() == 0`", "level": "warn", "clippy": true }),
+            json!({ "name": "unused_variables", "desc": "detect unused variables", "level": "warn", "clippy": false }),
+        ]
+    }
+
+    #[test]
+    fn test_rules() {
+        let diagnostics = vec![
+            diagnostic(Some("len_zero"), "warning", vec![span("src/lib.rs", 3, None)], vec![]),
+            diagnostic(Some("unused_variables"), "warning", vec![span("src/lib.rs", 4, None)], vec![]),
+            diagnostic(Some("len_zero"), "error", vec![span("src/main.rs", 8, None)], vec![]),
+            diagnostic(Some("unknown_lint"), "warning", vec![span("src/main.rs", 9, None)], vec![]),
+            diagnostic(None, "error", vec![], vec![]),
+        ];
+        let log = to_sarif(&rules(), &diagnostics);
+        let run = &log["runs"][0];
+
+        assert_eq!(log["version"], "2.1.0");
+        assert_eq!(
+            run["tool"]["driver"]["rules"],
+            json!([
+                {
+                    "id": "len_zero",
+                    "shortDescription": { "text": "checking `.len() == 0`" },
+                    "defaultConfiguration": { "level": "warning" },
+                    "helpUri": format!(
+                        "https://rust-lang-nursery.github.io/rust-clippy/v{}/index.html#len_zero",
+                        env!("CARGO_PKG_VERSION")
+                    ),
+                },
+                {
+                    "id": "unused_variables",
+                    "shortDescription": { "text": "detect unused variables" },
+                    "defaultConfiguration": { "level": "warning" },
+                },
+                { "id": "unknown_lint" },
+            ])
+        );
+
+        // the diagnostic without a code is not a result
+        let results = run["results"].as_array().unwrap();
+        let rule_indices: Vec<_> = results.iter().map(|result| result["ruleIndex"].as_u64().unwrap()).collect();
+        assert_eq!(rule_indices, vec![0, 1, 0, 2]);
+        assert_eq!(results[2]["ruleId"], "len_zero");
+        assert_eq!(results[2]["level"], "error");
+        assert_eq!(
+            results[2]["locations"],
+            json!([{
+                "physicalLocation": {
+                    "artifactLocation": { "uri": "src/main.rs" },
+                    "region": { "startLine": 8, "startColumn": 5, "endLine": 8, "endColumn": 17 },
+                },
+            }])
+        );
+    }
+
+    #[test]
+    fn test_fixes() {
+        let help = json!({
+            "message": "try",
+            "spans": [
+                span("src/lib.rs", 3, Some("x.is_empty()")),
+                span("src/main.rs", 5, Some("y")),
+                span("src/lib.rs", 7, Some("z")),
+            ],
+        });
+        let note = json!({ "message": "a note", "spans": [span("src/lib.rs", 3, None)] });
+        let diagnostics = vec![diagnostic(
+            Some("len_zero"),
+            "warning",
+            vec![span("src/lib.rs", 3, None)],
+            vec![note, help],
+        )];
+        let log = to_sarif(&rules(), &diagnostics);
+
+        let region = |line| json!({ "startLine": line, "startColumn": 5, "endLine": line, "endColumn": 17 });
+        // the replacements are grouped by file, the children without suggestion are not fixes
+        assert_eq!(
+            log["runs"][0]["results"][0]["fixes"],
+            json!([{
+                "description": { "text": "try" },
+                "artifactChanges": [
+                    {
+                        "artifactLocation": { "uri": "src/lib.rs" },
+                        "replacements": [
+                            { "deletedRegion": region(3), "insertedContent": { "text": "x.is_empty()" } },
+                            { "deletedRegion": region(7), "insertedContent": { "text": "z" } },
+                        ],
+                    },
+                    {
+                        "artifactLocation": { "uri": "src/main.rs" },
+                        "replacements": [
+                            { "deletedRegion": region(5), "insertedContent": { "text": "y" } },
+                        ],
+                    },
+                ],
+            }])
+        );
+    }
+
+    #[test]
+    fn test_to_level() {
+        assert_eq!(to_level("forbid"), "error");
+        assert_eq!(to_level("error: internal compiler error"), "error");
+        assert_eq!(to_level("warn"), "warning");
+        assert_eq!(to_level("allow"), "none");
+        assert_eq!(to_level("help"), "note");
+    }
+}
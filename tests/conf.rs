@@ -0,0 +1,122 @@
+extern crate clippy_lints;
+
+use clippy_lints::utils::conf::{check_rust_version, lookup_conf_files, read_merged, read_str, Error, MsrvMismatch};
+use clippy_lints::Conf;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+/// A directory removed once the test is done
+struct TempDir(PathBuf);
+
+impl TempDir {
+    fn new(name: &str) -> Self {
+        let dir = env::temp_dir().join(format!("clippy-conf-{}-{}", name, std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        TempDir(dir)
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+#[test]
+fn default() {
+    let conf = Conf::default();
+    assert_eq!(conf.cyclomatic_complexity_threshold, 25);
+    assert!(conf.msrv.is_none());
+}
+
+#[test]
+fn read_valid() {
+    let (conf, errors) = read_str("cyclomatic-complexity-threshold = 10\nmsrv = \"1.30\"");
+    assert!(errors.is_empty());
+    assert_eq!(conf.cyclomatic_complexity_threshold, 10);
+    assert_eq!(conf.msrv, Some("1.30".to_owned()));
+}
+
+#[test]
+fn read_unknown_key() {
+    let (conf, errors) = read_str("no-such-key = 1");
+    assert_eq!(errors.len(), 1);
+    match errors[0] {
+        Error::Toml(_) => {},
+        ref error => panic!("unexpected error: {}", error),
+    }
+    assert_eq!(conf.cyclomatic_complexity_threshold, 25);
+}
+
+#[test]
+fn rust_version() {
+    assert_eq!(check_rust_version(None, None), None);
+    assert_eq!(check_rust_version(Some("1.30"), Some("1.30.0")), None);
+    assert_eq!(
+        check_rust_version(Some("1.30"), None),
+        Some(MsrvMismatch::MissingRustVersion("1.30".to_owned()))
+    );
+    assert_eq!(
+        check_rust_version(None, Some("1.30")),
+        Some(MsrvMismatch::MissingMsrv("1.30".to_owned()))
+    );
+    assert_eq!(
+        check_rust_version(Some("1.30"), Some("1.31")),
+        Some(MsrvMismatch::Different {
+            msrv: "1.30".to_owned(),
+            rust_version: "1.31".to_owned(),
+        })
+    );
+    // the invalid versions are reported elsewhere
+    assert_eq!(check_rust_version(Some("1.x"), None), None);
+    assert_eq!(check_rust_version(Some("1.30"), Some("latest")), None);
+}
+
+#[test]
+fn lookup_parent_and_child() {
+    let parent = TempDir::new("lookup");
+    let child = parent.0.join("member");
+    fs::create_dir_all(&child).unwrap();
+    fs::write(parent.0.join("clippy.toml"), "").unwrap();
+    fs::write(child.join(".clippy.toml"), "").unwrap();
+
+    // the only test of this file reading `CARGO_MANIFEST_DIR`
+    env::set_var("CARGO_MANIFEST_DIR", &child);
+    let files = lookup_conf_files().unwrap();
+    // the directories above the temporary one may have configuration files too
+    assert_eq!(
+        &files[files.len() - 2..],
+        [parent.0.join("clippy.toml"), child.join(".clippy.toml")]
+    );
+}
+
+#[test]
+fn child_overrides_parent() {
+    let dir = TempDir::new("merge");
+    let parent = dir.0.join("parent.toml");
+    let child = dir.0.join("child.toml");
+    fs::write(&parent, "cyclomatic-complexity-threshold = 10\ntoo-many-arguments-threshold = 3").unwrap();
+    fs::write(&child, "cyclomatic-complexity-threshold = 20\nmsrv = \"1.30\"").unwrap();
+
+    let (conf, errors) = read_merged(&[parent, child]);
+    assert!(errors.is_empty());
+    assert_eq!(conf.cyclomatic_complexity_threshold, 20);
+    assert_eq!(conf.too_many_arguments_threshold, 3);
+    assert_eq!(conf.msrv, Some("1.30".to_owned()));
+}
+
+#[test]
+fn merge_with_invalid_child() {
+    let dir = TempDir::new("invalid");
+    let parent = dir.0.join("parent.toml");
+    let child = dir.0.join("child.toml");
+    fs::write(&parent, "cyclomatic-complexity-threshold = 10").unwrap();
+    fs::write(&child, "no-such-key = 1").unwrap();
+
+    // the invalid file is skipped and reported, the others still apply
+    let (conf, errors) = read_merged(&[parent, child.clone()]);
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].0, child);
+    assert_eq!(conf.cyclomatic_complexity_threshold, 10);
+}
@@ -0,0 +1,61 @@
+extern crate clippy_lints;
+
+use clippy_lints::explain::explain;
+
+#[test]
+fn explain_with_configuration() {
+    let expected = "\
+### cyclomatic_complexity
+
+Group: `clippy_complexity`, warn by default, added before Clippy 0.0.213
+
+**What it does:** Checks for methods with high cyclomatic complexity.
+
+**Why is this bad?** Methods of high cyclomatic complexity tend to be badly
+readable. Also LLVM will usually optimize small methods better.
+
+**Known problems:** Sometimes it's hard to find a way to reduce the
+complexity.
+
+**Example:** No. You'll see it when you get the warning.
+
+### Configuration
+
+* `cyclomatic-complexity-threshold`: The maximum cyclomatic complexity a function can have (defaults to `25`)
+
+### Suggestions
+
+This lint doesn't suggest any fix.
+";
+    assert_eq!(explain("cyclomatic_complexity").as_ref().map(String::as_str), Some(expected));
+}
+
+#[test]
+fn explain_version_and_suggestions() {
+    let explanation = explain("almost_complete_range").unwrap();
+    assert!(explanation.starts_with(
+        "### almost_complete_range\n\nGroup: `clippy_correctness`, deny by default, added in Clippy 0.0.213\n\n"
+    ));
+    assert!(explanation.contains("```rust\nlet _ = 'a'..'z';\nlet _ = 'A'..='z';\n```\n"));
+    assert!(!explanation.contains("### Configuration"));
+    assert!(explanation.ends_with(
+        "\n### Suggestions\n\n* `MachineApplicable`: some suggestions can be applied automatically\n"
+    ));
+}
+
+#[test]
+fn explain_unknown_suggestions() {
+    // `drop_ref` is stored in a variable before being emitted, its suggestions are not known
+    let explanation = explain("drop_ref").unwrap();
+    assert!(explanation.ends_with(
+        "\n### Suggestions\n\nThe suggestions of this lint are not known, run `cargo clippy --suggestion-stats` to \
+         count them.\n"
+    ));
+}
+
+#[test]
+fn explain_unknown_lint() {
+    assert_eq!(explain("not_a_lint"), None);
+    // the names are expected in lower case
+    assert_eq!(explain("CYCLOMATIC_COMPLEXITY"), None);
+}
@@ -0,0 +1,31 @@
+extern crate clippy_lints;
+
+use clippy_lints::utils::suggestion_stats::{render, SuggestionStats};
+use std::collections::BTreeMap;
+
+#[test]
+fn render_table() {
+    let mut stats = BTreeMap::new();
+    stats.insert(
+        "needless_return".to_owned(),
+        SuggestionStats {
+            machine_applicable: 3,
+            ..SuggestionStats::default()
+        },
+    );
+    stats.insert(
+        "cyclomatic_complexity".to_owned(),
+        SuggestionStats {
+            without_suggestion: 1,
+            ..SuggestionStats::default()
+        },
+    );
+    assert_eq!(
+        render(&stats),
+        "\
+lint                   total  machine-applicable  maybe-incorrect  has-placeholders  unspecified  no suggestion
+cyclomatic_complexity      1                   0                0                 0            0              1
+needless_return            3                   3                0                 0            0              0
+"
+    );
+}
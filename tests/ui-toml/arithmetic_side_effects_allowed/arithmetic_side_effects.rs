@@ -0,0 +1,29 @@
+#![warn(arithmetic_side_effects)]
+#![allow(unused, no_effect, unnecessary_operation)]
+
+use std::ops::Add;
+
+#[derive(Clone, Copy)]
+struct Meters(u32);
+
+impl Add for Meters {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        Meters(self.0.saturating_add(other.0))
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Feet(u32);
+
+impl Add for Feet {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        Feet(self.0.saturating_add(other.0))
+    }
+}
+
+fn main() {
+    Meters(1) + Meters(2);
+    Feet(1) + Feet(2);
+}
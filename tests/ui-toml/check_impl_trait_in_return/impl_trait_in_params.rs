@@ -0,0 +1,27 @@
+#![warn(impl_trait_in_params)]
+#![allow(dead_code)]
+
+use std::fmt::Debug;
+
+pub fn returns() -> impl Debug {
+    0
+}
+
+pub fn nested() -> Option<impl Iterator<Item = u8>> {
+    None::<std::vec::IntoIter<u8>>
+}
+
+pub struct Public;
+
+impl Public {
+    pub fn method(&self) -> impl Clone {
+        0
+    }
+}
+
+// ok, not exported
+fn private() -> impl Debug {
+    0
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+#![warn(host_endian_bytes, little_endian_bytes, big_endian_bytes)]
+
+fn main() {
+    let _ = 2u32.to_ne_bytes();
+    let _ = 2u32.to_be_bytes();
+    let _ = u32::from_be_bytes([0; 4]);
+
+    // the enforced endianness
+    let _ = 2u32.to_le_bytes();
+    let _ = u32::from_le_bytes([0; 4]);
+}
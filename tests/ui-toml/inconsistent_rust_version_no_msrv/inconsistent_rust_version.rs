@@ -0,0 +1,3 @@
+#![warn(inconsistent_rust_version)]
+
+fn main() {}
@@ -0,0 +1,18 @@
+// compile-flags: --test
+#![warn(indexing_slicing)]
+#![allow(dead_code)]
+
+fn first(v: &[u8]) -> u8 {
+    v[0]
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn first() {
+        let v = vec![1, 2, 3];
+        assert_eq!(v[0], super::first(&v[1..]) - 1);
+    }
+}
+
+fn main() {}
@@ -0,0 +1,3 @@
+#![warn(lint_groups_priority)]
+
+fn main() {}
@@ -0,0 +1,15 @@
+#![warn(mem_forget)]
+#![allow(dead_code)]
+
+use std::fs::File;
+use std::mem;
+
+struct Leaked {
+    v: Vec<u8>,
+}
+
+fn main() {
+    mem::forget(File::open("foo.txt").unwrap());
+    mem::forget(Leaked { v: vec![] });
+    mem::forget(vec![1]);
+}
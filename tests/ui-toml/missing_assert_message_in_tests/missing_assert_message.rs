@@ -0,0 +1,21 @@
+// compile-flags: --test
+#![warn(missing_assert_message)]
+#![allow(dead_code)]
+
+fn assert_with_message() {
+    assert!(true, "oh no");
+}
+
+#[test]
+fn test_assert_without_message() {
+    assert!(true);
+}
+
+#[cfg(test)]
+mod tests {
+    fn helper() {
+        assert_eq!(1, 1);
+    }
+}
+
+fn main() {}
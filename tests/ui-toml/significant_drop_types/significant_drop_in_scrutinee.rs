@@ -0,0 +1,24 @@
+#![warn(significant_drop_in_scrutinee)]
+
+struct Transaction;
+
+impl Transaction {
+    fn id(&self) -> u32 {
+        0
+    }
+}
+
+fn begin() -> Transaction {
+    Transaction
+}
+
+fn main() {
+    match begin().id() {
+        _ => (),
+    }
+
+    // ok, the transaction is the scrutinee itself
+    match begin() {
+        _ => (),
+    }
+}
@@ -0,0 +1,27 @@
+#![warn(almost_complete_range)]
+#![allow(unused)]
+
+const LOWER: char = 'a';
+
+fn main() {
+    let _ = 'a'..'z';
+    let _ = b'A'..b'Z';
+    let _ = '0'..'9';
+    let _ = LOWER..'z';
+    let _ = 'A'..='z';
+
+    match 'x' {
+        'A'..='z' => (),
+        _ => (),
+    }
+    match b'x' {
+        b'A'..=b'z' => (),
+        _ => (),
+    }
+
+    // not linted
+    let _ = 'a'..='z';
+    let _ = 'a'..'y';
+    let _ = 48..57;
+    let _ = b'a'..'z' as u8;
+}
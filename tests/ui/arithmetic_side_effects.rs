@@ -0,0 +1,54 @@
+#![warn(arithmetic_side_effects)]
+#![allow(unused, no_effect, unnecessary_operation, identity_op, erasing_op, eq_op)]
+
+use std::num::Wrapping;
+use std::ops::Add;
+
+#[derive(Clone, Copy)]
+struct Meters(u32);
+
+impl Add for Meters {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        Meters(self.0 + other.0)
+    }
+}
+
+const N: i32 = 10 * 4;
+
+fn main() {
+    let mut a = 1i32;
+    let b = 2u8;
+
+    a + 1;
+    a - b as i32;
+    a * a;
+    a / a;
+    a % a;
+    a << b;
+    -a;
+    a += 1;
+    b * 2 + 1;
+    a / -1;
+    Meters(1) + Meters(2);
+
+    // ok, can't overflow nor panic
+    1 + 2;
+    -1;
+    a + 0;
+    0 + a;
+    a - 0;
+    a * 0;
+    a * 1;
+    a / 2;
+    a % 3;
+    a << 3;
+    b >> 7;
+    b / 7;
+    a *= 1;
+    254u8 + 1;
+    N + 2;
+    1.0f64 + 2.0;
+    Wrapping(a) + Wrapping(1);
+    String::new() + "a";
+}
@@ -0,0 +1,16 @@
+#![warn(as_conversions)]
+
+fn main() {
+    let a = 42u32;
+    let f = 1.5f32;
+
+    let _ = a as u64;
+    let _ = a as u8;
+    let _ = a as f64;
+    let _ = f as f64;
+    let _ = f as i32;
+    let _ = 1.5f64 as f32;
+    let _ = true as u8;
+    let _ = 'a' as u32;
+    let _ = &a as *const u32 as *const u8;
+}
@@ -0,0 +1,33 @@
+#![warn(bool_to_int_with_if)]
+#![allow(unused)]
+
+const ONE: u8 = 1;
+
+fn main() {
+    let a = true;
+    let b = false;
+
+    let x = if a { 1 } else { 0 };
+    let x: u8 = if a && b { 1 } else { 0 };
+    let x: usize = if a {
+        1
+    } else {
+        0
+    };
+    let x: i64 = if !a { 0 } else { 1 };
+    let x: u16 = if a || b { 0 } else { 1 };
+
+    // ok
+    let x = if a { 1 } else { 2 };
+    let x = if a { 1.0 } else { 0.0 };
+    let x = if a {
+        side_effect();
+        1
+    } else {
+        0
+    };
+    let x = if a { 1 } else if b { 0 } else { 1 };
+    let x = if a { ONE } else { 0 };
+}
+
+fn side_effect() {}
@@ -0,0 +1,99 @@
+#![allow(dead_code, if_same_then_else)]
+#![warn(branches_sharing_code)]
+
+fn foo() -> bool {
+    unimplemented!()
+}
+
+fn bar<T>(_: T) {}
+
+fn shared_at_start(x: u32) -> u32 {
+    let y = if x == 0 {
+        bar("Hello World");
+        let z = x * 2;
+        z + 1
+    } else {
+        bar("Hello World");
+        let z = x * 2;
+        z - 1
+    };
+
+    if x == 1 {
+        bar("one");
+        bar(x);
+    } else if x == 2 {
+        bar("one");
+        bar("x is two");
+    } else {
+        bar("one");
+    }
+
+    y
+}
+
+fn shared_at_end(x: u32) {
+    if x == 0 {
+        bar("zero");
+        bar("done");
+    } else {
+        bar("not zero");
+        bar("done");
+    }
+
+    // the moved statement would use `name` out of its scope
+    if foo() {
+        let name = "foo";
+        bar(name);
+    } else {
+        let name = "bar";
+        bar(name);
+    }
+}
+
+fn not_linted(x: u32) {
+    // no `else`
+    if x == 0 {
+        bar("Hello World");
+    }
+
+    // the value of the blocks is different
+    let _ = if x == 0 {
+        1
+    } else {
+        2
+    };
+
+    // moving `x` before the `if` would change the condition of the `else if`
+    if x == 0 {
+        let x = 3;
+        bar(x);
+    } else if x == 1 {
+        let x = 3;
+        bar(x + 1);
+    } else {
+        let x = 3;
+        bar(x + 2);
+    }
+
+    // moving `x` before the `if` would change the value printed after it
+    if foo() {
+        let x = 3;
+        bar(x);
+    } else {
+        let x = 3;
+        bar(x + 1);
+    }
+    bar(x);
+
+    // `pop` changes the length read at the start of the blocks
+    let mut v = vec![1];
+    if v.pop().is_some() {
+        let n = v.len();
+        bar(n);
+    } else {
+        let n = v.len();
+        bar(n + 1);
+    }
+}
+
+fn main() {}
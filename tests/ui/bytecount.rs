@@ -24,4 +24,6 @@ fn main() {
     let y = vec![0_u16; 3];
 
     let _ = y.iter().filter(|&&a| a == 0).count(); // naive count, but not bytes
+
+    let _ = x.iter().cloned().filter(|&a| a == 0).count(); // naive byte count
 }
@@ -0,0 +1,31 @@
+#![warn(case_sensitive_file_extension_comparisons)]
+#![allow(dead_code)]
+
+use std::string::String;
+
+struct TestStruct;
+
+impl TestStruct {
+    fn ends_with(self, _arg: &str) {}
+}
+
+fn is_rust_file(filename: &str) -> bool {
+    filename.ends_with(".rs")
+}
+
+fn main() {
+    // std::string::String and &str should trigger the lint failure with .ext12
+    let _ = String::from("").ends_with(".ext12");
+    let _ = "str".ends_with(".ext12");
+    let _ = "str".ends_with(".EXT12");
+
+    // The test struct should not trigger the lint failure with .ext12
+    TestStruct {}.ends_with(".ext12");
+
+    // These should not trigger the lint failure
+    let _ = String::from("").ends_with("ext12");
+    let _ = "str".ends_with("ext12");
+    let _ = "str".ends_with(".Ext12");
+    let _ = "str".ends_with(".123");
+    TestStruct {}.ends_with("ext12");
+}
@@ -0,0 +1,23 @@
+#![warn(cast_possible_truncation, cast_sign_loss)]
+#![allow(dead_code, no_effect, unnecessary_operation)]
+
+struct Foo {
+    bar: u64,
+}
+
+fn main() {
+    let x = 1i64;
+    let foo = Foo { bar: 1 };
+    x as i8;
+    x as u64;
+    foo.bar as u16;
+    // not a simple place, no suggestion
+    (x + 1) as i32;
+
+    const C: u8 = 1i32 as u8;
+}
+
+#[clippy::msrv = "1.33"]
+fn too_old(x: i64) -> i32 {
+    x as i32
+}
@@ -1,6 +1,6 @@
 
 
-
+#![allow(empty_if_branches)]
 #[warn(collapsible_if)]
 fn main() {
     let x = "hello";
@@ -0,0 +1,39 @@
+#![warn(collapsible_str_replace)]
+#![allow(unused)]
+
+fn get_filter() -> char {
+    'u'
+}
+
+fn main() {
+    let p = 'p';
+    let s = 's';
+    let u = 'u';
+    let l = "l";
+
+    let mut iter = ["l", "z"].iter();
+
+    let _ = "hesuo worpd".replace('s', "l").replace('u', "l");
+    let _ = "hesuo worpd".replace('s', l).replace('u', l).replace('p', l);
+    let _ = "hesuo worpd".replace(s, "l").replace(u, "l").replace(p, "l");
+    let _ = "hesuo worpd".to_string().replace('s', "l").replace('u', "l");
+    // only the calls with the same replacement
+    let _ = "hesuo world".replace('s', "l").replace('u', "l").replace('w', "d");
+    let _ = "hesuo worpd".replace('p', "d").replace('s', "l").replace('u', "l");
+
+    // ok
+    let _ = "hesuo world".replace('s', "l").replace('u', "r");
+    let _ = "hesuo world".replace('s', "l").replace("u", "l");
+    let _ = "hesuo world".replace(get_filter(), "l").replace('s', "l");
+    let _ = "hesuo world".replace('s', iter.next().unwrap()).replace('u', iter.next().unwrap());
+}
+
+#[clippy::msrv = "1.57"]
+fn msrv_1_57() {
+    let _ = "cats".replace('c', "x").replace('a', "x");
+}
+
+#[clippy::msrv = "1.58"]
+fn msrv_1_58() {
+    let _ = "cats".replace('c', "x").replace('a', "x");
+}
@@ -0,0 +1,31 @@
+#![warn(impossible_comparisons, redundant_comparisons)]
+#![allow(unused, double_comparisons, absurd_extreme_comparisons)]
+
+const LIMIT: u32 = 100;
+
+fn main() {
+    let status_code = 200u32;
+    let x = -3i8;
+
+    // impossible
+    let _ = status_code <= 400 && status_code > 500;
+    let _ = status_code < 200 && 200 < status_code;
+    let _ = x > 5 && x < -5;
+    let _ = x == 1 && x > 1;
+    let _ = x == 1 && x != 1;
+    let _ = status_code > LIMIT && status_code > 0 && status_code < 50;
+
+    // redundant
+    let _ = status_code > 500 && status_code > 400;
+    let _ = status_code <= 400 && status_code < 500;
+    let _ = x != 3 && x < 2;
+    let _ = x == 1 && x >= 0;
+
+    // ok
+    let _ = status_code >= 400 && status_code < 500;
+    let _ = x != 1 && x != 2;
+    let _ = status_code > 500 || status_code < 400;
+    let _ = x > 5 && x + 1 < 5;
+    let y = 1i8;
+    let _ = x > 5 && y < 5;
+}
@@ -1,3 +1,4 @@
+#![allow(redundant_type_annotations)]
 #[derive(Debug)]
 struct Foo {}
 
@@ -0,0 +1,28 @@
+#![warn(constant_min_max_chain)]
+#![allow(unused)]
+
+const LOW: i32 = 1;
+
+fn main() {
+    let x = 4;
+    let _ = x.max(5).min(3);
+    let _ = x.min(3).max(5);
+    let _ = x.max(3).min(3);
+    let _ = x.clamp(1, 5).min(0);
+    let _ = x.clamp(1, 5).max(7);
+    let _ = x.min(0).clamp(1, 5);
+    let _ = x.max(10).clamp(1, 5);
+
+    let f = 4.5f64;
+    let _ = f.max(5.0).min(3.0);
+
+    // ok
+    let _ = x.max(3).min(5);
+    let _ = x.min(5).max(3);
+    let _ = x.clamp(1, 5).min(3);
+    let _ = x.max(2).clamp(1, 5);
+    let _ = x.max(5).max(3);
+    let _ = 4.max(5).min(3);
+    let y = 2;
+    let _ = x.max(y).min(3);
+}
@@ -1,5 +1,6 @@
-#![allow(blacklisted_name, collapsible_if, cyclomatic_complexity, eq_op, needless_continue,
-         needless_return, never_loop, no_effect, zero_divided_by_zero)]
+#![allow(blacklisted_name, branches_sharing_code, collapsible_if, cyclomatic_complexity, empty_if_branches, eq_op,
+         needless_continue, needless_return, never_loop, no_effect, same_functions_in_if_condition,
+         zero_divided_by_zero)]
 
 fn bar<T>(_: T) {}
 fn foo() -> bool { unimplemented!() }
@@ -0,0 +1,15 @@
+#![allow(unused_must_use)]
+#![warn(create_dir)]
+
+use std::fs::create_dir;
+
+fn create_dir_relative() {}
+
+fn main() {
+    std::fs::create_dir("foo");
+    create_dir("bar").unwrap();
+
+    // not linted
+    std::fs::create_dir_all("foo");
+    create_dir_relative();
+}
@@ -0,0 +1,68 @@
+#![warn(debug_assert_with_mut_call)]
+#![allow(trivially_copy_pass_by_ref)]
+
+struct S;
+
+impl S {
+    fn bool_self_ref(&self) -> bool {
+        false
+    }
+    fn bool_self_mut(&mut self) -> bool {
+        false
+    }
+    fn u32_self_mut(&mut self) -> u32 {
+        0
+    }
+}
+
+fn bool_ref(_: &u32) -> bool {
+    false
+}
+fn bool_mut(_: &mut u32) -> bool {
+    false
+}
+
+fn func_non_mutable() {
+    debug_assert!(bool_ref(&3));
+    debug_assert!(!bool_ref(&3));
+    debug_assert_eq!(0, 3 & 1);
+    debug_assert_ne!(1, 3 & 1);
+
+    let s = S;
+    debug_assert!(s.bool_self_ref());
+    debug_assert_eq!(s.bool_self_ref(), false);
+}
+
+fn func_mutable() {
+    debug_assert!(bool_mut(&mut 3));
+    debug_assert!(!bool_mut(&mut 3));
+    debug_assert_eq!(0, bool_mut(&mut 3) as u32);
+    debug_assert_ne!(1, bool_mut(&mut 3) as u32);
+
+    let mut s = S;
+    debug_assert!(s.bool_self_mut());
+    debug_assert_eq!(s.u32_self_mut(), 0);
+    debug_assert_ne!(1, s.u32_self_mut());
+}
+
+fn method_non_mutable() {
+    let v = vec![1];
+    debug_assert!(v.is_empty());
+}
+
+fn method_mutable() {
+    let mut v = vec![1];
+    debug_assert_eq!(v.pop(), Some(1));
+    let mut x = 0;
+    debug_assert!({
+        x += 1;
+        true
+    });
+}
+
+fn main() {
+    func_non_mutable();
+    func_mutable();
+    method_non_mutable();
+    method_mutable();
+}
@@ -0,0 +1,68 @@
+#![allow(dead_code, should_implement_trait)]
+#![warn(default_constructed_unit_structs)]
+
+use std::marker::PhantomData;
+
+#[derive(Default)]
+struct UnitStruct;
+
+impl UnitStruct {
+    fn new() -> Self {
+        // `Self` can't be used as the value of a unit struct
+        Self::default()
+    }
+
+    fn other() -> Self {
+        UnitStruct::default()
+    }
+}
+
+#[derive(Default)]
+struct TupleStruct(usize);
+
+#[derive(Default)]
+struct NormalStruct {
+    inner: PhantomData<usize>,
+}
+
+#[derive(Default)]
+struct EmptyStruct {}
+
+struct NonDefaultStruct;
+
+impl NonDefaultStruct {
+    fn default() -> Self {
+        NonDefaultStruct
+    }
+}
+
+struct CustomDefault;
+
+impl Default for CustomDefault {
+    fn default() -> Self {
+        println!("constructing a custom default");
+        CustomDefault
+    }
+}
+
+fn main() {
+    let _ = PhantomData::<usize>::default();
+    let _: PhantomData<i32> = PhantomData::default();
+    let _ = UnitStruct::default();
+
+    // not unit structs
+    let _ = TupleStruct::default();
+    let _ = NormalStruct::default();
+    let _ = EmptyStruct::default();
+
+    // not the derived `Default` implementation
+    let _ = NonDefaultStruct::default();
+    let _ = CustomDefault::default();
+
+    // the type is not written
+    let _: UnitStruct = Default::default();
+
+    // a type alias can't be used as the value
+    type Alias = UnitStruct;
+    let _ = Alias::default();
+}
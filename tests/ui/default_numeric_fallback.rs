@@ -0,0 +1,37 @@
+#![warn(default_numeric_fallback)]
+#![allow(unused, no_effect, unnecessary_operation)]
+
+struct Foo {
+    x: u64,
+}
+
+fn takes_u8(_: u8) {}
+
+fn generic<T>(_: T) {}
+
+fn returns_i32() -> i32 {
+    42
+}
+
+fn main() {
+    // linted
+    let x = 22;
+    let y = 1.5;
+    let z = [1, 2, 3];
+    let sum = 1 + 2;
+    generic(3);
+
+    // type given by an annotation
+    let x: i32 = 22;
+    let y: f64 = 1.5;
+    let z: [u8; 3] = [1, 2, 3];
+
+    // type given by a parameter, a field or a typed operand
+    takes_u8(4);
+    let _ = Foo { x: 5 };
+    let a = 1u64;
+    let _ = a + 6;
+    let _ = a == 7;
+    let _ = 8_i32;
+    let _ = 9 as u8;
+}
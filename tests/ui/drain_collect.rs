@@ -0,0 +1,51 @@
+#![allow(dead_code)]
+#![warn(drain_collect)]
+
+use std::collections::VecDeque;
+
+fn vec(v: &mut Vec<i32>) -> Vec<i32> {
+    v.drain(..).collect()
+}
+
+fn vec_range_from(v: &mut Vec<i32>) -> Vec<i32> {
+    v.drain(0..).collect()
+}
+
+fn vec_deque(v: &mut VecDeque<i32>) -> VecDeque<i32> {
+    v.drain(..).collect()
+}
+
+fn string(s: &mut String) -> String {
+    s.drain(..).collect()
+}
+
+struct S {
+    v: Vec<i32>,
+}
+
+impl S {
+    fn take(&mut self) -> Vec<i32> {
+        self.v.drain(..).collect()
+    }
+}
+
+fn not_linted(v: &mut Vec<i32>, s: &mut String) {
+    // not the whole collection
+    let _: Vec<_> = v.drain(1..).collect();
+    let _: Vec<_> = v.drain(..2).collect();
+    // another type
+    let _: VecDeque<_> = v.drain(..).collect();
+    let _: Vec<char> = s.drain(..).collect();
+}
+
+#[clippy::msrv = "1.39"]
+fn msrv_1_39(v: &mut Vec<i32>) -> Vec<i32> {
+    v.drain(..).collect()
+}
+
+#[clippy::msrv = "1.40"]
+fn msrv_1_40(v: &mut Vec<i32>) -> Vec<i32> {
+    v.drain(..).collect()
+}
+
+fn main() {}
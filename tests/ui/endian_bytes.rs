@@ -0,0 +1,23 @@
+#![warn(host_endian_bytes, little_endian_bytes, big_endian_bytes)]
+
+fn main() {
+    let _ = 2u32.to_ne_bytes();
+    let _ = 2i64.to_le_bytes();
+    let _ = 2u16.to_be_bytes();
+    let _ = u32::from_ne_bytes([0; 4]);
+    let _ = i64::from_le_bytes([0; 8]);
+    let _ = u16::from_be_bytes([0; 2]);
+
+    // not linted
+    let _ = 2u32.to_string();
+    let _ = u32::from_str_radix("2", 10);
+    let _ = Wrapper::from_le_bytes([0; 4]);
+}
+
+struct Wrapper;
+
+impl Wrapper {
+    fn from_le_bytes(bytes: [u8; 4]) -> u32 {
+        u32::from(bytes[0])
+    }
+}
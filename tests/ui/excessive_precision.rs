@@ -1,6 +1,6 @@
 
 #![warn(excessive_precision)]
-#![allow(print_literal)]
+#![allow(print_literal, lossy_float_literal, redundant_type_annotations)]
 
 fn main() {
     // Consts
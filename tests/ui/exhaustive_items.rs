@@ -0,0 +1,58 @@
+#![feature(non_exhaustive)]
+#![warn(exhaustive_enums, exhaustive_structs)]
+#![allow(dead_code)]
+
+fn main() {}
+
+pub mod enums {
+    pub enum Exhaustive {
+        Foo,
+        Bar,
+    }
+
+    // no warning, already non_exhaustive
+    #[non_exhaustive]
+    pub enum NonExhaustive {
+        Foo,
+        Bar,
+    }
+
+    // no warning, has a hidden variant
+    pub enum Hidden {
+        Foo,
+        #[doc(hidden)]
+        __Nonexhaustive,
+    }
+
+    // no warning, private
+    enum Private {
+        Foo,
+        Bar,
+    }
+}
+
+pub mod structs {
+    pub struct Exhaustive {
+        pub foo: u8,
+        pub bar: String,
+    }
+
+    // no warning, already non_exhaustive
+    #[non_exhaustive]
+    pub struct NonExhaustive {
+        pub foo: u8,
+        pub bar: String,
+    }
+
+    // no warning, has a private field
+    pub struct ExhaustivePrivateField {
+        pub foo: u8,
+        bar: String,
+    }
+
+    // no warning, private
+    struct ExhaustivePrivate {
+        pub foo: u8,
+        pub bar: String,
+    }
+}
@@ -0,0 +1,45 @@
+#![warn(explicit_auto_deref)]
+#![allow(dead_code, unused_variables)]
+
+fn f_str(_: &str) {}
+fn f_string(_: &String) {}
+fn f_mut_vec(_: &mut Vec<u8>) {}
+fn f_generic<T: ?Sized>(_: &T) {}
+
+struct S;
+
+impl S {
+    fn f_str(&self, _: &str) {}
+}
+
+fn main() {
+    let s = String::new();
+    let x = &s;
+    let y = &x;
+
+    f_str(&**x);
+    f_str(&*s);
+    f_string(&**y);
+    f_string(*y);
+    S.f_str(&***y);
+    let _ = (*x).len();
+    let _ = (**y).capacity();
+
+    let mut v = Vec::new();
+    let w = &mut v;
+    f_mut_vec(&mut *w);
+    (*w).push(1u8);
+    let b = Box::new(String::new());
+    let _ = (*b).len();
+
+    // ok
+    f_str(x);
+    f_generic(&**x);
+    let _ = (*x).clone();
+    let p: *const String = x;
+    unsafe { f_str(&**p) };
+    let b = &Box::new(1u8);
+    f_any(&**b);
+}
+
+fn f_any(_: &dyn std::any::Any) {}
@@ -0,0 +1,38 @@
+#![warn(field_reassign_with_default)]
+
+#[derive(Default)]
+struct A {
+    i: i32,
+    j: i64,
+}
+
+#[derive(Default)]
+struct B {
+    i: i32,
+}
+
+fn main() {
+    // lint, the other fields keep their default value
+    let mut a: A = Default::default();
+    a.i = 42;
+
+    // lint, all the fields are assigned
+    let mut b = B::default();
+    b.i = 1;
+
+    // lint with several fields
+    let mut c = A::default();
+    c.i = 1;
+    c.j = 2;
+
+    // not linted, the value depends on the variable
+    let mut d = A::default();
+    d.j = i64::from(d.i) + 1;
+
+    // not linted, the assignment doesn't directly follow the declaration
+    let mut e = A::default();
+    let x = 5;
+    e.i = x;
+
+    let _ = (a, b, c, d, e);
+}
@@ -0,0 +1,33 @@
+#![warn(filetype_is_file)]
+
+fn main() -> std::io::Result<()> {
+    use std::fs;
+    use std::ops::BitOr;
+
+    // !filetype.is_dir()
+    if fs::metadata("foo.txt")?.file_type().is_file() {
+        let _ = fs::read_to_string("foo.txt")?;
+    }
+
+    // filetype.is_dir()
+    if !fs::metadata("foo.txt")?.file_type().is_file() {
+        // nothing
+    } else {
+        let _ = fs::File::open("foo.txt")?;
+    }
+
+    // not linted, the path isn't read
+    if fs::metadata("foo.txt")?.file_type().is_file() {
+        println!("a file");
+    }
+
+    // not linted, the path is read in the other branch
+    if !fs::metadata("foo.txt")?.file_type().is_file() {
+        let _ = fs::read("foo.txt")?;
+    }
+
+    // not linted
+    let _ = fs::metadata("foo.txt")?.file_type().is_file().bitor(true);
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+#![allow(unused)]
+#![warn(imprecise_flops, suboptimal_flops)]
+
+use std::f64::consts::E;
+
+fn main() {
+    let x = 3f64;
+    let y = 4f32;
+
+    // suboptimal_flops
+    let _ = x.powi(2);
+    let _ = x.powf(0.5);
+    let _ = x.powf(3.0);
+    let _ = 2f64.powf(x);
+    let _ = E.powf(x);
+    let _ = x.log(2.0);
+    let _ = y.log(10.0);
+    let _ = x.log(E);
+    let _ = x * 2.0 + 1.0;
+    let _ = 1.0 + x * 2.0;
+    let _ = 2.0 * y + 1.0;
+    let _ = -2. * y + 1.0;
+
+    // imprecise_flops
+    let _ = (1.0 + x).ln();
+    let _ = (y + 1.0).ln();
+    let _ = x.exp() - 1.0;
+
+    // ok
+    let _ = x.powi(3);
+    let _ = x.powf(1.5);
+    let _ = x.log(3.0);
+    let _ = (2.0 + x).ln();
+    let _ = x.exp() - 2.0;
+    let _ = 2 * 3 + 1;
+}
@@ -0,0 +1,25 @@
+#![warn(format_in_format_args, to_string_in_format_args)]
+#![allow(useless_format)]
+
+use std::fmt::Write;
+
+fn main() {
+    let x = 42;
+    let mut s = String::new();
+
+    println!("error: {}", format!("something failed at {}", x));
+    let _ = format!("error: {}", format!("something failed at {}", x));
+    writeln!(s, "error: {}", format!("something failed at {}", x)).unwrap();
+
+    println!("{}", x.to_string());
+    eprint!("{} {}", x, "abc".to_string());
+    let _ = format!("{}", x.to_string());
+
+    // ok
+    println!("{:?}", format!("{}", x));
+    println!("{:>10}", x.to_string());
+    println!("{:?}", x.to_string());
+    println!("{}", format_args!("{}", x));
+    println!("{}", format!("{}", x).len());
+    let _ = vec![x.to_string()];
+}
@@ -4,7 +4,7 @@
 #![warn(clippy)]
 #![allow(unused_variables)]
 #![allow(unused_assignments)]
-#![allow(if_same_then_else)]
+#![allow(if_same_then_else, needless_else, same_functions_in_if_condition)]
 #![allow(deref_addrof)]
 
 fn foo() -> bool { true }
@@ -88,6 +88,14 @@ fn main() {
         -1, -2, -3 // <= no comma here
         *4, -5, -6
     ];
+    let _ = &[
+        -1, -2, -3 // <= no comma here
+        -4 - 5, -6
+    ];
+    let _ = &[
+        1, 2, 3 // <= no comma here
+        -4 + 5, 6
+    ];
 
     // those are ok:
     let _ = &[
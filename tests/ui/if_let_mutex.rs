@@ -0,0 +1,35 @@
+#![warn(if_let_mutex)]
+
+use std::sync::Mutex;
+
+fn do_stuff<T>(_: T) {}
+
+fn if_let() {
+    let m = Mutex::new(1u8);
+    if let Err(locked) = m.lock() {
+        do_stuff(locked);
+    } else {
+        let lock = m.lock().unwrap();
+        do_stuff(lock);
+    };
+}
+
+fn if_let_option() {
+    let m = Mutex::new(Some(0u8));
+    if let Some(locked) = m.lock().unwrap().as_ref() {
+        do_stuff(locked);
+        do_stuff(m.lock());
+    };
+}
+
+fn not_linted() {
+    let m = Mutex::new(1u8);
+    let n = Mutex::new(1u8);
+    if let Ok(locked) = m.lock() {
+        do_stuff(n.lock());
+        drop(locked);
+        do_stuff(m.lock());
+    };
+}
+
+fn main() {}
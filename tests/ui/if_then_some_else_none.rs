@@ -0,0 +1,58 @@
+#![warn(if_then_some_else_none)]
+#![allow(dead_code)]
+
+fn main() {
+    let x = 42;
+
+    // should lint
+    let _ = if foo() { Some(x) } else { None };
+    let _ = if x > 1 { Some("bar".to_string()) } else { None };
+    let _ = if !foo() { Some(0) } else { None };
+
+    // should not lint
+    let _ = if foo() {
+        println!("side effect");
+        Some(x)
+    } else {
+        None
+    };
+    let _ = if foo() {
+        Some(x)
+    } else {
+        println!("side effect");
+        None
+    };
+    let _ = if foo() { Some(x) } else { Some(0) };
+    let _ = if foo() { None } else { Some(x) };
+    let _ = if x > 10 {
+        None
+    } else if foo() {
+        Some(x)
+    } else {
+        None
+    };
+}
+
+// should not lint, the `?`, `return` and `break` would move into the closure
+fn early_exits(v: &[u8]) -> Option<u8> {
+    let _ = if foo() { Some(*v.first()?) } else { None };
+    for &b in v {
+        let _ = if b > 1 { Some(if b > 2 { b } else { return None }) } else { None };
+        let _ = if b > 1 { Some(if b > 2 { b } else { break }) } else { None };
+    }
+    None
+}
+
+#[clippy::msrv = "1.49"]
+fn older() -> Option<i32> {
+    if foo() { Some(1) } else { None }
+}
+
+#[clippy::msrv = "1.61"]
+fn no_then_some() -> Option<i32> {
+    if foo() { Some(1) } else { None }
+}
+
+fn foo() -> bool {
+    unimplemented!()
+}
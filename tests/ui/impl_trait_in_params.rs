@@ -0,0 +1,42 @@
+#![warn(impl_trait_in_params)]
+#![allow(dead_code, unused_variables)]
+
+use std::fmt::Debug;
+
+pub trait Trait {
+    fn in_trait(&self, x: impl Debug);
+}
+
+pub fn simple(x: impl Debug) {}
+
+pub fn with_generics<'a, T: Clone>(x: &'a T, y: impl Iterator<Item = T> + Clone) {}
+
+pub fn two(x: impl Debug, y: impl Clone) {}
+
+pub struct Public;
+
+impl Public {
+    pub fn method(&self, x: impl Debug) {}
+
+    fn private_method(&self, x: impl Debug) {}
+}
+
+impl Trait for Public {
+    fn in_trait(&self, x: impl Debug) {}
+}
+
+// ok, not exported
+fn private(x: impl Debug) {}
+
+mod private_mod {
+    pub fn not_reachable(x: impl std::fmt::Debug) {}
+}
+
+// ok, the return types are only checked with `check-impl-trait-in-return`
+pub fn returns() -> impl Debug {
+    0
+}
+
+pub fn named<T: Debug>(x: T) {}
+
+fn main() {}
@@ -18,6 +18,17 @@ fn main() {
     foo();
 }
 
+fn other_items() {
+    let x = 1;
+    struct S(u32);
+    impl S {
+        fn get(&self) -> u32 {
+            self.0
+        }
+    }
+    println!("{}", S(x).get());
+}
+
 fn mac() {
     let mut a = 5;
     println!("{}", a);
@@ -0,0 +1,34 @@
+#![warn(iter_on_single_or_empty_collections)]
+#![allow(unused)]
+
+fn array() {
+    let x = 42;
+    let _ = [x].iter();
+    let _ = [x].iter_mut();
+    let _ = [x + 1].into_iter();
+    let _: Vec<&i32> = [].iter().collect();
+
+    // ok
+    let _ = [x, x].iter();
+    let _ = [x; 1].iter();
+}
+
+fn option() {
+    let x = String::new();
+    let _ = Some(&x).into_iter();
+    let _ = Some(x.len()).iter();
+    let _ = None::<u32>.into_iter();
+}
+
+fn vec() {
+    let _ = vec![1].into_iter();
+    let _ = vec![1].iter();
+    let _: Vec<u8> = vec![].into_iter().collect();
+
+    // ok
+    let _ = vec![1, 2].into_iter();
+    let _ = vec![0; 1].into_iter();
+    let _ = Vec::<u8>::new().into_iter();
+}
+
+fn main() {}
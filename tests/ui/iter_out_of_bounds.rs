@@ -0,0 +1,17 @@
+#![warn(iter_out_of_bounds)]
+
+use std::iter::{empty, once};
+
+fn main() {
+    let v = vec![1, 2, 3];
+    let _ = [1, 2, 3].iter().skip(4);
+    let _ = [1, 2, 3].iter().take(4);
+    let _ = once(1).skip(2);
+    let _ = empty::<u8>().take(1);
+
+    // not linted
+    let _ = [1, 2, 3].iter().skip(3);
+    let _ = [1, 2, 3].iter().take(3);
+    let _ = v.iter().skip(4);
+    let _ = once(1).take(1);
+}
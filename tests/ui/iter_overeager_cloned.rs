@@ -0,0 +1,21 @@
+#![warn(iter_overeager_cloned)]
+#![allow(filter_next)]
+
+fn main() {
+    let vec = vec!["1".to_string(), "2".to_string(), "3".to_string()];
+
+    let _ = vec.iter().cloned().count();
+    let _ = vec.iter().cloned().last();
+    let _ = vec.iter().cloned().next();
+    let _ = vec.iter().cloned().nth(1);
+    let _: Vec<_> = vec.iter().cloned().skip(1).collect();
+    let _: Vec<_> = vec.iter().cloned().take(2).collect();
+    let _ = vec.iter().cloned().any(|s| s.is_empty());
+    let _ = vec.iter().cloned().find(|s| s.is_empty());
+    let _ = vec.iter().cloned().filter(|s| s.is_empty()).next();
+
+    // not linted, the items need to be owned
+    let _: Vec<_> = vec.iter().cloned().map(|s| s + "a").collect();
+    // the other lints of the methods called after `cloned()` still apply
+    let _ = vec.iter().cloned().fold(false, |acc, s| acc || s.is_empty());
+}
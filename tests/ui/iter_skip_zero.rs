@@ -0,0 +1,13 @@
+#![warn(iter_skip_zero)]
+
+use std::iter::once;
+
+fn main() {
+    let v = vec![1, 2, 3];
+    let _ = [1, 2, 3].iter().skip(0);
+    let _ = v.iter().skip(0);
+    let _ = once([1, 2, 3]).skip(0);
+
+    // not linted
+    let _ = v.iter().skip(1);
+}
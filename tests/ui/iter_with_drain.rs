@@ -0,0 +1,50 @@
+#![allow(dead_code, unused_mut, drain_collect)]
+#![warn(iter_with_drain)]
+
+use std::collections::VecDeque;
+
+fn linted() {
+    let mut v = vec![1, 2, 3];
+    let _: Vec<_> = v.drain(..).map(|x| x * 2).collect();
+
+    let mut d: VecDeque<_> = vec![1, 2, 3].into_iter().collect();
+    let _: Vec<_> = d.drain(..).collect();
+
+    let mut w = vec![1, 2, 3];
+    for x in w.drain(..) {
+        println!("{}", x);
+    }
+
+    let mut u = vec![1, 2, 3];
+    let _: Vec<_> = u.drain(0..).rev().collect();
+}
+
+fn not_linted(mut param: Vec<i32>) {
+    // used afterwards
+    let mut v = vec![1, 2, 3];
+    let _: Vec<_> = v.drain(..).map(|x| x * 2).collect();
+    v.push(4);
+
+    // drained several times
+    let mut w = vec![1, 2, 3];
+    for _ in 0..3 {
+        let _: Vec<_> = w.drain(..).map(|x| x * 2).collect();
+    }
+
+    // not the whole collection
+    let mut u = vec![1, 2, 3];
+    let _: Vec<_> = u.drain(1..).map(|x| x * 2).collect();
+
+    // the elements are only dropped
+    let mut t = vec![1, 2, 3];
+    t.drain(..);
+
+    // collected into the same type, linted by `drain_collect`
+    let mut s = vec![1, 2, 3];
+    let _: Vec<_> = s.drain(..).collect();
+
+    let _: Vec<_> = param.drain(..).map(|x| x * 2).collect();
+    param.clear();
+}
+
+fn main() {}
@@ -0,0 +1,22 @@
+#![warn(join_absolute_paths)]
+
+use std::path::{Path, PathBuf};
+
+const ROOT: &str = "/etc";
+
+fn main() {
+    let path = Path::new("/usr");
+    let _ = path.join("/bin");
+    let _ = path.join("\\bin");
+    let _ = path.join(ROOT);
+    let _ = path.join("C:\\bin");
+
+    let buf = PathBuf::from("/usr");
+    let _ = buf.join("/sbin");
+    let _ = (&buf).join("/lib");
+
+    // not linted
+    let _ = path.join("bin");
+    let _ = path.join(String::from("/bin"));
+    let _ = buf.join("lib/x86_64");
+}
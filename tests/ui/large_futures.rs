@@ -0,0 +1,18 @@
+// compile-flags: --edition 2018
+#![feature(async_await, await_macro, futures_api)]
+#![warn(large_futures)]
+#![allow(dead_code)]
+
+async fn big_fut(_arg: [u8; 1024 * 16]) {}
+
+async fn small_fut(_arg: [u8; 1024]) {}
+
+async fn wait() {
+    await!(big_fut([0u8; 1024 * 16]));
+
+    // the lint allows these
+    await!(small_fut([0u8; 1024]));
+    await!(Box::pin(big_fut([0u8; 1024 * 16])));
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+#![warn(lossy_float_literal)]
+
+fn main() {
+    // Lossy whole-number float literals
+    let _: f32 = 16_777_217.0;
+    let _: f32 = 1_000_000_001.0;
+    let _ = 10_000_000_000_000_000.0_f32;
+    let _ = 16_777_217f32;
+    let _: f64 = 9_007_199_254_740_993.0;
+
+    // Lossless whole-number float literals
+    let _: f32 = 16_777_216.0;
+    let _: f32 = 1_000_000_000.0;
+    let _: f64 = 16_777_217.0;
+    let _: f64 = 9_007_199_254_740_992.0;
+    let _ = 0.0_f32;
+
+    // Not whole numbers, see `excessive_precision`
+    let _: f32 = 0.5;
+    let _: f32 = 1e10;
+}
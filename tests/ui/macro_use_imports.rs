@@ -0,0 +1,14 @@
+// compile-flags: --edition 2018
+#![warn(macro_use_imports)]
+#![allow(dead_code)]
+
+#[macro_use]
+extern crate lazy_static;
+
+lazy_static! {
+    static ref NUMBER: u32 = 42;
+}
+
+fn main() {
+    let _ = *NUMBER;
+}
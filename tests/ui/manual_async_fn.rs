@@ -0,0 +1,26 @@
+// compile-flags: --edition 2018
+#![feature(async_await, futures_api)]
+#![warn(manual_async_fn)]
+#![allow(unused)]
+
+use std::future::Future;
+
+fn fut() -> impl Future<Output = i32> { async { 42 } }
+
+pub fn with_move(x: i32) -> impl Future<Output = i32> { async move { x } }
+
+fn unit() -> impl Future<Output = ()> { async {} }
+
+fn with_lifetime<'a>(x: &'a i32) -> impl Future<Output = i32> + 'a { async move { *x } }
+
+// not linted, the body isn't only an async block
+fn not_only_async() -> impl Future<Output = i32> {
+    let x = 42;
+    async move { x }
+}
+
+async fn already_async() -> i32 {
+    42
+}
+
+fn main() {}
@@ -0,0 +1,44 @@
+// compile-flags: --edition 2018 -Z no-analysis
+#![feature(async_await, await_macro, futures_api)]
+#![warn(manual_async_recursion)]
+#![allow(dead_code)]
+
+async fn sum(n: u32) -> u32 {
+    if n == 0 {
+        0
+    } else {
+        n + await!(sum(n - 1))
+    }
+}
+
+struct S;
+
+impl S {
+    async fn count(&self, n: u32) -> u32 {
+        if n == 0 {
+            0
+        } else {
+            await!(self.count(n - 1)) + await!(Self::count(self, n - 1))
+        }
+    }
+
+    // this is a call to the free function
+    async fn sum(&self, n: u32) -> u32 {
+        await!(sum(n))
+    }
+}
+
+// not awaited
+async fn spawned(n: u32) {
+    let _ = spawned(n);
+}
+
+fn not_async(n: u32) -> u32 {
+    if n == 0 {
+        0
+    } else {
+        n + not_async(n - 1)
+    }
+}
+
+fn main() {}
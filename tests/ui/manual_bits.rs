@@ -0,0 +1,21 @@
+#![warn(manual_bits)]
+#![allow(dead_code)]
+
+use std::mem::size_of;
+
+fn main() {
+    let _ = size_of::<i8>() * 8;
+    let _ = 8 * std::mem::size_of::<u64>();
+    let _ = (size_of::<usize>() * 8) as u32;
+    let _ = (8 * size_of::<i128>()) as u32;
+    let _ = (size_of::<u16>() * 8) as u64;
+
+    // ok
+    let _ = size_of::<u32>() * 4;
+    let _ = size_of::<[u8; 4]>() * 8;
+}
+
+#[clippy::msrv = "1.52"]
+fn too_old() {
+    let _ = size_of::<u8>() * 8;
+}
@@ -0,0 +1,14 @@
+#![warn(manual_find_map)]
+
+fn main() {
+    // is_some(), unwrap()
+    let _ = (0_i32..).find(|n| n.checked_add(1).is_some()).map(|n| n.checked_add(1).unwrap());
+
+    // is_ok(), unwrap()
+    let _ = ["1", "a"].iter().find(|s| s.parse::<u8>().is_ok()).map(|s| s.parse::<u8>().unwrap());
+
+    // not linted, different expressions
+    let _ = (0_i32..).find(|n| n.checked_add(1).is_some()).map(|n| n.checked_add(2).unwrap());
+    // not linted, different names
+    let _ = (0_i32..).find(|n| n.checked_add(1).is_some()).map(|m| m.checked_add(1).unwrap());
+}
@@ -0,0 +1,25 @@
+#![feature(range_contains)]
+#![warn(manual_is_ascii_check)]
+#![allow(almost_complete_range)]
+
+fn main() {
+    let c = 'x';
+    let b = b'x';
+
+    let _ = ('a'..='z').contains(&c);
+    let _ = ('A'..='Z').contains(&c);
+    let _ = ('0'..='9').contains(&c);
+    let _ = (b'a'..=b'z').contains(&b);
+    let _ = ('a'..='z').contains(&c) || ('A'..='Z').contains(&c);
+    let _ = ('0'..='9').contains(&c) || ('a'..='z').contains(&c) || ('A'..='Z').contains(&c);
+    let _ = ('0'..='9').contains(&c) || ('a'..='f').contains(&c) || ('A'..='F').contains(&c);
+    let _ = c >= 'a' && c <= 'z';
+    let _ = 'A' <= c && c <= 'Z';
+    let _ = (c >= 'a' && c <= 'z') || (c >= 'A' && c <= 'Z');
+
+    // ok
+    let _ = ('a'..'z').contains(&c);
+    let _ = ('a'..='y').contains(&c);
+    let _ = ('a'..='z').contains(&c) || ('0'..='9').contains(&c);
+    let _ = c > 'a' && c <= 'z';
+}
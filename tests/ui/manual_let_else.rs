@@ -0,0 +1,34 @@
+#![warn(manual_let_else)]
+#![allow(dead_code)]
+
+fn main() {}
+
+fn from_match(opt: Option<u32>) -> u32 {
+    let v = match opt { Some(v) => v, None => return 0 };
+    let mut w = match opt { None => panic!(), Some(x) => x };
+    w += v;
+    w
+}
+
+fn from_if_let(res: Result<u32, ()>) {
+    loop {
+        let v = if let Ok(w) = res { w } else { continue };
+        let _ = v;
+    }
+}
+
+fn not_linted(opt: Option<u32>, res: Result<u32, u32>) -> Result<u32, u32> {
+    // the fallback doesn't diverge
+    let _ = match opt { Some(v) => v, None => 0 };
+    // the diverging arm uses a binding
+    let v = match res { Ok(v) => v, Err(e) => return Err(e) };
+    // the binding is not returned as is
+    let w = match opt { Some(w) => w + 1, None => return Ok(v) };
+    Ok(w)
+}
+
+#[clippy::msrv = "1.64"]
+fn too_old(opt: Option<u32>) -> u32 {
+    let v = match opt { Some(v) => v, None => return 0 };
+    v
+}
@@ -0,0 +1,33 @@
+#![warn(manual_retain)]
+#![allow(unused)]
+
+use std::collections::{HashMap, HashSet};
+
+fn main() {
+    let mut vec = vec![0, 1, 2];
+    vec = vec.into_iter().filter(|x| x % 2 == 0).collect();
+    vec = vec.iter().cloned().filter(|x| x % 2 == 0).collect();
+    vec = vec.iter().filter(|&x| x % 2 == 0).cloned().collect();
+
+    let mut s = String::from("foobar");
+    s = s.chars().filter(|&c| c != 'o').collect();
+
+    let mut set: HashSet<i32> = (0..8).collect();
+    set = set.into_iter().filter(|x| x % 2 == 0).collect();
+
+    let mut map: HashMap<i32, i32> = (0..8).map(|x| (x, x * 10)).collect();
+    map = map.into_iter().filter(|&(k, v)| k % 2 == 0 && v > 0).collect();
+    map = map.into_iter().filter(|(k, _)| k % 2 == 0).collect();
+
+    // ok, another collection is filtered
+    let other = vec![1, 2];
+    vec = other.iter().cloned().filter(|x| x % 2 == 0).collect();
+    // ok, the closure can't be adapted
+    s = s.chars().filter(|c| *c != 'o').collect();
+}
+
+#[clippy::msrv = "1.25"]
+fn msrv_1_25() {
+    let mut s = String::from("foobar");
+    s = s.chars().filter(|&c| c != 'o').collect();
+}
@@ -0,0 +1,46 @@
+#![allow(unused_imports)]
+#![warn(manual_saturating_arithmetic)]
+
+use std::{i32, i64, u32, u8};
+
+fn main() {
+    let a = 1u32;
+    let b = 2u32;
+    let _ = a.checked_add(b).unwrap_or(u32::MAX);
+    let _ = a.checked_add(b).unwrap_or(u32::max_value());
+    let _ = a.checked_sub(b).unwrap_or(0);
+    let _ = a.checked_sub(b).unwrap_or(u32::min_value());
+    let _ = 1u8.checked_mul(b as u8).unwrap_or(255);
+
+    let c = 1i32;
+    let _ = c.checked_add(1).unwrap_or(i32::MAX);
+    let _ = c.checked_add(-1).unwrap_or(i32::MIN);
+    let _ = c.checked_sub(1).unwrap_or(i32::min_value());
+    let _ = c.checked_sub(-1).unwrap_or(i32::max_value());
+
+    // ok
+    let _ = a.checked_add(b).unwrap_or(1);
+    let _ = a.checked_add(b).unwrap_or(0);
+    let _ = a.checked_sub(b).unwrap_or(u32::MAX);
+    let _ = c.checked_add(-1).unwrap_or(i32::MAX);
+    let _ = c.checked_sub(1).unwrap_or(i32::MAX);
+    // the bound depends on the sign of `c`
+    let _ = c.checked_add(c).unwrap_or(i32::MAX);
+    let _ = c.checked_mul(2).unwrap_or(i32::MAX);
+    let _ = 1i64.checked_add(1).unwrap_or(i64::MIN);
+    // not the associated functions of the integer types
+    let _ = a.checked_add(b).unwrap_or(max_value());
+    let _ = a.checked_sub(b).unwrap_or(Bounds::min_value());
+}
+
+fn max_value() -> u32 {
+    1
+}
+
+struct Bounds;
+
+impl Bounds {
+    fn min_value() -> u32 {
+        1
+    }
+}
@@ -0,0 +1,23 @@
+#![warn(manual_string_new)]
+#![allow(unused)]
+
+macro_rules! empty {
+    () => {
+        "".to_string()
+    };
+}
+
+fn main() {
+    let _ = "".to_string();
+    let _ = "".to_owned();
+    let _: String = "".into();
+    let _ = String::from("");
+    let _ = <String as From<&str>>::from("");
+
+    // ok
+    let _ = " ".to_string();
+    let _ = String::from("a");
+    let _: &str = "".into();
+    let _ = String::new();
+    let _ = empty!();
+}
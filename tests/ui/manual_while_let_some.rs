@@ -0,0 +1,41 @@
+#![warn(manual_while_let_some)]
+
+struct Stack {
+    items: Vec<u32>,
+}
+
+fn main() {
+    let mut numbers = vec![1, 2, 3];
+    while !numbers.is_empty() {
+        let number = numbers.pop().unwrap();
+        println!("{}", number);
+    }
+
+    let mut pairs = vec![(1, 2), (3, 4)];
+    while !pairs.is_empty() {
+        let (a, b) = pairs.pop().expect("not empty");
+        println!("{} {}", a, b);
+    }
+
+    let mut stack = Stack { items: vec![1] };
+    while !stack.items.is_empty() {
+        let item = stack.items.pop().unwrap();
+        println!("{}", item);
+    }
+
+    // not linted
+    let mut other = vec![1];
+    while !numbers.is_empty() {
+        let number = other.pop().unwrap();
+        println!("{}", number);
+    }
+    while !numbers.is_empty() {
+        println!("{}", numbers.len());
+        let number = numbers.pop().unwrap();
+        println!("{}", number);
+    }
+    while !numbers.is_empty() {
+        let number: u32 = numbers.pop().unwrap();
+        println!("{}", number);
+    }
+}
@@ -1,3 +1,4 @@
+#![allow(redundant_type_annotations)]
 fn match_bool() {
     let test: bool = true;
 
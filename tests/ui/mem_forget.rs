@@ -24,4 +24,20 @@ fn main() {
     forgetSomething(eight);
 
     std::mem::forget(7);
+
+    let nine = Owner { v: vec![9] };
+    forgetSomething(nine);
+
+    let ten = Trivial { x: 10 };
+    forgetSomething(ten);
+}
+
+#[allow(dead_code)]
+struct Owner {
+    v: Vec<i32>,
+}
+
+#[allow(dead_code)]
+struct Trivial {
+    x: i32,
 }
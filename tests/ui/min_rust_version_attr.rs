@@ -0,0 +1,32 @@
+#![warn(redundant_field_names, const_static_lifetime)]
+#![allow(dead_code)]
+#![clippy::msrv = "1.16"]
+
+struct Foo {
+    bar: u8,
+}
+
+const NAME: &'static str = "crate";
+
+fn old_foo(bar: u8) -> Foo {
+    Foo { bar: bar }
+}
+
+#[clippy::msrv = "1.17"]
+fn new_foo(bar: u8) -> Foo {
+    Foo { bar: bar }
+}
+
+#[clippy::msrv = "1.31.0"]
+mod new {
+    const NAME: &'static str = "new";
+
+    // the innermost attribute wins
+    #[clippy::msrv = "1.0"]
+    const OLD_NAME: &'static str = "old";
+}
+
+#[clippy::msrv = "one point seventeen"]
+fn invalid() {}
+
+fn main() {}
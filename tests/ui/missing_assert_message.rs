@@ -0,0 +1,37 @@
+// compile-flags: --test
+#![warn(missing_assert_message)]
+#![allow(dead_code)]
+
+fn assert_without_message() {
+    assert!(true);
+    assert_eq!(1, 1);
+    assert_ne!(1, 2);
+    debug_assert!(true);
+    debug_assert_eq!(1, 1);
+    debug_assert_ne!(1, 2);
+    assert!(true,);
+    assert_eq!(1, 1,);
+}
+
+fn assert_with_message() {
+    assert!(true, "oh no");
+    assert_eq!(1, 1, "oh no");
+    assert_ne!(1, 2, "oh no");
+    debug_assert!(true, "oh no");
+    debug_assert_eq!(1, 1, "oh no: {}", 1);
+    debug_assert_ne!(1, 2, "oh no",);
+}
+
+#[test]
+fn test_assert_without_message() {
+    assert!(true);
+}
+
+#[cfg(test)]
+mod tests {
+    fn helper() {
+        assert_eq!(1, 1);
+    }
+}
+
+fn main() {}
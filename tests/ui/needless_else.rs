@@ -0,0 +1,46 @@
+#![warn(needless_else, empty_if_branches)]
+#![allow(unused)]
+
+fn side_effect() -> bool {
+    true
+}
+
+fn main() {
+    let b = true;
+    let x = 1;
+
+    if b {
+        println!("yes");
+    } else {}
+    if let Some(_) = Some(1) {
+        println!("some");
+    } else {}
+    if x > 0 {
+        println!("positive");
+    } else if x < 0 {
+        println!("negative");
+    } else {}
+
+    if x == 1 {}
+    if !b && x > 2 {} else {
+        println!("no");
+    }
+
+    // ok, the `else` has a comment
+    if b {
+        println!("yes");
+    } else {
+        // nothing to do
+    }
+    // ok, the `if` has a comment
+    if b {
+        // TODO
+    }
+    // ok, the condition has side effects
+    if side_effect() {}
+    // ok, `if let` with an empty branch
+    if let Some(_) = Some(1) {
+    } else {
+        println!("none");
+    }
+}
@@ -0,0 +1,45 @@
+#![allow(unused_variables, unused_assignments, useless_let_if_seq)]
+#![warn(needless_late_init)]
+
+fn f() -> bool {
+    true
+}
+
+fn main() {
+    let a;
+    a = "zero";
+
+    let b: u32;
+    f();
+    b = 1;
+
+    let c;
+    match 3 { 0 => c = "zero", 1 => { f(); c = "one"; } _ => c = "many" }
+
+    let d;
+    if f() { d = 1; } else if f() { d = 2; } else { d = 3; }
+
+    let e;
+    f();
+    if let Some(x) = Some(1) { e = x; } else { e = 0; }
+
+    let mut g;
+    g = 1;
+    g += 1;
+
+    // ok, not assigned in every branch
+    let i;
+    f();
+    if f() { i = 1; } else { f(); }
+
+    // ok, assigned in a loop
+    let k;
+    loop {
+        k = 1;
+        break;
+    }
+
+    // not linted here, see `useless_let_if_seq`
+    let l;
+    if f() { l = 1; } else { l = 2; }
+}
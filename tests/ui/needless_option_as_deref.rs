@@ -0,0 +1,13 @@
+#![allow(unused)]
+#![warn(needless_option_as_deref)]
+
+fn main() {
+    // should lint
+    let _: Option<&usize> = Some(&1).as_deref();
+    let x = Some(&String::new());
+    let _ = x.as_deref();
+
+    // should not lint
+    let _ = Some(Box::new(1)).as_deref();
+    let _ = Some(String::new()).as_deref();
+}
@@ -0,0 +1,16 @@
+#![warn(needless_rev)]
+
+fn main() {
+    let v = vec![1, 2, 3];
+
+    let _ = v.iter().rev().next();
+    let _ = v.iter().rev().last();
+    let _ = v.iter().rev().rev().count();
+    let _ = (0..10).rev().next();
+    let _ = v.iter().map(|x| x * 2).rev().next();
+
+    // ok
+    let _ = v.iter().next_back();
+    let _ = v.iter().rev().nth(1);
+    let _ = v.iter().rev().collect::<Vec<_>>();
+}
@@ -0,0 +1,26 @@
+// ignore-windows
+
+#![warn(non_octal_unix_permissions)]
+
+use std::fs::{DirBuilder, OpenOptions, Permissions};
+use std::os::unix::fs::{DirBuilderExt, OpenOptionsExt, PermissionsExt};
+
+fn main() {
+    let mut options = OpenOptions::new();
+    options.mode(644);
+    options.mode(0o644);
+
+    let mut permissions = Permissions::from_mode(0o644);
+    permissions.set_mode(755);
+    permissions.set_mode(0o755);
+    permissions.set_mode(0x1ed);
+
+    let mut builder = DirBuilder::new();
+    builder.mode(755u32);
+    builder.mode(0o755);
+
+    // ok, not octal digits
+    permissions.set_mode(488);
+    // ok, the same in octal
+    permissions.set_mode(0);
+}
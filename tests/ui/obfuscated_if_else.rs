@@ -0,0 +1,17 @@
+#![warn(obfuscated_if_else)]
+#![allow(unused)]
+
+fn side() -> i32 {
+    0
+}
+
+fn main() {
+    let x = 1;
+    let sign = (x >= 0).then_some("+").unwrap_or("-");
+    let value = true.then(|| x + 1).unwrap_or_else(|| x - 1);
+    let called = (x == 1).then(side).unwrap_or_else(|| 2);
+    let sum = (x > 0).then_some(1).unwrap_or(2) + 3;
+
+    // ok, another method
+    let default = (x > 0).then_some(1).unwrap_or_default();
+}
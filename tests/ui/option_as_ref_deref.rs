@@ -0,0 +1,46 @@
+#![allow(unused, redundant_closure)]
+#![warn(option_as_ref_deref)]
+
+use std::ffi::{CString, OsString};
+use std::ops::{Deref, DerefMut};
+use std::path::PathBuf;
+
+fn main() {
+    let mut opt = Some(String::from("123"));
+
+    let _ = opt.as_ref().map(String::as_str);
+    let _ = opt.as_ref().map(|x| x.as_str());
+    let _ = opt.as_mut().map(String::as_mut_str);
+    let _ = opt.as_mut().map(|x| x.as_mut_str());
+    let _ = opt.as_ref().map(Deref::deref);
+    let _ = opt.as_ref().map(|x| x.deref());
+    let _ = opt.as_mut().map(DerefMut::deref_mut);
+    let _ = opt.as_ref().map(|x| &**x);
+    let _ = opt.as_mut().map(|x| &mut **x);
+
+    let _ = Some(CString::new(vec![]).unwrap()).as_ref().map(CString::as_c_str);
+    let _ = Some(OsString::new()).as_ref().map(OsString::as_os_str);
+    let _ = Some(PathBuf::new()).as_ref().map(PathBuf::as_path);
+    let _ = Some(Vec::<()>::new()).as_ref().map(Vec::as_slice);
+    let _ = Some(Vec::<()>::new()).as_mut().map(Vec::as_mut_slice);
+    let _ = Some(Box::new(1)).as_ref().map(|x| &**x);
+
+    // ok
+    let _ = opt.as_ref().map(|x| x.len());
+    let _ = opt.as_ref().map(|x| &x[1..]);
+    let _ = opt.as_ref().map(String::as_bytes);
+    let _ = opt.as_mut().map(|x| &**x);
+    let _ = opt.as_deref();
+}
+
+#[clippy::msrv = "1.39"]
+fn msrv_1_39() {
+    let opt = Some(String::from("123"));
+    let _ = opt.as_ref().map(String::as_str);
+}
+
+#[clippy::msrv = "1.40"]
+fn msrv_1_40() {
+    let opt = Some(String::from("123"));
+    let _ = opt.as_ref().map(String::as_str);
+}
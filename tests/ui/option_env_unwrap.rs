@@ -0,0 +1,8 @@
+#![warn(option_env_unwrap)]
+
+fn main() {
+    let _ = option_env!("PATH").unwrap();
+    let _ = option_env!("PATH").expect("environment variable PATH isn't set");
+    let _ = option_env!("__CLIPPY_ENV_VAR_THAT_DOES_NOT_EXIST").unwrap();
+    let _ = option_env!("__CLIPPY_ENV_VAR_THAT_DOES_NOT_EXIST").unwrap_or("default"); // ok
+}
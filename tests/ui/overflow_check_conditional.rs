@@ -1,7 +1,7 @@
 
 
 
-#![allow(many_single_char_names)]
+#![allow(empty_if_branches, many_single_char_names)]
 #![warn(overflow_check_conditional)]
 
 fn main() {
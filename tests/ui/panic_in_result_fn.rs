@@ -0,0 +1,50 @@
+#![warn(unwrap_in_result, panic_in_result_fn)]
+#![allow(unreachable_code)]
+
+fn unwrap_option(x: Option<u32>) -> Result<u32, String> {
+    Ok(x.unwrap())
+}
+
+fn expect_result(s: &str) -> Result<u32, String> {
+    let x: u32 = s.parse().expect("not a number");
+    Ok(x)
+}
+
+fn panics(x: u32) -> Result<u32, String> {
+    if x == 0 {
+        panic!("zero");
+    }
+    if x == 1 {
+        panic!("one: {}", x);
+    }
+    if x == 2 {
+        unreachable!();
+    }
+    unimplemented!()
+}
+
+fn closure_returning_result() -> Result<u32, String> {
+    let f = || -> Result<u32, String> { Ok(Some(1).unwrap()) };
+    f()
+}
+
+// ok, no `Result`
+fn no_result(x: Option<u32>) -> u32 {
+    let f = || x.unwrap();
+    f()
+}
+
+// ok, the closure doesn't return a `Result`
+fn closure_not_returning_result(x: Option<u32>) -> Result<u32, String> {
+    let f = || x.unwrap();
+    Ok(f())
+}
+
+fn main() {
+    let _ = unwrap_option(Some(1));
+    let _ = expect_result("1");
+    let _ = panics(3);
+    let _ = closure_returning_result();
+    let _ = no_result(Some(1));
+    let _ = closure_not_returning_result(Some(1));
+}
@@ -0,0 +1,12 @@
+use std::path::PathBuf;
+
+fn main() {
+    let mut x = PathBuf::from("/foo");
+    x.push("/bar");
+    x.push("\\bar");
+    x.push("C:\\bar");
+
+    // not linted
+    x.push("bar");
+    x.push("bar/baz");
+}
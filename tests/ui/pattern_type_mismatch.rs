@@ -0,0 +1,55 @@
+#![warn(pattern_type_mismatch)]
+#![allow(dead_code, single_match, match_ref_pats)]
+
+fn main() {}
+
+fn syntax_match() {
+    let ref_value = &Some(42);
+
+    // not ok
+    match ref_value {
+        Some(_x) => (),
+        None => (),
+    }
+
+    // ok
+    match ref_value {
+        &Some(_x) => (),
+        &None => (),
+    }
+    match *ref_value {
+        Some(ref _x) => (),
+        None => (),
+    }
+}
+
+fn syntax_if_let() {
+    let ref_value = &mut Some(42);
+
+    // not ok
+    if let Some(_x) = ref_value {}
+
+    // ok
+    if let &mut Some(ref _x) = ref_value {}
+}
+
+fn syntax_let() {
+    let ref_value = &(2, 3);
+
+    // not ok
+    let (_a, _b) = ref_value;
+
+    // ok
+    let &(_a, _b) = ref_value;
+    let (ref _a, ref _b) = *ref_value;
+}
+
+fn nested() {
+    let ref_value = &Some(&(1, 2));
+
+    // not ok
+    if let &Some((_a, _b)) = ref_value {}
+
+    // ok
+    if let &Some(&(_a, _b)) = ref_value {}
+}
@@ -0,0 +1,42 @@
+#![warn(inefficient_pow_of_two, manual_rotate)]
+#![allow(dead_code)]
+
+const EIGHT: u32 = 8;
+
+#[inline]
+fn offsets(x: u32, y: usize) -> (u32, u32, usize, u32) {
+    (x * 8, 16 * x, (y + 1) / 4, x * EIGHT)
+}
+
+#[inline(always)]
+fn in_inline_always(x: u64) -> u64 {
+    x * 1024
+}
+
+// not linted
+#[inline]
+fn not_linted(x: u32, z: i32) -> (u32, u32, i32, u32) {
+    (x * 3, 8 / x, z * 8, x * 7)
+}
+
+fn not_inline(x: u32) -> u32 {
+    x * 8
+}
+
+fn rotations(x: u32, y: u8, s: u32, t: usize) {
+    let _ = (x << 3) | (x >> 29);
+    let _ = (x >> 29) | (x << 3);
+    let _ = (y << 1) | (y >> 7);
+    let _ = (x << s) | (x >> (32 - s));
+    let _ = (x >> s) | (x << (32 - s));
+    let _ = (x << t) | (x >> (32 - t));
+
+    // not rotations
+    let _ = (x << 3) | (x >> 28);
+    let _ = (x << 3) | (s >> 29);
+    let _ = (x << s) | (x >> (31 - s));
+    let z = 1i32;
+    let _ = (z << 3) | (z >> 29);
+}
+
+fn main() {}
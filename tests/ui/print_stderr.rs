@@ -0,0 +1,10 @@
+#![warn(print_stderr, print_stdout)]
+
+fn main() {
+    eprintln!("Hello");
+    eprint!("World");
+    eprintln!();
+
+    // linted by `print_stdout` only
+    println!("Hello");
+}
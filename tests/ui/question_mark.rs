@@ -42,6 +42,96 @@ impl SomeStruct {
 	}
 }
 
+pub struct MoveStruct {
+    pub opt: Option<Vec<u32>>,
+}
+
+impl MoveStruct {
+    pub fn ref_func(&self) -> Option<Vec<u32>> {
+        if self.opt.is_none() {
+            return None;
+        }
+
+        self.opt.clone()
+    }
+}
+
+// ok, there's an `else` branch
+fn if_else(a: Option<u32>) -> Option<u32> {
+    if a.is_none() {
+        return None;
+    } else {
+        println!("some");
+    }
+
+    a
+}
+
+#[derive(Debug)]
+struct MyError;
+
+#[derive(Debug)]
+struct OtherError;
+
+impl From<MyError> for OtherError {
+    fn from(_: MyError) -> Self {
+        OtherError
+    }
+}
+
+fn result_func(r: Result<u32, MyError>) -> Result<u32, MyError> {
+    let v = match r {
+        Ok(v) => v,
+        Err(e) => return Err(e),
+    };
+
+    Ok(v + 1)
+}
+
+fn result_reversed(r: Result<u32, MyError>) -> Result<u32, MyError> {
+    let v = match r {
+        Err(e) => {
+            return Err(e);
+        },
+        Ok(v) => v,
+    };
+
+    Ok(v)
+}
+
+fn result_converted(r: Result<u32, MyError>, s: Result<u32, MyError>) -> Result<u32, OtherError> {
+    let v = match r {
+        Ok(v) => v,
+        Err(e) => return Err(e.into()),
+    };
+    let w = match s {
+        Ok(w) => w,
+        Err(e) => return Err(From::from(e)),
+    };
+
+    Ok(v + w)
+}
+
+// ok, the error isn't the matched one
+fn result_replaced(r: Result<u32, MyError>) -> Result<u32, OtherError> {
+    let v = match r {
+        Ok(v) => v,
+        Err(_) => return Err(OtherError),
+    };
+
+    Ok(v)
+}
+
+// ok, the value is changed
+fn result_changed(r: Result<u32, MyError>) -> Result<u32, MyError> {
+    let v = match r {
+        Ok(v) => v + 1,
+        Err(e) => return Err(e),
+    };
+
+    Ok(v)
+}
+
 fn main() {
 	some_func(Some(42));
 	some_func(None);
@@ -51,4 +141,14 @@ fn main() {
 
     let so = SeemsOption::Some(45);
     returns_something_similar_to_option(so);
+
+    let move_struct = MoveStruct { opt: Some(vec![42]) };
+    move_struct.ref_func();
+    if_else(Some(42));
+
+    let _ = result_func(Ok(42));
+    let _ = result_reversed(Err(MyError));
+    let _ = result_converted(Ok(42), Ok(42));
+    let _ = result_replaced(Ok(42));
+    let _ = result_changed(Ok(42));
 }
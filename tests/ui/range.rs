@@ -5,6 +5,8 @@ impl NotARange {
     fn step_by(&self, _: u32) {}
 }
 
+const ZERO: usize = 0;
+
 #[warn(iterator_step_by_zero, range_zip_with_len)]
 fn main() {
     let _ = (0..1).step_by(0);
@@ -28,6 +30,8 @@ fn main() {
 
     // check const eval
     let _ = v1.iter().step_by(2/3);
+    let _ = v1.iter().step_by(ZERO);
+    let _ = v1.iter().step_by(ZERO + 1);
 }
 
 #[allow(unused)]
@@ -0,0 +1,38 @@
+#![warn(read_zero_byte_vec)]
+#![allow(dead_code, unused_variables, unused_io_amount)]
+
+use std::fs::File;
+use std::io::{self, Read};
+
+fn test() -> io::Result<()> {
+    let mut f = File::open("foo.txt")?;
+    let cap = 10;
+
+    // should lint
+    let mut data = Vec::with_capacity(20);
+    f.read_exact(&mut data)?;
+
+    let mut data2 = Vec::with_capacity(cap);
+    f.read_exact(&mut data2)?;
+
+    let mut data3 = Vec::new();
+    let _ = f.read(&mut data3)?;
+
+    let mut data4 = Vec::new();
+    let n = f.read(&mut data4).unwrap();
+
+    // should not lint
+    let mut buf = Vec::with_capacity(cap);
+    buf.resize(cap, 0);
+    f.read_exact(&mut buf)?;
+
+    let mut buf2 = Vec::new();
+    f.read_to_end(&mut buf2)?;
+
+    let mut buf3 = vec![0; cap];
+    f.read_exact(&mut buf3)?;
+
+    Ok(())
+}
+
+fn main() {}
@@ -0,0 +1,62 @@
+#![warn(readonly_write_lock)]
+#![allow(unused)]
+
+use std::sync::RwLock;
+
+fn mutate_i32(x: &mut i32) {
+    *x += 1;
+}
+
+fn accept_i32(_: i32) {}
+
+fn main() {
+    let lock = RwLock::new(42);
+    let lock2 = RwLock::new(vec![1]);
+
+    {
+        let writer = lock.write().unwrap();
+        println!("{}", *writer);
+    }
+
+    {
+        let writer = lock.write().expect("poisoned");
+        accept_i32(*writer);
+    }
+
+    {
+        let writer = lock2.write().unwrap();
+        println!("{}", writer.len());
+    }
+
+    // the lint allows these
+    {
+        let mut writer = lock.write().unwrap();
+        *writer += 1;
+    }
+
+    {
+        let mut writer = lock.write().unwrap();
+        mutate_i32(&mut writer);
+    }
+
+    {
+        let mut writer = lock2.write().unwrap();
+        writer.push(2);
+    }
+
+    {
+        let writer = lock.write().unwrap();
+        drop(writer);
+    }
+
+    {
+        let mut writer = lock.write().unwrap();
+        let mut add = || *writer += 1;
+        add();
+    }
+
+    {
+        let reader = lock.read().unwrap();
+        accept_i32(*reader);
+    }
+}
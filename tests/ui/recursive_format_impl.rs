@@ -0,0 +1,86 @@
+#![warn(recursive_format_impl)]
+#![allow(dead_code)]
+
+use std::fmt;
+use std::ops::Deref;
+
+struct ViaToString(i32);
+
+impl fmt::Display for ViaToString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = self.to_string();
+        f.write_str(&s)
+    }
+}
+
+struct Direct(i32);
+
+impl fmt::Display for Direct {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+struct Borrowed(i32);
+
+impl fmt::Display for Borrowed {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:>4}", &self)
+    }
+}
+
+struct RecursiveDebug(i32);
+
+impl fmt::Debug for RecursiveDebug {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "RecursiveDebug({:?})", *self)
+    }
+}
+
+#[derive(Debug)]
+struct DisplayWithDebug(i32);
+
+// ok, `Debug` is derived
+impl fmt::Display for DisplayWithDebug {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+struct Field(i32);
+
+// ok, the field is formatted
+impl fmt::Display for Field {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)?;
+        f.write_str(&self.0.to_string())
+    }
+}
+
+struct NotFormat(i32);
+
+impl NotFormat {
+    // ok, not an `impl Display`
+    fn fmt(&self) -> String {
+        format!("{}", Field(self.0))
+    }
+}
+
+struct Wrapper(String);
+
+impl Deref for Wrapper {
+    type Target = String;
+
+    fn deref(&self) -> &String {
+        &self.0
+    }
+}
+
+// ok, `**self` is the `String`
+impl fmt::Display for Wrapper {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", **self)
+    }
+}
+
+fn main() {}
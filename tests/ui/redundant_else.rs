@@ -0,0 +1,34 @@
+#![warn(redundant_else)]
+#![allow(dead_code)]
+
+fn foo() {}
+
+fn statements(a: bool) {
+    loop {
+        if a { break } else { foo(); }
+    }
+    if a { return; } else { foo() };
+    if a { panic!() } else { foo() }
+    foo();
+}
+
+fn tail(a: Option<u8>) -> u8 {
+    if let Some(a) = a { panic!("{}", a) } else { 0 }
+}
+
+fn multiline(a: bool) {
+    if a {
+        return;
+    } else {
+        foo();
+        foo();
+    }
+}
+
+fn not_linted(a: bool, b: bool) {
+    if a { foo() } else { return }
+    if a { return } else if b { foo() }
+    foo();
+}
+
+fn main() {}
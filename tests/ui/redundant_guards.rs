@@ -0,0 +1,51 @@
+#![feature(slice_patterns)]
+#![warn(redundant_at_rest_pattern, redundant_guards)]
+#![allow(unused)]
+
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+fn main() {
+    let slice: &[u8] = &[1, 2, 3];
+    if let [a..] = slice {}
+    if let [ref a..] = *slice {}
+    let [mut b..] = [1, 2, 3];
+
+    let opt = Some(5);
+    match opt {
+        Some(x) if x == 5 => {},
+        Some(x) if 0 == x => {},
+        _ => {},
+    }
+    let point = Point { x: 0, y: 1 };
+    match point {
+        Point { x, y } if y == 1 => println!("{}", x),
+        _ => {},
+    }
+    match 'c' {
+        c if c == 'a' => {},
+        _ => {},
+    }
+
+    // the lint allows these
+    if let [a, b..] = slice {}
+    if let [a.., b] = slice {}
+    match opt {
+        Some(x) if x == 5 => println!("{}", x),
+        Some(x) if x == 1 || x == 2 => {},
+        Some(ref x) if *x == 5 => {},
+        Some(x) if x > 5 => {},
+        _ => {},
+    }
+    match Some(1.0) {
+        Some(x) if x == 1.0 => {},
+        _ => {},
+    }
+    let string = String::new();
+    match Some(string) {
+        Some(s) if s == "a" => {},
+        _ => {},
+    }
+}
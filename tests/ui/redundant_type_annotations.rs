@@ -0,0 +1,47 @@
+#![warn(redundant_type_annotations)]
+#![allow(dead_code, unused_variables)]
+
+struct Cake(u32);
+
+impl Cake {
+    fn new() -> Self {
+        Cake(0)
+    }
+
+    fn slices(&self) -> u32 {
+        self.0
+    }
+}
+
+fn flavor() -> &'static str {
+    "chocolate"
+}
+
+fn make<T: Default>() -> T {
+    T::default()
+}
+
+fn main() {
+    let cake: Cake = Cake::new();
+    let slices: u32 = cake.slices();
+    let name: String = String::new();
+    let len: usize = name.len();
+    let flavor: &str = flavor();
+    let text: &str = "text";
+    let yes: bool = true;
+    let byte: u8 = 1u8;
+    let float: f32 = 1.5f32;
+
+    // ok, the type of the literal is inferred from the annotation
+    let int: u64 = 1;
+    let float: f32 = 1.5;
+    // ok, the return type is generic
+    let made: String = make();
+    let v: Vec<u8> = Vec::new();
+    let n: u32 = Default::default();
+    // ok, the value is coerced
+    let bytes: &[u8] = b"bytes";
+    let slice: &[u32] = &[1, 2];
+    // ok, the annotation has placeholders
+    let collected: Vec<_> = vec![1u8].into_iter().collect();
+}
@@ -0,0 +1,40 @@
+#![warn(ref_option_ref)]
+#![allow(unused)]
+
+static THRESHOLD: i32 = 10;
+static REF_THRESHOLD: &Option<&i32> = &Some(&THRESHOLD);
+
+type RefOptRefU32<'a> = &'a Option<&'a u32>;
+
+struct StructRef<'a> {
+    data: &'a Option<&'a u32>,
+}
+
+enum EnumRef<'a> {
+    Variant1(u32),
+    Variant2(&'a Option<&'a u32>),
+}
+
+trait RefOptTrait {
+    fn foo(&self, arg: &Option<&u32>);
+}
+
+impl RefOptTrait for u32 {
+    // the signature is imposed by the trait
+    fn foo(&self, arg: &Option<&u32>) {}
+}
+
+fn ret<'a>(x: &'a u32) -> &'a Option<&'a u32> {
+    unimplemented!()
+}
+
+fn string(s: Option<&String>) {}
+
+fn vec<'a>(v: Option<&'a Vec<u8>>) {}
+
+// the references are fine
+fn fine(a: Option<&u32>, b: &Option<u32>, c: Option<&str>, d: Option<&[u8]>, e: Option<&mut String>) {}
+
+fn main() {
+    let x: &Option<&u32> = &None;
+}
@@ -48,6 +48,7 @@ fn syntax_error() {
 
     let raw_string_error = Regex::new(r"[...\/...]");
     let raw_string_error = Regex::new(r#"[...\/...]"#);
+    let escaped_string_error = Regex::new("\\s[z-a]");
 }
 
 fn trivial_regex() {
@@ -0,0 +1,45 @@
+#![warn(rest_pat_in_fully_bound_structs)]
+#![allow(unused_variables)]
+
+struct A {
+    a: i32,
+    b: i64,
+    c: &'static str,
+}
+
+enum E {
+    One { a: i32, b: i64 },
+    Two(i32),
+}
+
+fn main() {
+    let a_struct = A { a: 5, b: 42, c: "A" };
+
+    match a_struct {
+        A { a: 5, b: 42, c: "", .. } => {}, // Lint
+        A { a: 0, b: 0, c: "", .. } => {},  // Lint
+        _ => {},
+    }
+
+    match a_struct {
+        A { a: 5, b: 42, .. } => {},
+        A { a: 0, b: 0, c: "", .. } => {}, // Lint
+        _ => {},
+    }
+
+    // No lint
+    match a_struct {
+        A { a: 5, .. } => {},
+        A { a: 0, b: 0, .. } => {},
+        _ => {},
+    }
+
+    let e = E::Two(1);
+    match e {
+        E::One { a: 1, b: 2, .. } => {}, // Lint
+        E::One { a: 1, .. } => {},
+        _ => {},
+    }
+
+    let A { a, b, c, .. } = a_struct; // Lint
+}
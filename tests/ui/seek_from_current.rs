@@ -0,0 +1,24 @@
+#![warn(seek_from_current)]
+#![allow(unused)]
+
+use std::fs::File;
+use std::io::{self, Seek, SeekFrom, Write};
+
+fn main() -> io::Result<()> {
+    let mut f = File::create("foo.txt")?;
+    f.write_all(b"Hi!")?;
+    let _ = f.seek(SeekFrom::Current(0))?;
+    let _ = f.seek(SeekFrom::Current(1))?;
+    let _ = f.seek(SeekFrom::Start(0))?;
+    Ok(())
+}
+
+#[clippy::msrv = "1.50"]
+fn msrv_1_50(f: &mut File) -> io::Result<u64> {
+    f.seek(SeekFrom::Current(0))
+}
+
+#[clippy::msrv = "1.51"]
+fn msrv_1_51(f: &mut File) -> io::Result<u64> {
+    f.seek(SeekFrom::Current(0))
+}
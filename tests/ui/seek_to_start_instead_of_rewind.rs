@@ -0,0 +1,33 @@
+#![warn(seek_to_start_instead_of_rewind)]
+#![allow(unused)]
+
+use std::fs::File;
+use std::io::{self, Seek, SeekFrom};
+
+fn seek_to_start<T: Seek>(t: &mut T) {
+    t.seek(SeekFrom::Start(0)).unwrap();
+}
+
+fn main() -> io::Result<()> {
+    let mut f = File::open("foo.txt")?;
+    f.seek(SeekFrom::Start(0))?;
+    f.seek(SeekFrom::Start(0)).expect("failed to seek");
+
+    // ok, the position is used
+    let _ = f.seek(SeekFrom::Start(0))?;
+    let pos = f.seek(SeekFrom::Start(0)).unwrap();
+    // ok, not the start
+    f.seek(SeekFrom::Start(1))?;
+    f.seek(SeekFrom::End(0))?;
+    Ok(())
+}
+
+#[clippy::msrv = "1.54"]
+fn msrv_1_54(f: &mut File) {
+    f.seek(SeekFrom::Start(0)).unwrap();
+}
+
+#[clippy::msrv = "1.55"]
+fn msrv_1_55(f: &mut File) {
+    f.seek(SeekFrom::Start(0)).unwrap();
+}
@@ -0,0 +1,30 @@
+// compile-flags: --test
+
+#![warn(should_panic_without_expect)]
+#![allow(dead_code)]
+
+#[test]
+#[should_panic]
+fn no_message() {
+    panic!("oops");
+}
+
+#[test]
+#[should_panic()]
+fn empty_list() {
+    panic!("oops");
+}
+
+#[test]
+#[should_panic(expected = "oops")]
+fn expected() {
+    panic!("oops");
+}
+
+#[test]
+#[should_panic = "oops"]
+fn message() {
+    panic!("oops");
+}
+
+fn main() {}
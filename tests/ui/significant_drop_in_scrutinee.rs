@@ -0,0 +1,63 @@
+#![warn(significant_drop_in_scrutinee)]
+#![allow(unused, single_match, needless_return)]
+
+use std::cell::RefCell;
+use std::sync::{Mutex, RwLock};
+
+#[clippy::has_significant_drop]
+struct Transaction;
+
+impl Transaction {
+    fn id(&self) -> u32 {
+        0
+    }
+}
+
+fn begin() -> Transaction {
+    Transaction
+}
+
+fn main() {
+    let mutex = Mutex::new(vec![1]);
+    match mutex.lock().unwrap().len() {
+        0 => mutex.lock().unwrap().push(1),
+        _ => (),
+    }
+    if let Some(&n) = mutex.lock().unwrap().first() {
+        mutex.lock().unwrap().push(n);
+    }
+    while let Some(n) = mutex.lock().unwrap().pop() {}
+
+    let cell = RefCell::new(Some(1));
+    match *cell.borrow() {
+        Some(_) => (),
+        None => *cell.borrow_mut() = Some(2),
+    }
+
+    let lock = RwLock::new(1);
+    match lock.read().unwrap().checked_add(1) {
+        Some(_) => (),
+        None => (),
+    }
+
+    match begin().id() {
+        _ => (),
+    }
+
+    // ok, the value is bound before the `match`
+    let len = mutex.lock().unwrap().len();
+    match len {
+        0 => mutex.lock().unwrap().push(1),
+        _ => (),
+    }
+    // ok, the guard is the scrutinee itself
+    match mutex.lock() {
+        Ok(guard) => drop(guard),
+        Err(_) => (),
+    }
+    // ok, no significant drop
+    let v = vec![1];
+    match v.clone().len() {
+        _ => (),
+    }
+}
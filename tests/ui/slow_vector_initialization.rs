@@ -0,0 +1,28 @@
+#![warn(slow_vector_initialization)]
+
+fn main() {
+    let len = 16;
+
+    let mut vec1 = Vec::with_capacity(len);
+    vec1.resize(len, 0);
+
+    let mut vec2 = Vec::with_capacity(len);
+    vec2.extend(std::iter::repeat(0).take(len));
+
+    let mut vec3 = Vec::with_capacity(len);
+    for _ in 0..len {
+        vec3.push(0);
+    }
+
+    // not linted, different lengths or values
+    let mut vec4 = Vec::with_capacity(len);
+    vec4.resize(len - 1, 0);
+    let mut vec5 = Vec::with_capacity(len);
+    vec5.resize(len, 1);
+    // not linted, the vector is used before
+    let mut vec6 = Vec::with_capacity(len);
+    vec6.push(1);
+    vec6.resize(len, 0);
+
+    let _ = (vec1, vec2, vec3, vec4, vec5, vec6);
+}
@@ -0,0 +1,15 @@
+#![warn(string_slice)]
+#![allow(no_effect, unnecessary_operation)]
+
+fn main() {
+    let s = "Ölkanne";
+    &s[1..];
+    let m = &String::from("Öl");
+    &m[..2];
+    &s[..];
+
+    // byte slices do not have character boundaries
+    let b = s.as_bytes();
+    &b[1..];
+    let _ = s.get(1..);
+}
@@ -0,0 +1,56 @@
+#![warn(struct_field_names)]
+#![allow(dead_code)]
+
+struct Config {
+    config_path: String,
+    config_port: u16,
+    config_timeout: u64,
+}
+
+struct Timings {
+    start_time: u64,
+    end_time: u64,
+    wait_time: u64,
+}
+
+struct Server {
+    server_name: String,
+    port: u16,
+}
+
+struct HttpClient {
+    timeout: u64,
+    retries: u32,
+    http_client_id: u32,
+}
+
+struct Field {
+    other_field: u8,
+    value: u8,
+}
+
+// ok
+struct TooFew {
+    data_a: u8,
+    data_b: u8,
+}
+
+struct Numbered {
+    field_1: u8,
+    field_2: u8,
+    field_3: u8,
+}
+
+struct Words {
+    runtime: u64,
+    uptime: u64,
+    downtime: u64,
+}
+
+struct Tuple(u8, u8, u8);
+
+struct Servers {
+    servername: String,
+}
+
+fn main() {}
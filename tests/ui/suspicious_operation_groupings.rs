@@ -0,0 +1,43 @@
+#![allow(dead_code, float_cmp)]
+#![warn(suspicious_operation_groupings)]
+
+struct Vec3 {
+    x: f64,
+    y: f64,
+    z: f64,
+}
+
+fn dot(a: &Vec3, b: &Vec3) -> f64 {
+    a.x * b.x + a.y * b.x + a.z * b.z
+}
+
+fn dot_lhs_typo(a: &Vec3, b: &Vec3) -> f64 {
+    a.x * b.x + a.x * b.y + a.z * b.z
+}
+
+fn dot_2d(a: &Vec3, b: &Vec3) -> f64 {
+    a.x * b.x + a.y * b.x
+}
+
+fn eq(a: &Vec3, b: &Vec3) -> bool {
+    a.x == b.x && a.y == b.y && a.z == b.y
+}
+
+// ok
+fn correct_dot(a: &Vec3, b: &Vec3) -> f64 {
+    a.x * b.x + a.y * b.y + a.z * b.z
+}
+
+fn cross_z(a: &Vec3, b: &Vec3) -> f64 {
+    a.x * b.y + a.y * b.x
+}
+
+fn different_operators(a: &Vec3, b: &Vec3) -> f64 {
+    a.x * b.x + a.y / b.x
+}
+
+fn different_bases(a: &Vec3, b: &Vec3, c: &Vec3) -> f64 {
+    a.x * b.x + a.y * c.x
+}
+
+fn main() {}
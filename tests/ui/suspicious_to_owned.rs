@@ -0,0 +1,20 @@
+#![warn(suspicious_to_owned)]
+#![allow(unused)]
+
+use std::borrow::Cow;
+
+fn main() {
+    let cow = Cow::Borrowed("Hello world!");
+    let owned = cow.to_owned();
+
+    let vec = vec![1, 2, 3];
+    let cow: Cow<'_, [i32]> = Cow::Borrowed(&vec);
+    let owned = cow.to_owned();
+
+    // ok, not a `Cow`
+    let s = "Hello world!".to_owned();
+    // ok, explicit
+    let cow = Cow::Borrowed("Hello world!");
+    let cloned = cow.clone();
+    let owned = cow.into_owned();
+}
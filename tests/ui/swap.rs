@@ -2,7 +2,7 @@
 
 
 #![warn(clippy)]
-#![allow(blacklisted_name, unused_assignments)]
+#![allow(blacklisted_name, dead_code, unused_assignments)]
 
 struct Foo(u32);
 
@@ -56,4 +56,43 @@ fn main() {
     ; let t = c.0;
     c.0 = a;
     a = t;
+
+    field();
+    nested_index();
+}
+
+struct Bar {
+    a: u32,
+    b: u32,
+}
+
+fn field() {
+    let mut bar = Bar { a: 1, b: 2 };
+    let t = bar.a;
+    bar.a = bar.b;
+    bar.b = t;
+}
+
+fn nested_index() {
+    // `&mut foo[0][0]` and `&mut foo[1][0]` can't be borrowed at the same time
+    let mut foo = vec![vec![1, 2], vec![3, 4]];
+    let t = foo[0][0];
+    foo[0][0] = foo[1][0];
+    foo[1][0] = t;
+}
+
+fn guard() {
+    // the fields are swapped through `DerefMut`, `std::mem::swap` would need a reborrow
+    let bar = std::sync::Mutex::new(Bar { a: 1, b: 2 });
+    let mut bar = bar.lock().unwrap();
+    let t = bar.a;
+    bar.a = bar.b;
+    bar.b = t;
+}
+
+fn boxed() {
+    let mut bar = Box::new(Bar { a: 1, b: 2 });
+    let t = bar.a;
+    bar.a = bar.b;
+    bar.b = t;
 }
@@ -0,0 +1,43 @@
+#![warn(swapped_arguments)]
+#![allow(unused)]
+
+fn resize(width: u32, height: u32) {}
+
+fn copy(src: &str, dst: &str, len: usize) {}
+
+fn mixed(width: u32, name: &str, height: u32) {}
+
+struct Window;
+
+impl Window {
+    fn resize(&mut self, width: u32, height: u32) {}
+}
+
+trait Resize {
+    fn resize_to(&self, width: u32, height: u32);
+}
+
+fn pattern((width, height): (u32, u32), other: u32) {}
+
+fn main() {
+    let (width, height) = (640, 480);
+    resize(height, width);
+    mixed(height, "window", width);
+    Window.resize(height, width);
+
+    let (src, dst) = ("a", "b");
+    copy(dst, src, 1);
+
+    // the lint allows these
+    resize(width, height);
+    resize(height, height);
+    resize(width * 2, height);
+    let (w, h) = (width, height);
+    resize(h, w);
+    let (width, height) = (640u32, 480u8);
+    mixed(u32::from(height), "window", width);
+}
+
+fn trait_method<T: Resize>(t: &T, width: u32, height: u32) {
+    t.resize_to(height, width);
+}
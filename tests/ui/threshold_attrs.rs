@@ -0,0 +1,22 @@
+#![warn(too_many_arguments, type_complexity)]
+#![allow(dead_code)]
+
+#[clippy::too_many_arguments = "8"]
+fn eight_args(_one: u32, _two: u32, _three: u32, _four: u32, _five: u32, _six: u32, _seven: u32, _eight: u32) {}
+
+#[clippy::too_many_arguments = "2"]
+fn three_args(_one: u32, _two: u32, _three: u32) {}
+
+// the attribute only applies to the item it is on
+fn still_eight_args(_one: u32, _two: u32, _three: u32, _four: u32, _five: u32, _six: u32, _seven: u32, _eight: u32) {}
+
+#[clippy::type_complexity = "50"]
+fn option_vec(_value: Vec<Option<u32>>) {}
+
+// and to everything inside the item
+#[clippy::type_complexity = "50"]
+mod strict {
+    pub struct Values(pub Vec<Option<u32>>);
+}
+
+fn main() {}
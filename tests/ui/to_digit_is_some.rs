@@ -0,0 +1,13 @@
+#![warn(to_digit_is_some)]
+
+fn main() {
+    let c = 'x';
+    let d = &c;
+
+    let _ = d.to_digit(10).is_some();
+    let _ = c.to_digit(8).is_some();
+
+    // not linted
+    let _ = c.to_digit(10).is_none();
+    let _ = c.is_digit(10);
+}
@@ -0,0 +1,58 @@
+#![warn(trailing_empty_array)]
+#![allow(unused)]
+
+struct RarelyUseful {
+    field: i32,
+    last: [usize; 0],
+}
+
+struct OnlyField {
+    first_and_last: [usize; 0],
+}
+
+struct GenericArray<T> {
+    field: i32,
+    last: [T; 0],
+}
+
+const ZERO: usize = 0;
+
+struct ConstLength {
+    field: i32,
+    last: [usize; ZERO],
+}
+
+struct ConstExprLength {
+    field: i32,
+    last: [usize; 1 - 1],
+}
+
+struct Tuple(i32, [usize; 0]);
+
+// the lint allows these
+
+#[repr(C)]
+struct GoodReason {
+    field: i32,
+    last: [usize; 0],
+}
+
+#[repr(C, packed)]
+struct GoodReasonPacked {
+    field: i32,
+    last: [usize; 0],
+}
+
+struct NotLast {
+    first: [usize; 0],
+    last: i32,
+}
+
+struct NonZeroLength {
+    field: i32,
+    last: [usize; 1],
+}
+
+struct Empty {}
+
+fn main() {}
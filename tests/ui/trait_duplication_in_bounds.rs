@@ -0,0 +1,23 @@
+#![warn(trait_duplication_in_bounds)]
+#![allow(dead_code)]
+
+fn bad_foo<T: Clone + Default, Z: Copy>(_arg0: T, _arg1: Z)
+where
+    T: Clone,
+    T: Default,
+{
+}
+
+fn good_bar<T: Clone + Default>(_arg: T) {}
+
+fn good_foo<T>(_arg: T)
+where
+    T: Clone + Default,
+{
+}
+
+fn bad_list<T: Clone + Clone>(_arg: T) {}
+
+fn generic_args<T: Into<u8> + Into<u16> + std::convert::Into<u8>>(_arg: T) {}
+
+fn main() {}
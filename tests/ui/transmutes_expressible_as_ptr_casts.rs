@@ -0,0 +1,25 @@
+#![warn(transmutes_expressible_as_ptr_casts)]
+#![allow(dead_code)]
+
+use std::mem::transmute;
+
+fn foo(_: u32) -> u8 {
+    0
+}
+
+fn main() {
+    let x = 1u32;
+    let fn_ptr: fn(u32) -> u8 = foo;
+    let ptr = &x as *const u32;
+
+    unsafe {
+        let _: usize = transmute(&x);
+        let _: isize = transmute(&x);
+        let _: usize = transmute(ptr);
+        let _: usize = transmute(fn_ptr);
+        let _: *const u8 = transmute(fn_ptr);
+
+        // not linted
+        let _: u64 = transmute(3.0f64);
+    }
+}
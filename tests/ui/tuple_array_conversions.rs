@@ -0,0 +1,28 @@
+#![warn(tuple_array_conversions)]
+
+fn main() {
+    let t = (1, 2, 3);
+    let _ = [t.0, t.1, t.2];
+    let a = [1, 2];
+    let _ = (a[0], a[1]);
+    let s = (String::new(), String::new());
+    let _ = [s.0, s.1];
+
+    // not linted
+    let _ = [t.0, t.1];
+    let _ = [t.1, t.0, t.2];
+    let _ = [t.0, t.0, t.2];
+    let mixed = (1u8, 2u16);
+    let _ = [mixed.0 as u16, mixed.1];
+    let u = (4, 5, 6);
+    let _ = [t.0, u.1, t.2];
+    let _ = (a[1], a[0]);
+    let b = [1, 2, 3];
+    let _ = (b[0], b[1]);
+}
+
+#[clippy::msrv = "1.70"]
+fn too_old() {
+    let t = (1, 2);
+    let _ = [t.0, t.1];
+}
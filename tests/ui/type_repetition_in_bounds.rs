@@ -0,0 +1,14 @@
+#![warn(type_repetition_in_bounds)]
+#![allow(dead_code)]
+
+pub fn foo<T>(_t: T) where T: Copy, T: Clone {}
+
+pub fn bar<T>(_t: T) where T: Copy + Clone {}
+
+// different types
+pub fn baz<T, U>(_t: T, _u: U) where T: Copy, U: Clone {}
+
+// higher-ranked bounds are left alone
+pub fn hrtb<T>(_t: T) where T: Copy, for<'a> T: PartialEq<&'a T> {}
+
+fn main() {}
@@ -0,0 +1,90 @@
+#![warn(unbuffered_file_io)]
+#![allow(unused_must_use)]
+
+use std::fs::File;
+use std::io::{self, BufWriter, Read, Write};
+
+fn write(lines: &[&str]) -> io::Result<()> {
+    let mut file = File::create("lines.txt")?;
+    for line in lines {
+        file.write_all(b"> ")?;
+        writeln!(file)?;
+        write!(file, "{}", line)?;
+    }
+    Ok(())
+}
+
+fn write_by_ref(file: &mut File, n: usize) {
+    let mut i = 0;
+    while i < n {
+        file.write(&[0]);
+        i += 1;
+    }
+}
+
+fn read() -> io::Result<()> {
+    let mut file = File::open("bytes.bin")?;
+    let mut byte = [0];
+    loop {
+        file.read_exact(&mut byte)?;
+        if byte[0] == 0 {
+            return Ok(());
+        }
+    }
+}
+
+fn no_loop() -> io::Result<()> {
+    let mut file = File::create("once.txt")?;
+    file.write_all(b"once")
+}
+
+fn opened_in_loop(names: &[&str]) -> io::Result<()> {
+    for name in names {
+        let mut file = File::create(name)?;
+        file.write_all(b"content")?;
+    }
+    Ok(())
+}
+
+fn buffered(lines: &[&str]) -> io::Result<()> {
+    let mut file = BufWriter::new(File::create("lines.txt")?);
+    for line in lines {
+        writeln!(file, "{}", line)?;
+    }
+    Ok(())
+}
+
+fn in_closure(lines: &[&str]) -> io::Result<()> {
+    let mut file = File::create("lines.txt")?;
+    let mut write_line = |line: &str| file.write_all(line.as_bytes());
+    write_line(lines[0])
+}
+
+fn read_to_end() -> io::Result<()> {
+    let mut file = File::open("bytes.bin")?;
+    let mut bytes = Vec::new();
+    for _ in 0..2 {
+        file.read_to_end(&mut bytes)?;
+    }
+    Ok(())
+}
+
+fn large_buffer() -> io::Result<()> {
+    let mut file = File::open("bytes.bin")?;
+    let mut buf = [0; 8192];
+    loop {
+        if file.read(&mut buf)? == 0 {
+            return Ok(());
+        }
+    }
+}
+
+fn unknown_size(lines: &[&str]) -> io::Result<()> {
+    let mut file = File::create("lines.txt")?;
+    for line in lines {
+        file.write_all(line.as_bytes())?;
+    }
+    Ok(())
+}
+
+fn main() {}
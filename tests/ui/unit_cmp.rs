@@ -2,7 +2,7 @@
 
 
 #![warn(unit_cmp)]
-#![allow(no_effect, unnecessary_operation)]
+#![allow(empty_if_branches, no_effect, unnecessary_operation)]
 
 #[derive(PartialEq)]
 pub struct ContainsUnit(()); // should be fine
@@ -0,0 +1,57 @@
+#![warn(unnecessary_struct_initialization)]
+#![allow(unused)]
+
+#[derive(Clone)]
+struct S {
+    f: String,
+    n: u32,
+}
+
+#[derive(Clone)]
+struct C {
+    n: u32,
+}
+
+#[derive(Clone, Copy)]
+struct P {
+    x: i32,
+    y: i32,
+}
+
+struct NoClone {
+    n: u32,
+}
+
+fn make() -> S {
+    S { f: String::new(), n: 0 }
+}
+
+fn main() {
+    let a = make();
+    let _ = S { ..a.clone() };
+    let _ = S { ..a };
+    let _ = S { ..make() };
+
+    let r = &C { n: 0 };
+    let _ = C { ..*r };
+
+    let p = P { x: 1, y: 2 };
+    let rp = &p;
+    let _ = P { x: rp.x, y: rp.y };
+    let _ = P { x: p.x, ..p };
+
+    let v = vec![C { n: 0 }];
+    let _ = C { ..v[0] };
+
+    // ok, not the same value
+    let q = P { x: 0, y: 0 };
+    let _ = P { x: p.x, y: q.y };
+    let _ = P { x: p.y, y: p.x };
+    let _ = P { x: 0, ..p };
+    let b = make();
+    let _ = S { f: b.f.clone(), n: b.n };
+
+    // ok, can't be moved nor cloned
+    let n = &NoClone { n: 0 };
+    let _ = NoClone { ..*n };
+}
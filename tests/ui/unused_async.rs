@@ -0,0 +1,26 @@
+// compile-flags: --edition 2018
+#![feature(async_await, await_macro, futures_api)]
+#![warn(unused_async)]
+#![allow(dead_code)]
+
+async fn foo() -> i32 {
+    4
+}
+
+async fn bar() -> i32 {
+    await!(foo())
+}
+
+struct S;
+
+impl S {
+    async fn unused(&self) -> i32 {
+        1
+    }
+
+    async fn used(&self) -> i32 {
+        await!(self.unused())
+    }
+}
+
+fn main() {}
@@ -27,5 +27,24 @@ fn unwrap<T: io::Read + io::Write>(s: &mut T) {
     s.read(&mut buf).unwrap();
 }
 
+fn let_underscore<T: io::Read + io::Write>(s: &mut T) -> io::Result<()> {
+    let _ = s.write(b"test")?;
+    let mut buf = [0u8; 4];
+    let _ = s.read(&mut buf).expect("read failed");
+    Ok(())
+}
+
+fn unwrap_or<T: io::Write>(s: &mut T) {
+    s.write(b"test").unwrap_or(0);
+}
+
+fn handled<T: io::Write>(s: &mut T) -> io::Result<usize> {
+    let n = s.write(b"test")?;
+    if n < 4 {
+        s.write(&b"test"[n..])?;
+    }
+    Ok(n)
+}
+
 fn main() {
 }
@@ -0,0 +1,49 @@
+#![warn(unused_self)]
+#![allow(dead_code)]
+
+struct A {
+    x: u8,
+}
+
+impl A {
+    // not ok
+    fn unused_ref(&self) -> u8 {
+        1
+    }
+    fn unused_mut(&mut self) {}
+    fn unused_value(self) -> u8 {
+        let x = 1;
+        x + 1
+    }
+
+    // ok
+    fn used_ref(&self) -> u8 {
+        self.x
+    }
+    fn used_mut(&mut self) {
+        self.x += 1;
+    }
+    fn used_in_closure(&self) -> u8 {
+        let f = || self.x;
+        f()
+    }
+    fn used_in_macro(&self) {
+        println!("{}", self.x);
+    }
+    fn associated() -> u8 {
+        1
+    }
+}
+
+trait T {
+    fn method(&self) -> u8;
+}
+
+impl T for A {
+    // signature imposed by the trait
+    fn method(&self) -> u8 {
+        1
+    }
+}
+
+fn main() {}
@@ -1,6 +1,7 @@
 #![warn(use_self)]
-#![allow(dead_code)]
+#![allow(dead_code, redundant_type_annotations)]
 #![allow(should_implement_trait)]
+#![allow(default_constructed_unit_structs)]
 
 fn main() {}
 
@@ -42,7 +43,7 @@ mod better {
     }
 }
 
-//todo the lint does not handle lifetimed struct
+// in impls with lifetimes, `Self` has the lifetimes of the impl
 //the following module should trigger the lint on the third method only
 mod lifetimes {
     struct Foo<'a>{foo_str: &'a str}
@@ -193,3 +194,44 @@ mod issue2894 {
         }
     }
 }
+
+mod generics {
+    struct Foo<T> {
+        value: T,
+    }
+
+    impl<T> Foo<T> {
+        // `Self` is applicable here
+        fn new(value: T) -> Foo<T> {
+            Foo { value }
+        }
+
+        // cannot replace with `Self`, because that's `Foo<T>`
+        fn other(value: u8) -> Foo<u8> {
+            Foo { value }
+        }
+
+        fn value(self) -> T
+        where
+            Foo<T>: Sized,
+        {
+            let Foo { value } = self;
+            value
+        }
+    }
+}
+
+mod nesting {
+    struct Foo {}
+
+    impl Foo {
+        fn foo() {
+            // `Self` is not in scope in nested items
+            fn bar() -> Foo {
+                Foo {}
+            }
+            let _: Foo = bar();
+            let _ = || Foo {};
+        }
+    }
+}
@@ -1,5 +1,5 @@
 #![deny(useless_asref)]
-#![allow(trivially_copy_pass_by_ref)]
+#![allow(trivially_copy_pass_by_ref, redundant_type_annotations)]
 use std::fmt::Debug;
 
 struct FakeAsRef;
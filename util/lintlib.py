@@ -18,6 +18,7 @@ confvar_re = re.compile(
 
 lint_levels = {
     "correctness": 'Deny',
+    "suspicious": 'Warn',
     "style": 'Warn',
     "complexity": 'Warn',
     "perf": 'Warn',
@@ -51,6 +52,8 @@ def parse_lints(lints, filepath):
                     deprecated = False
                     clippy = True
                     name = ""
+                    # the docs of clippy lints are inside of the macro
+                    last_comment = []
                 elif line.startswith("declare_deprecated_lint!"):
                     comment = False
                     deprecated = True
@@ -58,6 +61,11 @@ def parse_lints(lints, filepath):
                 else:
                     last_comment = []
             if not comment:
+                doc = line.strip()
+                if clippy and doc.startswith("///"):
+                    last_comment.append(doc[4:] + "\n" if doc.startswith("/// ") else doc[3:] + "\n")
+                    continue
+
                 m = lintname_re.search(line)
 
                 if m:
@@ -15,7 +15,8 @@ declare_deprecated_lint_re = re.compile(r'''
 ''', re.VERBOSE | re.DOTALL)
 
 declare_clippy_lint_re = re.compile(r'''
-    declare_clippy_lint! \s* [{(] \s*
+    declare_clippy_lint! \s* [{(]
+    (?: \s* ///[^\n]* | \s* \#\[[^\]]*\] )* \s*
     pub \s+ (?P<name>[A-Z_][A-Z_0-9]*) \s*,\s*
     (?P<cat>[a-z_]+) \s*,\s*
     " (?P<desc>(?:[^"\\]+|\\.)*) " \s* [})]
@@ -23,13 +24,28 @@ declare_clippy_lint_re = re.compile(r'''
 
 nl_escape_re = re.compile(r'\\\n\s*')
 
+version_re = re.compile(r'\#\[clippy::version \s*=\s* "[^"]+" \]', re.VERBOSE)
+
+msrv_check_re = re.compile(r'\.meets\(\s*msrvs::')
+msrv_conf_re = re.compile(r'/// Lint: (?P<lints>[A-Z_, ]+)\. The minimum Rust version')
+
+lint_const_re = re.compile(r'^[A-Z_][A-Z_0-9]*$')
+fn_def_re = re.compile(r'\bfn\s+(?P<name>\w+)\s*(?:<[^(]*>)?\s*\(')
+
+span_lint_call_re = re.compile(r'\b(?:span_lint\w*|span_\w+_and_lint)\s*\(')
+applicability_re = re.compile(r'Applicability::(\w+)')
+unspecified_sugg_re = re.compile(r'\b(?:span_suggestion|span_suggestions|multispan_sugg)\s*\(')
+
+applicability_order = ['MachineApplicable', 'MaybeIncorrect', 'HasPlaceholders', 'Unspecified']
+
 docs_link = 'https://rust-lang-nursery.github.io/rust-clippy/master/index.html'
 
 
-def collect(deprecated_lints, clippy_lints, fn):
+def collect(deprecated_lints, clippy_lints, unversioned_lints, fn):
     """Collect all lints from a file.
 
-    Adds entries to the lints list as `(module, name, level, desc)`.
+    Adds entries to the lints list as `(module, name, level, desc)`, and the
+    names of the lints without a `#[clippy::version]` to `unversioned_lints`.
     """
     with open(fn) as fp:
         code = fp.read()
@@ -45,12 +61,128 @@ def collect(deprecated_lints, clippy_lints, fn):
         # remove \-newline escapes from description string
         desc = nl_escape_re.sub('', match.group('desc'))
         cat = match.group('cat')
+        if not version_re.search(match.group(0)):
+            unversioned_lints.append(match.group('name').lower())
         clippy_lints[cat].append((os.path.splitext(os.path.basename(fn))[0],
                                   match.group('name').lower(),
                                   "allow",
                                   desc.replace('\\"', '"')))
 
 
+def call_args(code, start):
+    """Return the text of the call whose opening parenthesis is at `start`."""
+    depth = 0
+    i = start
+    while i < len(code):
+        c = code[i]
+        if c == '(':
+            depth += 1
+        elif c == ')':
+            depth -= 1
+            if depth == 0:
+                break
+        elif c == '"':
+            i += 1
+            while i < len(code) and code[i] != '"':
+                if code[i] == '\\':
+                    i += 1
+                i += 1
+        i += 1
+    return code[start:i + 1]
+
+
+def collect_msrv_modules(msrv_modules, fn):
+    """Collect the module of a file if its lints are disabled below some MSRV."""
+    with open(fn) as fp:
+        if msrv_check_re.search(fp.read()):
+            msrv_modules.append(os.path.splitext(os.path.basename(fn))[0])
+
+
+def check_msrv_docs(msrv_modules, all_lints):
+    """Check that the documentation of the `msrv` configuration value lists
+    the lints depending on it.
+
+    Returns the error messages. A module checking the MSRV must declare at
+    least one of the listed lints, the other lints it declares aren't checked.
+    """
+    with open(os.path.join('clippy_lints', 'src', 'utils', 'conf.rs')) as fp:
+        match = msrv_conf_re.search(fp.read())
+    if not match:
+        return ['Error: the documentation of the `msrv` configuration value is missing']
+    documented = set(lint.strip().lower() for lint in match.group('lints').split(','))
+    declared = set(l[1] for l in all_lints)
+
+    errors = []
+    for module in sorted(msrv_modules):
+        if not any(l[0] == module and l[1] in documented for l in all_lints):
+            errors.append('Error: the lints of `%s` check the MSRV, list them in the '
+                          'documentation of `msrv` in clippy_lints/src/utils/conf.rs' % module)
+    for lint in sorted(documented - declared):
+        errors.append('Error: the documentation of `msrv` lists the unknown lint `%s`' % lint)
+    return errors
+
+
+def collect_applicabilities(applicabilities, fn):
+    """Collect the applicabilities of the suggestions of each lint emitted in a file.
+
+    Only the lints passed by name to the `span_lint*` functions, or to a
+    function of the same file passing its `lint` parameter on to them, are
+    found. Lints without any suggestion get an empty set.
+    """
+    with open(fn) as fp:
+        code = fp.read()
+
+    # the applicabilities of the functions emitting the lint they are given
+    helpers = {}
+    for match in span_lint_call_re.finditer(code):
+        call = call_args(code, match.end() - 1)
+        args = call[1:].split(',', 2)
+        if len(args) < 2:
+            continue
+        name = args[1].strip()
+        found = set(applicability_re.findall(call))
+        if unspecified_sugg_re.search(call) or \
+           (call_is_sugg(code, match) and not found):
+            found.add('Unspecified')
+        if lint_const_re.match(name):
+            applicabilities.setdefault(name.lower(), set()).update(found)
+        else:
+            helper = enclosing_fn(code, match.start(), name)
+            if helper:
+                helpers.setdefault(helper, set()).update(found)
+
+    for helper, found in helpers.items():
+        for match in re.finditer(r'\b%s\s*\(' % helper, code):
+            for arg in call_args(code, match.end() - 1)[1:-1].split(','):
+                if lint_const_re.match(arg.strip()):
+                    applicabilities.setdefault(arg.strip().lower(), set()).update(found)
+
+
+def enclosing_fn(code, pos, param):
+    """The name of the function around `pos` if `param` is one of its parameters."""
+    fns = list(fn_def_re.finditer(code, 0, pos))
+    if fns and re.search(r'\b%s\s*:\s*&\s*\'static\s+Lint\b' % param,
+                         call_args(code, fns[-1].end() - 1)):
+        return fns[-1].group('name')
+    return None
+
+
+def call_is_sugg(code, match):
+    """Whether the matched call is to `span_lint_and_sugg`."""
+    return code[match.start():match.end()].startswith('span_lint_and_sugg')
+
+
+def gen_infos(lints, applicabilities):
+    """Write the list of lint infos with the applicabilities of the lints."""
+    for (module, name, _, _) in sorted(lints):
+        if name in applicabilities:
+            found = [a for a in applicability_order if a in applicabilities[name]]
+            apps = 'Some(&[%s])' % ', '.join('Applicability::' + a for a in found)
+        else:
+            apps = 'None'
+        yield '    (&crate::%s::%s::INFO, %s),\n' % (module, name.upper(), apps)
+
+
 def gen_group(lints):
     """Write lint group (list of all lints in the form module::NAME)."""
     for (module, name, _, _) in sorted(lints):
@@ -122,6 +254,7 @@ def main(print_only=False, check=False):
     deprecated_lints = []
     clippy_lints = {
         "correctness": [],
+        "suspicious": [],
         "style": [],
         "complexity": [],
         "perf": [],
@@ -137,10 +270,23 @@ def main(print_only=False, check=False):
         return
 
     # collect all lints from source files
+    applicabilities = {}
+    unversioned_lints = []
+    msrv_modules = []
     for fn in os.listdir('clippy_lints/src'):
         if fn.endswith('.rs'):
-            collect(deprecated_lints, clippy_lints,
+            collect(deprecated_lints, clippy_lints, unversioned_lints,
                     os.path.join('clippy_lints', 'src', fn))
+            collect_applicabilities(applicabilities,
+                                    os.path.join('clippy_lints', 'src', fn))
+            collect_msrv_modules(msrv_modules,
+                                 os.path.join('clippy_lints', 'src', fn))
+
+    if unversioned_lints:
+        print('Error: add the version of Clippy the lints were added in, e.g. '
+              '`#[clippy::version = "0.0.213"]`, to: ' +
+              ', '.join(sorted(unversioned_lints)))
+        return 1
 
     # determine version
     with open('Cargo.toml') as fp:
@@ -155,6 +301,7 @@ def main(print_only=False, check=False):
     all_lints = []
     clippy_lint_groups = [
         "correctness",
+        "suspicious",
         "style",
         "complexity",
         "perf",
@@ -165,9 +312,15 @@ def main(print_only=False, check=False):
     for _, value in clippy_lints.iteritems():
         all_lints += value
 
+    msrv_errors = check_msrv_docs(msrv_modules, all_lints)
+    if msrv_errors:
+        print('\n'.join(msrv_errors))
+        return 1
+
     if print_only:
         print_clippy_lint_groups = [
             "correctness",
+            "suspicious",
             "style",
             "complexity",
             "perf",
@@ -231,6 +384,12 @@ def main(print_only=False, check=False):
             lambda: gen_group(value),
             replace_start=False, write_back=not check)
 
+    # same for the lint infos printed by `cargo clippy --explain`
+    changed |= replace_region(
+        'clippy_lints/src/explain.rs', r'begin lint list', r'end lint list',
+        lambda: gen_infos(all_lints, applicabilities),
+        replace_start=False, write_back=not check)
+
     # same for "deprecated" lint collection
     changed |= replace_region(
         'clippy_lints/src/lib.rs', r'let mut store', r'end deprecated lints',